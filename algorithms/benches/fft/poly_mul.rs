@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_algorithms::fft::{polynomial::PolyScratch, DensePolynomial, EvaluationDomain};
+use snarkvm_curves::bls12_377::Fr;
+use snarkvm_utilities::TestRng;
+
+use criterion::Criterion;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// A global allocator that counts the number of allocations.
+struct CountingAllocator;
+
+/// The number of allocations made so far.
+static NUM_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        NUM_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Returns the number of allocations made by `f`.
+fn count_allocations<T>(f: impl FnOnce() -> T) -> usize {
+    let start = NUM_ALLOCATIONS.load(Ordering::Relaxed);
+    std::hint::black_box(f());
+    NUM_ALLOCATIONS.load(Ordering::Relaxed) - start
+}
+
+fn poly_mul(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    for log_degree in [10, 12, 14, 16] {
+        let degree = 1 << log_degree;
+        let a = DensePolynomial::<Fr>::rand(degree - 1, rng);
+        let b = DensePolynomial::<Fr>::rand(degree - 1, rng);
+        let domain = EvaluationDomain::new(2 * degree).unwrap();
+        let mut scratch = PolyScratch::new(domain);
+
+        // Report the number of allocations of each multiplication, after the scratch space is warmed up.
+        let _ = a.mul_in_domain(&b, domain, &mut scratch).unwrap();
+        println!(
+            "Allocations for a degree 2^{log_degree} multiplication: {} with `mul`, {} with `mul_in_domain`",
+            count_allocations(|| &a * &b),
+            count_allocations(|| a.mul_in_domain(&b, domain, &mut scratch).unwrap())
+        );
+
+        c.bench_function(&format!("DensePolynomial::mul (2^{log_degree})"), |bencher| bencher.iter(|| &a * &b));
+        c.bench_function(&format!("DensePolynomial::mul_in_domain (2^{log_degree})"), |bencher| {
+            bencher.iter(|| a.mul_in_domain(&b, domain, &mut scratch).unwrap())
+        });
+    }
+}
+
+criterion_group! {
+    name = poly_mul_group;
+    config = Criterion::default().sample_size(10);
+    targets = poly_mul
+}
+
+criterion_main!(poly_mul_group);
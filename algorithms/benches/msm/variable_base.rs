@@ -55,10 +55,29 @@ fn variable_base_edwards_bls12(c: &mut Criterion) {
     }
 }
 
+fn variable_base_bls12_377_tuned(c: &mut Criterion) {
+    use snarkvm_curves::bls12_377::{Fr, G1Affine};
+    let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(2_000_000);
+
+    // Tune the window sizes for each size class.
+    let window_sizes = tune(1 << 20);
+
+    for size_log2 in 10..=20 {
+        let size = 1 << size_log2;
+        c.bench_function(&format!("VariableBase MSM on BLS12-377 (2^{size_log2}, default)"), |b| {
+            b.iter(|| VariableBase::msm_with_config(&bases[..size], &scalars[..size], &Config::default()))
+        });
+        let window_size = window_sizes.get(&size_log2).copied();
+        c.bench_function(&format!("VariableBase MSM on BLS12-377 (2^{size_log2}, window {window_size:?})"), |b| {
+            b.iter(|| VariableBase::msm(&bases[..size], &scalars[..size]))
+        });
+    }
+}
+
 criterion_group! {
     name = variable_base_group;
     config = Criterion::default().sample_size(10);
-    targets = variable_base_bls12_377, variable_base_edwards_bls12
+    targets = variable_base_bls12_377, variable_base_edwards_bls12, variable_base_bls12_377_tuned
 }
 
 criterion_main!(variable_base_group);
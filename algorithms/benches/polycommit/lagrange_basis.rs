@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_algorithms::{fft::EvaluationDomain, polycommit::kzg10::UniversalParams};
+use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+
+use criterion::Criterion;
+
+#[macro_use]
+extern crate criterion;
+
+fn lagrange_basis(c: &mut Criterion) {
+    let pp = UniversalParams::<Bls12_377>::load().unwrap();
+
+    for size in [1 << 10, 1 << 12, 1 << 14] {
+        let domain = EvaluationDomain::<Fr>::new(size).unwrap();
+
+        c.bench_function(&format!("Lagrange basis - uncached ({size})"), |b| {
+            b.iter(|| {
+                pp.clear_lagrange_cache();
+                pp.lagrange_basis(domain).unwrap()
+            })
+        });
+
+        // Populate the cache, so that each iteration returns the cached basis.
+        pp.lagrange_basis(domain).unwrap();
+        c.bench_function(&format!("Lagrange basis - cached ({size})"), |b| {
+            b.iter(|| pp.lagrange_basis(domain).unwrap())
+        });
+    }
+}
+
+criterion_group! {
+    name = lagrange_basis_group;
+    config = Criterion::default().sample_size(10);
+    targets = lagrange_basis
+}
+
+criterion_main!(lagrange_basis_group);
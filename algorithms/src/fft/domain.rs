@@ -29,7 +29,7 @@ use crate::{
     cfg_into_iter,
     cfg_iter,
     cfg_iter_mut,
-    fft::{DomainCoeff, SparsePolynomial},
+    fft::{polynomial::PolyScratch, DomainCoeff, SparsePolynomial},
 };
 use snarkvm_fields::{batch_inversion, FftField, FftParameters, Field};
 #[cfg(not(feature = "serial"))]
@@ -37,7 +37,7 @@ use snarkvm_utilities::max_available_threads;
 use snarkvm_utilities::{execute_with_max_available_threads, serialize::*};
 
 use rand::Rng;
-use std::{borrow::Cow, fmt};
+use std::{borrow::Cow, fmt, ops::Range};
 
 use anyhow::{ensure, Result};
 
@@ -172,6 +172,15 @@ impl<F: FftField> EvaluationDomain<F> {
         });
     }
 
+    /// Compute an FFT, modifying the vector in place, with the precomputed roots of unity in `scratch`.
+    /// Note: This panics if `self` is larger than the domain of `scratch`.
+    pub fn fft_in_place_with_scratch<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>, scratch: &PolyScratch<F>) {
+        execute_with_max_available_threads(|| {
+            coeffs.resize(self.size(), T::zero());
+            self.in_order_fft_in_place_with_pc(&mut *coeffs, scratch.fft_precomputation());
+        });
+    }
+
     /// Compute an IFFT.
     pub fn ifft<T: DomainCoeff<F>>(&self, evals: &[T]) -> Vec<T> {
         let mut evals = evals.to_vec();
@@ -188,6 +197,15 @@ impl<F: FftField> EvaluationDomain<F> {
         });
     }
 
+    /// Compute an IFFT, modifying the vector in place, with the precomputed roots of unity in `scratch`.
+    /// Note: This panics if `self` is larger than the domain of `scratch`.
+    pub fn ifft_in_place_with_scratch<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>, scratch: &PolyScratch<F>) {
+        execute_with_max_available_threads(|| {
+            evals.resize(self.size(), T::zero());
+            self.in_order_ifft_in_place_with_pc(&mut *evals, scratch.ifft_precomputation());
+        });
+    }
+
     /// Compute an FFT over a coset of the domain.
     pub fn coset_fft<T: DomainCoeff<F>>(&self, coeffs: &[T]) -> Vec<T> {
         let mut coeffs = coeffs.to_vec();
@@ -304,7 +322,43 @@ impl<F: FftField> EvaluationDomain<F> {
 
     /// Return an iterator over the elements of the domain.
     pub fn elements(&self) -> Elements<F> {
-        Elements { cur_elem: F::one(), cur_pow: 0, domain: *self }
+        Elements { cur_elem: F::one(), cur_pow: 0, end: self.size, domain: *self }
+    }
+
+    /// Returns the `i`-th element of the domain, i.e. `g^i`, in `O(log i)` time.
+    pub fn element(&self, i: usize) -> F {
+        self.group_gen.pow([i as u64])
+    }
+
+    /// Return an iterator over the elements of the domain with indices in `range`,
+    /// without computing the elements that precede it.
+    /// The range is truncated to the size of the domain.
+    pub fn elements_range(&self, range: Range<usize>) -> Elements<F> {
+        self.elements_range_with_offset(range, F::one())
+    }
+
+    /// Returns the `i`-th element of the coset `F::multiplicative_generator() * H`,
+    /// i.e. the `i`-th point at which `coset_fft` evaluates.
+    pub fn coset_element(&self, i: usize) -> F {
+        F::multiplicative_generator() * self.element(i)
+    }
+
+    /// Return an iterator over the elements of the coset `F::multiplicative_generator() * H`.
+    pub fn coset_elements(&self) -> Elements<F> {
+        self.coset_elements_range(0..self.size())
+    }
+
+    /// Return an iterator over the elements of the coset `F::multiplicative_generator() * H`
+    /// with indices in `range`. The range is truncated to the size of the domain.
+    pub fn coset_elements_range(&self, range: Range<usize>) -> Elements<F> {
+        self.elements_range_with_offset(range, F::multiplicative_generator())
+    }
+
+    fn elements_range_with_offset(&self, range: Range<usize>, offset: F) -> Elements<F> {
+        let end = (range.end as u64).min(self.size);
+        let start = (range.start as u64).min(end);
+        let cur_elem = offset * self.group_gen.pow([start]);
+        Elements { cur_elem, cur_pow: start, end, domain: *self }
     }
 
     /// The target polynomial is the zero polynomial in our
@@ -859,6 +913,7 @@ pub(crate) fn compute_powers<F: Field>(size: usize, g: F) -> Vec<F> {
 pub struct Elements<F: FftField> {
     cur_elem: F,
     cur_pow: u64,
+    end: u64,
     domain: EvaluationDomain<F>,
 }
 
@@ -866,7 +921,7 @@ impl<F: FftField> Iterator for Elements<F> {
     type Item = F;
 
     fn next(&mut self) -> Option<F> {
-        if self.cur_pow == self.domain.size {
+        if self.cur_pow == self.end {
             None
         } else {
             let cur_elem = self.cur_elem;
@@ -875,8 +930,15 @@ impl<F: FftField> Iterator for Elements<F> {
             Some(cur_elem)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.end - self.cur_pow) as usize;
+        (remaining, Some(remaining))
+    }
 }
 
+impl<F: FftField> ExactSizeIterator for Elements<F> {}
+
 /// An iterator over the elements of the domain.
 #[derive(Clone, Eq, PartialEq, Debug, CanonicalDeserialize, CanonicalSerialize)]
 pub struct FFTPrecomputation<F: FftField> {
@@ -885,6 +947,11 @@ pub struct FFTPrecomputation<F: FftField> {
 }
 
 impl<F: FftField> FFTPrecomputation<F> {
+    /// Returns the domain of the precomputation.
+    pub fn domain(&self) -> EvaluationDomain<F> {
+        self.domain
+    }
+
     pub fn to_ifft_precomputation(&self) -> IFFTPrecomputation<F> {
         let mut inverse_roots = self.roots.clone();
         snarkvm_fields::batch_inversion(&mut inverse_roots);
@@ -915,6 +982,11 @@ pub struct IFFTPrecomputation<F: FftField> {
 }
 
 impl<F: FftField> IFFTPrecomputation<F> {
+    /// Returns the domain of the precomputation.
+    pub fn domain(&self) -> EvaluationDomain<F> {
+        self.domain
+    }
+
     pub fn precomputation_for_subdomain<'a>(&'a self, domain: &EvaluationDomain<F>) -> Option<Cow<'a, Self>> {
         if domain.size() == 1 {
             return Some(Cow::Owned(Self { inverse_roots: vec![], domain: *domain }));
@@ -986,6 +1058,52 @@ mod tests {
         }
     }
 
+    #[test]
+    fn element_matches_elements() {
+        for coeffs in 0..10 {
+            let size = 1 << coeffs;
+            let domain = EvaluationDomain::<Fr>::new(size).unwrap();
+            for i in 0..domain.size() {
+                assert_eq!(Some(domain.element(i)), domain.elements().nth(i));
+                assert_eq!(Some(domain.coset_element(i)), domain.coset_elements().nth(i));
+            }
+        }
+    }
+
+    #[test]
+    fn elements_range_contents() {
+        for coeffs in 0..8 {
+            let size = 1 << coeffs;
+            let domain = EvaluationDomain::<Fr>::new(size).unwrap();
+            for start in 0..=size {
+                for end in start..=size + 2 {
+                    let expected = domain.elements().skip(start).take(end - start).collect::<Vec<_>>();
+                    let range = domain.elements_range(start..end);
+                    assert_eq!(range.len(), expected.len());
+                    assert_eq!(range.collect::<Vec<_>>(), expected);
+
+                    let expected = domain.coset_elements().skip(start).take(end - start).collect::<Vec<_>>();
+                    assert_eq!(domain.coset_elements_range(start..end).collect::<Vec<_>>(), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn coset_elements_match_coset_fft() {
+        let rng = &mut TestRng::default();
+        for coeffs in 0..10 {
+            let size = 1 << coeffs;
+            let domain = EvaluationDomain::<Fr>::new(size).unwrap();
+            let poly = DensePolynomial::<Fr>::rand(size - 1, rng);
+            let evals = domain.coset_fft(&poly.coeffs);
+            for (i, element) in domain.coset_elements().enumerate() {
+                assert_eq!(element, Fr::multiplicative_generator() * domain.element(i));
+                assert_eq!(evals[i], poly.evaluate(element));
+            }
+        }
+    }
+
     /// Test that lagrange interpolation for a random polynomial at a random point works.
     #[test]
     fn non_systematic_lagrange_coefficients_test() {
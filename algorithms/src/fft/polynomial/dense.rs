@@ -31,7 +31,7 @@ use itertools::Itertools;
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
-use super::PolyMultiplier;
+use super::{PolyMultiplier, PolyScratch};
 
 /// Stores a polynomial in coefficient form.
 #[derive(Clone, PartialEq, Eq, Hash, Default, CanonicalSerialize, CanonicalDeserialize)]
@@ -151,6 +151,37 @@ impl<F: PrimeField> DensePolynomial<F> {
         DensePolynomial::from_coefficients_vec(shifted)
     }
 
+    /// Multiply `self` by `other` over the domain `domain`, reusing the evaluation buffers in `scratch`.
+    /// Returns an error if `domain` is too small to interpolate the product, or larger than the domain of `scratch`.
+    pub fn mul_in_domain(
+        &self,
+        other: &Self,
+        domain: EvaluationDomain<F>,
+        scratch: &mut PolyScratch<F>,
+    ) -> anyhow::Result<DensePolynomial<F>> {
+        if self.is_zero() || other.is_zero() {
+            return Ok(DensePolynomial::zero());
+        }
+        anyhow::ensure!(domain.size() > self.degree() + other.degree(), "The domain is too small for the product");
+        anyhow::ensure!(domain.size() <= scratch.domain().size(), "The domain is larger than the scratch space");
+
+        // Take the evaluation buffers, to use them alongside the precomputations.
+        let (mut lhs, mut rhs) = (std::mem::take(&mut scratch.lhs), std::mem::take(&mut scratch.rhs));
+        // Evaluate both polynomials over the domain.
+        for (evals, poly) in [(&mut lhs, self), (&mut rhs, other)] {
+            evals.clear();
+            evals.extend_from_slice(&poly.coeffs);
+            domain.fft_in_place_with_scratch(evals, scratch);
+        }
+        // Multiply the evaluations, and interpolate the product.
+        cfg_iter_mut!(lhs).zip_eq(&rhs).for_each(|(l, r)| *l *= r);
+        domain.ifft_in_place_with_scratch(&mut lhs, scratch);
+        let product = DensePolynomial::from_coefficients_slice(&lhs);
+        // Return the evaluation buffers to the scratch space.
+        (scratch.lhs, scratch.rhs) = (lhs, rhs);
+        Ok(product)
+    }
+
     /// Divide `self` by the vanishing polynomial for the domain `domain`.
     /// Returns the quotient and remainder of the division.
     pub fn divide_by_vanishing_poly(
@@ -500,7 +531,7 @@ impl<F: Field> DerefMut for DensePolynomial<F> {
 
 #[cfg(test)]
 mod tests {
-    use crate::fft::polynomial::*;
+    use crate::fft::{polynomial::*, EvaluationDomain};
     use num_traits::CheckedDiv;
     use snarkvm_curves::bls12_377::Fr;
     use snarkvm_fields::{Field, One, Zero};
@@ -625,6 +656,63 @@ mod tests {
         }
     }
 
+    #[test]
+    fn mul_in_domain_random() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a scratch space that is reused across all of the multiplications.
+        let max_domain = EvaluationDomain::new(140).unwrap();
+        let mut scratch = PolyScratch::new(max_domain);
+
+        for a_degree in 0..70 {
+            for b_degree in 0..70 {
+                let a = DensePolynomial::<Fr>::rand(a_degree, rng);
+                let b = DensePolynomial::<Fr>::rand(b_degree, rng);
+                let expected = &a * &b;
+                // Ensure the product matches, over the smallest domain and over the largest domain.
+                let domain = EvaluationDomain::new(a_degree + b_degree + 1).unwrap();
+                assert_eq!(expected, a.mul_in_domain(&b, domain, &mut scratch).unwrap());
+                assert_eq!(expected, a.mul_in_domain(&b, max_domain, &mut scratch).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn mul_in_domain_rejects_invalid_domains() {
+        let rng = &mut TestRng::default();
+
+        let a = DensePolynomial::<Fr>::rand(10, rng);
+        let b = DensePolynomial::<Fr>::rand(10, rng);
+
+        // Ensure a domain that is too small for the product is rejected.
+        let mut scratch = PolyScratch::new(EvaluationDomain::new(64).unwrap());
+        assert!(a.mul_in_domain(&b, EvaluationDomain::new(16).unwrap(), &mut scratch).is_err());
+        // Ensure a domain that is larger than the scratch space is rejected.
+        let mut scratch = PolyScratch::new(EvaluationDomain::new(16).unwrap());
+        assert!(a.mul_in_domain(&b, EvaluationDomain::new(32).unwrap(), &mut scratch).is_err());
+        // Ensure the zero polynomial is multiplied regardless of the domain.
+        assert!(a.mul_in_domain(&DensePolynomial::zero(), EvaluationDomain::new(1).unwrap(), &mut scratch).is_ok());
+    }
+
+    #[test]
+    fn fft_in_place_with_scratch() {
+        let rng = &mut TestRng::default();
+
+        let scratch = PolyScratch::new(EvaluationDomain::<Fr>::new(1 << 6).unwrap());
+        for log_size in 0..=6 {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            let poly = DensePolynomial::<Fr>::rand((1 << log_size) - 1, rng);
+
+            // Ensure the FFT and IFFT match the versions without scratch space.
+            let mut evals = poly.coeffs.clone();
+            domain.fft_in_place_with_scratch(&mut evals, &scratch);
+            assert_eq!(evals, domain.fft(&poly.coeffs));
+            domain.ifft_in_place_with_scratch(&mut evals, &scratch);
+            assert_eq!(evals, domain.ifft(&domain.fft(&poly.coeffs)));
+            assert_eq!(DensePolynomial::from_coefficients_vec(evals), poly);
+        }
+    }
+
     #[test]
     fn mul_polynomials_n_random() {
         let rng = &mut TestRng::default();
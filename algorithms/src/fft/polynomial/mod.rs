@@ -34,6 +34,9 @@ pub use sparse::SparsePolynomial;
 mod multiplier;
 pub use multiplier::*;
 
+mod scratch;
+pub use scratch::*;
+
 /// Represents either a sparse polynomial or a dense one.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Polynomial<'a, F: Field> {
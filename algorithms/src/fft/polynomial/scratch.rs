@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::fft::{
+    domain::{FFTPrecomputation, IFFTPrecomputation},
+    EvaluationDomain,
+};
+use snarkvm_fields::FftField;
+
+use parking_lot::Mutex;
+use std::borrow::Cow;
+
+/// Reusable buffers for polynomial arithmetic over an evaluation domain (and its subdomains),
+/// which avoid allocating fresh evaluation vectors for every multiplication.
+pub struct PolyScratch<'a, F: FftField> {
+    /// The FFT precomputation for the domain.
+    fft_precomputation: Cow<'a, FFTPrecomputation<F>>,
+    /// The IFFT precomputation for the domain.
+    ifft_precomputation: Cow<'a, IFFTPrecomputation<F>>,
+    /// The evaluation buffer for the left operand, which holds the result of a multiplication.
+    pub(super) lhs: Vec<F>,
+    /// The evaluation buffer for the right operand.
+    pub(super) rhs: Vec<F>,
+}
+
+impl<'a, F: FftField> PolyScratch<'a, F> {
+    /// Initializes the scratch space for the given domain, and its subdomains.
+    pub fn new(domain: EvaluationDomain<F>) -> Self {
+        Self::from_precomputations(Cow::Owned(domain.precompute_fft()), Cow::Owned(domain.precompute_ifft()))
+    }
+
+    /// Initializes the scratch space for the domain of the given precomputations, and its subdomains.
+    pub fn with_precomputation(fft_pc: &'a FFTPrecomputation<F>, ifft_pc: &'a IFFTPrecomputation<F>) -> Self {
+        debug_assert_eq!(fft_pc.domain(), ifft_pc.domain());
+        Self::from_precomputations(Cow::Borrowed(fft_pc), Cow::Borrowed(ifft_pc))
+    }
+
+    /// Initializes the scratch space from the given precomputations, with buffers sized to their domain.
+    fn from_precomputations(
+        fft_precomputation: Cow<'a, FFTPrecomputation<F>>,
+        ifft_precomputation: Cow<'a, IFFTPrecomputation<F>>,
+    ) -> Self {
+        let size = fft_precomputation.domain().size();
+        Self { fft_precomputation, ifft_precomputation, lhs: Vec::with_capacity(size), rhs: Vec::with_capacity(size) }
+    }
+
+    /// Returns the largest domain supported by the scratch space.
+    pub fn domain(&self) -> EvaluationDomain<F> {
+        self.fft_precomputation.domain()
+    }
+
+    /// Returns the FFT precomputation for the domain.
+    pub fn fft_precomputation(&self) -> &FFTPrecomputation<F> {
+        &self.fft_precomputation
+    }
+
+    /// Returns the IFFT precomputation for the domain.
+    pub fn ifft_precomputation(&self) -> &IFFTPrecomputation<F> {
+        &self.ifft_precomputation
+    }
+}
+
+/// A pool of scratch spaces for an evaluation domain (and its subdomains), which lends a scratch space
+/// to each caller and reuses it once the caller is done, so that concurrent jobs share their buffers.
+pub struct PolyScratchPool<'a, F: FftField> {
+    /// The FFT precomputation for the domain.
+    fft_precomputation: &'a FFTPrecomputation<F>,
+    /// The IFFT precomputation for the domain.
+    ifft_precomputation: &'a IFFTPrecomputation<F>,
+    /// The scratch spaces that are not lent out.
+    free: Mutex<Vec<PolyScratch<'a, F>>>,
+}
+
+impl<'a, F: FftField> PolyScratchPool<'a, F> {
+    /// Initializes an empty pool for the domain of the given precomputations, and its subdomains.
+    pub fn with_precomputation(fft_pc: &'a FFTPrecomputation<F>, ifft_pc: &'a IFFTPrecomputation<F>) -> Self {
+        debug_assert_eq!(fft_pc.domain(), ifft_pc.domain());
+        Self { fft_precomputation: fft_pc, ifft_precomputation: ifft_pc, free: Default::default() }
+    }
+
+    /// Runs the given function with a scratch space from the pool, and returns the scratch space to the pool.
+    /// Note: The pool is not locked while the function runs, so the function may itself use the pool.
+    pub fn with_scratch<T>(&self, f: impl FnOnce(&mut PolyScratch<'a, F>) -> T) -> T {
+        let scratch = self.free.lock().pop();
+        let mut scratch = scratch
+            .unwrap_or_else(|| PolyScratch::with_precomputation(self.fft_precomputation, self.ifft_precomputation));
+        let output = f(&mut scratch);
+        self.free.lock().push(scratch);
+        output
+    }
+}
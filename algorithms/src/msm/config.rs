@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::msm::VariableBase;
+use snarkvm_curves::{bls12_377::G1Affine, AffineCurve};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::Uniform;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+use std::{collections::BTreeMap, sync::OnceLock, time::Instant};
+
+/// The tuned window sizes, indexed by the size class (i.e. `log2` of the MSM size), if `tune` was run.
+static TUNED_WINDOW_SIZES: OnceLock<BTreeMap<u32, usize>> = OnceLock::new();
+
+/// The log2 of the smallest MSM size that is tuned.
+const MIN_TUNED_SIZE_LOG2: u32 = 10;
+/// The number of distinct bases sampled for tuning, which are repeated to reach each MSM size.
+const NUM_TUNING_BASES: usize = 1 << 8;
+/// The maximum window size (in bits).
+pub const MAX_WINDOW_SIZE: usize = 24;
+
+/// The configuration of a variable-base multi-scalar multiplication.
+/// Note: The configuration only affects the performance of the MSM, and never its result.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Config {
+    /// The window size (in bits) for Pippenger's algorithm, between `1` and `MAX_WINDOW_SIZE`.
+    /// If `None`, the window size is chosen heuristically from the MSM size.
+    pub window_size: Option<usize>,
+    /// The maximum number of windows that are processed in parallel.
+    /// If `None`, the windows are processed with all of the available threads.
+    pub num_threads: Option<usize>,
+}
+
+impl Config {
+    /// Returns the configuration for an MSM of the given size.
+    /// If `tune` was run, this is the tuned configuration for the size class, and otherwise the default configuration.
+    pub fn for_size(msm_size: usize) -> Self {
+        let window_size = TUNED_WINDOW_SIZES
+            .get()
+            .and_then(|window_sizes| window_sizes.get(&crate::fft::domain::log2(msm_size)))
+            .copied();
+        Self { window_size, num_threads: None }
+    }
+
+    /// Returns the window size (in bits) for an MSM of the given size.
+    pub fn window_size_for(&self, msm_size: usize) -> usize {
+        match self.window_size {
+            Some(window_size) => window_size.clamp(1, MAX_WINDOW_SIZE),
+            None => default_window_size(msm_size),
+        }
+    }
+
+    /// Returns the result of `f` for the start of each window of size `c` in `0..num_bits`,
+    /// processing at most `num_threads` windows in parallel.
+    pub(crate) fn map_windows<T: Send>(
+        &self,
+        num_bits: usize,
+        c: usize,
+        f: impl Fn(usize) -> T + Send + Sync,
+    ) -> Vec<T> {
+        #[cfg(not(feature = "serial"))]
+        {
+            let window_starts: Vec<_> = (0..num_bits).step_by(c).collect();
+            // Process the windows in at most `num_threads` chunks.
+            let min_len = match self.num_threads {
+                Some(num_threads) => (window_starts.len() + num_threads.max(1) - 1) / num_threads.max(1),
+                None => 1,
+            };
+            window_starts.into_par_iter().with_min_len(min_len).map(f).collect()
+        }
+        #[cfg(feature = "serial")]
+        {
+            (0..num_bits).step_by(c).map(f).collect()
+        }
+    }
+}
+
+/// Returns the heuristic window size (in bits) for an MSM of the given size (chosen empirically).
+pub(crate) fn default_window_size(msm_size: usize) -> usize {
+    match msm_size < 32 {
+        true => 1,
+        false => crate::msm::ln_without_floats(msm_size) + 2,
+    }
+}
+
+/// Benchmarks a few candidate window sizes around the heuristic, for each size class from `2^10` up to `max_size`,
+/// and caches the fastest window size of each size class, to be used by `VariableBase::msm` from then on.
+/// Returns the tuned window sizes, indexed by the size class (i.e. `log2` of the MSM size).
+///
+/// Note: Tuning is performed at most once per process. If the window sizes were already tuned,
+/// the cached window sizes are returned, regardless of `max_size`.
+pub fn tune(max_size: usize) -> &'static BTreeMap<u32, usize> {
+    TUNED_WINDOW_SIZES.get_or_init(|| {
+        let rng = &mut rand::thread_rng();
+
+        // Sample the bases and scalars, repeating the bases to reach the maximum size.
+        let max_size = max_size.max(1 << MIN_TUNED_SIZE_LOG2);
+        let bases: Vec<_> = (0..NUM_TUNING_BASES).map(|_| G1Affine::rand(rng)).collect();
+        let bases: Vec<_> = bases.iter().copied().cycle().take(max_size).collect();
+        let scalars: Vec<_> =
+            (0..max_size).map(|_| <G1Affine as AffineCurve>::ScalarField::rand(rng).to_bigint()).collect();
+
+        (MIN_TUNED_SIZE_LOG2..=crate::fft::domain::log2(max_size))
+            .filter(|size_log2| 1usize << size_log2 <= max_size)
+            .map(|size_log2| {
+                let size = 1 << size_log2;
+                // Select the candidate window sizes around the heuristic.
+                let heuristic = default_window_size(size);
+                let candidates = heuristic.saturating_sub(2).max(1)..=(heuristic + 2).min(MAX_WINDOW_SIZE);
+                // Select the fastest candidate window size.
+                let window_size = candidates
+                    .min_by_key(|&window_size| {
+                        let config = Config { window_size: Some(window_size), num_threads: None };
+                        let timer = Instant::now();
+                        VariableBase::msm_with_config(&bases[..size], &scalars[..size], &config);
+                        timer.elapsed()
+                    })
+                    .unwrap_or(heuristic);
+                (size_log2, window_size)
+            })
+            .collect()
+    })
+}
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod config;
+pub use config::*;
+
 pub mod fixed_base;
 pub use fixed_base::*;
 
@@ -12,12 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::msm::Config;
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::{Field, One, PrimeField, Zero};
-use snarkvm_utilities::{cfg_into_iter, BigInteger, BitIteratorBE};
-
-#[cfg(not(feature = "serial"))]
-use rayon::prelude::*;
+use snarkvm_utilities::{BigInteger, BitIteratorBE};
 
 #[cfg(target_arch = "x86_64")]
 use crate::{prefetch_slice, prefetch_slice_write};
@@ -362,6 +360,14 @@ fn batched_window<G: AffineCurve>(
 }
 
 pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+    msm_with_config(bases, scalars, &Config::default())
+}
+
+pub fn msm_with_config<G: AffineCurve>(
+    bases: &[G],
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    config: &Config,
+) -> G::Projective {
     if bases.len() < 15 {
         let num_bits = G::ScalarField::size_in_bits();
         let bigint_size = <G::ScalarField as PrimeField>::BigInteger::NUM_LIMBS * 64;
@@ -384,19 +390,15 @@ pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField
         debug_assert!(bits.iter_mut().all(|b| b.next().is_none()));
         sum
     } else {
-        // Determine the bucket size `c` (chosen empirically).
-        let c = match scalars.len() < 32 {
-            true => 1,
-            false => crate::msm::ln_without_floats(scalars.len()) + 2,
-        };
+        // Determine the bucket size `c`.
+        let c = config.window_size_for(scalars.len());
 
         let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
 
         // Each window is of size `c`.
         // We divide up the bits 0..num_bits into windows of size `c`, and
         // in parallel process each such window.
-        let window_sums: Vec<_> =
-            cfg_into_iter!(0..num_bits).step_by(c).map(|w_start| batched_window(bases, scalars, w_start, c)).collect();
+        let window_sums = config.map_windows(num_bits, c, |w_start| batched_window(bases, scalars, w_start, c));
 
         // We store the sum for the lowest window.
         let (lowest, window_sums) = window_sums.split_first().unwrap();
@@ -18,6 +18,7 @@ pub mod standard;
 #[cfg(target_arch = "x86_64")]
 pub mod prefetch;
 
+use crate::msm::Config;
 use snarkvm_curves::{bls12_377::G1Affine, traits::AffineCurve};
 use snarkvm_fields::PrimeField;
 
@@ -26,7 +27,18 @@ use core::any::TypeId;
 pub struct VariableBase;
 
 impl VariableBase {
+    /// Returns the multi-scalar multiplication of the given bases and scalars,
+    /// using the tuned configuration for its size if `msm::tune` was run, and the default configuration otherwise.
     pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+        Self::msm_with_config(bases, scalars, &Config::for_size(scalars.len()))
+    }
+
+    /// Returns the multi-scalar multiplication of the given bases and scalars, using the given configuration.
+    pub fn msm_with_config<G: AffineCurve>(
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+        config: &Config,
+    ) -> G::Projective {
         // For BLS12-377, we perform variable base MSM using a batched addition technique.
         if TypeId::of::<G>() == TypeId::of::<G1Affine>() {
             #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
@@ -39,11 +51,11 @@ impl VariableBase {
                     return result;
                 }
             }
-            batched::msm(bases, scalars)
+            batched::msm_with_config(bases, scalars, config)
         }
         // For all other curves, we perform variable base MSM using Pippenger's algorithm.
         else {
-            standard::msm(bases, scalars)
+            standard::msm_with_config(bases, scalars, config)
         }
     }
 
@@ -105,6 +117,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_msm_with_config() {
+        use snarkvm_curves::{edwards_bls12::EdwardsAffine, ProjectiveCurve};
+
+        let mut rng = TestRng::default();
+        for msm_size in [1, 10, 50, 100, 500] {
+            let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, msm_size);
+            let (edwards_bases, edwards_scalars) = create_scalar_bases::<EdwardsAffine, _>(&mut rng, msm_size);
+            let expected = VariableBase::msm_naive(&bases, &scalars).to_affine();
+            let edwards_expected = VariableBase::msm_naive(&edwards_bases, &edwards_scalars).to_affine();
+
+            for window_size in [None, Some(0), Some(1), Some(2), Some(5), Some(8), Some(12)] {
+                for num_threads in [None, Some(0), Some(1), Some(3)] {
+                    let config = Config { window_size, num_threads };
+                    // Ensure the result matches the naive sum, for both the batched and standard implementations.
+                    let candidate = VariableBase::msm_with_config(&bases, &scalars, &config).to_affine();
+                    assert_eq!(expected, candidate, "MSM size: {msm_size}, config: {config:?}");
+                    let candidate = VariableBase::msm_with_config(&edwards_bases, &edwards_scalars, &config);
+                    assert_eq!(edwards_expected, candidate.to_affine(), "MSM size: {msm_size}, config: {config:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_msm_after_tune() {
+        use snarkvm_curves::ProjectiveCurve;
+
+        // Tune the window sizes, and ensure each tuned window size is valid.
+        let window_sizes = crate::msm::tune(1 << 10);
+        assert!(!window_sizes.is_empty());
+        assert!(window_sizes.values().all(|window_size| (1..=crate::msm::MAX_WINDOW_SIZE).contains(window_size)));
+
+        // Ensure the result is unchanged.
+        let mut rng = TestRng::default();
+        for msm_size in [100, 1 << 10] {
+            let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, msm_size);
+            let expected = VariableBase::msm_naive(&bases, &scalars).to_affine();
+            assert_eq!(expected, VariableBase::msm(&bases, &scalars).to_affine(), "MSM size: {msm_size}");
+        }
+    }
+
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
     #[test]
     fn test_msm_cuda() {
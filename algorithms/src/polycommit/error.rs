@@ -151,3 +151,52 @@ impl core::fmt::Display for PCError {
         }
     }
 }
+
+/// The error type for `VerifierKey::validate`.
+#[derive(Debug)]
+pub enum VerifierKeyError {
+    /// The named group element is not on the curve.
+    NotOnCurve(&'static str),
+
+    /// The named group element is not in the prime-order subgroup.
+    NotInCorrectSubgroup(&'static str),
+
+    /// The named group element is zero.
+    IsZero(&'static str),
+
+    /// The named group element does not match the universal parameters.
+    MismatchedParameters(&'static str),
+
+    /// The named prepared group element does not match its affine group element.
+    MismatchedPrepared(&'static str),
+
+    /// The pairing `e(g, beta_h)` does not equal the pairing `e(beta_g, h)`.
+    InconsistentPairing,
+
+    /// The universal parameters do not contain the powers needed for the validation.
+    MissingParameters(anyhow::Error),
+}
+
+impl snarkvm_utilities::error::Error for VerifierKeyError {}
+
+impl core::fmt::Display for VerifierKeyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotOnCurve(element) => write!(f, "the verifier key element `{element}` is not on the curve"),
+            Self::NotInCorrectSubgroup(element) => {
+                write!(f, "the verifier key element `{element}` is not in the correct subgroup")
+            }
+            Self::IsZero(element) => write!(f, "the verifier key element `{element}` is zero"),
+            Self::MismatchedParameters(element) => {
+                write!(f, "the verifier key element `{element}` does not match the universal parameters")
+            }
+            Self::MismatchedPrepared(element) => {
+                write!(f, "the prepared verifier key element `{element}` does not match `{element}`")
+            }
+            Self::InconsistentPairing => {
+                write!(f, "the verifier key `beta_h` is inconsistent with the universal parameters")
+            }
+            Self::MissingParameters(error) => write!(f, "the universal parameters are incomplete - {error}"),
+        }
+    }
+}
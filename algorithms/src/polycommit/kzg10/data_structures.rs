@@ -14,11 +14,12 @@
 
 use crate::{
     fft::{DensePolynomial, EvaluationDomain},
+    polycommit::VerifierKeyError,
     AlgebraicSponge,
 };
 use snarkvm_curves::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{ConstraintFieldError, ToConstraintField, Zero};
-use snarkvm_parameters::testnet3::PowersOfG;
+use snarkvm_parameters::testnet3::{PowersConfig, PowersOfG, ProgressReporter};
 use snarkvm_utilities::{
     borrow::Cow,
     error,
@@ -29,12 +30,18 @@ use snarkvm_utilities::{
 };
 
 use crate::srs::{UniversalProver, UniversalVerifier};
-use anyhow::Result;
+use anyhow::{anyhow, ensure, Result};
 use core::ops::{Add, AddAssign};
 use parking_lot::RwLock;
 use rand_core::RngCore;
 use std::{collections::BTreeMap, io, ops::Range, sync::Arc};
 
+/// The maximum number of Lagrange bases that are cached by the `UniversalParams`.
+pub const MAX_CACHED_LAGRANGE_BASES: usize = 8;
+
+/// A cache of Lagrange bases at beta G, keyed by the domain size.
+type LagrangeBasesCache<E> = Arc<RwLock<BTreeMap<usize, Arc<Vec<<E as PairingEngine>::G1Affine>>>>>;
+
 /// `UniversalParams` are the universal parameters for the KZG10 scheme.
 #[derive(Clone, Debug)]
 pub struct UniversalParams<E: PairingEngine> {
@@ -49,26 +56,113 @@ pub struct UniversalParams<E: PairingEngine> {
     pub prepared_h: <E::G2Affine as PairingCurve>::Prepared,
     /// \beta times the above generator of G2, prepared for use in pairings.
     pub prepared_beta_h: <E::G2Affine as PairingCurve>::Prepared,
+    /// The Lagrange bases at beta G that have been computed, keyed by the domain size.
+    lagrange_bases: LagrangeBasesCache<E>,
 }
 
 impl<E: PairingEngine> UniversalParams<E> {
+    /// Loads the parameters, downloading missing powers with the global `PowersConfig`.
     pub fn load() -> Result<Self> {
-        let powers = Arc::new(RwLock::new(PowersOfG::<E>::load()?));
+        Self::load_with_config(PowersConfig::global().clone())
+    }
+
+    /// Loads the parameters, downloading missing powers with the given `config`.
+    pub fn load_with_config(config: PowersConfig) -> Result<Self> {
+        Ok(Self::from_powers(PowersOfG::<E>::load_with_config(config)?))
+    }
+
+    /// Initializes the parameters synchronously from the given bytes of the `powers-of-beta-16`
+    /// and `shifted-powers-of-beta-16` power files, and of the `beta-h` file, without downloading any powers.
+    /// This allows a host that cannot block on a download (e.g. a WASM host) to fetch the files itself.
+    ///
+    /// The group elements in the given bytes are checked to be valid.
+    pub fn from_bytes(powers_16: &[u8], shifted_powers: &[u8], beta_h_bytes: &[u8]) -> Result<Self> {
+        Ok(Self::from_powers(PowersOfG::<E>::from_bytes(powers_16, shifted_powers, beta_h_bytes)?))
+    }
+
+    /// Initializes the parameters synchronously from the given bytes of the `powers-of-beta-16`
+    /// and `shifted-powers-of-beta-16` power files, and of the `beta-h` file, without downloading any powers.
+    ///
+    /// The group elements in the given bytes are **not** checked to be valid,
+    /// so the bytes must come from a trusted source.
+    pub fn from_bytes_unchecked(powers_16: &[u8], shifted_powers: &[u8], beta_h_bytes: &[u8]) -> Result<Self> {
+        Ok(Self::from_powers(PowersOfG::<E>::from_bytes_unchecked(powers_16, shifted_powers, beta_h_bytes)?))
+    }
+
+    /// Initializes the parameters from the given powers.
+    fn from_powers(powers: PowersOfG<E>) -> Self {
         let h = E::G2Affine::prime_subgroup_generator();
         let prepared_h = h.prepare();
-        let prepared_beta_h = powers.read().beta_h().prepare();
+        let prepared_beta_h = powers.beta_h().prepare();
+        let powers = Arc::new(RwLock::new(powers));
 
-        Ok(Self { powers, h, prepared_h, prepared_beta_h })
+        Self { powers, h, prepared_h, prepared_beta_h, lagrange_bases: Default::default() }
     }
 
     pub fn download_powers_for(&self, range: Range<usize>) -> Result<()> {
         self.powers.write().download_powers_for(range)
     }
 
-    pub fn lagrange_basis(&self, domain: EvaluationDomain<E::Fr>) -> Result<Vec<E::G1Affine>> {
+    /// Downloads the powers of beta G in `range`, reporting the progress to `progress`.
+    pub fn download_powers_for_with_progress(
+        &self,
+        range: Range<usize>,
+        progress: &dyn ProgressReporter,
+    ) -> Result<()> {
+        self.powers.write().download_powers_for_with_progress(range, progress)
+    }
+
+    /// Returns `true` if the powers of beta G in `range` are available without a download.
+    pub fn contains_powers_for(&self, range: Range<usize>) -> bool {
+        self.powers.read().contains_powers_for(&range)
+    }
+
+    /// Returns the configuration used to download missing powers.
+    pub fn powers_config(&self) -> PowersConfig {
+        self.powers.read().config().clone()
+    }
+
+    /// Returns `true` if the parameters are in offline mode.
+    pub fn is_offline(&self) -> bool {
+        self.powers.read().is_offline()
+    }
+
+    /// Sets the offline mode. In offline mode, requesting powers that are not already available
+    /// (e.g. via `power_of_beta_g`, `powers_of_beta_g`, or `lagrange_basis`) returns an error
+    /// listing the missing power files, instead of downloading them.
+    pub fn set_offline(&self, offline: bool) {
+        self.powers.write().set_offline(offline)
+    }
+
+    /// Returns the Lagrange basis at beta G for the given `domain`.
+    /// The basis is cached by domain size, for up to `MAX_CACHED_LAGRANGE_BASES` domains,
+    /// so that repeated calls share the same memory.
+    pub fn lagrange_basis(&self, domain: EvaluationDomain<E::Fr>) -> Result<Arc<Vec<E::G1Affine>>> {
+        // Return the cached basis, if it exists.
+        if let Some(basis) = self.lagrange_bases.read().get(&domain.size()) {
+            return Ok(basis.clone());
+        }
+
+        // Compute the basis.
         let basis = domain
             .ifft(&self.powers_of_beta_g(0, domain.size())?.iter().map(|e| (*e).to_projective()).collect::<Vec<_>>());
-        Ok(E::G1Projective::batch_normalization_into_affine(basis))
+        let basis = Arc::new(E::G1Projective::batch_normalization_into_affine(basis));
+
+        // Cache the basis, if the cache is not full.
+        // Note: Another thread may have computed the same basis in the meantime, in which case that basis is returned.
+        let mut lagrange_bases = self.lagrange_bases.write();
+        if let Some(basis) = lagrange_bases.get(&domain.size()) {
+            return Ok(basis.clone());
+        }
+        if lagrange_bases.len() < MAX_CACHED_LAGRANGE_BASES {
+            lagrange_bases.insert(domain.size(), basis.clone());
+        }
+        Ok(basis)
+    }
+
+    /// Clears the cached Lagrange bases, releasing their memory once no caller holds them.
+    pub fn clear_lagrange_cache(&self) {
+        self.lagrange_bases.write().clear()
     }
 
     pub fn power_of_beta_g(&self, index: usize) -> Result<E::G1Affine> {
@@ -91,6 +185,22 @@ impl<E: PairingEngine> UniversalParams<E> {
         self.powers.read().max_num_powers() - 1
     }
 
+    /// Returns an owned copy of the powers needed to commit to polynomials of up to the given degree,
+    /// along with the powers of beta times gamma G that are used for hiding.
+    pub fn owned_powers_for_degree(&self, degree: usize) -> Result<OwnedPowers<E>> {
+        ensure!(degree <= self.max_degree(), "Degree {degree} exceeds the maximum degree {}", self.max_degree());
+        let powers_of_beta_g = self.powers_of_beta_g(0, degree + 1)?;
+        // Note: The powers of beta times gamma G are keyed sparsely, so only the contiguous prefix is taken.
+        let powers_of_beta_times_gamma_g = self
+            .powers_of_beta_times_gamma_g()
+            .iter()
+            .enumerate()
+            .take_while(|(index, (key, _))| index == *key)
+            .map(|(_, (_, power))| *power)
+            .collect();
+        Ok(OwnedPowers { powers_of_beta_g, powers_of_beta_times_gamma_g })
+    }
+
     pub fn to_universal_prover(&self) -> Result<UniversalProver<E>> {
         Ok(UniversalProver::<E> { max_degree: self.max_degree(), _unused: None })
     }
@@ -124,7 +234,7 @@ impl<E: PairingEngine> FromBytes for UniversalParams<E> {
         // Deserialize `prepared_beta_h`.
         let prepared_beta_h: <E::G2Affine as PairingCurve>::Prepared = FromBytes::read_le(&mut reader)?;
 
-        Ok(Self { powers, h, prepared_h, prepared_beta_h })
+        Ok(Self { powers, h, prepared_h, prepared_beta_h, lagrange_bases: Default::default() })
     }
 }
 
@@ -161,6 +271,98 @@ impl<E: PairingEngine> Powers<'_, E> {
         self.powers_of_beta_g.len()
     }
 }
+
+/// `OwnedPowers` is an owned copy of `Powers`, which can be persisted and reloaded
+/// by provers that always commit with the same degree.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct OwnedPowers<E: PairingEngine> {
+    /// Group elements of the form `β^i G`, for different values of `i`.
+    pub powers_of_beta_g: Vec<E::G1Affine>,
+    /// Group elements of the form `β^i γG`, for different values of `i`.
+    pub powers_of_beta_times_gamma_g: Vec<E::G1Affine>,
+}
+
+impl<E: PairingEngine> OwnedPowers<E> {
+    /// The number of powers in `self`.
+    pub fn size(&self) -> usize {
+        self.powers_of_beta_g.len()
+    }
+
+    /// Returns a `Powers` view over `self`, without copying the group elements.
+    pub fn as_powers(&self) -> Powers<'_, E> {
+        Powers {
+            powers_of_beta_g: Cow::Borrowed(&self.powers_of_beta_g),
+            powers_of_beta_times_gamma_g: Cow::Borrowed(&self.powers_of_beta_times_gamma_g),
+        }
+    }
+}
+
+impl<E: PairingEngine> From<Powers<'_, E>> for OwnedPowers<E> {
+    fn from(powers: Powers<'_, E>) -> Self {
+        Self {
+            powers_of_beta_g: powers.powers_of_beta_g.into_owned(),
+            powers_of_beta_times_gamma_g: powers.powers_of_beta_times_gamma_g.into_owned(),
+        }
+    }
+}
+
+impl<E: PairingEngine> CanonicalSerialize for OwnedPowers<E> {
+    fn serialize_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> Result<(), SerializationError> {
+        self.powers_of_beta_g.serialize_with_mode(&mut writer, compress)?;
+        self.powers_of_beta_times_gamma_g.serialize_with_mode(&mut writer, compress)?;
+        Ok(())
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.powers_of_beta_g.serialized_size(compress) + self.powers_of_beta_times_gamma_g.serialized_size(compress)
+    }
+}
+
+impl<E: PairingEngine> CanonicalDeserialize for OwnedPowers<E> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        // Note: The group elements are validated together below, instead of per vector.
+        let powers_of_beta_g = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, Validate::No)?;
+        let powers_of_beta_times_gamma_g =
+            CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, Validate::No)?;
+        let powers = Self { powers_of_beta_g, powers_of_beta_times_gamma_g };
+        if let Validate::Yes = validate {
+            powers.check()?;
+        }
+        Ok(powers)
+    }
+}
+
+impl<E: PairingEngine> Valid for OwnedPowers<E> {
+    fn check(&self) -> Result<(), SerializationError> {
+        // Ensure there is at least the generator, so that a commitment can be computed.
+        if self.powers_of_beta_g.is_empty() {
+            return Err(SerializationError::InvalidData);
+        }
+        // Ensure every group element is on the curve and in the prime-order subgroup.
+        Valid::batch_check(self.powers_of_beta_g.iter())?;
+        Valid::batch_check(self.powers_of_beta_times_gamma_g.iter())?;
+        Ok(())
+    }
+}
+
+impl<E: PairingEngine> FromBytes for OwnedPowers<E> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize_compressed(&mut reader)
+            .map_err(|_| error("could not deserialize OwnedPowers"))
+    }
+}
+
+impl<E: PairingEngine> ToBytes for OwnedPowers<E> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_compressed(self, &mut writer)
+            .map_err(|_| error("could not serialize OwnedPowers"))
+    }
+}
+
 /// `LagrangeBasis` is used to commit to and create evaluation proofs for a given polynomial.
 #[derive(Clone, Debug, Hash)]
 pub struct LagrangeBasis<'a, E: PairingEngine> {
@@ -197,6 +399,60 @@ pub struct VerifierKey<E: PairingEngine> {
     pub prepared_beta_h: <E::G2Affine as PairingCurve>::Prepared,
 }
 
+impl<E: PairingEngine> VerifierKey<E> {
+    /// Ensures the group elements of the verifier key are well-formed,
+    /// and consistent with the universal parameters `params`.
+    pub fn validate(&self, params: &UniversalParams<E>) -> Result<(), VerifierKeyError> {
+        /// Ensures the group element is on the curve, in the correct subgroup, and non-zero.
+        fn check_element<G: AffineCurve>(element: &G, name: &'static str) -> Result<(), VerifierKeyError> {
+            if !element.is_on_curve() {
+                Err(VerifierKeyError::NotOnCurve(name))
+            } else if !element.is_in_correct_subgroup_assuming_on_curve() {
+                Err(VerifierKeyError::NotInCorrectSubgroup(name))
+            } else if element.is_zero() {
+                Err(VerifierKeyError::IsZero(name))
+            } else {
+                Ok(())
+            }
+        }
+
+        check_element(&self.g, "g")?;
+        check_element(&self.gamma_g, "gamma_g")?;
+        check_element(&self.h, "h")?;
+        check_element(&self.beta_h, "beta_h")?;
+
+        // Ensure the prepared group elements correspond to the affine group elements.
+        if self.prepared_h != self.h.prepare() {
+            return Err(VerifierKeyError::MismatchedPrepared("h"));
+        }
+        if self.prepared_beta_h != self.beta_h.prepare() {
+            return Err(VerifierKeyError::MismatchedPrepared("beta_h"));
+        }
+
+        // Ensure the generators match the universal parameters.
+        if self.g != params.power_of_beta_g(0).map_err(VerifierKeyError::MissingParameters)? {
+            return Err(VerifierKeyError::MismatchedParameters("g"));
+        }
+        match params.powers_of_beta_times_gamma_g().get(&0) {
+            Some(gamma_g) if *gamma_g == self.gamma_g => (),
+            Some(_) => return Err(VerifierKeyError::MismatchedParameters("gamma_g")),
+            None => {
+                return Err(VerifierKeyError::MissingParameters(anyhow!("missing the power of beta times gamma G")))
+            }
+        }
+        if self.h != params.h {
+            return Err(VerifierKeyError::MismatchedParameters("h"));
+        }
+
+        // Ensure `beta_h` is consistent with the universal parameters, by checking `e(g, beta_h) == e(beta_g, h)`.
+        let beta_g = params.power_of_beta_g(1).map_err(VerifierKeyError::MissingParameters)?;
+        if E::pairing(self.g, self.beta_h) != E::pairing(beta_g, self.h) {
+            return Err(VerifierKeyError::InconsistentPairing);
+        }
+        Ok(())
+    }
+}
+
 impl<E: PairingEngine> CanonicalSerialize for VerifierKey<E> {
     fn serialize_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> Result<(), SerializationError> {
         self.g.serialize_with_mode(&mut writer, compress)?;
@@ -428,3 +684,49 @@ impl<E: PairingEngine> KZGProof<E> {
         self.random_v.is_some()
     }
 }
+
+/// `KZGBatchProof` is an evaluation proof for several points that is output by `KZG10::batch_open`.
+///
+/// Following [\[BDFG20\]][bdfg], the proof consists of a commitment to the quotient of the polynomial
+/// by the vanishing polynomial over the points, and a commitment to the opening witness of the
+/// linearized polynomial at a Fiat-Shamir challenge, so that it verifies with two pairings
+/// against the `VerifierKey`.
+///
+/// [bdfg]: https://eprint.iacr.org/2020/081
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct KZGBatchProof<E: PairingEngine> {
+    /// This is a commitment to the quotient of the polynomial by the vanishing polynomial over the points.
+    pub w: E::G1Affine,
+    /// This is a commitment to the witness polynomial of the linearized polynomial at the challenge point.
+    pub w_challenge: E::G1Affine,
+    /// This is the evaluation at the challenge point of the linearized random polynomial.
+    pub random_v: Option<E::Fr>,
+}
+
+impl<E: PairingEngine> KZGBatchProof<E> {
+    pub fn absorb_into_sponge(&self, sponge: &mut impl AlgebraicSponge<E::Fq, 2>) {
+        sponge.absorb_native_field_elements(&self.w.to_field_elements().unwrap());
+        sponge.absorb_native_field_elements(&self.w_challenge.to_field_elements().unwrap());
+        if let Some(random_v) = self.random_v {
+            sponge.absorb_nonnative_field_elements([random_v]);
+        }
+    }
+
+    pub fn is_hiding(&self) -> bool {
+        self.random_v.is_some()
+    }
+}
+
+impl<E: PairingEngine> FromBytes for KZGBatchProof<E> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize_compressed(&mut reader)
+            .map_err(|_| error("could not deserialize KZG batch proof"))
+    }
+}
+
+impl<E: PairingEngine> ToBytes for KZGBatchProof<E> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_compressed(self, &mut writer)
+            .map_err(|_| error("could not serialize KZG batch proof"))
+    }
+}
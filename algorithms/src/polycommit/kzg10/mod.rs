@@ -20,6 +20,7 @@
 //! This construction achieves extractability in the algebraic group model (AGM).
 
 use crate::{
+    crypto_hash::sha256::sha256,
     fft::{DensePolynomial, Polynomial},
     msm::VariableBase,
     polycommit::PCError,
@@ -27,7 +28,7 @@ use crate::{
 use anyhow::anyhow;
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{One, PrimeField, Zero};
-use snarkvm_utilities::{cfg_iter, cfg_iter_mut, rand::Uniform, BitIteratorBE};
+use snarkvm_utilities::{cfg_iter, cfg_iter_mut, rand::Uniform, BitIteratorBE, ToBytes};
 
 use core::{marker::PhantomData, ops::Mul};
 use itertools::Itertools;
@@ -399,6 +400,149 @@ impl<E: PairingEngine> KZG10<E> {
         Ok(result)
     }
 
+    /// On input a polynomial `p` and a set of distinct `points`, outputs a single proof
+    /// for the evaluations of `p` at all of the `points`.
+    ///
+    /// The proof commits to the quotient `q(X)` of `p(X)` by the vanishing polynomial `Z(X)`
+    /// over the `points`. To keep the verifier at two pairings without requiring powers of beta
+    /// in G2, it also opens `p(X) - Z(x) q(X)` at a Fiat-Shamir challenge `x` (see [\[BDFG20\]][bdfg]).
+    ///
+    /// [bdfg]: https://eprint.iacr.org/2020/081
+    pub fn batch_open(
+        powers: &Powers<E>,
+        polynomial: &DensePolynomial<E::Fr>,
+        points: &[E::Fr],
+        rand: &KZGRandomness<E>,
+    ) -> Result<KZGBatchProof<E>, PCError> {
+        Self::check_degree_is_too_large(polynomial.degree(), powers.size())?;
+        Self::check_batch_points(points)?;
+        let open_time =
+            start_timer!(|| format!("Opening polynomial of degree {} at {} points", polynomial.degree(), points.len()));
+
+        let blinding_polynomial = rand.is_hiding().then_some(&rand.blinding_polynomial);
+
+        let witness_time = start_timer!(|| "Computing quotient by the vanishing polynomial");
+        let vanishing_polynomial =
+            points.iter().fold(DensePolynomial::from_coefficients_vec(vec![E::Fr::one()]), |z, point| {
+                &z * &DensePolynomial::from_coefficients_vec(vec![-*point, E::Fr::one()])
+            });
+        let quotient = polynomial / &vanishing_polynomial;
+        let hiding_quotient = blinding_polynomial.map(|p| p / &vanishing_polynomial);
+        end_timer!(witness_time);
+        let w = Self::commit_with_hiding_part(powers, &quotient, hiding_quotient.as_ref())?.to_affine();
+
+        // Derive the challenge point from the commitment, the points, the evaluations, and `w`.
+        let commitment = Self::commit_with_hiding_part(powers, polynomial, blinding_polynomial)?.to_affine();
+        let values = cfg_iter!(points).map(|point| polynomial.evaluate(*point)).collect::<Vec<_>>();
+        let challenge = Self::batch_open_challenge(&KZGCommitment(commitment), points, &values, &w)?;
+
+        // The linearized polynomial `p(X) - Z(x) q(X)` evaluates to the interpolation of the
+        // evaluations at `x`, which is the remainder that the division by `X - x` ignores.
+        let witness_time = start_timer!(|| "Computing witness polynomials at the challenge point");
+        let divisor = DensePolynomial::from_coefficients_vec(vec![-challenge, E::Fr::one()]);
+        let vanishing_at_challenge = vanishing_polynomial.evaluate(challenge);
+        let challenge_witness = &(polynomial - &(&quotient * vanishing_at_challenge)) / &divisor;
+        let (hiding_challenge_witness, random_v) = match (blinding_polynomial, &hiding_quotient) {
+            (Some(blinding_polynomial), Some(hiding_quotient)) => {
+                let linearized = blinding_polynomial - &(hiding_quotient * vanishing_at_challenge);
+                (Some(&linearized / &divisor), Some(linearized.evaluate(challenge)))
+            }
+            _ => (None, None),
+        };
+        end_timer!(witness_time);
+        let w_challenge =
+            Self::commit_with_hiding_part(powers, &challenge_witness, hiding_challenge_witness.as_ref())?.to_affine();
+
+        end_timer!(open_time);
+        Ok(KZGBatchProof { w, w_challenge, random_v })
+    }
+
+    /// Verifies that each value in `values` is the evaluation at the corresponding point in `points`
+    /// of the polynomial committed inside `commitment`, using two pairings.
+    pub fn check_batch_open(
+        vk: &VerifierKey<E>,
+        commitment: &KZGCommitment<E>,
+        points: &[E::Fr],
+        values: &[E::Fr],
+        proof: &KZGBatchProof<E>,
+    ) -> Result<bool, PCError> {
+        if points.len() != values.len() {
+            Err(anyhow!("Expected {} values for the batch opening, found {}", points.len(), values.len()))?;
+        }
+        Self::check_batch_points(points)?;
+        let check_time = start_timer!(|| format!("Checking evaluations at {} points", points.len()));
+
+        let challenge = Self::batch_open_challenge(commitment, points, values, &proof.w)?;
+        let vanishing_at_challenge = points.iter().map(|point| challenge - point).product::<E::Fr>();
+        let interpolation_at_challenge = interpolate_at(points, values, challenge);
+
+        // Check that `C - I(x) G - r gamma_G - Z(x) w + x w_challenge = beta w_challenge`.
+        let mut inner = commitment.0.to_projective() - vk.g.mul(interpolation_at_challenge);
+        if let Some(random_v) = proof.random_v {
+            inner -= &vk.gamma_g.mul(random_v);
+        }
+        inner -= &proof.w.mul(vanishing_at_challenge);
+        inner += &proof.w_challenge.mul(challenge);
+
+        let affine_points =
+            E::G1Projective::batch_normalization_into_affine(vec![-proof.w_challenge.to_projective(), inner]);
+        let (w_challenge, inner) = (affine_points[0], affine_points[1]);
+
+        let pairing_time = start_timer!(|| "Performing product of pairings");
+        let result = E::product_of_pairings(
+            [(&w_challenge.prepare(), &vk.prepared_beta_h), (&inner.prepare(), &vk.prepared_h)].iter().copied(),
+        )
+        .is_one();
+        end_timer!(pairing_time);
+        end_timer!(check_time, || format!("Result: {result}"));
+        Ok(result)
+    }
+
+    /// Returns the commitment to `polynomial`, with `hiding_polynomial` committed under `gamma_g`.
+    fn commit_with_hiding_part(
+        powers: &Powers<E>,
+        polynomial: &DensePolynomial<E::Fr>,
+        hiding_polynomial: Option<&DensePolynomial<E::Fr>>,
+    ) -> Result<E::G1Projective, PCError> {
+        Self::check_degree_is_too_large(polynomial.degree(), powers.size())?;
+        let (num_leading_zeros, coeffs) = skip_leading_zeros_and_convert_to_bigints(polynomial);
+        let bases = &powers.powers_of_beta_g[num_leading_zeros..(num_leading_zeros + coeffs.len())];
+        let mut commitment = VariableBase::msm(bases, &coeffs);
+
+        if let Some(hiding_polynomial) = hiding_polynomial {
+            Self::check_degree_is_too_large(hiding_polynomial.degree(), powers.powers_of_beta_times_gamma_g.len())?;
+            let random_coeffs = convert_to_bigints(&hiding_polynomial.coeffs);
+            commitment += &VariableBase::msm(&powers.powers_of_beta_times_gamma_g, &random_coeffs);
+        }
+        Ok(commitment)
+    }
+
+    /// Returns the Fiat-Shamir challenge point for a batch opening.
+    fn batch_open_challenge(
+        commitment: &KZGCommitment<E>,
+        points: &[E::Fr],
+        values: &[E::Fr],
+        w: &E::G1Affine,
+    ) -> Result<E::Fr, PCError> {
+        let hash_input = [commitment.to_bytes_le(), points.to_bytes_le(), values.to_bytes_le(), w.to_bytes_le()]
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(anyhow::Error::from)?
+            .concat();
+        Ok(E::Fr::from_bytes_le_mod_order(&sha256(&hash_input)))
+    }
+
+    /// Ensures the points of a batch opening are non-empty and distinct.
+    fn check_batch_points(points: &[E::Fr]) -> Result<(), PCError> {
+        if points.is_empty() {
+            Err(anyhow!("A batch opening requires at least one point"))?;
+        }
+        if !points.iter().all_unique() {
+            Err(anyhow!("The points of a batch opening must be distinct"))?;
+        }
+        Ok(())
+    }
+
     pub(crate) fn check_degree_is_too_large(degree: usize, num_powers: usize) -> Result<(), PCError> {
         let num_coefficients = degree + 1;
         if num_coefficients > num_powers {
@@ -460,6 +604,26 @@ fn skip_leading_zeros_and_convert_to_bigints<F: PrimeField>(p: &DensePolynomial<
     }
 }
 
+/// Evaluates at `point` the polynomial of minimal degree that interpolates `values` over the distinct `points`.
+fn interpolate_at<F: PrimeField>(points: &[F], values: &[F], point: F) -> F {
+    // Computes the denominators `\prod_{j != i} (z_i - z_j)` of the Lagrange basis polynomials.
+    let mut denominators = points
+        .iter()
+        .enumerate()
+        .map(|(i, z_i)| points.iter().enumerate().filter(|(j, _)| i != *j).map(|(_, z_j)| *z_i - z_j).product())
+        .collect::<Vec<F>>();
+    snarkvm_fields::batch_inversion(&mut denominators);
+    values
+        .iter()
+        .zip_eq(denominators)
+        .enumerate()
+        .map(|(i, (value, denominator))| {
+            let numerator: F = points.iter().enumerate().filter(|(j, _)| i != *j).map(|(_, z_j)| point - z_j).product();
+            *value * numerator * denominator
+        })
+        .sum()
+}
+
 fn convert_to_bigints<F: PrimeField>(p: &[F]) -> Vec<F::BigInteger> {
     let to_bigint_time = start_timer!(|| "Converting polynomial coeffs to bigints");
     let coeffs = cfg_iter!(p).map(|s| s.to_bigint()).collect::<Vec<_>>();
@@ -472,8 +636,12 @@ mod tests {
     #![allow(non_camel_case_types)]
     #![allow(clippy::needless_borrow)]
     use super::*;
+    use crate::polycommit::VerifierKeyError;
     use snarkvm_curves::bls12_377::{Bls12_377, Fr};
-    use snarkvm_utilities::{rand::TestRng, FromBytes, ToBytes};
+    use snarkvm_fields::Field;
+    use snarkvm_utilities::{rand::TestRng, CanonicalDeserialize, CanonicalSerialize, FromBytes, ToBytes};
+
+    use rand::Rng;
 
     use std::borrow::Cow;
 
@@ -526,6 +694,225 @@ mod tests {
         assert_eq!(&pp_bytes, &pp_recovered_bytes);
     }
 
+    #[test]
+    fn test_kzg10_owned_powers_serialization() {
+        let rng = &mut TestRng::default();
+        let degree = 16;
+        let hiding_bound = Some(1);
+        let pp = KZG_Bls12_377::load_srs(degree).unwrap();
+        let (_, vk) = KZG_Bls12_377::trim(&pp, degree, hiding_bound);
+
+        // Ensure the owned powers round-trip.
+        let powers = pp.owned_powers_for_degree(degree).unwrap();
+        assert_eq!(powers.size(), degree + 1);
+        let powers_bytes = powers.to_bytes_le().unwrap();
+        let powers_recovered = OwnedPowers::<Bls12_377>::read_le(&powers_bytes[..]).unwrap();
+        assert_eq!(powers, powers_recovered);
+
+        // Ensure the reloaded powers can be used to prove and verify.
+        let p = DensePolynomial::rand(degree, rng);
+        let (comm, rand) =
+            KZG_Bls12_377::commit(&powers_recovered.as_powers(), &(&p).into(), hiding_bound, Some(rng)).unwrap();
+        let point = Fr::rand(rng);
+        let value = p.evaluate(point);
+        let proof = KZG_Bls12_377::open(&powers_recovered.as_powers(), &p, point, &rand).unwrap();
+        assert!(KZG_Bls12_377::check(&vk, &comm, point, value, &proof).unwrap());
+
+        // Ensure an empty set of powers is rejected.
+        let empty = OwnedPowers::<Bls12_377> { powers_of_beta_g: vec![], powers_of_beta_times_gamma_g: vec![] };
+        let empty_bytes = empty.to_bytes_le().unwrap();
+        assert!(OwnedPowers::<Bls12_377>::read_le(&empty_bytes[..]).is_err());
+
+        // Ensure a point outside of the prime-order subgroup is rejected, unless validation is skipped.
+        let mut x = snarkvm_curves::bls12_377::Fq::one();
+        let point = loop {
+            match snarkvm_curves::bls12_377::G1Affine::from_x_coordinate(x, true) {
+                Some(point) if !point.is_in_correct_subgroup_assuming_on_curve() => break point,
+                _ => x += snarkvm_curves::bls12_377::Fq::one(),
+            }
+        };
+        let mut invalid = powers_recovered;
+        invalid.powers_of_beta_g[1] = point;
+        let invalid_bytes = invalid.to_bytes_le().unwrap();
+        assert!(OwnedPowers::<Bls12_377>::read_le(&invalid_bytes[..]).is_err());
+        assert!(OwnedPowers::<Bls12_377>::deserialize_compressed_unchecked(&invalid_bytes[..]).is_ok());
+    }
+
+    #[test]
+    fn test_kzg10_universal_params_from_bytes() {
+        let rng = &mut TestRng::default();
+        let num_powers = 1 << 15;
+
+        // Extract the bytes of the `powers-of-beta-16`, `shifted-powers-of-beta-16`, and `beta-h` files from a loaded instance.
+        let pp = UniversalParams::<Bls12_377>::load().unwrap();
+        let max_num_powers = pp.max_degree() + 1;
+        let powers_16 = pp.powers_of_beta_g(num_powers, 2 * num_powers).unwrap();
+        let shifted_powers = pp.powers_of_beta_g(max_num_powers - 2 * num_powers, max_num_powers - num_powers).unwrap();
+        let mut powers_16_bytes = vec![];
+        powers_16.serialize_uncompressed(&mut powers_16_bytes).unwrap();
+        let mut shifted_powers_bytes = vec![];
+        shifted_powers.serialize_uncompressed(&mut shifted_powers_bytes).unwrap();
+        let mut beta_h_bytes = vec![];
+        pp.beta_h().serialize_uncompressed(&mut beta_h_bytes).unwrap();
+
+        // Ensure the parameters are constructed from the bytes, without any downloads.
+        let candidate =
+            UniversalParams::<Bls12_377>::from_bytes(&powers_16_bytes, &shifted_powers_bytes, &beta_h_bytes).unwrap();
+        candidate.set_offline(true);
+        assert!(candidate.contains_powers_for(0..(2 * num_powers)));
+        assert!(candidate.contains_powers_for((max_num_powers - 2 * num_powers)..max_num_powers));
+        assert_eq!(candidate.beta_h(), pp.beta_h());
+        assert_eq!(
+            candidate.powers_of_beta_g(0, 2 * num_powers).unwrap(),
+            pp.powers_of_beta_g(0, 2 * num_powers).unwrap()
+        );
+        assert_eq!(
+            candidate.powers_of_beta_g(max_num_powers - 2 * num_powers, max_num_powers).unwrap(),
+            pp.powers_of_beta_g(max_num_powers - 2 * num_powers, max_num_powers).unwrap()
+        );
+
+        // Ensure the unchecked variant constructs the same parameters.
+        let unchecked =
+            UniversalParams::<Bls12_377>::from_bytes_unchecked(&powers_16_bytes, &shifted_powers_bytes, &beta_h_bytes)
+                .unwrap();
+        assert_eq!(unchecked.to_bytes_le().unwrap(), candidate.to_bytes_le().unwrap());
+
+        // Ensure the parameters can be used to prove and verify, with a degree beyond the embedded powers.
+        let degree = num_powers + 1;
+        let hiding_bound = Some(1);
+        let (ck, vk) = KZG_Bls12_377::trim(&candidate, degree, hiding_bound);
+        let p = DensePolynomial::rand(degree, rng);
+        let (comm, rand) = KZG_Bls12_377::commit(&ck, &(&p).into(), hiding_bound, Some(rng)).unwrap();
+        let point = Fr::rand(rng);
+        let value = p.evaluate(point);
+        let proof = KZG_Bls12_377::open(&ck, &p, point, &rand).unwrap();
+        assert!(KZG_Bls12_377::check(&vk, &comm, point, value, &proof).unwrap());
+
+        // Ensure malformed bytes are rejected.
+        assert!(
+            UniversalParams::<Bls12_377>::from_bytes(&powers_16_bytes, &powers_16_bytes[1..], &beta_h_bytes).is_err()
+        );
+        assert!(UniversalParams::<Bls12_377>::from_bytes(&powers_16_bytes, &shifted_powers_bytes, &beta_h_bytes[1..])
+            .is_err());
+
+        // Ensure a point outside of the prime-order subgroup is rejected, unless validation is skipped.
+        let mut x = snarkvm_curves::bls12_377::Fq::one();
+        let point = loop {
+            match snarkvm_curves::bls12_377::G1Affine::from_x_coordinate(x, true) {
+                Some(point) if !point.is_in_correct_subgroup_assuming_on_curve() => break point,
+                _ => x += snarkvm_curves::bls12_377::Fq::one(),
+            }
+        };
+        let mut invalid_powers = powers_16;
+        invalid_powers[1] = point;
+        let mut invalid_bytes = vec![];
+        invalid_powers.serialize_uncompressed(&mut invalid_bytes).unwrap();
+        assert!(UniversalParams::<Bls12_377>::from_bytes(&invalid_bytes, &shifted_powers_bytes, &beta_h_bytes).is_err());
+        assert!(UniversalParams::<Bls12_377>::from_bytes_unchecked(
+            &invalid_bytes,
+            &shifted_powers_bytes,
+            &beta_h_bytes
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_kzg10_universal_params_offline() {
+        // Note: The embedded powers only contain the first and last 2^15 powers,
+        // so any larger range of powers would require a download.
+        let pp = UniversalParams::<Bls12_377>::load().unwrap();
+        let num_powers = 1 << 15;
+        let max_num_powers = pp.max_degree() + 1;
+
+        // Ensure the available powers are reported correctly.
+        assert!(pp.contains_powers_for(0..num_powers));
+        assert!(pp.contains_powers_for((max_num_powers - num_powers)..max_num_powers));
+        assert!(!pp.contains_powers_for(0..(num_powers + 1)));
+        assert!(!pp.contains_powers_for((max_num_powers - 2 * num_powers)..max_num_powers));
+
+        // Enable the offline mode.
+        assert!(!pp.is_offline());
+        pp.set_offline(true);
+        assert!(pp.is_offline());
+
+        // Ensure the available powers are still returned.
+        assert!(pp.power_of_beta_g(num_powers - 1).is_ok());
+        assert_eq!(pp.powers_of_beta_g(0, num_powers).unwrap().len(), num_powers);
+
+        // Ensure the missing powers return an error listing the missing power files.
+        let error = pp.power_of_beta_g(num_powers).unwrap_err().to_string();
+        assert!(error.contains("missing power files: powers-of-beta-16"), "{error}");
+        let error = pp.powers_of_beta_g(0, 4 * num_powers).unwrap_err().to_string();
+        assert!(error.contains("missing power files: powers-of-beta-16, powers-of-beta-17"), "{error}");
+        let error = pp.powers_of_beta_g(max_num_powers - 2 * num_powers, max_num_powers).unwrap_err().to_string();
+        assert!(error.contains("missing power files: shifted-powers-of-beta-16"), "{error}");
+        let domain = crate::fft::EvaluationDomain::<Fr>::new(2 * num_powers).unwrap();
+        assert!(pp.lagrange_basis(domain).is_err());
+        assert!(pp.download_powers_for(0..(2 * num_powers)).is_err());
+
+        // Ensure no powers were downloaded.
+        assert!(!pp.contains_powers_for(0..(num_powers + 1)));
+    }
+
+    #[test]
+    fn test_kzg10_universal_params_lagrange_basis_cache() {
+        let pp = UniversalParams::<Bls12_377>::load().unwrap();
+        let domain = crate::fft::EvaluationDomain::<Fr>::new(1 << 5).unwrap();
+
+        // Ensure repeated calls with the same domain return the same basis.
+        let basis = pp.lagrange_basis(domain).unwrap();
+        assert!(std::sync::Arc::ptr_eq(&basis, &pp.lagrange_basis(domain).unwrap()));
+        assert!(std::sync::Arc::ptr_eq(&basis, &pp.clone().lagrange_basis(domain).unwrap()));
+
+        // Ensure clearing the cache recomputes the same basis.
+        pp.clear_lagrange_cache();
+        let candidate = pp.lagrange_basis(domain).unwrap();
+        assert!(!std::sync::Arc::ptr_eq(&basis, &candidate));
+        assert_eq!(basis, candidate);
+
+        // Ensure the number of cached bases is bounded.
+        pp.clear_lagrange_cache();
+        for log_size in 1..=MAX_CACHED_LAGRANGE_BASES {
+            let domain = crate::fft::EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            let basis = pp.lagrange_basis(domain).unwrap();
+            assert!(std::sync::Arc::ptr_eq(&basis, &pp.lagrange_basis(domain).unwrap()));
+        }
+        let domain = crate::fft::EvaluationDomain::<Fr>::new(1 << (MAX_CACHED_LAGRANGE_BASES + 1)).unwrap();
+        let basis = pp.lagrange_basis(domain).unwrap();
+        assert!(!std::sync::Arc::ptr_eq(&basis, &pp.lagrange_basis(domain).unwrap()));
+    }
+
+    #[test]
+    fn test_kzg10_universal_params_download_progress() {
+        /// A progress reporter that records every call.
+        #[derive(Default)]
+        struct CountingReporter(std::sync::Mutex<Vec<(u64, u64, String)>>);
+
+        impl snarkvm_parameters::testnet3::ProgressReporter for CountingReporter {
+            fn on_progress(&self, downloaded_bytes: u64, total_bytes: u64, label: &str) {
+                self.0.lock().unwrap().push((downloaded_bytes, total_bytes, label.to_string()));
+            }
+        }
+
+        // Note: The powers of beta up to 2^16 are embedded, so this does not require a download.
+        let pp = UniversalParams::<Bls12_377>::load().unwrap();
+        let reporter = CountingReporter::default();
+        pp.download_powers_for_with_progress(0..(1 << 16), &reporter).unwrap();
+
+        // Ensure the reporter was called once for the loaded power file.
+        let calls = reporter.0.into_inner().unwrap();
+        assert_eq!(calls.len(), 1);
+        let (downloaded, total, label) = &calls[0];
+        assert!(*downloaded > 0);
+        assert_eq!(downloaded, total);
+        assert_eq!(label, "powers-of-beta-16");
+
+        // Ensure the powers that are already available are not reported again.
+        let reporter = CountingReporter::default();
+        pp.download_powers_for_with_progress(0..(1 << 16), &reporter).unwrap();
+        assert!(reporter.0.into_inner().unwrap().is_empty());
+    }
+
     fn end_to_end_test_template<E: PairingEngine>() -> Result<(), PCError> {
         let rng = &mut TestRng::default();
         for _ in 0..100 {
@@ -609,6 +996,91 @@ mod tests {
         Ok(())
     }
 
+    fn batch_open_test_template<E: PairingEngine>() -> Result<(), PCError> {
+        let rng = &mut TestRng::default();
+        for _ in 0..10 {
+            let mut degree = 0;
+            while degree <= 1 {
+                degree = usize::rand(rng) % 20;
+            }
+            let pp = KZG10::<E>::load_srs(degree)?;
+            for hiding_bound in [None, Some(1)] {
+                let (ck, vk) = KZG10::trim(&pp, degree, hiding_bound);
+                let p = DensePolynomial::rand(degree, rng);
+                let (comm, rand) = KZG10::<E>::commit(&ck, &(&p).into(), hiding_bound, Some(rng))?;
+
+                let num_points = 1 + usize::rand(rng) % 5;
+                let points = (0..num_points).map(|_| E::Fr::rand(rng)).collect::<Vec<_>>();
+                let values = points.iter().map(|point| p.evaluate(*point)).collect::<Vec<_>>();
+                let proof = KZG10::<E>::batch_open(&ck, &p, &points, &rand)?;
+                assert_eq!(proof.is_hiding(), hiding_bound.is_some());
+                assert!(KZG10::<E>::check_batch_open(&vk, &comm, &points, &values, &proof)?);
+
+                // Ensure the batch proof agrees with the individually opened proofs.
+                for (point, value) in points.iter().zip_eq(&values) {
+                    let individual_proof = KZG10::<E>::open(&ck, &p, *point, &rand)?;
+                    assert!(KZG10::<E>::check(&vk, &comm, *point, *value, &individual_proof)?);
+                }
+                if num_points == 1 {
+                    let individual_proof = KZG10::<E>::open(&ck, &p, points[0], &rand)?;
+                    assert_eq!(proof.w, individual_proof.w);
+                }
+
+                // Ensure the batch proof round-trips through its byte representation.
+                let proof_bytes = proof.to_bytes_le().unwrap();
+                assert_eq!(proof, KZGBatchProof::<E>::read_le(&proof_bytes[..]).unwrap());
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_open() {
+        batch_open_test_template::<Bls12_377>().expect("test failed for bls12-377");
+    }
+
+    #[test]
+    fn test_batch_open_with_duplicate_points() {
+        let rng = &mut TestRng::default();
+
+        let degree = 10;
+        let pp = KZG_Bls12_377::load_srs(degree).unwrap();
+        let (ck, vk) = KZG_Bls12_377::trim(&pp, degree, None);
+        let p = DensePolynomial::<Fr>::rand(degree, rng);
+        let (comm, rand) = KZG_Bls12_377::commit(&ck, &(&p).into(), None, None).unwrap();
+
+        let point = Fr::rand(rng);
+        let points = vec![point, Fr::rand(rng), point];
+        let values = points.iter().map(|point| p.evaluate(*point)).collect::<Vec<_>>();
+        assert!(KZG_Bls12_377::batch_open(&ck, &p, &points, &rand).is_err());
+
+        // Ensure the verifier rejects the duplicate points as well.
+        let proof = KZG_Bls12_377::batch_open(&ck, &p, &points[..2], &rand).unwrap();
+        assert!(KZG_Bls12_377::check_batch_open(&vk, &comm, &points, &values, &proof).is_err());
+        assert!(KZG_Bls12_377::batch_open(&ck, &p, &[], &rand).is_err());
+    }
+
+    #[test]
+    fn test_batch_open_with_incorrect_evaluation() {
+        let rng = &mut TestRng::default();
+
+        let degree = 10;
+        let hiding_bound = Some(1);
+        let pp = KZG_Bls12_377::load_srs(degree).unwrap();
+        let (ck, vk) = KZG_Bls12_377::trim(&pp, degree, hiding_bound);
+        let p = DensePolynomial::<Fr>::rand(degree, rng);
+        let (comm, rand) = KZG_Bls12_377::commit(&ck, &(&p).into(), hiding_bound, Some(rng)).unwrap();
+
+        let points = (0..4).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let mut values = points.iter().map(|point| p.evaluate(*point)).collect::<Vec<_>>();
+        let proof = KZG_Bls12_377::batch_open(&ck, &p, &points, &rand).unwrap();
+        assert!(KZG_Bls12_377::check_batch_open(&vk, &comm, &points, &values, &proof).unwrap());
+
+        // Ensure a single incorrect evaluation is rejected.
+        values[2] += Fr::one();
+        assert!(!KZG_Bls12_377::check_batch_open(&vk, &comm, &points, &values, &proof).unwrap());
+    }
+
     #[test]
     fn test_end_to_end() {
         end_to_end_test_template::<Bls12_377>().expect("test failed for bls12-377");
@@ -624,6 +1096,42 @@ mod tests {
         batch_check_test_template::<Bls12_377>().expect("test failed for bls12-377");
     }
 
+    #[test]
+    fn test_verifier_key_validate() {
+        let rng = &mut TestRng::default();
+
+        let degree = 10;
+        let pp = KZG_Bls12_377::load_srs(degree).unwrap();
+        let (_, vk) = KZG_Bls12_377::trim(&pp, degree, Some(1));
+        assert!(vk.validate(&pp).is_ok());
+
+        // Ensure a verifier key with a random `beta_h` is rejected.
+        let mut candidate = vk.clone();
+        candidate.beta_h = rng.gen();
+        candidate.prepared_beta_h = candidate.beta_h.prepare();
+        assert!(matches!(candidate.validate(&pp), Err(VerifierKeyError::InconsistentPairing)));
+
+        // Ensure a verifier key with a stale prepared `beta_h` is rejected.
+        let mut candidate = vk.clone();
+        candidate.beta_h = rng.gen();
+        assert!(matches!(candidate.validate(&pp), Err(VerifierKeyError::MismatchedPrepared("beta_h"))));
+
+        // Ensure a verifier key with a random `gamma_g` is rejected.
+        let mut candidate = vk.clone();
+        candidate.gamma_g = rng.gen();
+        assert!(matches!(candidate.validate(&pp), Err(VerifierKeyError::MismatchedParameters("gamma_g"))));
+
+        // Ensure a verifier key with a zero `g` is rejected.
+        let mut candidate = vk.clone();
+        candidate.g = Zero::zero();
+        assert!(matches!(candidate.validate(&pp), Err(VerifierKeyError::IsZero("g"))));
+
+        // Ensure a verifier key with a `g` that is not on the curve is rejected.
+        let mut candidate = vk;
+        candidate.g.y.double_in_place();
+        assert!(matches!(candidate.validate(&pp), Err(VerifierKeyError::NotOnCurve("g"))));
+    }
+
     #[test]
     fn test_degree_is_too_large() {
         let rng = &mut TestRng::default();
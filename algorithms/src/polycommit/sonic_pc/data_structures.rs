@@ -24,6 +24,7 @@ use std::{
     collections::{BTreeMap, BTreeSet},
     fmt,
     ops::{AddAssign, MulAssign, SubAssign},
+    sync::Arc,
 };
 
 /// `UniversalParams` are the universal parameters for the KZG10 scheme.
@@ -42,7 +43,7 @@ pub struct CommitterKey<E: PairingEngine> {
     pub powers_of_beta_g: Vec<E::G1Affine>,
 
     /// The key used to commit to polynomials in Lagrange basis.
-    pub lagrange_bases_at_beta_g: BTreeMap<usize, Vec<E::G1Affine>>,
+    pub lagrange_bases_at_beta_g: BTreeMap<usize, Arc<Vec<E::G1Affine>>>,
 
     /// The key used to commit to hiding polynomials.
     pub powers_of_beta_times_gamma_g: Vec<E::G1Affine>,
@@ -81,7 +82,7 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
                 let power: E::G1Affine = FromBytes::read_le(&mut reader)?;
                 basis.push(power);
             }
-            lagrange_bases_at_beta_g.insert(size as usize, basis);
+            lagrange_bases_at_beta_g.insert(size as usize, Arc::new(basis));
         }
 
         // Deserialize `powers_of_beta_times_gamma_g`.
@@ -198,7 +199,7 @@ impl<E: PairingEngine> ToBytes for CommitterKey<E> {
         (self.lagrange_bases_at_beta_g.len() as u32).write_le(&mut writer)?;
         for (size, powers) in &self.lagrange_bases_at_beta_g {
             (*size as u32).write_le(&mut writer)?;
-            for power in powers {
+            for power in powers.iter() {
                 power.write_le(&mut writer)?;
             }
         }
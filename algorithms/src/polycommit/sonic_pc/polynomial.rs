@@ -15,7 +15,9 @@
 use super::PolynomialLabel;
 use crate::fft::{DensePolynomial, EvaluationDomain, Evaluations as EvaluationsOnDomain, Polynomial, SparsePolynomial};
 use snarkvm_fields::{Field, PrimeField};
-use snarkvm_utilities::{cfg_iter, cfg_iter_mut, CanonicalDeserialize, CanonicalSerialize};
+#[cfg(not(feature = "serial"))]
+use snarkvm_utilities::max_available_threads;
+use snarkvm_utilities::{cfg_chunks, cfg_chunks_mut, cfg_iter_mut, CanonicalDeserialize, CanonicalSerialize};
 
 use hashbrown::HashMap;
 use std::borrow::Cow;
@@ -405,13 +407,29 @@ impl<'a, F: PrimeField> PolynomialWithBasis<'a, F> {
                 let domain = evaluations.domain();
                 let degree = domain.size() as u64;
                 let multiplier = (point.pow([degree]) - F::one()) / F::from(degree);
-                let powers: Vec<_> = domain.elements().collect();
-                let mut denominators = cfg_iter!(powers).map(|pow| point - pow).collect::<Vec<_>>();
+                // Walk the domain in per-thread chunks instead of materializing all of its elements.
+                #[cfg(not(feature = "serial"))]
+                let num_chunks = max_available_threads();
+                #[cfg(feature = "serial")]
+                let num_chunks = 1;
+                let chunk_size = ((domain.size() + num_chunks - 1) / num_chunks).max(1);
+                let chunk_range = |i: usize| i * chunk_size..(i + 1) * chunk_size;
+                let mut denominators = vec![F::zero(); domain.size()];
+                cfg_chunks_mut!(denominators, chunk_size).enumerate().for_each(|(i, chunk)| {
+                    chunk.iter_mut().zip(domain.elements_range(chunk_range(i))).for_each(|(d, e)| *d = point - e)
+                });
                 snarkvm_fields::batch_inversion(&mut denominators);
-                cfg_iter_mut!(denominators)
-                    .zip_eq(powers)
-                    .zip_eq(&evaluations.evaluations)
-                    .map(|((denom, power), coeff)| *denom * power * coeff)
+                cfg_chunks!(denominators, chunk_size)
+                    .zip_eq(cfg_chunks!(evaluations.evaluations, chunk_size))
+                    .enumerate()
+                    .map(|(i, (denoms, coeffs))| {
+                        denoms
+                            .iter()
+                            .zip(domain.elements_range(chunk_range(i)))
+                            .zip(coeffs)
+                            .map(|((denom, power), coeff)| *denom * power * coeff)
+                            .sum::<F>()
+                    })
                     .sum::<F>()
                     * multiplier
             }
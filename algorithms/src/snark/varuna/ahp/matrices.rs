@@ -293,11 +293,10 @@ mod tests {
         let variable_domain = EvaluationDomain::new(2 + 6).unwrap();
         let input_domain = EvaluationDomain::new(2).unwrap();
         let inverse_map = constraint_domain.elements().enumerate().map(|(i, e)| (e, i)).collect::<HashMap<_, _>>();
-        let elements = constraint_domain.elements().collect::<Vec<_>>();
         let reindexed_inverse_map = (0..constraint_domain.size())
             .map(|i| {
                 let reindexed_i = constraint_domain.reindex_by_subdomain(&input_domain, i).unwrap();
-                (elements[reindexed_i], i)
+                (constraint_domain.element(reindexed_i), i)
             })
             .collect::<HashMap<_, _>>();
         let constraint_domain_elements = constraint_domain.elements().collect::<Vec<_>>();
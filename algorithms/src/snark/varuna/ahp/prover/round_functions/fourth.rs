@@ -14,8 +14,8 @@
 
 use crate::{
     fft::{
-        domain::{FFTPrecomputation, IFFTPrecomputation},
-        polynomial::PolyMultiplier,
+        domain::IFFTPrecomputation,
+        polynomial::PolyScratchPool,
         DensePolynomial,
         EvaluationDomain,
         Evaluations as EvaluationsOnDomain,
@@ -86,11 +86,19 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         let verifier::SecondMessage { alpha, .. } = second_message;
         let verifier::ThirdMessage { beta } = third_message;
 
+        // Initialize a scratch pool for each circuit, which is shared by the jobs of its matrices.
+        let scratches = state
+            .circuit_specific_states
+            .keys()
+            .map(|&circuit| {
+                PolyScratchPool::with_precomputation(&circuit.fft_precomputation, &circuit.ifft_precomputation)
+            })
+            .collect_vec();
         let mut pool = ExecutionPool::with_capacity(3 * state.circuit_specific_states.len());
 
         let max_non_zero_domain_size = state.max_non_zero_domain;
         let matrix_labels = ["a", "b", "c"];
-        for (&circuit, state_i) in &state.circuit_specific_states {
+        for ((&circuit, state_i), scratch) in state.circuit_specific_states.iter().zip_eq(&scratches) {
             let v_R_i_at_alpha = state_i.constraint_domain.evaluate_vanishing_polynomial(*alpha);
             let v_C_i_at_beta = state_i.variable_domain.evaluate_vanishing_polynomial(*beta);
             let v_R_i_alpha_v_C_i_beta = v_R_i_at_alpha * v_C_i_at_beta;
@@ -111,7 +119,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
                         *beta,
                         v_R_i_alpha_v_C_i_beta,
                         max_non_zero_domain_size,
-                        &circuit.fft_precomputation,
+                        scratch,
                         &circuit.ifft_precomputation,
                     );
                     (circuit, result)
@@ -162,7 +170,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         beta: F,
         v_R_i_alpha_v_C_i_beta: F,
         max_non_zero_domain: EvaluationDomain<F>,
-        fft_precomputation: &FFTPrecomputation<F>,
+        scratch: &PolyScratchPool<F>,
         ifft_precomputation: &IFFTPrecomputation<F>,
     ) -> Result<(Sum<F>, Lhs<F>, Gpoly<F>, Apoly<F>, Bpoly<F>)> {
         let (row_on_K, col_on_K, row_col_val) =
@@ -221,11 +229,9 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         let g = DensePolynomial::from_coefficients_slice(&f.coeffs[1..]);
         let mut h = &a_poly
             - &{
-                let mut multiplier = PolyMultiplier::new();
-                multiplier.add_polynomial_ref(&b_poly, "b");
-                multiplier.add_polynomial_ref(&f, "f");
-                multiplier.add_precomputation(fft_precomputation, ifft_precomputation);
-                multiplier.multiply().unwrap()
+                let product_domain = EvaluationDomain::new(b_poly.degree() + f.degree() + 1)
+                    .ok_or(anyhow::anyhow!("The product of b and f is too large"))?;
+                scratch.with_scratch(|scratch| b_poly.mul_in_domain(&f, product_domain, scratch))?
             };
 
         let combiner = F::one(); // We are applying combiners in the fifth round when summing the witnesses
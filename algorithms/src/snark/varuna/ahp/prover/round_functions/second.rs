@@ -15,7 +15,7 @@
 use std::collections::BTreeMap;
 
 use crate::{
-    fft::{polynomial::PolyMultiplier, DensePolynomial, EvaluationDomain, Evaluations as EvaluationsOnDomain},
+    fft::{polynomial::PolyScratchPool, DensePolynomial, EvaluationDomain, Evaluations as EvaluationsOnDomain},
     polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo, PolynomialLabel},
     snark::varuna::{
         ahp::{verifier, AHPForR1CS},
@@ -76,10 +76,18 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         state: &mut prover::State<F, SM>,
         batch_combiners: &BTreeMap<CircuitId, verifier::BatchCombiners<F>>,
     ) -> Result<DensePolynomial<F>> {
+        // Initialize a scratch pool for each circuit, which is shared by the jobs of its instances.
+        let scratches = state
+            .circuit_specific_states
+            .keys()
+            .map(|&circuit| {
+                PolyScratchPool::with_precomputation(&circuit.fft_precomputation, &circuit.ifft_precomputation)
+            })
+            .collect::<Vec<_>>();
         let mut job_pool = ExecutionPool::with_capacity(state.circuit_specific_states.len());
         let max_constraint_domain = state.max_constraint_domain;
 
-        for (circuit, circuit_specific_state) in state.circuit_specific_states.iter_mut() {
+        for ((circuit, circuit_specific_state), scratch) in state.circuit_specific_states.iter_mut().zip(&scratches) {
             let z_a = circuit_specific_state.z_a.take().unwrap();
             let z_b = circuit_specific_state.z_b.take().unwrap();
             let z_c = circuit_specific_state.z_c.take().unwrap();
@@ -87,8 +95,6 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
             let circuit_combiner = batch_combiners[&circuit.id].circuit_combiner;
             let instance_combiners = batch_combiners[&circuit.id].instance_combiners.clone();
             let constraint_domain = circuit_specific_state.constraint_domain;
-            let fft_precomputation = &circuit.fft_precomputation;
-            let ifft_precomputation = &circuit.ifft_precomputation;
 
             let _circuit_id = &circuit.id; // seems like a compiler bug marks this as unused
 
@@ -103,11 +109,10 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
                     let z_a = Self::calculate_z_m(za_label, z_a, constraint_domain, circuit);
                     let z_b = Self::calculate_z_m(zb_label, z_b, constraint_domain, circuit);
                     let z_c = Self::calculate_z_m(zc_label, z_c, constraint_domain, circuit);
-                    let mut multiplier_2 = PolyMultiplier::new();
-                    multiplier_2.add_precomputation(fft_precomputation, ifft_precomputation);
-                    multiplier_2.add_polynomial(z_a, "z_a");
-                    multiplier_2.add_polynomial(z_b, "z_b");
-                    let mut rowcheck = multiplier_2.multiply().unwrap();
+                    let product_domain = EvaluationDomain::new(z_a.degree() + z_b.degree() + 1)
+                        .ok_or(anyhow::anyhow!("The product of z_a and z_b is too large"))?;
+                    let mut rowcheck =
+                        scratch.with_scratch(|scratch| z_a.mul_in_domain(&z_b, product_domain, scratch))?;
                     cfg_iter_mut!(rowcheck.coeffs).zip(&z_c.coeffs).for_each(|(ab, c)| *ab -= c);
 
                     instance_lhs += &(&rowcheck * instance_combiner);
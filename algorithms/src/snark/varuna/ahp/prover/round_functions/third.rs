@@ -13,13 +13,7 @@
 // limitations under the License.
 
 use crate::{
-    fft::{
-        domain::{FFTPrecomputation, IFFTPrecomputation},
-        polynomial::PolyMultiplier,
-        DensePolynomial,
-        EvaluationDomain,
-        Evaluations,
-    },
+    fft::{domain::IFFTPrecomputation, polynomial::PolyScratchPool, DensePolynomial, EvaluationDomain, Evaluations},
     polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo, PolynomialLabel},
     snark::varuna::{
         ahp::{indexer::CircuitId, verifier, AHPForR1CS},
@@ -137,20 +131,30 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         let matrix_labels = ["a", "b", "c"];
         let matrix_combiners = [F::one(), *eta_b, *eta_c];
 
+        // Initialize a scratch pool for each circuit, which is shared by the jobs of its instances.
+        let scratches = state
+            .circuit_specific_states
+            .keys()
+            .map(|&circuit| {
+                PolyScratchPool::with_precomputation(&circuit.fft_precomputation, &circuit.ifft_precomputation)
+            })
+            .collect_vec();
+
         // Compute lineval sumcheck witnesses
         let mut job_pool = ExecutionPool::with_capacity(total_instances * 3);
-        for ((((circuit, circuit_specific_state), batch_combiner), assignments_i), matrix_transposes_i) in state
-            .circuit_specific_states
-            .iter_mut()
-            .zip_eq(batch_combiners.values())
-            .zip_eq(assignments.values())
-            .zip_eq(matrix_transposes.values())
+        for (((((circuit, circuit_specific_state), scratch), batch_combiner), assignments_i), matrix_transposes_i) in
+            state
+                .circuit_specific_states
+                .iter_mut()
+                .zip_eq(&scratches)
+                .zip_eq(batch_combiners.values())
+                .zip_eq(assignments.values())
+                .zip_eq(matrix_transposes.values())
         {
             let circuit_combiner = batch_combiner.circuit_combiner;
             let instance_combiners = &batch_combiner.instance_combiners;
             let constraint_domain = &circuit_specific_state.constraint_domain;
             let variable_domain = &circuit_specific_state.variable_domain;
-            let fft_precomputation = &circuit.fft_precomputation;
             let ifft_precomputation = &circuit.ifft_precomputation;
 
             for (_j, (&instance_combiner, assignment)) in
@@ -165,7 +169,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
                             constraint_domain,
                             variable_domain,
                             max_variable_domain,
-                            fft_precomputation,
+                            scratch,
                             ifft_precomputation,
                             assignment,
                             matrix_transpose,
@@ -281,7 +285,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         constraint_domain: &EvaluationDomain<F>,
         variable_domain: &EvaluationDomain<F>,
         max_variable_domain: &EvaluationDomain<F>,
-        fft_precomputation: &FFTPrecomputation<F>,
+        scratch: &PolyScratchPool<F>,
         ifft_precomputation: &IFFTPrecomputation<F>,
         assignment: &DensePolynomial<F>,
         matrix_transpose: &Matrix<F>,
@@ -308,11 +312,10 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         let z_m_at_alpha_time = start_timer!(|| format!("Compute z_m_at_alpha_time for {_label}"));
         let m_at_alpha = Evaluations::from_vec_and_domain(m_at_alpha_evals, *variable_domain)
             .interpolate_with_pc(ifft_precomputation);
-        let mut multiplier = PolyMultiplier::new();
-        multiplier.add_precomputation(fft_precomputation, ifft_precomputation);
-        multiplier.add_polynomial(m_at_alpha, "m_at_alpha");
-        multiplier.add_polynomial_ref(assignment, "assignment");
-        let mut z_m_at_alpha = multiplier.multiply().unwrap();
+        let product_domain = EvaluationDomain::new(m_at_alpha.degree() + assignment.degree() + 1)
+            .ok_or(anyhow::anyhow!("The product of m_at_alpha and the assignment is too large"))?;
+        let mut z_m_at_alpha =
+            scratch.with_scratch(|scratch| m_at_alpha.mul_in_domain(assignment, product_domain, scratch))?;
         let sum = z_m_at_alpha.evaluate_over_domain_by_ref(*variable_domain).evaluations.into_iter().sum::<F>();
         end_timer!(z_m_at_alpha_time);
 
@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_algorithms::fft::{
+    polynomial::{PolyScratch, PolyScratchPool},
+    DensePolynomial,
+    EvaluationDomain,
+};
+use snarkvm_curves::bls12_377::Fr;
+use snarkvm_utilities::TestRng;
+
+use rayon::prelude::*;
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// A global allocator that counts the number of bytes allocated.
+struct CountingAllocator;
+
+/// The number of bytes allocated so far.
+static NUM_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        NUM_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Returns the number of bytes allocated by `f`.
+fn count_bytes<T>(f: impl FnOnce() -> T) -> usize {
+    let start = NUM_BYTES.load(Ordering::Relaxed);
+    std::hint::black_box(f());
+    NUM_BYTES.load(Ordering::Relaxed) - start
+}
+
+#[test]
+fn test_mul_in_domain_reuses_scratch() {
+    let rng = &mut TestRng::default();
+
+    let degree = 1 << 10;
+    let a = DensePolynomial::<Fr>::rand(degree - 1, rng);
+    let b = DensePolynomial::<Fr>::rand(degree - 1, rng);
+    let domain = EvaluationDomain::new(2 * degree).unwrap();
+    let (fft_precomputation, ifft_precomputation) = (domain.precompute_fft(), domain.precompute_ifft());
+    let mut scratch = PolyScratch::with_precomputation(&fft_precomputation, &ifft_precomputation);
+
+    // Warm up the scratch space.
+    assert_eq!(a.mul_in_domain(&b, domain, &mut scratch).unwrap(), &a * &b);
+
+    // Count the bytes allocated by a multiplication with a fresh scratch space, as was done for every job.
+    let fresh = count_bytes(|| {
+        let mut scratch = PolyScratch::with_precomputation(&fft_precomputation, &ifft_precomputation);
+        a.mul_in_domain(&b, domain, &mut scratch).unwrap()
+    });
+    // Ensure multiplications with the warm scratch space do not allocate the two evaluation buffers.
+    // Note: The FFTs allocate a small and varying number of bytes on their own, which is tolerated here.
+    let buffer = domain.size() * std::mem::size_of::<Fr>();
+    for _ in 0..4 {
+        let warm = count_bytes(|| a.mul_in_domain(&b, domain, &mut scratch).unwrap());
+        assert!(warm + buffer < fresh, "Allocated {warm} bytes with the warm scratch space, and {fresh} without");
+    }
+
+    // Ensure a smaller product reuses the same scratch space.
+    let c = DensePolynomial::<Fr>::rand(degree / 2 - 1, rng);
+    let subdomain = EvaluationDomain::new(degree).unwrap();
+    let fresh = count_bytes(|| {
+        let mut scratch = PolyScratch::with_precomputation(&fft_precomputation, &ifft_precomputation);
+        c.mul_in_domain(&c, subdomain, &mut scratch).unwrap()
+    });
+    let warm = count_bytes(|| c.mul_in_domain(&c, subdomain, &mut scratch).unwrap());
+    assert!(warm + buffer < fresh, "Allocated {warm} bytes with the warm scratch space, and {fresh} without");
+}
+
+#[test]
+fn test_scratch_pool_reuses_scratch() {
+    let rng = &mut TestRng::default();
+
+    let degree = 1 << 10;
+    let a = DensePolynomial::<Fr>::rand(degree - 1, rng);
+    let b = DensePolynomial::<Fr>::rand(degree - 1, rng);
+    let domain = EvaluationDomain::new(2 * degree).unwrap();
+    let (fft_precomputation, ifft_precomputation) = (domain.precompute_fft(), domain.precompute_ifft());
+    let pool = PolyScratchPool::with_precomputation(&fft_precomputation, &ifft_precomputation);
+
+    // Warm up the pool.
+    let expected = &a * &b;
+    assert_eq!(pool.with_scratch(|scratch| a.mul_in_domain(&b, domain, scratch)).unwrap(), expected);
+
+    // Ensure the jobs of a pool run in parallel, and reuse the scratch spaces of earlier jobs.
+    let products = (0..8)
+        .into_par_iter()
+        .map(|_| pool.with_scratch(|scratch| a.mul_in_domain(&b, domain, scratch)).unwrap())
+        .collect::<Vec<_>>();
+    assert!(products.iter().all(|product| product == &expected));
+
+    // Ensure a sequential multiplication with the pool does not allocate the two evaluation buffers.
+    let fresh = count_bytes(|| {
+        let mut scratch = PolyScratch::with_precomputation(&fft_precomputation, &ifft_precomputation);
+        a.mul_in_domain(&b, domain, &mut scratch).unwrap()
+    });
+    let buffer = domain.size() * std::mem::size_of::<Fr>();
+    let warm = count_bytes(|| pool.with_scratch(|scratch| a.mul_in_domain(&b, domain, scratch)).unwrap());
+    assert!(warm + buffer < fresh, "Allocated {warm} bytes with the scratch pool, and {fresh} without");
+}
@@ -67,9 +67,7 @@ impl<E: Environment, const TYPE: u8, const VARIANT: usize> LeafHash for Keccak<E
         // Hash the input.
         let output = Hash::hash(self, &input);
         // Read the first VARIANT bits.
-        let mut result = BooleanHash::default();
-        result.0.clone_from_slice(&output[..VARIANT]);
-        result
+        BooleanHash::from_keccak_output(&output)
     }
 }
 
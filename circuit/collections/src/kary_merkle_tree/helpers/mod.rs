@@ -23,6 +23,21 @@ pub use path_hash::*;
 #[derive(Clone, Debug)]
 pub struct BooleanHash<E: Environment, const VARIANT: usize>(pub [Boolean<E>; VARIANT]);
 
+impl<E: Environment, const VARIANT: usize> BooleanHash<E, VARIANT> {
+    /// Initializes a boolean hash from the first `VARIANT` bits of the given Keccak output.
+    /// Note: This matches the truncation rule of the console boolean hash.
+    pub fn from_keccak_output(output: &[Boolean<E>]) -> Self {
+        // Ensure the output contains at least `VARIANT` bits.
+        if output.len() < VARIANT {
+            E::halt(format!("Keccak output must contain at least {VARIANT} bits, found {}", output.len()))
+        }
+        // Read the first VARIANT bits.
+        let mut result = Self::default();
+        result.0.clone_from_slice(&output[..VARIANT]);
+        result
+    }
+}
+
 impl<E: Environment, const VARIANT: usize> Default for BooleanHash<E, VARIANT> {
     /// Initializes a new "empty" boolean hash.
     fn default() -> Self {
@@ -29,10 +29,8 @@ pub trait PathHash<E: Environment> {
     fn hash_children(&self, children: &[Self::Hash]) -> Self::Hash;
 
     /// Returns the empty hash.
-    fn hash_empty<const ARITY: u8>(&self) -> Self::Hash {
-        let children = vec![Self::Hash::default(); ARITY as usize];
-        self.hash_children(&children)
-    }
+    /// Note: The empty hash must be domain-separated from the hash of `ARITY` default child nodes.
+    fn hash_empty<const ARITY: u8>(&self) -> Self::Hash;
 }
 
 impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> PathHash<E> for BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
@@ -50,6 +48,19 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> PathHash<E> f
         // Hash the input.
         Hash::hash(self, &input)
     }
+
+    /// Returns the empty hash.
+    fn hash_empty<const ARITY: u8>(&self) -> Self::Hash {
+        let mut input = Vec::new();
+        // Prepend the empty nodes with two `true` bits, to separate them from the child nodes.
+        input.push(Boolean::constant(true));
+        input.push(Boolean::constant(true));
+        for child in vec![Self::Hash::default(); ARITY as usize] {
+            child.write_bits_le(&mut input);
+        }
+        // Hash the input.
+        Hash::hash(self, &input)
+    }
 }
 
 impl<E: Environment, const RATE: usize> PathHash<E> for Poseidon<E, RATE> {
@@ -67,6 +78,16 @@ impl<E: Environment, const RATE: usize> PathHash<E> for Poseidon<E, RATE> {
         // Hash the input.
         Hash::hash(self, &input)
     }
+
+    /// Returns the empty hash.
+    fn hash_empty<const ARITY: u8>(&self) -> Self::Hash {
+        let mut input = Vec::with_capacity(1 + ARITY as usize);
+        // Prepend the empty nodes with a `2field` element, to separate them from the child nodes.
+        input.push(Self::Hash::one().double());
+        input.resize(1 + ARITY as usize, Self::Hash::default());
+        // Hash the input.
+        Hash::hash(self, &input)
+    }
 }
 
 impl<E: Environment, const TYPE: u8, const VARIANT: usize> PathHash<E> for Keccak<E, TYPE, VARIANT> {
@@ -84,16 +105,36 @@ impl<E: Environment, const TYPE: u8, const VARIANT: usize> PathHash<E> for Kecca
         // Hash the input.
         let output = Hash::hash(self, &input);
         // Read the first VARIANT bits.
-        let mut result = BooleanHash::default();
-        result.0.clone_from_slice(&output[..VARIANT]);
-        result
+        BooleanHash::from_keccak_output(&output)
+    }
+
+    /// Returns the empty hash.
+    fn hash_empty<const ARITY: u8>(&self) -> Self::Hash {
+        let mut input = Vec::with_capacity(2 + ARITY as usize * VARIANT);
+        // Prepend the empty nodes with two `true` bits, to separate them from the child nodes.
+        input.push(Boolean::constant(true));
+        input.push(Boolean::constant(true));
+        input.resize(2 + ARITY as usize * VARIANT, Boolean::constant(false));
+        // Hash the input.
+        let output = Hash::hash(self, &input);
+        // Read the first VARIANT bits.
+        BooleanHash::from_keccak_output(&output)
     }
 }
 
 #[cfg(all(test, console))]
 mod tests {
     use super::*;
-    use snarkvm_circuit_algorithms::{Keccak256, Poseidon2, Sha3_256, BHP512};
+    use snarkvm_circuit_algorithms::{
+        Keccak256,
+        Keccak384,
+        Keccak512,
+        Poseidon2,
+        Sha3_256,
+        Sha3_384,
+        Sha3_512,
+        BHP512,
+    };
     use snarkvm_circuit_types::environment::Circuit;
     use snarkvm_utilities::{TestRng, Uniform};
 
@@ -141,6 +182,76 @@ mod tests {
         }};
     }
 
+    /// Checks that the circuit `hash_children` ejects to the console value for arity 2, 4, and 8.
+    fn check_hash_children_matches_console<const TYPE: u8, const VARIANT: usize>(
+        native: snarkvm_console_algorithms::Keccak<TYPE, VARIANT>,
+        circuit: Keccak<Circuit, TYPE, VARIANT>,
+    ) -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for arity in [2, 4, 8] {
+            // Sample random children.
+            let children = (0..arity).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>();
+
+            // Compute the expected hash.
+            let expected = console::kary_merkle_tree::PathHash::hash_children(&native, &children)?;
+
+            // Prepare the circuit input.
+            let children = children.into_iter().map(|child| Inject::new(Mode::Private, child)).collect::<Vec<_>>();
+
+            // Ensure the candidate hash matches the expected hash.
+            let candidate = circuit.hash_children(&children);
+            assert!(Circuit::is_satisfied());
+            assert_eq!(expected, candidate.eject_value());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    /// Checks that the circuit `hash_empty` ejects to the console value, and differs from the hash of default child nodes.
+    fn check_hash_empty<P: console::kary_merkle_tree::PathHash, C: PathHash<Circuit, Primitive = P>>(
+        native: &P,
+        circuit: &C,
+    ) -> Result<()> {
+        const ARITY: u8 = 4;
+
+        // Compute the expected hash.
+        let expected = native.hash_empty::<ARITY>()?;
+        assert_ne!(expected, native.hash_children(&vec![Default::default(); ARITY as usize])?);
+
+        // Ensure the candidate hash matches the expected hash.
+        let candidate = circuit.hash_empty::<ARITY>();
+        assert_eq!(expected, candidate.eject_value());
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_empty() -> Result<()> {
+        let native = snarkvm_console_algorithms::BHP512::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        check_hash_empty(&native, &BHP512::<Circuit>::constant(native.clone()))?;
+
+        let native = snarkvm_console_algorithms::Poseidon2::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        check_hash_empty(&native, &Poseidon2::<Circuit>::constant(native.clone()))?;
+
+        check_hash_empty(&snarkvm_console_algorithms::Keccak256 {}, &Keccak256::<Circuit>::new())?;
+        check_hash_empty(&snarkvm_console_algorithms::Sha3_256 {}, &Sha3_256::<Circuit>::new())
+    }
+
+    #[test]
+    fn test_hash_children_keccak_matches_console() -> Result<()> {
+        check_hash_children_matches_console(snarkvm_console_algorithms::Keccak256 {}, Keccak256::<Circuit>::new())?;
+        check_hash_children_matches_console(snarkvm_console_algorithms::Keccak384 {}, Keccak384::<Circuit>::new())?;
+        check_hash_children_matches_console(snarkvm_console_algorithms::Keccak512 {}, Keccak512::<Circuit>::new())
+    }
+
+    #[test]
+    fn test_hash_children_sha3_matches_console() -> Result<()> {
+        check_hash_children_matches_console(snarkvm_console_algorithms::Sha3_256 {}, Sha3_256::<Circuit>::new())?;
+        check_hash_children_matches_console(snarkvm_console_algorithms::Sha3_384 {}, Sha3_384::<Circuit>::new())?;
+        check_hash_children_matches_console(snarkvm_console_algorithms::Sha3_512 {}, Sha3_512::<Circuit>::new())
+    }
+
     #[test]
     fn test_hash_children_bhp512_constant() -> Result<()> {
         check_hash_children!(BHP512, Constant, 2, (1599, 0, 0, 0))?;
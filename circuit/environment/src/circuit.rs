@@ -241,9 +241,7 @@ impl Environment for Circuit {
 
     /// Halts the program from further synthesis, evaluation, and execution in the current environment.
     fn halt<S: Into<String>, T>(message: S) -> T {
-        let error = message.into();
-        // eprintln!("{}", &error);
-        panic!("{}", &error)
+        console::Halt::unwind(message)
     }
 
     /// TODO (howardwu): Abstraction - Refactor this into an appropriate design.
@@ -0,0 +1,187 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+
+use core::fmt;
+
+/// The circuit environment for `DevnetV0`.
+///
+/// Note: As `DevnetV0` shares the fields and curves of `Testnet3`, this environment is backed by
+/// the constraint system of `Circuit`, which it shares on each thread.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct DevnetCircuit;
+
+impl Environment for DevnetCircuit {
+    type Affine = <console::DevnetV0 as console::Environment>::Affine;
+    type BaseField = <console::DevnetV0 as console::Environment>::Field;
+    type Network = console::DevnetV0;
+    type ScalarField = <console::DevnetV0 as console::Environment>::Scalar;
+
+    /// Returns the `zero` constant.
+    fn zero() -> LinearCombination<Self::BaseField> {
+        Circuit::zero()
+    }
+
+    /// Returns the `one` constant.
+    fn one() -> LinearCombination<Self::BaseField> {
+        Circuit::one()
+    }
+
+    /// Returns a new variable of the given mode and value.
+    fn new_variable(mode: Mode, value: Self::BaseField) -> Variable<Self::BaseField> {
+        Circuit::new_variable(mode, value)
+    }
+
+    /// Returns a new witness of the given mode and value.
+    fn new_witness<Fn: FnOnce() -> Output::Primitive, Output: Inject>(mode: Mode, logic: Fn) -> Output {
+        Circuit::new_witness(mode, logic)
+    }
+
+    /// Enters a new scope for the environment.
+    fn scope<S: Into<String>, Fn, Output>(name: S, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        Circuit::scope(name, logic)
+    }
+
+    /// Adds one constraint enforcing that `(A * B) == C`.
+    fn enforce<Fn, A, B, C>(constraint: Fn)
+    where
+        Fn: FnOnce() -> (A, B, C),
+        A: Into<LinearCombination<Self::BaseField>>,
+        B: Into<LinearCombination<Self::BaseField>>,
+        C: Into<LinearCombination<Self::BaseField>>,
+    {
+        Circuit::enforce(constraint)
+    }
+
+    /// Returns `true` if all constraints in the environment are satisfied.
+    fn is_satisfied() -> bool {
+        Circuit::is_satisfied()
+    }
+
+    /// Returns `true` if all constraints in the current scope are satisfied.
+    fn is_satisfied_in_scope() -> bool {
+        Circuit::is_satisfied_in_scope()
+    }
+
+    /// Returns the number of constants in the entire circuit.
+    fn num_constants() -> u64 {
+        Circuit::num_constants()
+    }
+
+    /// Returns the number of public variables in the entire circuit.
+    fn num_public() -> u64 {
+        Circuit::num_public()
+    }
+
+    /// Returns the number of private variables in the entire circuit.
+    fn num_private() -> u64 {
+        Circuit::num_private()
+    }
+
+    /// Returns the number of constraints in the entire circuit.
+    fn num_constraints() -> u64 {
+        Circuit::num_constraints()
+    }
+
+    /// Returns the number of nonzeros in the entire circuit.
+    fn num_nonzeros() -> (u64, u64, u64) {
+        Circuit::num_nonzeros()
+    }
+
+    /// Returns the number of constants for the current scope.
+    fn num_constants_in_scope() -> u64 {
+        Circuit::num_constants_in_scope()
+    }
+
+    /// Returns the number of public variables for the current scope.
+    fn num_public_in_scope() -> u64 {
+        Circuit::num_public_in_scope()
+    }
+
+    /// Returns the number of private variables for the current scope.
+    fn num_private_in_scope() -> u64 {
+        Circuit::num_private_in_scope()
+    }
+
+    /// Returns the number of constraints for the current scope.
+    fn num_constraints_in_scope() -> u64 {
+        Circuit::num_constraints_in_scope()
+    }
+
+    /// Returns the number of nonzeros for the current scope.
+    fn num_nonzeros_in_scope() -> (u64, u64, u64) {
+        Circuit::num_nonzeros_in_scope()
+    }
+
+    /// Halts the program from further synthesis, evaluation, and execution in the current environment.
+    fn halt<S: Into<String>, T>(message: S) -> T {
+        Circuit::halt(message)
+    }
+
+    /// Returns the R1CS circuit, resetting the circuit.
+    fn inject_r1cs(r1cs: R1CS<Self::BaseField>) {
+        Circuit::inject_r1cs(r1cs)
+    }
+
+    /// Returns the R1CS circuit, resetting the circuit.
+    fn eject_r1cs_and_reset() -> R1CS<Self::BaseField> {
+        Circuit::eject_r1cs_and_reset()
+    }
+
+    /// Returns the R1CS assignment of the circuit, resetting the circuit.
+    fn eject_assignment_and_reset() -> Assignment<<Self::Network as console::Environment>::Field> {
+        Circuit::eject_assignment_and_reset()
+    }
+
+    /// Clears the circuit and initializes an empty environment.
+    fn reset() {
+        Circuit::reset()
+    }
+}
+
+impl fmt::Display for DevnetCircuit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&Circuit, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_circuit_scope() {
+        DevnetCircuit::scope("test_circuit_scope", || {
+            assert_eq!(0, DevnetCircuit::num_constants());
+            assert_eq!(1, DevnetCircuit::num_public());
+            assert_eq!(0, DevnetCircuit::num_private());
+            assert_eq!(0, DevnetCircuit::num_constraints());
+        })
+    }
+
+    #[test]
+    fn test_circuit_is_shared() {
+        // Ensure the devnet circuit is backed by the constraint system of `Circuit`.
+        let value = <DevnetCircuit as Environment>::BaseField::from(2u64);
+        let _ = DevnetCircuit::new_variable(Mode::Private, value);
+        assert_eq!(1, DevnetCircuit::num_private());
+        assert_eq!(1, Circuit::num_private());
+        DevnetCircuit::reset();
+        assert_eq!(0, Circuit::num_private());
+    }
+}
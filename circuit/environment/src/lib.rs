@@ -22,6 +22,9 @@ pub use snarkvm_circuit_environment_witness::rename_selfs;
 pub mod circuit;
 pub use circuit::*;
 
+pub mod devnet_circuit;
+pub use devnet_circuit::*;
+
 pub mod environment;
 pub use environment::*;
 
@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Aleo;
+use snarkvm_circuit_algorithms::{
+    Commit,
+    CommitUncompressed,
+    Hash,
+    HashMany,
+    HashToGroup,
+    HashToScalar,
+    HashUncompressed,
+    Keccak256,
+    Keccak384,
+    Keccak512,
+    Pedersen128,
+    Pedersen64,
+    Poseidon2,
+    Poseidon4,
+    Poseidon8,
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
+    BHP1024,
+    BHP256,
+    BHP512,
+    BHP768,
+};
+use snarkvm_circuit_collections::merkle_tree::MerklePath;
+use snarkvm_circuit_types::{
+    environment::{prelude::*, Assignment, DevnetCircuit, R1CS},
+    Boolean,
+    Field,
+    Group,
+    Scalar,
+};
+
+use core::fmt;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct AleoDevnetV0;
+
+aleo_network!(AleoDevnetV0, DevnetCircuit, console::DevnetV0, console::devnet_v0);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AleoV0;
+
+    type CurrentAleo = AleoDevnetV0;
+
+    #[test]
+    fn test_circuit_scope() {
+        CurrentAleo::scope("test_circuit_scope", || {
+            assert_eq!(0, CurrentAleo::num_constants());
+            assert_eq!(1, CurrentAleo::num_public());
+            assert_eq!(0, CurrentAleo::num_private());
+            assert_eq!(0, CurrentAleo::num_constraints());
+        })
+    }
+
+    #[test]
+    fn test_hash_matches_aleo_v0() {
+        let input = snarkvm_console_types::Field::<console::DevnetV0>::from_u64(1234);
+        let expected = snarkvm_console_types::Field::<console::Testnet3>::from_u64(1234);
+
+        // Ensure the devnet circuit uses the same hash parameters as `AleoV0`.
+        let candidate = CurrentAleo::hash_psd2(&[Field::new(Mode::Private, input)]).eject_value();
+        assert!(CurrentAleo::is_satisfied());
+        let num_constraints = CurrentAleo::num_constraints();
+        CurrentAleo::reset();
+
+        let expected = AleoV0::hash_psd2(&[Field::new(Mode::Private, expected)]).eject_value();
+        assert_eq!(candidate.to_string(), expected.to_string());
+        assert_eq!(num_constraints, AleoV0::num_constraints());
+        AleoV0::reset();
+    }
+}
@@ -15,9 +15,15 @@
 #![forbid(unsafe_code)]
 #![allow(clippy::too_many_arguments)]
 
+#[macro_use]
+mod macros;
+
 pub mod v0;
 pub use v0::*;
 
+pub mod devnet_v0;
+pub use devnet_v0::*;
+
 use snarkvm_circuit_collections::merkle_tree::MerklePath;
 use snarkvm_circuit_types::{environment::Environment, Boolean, Field, Group, Scalar};
 
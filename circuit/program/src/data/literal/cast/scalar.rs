@@ -35,6 +35,7 @@ impl<E: Environment> Cast<Boolean<E>> for Scalar<E> {
     /// To cast arbitrary scalars to booleans, use `Scalar::cast_lossy`.
     #[inline]
     fn cast(&self) -> Boolean<E> {
+        // Note: See `Boolean::from_field_strict` for why this cast does not use it.
         let is_one = self.is_one();
         E::assert(self.is_zero().bitor(&is_one));
         is_one
@@ -34,7 +34,8 @@ impl<E: Environment> CastLossy<Boolean<E>> for Field<E> {
     /// This operation returns the least significant bit of the field.
     #[inline]
     fn cast_lossy(&self) -> Boolean<E> {
-        let bits_le = self.to_bits_le();
+        // Note: The least significant bit is taken from the unique bit representation of the field.
+        let bits_le = self.to_bits_le_strict(<console::Field<E::Network> as console::SizeInBits>::size_in_bits());
         debug_assert!(!bits_le.is_empty(), "An integer must have at least one bit");
         bits_le[0].clone()
     }
@@ -171,7 +171,7 @@ impl<A: Aleo> Response<A> {
             .collect();
 
         // Return the response.
-        Self { output_ids, outputs }
+        Self { output_ids, outputs, output_types: output_types.to_vec() }
     }
 }
 
@@ -162,6 +162,8 @@ pub struct Response<A: Aleo> {
     output_ids: Vec<OutputID<A>>,
     /// The function outputs.
     outputs: Vec<Value<A>>,
+    /// The function output types.
+    output_types: Vec<console::ValueType<A::Network>>, // Note: Console type
 }
 
 impl<A: Aleo> Response<A> {
@@ -174,6 +176,11 @@ impl<A: Aleo> Response<A> {
     pub fn outputs(&self) -> &[Value<A>] {
         &self.outputs
     }
+
+    /// Returns the function output types.
+    pub fn output_types(&self) -> &[console::ValueType<A::Network>] {
+        &self.output_types
+    }
 }
 
 #[cfg(console)]
@@ -190,6 +197,7 @@ impl<A: Aleo> Eject for Response<A> {
         Self::Primitive::from((
             self.output_ids.iter().map(|output_id| output_id.eject_value()).collect(),
             self.outputs.eject_value(),
+            self.output_types.clone(),
         ))
     }
 }
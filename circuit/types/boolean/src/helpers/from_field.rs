@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Boolean<E> {
+    /// Returns the boolean represented by the given field element, enforcing that it is zero or one.
+    ///
+    /// This method halts if the field element is a constant that is neither zero nor one.
+    ///
+    /// Note: The literal casts to a boolean do not use this method, as any change to their constraints
+    /// would change the verifying keys of deployed programs that cast to a boolean.
+    pub fn from_field_strict(field: impl Into<LinearCombination<E::BaseField>>) -> Self {
+        let field = field.into();
+        match field.is_constant() {
+            true => match field.value().is_zero() || field.value().is_one() {
+                true => Self(field),
+                false => E::halt(format!("Failed to convert the field element '{}' to a boolean", field.value())),
+            },
+            false => {
+                // Ensure (1 - b) * b = 0
+                // `b` must be either 0 or 1.
+                E::enforce(|| (E::one() - &field, field.clone(), E::zero()));
+                Self(field)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    type F = <Circuit as Environment>::BaseField;
+
+    fn check_from_field_strict(mode: Mode) {
+        for expected in [false, true] {
+            let field: LinearCombination<F> = Circuit::new_variable(mode, F::from(expected as u64)).into();
+
+            Circuit::scope(format!("{mode} {expected}"), || {
+                let candidate = Boolean::<Circuit>::from_field_strict(field);
+                assert_eq!(expected, candidate.eject_value());
+                match mode.is_constant() {
+                    true => assert_scope!(0, 0, 0, 0),
+                    false => assert_scope!(0, 0, 0, 1),
+                }
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_field_strict_constant() {
+        check_from_field_strict(Mode::Constant);
+    }
+
+    #[test]
+    fn test_from_field_strict_public() {
+        check_from_field_strict(Mode::Public);
+    }
+
+    #[test]
+    fn test_from_field_strict_private() {
+        check_from_field_strict(Mode::Private);
+    }
+
+    #[test]
+    fn test_from_field_strict_rejects_non_boolean_witness() {
+        // Assign a malicious witness that is neither zero nor one.
+        let field: LinearCombination<F> = Circuit::new_variable(Mode::Private, F::from(2u64)).into();
+        let _candidate = Boolean::<Circuit>::from_field_strict(field);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+
+        // Ensure a constant that is neither zero nor one halts.
+        let field: LinearCombination<F> = Circuit::new_variable(Mode::Constant, F::from(2u64)).into();
+        assert!(std::panic::catch_unwind(|| Boolean::<Circuit>::from_field_strict(field)).is_err());
+        Circuit::reset();
+    }
+}
@@ -62,6 +62,24 @@ impl<E: Environment> ToBits for &Field<E> {
 }
 
 impl<E: Environment> Field<E> {
+    /// Outputs the `length`-bit little-endian representation of `self`, enforcing that it is
+    /// the unique representation, i.e. the bits, as an integer, are less than the modulus.
+    ///
+    /// If `length` is less than the size of the field, the upper bits of `self` are enforced to be zero.
+    /// Otherwise, the representation is padded with zeros to `length` bits.
+    pub fn to_bits_le_strict(&self, length: usize) -> Vec<Boolean<E>> {
+        match length <= E::BaseField::size_in_data_bits() {
+            // As `2^length` is at most the modulus, any `length`-bit representation is unique.
+            true => self.to_lower_bits_le(length),
+            // Otherwise, `to_bits_le` checks the representation against the modulus.
+            false => {
+                let mut bits_le = self.to_bits_le();
+                bits_le.resize(length, Boolean::constant(false));
+                bits_le
+            }
+        }
+    }
+
     /// Outputs a non-unique little-endian bit representation of `self` *without* trailing zeros.
     #[doc(hidden)]
     fn to_non_unique_bits_le(&self) -> Vec<Boolean<E>> {
@@ -168,6 +186,76 @@ mod tests {
         }
     }
 
+    fn check_to_bits_le_strict(mode: Mode) {
+        let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+        let size_in_data_bits = <Circuit as Environment>::BaseField::size_in_data_bits();
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let expected: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let candidate = Field::<Circuit>::new(mode, expected);
+
+            // Ensure the full representation is padded to the given length.
+            Circuit::scope(&format!("{mode} {i}"), || {
+                let candidate_bits = candidate.to_bits_le_strict(size_in_bits + 3);
+                assert_eq!(size_in_bits + 3, candidate_bits.len());
+                for (expected_bit, candidate_bit) in
+                    expected.to_bits_le().into_iter().chain([false; 3]).zip_eq(&candidate_bits)
+                {
+                    assert_eq!(expected_bit, candidate_bit.eject_value());
+                }
+                assert_count!(ToBits<Boolean>() => Field, &mode);
+            });
+            Circuit::reset();
+
+            // Ensure a shorter representation enforces the upper bits are zero.
+            let candidate = Field::<Circuit>::new(mode, expected);
+            let expected_bits = expected.to_bits_le();
+            let is_in_range = expected_bits[size_in_data_bits..].iter().all(|bit| !bit);
+            if is_in_range || mode.is_constant() {
+                continue;
+            }
+            let candidate_bits = candidate.to_bits_le_strict(size_in_data_bits);
+            assert_eq!(size_in_data_bits, candidate_bits.len());
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_to_bits_le_strict_constant() {
+        check_to_bits_le_strict(Mode::Constant);
+    }
+
+    #[test]
+    fn test_to_bits_le_strict_public() {
+        check_to_bits_le_strict(Mode::Public);
+    }
+
+    #[test]
+    fn test_to_bits_le_strict_private() {
+        check_to_bits_le_strict(Mode::Private);
+    }
+
+    #[test]
+    fn test_to_bits_le_strict_rejects_large_values() {
+        // Ensure a value that does not fit in 8 bits does not satisfy the decomposition.
+        let candidate = Field::<Circuit>::new(Mode::Private, console::Field::from_u16(256));
+        let candidate_bits = candidate.to_bits_le_strict(8);
+        assert_eq!(8, candidate_bits.len());
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+
+        // Ensure a value that fits in 8 bits satisfies the decomposition.
+        let candidate = Field::<Circuit>::new(Mode::Private, console::Field::from_u16(255));
+        let candidate_bits = candidate.to_bits_le_strict(8);
+        assert!(candidate_bits.iter().all(|bit| bit.eject_value()));
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
     #[test]
     fn test_to_bits_le_constant() {
         check_to_bits_le(Mode::Constant);
@@ -37,10 +37,13 @@ pub mod pow_checked;
 pub mod pow_wrapped;
 pub mod rem_checked;
 pub mod rem_wrapped;
+pub mod rotate_left;
+pub mod rotate_right;
 pub mod shl_checked;
 pub mod shl_wrapped;
 pub mod shr_checked;
 pub mod shr_wrapped;
+pub mod signum;
 pub mod sub_checked;
 pub mod sub_wrapped;
 pub mod ternary;
@@ -0,0 +1,176 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType, M: Magnitude> RotateRight<Integer<E, M>> for Integer<E, I> {
+    type Output = Self;
+
+    /// Rotates `self` to the right by `rhs` bits, modulo the number of bits in `self`.
+    #[inline]
+    fn rotate_right(&self, rhs: &Integer<E, M>) -> Self::Output {
+        // Retrieve the index for the first upper bit from the RHS that we mask.
+        // Note: As `I::BITS` is a power of two, masking the upper bits reduces `rhs` modulo `I::BITS`.
+        let first_upper_bit_index = I::BITS.trailing_zeros() as usize;
+
+        if rhs.is_constant() {
+            // If the rotation amount is a constant, then we can manually rotate the bits.
+            // Note: Casting `rhs` to a `u32` is safe since `Magnitude`s can only be `u8`, `u16`, or `u32`.
+            let rotate_amount = rhs.eject_value().to_u32().unwrap() % I::BITS as u32;
+            let mut bits_le = self.bits_le.clone();
+            bits_le.rotate_left(rotate_amount as usize);
+
+            Self { bits_le, phantom: Default::default() }
+        } else {
+            // Perform the rotation as a barrel shifter over the lower bits of `rhs`.
+            // In each iteration, the bits are rotated right by 2^i, if the i-th bit of `rhs` is set.
+            let mut bits_le = self.bits_le.clone();
+            for (i, bit) in rhs.bits_le[..first_upper_bit_index].iter().enumerate() {
+                let mut rotated = bits_le.clone();
+                rotated.rotate_left(1 << i);
+                bits_le = rotated.iter().zip_eq(&bits_le).map(|(a, b)| Boolean::ternary(bit, a, b)).collect();
+            }
+
+            Self { bits_le, phantom: Default::default() }
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> Metrics<dyn RotateRight<Integer<E, M>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode, bool);
+
+    fn count(case: &Self::Case) -> Count {
+        // The number of stages in the barrel shifter.
+        let num_stages = I::BITS.trailing_zeros() as u64;
+
+        match (case.0, case.1) {
+            (_, Mode::Constant) => Count::is(0, 0, 0, 0),
+            // Note: The first stage only selects between constants, and is thus free.
+            (Mode::Constant, _) => Count::less_than(0, 0, (num_stages - 1) * I::BITS, (num_stages - 1) * I::BITS),
+            (_, _) => Count::is(0, 0, num_stages * I::BITS, num_stages * I::BITS),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> OutputMode<dyn RotateRight<Integer<E, M>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    /// The case is `(mode_a, mode_b, is_a_uniform)`, where `is_a_uniform` indicates all bits of `a` are equal.
+    type Case = (Mode, Mode, bool);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1, case.2) {
+            (Mode::Constant, Mode::Constant, _) => Mode::Constant,
+            (Mode::Constant, _, true) => Mode::Constant,
+            (mode_a, Mode::Constant, _) => mode_a,
+            (_, _, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::{ops::RangeInclusive, panic::RefUnwindSafe};
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_rotate_right<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, M>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let expected = first.rotate_right(&second);
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, M>::new(mode_b, second);
+        let a_is_uniform = *first == I::zero() || *first == !I::zero();
+        Circuit::scope(name, || {
+            let candidate = a.rotate_right(&b);
+            assert_eq!(expected, candidate.eject_value());
+            assert_count!(RotateRight(Integer<I>, Integer<M>) => Integer<I>, &(mode_a, mode_b, a_is_uniform));
+            assert_output_mode!(RotateRight(Integer<I>, Integer<M>) => Integer<I>, &(mode_a, mode_b, a_is_uniform), candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("RotateRight: {mode_a} >>> {mode_b} {i}");
+            check_rotate_right::<I, M>(&name, first, second, mode_a, mode_b);
+
+            // Check the rotation amounts at and around the boundary of the type.
+            for amount in [0, I::BITS - 1, I::BITS, I::BITS + 1, M::MAX.to_u64().unwrap()] {
+                let amount = console::Integer::new(<M as num_traits::NumCast>::from(amount).unwrap());
+                let name = format!("RotateRight: {mode_a} >>> {mode_b} by {amount} {i}");
+                check_rotate_right::<I, M>(&name, first, amount, mode_a, mode_b);
+            }
+        }
+
+        // Check the uniform cases, for which a constant input stays constant.
+        for first in [console::Integer::new(I::zero()), console::Integer::new(!I::zero())] {
+            let second = Uniform::rand(&mut rng);
+            let name = format!("RotateRight: {mode_a} >>> {mode_b} uniform");
+            check_rotate_right::<I, M>(&name, first, second, mode_a, mode_b);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+        RangeInclusive<M>: Iterator<Item = M>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in M::MIN..=M::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, M>::new(second);
+
+                let name = format!("RotateRight: ({first} >>> {second})");
+                check_rotate_right::<I, M>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, u8, u8, rotate_right);
+    test_integer_binary!(run_test, u8, u16, rotate_right);
+    test_integer_binary!(run_test, u8, u32, rotate_right);
+
+    test_integer_binary!(run_test, u16, u8, rotate_right);
+    test_integer_binary!(run_test, u16, u16, rotate_right);
+    test_integer_binary!(run_test, u16, u32, rotate_right);
+
+    test_integer_binary!(run_test, u32, u8, rotate_right);
+    test_integer_binary!(run_test, u32, u16, rotate_right);
+    test_integer_binary!(run_test, u32, u32, rotate_right);
+
+    test_integer_binary!(run_test, u64, u8, rotate_right);
+    test_integer_binary!(run_test, u64, u16, rotate_right);
+    test_integer_binary!(run_test, u64, u32, rotate_right);
+
+    test_integer_binary!(run_test, u128, u8, rotate_right);
+    test_integer_binary!(run_test, u128, u16, rotate_right);
+    test_integer_binary!(run_test, u128, u32, rotate_right);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, u8, rotate_right, exhaustive);
+}
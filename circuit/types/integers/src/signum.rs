@@ -0,0 +1,147 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Signum for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    fn signum(self) -> Self::Output {
+        (&self).signum()
+    }
+}
+
+impl<E: Environment, I: IntegerType> Signum for &Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    /// Returns the `sign` of `self`, as `-1`, `0`, or `1`.
+    ///
+    /// The least significant bit of the result is set if and only if `self` is nonzero,
+    /// and the remaining bits are set if and only if `self` is negative, as `-1` is all ones.
+    fn signum(self) -> Self::Output {
+        // Compute whether `self` is nonzero.
+        let is_nonzero = self.to_field().is_not_equal(&Field::zero());
+
+        // Construct the bits of the sign.
+        let mut bits_le = Vec::with_capacity(I::BITS as usize);
+        bits_le.push(is_nonzero);
+        if I::is_signed() {
+            bits_le.resize(I::BITS as usize, self.msb().clone());
+        }
+
+        // Note: For unsigned integers, the remaining bits are padded with zeros.
+        Integer::from_bits_le(&bits_le)
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn Signum<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            Mode::Constant => Count::is(1, 0, 0, 0),
+            _ => Count::is(0, 0, 2, 2),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn Signum<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::{ops::RangeInclusive, panic::UnwindSafe};
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_signum<I: IntegerType + UnwindSafe>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        let expected = value.signum();
+        Circuit::scope(name, || {
+            let candidate = a.signum();
+            assert_eq!(expected, candidate.eject_value());
+            assert_count!(Signum(Integer<I>) => Integer<I>, &mode);
+            assert_output_mode!(Signum(Integer<I>) => Integer<I>, &mode, candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + UnwindSafe>(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("Signum: {mode} {i}");
+            let value = Uniform::rand(&mut rng);
+            check_signum::<I>(&name, value, mode);
+        }
+
+        // Check the 0 case.
+        let name = format!("Signum: {mode} zero");
+        check_signum::<I>(&name, console::Integer::zero(), mode);
+
+        // Check the 1 case.
+        let name = format!("Signum: {mode} one");
+        check_signum::<I>(&name, console::Integer::one(), mode);
+
+        // Check the console::Integer::MIN case.
+        let name = format!("Signum: {mode} min");
+        check_signum::<I>(&name, console::Integer::MIN, mode);
+
+        // Check the console::Integer::MAX case.
+        let name = format!("Signum: {mode} max");
+        check_signum::<I>(&name, console::Integer::MAX, mode);
+    }
+
+    fn run_exhaustive_test<I: IntegerType + UnwindSafe>(mode: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for value in I::MIN..=I::MAX {
+            let value = console::Integer::<_, I>::new(value);
+
+            let name = format!("Signum: {mode}");
+            check_signum::<I>(&name, value, mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, signum);
+    test_integer_unary!(run_test, i16, signum);
+    test_integer_unary!(run_test, i32, signum);
+    test_integer_unary!(run_test, i64, signum);
+    test_integer_unary!(run_test, i128, signum);
+
+    test_integer_unary!(run_test, u8, signum);
+    test_integer_unary!(run_test, u16, signum);
+    test_integer_unary!(run_test, u32, signum);
+    test_integer_unary!(run_test, u64, signum);
+    test_integer_unary!(run_test, u128, signum);
+
+    test_integer_unary!(#[ignore], run_exhaustive_test, u8, signum, exhaustive);
+    test_integer_unary!(#[ignore], run_exhaustive_test, i8, signum, exhaustive);
+}
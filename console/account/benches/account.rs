@@ -15,7 +15,7 @@
 #[macro_use]
 extern crate criterion;
 
-use snarkvm_console_account::{Address, PrivateKey, ViewKey};
+use snarkvm_console_account::{Address, Field, PrivateKey, Signature, ViewKey};
 use snarkvm_console_network::{environment::prelude::*, Testnet3};
 
 use criterion::Criterion;
@@ -56,10 +56,54 @@ fn account_address(c: &mut Criterion) {
     });
 }
 
+/// A tuple of `(address, message, signature)`.
+type SignedMessage = (Address<CurrentNetwork>, Vec<Field<CurrentNetwork>>, Signature<CurrentNetwork>);
+
+/// Samples the given number of `(address, message, signature)` tuples.
+fn sample_signatures(num_signatures: usize, rng: &mut TestRng) -> Vec<SignedMessage> {
+    (0..num_signatures)
+        .map(|_| {
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+            let address = Address::try_from(&private_key).unwrap();
+            let message: Vec<_> = (0..4).map(|_| Uniform::rand(rng)).collect();
+            let signature = Signature::sign(&private_key, &message, rng).unwrap();
+            (address, message, signature)
+        })
+        .collect()
+}
+
+fn signature_verify(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    for num_signatures in [16, 64, 256] {
+        let signatures = sample_signatures(num_signatures, rng);
+
+        c.bench_function(&format!("signature_verify_{num_signatures}"), |b| {
+            b.iter(|| {
+                assert!(signatures.iter().all(|(address, message, signature)| signature.verify(address, message)));
+            })
+        });
+    }
+}
+
+fn signature_verify_batch(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    for num_signatures in [16, 64, 256] {
+        let signatures = sample_signatures(num_signatures, rng);
+        let items: Vec<_> =
+            signatures.iter().map(|(address, message, signature)| (address, message.as_slice(), signature)).collect();
+
+        c.bench_function(&format!("signature_verify_batch_{num_signatures}"), |b| {
+            b.iter(|| assert!(Signature::verify_batch(&items, rng)))
+        });
+    }
+}
+
 criterion_group! {
     name = account;
     config = Criterion::default().sample_size(20);
-    targets = account_private_key, account_view_key, account_address
+    targets = account_private_key, account_view_key, account_address, signature_verify, signature_verify_batch
 }
 
 criterion_main!(account);
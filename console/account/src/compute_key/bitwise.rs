@@ -14,6 +14,14 @@
 
 use super::*;
 
+impl<N: Network> ComputeKey<N> {
+    /// Returns `true` if `self` and `other` are equal, in constant time.
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        // Note: The non-short-circuiting `&` is used to compare every component.
+        self.pk_sig.ct_eq(&other.pk_sig) & self.pr_sig.ct_eq(&other.pr_sig) & self.sk_prf.ct_eq(&other.sk_prf)
+    }
+}
+
 impl<N: Network> Equal for ComputeKey<N> {
     type Output = Boolean<N>;
 
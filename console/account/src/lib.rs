@@ -240,4 +240,38 @@ mod tests {
             assert_eq!(expected_signature, Signature::<CurrentNetwork>::read_le(&expected_bytes[..]).unwrap());
         }
     }
+
+    #[test]
+    fn test_ct_eq() {
+        let mut rng = TestRng::default();
+
+        // Sample the first account.
+        let mut private_key = PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap();
+        let mut view_key = ViewKey::try_from(&private_key).unwrap();
+        let mut address = Address::try_from(&private_key).unwrap();
+        let mut signature = private_key.sign_bytes(b"message", &mut rng).unwrap();
+
+        for i in 0..ITERATIONS {
+            // Sample another account, or reuse the previous one.
+            let (other_private_key, other_signature) = match i % 2 == 0 {
+                true => (private_key, signature),
+                false => {
+                    let other_private_key = PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap();
+                    (other_private_key, other_private_key.sign_bytes(b"message", &mut rng).unwrap())
+                }
+            };
+            let other_view_key = ViewKey::try_from(&other_private_key).unwrap();
+            let other_address = Address::try_from(&other_private_key).unwrap();
+
+            // Check that the constant-time equality agrees with `==`.
+            assert_eq!(private_key.ct_eq(&other_private_key), private_key == other_private_key);
+            assert_eq!(view_key.ct_eq(&other_view_key), view_key == other_view_key);
+            assert_eq!(address.ct_eq(&other_address), address == other_address);
+            assert_eq!(signature.ct_eq(&other_signature), signature == other_signature);
+
+            // Use the other account in the next iteration.
+            (private_key, view_key, address, signature) =
+                (other_private_key, other_view_key, other_address, other_signature);
+        }
+    }
 }
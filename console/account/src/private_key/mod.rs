@@ -57,4 +57,10 @@ impl<N: Network> PrivateKey<N> {
     pub const fn r_sig(&self) -> Scalar<N> {
         self.r_sig
     }
+
+    /// Returns `true` if `self` and `other` are equal, in constant time.
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        // Note: The non-short-circuiting `&` is used to compare every component.
+        self.seed.ct_eq(&other.seed) & self.sk_sig.ct_eq(&other.sk_sig) & self.r_sig.ct_eq(&other.r_sig)
+    }
 }
@@ -14,6 +14,16 @@
 
 use super::*;
 
+impl<N: Network> Signature<N> {
+    /// Returns `true` if `self` and `other` are equal, in constant time.
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        // Note: The non-short-circuiting `&` is used to compare every component.
+        self.challenge.ct_eq(&other.challenge)
+            & self.response.ct_eq(&other.response)
+            & self.compute_key.ct_eq(&other.compute_key)
+    }
+}
+
 impl<N: Network> Equal for Signature<N> {
     type Output = Boolean<N>;
 
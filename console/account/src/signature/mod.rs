@@ -21,6 +21,7 @@ mod size_in_bits;
 mod to_bits;
 mod to_fields;
 mod verify;
+mod verify_batch;
 
 #[cfg(feature = "private_key")]
 mod sign;
@@ -32,7 +33,7 @@ use crate::PrivateKey;
 
 use crate::address::Address;
 use snarkvm_console_network::prelude::*;
-use snarkvm_console_types::{Boolean, Field, Scalar};
+use snarkvm_console_types::{Boolean, Field, Group, Scalar};
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Signature<N: Network> {
@@ -18,6 +18,26 @@ impl<N: Network> Signature<N> {
     /// Verifies (challenge == challenge') && (address == address') where:
     ///     challenge' := HashToScalar(G^response pk_sig^challenge, pk_sig, pr_sig, address, message)
     pub fn verify(&self, address: &Address<N>, message: &[Field<N>]) -> bool {
+        // Ensure the verifier challenge is correct.
+        if !self.verify_challenge(address, message) {
+            return false;
+        }
+
+        // Derive the address from the compute key, and return `false` if this operation fails.
+        let candidate_address = match Address::try_from(self.compute_key) {
+            // Output the computed candidate address.
+            Ok(candidate_address) => candidate_address,
+            // Return `false` if the address errored.
+            Err(_) => return false,
+        };
+
+        // Return `true` if the candidate address is correct.
+        address.ct_eq(&candidate_address)
+    }
+
+    /// Verifies (challenge == challenge') where:
+    ///     challenge' := HashToScalar(G^response pk_sig^challenge, pk_sig, pr_sig, address, message)
+    pub(super) fn verify_challenge(&self, address: &Address<N>, message: &[Field<N>]) -> bool {
         // Ensure the number of field elements does not exceed the maximum allowed size.
         if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
             eprintln!("Cannot sign the signature: the signed message exceeds maximum allowed size");
@@ -45,16 +65,8 @@ impl<N: Network> Signature<N> {
             Err(_) => return false,
         };
 
-        // Derive the address from the compute key, and return `false` if this operation fails.
-        let candidate_address = match Address::try_from(self.compute_key) {
-            // Output the computed candidate address.
-            Ok(candidate_address) => candidate_address,
-            // Return `false` if the address errored.
-            Err(_) => return false,
-        };
-
-        // Return `true` if the candidate challenge and address are correct.
-        self.challenge == candidate_challenge && *address == candidate_address
+        // Return `true` if the candidate challenge is correct.
+        self.challenge.ct_eq(&candidate_challenge)
     }
 
     /// Verifies a signature for the given address and message (as bytes).
@@ -0,0 +1,225 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_algorithms::msm::VariableBase;
+
+/// A signature to verify in a batch, given as a tuple of `(address, message, signature)`.
+type BatchItem<'a, N> = (&'a Address<N>, &'a [Field<N>], &'a Signature<N>);
+
+impl<N: Network> Signature<N> {
+    /// Verifies a batch of signatures, given as tuples of `(address, message, signature)`.
+    ///
+    /// The verifier challenge of each signature is checked individually, as it commits to the
+    /// (unknown) nonce of the signature. The address of each signature is then checked at once,
+    /// using a random linear combination over the group with 128-bit coefficients:
+    ///     sum_i r_i * (address_i - pk_sig_i - pr_sig_i) == (sum_i r_i * sk_prf_i) * G
+    pub fn verify_batch<R: Rng + CryptoRng>(items: &[BatchItem<N>], rng: &mut R) -> bool {
+        // Ensure the verifier challenge of each signature is correct.
+        if !items.iter().all(|(address, message, signature)| signature.verify_challenge(address, message)) {
+            return false;
+        }
+        // Ensure the address of each signature is correct.
+        Self::verify_addresses(items, rng)
+    }
+
+    /// Verifies a batch of signatures, given as tuples of `(address, message, signature)`,
+    /// and returns the indices of the invalid signatures, in ascending order.
+    /// If the batch check of the addresses fails, the batch is bisected to locate the invalid signatures.
+    pub fn verify_batch_with_report<R: Rng + CryptoRng>(items: &[BatchItem<N>], rng: &mut R) -> Vec<usize> {
+        let mut invalid_indices = Vec::new();
+        // Check the verifier challenge of each signature once, as it is not rechecked when bisecting.
+        let (candidates, candidate_indices): (Vec<_>, Vec<_>) = items
+            .iter()
+            .enumerate()
+            .filter_map(|(index, item @ (address, message, signature))| {
+                match signature.verify_challenge(address, message) {
+                    true => Some((*item, index)),
+                    false => {
+                        invalid_indices.push(index);
+                        None
+                    }
+                }
+            })
+            .unzip();
+        // Check the addresses of the remaining signatures.
+        Self::bisect_addresses(&candidates, &candidate_indices, &mut invalid_indices, rng);
+        // Return the invalid indices in ascending order.
+        invalid_indices.sort_unstable();
+        invalid_indices
+    }
+
+    /// Verifies the addresses of the given batch, and bisects it on failure to collect the indices of the invalid signatures.
+    fn bisect_addresses<R: Rng + CryptoRng>(
+        items: &[BatchItem<N>],
+        indices: &[usize],
+        invalid_indices: &mut Vec<usize>,
+        rng: &mut R,
+    ) {
+        match items.len() {
+            0 => (),
+            // If the batch is a single signature, check its address directly.
+            1 => {
+                let (address, _, signature) = items[0];
+                if !Address::try_from(signature.compute_key).map_or(false, |candidate| address.ct_eq(&candidate)) {
+                    invalid_indices.push(indices[0]);
+                }
+            }
+            // Otherwise, check the batch, and bisect it on failure.
+            num_items => {
+                if !Self::verify_addresses(items, rng) {
+                    let (left, right) = items.split_at(num_items / 2);
+                    let (left_indices, right_indices) = indices.split_at(num_items / 2);
+                    Self::bisect_addresses(left, left_indices, invalid_indices, rng);
+                    Self::bisect_addresses(right, right_indices, invalid_indices, rng);
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if the address of each signature is derived from its compute key, using one
+    /// multi-scalar multiplication over the batch with random 128-bit coefficients.
+    fn verify_addresses<R: Rng + CryptoRng>(items: &[BatchItem<N>], rng: &mut R) -> bool {
+        let mut bases = Vec::with_capacity(items.len());
+        let mut coefficients = Vec::with_capacity(items.len());
+        let mut accumulated_scalar = Scalar::<N>::zero();
+
+        for (address, _, signature) in items {
+            // Sample a random 128-bit coefficient.
+            let coefficient = Scalar::<N>::new(N::Scalar::from(rng.gen::<u128>()));
+
+            // Retrieve the compute key.
+            let compute_key = signature.compute_key;
+            // Compute (address_i - pk_sig_i - pr_sig_i).
+            bases.push(*(***address - compute_key.pk_sig() - compute_key.pr_sig()));
+            coefficients.push(coefficient.to_bigint());
+            // Accumulate r_i * sk_prf_i.
+            accumulated_scalar += compute_key.sk_prf() * coefficient;
+        }
+
+        // Compute sum_i r_i * (address_i - pk_sig_i - pr_sig_i).
+        let bases = N::Projective::batch_normalization_into_affine(bases);
+        let accumulated_group = Group::<N>::new(VariableBase::msm(&bases, &coefficients).to_affine());
+
+        // Return `true` if the random linear combination of the addresses is correct.
+        accumulated_group.ct_eq(&N::g_scalar_multiply(&accumulated_scalar))
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "private_key")]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 10;
+    const BATCH_SIZE: usize = 16;
+
+    type OwnedBatchItem = (Address<CurrentNetwork>, Vec<Field<CurrentNetwork>>, Signature<CurrentNetwork>);
+
+    /// Samples a batch of valid `(address, message, signature)` tuples.
+    fn sample_batch(rng: &mut TestRng) -> Result<Vec<OwnedBatchItem>> {
+        (0..BATCH_SIZE)
+            .map(|i| {
+                // Sample an address and a private key.
+                let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+                let address = Address::try_from(&private_key)?;
+                // Sign a random message.
+                let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+                let signature = Signature::sign(&private_key, &message, rng)?;
+                Ok((address, message, signature))
+            })
+            .collect()
+    }
+
+    /// Returns the batch as a vector of borrowed tuples.
+    fn to_items(batch: &[OwnedBatchItem]) -> Vec<BatchItem<CurrentNetwork>> {
+        batch.iter().map(|(address, message, signature)| (address, message.as_slice(), signature)).collect()
+    }
+
+    #[test]
+    fn test_verify_batch() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a batch, and corrupt a random subset of it.
+            let mut batch = sample_batch(rng)?;
+            for (_, message, _) in batch.iter_mut() {
+                if rng.gen_bool(0.2) {
+                    message.push(Uniform::rand(rng));
+                }
+            }
+            let items = to_items(&batch);
+
+            // Check that the batch verification agrees with the individual verifications.
+            let expected_invalid_indices: Vec<_> = items
+                .iter()
+                .enumerate()
+                .filter(|(_, (address, message, signature))| !signature.verify(address, message))
+                .map(|(index, _)| index)
+                .collect();
+            assert_eq!(Signature::verify_batch(&items, rng), expected_invalid_indices.is_empty());
+            assert_eq!(Signature::verify_batch_with_report(&items, rng), expected_invalid_indices);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_batch_single_forgery() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a valid batch.
+        let batch = sample_batch(rng)?;
+        let items = to_items(&batch);
+        assert!(Signature::verify_batch(&items, rng));
+        assert!(Signature::verify_batch_with_report(&items, rng).is_empty());
+
+        // Forge a signature for the address of another signer, using a different private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let forged_signature = Signature::sign(&private_key, items[5].1, rng)?;
+
+        let mut forged_items = items.clone();
+        forged_items[5].2 = &forged_signature;
+        assert!(!Signature::verify_batch(&forged_items, rng));
+        assert_eq!(Signature::verify_batch_with_report(&forged_items, rng), vec![5]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_batch_swapped_signatures() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a valid batch.
+        let batch = sample_batch(rng)?;
+        let items = to_items(&batch);
+        assert!(Signature::verify_batch(&items, rng));
+
+        // Swap two of the signatures.
+        let mut swapped_items = items.clone();
+        swapped_items[2].2 = items[7].2;
+        swapped_items[7].2 = items[2].2;
+        assert!(!Signature::verify_batch(&swapped_items, rng));
+        assert_eq!(Signature::verify_batch_with_report(&swapped_items, rng), vec![2, 7]);
+
+        // Swap two of the messages.
+        let mut swapped_items = items.clone();
+        swapped_items[2].1 = items[7].1;
+        swapped_items[7].1 = items[2].1;
+        assert!(!Signature::verify_batch(&swapped_items, rng));
+        assert_eq!(Signature::verify_batch_with_report(&swapped_items, rng), vec![2, 7]);
+        Ok(())
+    }
+}
@@ -37,6 +37,11 @@ impl<N: Network> ViewKey<N> {
     pub const fn from_scalar(view_key: Scalar<N>) -> Self {
         Self(view_key)
     }
+
+    /// Returns `true` if `self` and `other` are equal, in constant time.
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        self.0.ct_eq(&other.0)
+    }
 }
 
 impl<N: Network> Deref for ViewKey<N> {
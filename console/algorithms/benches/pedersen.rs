@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_console_algorithms::{Pedersen128, Pedersen64};
+use snarkvm_console_types::prelude::*;
+use snarkvm_utilities::{TestRng, Uniform};
+
+use criterion::Criterion;
+
+const NUM_INPUTS: usize = 4096;
+
+fn pedersen64(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+    let hash = Pedersen64::<Console>::setup("Pedersen64");
+
+    let inputs = (0..NUM_INPUTS).map(|_| (0..64).map(|_| bool::rand(rng)).collect::<Vec<_>>()).collect::<Vec<_>>();
+    c.bench_function(&format!("Pedersen64 Hash - {NUM_INPUTS} inputs"), |b| {
+        b.iter(|| inputs.iter().map(|input| hash.hash(input)).collect::<Result<Vec<_>>>())
+    });
+    c.bench_function(&format!("Pedersen64 Hash Batch - {NUM_INPUTS} inputs"), |b| b.iter(|| hash.hash_batch(&inputs)));
+
+    // The constant-time hash performs the same number of additions for the all-zero and all-one inputs.
+    for bit in [false, true] {
+        let input = vec![bit; 64];
+        c.bench_function(&format!("Pedersen64 Hash Constant Time - all {}", bit as u8), |b| {
+            b.iter(|| hash.hash_constant_time(&input))
+        });
+        c.bench_function(&format!("Pedersen64 Hash Variable Time - all {}", bit as u8), |b| {
+            b.iter(|| hash.hash(&input))
+        });
+    }
+}
+
+fn pedersen128(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+    let hash = Pedersen128::<Console>::setup("Pedersen128");
+
+    let inputs = (0..NUM_INPUTS).map(|_| (0..128).map(|_| bool::rand(rng)).collect::<Vec<_>>()).collect::<Vec<_>>();
+    c.bench_function(&format!("Pedersen128 Hash - {NUM_INPUTS} inputs"), |b| {
+        b.iter(|| inputs.iter().map(|input| hash.hash(input)).collect::<Result<Vec<_>>>())
+    });
+    c.bench_function(&format!("Pedersen128 Hash Batch - {NUM_INPUTS} inputs"), |b| b.iter(|| hash.hash_batch(&inputs)));
+
+    // The constant-time hash performs the same number of additions for the all-zero and all-one inputs.
+    for bit in [false, true] {
+        let input = vec![bit; 128];
+        c.bench_function(&format!("Pedersen128 Hash Constant Time - all {}", bit as u8), |b| {
+            b.iter(|| hash.hash_constant_time(&input))
+        });
+        c.bench_function(&format!("Pedersen128 Hash Variable Time - all {}", bit as u8), |b| {
+            b.iter(|| hash.hash(&input))
+        });
+    }
+}
+
+criterion_group! {
+    name = pedersen;
+    config = Criterion::default().sample_size(10);
+    targets = pedersen64, pedersen128
+}
+
+criterion_main!(pedersen);
@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
+    /// Returns the Pedersen hash of the given input as a field element,
+    /// performing the same sequence of group operations for every input of a given length.
+    /// This method should be used in place of `hash` when the input is secret.
+    pub fn hash_constant_time(&self, input: &[bool]) -> Result<Field<E>> {
+        // Compute the Pedersen hash as an affine group element, and return the x-coordinate.
+        Ok(self.hash_uncompressed_constant_time(input)?.to_x_coordinate())
+    }
+
+    /// Returns the Pedersen hash of the given input as a group element,
+    /// performing the same sequence of group operations for every input of a given length.
+    /// This method should be used in place of `hash_uncompressed` when the input is secret.
+    ///
+    /// The variable-time path only adds the bases for the set bits, so its running time leaks
+    /// the Hamming weight of the input. This method instead performs exactly `NUM_BITS` additions,
+    /// selecting between the base and the identity by index rather than by branching on the bit.
+    /// As the twisted Edwards addition formula is unified, adding the identity costs the same as adding a base.
+    /// For uniformly random inputs, this is roughly twice the cost of `hash_uncompressed`.
+    pub fn hash_uncompressed_constant_time(&self, input: &[bool]) -> Result<Group<E>> {
+        // Ensure the input size is within the parameter size.
+        if input.len() > NUM_BITS as usize {
+            bail!("Invalid input size for Pedersen: expected <= {NUM_BITS}, found {}", input.len())
+        }
+
+        // Compute sum of h_i^{m_i} for all i, where the padded bits select the identity.
+        let zero = Group::<E>::zero();
+        Ok(self.base_window.iter().enumerate().fold(zero, |accumulator, (i, base)| {
+            let bit = input.get(i).copied().unwrap_or(false);
+            accumulator + [zero, *base][bit as usize]
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: usize = 100;
+
+    fn check_constant_time<const NUM_BITS: u8>() -> Result<()> {
+        let pedersen = Pedersen::<CurrentEnvironment, NUM_BITS>::setup("PedersenTest");
+
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random input of a random length.
+            let num_bits = rng.gen_range(0..=NUM_BITS as usize);
+            let input = (0..num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+
+            // Ensure the constant-time hashes equal the variable-time hashes.
+            assert_eq!(pedersen.hash_uncompressed(&input)?, pedersen.hash_uncompressed_constant_time(&input)?);
+            assert_eq!(pedersen.hash(&input)?, pedersen.hash_constant_time(&input)?);
+        }
+
+        // Ensure the all-zero and all-one inputs match.
+        for bit in [false, true] {
+            let input = vec![bit; NUM_BITS as usize];
+            assert_eq!(pedersen.hash_uncompressed(&input)?, pedersen.hash_uncompressed_constant_time(&input)?);
+        }
+
+        // Ensure an oversized input fails.
+        assert!(pedersen.hash_constant_time(&vec![false; NUM_BITS as usize + 1]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_constant_time_64() -> Result<()> {
+        check_constant_time::<64>()
+    }
+
+    #[test]
+    fn test_hash_constant_time_128() -> Result<()> {
+        check_constant_time::<128>()
+    }
+}
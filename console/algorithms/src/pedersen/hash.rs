@@ -23,4 +23,46 @@ impl<E: Environment, const NUM_BITS: u8> Hash for Pedersen<E, NUM_BITS> {
         // Compute the Pedersen hash as an affine group element, and return the x-coordinate.
         Ok(self.hash_uncompressed(input)?.to_x_coordinate())
     }
+
+    /// Returns the Pedersen hashes of the given inputs as field elements.
+    fn hash_batch(&self, inputs: &[Vec<Self::Input>]) -> Result<Vec<Self::Output>> {
+        // Compute the Pedersen hashes as affine group elements, and return the x-coordinates.
+        Ok(cfg_into_iter!(self.hash_uncompressed_batch(inputs)?).map(|hash| hash.to_x_coordinate()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_hash_batch() -> Result<()> {
+        let pedersen = Pedersen64::<CurrentEnvironment>::setup("PedersenTest");
+
+        let mut rng = TestRng::default();
+
+        // Sample random inputs of varying lengths.
+        let inputs = (0..ITERATIONS)
+            .map(|_| {
+                let num_bits = rng.gen_range(0..=64);
+                (0..num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        // Ensure the batch hashes equal the sequential hashes.
+        let expected = inputs.iter().map(|input| pedersen.hash(input)).collect::<Result<Vec<_>>>()?;
+        assert_eq!(expected, pedersen.hash_batch(&inputs)?);
+        assert!(pedersen.hash_batch(&[])?.is_empty());
+
+        // Ensure an oversized input fails the whole batch.
+        let mut inputs = inputs;
+        inputs.push(vec![true; 65]);
+        assert!(pedersen.hash_batch(&inputs).is_err());
+        Ok(())
+    }
 }
@@ -38,4 +38,64 @@ impl<E: Environment, const NUM_BITS: u8> HashUncompressed for Pedersen<E, NUM_BI
             })
             .sum())
     }
+
+    /// Returns the Pedersen hashes of the given inputs as group elements.
+    /// Note: The input sizes are validated before any hash is computed, and the base window is shared across threads.
+    fn hash_uncompressed_batch(&self, inputs: &[Vec<Self::Input>]) -> Result<Vec<Self::Output>> {
+        // Ensure the input sizes are within the parameter size.
+        if let Some(input) = inputs.iter().find(|input| input.len() > NUM_BITS as usize) {
+            bail!("Invalid input size for Pedersen: expected <= {NUM_BITS}, found {}", input.len())
+        }
+
+        // Compute sum of h_i^{m_i} for all i, for each input.
+        // Note: Padding the inputs is unnecessary, as the padded bits do not contribute to the sum.
+        let base_window = &*self.base_window;
+        Ok(cfg_iter!(inputs)
+            .map(|input| {
+                input
+                    .iter()
+                    .zip(base_window)
+                    .flat_map(|(bit, base)| match bit {
+                        true => Some(*base),
+                        false => None,
+                    })
+                    .sum()
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_hash_uncompressed_batch() -> Result<()> {
+        let pedersen = Pedersen128::<CurrentEnvironment>::setup("PedersenTest");
+
+        let mut rng = TestRng::default();
+
+        // Sample random inputs of varying lengths.
+        let inputs = (0..ITERATIONS)
+            .map(|_| {
+                let num_bits = rng.gen_range(0..=128);
+                (0..num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        // Ensure the batch hashes equal the sequential hashes.
+        let expected = inputs.iter().map(|input| pedersen.hash_uncompressed(input)).collect::<Result<Vec<_>>>()?;
+        assert_eq!(expected, pedersen.hash_uncompressed_batch(&inputs)?);
+
+        // Ensure an oversized input fails the whole batch.
+        let mut inputs = inputs;
+        inputs.insert(0, vec![false; 129]);
+        assert!(pedersen.hash_uncompressed_batch(&inputs).is_err());
+        Ok(())
+    }
 }
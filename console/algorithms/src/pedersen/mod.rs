@@ -14,6 +14,7 @@
 
 mod commit;
 mod commit_uncompressed;
+mod constant_time;
 mod hash;
 mod hash_uncompressed;
 
@@ -22,6 +23,9 @@ use snarkvm_console_types::prelude::*;
 
 use std::{borrow::Cow, sync::Arc};
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 /// Pedersen64 is an *additively-homomorphic* collision-resistant hash function that takes up to a 64-bit input.
 pub type Pedersen64<E> = Pedersen<E, 64>;
 /// Pedersen128 is an *additively-homomorphic* collision-resistant hash function that takes up to a 128-bit input.
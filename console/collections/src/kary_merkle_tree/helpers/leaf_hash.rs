@@ -80,8 +80,6 @@ impl<const TYPE: u8, const VARIANT: usize> LeafHash for Keccak<TYPE, VARIANT> {
         // Hash the input.
         let output = Hash::hash(self, &input)?;
         // Read the first VARIANT bits.
-        let mut result = BooleanHash::new();
-        result.0.copy_from_slice(&output[..VARIANT]);
-        Ok(result)
+        BooleanHash::from_keccak_output(&output)
     }
 }
@@ -28,6 +28,17 @@ impl<const VARIANT: usize> BooleanHash<VARIANT> {
     pub const fn new() -> Self {
         Self([false; VARIANT])
     }
+
+    /// Initializes a boolean hash from the first `VARIANT` bits of the given Keccak output.
+    /// Note: The circuit applies the same truncation rule, which is the identity for the standard variants.
+    pub fn from_keccak_output(output: &[bool]) -> Result<Self> {
+        // Ensure the output contains at least `VARIANT` bits.
+        ensure!(output.len() >= VARIANT, "Keccak output must contain at least {VARIANT} bits, found {}", output.len());
+        // Read the first VARIANT bits.
+        let mut result = Self::new();
+        result.0.copy_from_slice(&output[..VARIANT]);
+        Ok(result)
+    }
 }
 
 impl<const VARIANT: usize> Default for BooleanHash<VARIANT> {
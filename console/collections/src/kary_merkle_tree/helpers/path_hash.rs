@@ -27,10 +27,8 @@ pub trait PathHash: Clone + Send + Sync {
     fn hash_children(&self, children: &[Self::Hash]) -> Result<Self::Hash>;
 
     /// Returns the empty hash.
-    fn hash_empty<const ARITY: u8>(&self) -> Result<Self::Hash> {
-        let children = vec![Self::Hash::default(); ARITY as usize];
-        self.hash_children(&children)
-    }
+    /// Note: The empty hash must be domain-separated from the hash of `ARITY` default child nodes.
+    fn hash_empty<const ARITY: u8>(&self) -> Result<Self::Hash>;
 
     /// Returns the hash for each tuple of child nodes.
     fn hash_all_children(&self, child_nodes: &[Vec<Self::Hash>]) -> Result<Vec<Self::Hash>> {
@@ -56,6 +54,19 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> PathHash for
         // Hash the input.
         Hash::hash(self, &input)
     }
+
+    /// Returns the empty hash.
+    fn hash_empty<const ARITY: u8>(&self) -> Result<Self::Hash> {
+        let mut input = Vec::new();
+        // Prepend the empty nodes with two `true` bits, to separate them from the child nodes.
+        input.push(true);
+        input.push(true);
+        for child in vec![Self::Hash::default(); ARITY as usize] {
+            child.write_bits_le(&mut input);
+        }
+        // Hash the input.
+        Hash::hash(self, &input)
+    }
 }
 
 impl<E: Environment, const RATE: usize> PathHash for Poseidon<E, RATE> {
@@ -72,6 +83,16 @@ impl<E: Environment, const RATE: usize> PathHash for Poseidon<E, RATE> {
         // Hash the input.
         Hash::hash(self, &input)
     }
+
+    /// Returns the empty hash.
+    fn hash_empty<const ARITY: u8>(&self) -> Result<Self::Hash> {
+        let mut input = Vec::with_capacity(1 + ARITY as usize);
+        // Prepend the empty nodes with a `2field` element, to separate them from the child nodes.
+        input.push(Self::Hash::one().double());
+        input.resize(1 + ARITY as usize, Self::Hash::default());
+        // Hash the input.
+        Hash::hash(self, &input)
+    }
 }
 
 impl<const TYPE: u8, const VARIANT: usize> PathHash for Keccak<TYPE, VARIANT> {
@@ -88,8 +109,19 @@ impl<const TYPE: u8, const VARIANT: usize> PathHash for Keccak<TYPE, VARIANT> {
         // Hash the input.
         let output = Hash::hash(self, &input)?;
         // Read the first VARIANT bits.
-        let mut result = BooleanHash::new();
-        result.0.copy_from_slice(&output[..VARIANT]);
-        Ok(result)
+        BooleanHash::from_keccak_output(&output)
+    }
+
+    /// Returns the empty hash.
+    fn hash_empty<const ARITY: u8>(&self) -> Result<Self::Hash> {
+        let mut input = Vec::with_capacity(2 + ARITY as usize * VARIANT);
+        // Prepend the empty nodes with two `true` bits, to separate them from the child nodes.
+        input.push(true);
+        input.push(true);
+        input.resize(2 + ARITY as usize * VARIANT, false);
+        // Hash the input.
+        let output = Hash::hash(self, &input)?;
+        // Read the first VARIANT bits.
+        BooleanHash::from_keccak_output(&output)
     }
 }
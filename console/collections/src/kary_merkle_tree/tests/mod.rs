@@ -13,7 +13,17 @@
 // limitations under the License.
 
 use super::*;
-use snarkvm_console_algorithms::{Keccak256, Poseidon, Sha3_256, BHP1024, BHP512};
+use snarkvm_console_algorithms::{
+    Keccak256,
+    Keccak384,
+    Keccak512,
+    Poseidon,
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
+    BHP1024,
+    BHP512,
+};
 use snarkvm_console_types::prelude::Console;
 
 type CurrentEnvironment = Console;
@@ -259,6 +269,29 @@ fn check_merkle_tree_depth_3_arity_3_padded<LH: LeafHash<Hash = PH::Hash>, PH: P
     Ok(())
 }
 
+/// Runs the following test:
+/// 1. Construct an empty depth-2 arity-3 Merkle tree.
+/// 2. Checks that its root differs from the root of a tree whose leaves are all default hashes.
+fn check_merkle_tree_empty_is_domain_separated<LH: LeafHash<Hash = PH::Hash>, PH: PathHash>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+) -> Result<()> {
+    const ARITY: u8 = 3;
+
+    // Ensure the empty hash differs from the hash of default child nodes.
+    let default_children = vec![PH::Hash::default(); ARITY as usize];
+    let zero_node = path_hasher.hash_children(&default_children)?;
+    assert_ne!(path_hasher.hash_empty::<ARITY>()?, zero_node);
+
+    // Construct the empty Merkle tree.
+    let merkle_tree = KaryMerkleTree::<LH, PH, 2, ARITY>::new(leaf_hasher, path_hasher, &[])?;
+
+    // Compute the root of the Merkle tree whose leaves are all default hashes.
+    let zero_root = path_hasher.hash_children(&vec![zero_node; ARITY as usize])?;
+    assert_ne!(zero_root, *merkle_tree.root());
+    Ok(())
+}
+
 #[test]
 fn test_kary_merkle_tree_bhp() -> Result<()> {
     fn run_test<const DEPTH: u8, const ARITY: u8>(rng: &mut TestRng) -> Result<()> {
@@ -441,6 +474,51 @@ fn test_kary_merkle_tree_sha3() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_kary_merkle_tree_keccak_variants() -> Result<()> {
+    fn run_test<H: LeafHash<Hash = <H as PathHash>::Hash, Leaf = Vec<bool>> + PathHash + Default>(
+        rng: &mut TestRng,
+    ) -> Result<()> {
+        let hasher = H::default();
+        for i in 0..ITERATIONS {
+            // Determine the number of leaves.
+            let num_leaves = rng.gen_range(0..64u128);
+            println!("Iteration {i} - Testing a depth 3 arity 4 tree with {num_leaves} leaves");
+
+            // Check the Merkle tree.
+            check_kary_merkle_tree::<H, H, 3, 4>(
+                &hasher,
+                &hasher,
+                &(0..num_leaves)
+                    .map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le())
+                    .collect::<Vec<Vec<bool>>>(),
+            )?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    run_test::<Keccak384>(&mut rng)?;
+    run_test::<Keccak512>(&mut rng)?;
+    run_test::<Sha3_384>(&mut rng)?;
+    run_test::<Sha3_512>(&mut rng)
+}
+
+#[test]
+fn test_boolean_hash_from_keccak_output() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Ensure the first VARIANT bits are read.
+    let output = (0..512).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+    assert_eq!(output[..256], *BooleanHash::<256>::from_keccak_output(&output)?);
+    assert_eq!(output, *BooleanHash::<512>::from_keccak_output(&output)?);
+
+    // Ensure a short output fails.
+    assert!(BooleanHash::<512>::from_keccak_output(&output[..511]).is_err());
+    Ok(())
+}
+
 #[test]
 fn test_merkle_tree_depth_2_arity_3_bhp() -> Result<()> {
     type LH = BHP1024<CurrentEnvironment>;
@@ -589,6 +667,23 @@ fn test_merkle_tree_depth_3_arity_3_sha3() -> Result<()> {
     )
 }
 
+#[test]
+fn test_merkle_tree_empty_is_domain_separated() -> Result<()> {
+    // Check BHP.
+    let leaf_hasher = BHP1024::<CurrentEnvironment>::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = BHP512::<CurrentEnvironment>::setup("AleoMerkleTreeTest1")?;
+    check_merkle_tree_empty_is_domain_separated(&leaf_hasher, &path_hasher)?;
+
+    // Check Poseidon.
+    let leaf_hasher = Poseidon::<CurrentEnvironment, 4>::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = Poseidon::<CurrentEnvironment, 3>::setup("AleoMerkleTreeTest1")?;
+    check_merkle_tree_empty_is_domain_separated(&leaf_hasher, &path_hasher)?;
+
+    // Check Keccak and SHA-3.
+    check_merkle_tree_empty_is_domain_separated(&Keccak256::default(), &Keccak256::default())?;
+    check_merkle_tree_empty_is_domain_separated(&Sha3_256::default(), &Sha3_256::default())
+}
+
 #[test]
 fn test_kary_merkle_tree_size_is_within_bounds() -> Result<()> {
     fn run_test<const DEPTH: u8, const ARITY: u8>(num_leaves: u128, rng: &mut TestRng) -> Result<()> {
@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::prelude::{Deserialize, DeserializeOwned, Serialize};
+use crate::{
+    prelude::{Deserialize, DeserializeOwned, Serialize},
+    Halt,
+};
 use snarkvm_curves::{
     bls12_377::Bls12_377,
     edwards_bls12::{EdwardsAffine, EdwardsParameters},
@@ -58,7 +61,7 @@ pub trait Environment:
 
     /// Halts the program from further synthesis, evaluation, and execution in the current environment.
     fn halt<S: Into<String>, T>(message: S) -> T {
-        panic!("{}", message.into())
+        Halt::unwind(message)
     }
 }
 
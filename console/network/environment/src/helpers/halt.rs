@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::{any::Any, cell::Cell, fmt};
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+
+thread_local! {
+    /// The number of nested `Halt::catch` scopes on the current thread.
+    static CATCH_DEPTH: Cell<usize> = Cell::new(0);
+}
+
+/// The payload that `Environment::halt` unwinds with, inside of `Halt::catch`.
+///
+/// Callers that catch the unwind may downcast the payload to `Halt`, to distinguish
+/// a deterministic halt of the program from a genuine panic.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Halt(String);
+
+impl Halt {
+    /// Initializes a new halt with the given message.
+    pub fn new<S: Into<String>>(message: S) -> Self {
+        Self(message.into())
+    }
+
+    /// Returns the halt message.
+    pub fn message(&self) -> &str {
+        &self.0
+    }
+
+    /// Halts the current thread with the given message.
+    ///
+    /// Inside of `Halt::catch`, this unwinds with a `Halt` payload, which is caught by the scope.
+    /// Otherwise, this panics with the given message.
+    pub fn unwind<S: Into<String>, T>(message: S) -> T {
+        match CATCH_DEPTH.with(|depth| depth.get()) > 0 {
+            // Use resume_unwind instead of panic!() to skip the panic hook, as the halt is caught.
+            true => resume_unwind(Box::new(Self::new(message))),
+            false => panic!("{}", message.into()),
+        }
+    }
+
+    /// Runs the given closure, returning the unwind payload if it halts or panics.
+    pub fn catch<T>(f: impl FnOnce() -> T) -> std::thread::Result<T> {
+        CATCH_DEPTH.with(|depth| depth.set(depth.get() + 1));
+        let result = catch_unwind(AssertUnwindSafe(f));
+        CATCH_DEPTH.with(|depth| depth.set(depth.get() - 1));
+        result
+    }
+
+    /// Returns the halt in the given unwind payload, if the unwind was caused by a halt.
+    pub fn from_payload(payload: &(dyn Any + Send)) -> Option<&Self> {
+        payload.downcast_ref::<Self>()
+    }
+}
+
+impl fmt::Display for Halt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for Halt {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_halt_payload() {
+        // Ensure a halt is recovered from the unwind payload.
+        let payload = Halt::catch(|| Halt::unwind::<_, ()>("division by zero")).unwrap_err();
+        assert_eq!(Halt::from_payload(payload.as_ref()), Some(&Halt::new("division by zero")));
+
+        // Ensure a genuine panic is not classified as a halt.
+        let payload = Halt::catch(|| panic!("internal error")).unwrap_err();
+        assert!(Halt::from_payload(payload.as_ref()).is_none());
+    }
+
+    #[test]
+    fn test_halt_outside_catch() {
+        // Ensure a halt outside of `Halt::catch` panics with its message.
+        let payload = catch_unwind(|| Halt::unwind::<_, ()>("division by zero")).unwrap_err();
+        assert!(Halt::from_payload(payload.as_ref()).is_none());
+        assert_eq!(payload.downcast_ref::<String>().map(String::as_str), Some("division by zero"));
+
+        // Ensure the scope is exited after a halt is caught.
+        assert!(Halt::catch(|| Halt::unwind::<_, ()>("division by zero")).is_err());
+        let payload = catch_unwind(|| Halt::unwind::<_, ()>("division by zero")).unwrap_err();
+        assert!(Halt::from_payload(payload.as_ref()).is_none());
+    }
+}
@@ -28,15 +28,17 @@ pub mod prelude {
     pub use crate::{environment::*, helpers::*, traits::*};
 
     pub use snarkvm_curves::{AffineCurve, MontgomeryParameters, ProjectiveCurve, TwistedEdwardsParameters};
-    pub use snarkvm_fields::{Field as _, PrimeField as _, SquareRootField as _, Zero as _};
+    pub use snarkvm_fields::{batch_inversion, Field as _, PrimeField as _, SquareRootField as _, Zero as _};
     pub use snarkvm_utilities::{
         cfg_find,
         cfg_find_map,
         cfg_into_iter,
         cfg_iter,
         cfg_iter_mut,
+        cfg_keys,
         cfg_reduce,
         cfg_values,
+        ct_eq_limbs,
         error,
         has_duplicates,
         io::{Read, Result as IoResult, Write},
@@ -41,6 +41,11 @@ pub trait Hash {
 
     /// Returns the hash of the given input.
     fn hash(&self, input: &[Self::Input]) -> Result<Self::Output>;
+
+    /// Returns the hashes of the given inputs.
+    fn hash_batch(&self, inputs: &[Vec<Self::Input>]) -> Result<Vec<Self::Output>> {
+        inputs.iter().map(|input| self.hash(input)).collect()
+    }
 }
 
 /// A trait for a hash function that produces multiple outputs.
@@ -77,6 +82,11 @@ pub trait HashUncompressed {
 
     /// Returns the hash of the given input.
     fn hash_uncompressed(&self, input: &[Self::Input]) -> Result<Self::Output>;
+
+    /// Returns the hashes of the given inputs.
+    fn hash_uncompressed_batch(&self, inputs: &[Vec<Self::Input>]) -> Result<Vec<Self::Output>> {
+        inputs.iter().map(|input| self.hash_uncompressed(input)).collect()
+    }
 }
 
 /// A trait for a pseudorandom function.
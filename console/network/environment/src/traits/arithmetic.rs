@@ -156,6 +156,20 @@ pub trait ShrWrapped<Rhs: ?Sized = Self> {
     fn shr_wrapped(&self, rhs: &Rhs) -> Self::Output;
 }
 
+/// Binary operator for rotating a value to the left, by the rhs modulo the number of bits in self.
+pub trait RotateLeft<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn rotate_left(&self, rhs: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for rotating a value to the right, by the rhs modulo the number of bits in self.
+pub trait RotateRight<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn rotate_right(&self, rhs: &Rhs) -> Self::Output;
+}
+
 /// Binary operator for subtracting two values, enforcing an underflow never occurs.
 pub trait SubChecked<Rhs: ?Sized = Self> {
     type Output;
@@ -198,6 +212,13 @@ pub trait AbsWrapped {
     fn abs_wrapped(self) -> Self::Output;
 }
 
+/// Unary operator for retrieving the sign, as `-1`, `0`, or `1`.
+pub trait Signum {
+    type Output;
+
+    fn signum(self) -> Self::Output;
+}
+
 /// Unary operator for retrieving the doubled value.
 pub trait Double {
     type Output;
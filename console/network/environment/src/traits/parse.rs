@@ -21,6 +21,9 @@ use nom::{
 /// The `nom`-compatible parser return type.
 pub type ParserResult<'a, O> = IResult<&'a str, O, VerboseError<&'a str>>;
 
+/// The `nom`-compatible parser error type.
+pub type ParserError<'a> = NomErr<VerboseError<&'a str>>;
+
 /// Converts a `ParserResult` into a human-readable message.
 pub fn convert_result<'a, O>(result: ParserResult<'a, O>, input: &'a str) -> String {
     match result {
@@ -32,6 +35,56 @@ pub fn convert_result<'a, O>(result: ParserResult<'a, O>, input: &'a str) -> Str
     }
 }
 
+/// A line and column in a parser input, where both are 1-indexed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ParserPosition {
+    /// The line number.
+    line: usize,
+    /// The column number, in characters.
+    column: usize,
+}
+
+impl ParserPosition {
+    /// Returns the position at which the given remainder begins in the input.
+    ///
+    /// Note: The remainder must be a suffix of the input, as returned by a parser over the input.
+    pub fn locate(input: &str, remainder: &str) -> Self {
+        // Retrieve the consumed portion of the input.
+        let consumed = input.get(..input.len().saturating_sub(remainder.len())).unwrap_or(input);
+        // Compute the line and column.
+        let line = consumed.matches('\n').count() + 1;
+        let column = consumed.rsplit('\n').next().map_or(0, |line| line.chars().count()) + 1;
+        Self { line, column }
+    }
+
+    /// Returns the line number.
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the column number.
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl core::fmt::Display for ParserPosition {
+    /// Prints the position as a string.
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Returns the furthest remainder of the input reached by the given parser error, if any.
+pub fn error_remainder<'a>(error: &ParserError<'a>) -> Option<&'a str> {
+    match error {
+        NomErr::Incomplete(_) => None,
+        NomErr::Error(err) | NomErr::Failure(err) => {
+            err.errors.iter().map(|(remainder, _)| *remainder).min_by_key(|remainder| remainder.len())
+        }
+    }
+}
+
 /// Operations to parse a string literal into an object.
 pub trait Parser: core::fmt::Display + core::str::FromStr {
     /// Parses a string literal into an object.
@@ -39,3 +92,19 @@ pub trait Parser: core::fmt::Display + core::str::FromStr {
     where
         Self: Sized;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_locate() {
+        let input = "first\nsecond line\nthird";
+        assert_eq!(ParserPosition::locate(input, input), ParserPosition { line: 1, column: 1 });
+        assert_eq!(ParserPosition::locate(input, &input[3..]), ParserPosition { line: 1, column: 4 });
+        assert_eq!(ParserPosition::locate(input, &input[6..]), ParserPosition { line: 2, column: 1 });
+        assert_eq!(ParserPosition::locate(input, &input[13..]), ParserPosition { line: 2, column: 8 });
+        assert_eq!(ParserPosition::locate(input, ""), ParserPosition { line: 3, column: 6 });
+        assert_eq!(ParserPosition::locate(input, &input[13..]).to_string(), "line 2, column 8");
+    }
+}
@@ -0,0 +1,370 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{
+    Blake2Xs,
+    Keccak256,
+    Keccak384,
+    Keccak512,
+    Pedersen128,
+    Pedersen64,
+    Poseidon2,
+    Poseidon4,
+    Poseidon8,
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
+    BHP1024,
+    BHP256,
+    BHP512,
+    BHP768,
+};
+
+/// The consensus constants of `DevnetV0`, resolved once at compile time.
+pub const CONFIG: DevnetConfig = DevnetConfig::from_env();
+
+/// The tunable consensus constants of a devnet.
+///
+/// Every constant defaults to its `Testnet3` value, and may be overridden by setting the
+/// corresponding `SNARKVM_DEVNET_*` environment variable when compiling snarkVM.
+/// Invalid overrides are rejected at compile time.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DevnetConfig {
+    /// The network ID (`SNARKVM_DEVNET_ID`).
+    pub id: u16,
+    /// The genesis timestamp, in seconds since the Unix epoch (`SNARKVM_DEVNET_GENESIS_TIMESTAMP`).
+    pub genesis_timestamp: i64,
+    /// The genesis coinbase target (`SNARKVM_DEVNET_GENESIS_COINBASE_TARGET`).
+    pub genesis_coinbase_target: u64,
+    /// The genesis proof target (`SNARKVM_DEVNET_GENESIS_PROOF_TARGET`).
+    pub genesis_proof_target: u64,
+    /// The anchor time, in seconds (`SNARKVM_DEVNET_ANCHOR_TIME`).
+    pub anchor_time: u16,
+    /// The expected time per block, in seconds (`SNARKVM_DEVNET_BLOCK_TIME`).
+    pub block_time: u16,
+    /// The number of blocks per epoch (`SNARKVM_DEVNET_NUM_BLOCKS_PER_EPOCH`).
+    pub num_blocks_per_epoch: u32,
+    /// The path to the genesis block bytes (`SNARKVM_DEVNET_GENESIS_PATH`).
+    pub genesis_path: Option<&'static str>,
+}
+
+impl DevnetConfig {
+    /// The default network ID of a devnet.
+    pub const DEFAULT_ID: u16 = 4;
+
+    /// Returns the devnet configuration with the consensus constants of `Testnet3`.
+    pub const fn testnet3() -> Self {
+        Self {
+            id: Self::DEFAULT_ID,
+            genesis_timestamp: Testnet3::GENESIS_TIMESTAMP,
+            genesis_coinbase_target: Testnet3::GENESIS_COINBASE_TARGET,
+            genesis_proof_target: Testnet3::GENESIS_PROOF_TARGET,
+            anchor_time: Testnet3::ANCHOR_TIME,
+            block_time: Testnet3::BLOCK_TIME,
+            num_blocks_per_epoch: Testnet3::NUM_BLOCKS_PER_EPOCH,
+            genesis_path: None,
+        }
+    }
+
+    /// Returns the devnet configuration, applying the overrides set in the build environment.
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn from_env() -> Self {
+        let mut config = Self::testnet3();
+        if let Some(value) = option_env!("SNARKVM_DEVNET_ID") {
+            config.id = parse_u64(value, u16::MAX as u64) as u16;
+        }
+        if let Some(value) = option_env!("SNARKVM_DEVNET_GENESIS_TIMESTAMP") {
+            config.genesis_timestamp = parse_u64(value, i64::MAX as u64) as i64;
+        }
+        if let Some(value) = option_env!("SNARKVM_DEVNET_GENESIS_COINBASE_TARGET") {
+            config.genesis_coinbase_target = parse_u64(value, u64::MAX);
+        }
+        if let Some(value) = option_env!("SNARKVM_DEVNET_GENESIS_PROOF_TARGET") {
+            config.genesis_proof_target = parse_u64(value, u64::MAX);
+        }
+        if let Some(value) = option_env!("SNARKVM_DEVNET_ANCHOR_TIME") {
+            config.anchor_time = parse_u64(value, u16::MAX as u64) as u16;
+        }
+        if let Some(value) = option_env!("SNARKVM_DEVNET_BLOCK_TIME") {
+            config.block_time = parse_u64(value, u16::MAX as u64) as u16;
+            config.num_blocks_per_epoch = config.default_num_blocks_per_epoch();
+        }
+        if let Some(value) = option_env!("SNARKVM_DEVNET_NUM_BLOCKS_PER_EPOCH") {
+            config.num_blocks_per_epoch = parse_u64(value, u32::MAX as u64) as u32;
+        }
+        config.genesis_path = option_env!("SNARKVM_DEVNET_GENESIS_PATH");
+        config.verify()
+    }
+
+    /// Returns the number of blocks in the anchor time.
+    pub const fn anchor_height(&self) -> u32 {
+        self.anchor_time as u32 / self.block_time as u32
+    }
+
+    /// Returns the number of blocks in ~1 hour, rounded down to a multiple of the anchor height.
+    const fn default_num_blocks_per_epoch(&self) -> u32 {
+        let num_blocks = 3600 / self.block_time as u32;
+        match self.anchor_height() {
+            0 => num_blocks,
+            anchor_height => num_blocks - num_blocks % anchor_height,
+        }
+    }
+
+    /// Returns the configuration, after ensuring the constants are consistent with one another.
+    const fn verify(self) -> Self {
+        assert!(self.id != Testnet3::ID, "The devnet ID must differ from the Testnet3 ID");
+        assert!(self.block_time > 0, "The devnet block time must be nonzero");
+        assert!(self.anchor_height() > 0, "The devnet anchor time must be at least the block time");
+        assert!(self.num_blocks_per_epoch > 0, "The devnet number of blocks per epoch must be nonzero");
+        assert!(
+            self.num_blocks_per_epoch % self.anchor_height() == 0,
+            "The devnet number of blocks per epoch must be a multiple of the anchor height"
+        );
+        assert!(self.genesis_proof_target > 0, "The devnet genesis proof target must be nonzero");
+        assert!(
+            self.genesis_proof_target <= self.genesis_coinbase_target,
+            "The devnet genesis proof target must not exceed the genesis coinbase target"
+        );
+        self
+    }
+}
+
+/// Parses the given decimal string as an unsigned integer, up to the given maximum.
+const fn parse_u64(value: &str, max: u64) -> u64 {
+    let bytes = value.as_bytes();
+    assert!(!bytes.is_empty(), "A devnet override must not be empty");
+    let mut output = 0u64;
+    let mut i = 0;
+    while i < bytes.len() {
+        assert!(bytes[i].is_ascii_digit(), "A devnet override must be a decimal integer");
+        output = match output.checked_mul(10) {
+            Some(output) => match output.checked_add((bytes[i] - b'0') as u64) {
+                Some(output) => output,
+                None => panic!("A devnet override is out of range"),
+            },
+            None => panic!("A devnet override is out of range"),
+        };
+        i += 1;
+    }
+    assert!(output <= max, "A devnet override is out of range");
+    output
+}
+
+/// The 'credits.aleo' circuit keys of `DevnetV0`, as a map of `function name` to `(proving key, verifying key)`.
+type CreditsKeys = IndexMap<String, (Arc<VarunaProvingKey<DevnetV0>>, Arc<VarunaVerifyingKey<DevnetV0>>)>;
+
+/// The 'credits.aleo' circuit keys of `DevnetV0`, once they are synthesized for its network ID.
+static CREDITS_KEYS: OnceCell<CreditsKeys> = OnceCell::new();
+
+/// A development network, with the cryptographic parameters of `Testnet3` and tunable consensus constants.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DevnetV0;
+
+cryptographic_parameters!(DevnetV0);
+
+impl Environment for DevnetV0 {
+    type Affine = <Console as Environment>::Affine;
+    type BigInteger = <Console as Environment>::BigInteger;
+    type Field = <Console as Environment>::Field;
+    type PairingCurve = <Console as Environment>::PairingCurve;
+    type Projective = <Console as Environment>::Projective;
+    type Scalar = <Console as Environment>::Scalar;
+
+    /// The coefficient `A` of the twisted Edwards curve.
+    const EDWARDS_A: Self::Field = Console::EDWARDS_A;
+    /// The coefficient `D` of the twisted Edwards curve.
+    const EDWARDS_D: Self::Field = Console::EDWARDS_D;
+    /// The coefficient `A` of the Montgomery curve.
+    const MONTGOMERY_A: Self::Field = Console::MONTGOMERY_A;
+    /// The coefficient `B` of the Montgomery curve.
+    const MONTGOMERY_B: Self::Field = Console::MONTGOMERY_B;
+}
+
+impl Network for DevnetV0 {
+    /// The block hash type.
+    type BlockHash = AleoID<Field<Self>, { hrp2!("ab") }>;
+    /// The ratification ID type.
+    type RatificationID = AleoID<Field<Self>, { hrp2!("ar") }>;
+    /// The state root type.
+    type StateRoot = AleoID<Field<Self>, { hrp2!("sr") }>;
+    /// The transaction ID type.
+    type TransactionID = AleoID<Field<Self>, { hrp2!(TRANSACTION_PREFIX) }>;
+    /// The transition ID type.
+    type TransitionID = AleoID<Field<Self>, { hrp2!("au") }>;
+
+    /// The network edition.
+    const EDITION: u16 = 0;
+    /// The network ID.
+    const ID: u16 = CONFIG.id;
+    /// The function name for the inclusion circuit.
+    const INCLUSION_FUNCTION_NAME: &'static str = Testnet3::INCLUSION_FUNCTION_NAME;
+    /// The network name.
+    const NAME: &'static str = "Aleo Devnet 0";
+
+    /// The genesis block timestamp.
+    const GENESIS_TIMESTAMP: i64 = CONFIG.genesis_timestamp;
+    /// The genesis block coinbase target.
+    const GENESIS_COINBASE_TARGET: u64 = CONFIG.genesis_coinbase_target;
+    /// The genesis block proof target.
+    const GENESIS_PROOF_TARGET: u64 = CONFIG.genesis_proof_target;
+
+    /// The anchor time in seconds.
+    const ANCHOR_TIME: u16 = CONFIG.anchor_time;
+    /// The expected time per block in seconds.
+    const BLOCK_TIME: u16 = CONFIG.block_time;
+    /// The number of blocks per epoch.
+    const NUM_BLOCKS_PER_EPOCH: u32 = CONFIG.num_blocks_per_epoch;
+
+    /// Returns the genesis block bytes, read once from `SNARKVM_DEVNET_GENESIS_PATH`.
+    fn genesis_bytes() -> Result<&'static [u8]> {
+        static INSTANCE: OnceCell<Vec<u8>> = OnceCell::new();
+        let bytes = INSTANCE.get_or_try_init(|| {
+            let Some(path) = CONFIG.genesis_path else {
+                bail!("The devnet genesis block requires 'SNARKVM_DEVNET_GENESIS_PATH'")
+            };
+            std::fs::read(path).map_err(|error| anyhow!("Failed to read the devnet genesis block at '{path}': {error}"))
+        })?;
+        Ok(bytes)
+    }
+
+    /// Returns the proving key for the given function name in `credits.aleo`.
+    fn get_credits_proving_key(function_name: String) -> Result<&'static Arc<VarunaProvingKey<Self>>> {
+        CREDITS_KEYS
+            .get()
+            .ok_or_else(|| anyhow!("The 'credits.aleo' keys of {} have not been synthesized", Self::NAME))?
+            .get(&function_name)
+            .map(|(proving_key, _)| proving_key)
+            .ok_or_else(|| anyhow!("Proving key for credits.aleo/{function_name}' not found"))
+    }
+
+    /// Returns the verifying key for the given function name in `credits.aleo`.
+    fn get_credits_verifying_key(function_name: String) -> Result<&'static Arc<VarunaVerifyingKey<Self>>> {
+        CREDITS_KEYS
+            .get()
+            .ok_or_else(|| anyhow!("The 'credits.aleo' keys of {} have not been synthesized", Self::NAME))?
+            .get(&function_name)
+            .map(|(_, verifying_key)| verifying_key)
+            .ok_or_else(|| anyhow!("Verifying key for credits.aleo/{function_name}' not found"))
+    }
+
+    /// Sets the proving and verifying keys of `credits.aleo`, which are synthesized for the devnet network ID,
+    /// as the 'credits.aleo' circuits commit to the network ID and the keys of `Testnet3` do not apply.
+    /// If the keys are already set, they are left unchanged, as their synthesis is deterministic.
+    #[allow(clippy::type_complexity)]
+    fn set_credits_keys(keys: Vec<(String, Arc<VarunaProvingKey<Self>>, Arc<VarunaVerifyingKey<Self>>)>) -> Result<()> {
+        // Note: A concurrent caller may set the same keys first, in which case the given keys are dropped.
+        let _ = CREDITS_KEYS.set(keys.into_iter().map(|(name, pk, vk)| (name, (pk, vk))).collect());
+        Ok(())
+    }
+
+    /// Returns the `proving key` for the inclusion circuit.
+    /// Note: The inclusion circuit does not depend on the network ID, so the keys of `Testnet3` apply.
+    fn inclusion_proving_key() -> &'static Arc<VarunaProvingKey<Self>> {
+        Testnet3::inclusion_proving_key()
+    }
+
+    /// Returns the `verifying key` for the inclusion circuit.
+    /// Note: The inclusion circuit does not depend on the network ID, so the keys of `Testnet3` apply.
+    fn inclusion_verifying_key() -> &'static Arc<VarunaVerifyingKey<Self>> {
+        Testnet3::inclusion_verifying_key()
+    }
+
+    /// Returns the Varuna universal prover.
+    fn varuna_universal_prover() -> &'static UniversalProver<Self::PairingCurve> {
+        Testnet3::varuna_universal_prover()
+    }
+
+    /// Returns the Varuna universal verifier.
+    fn varuna_universal_verifier() -> &'static UniversalVerifier<Self::PairingCurve> {
+        Testnet3::varuna_universal_verifier()
+    }
+
+    /// Returns the sponge parameters used for the sponge in the Varuna SNARK.
+    fn varuna_fs_parameters() -> &'static FiatShamirParameters<Self> {
+        Testnet3::varuna_fs_parameters()
+    }
+
+    cryptographic_functions!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = DevnetV0;
+
+    #[test]
+    fn test_config() {
+        // Ensure the network constants match the configuration.
+        assert_eq!(CurrentNetwork::ID, CONFIG.id);
+        assert_eq!(CurrentNetwork::BLOCK_TIME, CONFIG.block_time);
+        assert_eq!(CurrentNetwork::ANCHOR_HEIGHT, CONFIG.anchor_height());
+        assert_eq!(CurrentNetwork::NUM_BLOCKS_PER_EPOCH, CONFIG.num_blocks_per_epoch);
+        assert_ne!(CurrentNetwork::ID, Testnet3::ID);
+        CurrentNetwork::verify_internal_consistency().unwrap();
+        // Ensure the genesis block bytes are an error, rather than a panic, if no genesis path is configured.
+        if CONFIG.genesis_path.is_none() {
+            assert!(CurrentNetwork::genesis_bytes().is_err());
+        }
+
+        // Ensure the defaults match the consensus constants of Testnet3.
+        let config = DevnetConfig::testnet3();
+        assert_eq!(config.genesis_timestamp, Testnet3::GENESIS_TIMESTAMP);
+        assert_eq!(config.anchor_height(), Testnet3::ANCHOR_HEIGHT);
+        assert_eq!(config.num_blocks_per_epoch, Testnet3::NUM_BLOCKS_PER_EPOCH);
+        assert_eq!(config.default_num_blocks_per_epoch(), Testnet3::NUM_BLOCKS_PER_EPOCH);
+        assert_eq!(config.verify(), config);
+    }
+
+    #[test]
+    fn test_config_overrides() {
+        // Ensure a faster block time shortens the default epoch to a multiple of the anchor height.
+        let config = DevnetConfig { block_time: 7, ..DevnetConfig::testnet3() };
+        assert_eq!(config.anchor_height(), 3);
+        assert_eq!(config.default_num_blocks_per_epoch(), 513);
+
+        // Ensure inconsistent overrides are rejected.
+        let verify = |config: DevnetConfig| std::panic::catch_unwind(|| config.verify()).is_ok();
+        assert!(verify(DevnetConfig { num_blocks_per_epoch: 10, ..DevnetConfig::testnet3() }));
+        assert!(!verify(DevnetConfig { num_blocks_per_epoch: 11, ..DevnetConfig::testnet3() }));
+        assert!(!verify(DevnetConfig { block_time: 0, ..DevnetConfig::testnet3() }));
+        assert!(!verify(DevnetConfig { anchor_time: 1, ..DevnetConfig::testnet3() }));
+        assert!(!verify(DevnetConfig { id: Testnet3::ID, ..DevnetConfig::testnet3() }));
+    }
+
+    #[test]
+    fn test_parse_u64() {
+        assert_eq!(parse_u64("0", u64::MAX), 0);
+        assert_eq!(parse_u64("360", u64::MAX), 360);
+        assert_eq!(parse_u64("65535", u16::MAX as u64), 65535);
+        assert_eq!(parse_u64("18446744073709551615", u64::MAX), u64::MAX);
+
+        assert!(std::panic::catch_unwind(|| parse_u64("", u64::MAX)).is_err());
+        assert!(std::panic::catch_unwind(|| parse_u64("-1", u64::MAX)).is_err());
+        assert!(std::panic::catch_unwind(|| parse_u64("1s", u64::MAX)).is_err());
+        assert!(std::panic::catch_unwind(|| parse_u64("65536", u16::MAX as u64)).is_err());
+        assert!(std::panic::catch_unwind(|| parse_u64("18446744073709551616", u64::MAX)).is_err());
+    }
+
+    #[test]
+    fn test_g_scalar_multiply() {
+        // Compute G^r.
+        let scalar = Scalar::rand(&mut TestRng::default());
+        let group = CurrentNetwork::g_scalar_multiply(&scalar);
+        assert_eq!(group, CurrentNetwork::g_powers()[0] * scalar);
+        // Ensure the bases match Testnet3.
+        assert_eq!(CurrentNetwork::g_powers()[0].to_string(), Testnet3::g_powers()[0].to_string());
+    }
+}
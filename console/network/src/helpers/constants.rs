@@ -0,0 +1,216 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::prelude::*;
+use snarkvm_algorithms::crypto_hash::sha256;
+
+/// A consensus constant of the network, with its name, value, and unit.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Constant {
+    /// The name of the constant, as declared on the `Network` trait.
+    pub name: String,
+    /// The value of the constant.
+    pub value: String,
+    /// The unit of the value.
+    pub unit: String,
+}
+
+impl Constant {
+    /// Initializes a new constant.
+    pub fn new(name: &str, value: impl ToString, unit: &str) -> Self {
+        Self { name: name.to_string(), value: value.to_string(), unit: unit.to_string() }
+    }
+}
+
+/// A summary of the consensus constants compiled into a network.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ConstantsSummary {
+    /// The network name.
+    pub network: String,
+    /// The consensus constants.
+    pub constants: Vec<Constant>,
+}
+
+impl ConstantsSummary {
+    /// Returns the summary of the consensus constants for the given network.
+    pub fn new<N: Network>() -> Self {
+        let constants = vec![
+            Constant::new("ID", N::ID, "id"),
+            Constant::new("EDITION", N::EDITION, "edition"),
+            Constant::new("GENESIS_TIMESTAMP", N::GENESIS_TIMESTAMP, "seconds since the Unix epoch"),
+            Constant::new("GENESIS_COINBASE_TARGET", N::GENESIS_COINBASE_TARGET, "target"),
+            Constant::new("GENESIS_PROOF_TARGET", N::GENESIS_PROOF_TARGET, "target"),
+            Constant::new("STARTING_SUPPLY", N::STARTING_SUPPLY, "microcredits"),
+            Constant::new("DEPLOYMENT_FEE_MULTIPLIER", N::DEPLOYMENT_FEE_MULTIPLIER, "microcredits per byte"),
+            Constant::new("MAX_FEE", N::MAX_FEE, "microcredits"),
+            Constant::new("ANCHOR_HEIGHT", N::ANCHOR_HEIGHT, "blocks"),
+            Constant::new("ANCHOR_TIME", N::ANCHOR_TIME, "seconds"),
+            Constant::new("BLOCK_TIME", N::BLOCK_TIME, "seconds"),
+            Constant::new("COINBASE_PUZZLE_DEGREE", N::COINBASE_PUZZLE_DEGREE, "degree"),
+            Constant::new("MAX_PROVER_SOLUTIONS", N::MAX_PROVER_SOLUTIONS, "solutions"),
+            Constant::new("NUM_BLOCKS_PER_EPOCH", N::NUM_BLOCKS_PER_EPOCH, "blocks"),
+            Constant::new("MAX_DATA_ENTRIES", N::MAX_DATA_ENTRIES, "entries"),
+            Constant::new("MAX_DATA_DEPTH", N::MAX_DATA_DEPTH, "depth"),
+            Constant::new("MAX_DATA_SIZE_IN_FIELDS", N::MAX_DATA_SIZE_IN_FIELDS, "fields"),
+            Constant::new("MIN_STRUCT_ENTRIES", N::MIN_STRUCT_ENTRIES, "entries"),
+            Constant::new("MAX_STRUCT_ENTRIES", N::MAX_STRUCT_ENTRIES, "entries"),
+            Constant::new("MIN_ARRAY_ELEMENTS", N::MIN_ARRAY_ELEMENTS, "elements"),
+            Constant::new("MAX_ARRAY_ELEMENTS", N::MAX_ARRAY_ELEMENTS, "elements"),
+            Constant::new("MIN_RECORD_ENTRIES", N::MIN_RECORD_ENTRIES, "entries"),
+            Constant::new("MAX_RECORD_ENTRIES", N::MAX_RECORD_ENTRIES, "entries"),
+            Constant::new("MAX_MAPPINGS", N::MAX_MAPPINGS, "mappings"),
+            Constant::new("MAX_FUNCTIONS", N::MAX_FUNCTIONS, "functions"),
+            Constant::new("MAX_OPERANDS", N::MAX_OPERANDS, "operands"),
+            Constant::new("MAX_INSTRUCTIONS", N::MAX_INSTRUCTIONS, "instructions"),
+            Constant::new("MAX_COMMANDS", N::MAX_COMMANDS, "commands"),
+            Constant::new("MAX_WRITES", N::MAX_WRITES, "commands"),
+            Constant::new("MAX_CALL_DEPTH", N::MAX_CALL_DEPTH, "calls"),
+            Constant::new("MAX_INPUTS", N::MAX_INPUTS, "inputs"),
+            Constant::new("MAX_OUTPUTS", N::MAX_OUTPUTS, "outputs"),
+        ];
+        Self { network: N::NAME.to_string(), constants }
+    }
+
+    /// Returns the constant with the given name, if it exists.
+    pub fn get(&self, name: &str) -> Option<&Constant> {
+        self.constants.iter().find(|constant| constant.name == name)
+    }
+
+    /// Returns a hex-encoded SHA-256 fingerprint of the summary, to compare the constants of two builds.
+    pub fn fingerprint(&self) -> String {
+        // Construct the preimage, with one line per constant.
+        let mut preimage = format!("network={}\n", self.network);
+        for Constant { name, value, unit } in &self.constants {
+            preimage.push_str(&format!("{name}={value} ({unit})\n"));
+        }
+        // Hash the preimage.
+        sha256(preimage.as_bytes()).iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+}
+
+/// Ensures the consensus constants of the given network satisfy the invariants across constants.
+pub(crate) fn verify_internal_consistency<N: Network>() -> Result<()> {
+    // Ensure the timing constants are compatible.
+    ensure!(N::BLOCK_TIME > 0, "The block time must be nonzero");
+    ensure!(N::ANCHOR_HEIGHT > 0, "The anchor height must be nonzero");
+    ensure!(
+        N::ANCHOR_HEIGHT == N::ANCHOR_TIME as u32 / N::BLOCK_TIME as u32,
+        "The anchor height must be the number of blocks in the anchor time"
+    );
+    ensure!(
+        N::NUM_BLOCKS_PER_EPOCH % N::ANCHOR_HEIGHT == 0,
+        "The number of blocks per epoch ({}) must be a multiple of the anchor height ({})",
+        N::NUM_BLOCKS_PER_EPOCH,
+        N::ANCHOR_HEIGHT
+    );
+
+    // Ensure the genesis targets are within bounds.
+    ensure!(N::GENESIS_PROOF_TARGET > 0, "The genesis proof target must be nonzero");
+    ensure!(
+        N::GENESIS_PROOF_TARGET <= N::GENESIS_COINBASE_TARGET,
+        "The genesis proof target must not exceed the genesis coinbase target"
+    );
+
+    // Ensure the supply and fee constants are compatible.
+    ensure!(N::MAX_FEE <= N::STARTING_SUPPLY, "The maximum fee must not exceed the starting supply");
+
+    // Ensure the coinbase puzzle constants are compatible.
+    ensure!(
+        (N::COINBASE_PUZZLE_DEGREE as u64 + 1).is_power_of_two(),
+        "The coinbase puzzle degree must be one less than a power of two"
+    );
+    ensure!(N::MAX_PROVER_SOLUTIONS > 0, "The maximum number of prover solutions must be nonzero");
+    ensure!(
+        N::MAX_PROVER_SOLUTIONS <= u16::MAX as usize,
+        "The maximum number of prover solutions must fit in a u16, as it is serialized as one"
+    );
+
+    // Ensure the data constants are within their size and depth limits.
+    ensure!(N::MAX_DATA_DEPTH < u8::MAX as usize, "The maximum data depth must be strictly less than u8::MAX");
+    ensure!(N::MAX_DATA_SIZE_IN_FIELDS <= u16::MAX as u32, "The maximum data size in fields must not exceed u16::MAX");
+    ensure!(N::MIN_STRUCT_ENTRIES <= N::MAX_STRUCT_ENTRIES, "The struct entry bounds are inverted");
+    ensure!(
+        N::MAX_STRUCT_ENTRIES <= N::MAX_DATA_ENTRIES,
+        "The maximum struct entries must not exceed the data entries"
+    );
+    ensure!(N::MIN_ARRAY_ELEMENTS <= N::MAX_ARRAY_ELEMENTS, "The array element bounds are inverted");
+    ensure!(
+        N::MAX_ARRAY_ELEMENTS <= N::MAX_DATA_ENTRIES,
+        "The maximum array elements must not exceed the data entries"
+    );
+    ensure!(N::MIN_RECORD_ENTRIES <= N::MAX_RECORD_ENTRIES, "The record entry bounds are inverted");
+    ensure!(
+        N::MAX_RECORD_ENTRIES <= N::MIN_RECORD_ENTRIES + N::MAX_DATA_ENTRIES,
+        "The maximum record entries must not exceed the owner and the data entries"
+    );
+
+    // Ensure the program constants are within the limits of their encodings.
+    ensure!(N::MAX_INSTRUCTIONS <= u16::MAX as usize, "The maximum instructions must not exceed u16::MAX");
+    ensure!(N::MAX_COMMANDS <= u16::MAX as usize, "The maximum commands must not exceed u16::MAX");
+    ensure!(N::MAX_WRITES as usize <= N::MAX_COMMANDS, "The maximum writes must not exceed the maximum commands");
+    ensure!(N::MAX_OPERANDS >= N::MAX_INPUTS, "The maximum operands must accommodate the maximum inputs");
+    ensure!(N::MAX_INPUTS > 0 && N::MAX_OUTPUTS > 0, "The maximum inputs and outputs must be nonzero");
+    ensure!(N::MAX_CALL_DEPTH > 0, "The maximum call depth must be nonzero");
+    ensure!(
+        N::MAX_CALL_DEPTH + 1 >= N::MAX_FUNCTIONS,
+        "The maximum call depth must accommodate a chain of calls to the maximum functions"
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_verify_internal_consistency() {
+        CurrentNetwork::verify_internal_consistency().unwrap();
+    }
+
+    #[test]
+    fn test_constants_summary() {
+        let summary = CurrentNetwork::constants_summary();
+        assert_eq!(summary.network, CurrentNetwork::NAME);
+
+        // Ensure the constant names are unique.
+        assert!(summary.constants.iter().map(|constant| &constant.name).all_unique());
+
+        // Ensure the values match the network.
+        assert_eq!(summary.get("BLOCK_TIME").unwrap().value, CurrentNetwork::BLOCK_TIME.to_string());
+        assert_eq!(summary.get("MAX_FEE").unwrap().value, CurrentNetwork::MAX_FEE.to_string());
+        assert!(summary.get("UNKNOWN").is_none());
+    }
+
+    #[test]
+    fn test_fingerprint_canary() {
+        let summary = CurrentNetwork::constants_summary();
+        let fingerprint = summary.fingerprint();
+        assert_eq!(fingerprint.len(), 64);
+        assert_eq!(fingerprint, CurrentNetwork::constants_summary().fingerprint());
+
+        // Ensure the fingerprint changes when any constant changes.
+        for index in 0..summary.constants.len() {
+            let mut candidate = summary.clone();
+            candidate.constants[index].value.push('0');
+            assert_ne!(fingerprint, candidate.fingerprint(), "{}", summary.constants[index].name);
+
+            let mut candidate = summary.clone();
+            candidate.constants[index].unit.push('s');
+            assert_ne!(fingerprint, candidate.fingerprint(), "{}", summary.constants[index].name);
+        }
+    }
+}
@@ -14,6 +14,8 @@
 
 use crate::prelude::*;
 
+use snarkvm_console_types::Field;
+
 use anyhow::Result;
 use bech32::{self, FromBase32, ToBase32};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
@@ -43,6 +45,60 @@ pub trait Bech32ID<F: FieldTrait>:
     fn prefix() -> String;
     fn size_in_bytes() -> usize;
     fn number_of_data_characters() -> usize;
+
+    /// Reads in a hex string of the little-endian bytes, without a `0x` prefix.
+    #[inline]
+    fn from_hex(hex: &str) -> Result<Self> {
+        let size_in_bytes = Self::size_in_bytes();
+        ensure!(size_in_bytes <= MAX_ID_SIZE_IN_BYTES, "Invalid byte size for an ID: {size_in_bytes} bytes");
+        // Ensure the hex string has the expected number of characters.
+        if hex.len() != 2 * size_in_bytes {
+            bail!("Invalid length for a hex ID: {} characters (expected {})", hex.len(), 2 * size_in_bytes)
+        }
+
+        // Decode the hex string into a buffer on the stack.
+        let mut buffer = [0u8; MAX_ID_SIZE_IN_BYTES];
+        for (index, pair) in hex.as_bytes().chunks_exact(2).enumerate() {
+            match (hex_to_nibble(pair[0]), hex_to_nibble(pair[1])) {
+                (Some(high), Some(low)) => buffer[index] = (high << 4) | low,
+                _ => {
+                    // Note: As the preceding characters are ASCII, the position is a character boundary.
+                    let position = 2 * index + usize::from(hex_to_nibble(pair[0]).is_some());
+                    let character = hex[position..].chars().next().unwrap_or_default();
+                    bail!("Invalid character '{character}' at position {position} of a hex ID")
+                }
+            }
+        }
+        Self::from_bytes_le(&buffer[..size_in_bytes])
+    }
+
+    /// Returns the hex string of the little-endian bytes, without a `0x` prefix.
+    #[inline]
+    fn to_hex(&self) -> String {
+        const HEX_CHARACTERS: &[u8; 16] = b"0123456789abcdef";
+
+        let bytes = self.to_bytes_le().expect("Failed to write data as bytes");
+        let mut hex = String::with_capacity(2 * bytes.len());
+        for byte in bytes {
+            hex.push(char::from(HEX_CHARACTERS[usize::from(byte >> 4)]));
+            hex.push(char::from(HEX_CHARACTERS[usize::from(byte & 0x0f)]));
+        }
+        hex
+    }
+}
+
+/// The maximum size of an ID in bytes, which bounds the buffer used to parse a hex string.
+const MAX_ID_SIZE_IN_BYTES: usize = 64;
+
+/// Returns the value of the given hex character, if it is valid.
+#[inline]
+const fn hex_to_nibble(character: u8) -> Option<u8> {
+    match character {
+        b'0'..=b'9' => Some(character - b'0'),
+        b'a'..=b'f' => Some(character - b'a' + 10),
+        b'A'..=b'F' => Some(character - b'A' + 10),
+        _ => None,
+    }
 }
 
 #[rustfmt::skip]
@@ -105,6 +161,25 @@ impl<F: FieldTrait, const PREFIX: u16> FromBytes for AleoID<F, PREFIX> {
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         Ok(Self(F::read_le(&mut reader)?))
     }
+
+    /// Reads in the little-endian bytes, which must be exactly `size_in_bytes` bytes long.
+    #[inline]
+    fn from_bytes_le(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != Self::size_in_bytes() {
+            bail!("Invalid byte size for an ID: {} bytes (expected {})", bytes.len(), Self::size_in_bytes())
+        }
+        Ok(Self::read_le(bytes)?)
+    }
+}
+
+impl<E: Environment, const PREFIX: u16> TryFrom<&[u8]> for AleoID<Field<E>, PREFIX> {
+    type Error = Error;
+
+    /// Reads in the little-endian bytes, which must be exactly `size_in_bytes` bytes long.
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_bytes_le(bytes)
+    }
 }
 
 impl<F: FieldTrait, const PREFIX: u16> ToBytes for AleoID<F, PREFIX> {
@@ -210,3 +285,110 @@ impl<F: FieldTrait, const PREFIX: u16> Distribution<AleoID<F, PREFIX>> for Stand
         AleoID::<F, PREFIX>(Uniform::rand(rng))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Network, Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    fn check_bytes_and_hex<T: Bech32ID<Field<CurrentNetwork>>>() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let expected = T::rand(rng);
+            let bytes = expected.to_bytes_le()?;
+            assert_eq!(bytes.len(), T::size_in_bytes());
+
+            // Check the bytes round trip.
+            assert_eq!(expected, T::from_bytes_le(&bytes)?);
+
+            // Check the hex round trip, in lowercase and uppercase.
+            let hex = expected.to_hex();
+            assert_eq!(hex.len(), 2 * T::size_in_bytes());
+            assert!(hex.chars().all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c)));
+            assert_eq!(expected, T::from_hex(&hex)?);
+            assert_eq!(expected, T::from_hex(&hex.to_uppercase())?);
+
+            // Check the display format is unchanged.
+            let string = expected.to_string();
+            assert!(string.starts_with(&T::prefix()));
+            assert_ne!(string, hex);
+            assert_eq!(expected, T::from_str(&string).map_err(|_| anyhow!("Failed to parse the ID"))?);
+
+            // Check the wrong lengths fail.
+            assert!(T::from_bytes_le(&bytes[..bytes.len() - 1]).is_err());
+            assert!(T::from_bytes_le(&[bytes.as_slice(), &[0u8]].concat()).is_err());
+            assert!(T::from_bytes_le(&[]).is_err());
+            assert!(T::from_hex(&hex[..hex.len() - 1]).is_err());
+            assert!(T::from_hex(&hex[..hex.len() - 2]).is_err());
+            assert!(T::from_hex(&format!("{hex}0")).is_err());
+            assert!(T::from_hex(&format!("{hex}00")).is_err());
+            assert!(T::from_hex(&format!("0x{}", &hex[2..])).is_err());
+            assert!(T::from_hex("").is_err());
+
+            // Check the invalid characters fail.
+            for invalid in ['g', 'G', 'x', ' ', '-'] {
+                let mut candidate = hex.clone();
+                candidate.replace_range(5..6, &invalid.to_string());
+                assert!(T::from_hex(&candidate).is_err());
+            }
+            // Check a non-ASCII character (of two bytes) fails.
+            let candidate = format!("{}é", &hex[..hex.len() - 2]);
+            assert_eq!(candidate.len(), hex.len());
+            assert!(T::from_hex(&candidate).is_err());
+            // Check the bech32m string is not accepted as hex.
+            assert!(T::from_hex(&string).is_err());
+        }
+
+        // Check a non-canonical field element fails.
+        let non_canonical = vec![0xffu8; T::size_in_bytes()];
+        assert!(T::from_bytes_le(&non_canonical).is_err());
+        assert!(T::from_hex(&"f".repeat(2 * T::size_in_bytes())).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_and_hex() -> Result<()> {
+        check_bytes_and_hex::<<CurrentNetwork as Network>::BlockHash>()?;
+        check_bytes_and_hex::<<CurrentNetwork as Network>::StateRoot>()?;
+        check_bytes_and_hex::<<CurrentNetwork as Network>::TransactionID>()?;
+        check_bytes_and_hex::<<CurrentNetwork as Network>::TransitionID>()
+    }
+
+    #[test]
+    fn test_hex_zero() -> Result<()> {
+        type BlockHash = <CurrentNetwork as Network>::BlockHash;
+
+        // Check the zero ID encodes to all zeros.
+        let zero = BlockHash::default();
+        assert_eq!(zero.to_hex(), "0".repeat(64));
+        assert_eq!(zero, BlockHash::from_hex(&"0".repeat(64))?);
+        // Check the one ID is encoded in little-endian order.
+        let one = BlockHash::from(Field::one());
+        assert_eq!(one.to_hex(), format!("01{}", "0".repeat(62)));
+        assert_eq!(one, BlockHash::from_hex(&format!("01{}", "0".repeat(62)))?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_bytes() -> Result<()> {
+        type BlockHash = <CurrentNetwork as Network>::BlockHash;
+
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let expected = BlockHash::rand(rng);
+            let bytes = expected.to_bytes_le()?;
+            // Check the bytes round trip.
+            assert_eq!(expected, BlockHash::try_from(bytes.as_slice())?);
+            // Check the wrong lengths fail.
+            assert!(BlockHash::try_from(&bytes[..bytes.len() - 1]).is_err());
+            assert!(BlockHash::try_from([bytes.as_slice(), &[0u8]].concat().as_slice()).is_err());
+        }
+        Ok(())
+    }
+}
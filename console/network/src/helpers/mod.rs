@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod constants;
+pub use constants::*;
+
 mod id;
 pub use id::*;
 
@@ -25,9 +25,15 @@ pub use snarkvm_console_network_environment::*;
 mod helpers;
 pub use helpers::*;
 
+#[macro_use]
+mod macros;
+
 mod testnet3;
 pub use testnet3::*;
 
+pub mod devnet_v0;
+pub use devnet_v0::{DevnetConfig, DevnetV0};
+
 pub mod prelude {
     pub use crate::{environment::prelude::*, Network};
 }
@@ -40,7 +46,10 @@ use snarkvm_algorithms::{
     AlgebraicSponge,
 };
 use snarkvm_console_algorithms::{Poseidon2, Poseidon4, BHP1024, BHP512};
-use snarkvm_console_collections::merkle_tree::{MerklePath, MerkleTree};
+use snarkvm_console_collections::{
+    kary_merkle_tree::{KaryMerklePath, KaryMerkleTree},
+    merkle_tree::{MerklePath, MerkleTree},
+};
 use snarkvm_console_types::{Field, Group, Scalar};
 use snarkvm_curves::PairingEngine;
 
@@ -53,6 +62,16 @@ pub type BHPMerkleTree<N, const DEPTH: u8> = MerkleTree<N, BHP1024<N>, BHP512<N>
 /// A helper type for the Poseidon Merkle tree.
 pub type PoseidonMerkleTree<N, const DEPTH: u8> = MerkleTree<N, Poseidon4<N>, Poseidon2<N>, DEPTH>;
 
+/// A helper type for the BHP k-ary Merkle tree.
+pub type BHPKaryMerkleTree<N, const DEPTH: u8, const ARITY: u8> = KaryMerkleTree<BHP1024<N>, BHP512<N>, DEPTH, ARITY>;
+/// A helper type for the BHP k-ary Merkle path.
+pub type BHPKaryMerklePath<N, const DEPTH: u8, const ARITY: u8> = KaryMerklePath<BHP512<N>, DEPTH, ARITY>;
+/// A helper type for the Poseidon k-ary Merkle tree.
+pub type PoseidonKaryMerkleTree<N, const DEPTH: u8, const ARITY: u8> =
+    KaryMerkleTree<Poseidon4<N>, Poseidon2<N>, DEPTH, ARITY>;
+/// A helper type for the Poseidon k-ary Merkle path.
+pub type PoseidonKaryMerklePath<N, const DEPTH: u8, const ARITY: u8> = KaryMerklePath<Poseidon2<N>, DEPTH, ARITY>;
+
 /// Helper types for the Varuna parameters.
 type Fq<N> = <<N as Environment>::PairingCurve as PairingEngine>::Fq;
 pub type FiatShamir<N> = PoseidonSponge<Fq<N>, 2, 1>;
@@ -150,12 +169,29 @@ pub trait Network:
     const MAX_COMMANDS: usize = u16::MAX as usize;
     /// The maximum number of write commands in finalize.
     const MAX_WRITES: u16 = 16;
+    /// The maximum depth of nested calls (to closures or functions) from a top-level function.
+    ///
+    /// Note: A transaction holds at most `MAX_FUNCTIONS` transitions, one per function call (besides the fee),
+    /// so a chain of nested function calls that fits in a transaction reaches a depth of at most `MAX_FUNCTIONS - 1`.
+    /// This limit is set to that depth, so that it never rejects such a chain.
+    const MAX_CALL_DEPTH: usize = Self::MAX_FUNCTIONS - 1;
 
     /// The maximum number of inputs per transition.
     const MAX_INPUTS: usize = 16;
     /// The maximum number of outputs per transition.
     const MAX_OUTPUTS: usize = 16;
 
+    /// Returns a summary of the consensus constants of the network.
+    fn constants_summary() -> ConstantsSummary {
+        ConstantsSummary::new::<Self>()
+    }
+
+    /// Ensures the consensus constants of the network are consistent with one another.
+    /// Note: Node software may invoke this at startup, to fail fast on a misconfigured build.
+    fn verify_internal_consistency() -> Result<()> {
+        helpers::verify_internal_consistency::<Self>()
+    }
+
     /// The state root type.
     type StateRoot: Bech32ID<Field<Self>>;
     /// The block hash type.
@@ -168,7 +204,7 @@ pub trait Network:
     type TransitionID: Bech32ID<Field<Self>>;
 
     /// Returns the genesis block bytes.
-    fn genesis_bytes() -> &'static [u8];
+    fn genesis_bytes() -> Result<&'static [u8]>;
 
     /// Returns the proving key for the given function name in `credits.aleo`.
     fn get_credits_proving_key(function_name: String) -> Result<&'static Arc<VarunaProvingKey<Self>>>;
@@ -176,6 +212,15 @@ pub trait Network:
     /// Returns the verifying key for the given function name in `credits.aleo`.
     fn get_credits_verifying_key(function_name: String) -> Result<&'static Arc<VarunaVerifyingKey<Self>>>;
 
+    /// Sets the `credits.aleo` proving and verifying keys, as a list of `(function name, proving key, verifying key)`.
+    /// Note: This is only supported by networks that do not bundle their `credits.aleo` keys.
+    #[allow(clippy::type_complexity)]
+    fn set_credits_keys(
+        _keys: Vec<(String, Arc<VarunaProvingKey<Self>>, Arc<VarunaVerifyingKey<Self>>)>,
+    ) -> Result<()> {
+        bail!("The 'credits.aleo' keys of {} are bundled, and cannot be set", Self::NAME)
+    }
+
     /// Returns the `proving key` for the inclusion circuit.
     fn inclusion_proving_key() -> &'static Arc<VarunaProvingKey<Self>>;
 
@@ -353,4 +398,32 @@ pub trait Network:
         root: &Field<Self>,
         leaf: &Vec<Field<Self>>,
     ) -> bool;
+
+    /// Returns a k-ary Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits.
+    /// The leaf hasher uses the domain `AleoBHP1024`, and the path hasher uses the domain `AleoBHP512`.
+    fn merkle_tree_k_ary_bhp<const DEPTH: u8, const ARITY: u8>(
+        leaves: &[Vec<bool>],
+    ) -> Result<BHPKaryMerkleTree<Self, DEPTH, ARITY>>;
+
+    /// Returns a k-ary Merkle tree with a Poseidon leaf hasher with input rate of 4 and a Poseidon path hasher with input rate of 2.
+    /// The leaf hasher uses the domain `AleoPoseidon4`, and the path hasher uses the domain `AleoPoseidon2`.
+    fn merkle_tree_k_ary_psd<const DEPTH: u8, const ARITY: u8>(
+        leaves: &[Vec<Field<Self>>],
+    ) -> Result<PoseidonKaryMerkleTree<Self, DEPTH, ARITY>>;
+
+    /// Returns `true` if the given k-ary Merkle path is valid for the given root and leaf.
+    #[allow(clippy::ptr_arg)]
+    fn verify_k_ary_merkle_path_bhp<const DEPTH: u8, const ARITY: u8>(
+        path: &BHPKaryMerklePath<Self, DEPTH, ARITY>,
+        root: &Field<Self>,
+        leaf: &Vec<bool>,
+    ) -> bool;
+
+    /// Returns `true` if the given k-ary Merkle path is valid for the given root and leaf.
+    #[allow(clippy::ptr_arg)]
+    fn verify_k_ary_merkle_path_psd<const DEPTH: u8, const ARITY: u8>(
+        path: &PoseidonKaryMerklePath<Self, DEPTH, ARITY>,
+        root: &Field<Self>,
+        leaf: &Vec<Field<Self>>,
+    ) -> bool;
 }
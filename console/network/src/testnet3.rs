@@ -33,40 +33,9 @@ use snarkvm_console_algorithms::{
 };
 
 lazy_static! {
-    /// The group bases for the Aleo signature and encryption schemes.
-    pub static ref GENERATOR_G: Vec<Group<Testnet3>> = Testnet3::new_bases("AleoAccountEncryptionAndSignatureScheme0");
-
     /// The Varuna sponge parameters.
     pub static ref VARUNA_FS_PARAMETERS: FiatShamirParameters<Testnet3> = FiatShamir::<Testnet3>::sample_parameters();
 
-    /// The encryption domain as a constant field element.
-    pub static ref ENCRYPTION_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoSymmetricEncryption0");
-    /// The graph key domain as a constant field element.
-    pub static ref GRAPH_KEY_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoGraphKey0");
-    /// The serial number domain as a constant field element.
-    pub static ref SERIAL_NUMBER_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoSerialNumber0");
-
-    /// The BHP hash function, which can take an input of up to 256 bits.
-    pub static ref BHP_256: BHP256<Testnet3> = BHP256::<Testnet3>::setup("AleoBHP256").expect("Failed to setup BHP256");
-    /// The BHP hash function, which can take an input of up to 512 bits.
-    pub static ref BHP_512: BHP512<Testnet3> = BHP512::<Testnet3>::setup("AleoBHP512").expect("Failed to setup BHP512");
-    /// The BHP hash function, which can take an input of up to 768 bits.
-    pub static ref BHP_768: BHP768<Testnet3> = BHP768::<Testnet3>::setup("AleoBHP768").expect("Failed to setup BHP768");
-    /// The BHP hash function, which can take an input of up to 1024 bits.
-    pub static ref BHP_1024: BHP1024<Testnet3> = BHP1024::<Testnet3>::setup("AleoBHP1024").expect("Failed to setup BHP1024");
-
-    /// The Pedersen hash function, which can take an input of up to 64 bits.
-    pub static ref PEDERSEN_64: Pedersen64<Testnet3> = Pedersen64::<Testnet3>::setup("AleoPedersen64");
-    /// The Pedersen hash function, which can take an input of up to 128 bits.
-    pub static ref PEDERSEN_128: Pedersen128<Testnet3> = Pedersen128::<Testnet3>::setup("AleoPedersen128");
-
-    /// The Poseidon hash function, using a rate of 2.
-    pub static ref POSEIDON_2: Poseidon2<Testnet3> = Poseidon2::<Testnet3>::setup("AleoPoseidon2").expect("Failed to setup Poseidon2");
-    /// The Poseidon hash function, using a rate of 4.
-    pub static ref POSEIDON_4: Poseidon4<Testnet3> = Poseidon4::<Testnet3>::setup("AleoPoseidon4").expect("Failed to setup Poseidon4");
-    /// The Poseidon hash function, using a rate of 8.
-    pub static ref POSEIDON_8: Poseidon8<Testnet3> = Poseidon8::<Testnet3>::setup("AleoPoseidon8").expect("Failed to setup Poseidon8");
-
     pub static ref CREDITS_PROVING_KEYS: IndexMap<String, Arc<VarunaProvingKey<Console>>> = {
         let mut map = IndexMap::new();
         snarkvm_parameters::insert_credit_keys!(map, VarunaProvingKey<Console>, Prover);
@@ -84,22 +53,7 @@ pub const TRANSACTION_PREFIX: &str = "at";
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Testnet3;
 
-impl Testnet3 {
-    /// Initializes a new instance of group bases from a given input domain message.
-    fn new_bases(message: &str) -> Vec<Group<Self>> {
-        // Hash the given message to a point on the curve, to initialize the starting base.
-        let (base, _, _) = Blake2Xs::hash_to_curve::<<Self as Environment>::Affine>(message);
-
-        // Compute the bases up to the size of the scalar field (in bits).
-        let mut g = Group::<Self>::new(base);
-        let mut g_bases = Vec::with_capacity(Scalar::<Self>::size_in_bits());
-        for _ in 0..Scalar::<Self>::size_in_bits() {
-            g_bases.push(g);
-            g = g.double();
-        }
-        g_bases
-    }
-}
+cryptographic_parameters!(Testnet3);
 
 impl Environment for Testnet3 {
     type Affine = <Console as Environment>::Affine;
@@ -141,8 +95,8 @@ impl Network for Testnet3 {
     const NAME: &'static str = "Aleo Testnet 3";
 
     /// Returns the genesis block bytes.
-    fn genesis_bytes() -> &'static [u8] {
-        snarkvm_parameters::testnet3::GenesisBytes::load_bytes()
+    fn genesis_bytes() -> Result<&'static [u8]> {
+        Ok(snarkvm_parameters::testnet3::GenesisBytes::load_bytes())
     }
 
     /// Returns the proving key for the given function name in `credits.aleo`.
@@ -183,23 +137,6 @@ impl Network for Testnet3 {
         })
     }
 
-    /// Returns the powers of `G`.
-    fn g_powers() -> &'static Vec<Group<Self>> {
-        &GENERATOR_G
-    }
-
-    /// Returns the scalar multiplication on the generator `G`.
-    fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
-        GENERATOR_G
-            .iter()
-            .zip_eq(&scalar.to_bits_le())
-            .filter_map(|(base, bit)| match bit {
-                true => Some(base),
-                false => None,
-            })
-            .sum()
-    }
-
     /// Returns the Varuna universal prover.
     fn varuna_universal_prover() -> &'static UniversalProver<Self::PairingCurve> {
         static INSTANCE: OnceCell<UniversalProver<<Console as Environment>::PairingCurve>> = OnceCell::new();
@@ -227,258 +164,7 @@ impl Network for Testnet3 {
         &VARUNA_FS_PARAMETERS
     }
 
-    /// Returns the encryption domain as a constant field element.
-    fn encryption_domain() -> Field<Self> {
-        *ENCRYPTION_DOMAIN
-    }
-
-    /// Returns the graph key domain as a constant field element.
-    fn graph_key_domain() -> Field<Self> {
-        *GRAPH_KEY_DOMAIN
-    }
-
-    /// Returns the serial number domain as a constant field element.
-    fn serial_number_domain() -> Field<Self> {
-        *SERIAL_NUMBER_DOMAIN
-    }
-
-    /// Returns a BHP commitment with an input hasher of 256-bits and randomizer.
-    fn commit_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
-        BHP_256.commit(input, randomizer)
-    }
-
-    /// Returns a BHP commitment with an input hasher of 512-bits and randomizer.
-    fn commit_bhp512(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
-        BHP_512.commit(input, randomizer)
-    }
-
-    /// Returns a BHP commitment with an input hasher of 768-bits and randomizer.
-    fn commit_bhp768(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
-        BHP_768.commit(input, randomizer)
-    }
-
-    /// Returns a BHP commitment with an input hasher of 1024-bits and randomizer.
-    fn commit_bhp1024(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
-        BHP_1024.commit(input, randomizer)
-    }
-
-    /// Returns a Pedersen commitment for the given (up to) 64-bit input and randomizer.
-    fn commit_ped64(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
-        PEDERSEN_64.commit(input, randomizer)
-    }
-
-    /// Returns a Pedersen commitment for the given (up to) 128-bit input and randomizer.
-    fn commit_ped128(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
-        PEDERSEN_128.commit(input, randomizer)
-    }
-
-    /// Returns a BHP commitment with an input hasher of 256-bits and randomizer.
-    fn commit_to_group_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
-        BHP_256.commit_uncompressed(input, randomizer)
-    }
-
-    /// Returns a BHP commitment with an input hasher of 512-bits and randomizer.
-    fn commit_to_group_bhp512(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
-        BHP_512.commit_uncompressed(input, randomizer)
-    }
-
-    /// Returns a BHP commitment with an input hasher of 768-bits and randomizer.
-    fn commit_to_group_bhp768(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
-        BHP_768.commit_uncompressed(input, randomizer)
-    }
-
-    /// Returns a BHP commitment with an input hasher of 1024-bits and randomizer.
-    fn commit_to_group_bhp1024(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
-        BHP_1024.commit_uncompressed(input, randomizer)
-    }
-
-    /// Returns a Pedersen commitment for the given (up to) 64-bit input and randomizer.
-    fn commit_to_group_ped64(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
-        PEDERSEN_64.commit_uncompressed(input, randomizer)
-    }
-
-    /// Returns a Pedersen commitment for the given (up to) 128-bit input and randomizer.
-    fn commit_to_group_ped128(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
-        PEDERSEN_128.commit_uncompressed(input, randomizer)
-    }
-
-    /// Returns the BHP hash with an input hasher of 256-bits.
-    fn hash_bhp256(input: &[bool]) -> Result<Field<Self>> {
-        BHP_256.hash(input)
-    }
-
-    /// Returns the BHP hash with an input hasher of 512-bits.
-    fn hash_bhp512(input: &[bool]) -> Result<Field<Self>> {
-        BHP_512.hash(input)
-    }
-
-    /// Returns the BHP hash with an input hasher of 768-bits.
-    fn hash_bhp768(input: &[bool]) -> Result<Field<Self>> {
-        BHP_768.hash(input)
-    }
-
-    /// Returns the BHP hash with an input hasher of 1024-bits.
-    fn hash_bhp1024(input: &[bool]) -> Result<Field<Self>> {
-        BHP_1024.hash(input)
-    }
-
-    /// Returns the Keccak hash with a 256-bit output.
-    fn hash_keccak256(input: &[bool]) -> Result<Vec<bool>> {
-        Keccak256::default().hash(input)
-    }
-
-    /// Returns the Keccak hash with a 384-bit output.
-    fn hash_keccak384(input: &[bool]) -> Result<Vec<bool>> {
-        Keccak384::default().hash(input)
-    }
-
-    /// Returns the Keccak hash with a 512-bit output.
-    fn hash_keccak512(input: &[bool]) -> Result<Vec<bool>> {
-        Keccak512::default().hash(input)
-    }
-
-    /// Returns the Pedersen hash for a given (up to) 64-bit input.
-    fn hash_ped64(input: &[bool]) -> Result<Field<Self>> {
-        PEDERSEN_64.hash(input)
-    }
-
-    /// Returns the Pedersen hash for a given (up to) 128-bit input.
-    fn hash_ped128(input: &[bool]) -> Result<Field<Self>> {
-        PEDERSEN_128.hash(input)
-    }
-
-    /// Returns the Poseidon hash with an input rate of 2.
-    fn hash_psd2(input: &[Field<Self>]) -> Result<Field<Self>> {
-        POSEIDON_2.hash(input)
-    }
-
-    /// Returns the Poseidon hash with an input rate of 4.
-    fn hash_psd4(input: &[Field<Self>]) -> Result<Field<Self>> {
-        POSEIDON_4.hash(input)
-    }
-
-    /// Returns the Poseidon hash with an input rate of 8.
-    fn hash_psd8(input: &[Field<Self>]) -> Result<Field<Self>> {
-        POSEIDON_8.hash(input)
-    }
-
-    /// Returns the SHA-3 hash with a 256-bit output.
-    fn hash_sha3_256(input: &[bool]) -> Result<Vec<bool>> {
-        Sha3_256::default().hash(input)
-    }
-
-    /// Returns the SHA-3 hash with a 384-bit output.
-    fn hash_sha3_384(input: &[bool]) -> Result<Vec<bool>> {
-        Sha3_384::default().hash(input)
-    }
-
-    /// Returns the SHA-3 hash with a 512-bit output.
-    fn hash_sha3_512(input: &[bool]) -> Result<Vec<bool>> {
-        Sha3_512::default().hash(input)
-    }
-
-    /// Returns the extended Poseidon hash with an input rate of 2.
-    fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
-        POSEIDON_2.hash_many(input, num_outputs)
-    }
-
-    /// Returns the extended Poseidon hash with an input rate of 4.
-    fn hash_many_psd4(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
-        POSEIDON_4.hash_many(input, num_outputs)
-    }
-
-    /// Returns the extended Poseidon hash with an input rate of 8.
-    fn hash_many_psd8(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
-        POSEIDON_8.hash_many(input, num_outputs)
-    }
-
-    /// Returns the BHP hash with an input hasher of 256-bits.
-    fn hash_to_group_bhp256(input: &[bool]) -> Result<Group<Self>> {
-        BHP_256.hash_uncompressed(input)
-    }
-
-    /// Returns the BHP hash with an input hasher of 512-bits.
-    fn hash_to_group_bhp512(input: &[bool]) -> Result<Group<Self>> {
-        BHP_512.hash_uncompressed(input)
-    }
-
-    /// Returns the BHP hash with an input hasher of 768-bits.
-    fn hash_to_group_bhp768(input: &[bool]) -> Result<Group<Self>> {
-        BHP_768.hash_uncompressed(input)
-    }
-
-    /// Returns the BHP hash with an input hasher of 1024-bits.
-    fn hash_to_group_bhp1024(input: &[bool]) -> Result<Group<Self>> {
-        BHP_1024.hash_uncompressed(input)
-    }
-
-    /// Returns the Pedersen hash for a given (up to) 64-bit input.
-    fn hash_to_group_ped64(input: &[bool]) -> Result<Group<Self>> {
-        PEDERSEN_64.hash_uncompressed(input)
-    }
-
-    /// Returns the Pedersen hash for a given (up to) 128-bit input.
-    fn hash_to_group_ped128(input: &[bool]) -> Result<Group<Self>> {
-        PEDERSEN_128.hash_uncompressed(input)
-    }
-
-    /// Returns the Poseidon hash with an input rate of 2 on the affine curve.
-    fn hash_to_group_psd2(input: &[Field<Self>]) -> Result<Group<Self>> {
-        POSEIDON_2.hash_to_group(input)
-    }
-
-    /// Returns the Poseidon hash with an input rate of 4 on the affine curve.
-    fn hash_to_group_psd4(input: &[Field<Self>]) -> Result<Group<Self>> {
-        POSEIDON_4.hash_to_group(input)
-    }
-
-    /// Returns the Poseidon hash with an input rate of 8 on the affine curve.
-    fn hash_to_group_psd8(input: &[Field<Self>]) -> Result<Group<Self>> {
-        POSEIDON_8.hash_to_group(input)
-    }
-
-    /// Returns the Poseidon hash with an input rate of 2 on the scalar field.
-    fn hash_to_scalar_psd2(input: &[Field<Self>]) -> Result<Scalar<Self>> {
-        POSEIDON_2.hash_to_scalar(input)
-    }
-
-    /// Returns the Poseidon hash with an input rate of 4 on the scalar field.
-    fn hash_to_scalar_psd4(input: &[Field<Self>]) -> Result<Scalar<Self>> {
-        POSEIDON_4.hash_to_scalar(input)
-    }
-
-    /// Returns the Poseidon hash with an input rate of 8 on the scalar field.
-    fn hash_to_scalar_psd8(input: &[Field<Self>]) -> Result<Scalar<Self>> {
-        POSEIDON_8.hash_to_scalar(input)
-    }
-
-    /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits.
-    fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>> {
-        MerkleTree::new(&*BHP_1024, &*BHP_512, leaves)
-    }
-
-    /// Returns a Merkle tree with a Poseidon leaf hasher with input rate of 4 and a Poseidon path hasher with input rate of 2.
-    fn merkle_tree_psd<const DEPTH: u8>(leaves: &[Vec<Field<Self>>]) -> Result<PoseidonMerkleTree<Self, DEPTH>> {
-        MerkleTree::new(&*POSEIDON_4, &*POSEIDON_2, leaves)
-    }
-
-    /// Returns `true` if the given Merkle path is valid for the given root and leaf.
-    fn verify_merkle_path_bhp<const DEPTH: u8>(
-        path: &MerklePath<Self, DEPTH>,
-        root: &Field<Self>,
-        leaf: &Vec<bool>,
-    ) -> bool {
-        path.verify(&*BHP_1024, &*BHP_512, root, leaf)
-    }
-
-    /// Returns `true` if the given Merkle path is valid for the given root and leaf.
-    fn verify_merkle_path_psd<const DEPTH: u8>(
-        path: &MerklePath<Self, DEPTH>,
-        root: &Field<Self>,
-        leaf: &Vec<Field<Self>>,
-    ) -> bool {
-        path.verify(&*POSEIDON_4, &*POSEIDON_2, root, leaf)
-    }
+    cryptographic_functions!();
 }
 
 #[cfg(test)]
@@ -494,4 +180,64 @@ mod tests {
         let group = CurrentNetwork::g_scalar_multiply(&scalar);
         assert_eq!(group, CurrentNetwork::g_powers()[0] * scalar);
     }
+
+    #[test]
+    fn test_merkle_tree_k_ary_bhp() {
+        const DEPTH: u8 = 4;
+        const ARITY: u8 = 4;
+
+        let rng = &mut TestRng::default();
+        let leaves = (0..20).map(|_| Field::<CurrentNetwork>::rand(rng).to_bits_le()).collect::<Vec<_>>();
+
+        // Ensure two independent call sites produce the same root.
+        let tree = CurrentNetwork::merkle_tree_k_ary_bhp::<DEPTH, ARITY>(&leaves).unwrap();
+        let other = std::thread::spawn({
+            let leaves = leaves.clone();
+            move || *CurrentNetwork::merkle_tree_k_ary_bhp::<DEPTH, ARITY>(&leaves).unwrap().root()
+        });
+        assert_eq!(tree.root(), &other.join().unwrap());
+
+        // Ensure the root matches a tree constructed with the documented hashers.
+        let leaf_hasher = BHP1024::<CurrentNetwork>::setup("AleoBHP1024").unwrap();
+        let path_hasher = BHP512::<CurrentNetwork>::setup("AleoBHP512").unwrap();
+        let expected = KaryMerkleTree::<_, _, DEPTH, ARITY>::new(&leaf_hasher, &path_hasher, &leaves).unwrap();
+        assert_eq!(tree.root(), expected.root());
+
+        // Ensure the Merkle paths verify.
+        for (index, leaf) in leaves.iter().enumerate() {
+            let path = tree.prove(index, leaf).unwrap();
+            assert!(CurrentNetwork::verify_k_ary_merkle_path_bhp(&path, tree.root(), leaf));
+            assert!(!CurrentNetwork::verify_k_ary_merkle_path_bhp(&path, &Field::rand(rng), leaf));
+        }
+    }
+
+    #[test]
+    fn test_merkle_tree_k_ary_psd() {
+        const DEPTH: u8 = 4;
+        const ARITY: u8 = 8;
+
+        let rng = &mut TestRng::default();
+        let leaves = (0..50).map(|_| vec![Field::<CurrentNetwork>::rand(rng)]).collect::<Vec<_>>();
+
+        // Ensure two independent call sites produce the same root.
+        let tree = CurrentNetwork::merkle_tree_k_ary_psd::<DEPTH, ARITY>(&leaves).unwrap();
+        let other = std::thread::spawn({
+            let leaves = leaves.clone();
+            move || *CurrentNetwork::merkle_tree_k_ary_psd::<DEPTH, ARITY>(&leaves).unwrap().root()
+        });
+        assert_eq!(tree.root(), &other.join().unwrap());
+
+        // Ensure the root matches a tree constructed with the documented hashers.
+        let leaf_hasher = Poseidon4::<CurrentNetwork>::setup("AleoPoseidon4").unwrap();
+        let path_hasher = Poseidon2::<CurrentNetwork>::setup("AleoPoseidon2").unwrap();
+        let expected = KaryMerkleTree::<_, _, DEPTH, ARITY>::new(&leaf_hasher, &path_hasher, &leaves).unwrap();
+        assert_eq!(tree.root(), expected.root());
+
+        // Ensure the Merkle paths verify.
+        for (index, leaf) in leaves.iter().enumerate() {
+            let path = tree.prove(index, leaf).unwrap();
+            assert!(CurrentNetwork::verify_k_ary_merkle_path_psd(&path, tree.root(), leaf));
+            assert!(!CurrentNetwork::verify_k_ary_merkle_path_psd(&path, &Field::rand(rng), leaf));
+        }
+    }
 }
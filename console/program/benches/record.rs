@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_console_account::{Address, PrivateKey, ViewKey};
+use snarkvm_console_network::{environment::prelude::*, Network, Testnet3};
+use snarkvm_console_program::{Ciphertext, Entry, Identifier, Literal, Owner, Plaintext, Record};
+use snarkvm_console_types::{Field, Scalar, U64};
+
+use criterion::Criterion;
+use indexmap::IndexMap;
+
+type CurrentNetwork = Testnet3;
+
+/// Samples a record ciphertext with a private owner and private data, owned by a random account.
+fn sample_record(rng: &mut TestRng) -> Record<CurrentNetwork, Ciphertext<CurrentNetwork>> {
+    let owner = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    let data = IndexMap::from_iter([
+        (Identifier::from_str("amount").unwrap(), Entry::Private(Plaintext::from(Literal::U64(U64::rand(rng))))),
+        (Identifier::from_str("memo").unwrap(), Entry::Private(Plaintext::from(Literal::Field(Field::rand(rng))))),
+        (Identifier::from_str("salt").unwrap(), Entry::Private(Plaintext::from(Literal::Scalar(Scalar::rand(rng))))),
+    ]);
+    let randomizer = Scalar::rand(rng);
+    let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+    let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_plaintext(
+        Owner::Private(Plaintext::from(Literal::Address(owner))),
+        data,
+        nonce,
+    )
+    .unwrap();
+    record.encrypt(randomizer).unwrap()
+}
+
+fn record_is_owner_not_owned(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    // Sample records that are not owned by the view key.
+    let records = (0..100).map(|_| sample_record(rng)).collect::<Vec<_>>();
+    let view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    let address = view_key.to_address();
+
+    c.bench_function("record_is_owner_not_owned", |b| {
+        b.iter(|| records.iter().filter(|record| record.is_owner(&address, &view_key)).count())
+    });
+}
+
+fn record_decrypt_not_owned(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    // Sample records that are not owned by the view key.
+    let records = (0..100).map(|_| sample_record(rng)).collect::<Vec<_>>();
+    let view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+    c.bench_function("record_decrypt_not_owned", |b| {
+        b.iter(|| records.iter().filter(|record| record.decrypt(&view_key).is_ok()).count())
+    });
+}
+
+criterion_group! {
+    name = record;
+    config = Criterion::default().sample_size(20);
+    targets = record_is_owner_not_owned, record_decrypt_not_owned
+}
+
+criterion_main!(record);
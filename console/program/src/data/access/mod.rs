@@ -16,7 +16,7 @@ mod bytes;
 mod parse;
 mod serialize;
 
-use crate::{Identifier, U32};
+use crate::{Identifier, U16, U32, U8};
 use snarkvm_console_network::prelude::*;
 
 /// A helper type for accessing an entry in a register, struct, array, or record.
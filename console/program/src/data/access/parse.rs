@@ -17,12 +17,29 @@ use super::*;
 impl<N: Network> Parser for Access<N> {
     fn parse(string: &str) -> ParserResult<Self> {
         alt((
-            map(pair(tag("["), pair(U32::parse, tag("]"))), |(_, (index, _))| Self::Index(index)),
+            map(pair(tag("["), pair(parse_index, tag("]"))), |(_, (index, _))| Self::Index(index)),
             map(pair(tag("."), Identifier::parse), |(_, identifier)| Self::Member(identifier)),
         ))(string)
     }
 }
 
+/// Parses an array index as a `u32`, `u16`, or `u8` literal, or as an integer without a type (defaulting to `u32`).
+/// The index is normalized to a `u32`.
+fn parse_index<N: Network>(string: &str) -> ParserResult<U32<N>> {
+    // Parses an integer without a type, as a `u32`.
+    let parse_untyped =
+        map_res(recognize(many1(terminated(one_of("0123456789"), many0(char('_'))))), |digits: &str| {
+            digits.replace('_', "").parse::<u32>().map(U32::new)
+        });
+
+    alt((
+        U32::parse,
+        map(U16::<N>::parse, |index| U32::new(u32::from(*index))),
+        map(U8::<N>::parse, |index| U32::new(u32::from(*index))),
+        parse_untyped,
+    ))(string)
+}
+
 impl<N: Network> FromStr for Access<N> {
     type Err = Error;
 
@@ -74,6 +91,47 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_index() -> Result<()> {
+        // Ensure every accepted spelling is normalized to a `u32` index.
+        for (string, expected) in [
+            ("[0u32]", 0),
+            ("[7u32]", 7),
+            ("[4294967295u32]", u32::MAX),
+            ("[0u16]", 0),
+            ("[65535u16]", u16::MAX as u32),
+            ("[0u8]", 0),
+            ("[255u8]", u8::MAX as u32),
+            ("[0]", 0),
+            ("[1_000]", 1000),
+            ("[4294967295]", u32::MAX),
+        ] {
+            let access = Access::<CurrentNetwork>::from_str(string)?;
+            assert_eq!(access, Access::Index(U32::new(expected)), "Failed to parse '{string}'");
+            // Ensure the access is displayed as a `u32` index.
+            assert_eq!(access.to_string(), format!("[{expected}u32]"));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_index_fails() {
+        // Negative indices.
+        assert!(Access::<CurrentNetwork>::from_str("[-1]").is_err());
+        assert!(Access::<CurrentNetwork>::from_str("[-1u8]").is_err());
+        assert!(Access::<CurrentNetwork>::from_str("[-1u16]").is_err());
+        assert!(Access::<CurrentNetwork>::from_str("[-1u32]").is_err());
+        // Out-of-range indices.
+        assert!(Access::<CurrentNetwork>::from_str("[256u8]").is_err());
+        assert!(Access::<CurrentNetwork>::from_str("[65536u16]").is_err());
+        assert!(Access::<CurrentNetwork>::from_str("[4294967296u32]").is_err());
+        assert!(Access::<CurrentNetwork>::from_str("[4294967296]").is_err());
+        // Unsupported types.
+        assert!(Access::<CurrentNetwork>::from_str("[0u64]").is_err());
+        assert!(Access::<CurrentNetwork>::from_str("[0i8]").is_err());
+        assert!(Access::<CurrentNetwork>::from_str("[0field]").is_err());
+    }
+
     #[test]
     fn test_parse_fails() -> Result<()> {
         // Must be non-empty.
@@ -23,6 +23,21 @@ impl<N: Network> PartialEq for Identifier<N> {
     }
 }
 
+impl<N: Network> Ord for Identifier<N> {
+    /// Returns the canonical ordering of `self` and `other`, by their field encoding.
+    /// Note that this is not a lexicographic ordering of the identifier strings.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<N: Network> PartialOrd for Identifier<N> {
+    /// Returns the canonical ordering of `self` and `other`, by their field encoding.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl<N: Network> core::hash::Hash for Identifier<N> {
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.0.hash(state)
@@ -21,6 +21,9 @@ mod serialize;
 mod size_in_bits;
 mod to_bits;
 mod to_field;
+mod validate;
+
+pub use validate::IdentifierError;
 
 use snarkvm_console_network::Network;
 use snarkvm_console_types::{prelude::*, Field};
@@ -116,6 +119,17 @@ pub(crate) mod tests {
         Ok(string.to_lowercase())
     }
 
+    /// Returns `true` if the given string is a valid identifier.
+    /// This is a byte-level reference specification, written independently of `Identifier::validate`.
+    pub(crate) fn is_valid_identifier_string<N: Network>(string: &str) -> bool {
+        let bytes = string.as_bytes();
+        // Note: Every byte of a multi-byte UTF-8 character is outside of the ASCII range.
+        !bytes.is_empty()
+            && bytes.len() <= Field::<N>::size_in_data_bits() / 8
+            && bytes[0].is_ascii_alphabetic()
+            && bytes.iter().all(|byte| byte.is_ascii_alphanumeric() || *byte == b'_')
+    }
+
     #[test]
     fn test_try_from() -> Result<()> {
         let mut rng = TestRng::default();
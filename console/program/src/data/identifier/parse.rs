@@ -35,22 +35,8 @@ impl<N: Network> FromStr for Identifier<N> {
 
     /// Reads in an identifier from a string.
     fn from_str(identifier: &str) -> Result<Self, Self::Err> {
-        // Ensure the identifier is not an empty string, and starts with an ASCII letter.
-        match identifier.chars().next() {
-            Some(character) => ensure!(character.is_ascii_alphabetic(), "Identifier must start with a letter"),
-            None => bail!("Identifier cannot be empty"),
-        }
-
-        // Ensure the identifier consists of ASCII letters, ASCII digits, and underscores.
-        if identifier.chars().any(|character| !character.is_ascii_alphanumeric() && character != '_') {
-            bail!("Identifier '{identifier}' must consist of letters, digits, and underscores")
-        }
-
-        // Ensure identifier fits within the data capacity of the base field.
-        let max_bytes = Field::<N>::size_in_data_bits() / 8; // Note: This intentionally rounds down.
-        if identifier.len() > max_bytes {
-            bail!("Identifier is too large. Identifiers must be <= {max_bytes} bytes long")
-        }
+        // Ensure the identifier is valid.
+        Self::validate(identifier)?;
 
         // Note: The string bytes themselves are **not** little-endian. Rather, they are order-preserving
         // for reconstructing the string when recovering the field element back into bytes.
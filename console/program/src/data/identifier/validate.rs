@@ -0,0 +1,192 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The reason a string is not a valid identifier.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IdentifierError {
+    /// The identifier is an empty string.
+    Empty,
+    /// The identifier does not start with an ASCII letter.
+    InvalidStart { character: char },
+    /// The identifier contains a character that is not an ASCII letter, ASCII digit, or underscore.
+    InvalidCharacter { character: char, position: usize },
+    /// The identifier exceeds the data capacity of the base field.
+    TooLong { length: usize, max_length: usize },
+}
+
+impl IdentifierError {
+    /// Returns the error with its character position shifted by the given offset.
+    pub(crate) fn with_offset(self, offset: usize) -> Self {
+        match self {
+            Self::InvalidCharacter { character, position } => {
+                Self::InvalidCharacter { character, position: position + offset }
+            }
+            error => error,
+        }
+    }
+}
+
+impl Display for IdentifierError {
+    /// Prints the identifier error as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "Identifier cannot be empty"),
+            Self::InvalidStart { character } => write!(f, "Identifier must start with a letter, found '{character}'"),
+            Self::InvalidCharacter { character, position } => write!(
+                f,
+                "Identifier must consist of letters, digits, and underscores, found '{character}' at position {position}"
+            ),
+            Self::TooLong { length, max_length } => write!(
+                f,
+                "Identifier is too large ({length} bytes). Identifiers must be <= {max_length} bytes long"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for IdentifierError {}
+
+impl<N: Network> Identifier<N> {
+    /// Checks that the given string is a valid identifier, and returns the reason if it is not.
+    ///
+    /// # Requirements
+    /// The identifier must not be an empty string.
+    /// The identifier must start with a letter.
+    /// The identifier must be alphanumeric, and may include underscores.
+    /// The identifier must fit within the data capacity of a base field element.
+    pub fn validate(identifier: &str) -> Result<(), IdentifierError> {
+        let mut characters = identifier.char_indices();
+
+        // Ensure the identifier is not an empty string, and starts with an ASCII letter.
+        match characters.next() {
+            Some((_, character)) if !character.is_ascii_alphabetic() => {
+                return Err(IdentifierError::InvalidStart { character });
+            }
+            Some(_) => (),
+            None => return Err(IdentifierError::Empty),
+        }
+
+        // Ensure the identifier consists of ASCII letters, ASCII digits, and underscores.
+        if let Some((position, character)) =
+            characters.find(|(_, character)| !character.is_ascii_alphanumeric() && *character != '_')
+        {
+            return Err(IdentifierError::InvalidCharacter { character, position });
+        }
+
+        // Ensure identifier fits within the data capacity of the base field.
+        let max_length = Field::<N>::size_in_data_bits() / 8; // Note: This intentionally rounds down.
+        if identifier.len() > max_length {
+            return Err(IdentifierError::TooLong { length: identifier.len(), max_length });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::identifier::tests::is_valid_identifier_string;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 10_000;
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(Identifier::<CurrentNetwork>::validate("foo_bar1"), Ok(()));
+        assert_eq!(Identifier::<CurrentNetwork>::validate(""), Err(IdentifierError::Empty));
+        assert_eq!(
+            Identifier::<CurrentNetwork>::validate("1foo"),
+            Err(IdentifierError::InvalidStart { character: '1' })
+        );
+        assert_eq!(
+            Identifier::<CurrentNetwork>::validate("_foo"),
+            Err(IdentifierError::InvalidStart { character: '_' })
+        );
+        assert_eq!(
+            Identifier::<CurrentNetwork>::validate("foo-bar"),
+            Err(IdentifierError::InvalidCharacter { character: '-', position: 3 })
+        );
+        assert_eq!(
+            Identifier::<CurrentNetwork>::validate("fooé"),
+            Err(IdentifierError::InvalidCharacter { character: 'é', position: 3 })
+        );
+
+        let max_length = Field::<CurrentNetwork>::size_in_data_bits() / 8;
+        assert_eq!(Identifier::<CurrentNetwork>::validate(&"a".repeat(max_length)), Ok(()));
+        assert_eq!(
+            Identifier::<CurrentNetwork>::validate(&"a".repeat(max_length + 1)),
+            Err(IdentifierError::TooLong { length: max_length + 1, max_length })
+        );
+    }
+
+    #[test]
+    fn test_validate_matches_specification() {
+        let max_length = Field::<CurrentNetwork>::size_in_data_bits() / 8;
+
+        // Note: The strings are chosen to probe the boundaries of the specification.
+        let mut strings = [
+            "",
+            "a",
+            "Z",
+            "a9_",
+            "_",
+            "_a",
+            "9a",
+            "a-b",
+            "a.b",
+            " a",
+            "a ",
+            "a\t",
+            "a\n",
+            "a\0",
+            "\0",
+            "é",
+            "aé",
+            "\u{FF41}",
+            "a\u{200B}",
+            "a\u{0301}",
+            "a\u{0663}",
+            "\u{212A}",
+            "a\u{FEFF}",
+            "ß",
+            "\u{0130}",
+        ]
+        .map(String::from)
+        .to_vec();
+        strings.push("a".repeat(max_length));
+        strings.push("a".repeat(max_length + 1));
+        strings.push("a".repeat(max_length - 1) + "é");
+        strings.push("a".repeat(max_length - 2) + "é");
+
+        // Sample random strings.
+        let mut rng = TestRng::default();
+        let characters = ['a', 'z', 'A', 'Z', '0', '9', '_', '-', '.', ' ', '\0', 'é', '\u{212A}', '\u{0663}'];
+        for _ in 0..ITERATIONS {
+            let length = rng.gen_range(0..max_length + 4);
+            strings.push((0..length).map(|_| characters[rng.gen_range(0..characters.len())]).collect());
+        }
+
+        for string in strings {
+            // Ensure the string is accepted by `validate` and `from_str` iff it satisfies the specification.
+            let expected = is_valid_identifier_string::<CurrentNetwork>(&string);
+            assert_eq!(Identifier::<CurrentNetwork>::validate(&string).is_ok(), expected, "{string:?}");
+            assert_eq!(Identifier::<CurrentNetwork>::from_str(&string).is_ok(), expected, "{string:?}");
+        }
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Ord for Literal<N> {
+    /// Returns the canonical ordering of `self` and `other`.
+    ///
+    /// Literals are ordered first by their type (in the order of `Literal::variant`), and then by value.
+    /// Note that this is a *canonical* ordering, and not a numeric one, across literal types (e.g. `1u8 < 0u16`).
+    ///
+    /// Within a type, booleans, integers, fields, and scalars are ordered by their numeric value,
+    /// strings are ordered by their UTF-8 bytes, addresses and groups are ordered by their x-coordinate,
+    /// and signatures are ordered by their components, in the order they are serialized.
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Address(a), Self::Address(b)) => a.to_x_coordinate().cmp(&b.to_x_coordinate()),
+            (Self::Boolean(a), Self::Boolean(b)) => (**a).cmp(&**b),
+            (Self::Field(a), Self::Field(b)) => a.cmp(b),
+            (Self::Group(a), Self::Group(b)) => a.to_x_coordinate().cmp(&b.to_x_coordinate()),
+            (Self::I8(a), Self::I8(b)) => a.cmp(b),
+            (Self::I16(a), Self::I16(b)) => a.cmp(b),
+            (Self::I32(a), Self::I32(b)) => a.cmp(b),
+            (Self::I64(a), Self::I64(b)) => a.cmp(b),
+            (Self::I128(a), Self::I128(b)) => a.cmp(b),
+            (Self::U8(a), Self::U8(b)) => a.cmp(b),
+            (Self::U16(a), Self::U16(b)) => a.cmp(b),
+            (Self::U32(a), Self::U32(b)) => a.cmp(b),
+            (Self::U64(a), Self::U64(b)) => a.cmp(b),
+            (Self::U128(a), Self::U128(b)) => a.cmp(b),
+            (Self::Scalar(a), Self::Scalar(b)) => a.cmp(b),
+            (Self::Signature(a), Self::Signature(b)) => {
+                let (key_a, key_b) = (a.compute_key(), b.compute_key());
+                a.challenge()
+                    .cmp(&b.challenge())
+                    .then_with(|| a.response().cmp(&b.response()))
+                    .then_with(|| key_a.pk_sig().to_x_coordinate().cmp(&key_b.pk_sig().to_x_coordinate()))
+                    .then_with(|| key_a.pr_sig().to_x_coordinate().cmp(&key_b.pr_sig().to_x_coordinate()))
+            }
+            (Self::String(a), Self::String(b)) => a.as_bytes().cmp(b.as_bytes()),
+            _ => self.variant().cmp(&other.variant()),
+        }
+    }
+}
+
+impl<N: Network> PartialOrd for Literal<N> {
+    /// Returns the canonical ordering of `self` and `other`.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    use num_traits::FromPrimitive;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u32 = 100;
+
+    /// Returns a list of randomly-sampled literals, of every literal type.
+    fn sample_literals(rng: &mut TestRng) -> Vec<Literal<CurrentNetwork>> {
+        let mut literals = Vec::new();
+        for variant in 0..17 {
+            let literal_type = LiteralType::from_u8(variant).unwrap();
+            for _ in 0..4 {
+                literals.push(Literal::sample(literal_type, rng));
+            }
+        }
+        literals
+    }
+
+    #[test]
+    fn test_total_order() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let mut literals = sample_literals(rng);
+            for a in &literals {
+                // Ensure the ordering is reflexive, antisymmetric, and consistent with equality.
+                assert_eq!(a.cmp(a), Ordering::Equal);
+                for b in &literals {
+                    assert_eq!(a.cmp(b), b.cmp(a).reverse());
+                    assert_eq!(a == b, a.cmp(b) == Ordering::Equal);
+                }
+            }
+            // Ensure the ordering is transitive.
+            literals.sort();
+            for (i, a) in literals.iter().enumerate() {
+                for b in &literals[i..] {
+                    assert!(a <= b, "'{a}' is ordered after '{b}'");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_order_by_type_then_value() {
+        let rng = &mut TestRng::default();
+
+        // Ensure literals of different types are ordered by their type, and not by their value.
+        let a = Literal::<CurrentNetwork>::from_str("255u8").unwrap();
+        let b = Literal::<CurrentNetwork>::from_str("0u16").unwrap();
+        assert!(a < b);
+        let a = Literal::<CurrentNetwork>::from_str("true").unwrap();
+        let b = Literal::<CurrentNetwork>::from_str("0field").unwrap();
+        assert!(a < b);
+
+        for _ in 0..ITERATIONS {
+            // Ensure integers are ordered by their numeric value.
+            let (a, b) = (U64::<CurrentNetwork>::rand(rng), U64::<CurrentNetwork>::rand(rng));
+            assert_eq!(Literal::U64(a).cmp(&Literal::U64(b)), (*a).cmp(&*b));
+            let (a, b) = (I64::<CurrentNetwork>::rand(rng), I64::<CurrentNetwork>::rand(rng));
+            assert_eq!(Literal::I64(a).cmp(&Literal::I64(b)), (*a).cmp(&*b));
+        }
+    }
+
+    #[test]
+    fn test_order_agrees_with_bytes() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Ensure booleans and `u8` literals are ordered by their byte representation.
+            for literal_type in [LiteralType::Boolean, LiteralType::U8] {
+                let a = Literal::<CurrentNetwork>::sample(literal_type, rng);
+                let b = Literal::<CurrentNetwork>::sample(literal_type, rng);
+                assert_eq!(a.cmp(&b), a.to_bytes_le().unwrap().cmp(&b.to_bytes_le().unwrap()));
+            }
+            // Ensure strings are ordered by their UTF-8 bytes.
+            let a = StringType::<CurrentNetwork>::rand(rng);
+            let b = StringType::<CurrentNetwork>::rand(rng);
+            assert_eq!(Literal::String(a.clone()).cmp(&Literal::String(b.clone())), a.as_bytes().cmp(b.as_bytes()));
+        }
+    }
+}
@@ -18,6 +18,7 @@ pub use cast_lossy::CastLossy;
 mod bytes;
 mod cast;
 mod cast_lossy;
+mod compare;
 mod equal;
 mod from_bits;
 mod parse;
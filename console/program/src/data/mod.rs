@@ -22,7 +22,7 @@ mod future;
 pub use future::{Argument, Future};
 
 pub(super) mod identifier;
-pub use identifier::Identifier;
+pub use identifier::{Identifier, IdentifierError};
 
 mod literal;
 pub use literal::{Cast, CastLossy, Literal};
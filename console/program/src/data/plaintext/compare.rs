@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use core::cmp::Ordering;
+
+impl<N: Network> Ord for Plaintext<N> {
+    /// Returns the canonical ordering of `self` and `other`.
+    ///
+    /// Plaintexts are ordered first by their kind (literals, then structs, then arrays), and then structurally:
+    /// literals by the canonical ordering of `Literal`, structs by their members (name, then value) in member order,
+    /// and arrays by their elements in order, where a prefix is ordered before any longer array.
+    /// Note that this is a *canonical* ordering, and not a numeric one, across literal types.
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Literal(a, _), Self::Literal(b, _)) => a.cmp(b),
+            (Self::Struct(a, _), Self::Struct(b, _)) => a.iter().cmp(b.iter()),
+            (Self::Array(a, _), Self::Array(b, _)) => a.iter().cmp(b.iter()),
+            _ => self.kind().cmp(&other.kind()),
+        }
+    }
+}
+
+impl<N: Network> PartialOrd for Plaintext<N> {
+    /// Returns the canonical ordering of `self` and `other`.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the ordering of the byte representations of `self` and `other`.
+    ///
+    /// Note: This method allocates on every comparison, and is only intended as a fallback;
+    /// prefer the canonical ordering of `Ord`, which coincides with this one for booleans, `u8` literals,
+    /// and equal-length arrays of either.
+    pub fn cmp_bytes(&self, other: &Self) -> Result<Ordering> {
+        Ok(self.to_bytes_le()?.cmp(&other.to_bytes_le()?))
+    }
+
+    /// Returns the kind of the plaintext, in the order of its byte representation.
+    const fn kind(&self) -> u8 {
+        match self {
+            Self::Literal(..) => 0,
+            Self::Struct(..) => 1,
+            Self::Array(..) => 2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u32 = 100;
+
+    /// Returns a list of plaintexts, of every kind.
+    fn sample_plaintexts() -> Vec<Plaintext<CurrentNetwork>> {
+        [
+            "0u8",
+            "255u8",
+            "0u16",
+            "true",
+            "-1i8",
+            "5field",
+            "\"hello\"",
+            "{ a: 1u8, b: 2u8 }",
+            "{ a: 1u8, b: 3u8 }",
+            "{ a: 2u8, b: 0u8 }",
+            "{ a: 1u8 }",
+            "{ c: 1u8, b: 2u8 }",
+            "{ a: { b: 1u8 }, c: [ true, false ] }",
+            "[ 1u8 ]",
+            "[ 1u8, 2u8 ]",
+            "[ 2u8 ]",
+            "[ 0u16 ]",
+            "[ [ 1u8 ], [ 2u8 ] ]",
+            "[ { a: 1u8 }, { a: 0u8 } ]",
+        ]
+        .iter()
+        .map(|string| Plaintext::from_str(string).unwrap())
+        .collect()
+    }
+
+    #[test]
+    fn test_total_order() {
+        let mut plaintexts = sample_plaintexts();
+        for a in &plaintexts {
+            // Ensure the ordering is reflexive, antisymmetric, and consistent with equality.
+            assert_eq!(a.cmp(a), Ordering::Equal);
+            for b in &plaintexts {
+                assert_eq!(a.cmp(b), b.cmp(a).reverse());
+                assert_eq!(a == b, a.cmp(b) == Ordering::Equal);
+            }
+        }
+        // Ensure the ordering is transitive.
+        plaintexts.sort();
+        for (i, a) in plaintexts.iter().enumerate() {
+            for b in &plaintexts[i..] {
+                assert!(a <= b, "'{a}' is ordered after '{b}'");
+            }
+        }
+    }
+
+    #[test]
+    fn test_structural_order() {
+        let plaintext = |string: &str| Plaintext::<CurrentNetwork>::from_str(string).unwrap();
+
+        // Ensure literals are ordered before structs, and structs before arrays.
+        assert!(plaintext("[ 0u8 ]") > plaintext("{ a: 0u8 }"));
+        assert!(plaintext("{ a: 0u8 }") > plaintext("\"zzz\""));
+        // Ensure structs are ordered by their members, in member order.
+        assert!(plaintext("{ a: 1u8, b: 3u8 }") > plaintext("{ a: 1u8, b: 2u8 }"));
+        assert!(plaintext("{ a: 2u8, b: 0u8 }") > plaintext("{ a: 1u8, b: 3u8 }"));
+        assert!(plaintext("{ a: 1u8, b: 0u8 }") > plaintext("{ a: 1u8 }"));
+        // Ensure arrays are ordered by their elements, in order.
+        assert!(plaintext("[ 1u8, 2u8 ]") > plaintext("[ 1u8 ]"));
+        assert!(plaintext("[ 2u8 ]") > plaintext("[ 1u8, 2u8 ]"));
+        assert!(plaintext("[ 0u16 ]") > plaintext("[ 255u8 ]"));
+    }
+
+    #[test]
+    fn test_order_agrees_with_bytes() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Ensure booleans and `u8` literals are ordered by their byte representation.
+            let a = Plaintext::<CurrentNetwork>::from(Literal::U8(U8::rand(rng)));
+            let b = Plaintext::<CurrentNetwork>::from(Literal::U8(U8::rand(rng)));
+            assert_eq!(a.cmp(&b), a.cmp_bytes(&b).unwrap());
+            let a = Plaintext::<CurrentNetwork>::from(Literal::Boolean(Boolean::rand(rng)));
+            let b = Plaintext::<CurrentNetwork>::from(Literal::Boolean(Boolean::rand(rng)));
+            assert_eq!(a.cmp(&b), a.cmp_bytes(&b).unwrap());
+
+            // Ensure equal-length arrays of `u8` literals are ordered by their byte representation.
+            let sample = |rng: &mut TestRng| {
+                Plaintext::<CurrentNetwork>::Array(
+                    (0..4).map(|_| Plaintext::from(Literal::U8(U8::new(rng.gen_range(0..4))))).collect(),
+                    Default::default(),
+                )
+            };
+            let (a, b) = (sample(rng), sample(rng));
+            assert_eq!(a.cmp(&b), a.cmp_bytes(&b).unwrap());
+        }
+    }
+}
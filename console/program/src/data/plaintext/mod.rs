@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod bytes;
+mod compare;
 mod encrypt;
 mod equal;
 mod find;
@@ -29,9 +30,14 @@ use crate::{Access, Ciphertext, Identifier, Literal};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
+use core::sync::atomic::{AtomicUsize, Ordering};
 use indexmap::IndexMap;
 use once_cell::sync::OnceCell;
 
+/// The maximum size (in bytes) of a bit decomposition that is retained in the cache of a plaintext.
+/// Larger bit decompositions are recomputed on demand, to bound the memory retained by parsed plaintexts.
+static BITS_CACHE_THRESHOLD_IN_BYTES: AtomicUsize = AtomicUsize::new(usize::MAX);
+
 #[derive(Clone)]
 pub enum Plaintext<N: Network> {
     /// A literal.
@@ -56,6 +62,33 @@ impl<N: Network> From<&Literal<N>> for Plaintext<N> {
     }
 }
 
+impl<N: Network> Plaintext<N> {
+    /// Returns the maximum size (in bytes) of a bit decomposition that is cached in a plaintext.
+    pub fn bits_cache_threshold() -> usize {
+        BITS_CACHE_THRESHOLD_IN_BYTES.load(Ordering::Relaxed)
+    }
+
+    /// Sets the maximum size (in bytes) of a bit decomposition that is cached in a plaintext.
+    /// A threshold of `0` disables the cache, while `usize::MAX` (the default) caches every bit decomposition.
+    /// Note: This setting applies to all plaintexts, and does not evict bits that are already cached.
+    pub fn set_bits_cache_threshold(num_bytes: usize) {
+        BITS_CACHE_THRESHOLD_IN_BYTES.store(num_bytes, Ordering::Relaxed)
+    }
+
+    /// Returns the number of bytes retained by the bit caches of this plaintext and its members.
+    pub fn cached_bits_size_in_bytes(&self) -> usize {
+        match self {
+            Self::Literal(_, bits) => bits.get().map_or(0, Vec::len),
+            Self::Struct(members, bits) => {
+                bits.get().map_or(0, Vec::len) + members.values().map(Self::cached_bits_size_in_bytes).sum::<usize>()
+            }
+            Self::Array(elements, bits) => {
+                bits.get().map_or(0, Vec::len) + elements.iter().map(Self::cached_bits_size_in_bytes).sum::<usize>()
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -228,4 +261,59 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_plaintext_bits_cache_threshold() -> Result<()> {
+        let plaintexts = [
+            "true",
+            "1field",
+            "{ x: 0field, y: 1u8 }",
+            "[ 0field, 1field, 2field, 3field ]",
+            "{ a: [ { x: 0field, y: 1field }, { x: 2field, y: 3field } ], b: 4u64 }",
+        ];
+
+        for plaintext in plaintexts {
+            // Compute the bits with the cache enabled.
+            Plaintext::<CurrentNetwork>::set_bits_cache_threshold(usize::MAX);
+            let cached = Plaintext::<CurrentNetwork>::from_str(plaintext)?;
+            let expected_bits_le = cached.to_bits_le();
+            let expected_bits_be = cached.to_bits_be();
+            let expected_fields = cached.to_fields()?;
+            assert!(cached.cached_bits_size_in_bytes() >= expected_bits_le.len());
+            // Ensure the cached bits are returned on subsequent calls.
+            assert_eq!(expected_bits_le, cached.to_bits_le());
+            assert_eq!(expected_bits_be, cached.to_bits_be());
+
+            // Compute the bits with the cache disabled.
+            Plaintext::<CurrentNetwork>::set_bits_cache_threshold(0);
+            let uncached = Plaintext::<CurrentNetwork>::from_str(plaintext)?;
+            assert_eq!(expected_bits_le, uncached.to_bits_le());
+            assert_eq!(expected_bits_be, uncached.to_bits_be());
+            assert_eq!(expected_fields, uncached.to_fields()?);
+            assert_eq!(cached, uncached);
+            assert_eq!(uncached, Plaintext::<CurrentNetwork>::from_fields(&expected_fields)?);
+            assert_eq!(0, uncached.cached_bits_size_in_bytes());
+
+            // Ensure `find` works without the cache.
+            if let Plaintext::Struct(members, _) = &uncached {
+                for (identifier, member) in members {
+                    assert_eq!(member, &uncached.find(&[*identifier])?);
+                    assert_eq!(member.to_bits_le(), uncached.find(&[*identifier])?.to_bits_le());
+                }
+            }
+            assert_eq!(0, uncached.cached_bits_size_in_bytes());
+        }
+
+        // Compute the bits with a threshold that only caches the members of a struct.
+        let expected = Plaintext::<CurrentNetwork>::from_str("{ x: 0field, y: 1field }")?;
+        let member_bits = Plaintext::<CurrentNetwork>::from_str("0field")?.to_bits_le().len();
+        Plaintext::<CurrentNetwork>::set_bits_cache_threshold(member_bits);
+        let candidate = Plaintext::<CurrentNetwork>::from_str("{ x: 0field, y: 1field }")?;
+        assert_eq!(expected.to_bits_le(), candidate.to_bits_le());
+        assert_eq!(2 * member_bits, candidate.cached_bits_size_in_bytes());
+
+        // Restore the default threshold.
+        Plaintext::<CurrentNetwork>::set_bits_cache_threshold(usize::MAX);
+        Ok(())
+    }
 }
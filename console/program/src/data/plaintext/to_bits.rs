@@ -14,25 +14,46 @@
 
 use super::*;
 
+impl<N: Network> Plaintext<N> {
+    /// Writes the bits from the given cache into the vector, computing them if the cache is empty.
+    /// The computed bits are only cached if their size is within the bits cache threshold.
+    fn write_cached_bits(cache: &OnceCell<Vec<bool>>, vec: &mut Vec<bool>, compute: impl FnOnce() -> Vec<bool>) {
+        match cache.get() {
+            // Extend the vector with the cached bits.
+            Some(bits) => vec.extend_from_slice(bits),
+            None => {
+                // Compute the bits.
+                let bits = compute();
+                // Extend the vector with the bits.
+                vec.extend_from_slice(&bits);
+                // Cache the bits, if they are within the threshold.
+                // Note: Each bit is stored as one byte.
+                if bits.len() <= Self::bits_cache_threshold() {
+                    // Note: The cache may have been set concurrently, in which case these bits are discarded.
+                    let _ = cache.set(bits);
+                }
+            }
+        }
+    }
+}
+
 impl<N: Network> ToBits for Plaintext<N> {
     /// Returns this plaintext as a list of **little-endian** bits.
     fn write_bits_le(&self, vec: &mut Vec<bool>) {
         match self {
             Self::Literal(literal, bits_le) => {
-                // Compute the bits.
-                let bits = bits_le.get_or_init(|| {
+                // Compute the bits, or retrieve them from the cache.
+                Self::write_cached_bits(bits_le, vec, || {
                     let mut bits_le = vec![false, false]; // Variant bits.
                     literal.variant().write_bits_le(&mut bits_le);
                     literal.size_in_bits().write_bits_le(&mut bits_le);
                     literal.write_bits_le(&mut bits_le);
                     bits_le
-                });
-                // Extend the vector with the bits.
-                vec.extend_from_slice(bits)
+                })
             }
             Self::Struct(struct_, bits_le) => {
-                // Compute the bits.
-                let bits = bits_le.get_or_init(|| {
+                // Compute the bits, or retrieve them from the cache.
+                Self::write_cached_bits(bits_le, vec, || {
                     let mut bits_le = vec![false, true]; // Variant bits.
 
                     // Write the length of the struct.
@@ -54,13 +75,11 @@ impl<N: Network> ToBits for Plaintext<N> {
                         bits_le.extend_from_slice(&value_bits);
                     }
                     bits_le
-                });
-                // Extend the vector with the bits.
-                vec.extend_from_slice(bits)
+                })
             }
             Self::Array(array, bits_le) => {
-                // Compute the bits.
-                let bits = bits_le.get_or_init(|| {
+                // Compute the bits, or retrieve them from the cache.
+                Self::write_cached_bits(bits_le, vec, || {
                     let mut bits_le = vec![true, false]; // Variant bits.
 
                     // Write the length of the array.
@@ -81,83 +100,63 @@ impl<N: Network> ToBits for Plaintext<N> {
                         bits_le.extend(element_bits);
                     }
                     bits_le
-                });
-                // Extend the vector with the bits.
-                vec.extend_from_slice(bits)
+                })
             }
         }
     }
 
     /// Returns this plaintext as a list of **big-endian** bits.
+    /// Note: Only the little-endian bits are cached, as they are used to hash the plaintext.
     fn write_bits_be(&self, vec: &mut Vec<bool>) {
         match self {
-            Self::Literal(literal, bits_be) => {
-                // Compute the bits.
-                let bits = bits_be.get_or_init(|| {
-                    let mut bits_be = vec![false, false]; // Variant bits.
-                    literal.variant().write_bits_be(&mut bits_be);
-                    literal.size_in_bits().write_bits_be(&mut bits_be);
-                    literal.write_bits_be(&mut bits_be);
-                    bits_be
-                });
-                // Extend the vector with the bits.
-                vec.extend_from_slice(bits)
+            Self::Literal(literal, _) => {
+                vec.extend([false, false]); // Variant bits.
+                literal.variant().write_bits_be(vec);
+                literal.size_in_bits().write_bits_be(vec);
+                literal.write_bits_be(vec);
             }
-            Self::Struct(struct_, bits_be) => {
-                // Compute the bits.
-                let bits = bits_be.get_or_init(|| {
-                    let mut bits_be = vec![false, true]; // Variant bits.
-
-                    // Write the length of the struct.
-                    u8::try_from(struct_.len())
-                        .or_halt_with::<N>("Plaintext struct length exceeds u8::MAX")
-                        .write_bits_be(&mut bits_be);
-
-                    // Write each member of the struct.
-                    for (identifier, value) in struct_ {
-                        // Write the identifier of the member.
-                        identifier.size_in_bits().write_bits_be(&mut bits_be);
-                        identifier.write_bits_be(&mut bits_be);
-
-                        // Write the value of the member.
-                        let value_bits = value.to_bits_be();
-                        u16::try_from(value_bits.len())
-                            .or_halt_with::<N>("Plaintext member exceeds u16::MAX bits")
-                            .write_bits_be(&mut bits_be);
-                        bits_be.extend_from_slice(&value_bits);
-                    }
-
-                    bits_be
-                });
-                // Extend the vector with the bits.
-                vec.extend_from_slice(bits)
+            Self::Struct(struct_, _) => {
+                vec.extend([false, true]); // Variant bits.
+
+                // Write the length of the struct.
+                u8::try_from(struct_.len())
+                    .or_halt_with::<N>("Plaintext struct length exceeds u8::MAX")
+                    .write_bits_be(vec);
+
+                // Write each member of the struct.
+                for (identifier, value) in struct_ {
+                    // Write the identifier of the member.
+                    identifier.size_in_bits().write_bits_be(vec);
+                    identifier.write_bits_be(vec);
+
+                    // Write the value of the member.
+                    let value_bits = value.to_bits_be();
+                    u16::try_from(value_bits.len())
+                        .or_halt_with::<N>("Plaintext member exceeds u16::MAX bits")
+                        .write_bits_be(vec);
+                    vec.extend_from_slice(&value_bits);
+                }
             }
-            Self::Array(array, bits_be) => {
-                // Compute the bits.
-                let bits = bits_be.get_or_init(|| {
-                    let mut bits_be = vec![true, false]; // Variant bits.
-
-                    // Write the length of the array.
-                    u32::try_from(array.len())
-                        .or_halt_with::<N>("Plaintext array length exceeds u32::MAX")
-                        .write_bits_be(&mut bits_be);
-
-                    // Write each element of the array.
-                    for element in array {
-                        let element_bits = element.to_bits_be();
-
-                        // Write the size of the element.
-                        u16::try_from(element_bits.len())
-                            .or_halt_with::<N>("Plaintext element exceeds u16::MAX bits")
-                            .write_bits_be(&mut bits_be);
-
-                        // Write the element.
-                        bits_be.extend(element_bits);
-                    }
-                    bits_be
-                });
-                // Extend the vector with the bits.
-                vec.extend_from_slice(bits)
+            Self::Array(array, _) => {
+                vec.extend([true, false]); // Variant bits.
+
+                // Write the length of the array.
+                u32::try_from(array.len())
+                    .or_halt_with::<N>("Plaintext array length exceeds u32::MAX")
+                    .write_bits_be(vec);
+
+                // Write each element of the array.
+                for element in array {
+                    let element_bits = element.to_bits_be();
+
+                    // Write the size of the element.
+                    u16::try_from(element_bits.len())
+                        .or_halt_with::<N>("Plaintext element exceeds u16::MAX bits")
+                        .write_bits_be(vec);
+
+                    // Write the element.
+                    vec.extend(element_bits);
+                }
             }
         }
     }
@@ -15,15 +15,18 @@
 use super::*;
 
 impl<N: Network> Record<N, Ciphertext<N>> {
-    /// Decrypts `self` into plaintext using the given view key.
-    pub fn is_owner(&self, view_key: &ViewKey<N>) -> bool {
-        // Compute the address.
-        let address = view_key.to_address();
+    /// Returns `true` if the given address, corresponding to the given view key, is the record owner.
+    ///
+    /// This method only decrypts the owner of the record, and does not decrypt the record data.
+    /// Callers checking many records should compute the address once, and pass it to each call.
+    pub fn is_owner(&self, address: &Address<N>, view_key: &ViewKey<N>) -> bool {
         // Check if the address is the owner.
         self.is_owner_with_address_x_coordinate(view_key, &address.to_x_coordinate())
     }
 
-    /// Decrypts `self` into plaintext using the x-coordinate of the address corresponding to the given view key.
+    /// Returns `true` if the address with the given x-coordinate, corresponding to the given view key, is the record owner.
+    ///
+    /// This method only decrypts the owner of the record, and does not decrypt the record data.
     pub fn is_owner_with_address_x_coordinate(&self, view_key: &ViewKey<N>, address_x_coordinate: &Field<N>) -> bool {
         // In debug mode, check that the address corresponds to the given view key.
         debug_assert_eq!(
@@ -92,14 +95,18 @@ mod tests {
         let ciphertext = record.encrypt(randomizer)?;
 
         // Ensure the record belongs to the owner.
-        assert!(ciphertext.is_owner(&view_key));
+        assert!(ciphertext.is_owner(&view_key.to_address(), &view_key));
+        // Ensure the check agrees with a full decryption.
+        assert!(ciphertext.decrypt(&view_key).is_ok());
 
         // Sample a random view key and address.
         let private_key = PrivateKey::<N>::new(rng)?;
         let view_key = ViewKey::try_from(&private_key)?;
 
         // Ensure the random address is not the owner.
-        assert!(!ciphertext.is_owner(&view_key));
+        assert!(!ciphertext.is_owner(&view_key.to_address(), &view_key));
+        // Ensure the check agrees with a full decryption.
+        assert!(ciphertext.decrypt(&view_key).is_err());
 
         Ok(())
     }
@@ -27,6 +27,7 @@ mod is_owner;
 mod num_randomizers;
 mod parse_ciphertext;
 mod parse_plaintext;
+mod rerandomize;
 mod serial_number;
 mod serialize;
 mod tag;
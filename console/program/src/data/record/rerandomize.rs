@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Record<N, Ciphertext<N>> {
+    /// Re-encrypts `self` under a freshly-sampled nonce, for the same owner and with the same data.
+    /// Returns the re-randomized record ciphertext and its new nonce.
+    ///
+    /// The re-randomized record decrypts to the same owner and data under the given view key,
+    /// but it has a *different* record commitment, and therefore a different serial number.
+    /// As such, it is not spendable as the original record, and is intended for off-chain storage only,
+    /// so that copies of the same record can not be linked to one another.
+    #[allow(clippy::type_complexity)]
+    pub fn rerandomize<R: Rng + CryptoRng>(
+        &self,
+        view_key: &ViewKey<N>,
+        rng: &mut R,
+    ) -> Result<(Record<N, Ciphertext<N>>, Group<N>)> {
+        // Decrypt the record, which ensures the view key corresponds to the record owner.
+        let record = self.decrypt(view_key)?;
+
+        // Sample a new randomizer, and compute the new nonce.
+        let randomizer = Scalar::rand(rng);
+        let nonce = N::g_scalar_multiply(&randomizer);
+        // Ensure the new nonce differs from the current nonce, so that the record commitment changes.
+        ensure!(nonce != self.nonce, "Illegal operation: Record::rerandomize() sampled the current record nonce.");
+
+        // Re-encrypt the record under the new nonce.
+        let ciphertext =
+            Record::<N, Plaintext<N>>::from_plaintext(record.owner, record.data, nonce)?.encrypt(randomizer)?;
+        // Return the re-randomized record and the new nonce.
+        Ok((ciphertext, nonce))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Literal;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_console_types::U64;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_rerandomize() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let program_id = ProgramID::<CurrentNetwork>::from_str("token.aleo")?;
+        let record_name = Identifier::from_str("token")?;
+
+        for _ in 0..ITERATIONS {
+            // Sample the owner.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let owner = Owner::Private(Plaintext::from(Literal::Address(Address::try_from(&private_key)?)));
+
+            // Prepare the record.
+            let randomizer = Scalar::rand(&mut rng);
+            let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_plaintext(
+                owner,
+                IndexMap::from_iter([
+                    (
+                        Identifier::from_str("a")?,
+                        Entry::Private(Plaintext::from(Literal::Field(Field::rand(&mut rng)))),
+                    ),
+                    (Identifier::from_str("b")?, Entry::Public(Plaintext::from(Literal::U64(U64::rand(&mut rng))))),
+                ]),
+                CurrentNetwork::g_scalar_multiply(&randomizer),
+            )?;
+            let ciphertext = record.encrypt(randomizer)?;
+
+            // Re-randomize the record.
+            let (candidate, nonce) = ciphertext.rerandomize(&view_key, &mut rng)?;
+            assert_eq!(candidate.nonce(), &nonce);
+            assert_ne!(candidate.nonce(), ciphertext.nonce());
+            assert_ne!(candidate, ciphertext);
+
+            // Ensure the re-randomized record decrypts to the same owner and data.
+            let candidate_record = candidate.decrypt(&view_key)?;
+            assert_eq!(candidate_record.owner(), record.owner());
+            assert_eq!(candidate_record.data(), record.data());
+
+            // Ensure the commitment and serial number differ.
+            let commitment = record.to_commitment(&program_id, &record_name)?;
+            let candidate_commitment = candidate_record.to_commitment(&program_id, &record_name)?;
+            assert_ne!(commitment, candidate_commitment);
+            assert_ne!(
+                Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::serial_number(private_key, commitment)?,
+                Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::serial_number(private_key, candidate_commitment)?
+            );
+
+            // Ensure re-randomizing with an incorrect view key fails.
+            let incorrect_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+            assert!(ciphertext.rerandomize(&incorrect_view_key, &mut rng).is_err());
+        }
+        Ok(())
+    }
+}
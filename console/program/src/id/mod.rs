@@ -18,8 +18,11 @@ mod serialize;
 mod to_address;
 mod to_bits;
 mod to_fields;
+mod validate;
 
-use crate::Identifier;
+pub use validate::ProgramIDError;
+
+use crate::{Identifier, IdentifierError};
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::{Address, Boolean, Field};
 
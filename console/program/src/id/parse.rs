@@ -32,6 +32,9 @@ impl<N: Network> FromStr for ProgramID<N> {
     /// Parses a string into a program ID.
     #[inline]
     fn from_str(string: &str) -> Result<Self> {
+        // Ensure the program ID is valid.
+        Self::validate(string)?;
+
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
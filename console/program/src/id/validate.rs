@@ -0,0 +1,209 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The reason a string is not a valid program ID.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProgramIDError {
+    /// The program ID does not have a network-level domain suffix.
+    MissingNetwork,
+    /// The program name is not a valid identifier.
+    InvalidName(IdentifierError),
+    /// The program name contains a character that is not a lowercase letter, digit, or underscore.
+    NonLowercaseName { character: char, position: usize },
+    /// The network-level domain is not a valid identifier.
+    InvalidNetwork(IdentifierError),
+    /// The network-level domain is not `aleo`.
+    UnsupportedNetwork { network: String },
+}
+
+impl Display for ProgramIDError {
+    /// Prints the program ID error as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::MissingNetwork => write!(f, "Program ID must end with a network-level domain, such as '.aleo'"),
+            Self::InvalidName(error) => write!(f, "Program name is invalid - {error}"),
+            Self::NonLowercaseName { character, position } => write!(
+                f,
+                "Program name must consist of lowercase letters, digits, and underscores, found '{character}' at position {position}"
+            ),
+            Self::InvalidNetwork(error) => write!(f, "Program network is invalid - {error}"),
+            Self::UnsupportedNetwork { network } => write!(f, "Program network '{network}' is invalid, expected 'aleo'"),
+        }
+    }
+}
+
+impl std::error::Error for ProgramIDError {}
+
+impl<N: Network> ProgramID<N> {
+    /// Checks that the given string is a valid program ID of the form `{name}.aleo`,
+    /// and returns the reason if it is not. The character positions in the error are
+    /// relative to the start of the program ID.
+    pub fn validate(program_id: &str) -> Result<(), ProgramIDError> {
+        // Split the program ID into a name and network-level domain.
+        let (name, network) = match program_id.split_once('.') {
+            Some((name, network)) => (name, Some(network)),
+            None => (program_id, None),
+        };
+
+        // Ensure the name is a valid identifier.
+        Identifier::<N>::validate(name).map_err(ProgramIDError::InvalidName)?;
+        // Ensure the name is lowercase alphabets and numbers.
+        if let Some((position, character)) = name.char_indices().find(|(_, character)| character.is_ascii_uppercase()) {
+            return Err(ProgramIDError::NonLowercaseName { character, position });
+        }
+
+        // Ensure the network-level domain is present.
+        let network = match network {
+            Some(network) if !network.is_empty() => network,
+            _ => return Err(ProgramIDError::MissingNetwork),
+        };
+        // Ensure the network-level domain is a valid identifier.
+        Identifier::<N>::validate(network)
+            .map_err(|error| ProgramIDError::InvalidNetwork(error.with_offset(name.len() + 1)))?;
+        // Ensure the program network-level domain is `aleo`.
+        if network != "aleo" {
+            return Err(ProgramIDError::UnsupportedNetwork { network: network.to_string() });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::identifier::tests::is_valid_identifier_string;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 10_000;
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(ProgramID::<CurrentNetwork>::validate("foo_bar1.aleo"), Ok(()));
+        assert_eq!(ProgramID::<CurrentNetwork>::validate("foo"), Err(ProgramIDError::MissingNetwork));
+        assert_eq!(ProgramID::<CurrentNetwork>::validate("foo."), Err(ProgramIDError::MissingNetwork));
+        assert_eq!(
+            ProgramID::<CurrentNetwork>::validate(".aleo"),
+            Err(ProgramIDError::InvalidName(IdentifierError::Empty))
+        );
+        assert_eq!(
+            ProgramID::<CurrentNetwork>::validate("0foo.aleo"),
+            Err(ProgramIDError::InvalidName(IdentifierError::InvalidStart { character: '0' }))
+        );
+        assert_eq!(
+            ProgramID::<CurrentNetwork>::validate("foo-bar.aleo"),
+            Err(ProgramIDError::InvalidName(IdentifierError::InvalidCharacter { character: '-', position: 3 }))
+        );
+        assert_eq!(
+            ProgramID::<CurrentNetwork>::validate("fooBar.aleo"),
+            Err(ProgramIDError::NonLowercaseName { character: 'B', position: 3 })
+        );
+        assert_eq!(
+            ProgramID::<CurrentNetwork>::validate("foo.aleo.bar"),
+            Err(ProgramIDError::InvalidNetwork(IdentifierError::InvalidCharacter { character: '.', position: 8 }))
+        );
+        assert_eq!(
+            ProgramID::<CurrentNetwork>::validate("foo.eth"),
+            Err(ProgramIDError::UnsupportedNetwork { network: "eth".to_string() })
+        );
+
+        let max_length = Field::<CurrentNetwork>::size_in_data_bits() / 8;
+        assert_eq!(
+            ProgramID::<CurrentNetwork>::validate(&format!("{}.aleo", "a".repeat(max_length + 1))),
+            Err(ProgramIDError::InvalidName(IdentifierError::TooLong { length: max_length + 1, max_length }))
+        );
+    }
+
+    #[test]
+    fn test_from_str_error() {
+        // Ensure the detailed error is surfaced by `from_str`.
+        let error = ProgramID::<CurrentNetwork>::from_str("fooBar.aleo").unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<ProgramIDError>(),
+            Some(&ProgramIDError::NonLowercaseName { character: 'B', position: 3 })
+        );
+    }
+
+    /// Returns `true` if the given string is a valid program ID.
+    /// This is a reference specification, written independently of `ProgramID::validate`.
+    fn is_valid_program_id_string(string: &str) -> bool {
+        match string.strip_suffix(".aleo") {
+            Some(name) => {
+                is_valid_identifier_string::<CurrentNetwork>(name)
+                    && !name.bytes().any(|byte| byte.is_ascii_uppercase())
+            }
+            None => false,
+        }
+    }
+
+    #[test]
+    fn test_validate_matches_specification() {
+        let max_length = Field::<CurrentNetwork>::size_in_data_bits() / 8;
+
+        // Note: The strings are chosen to probe the boundaries of the specification.
+        let mut strings = [
+            "",
+            "aleo",
+            ".aleo",
+            "a.aleo",
+            "A.aleo",
+            "a.ALEO",
+            "a.Aleo",
+            "a.aleo.",
+            "a..aleo",
+            "a.aleo.aleo",
+            "a.b.aleo",
+            " a.aleo",
+            "a.aleo ",
+            "a.aleo\0",
+            "a.aleo\n",
+            "a.ale",
+            "a.aleoo",
+            "a.",
+            "a_.aleo",
+            "a1.aleo",
+            "1a.aleo",
+            "_a.aleo",
+            "é.aleo",
+            "a.aléo",
+            "a\u{212A}.aleo",
+            "\u{FF41}.aleo",
+            "a\u{0301}.aleo",
+        ]
+        .map(String::from)
+        .to_vec();
+        strings.push("a".repeat(max_length) + ".aleo");
+        strings.push("a".repeat(max_length + 1) + ".aleo");
+        strings.push("a".repeat(max_length - 1) + "é.aleo");
+
+        // Sample random strings.
+        let mut rng = TestRng::default();
+        let fragments = ["a", "z", "A", "0", "_", "-", ".", "é", "\u{212A}", "aleo", ".aleo", ".ALEO", "foo"];
+        for _ in 0..ITERATIONS {
+            let length = rng.gen_range(0..8);
+            strings.push((0..length).map(|_| fragments[rng.gen_range(0..fragments.len())]).collect());
+        }
+
+        for string in strings {
+            // Ensure the string is accepted by `validate` and `from_str` iff it satisfies the specification.
+            let expected = is_valid_program_id_string(&string);
+            assert_eq!(ProgramID::<CurrentNetwork>::validate(&string).is_ok(), expected, "{string:?}");
+            assert_eq!(ProgramID::<CurrentNetwork>::from_str(&string).is_ok(), expected, "{string:?}");
+        }
+    }
+}
@@ -21,6 +21,9 @@ mod sign;
 mod string;
 mod verify;
 
+mod unsigned;
+pub use unsigned::{SignerCommitment, SignerNonce, UnsignedRequest};
+
 use crate::{Identifier, Plaintext, ProgramID, Record, Value, ValueType};
 use snarkvm_console_account::{Address, ComputeKey, GraphKey, PrivateKey, Signature, ViewKey};
 use snarkvm_console_network::Network;
@@ -0,0 +1,481 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The commitment of a signer to the nonce of a request, which allows a request to be signed out-of-band.
+///
+/// A request is signed out-of-band in two phases:
+///   1. The signer derives a secret nonce and its commitment (see `Request::commit_signer`).
+///   2. The unsigned request is constructed from the commitment (see `UnsignedRequest::new`),
+///      and the signer signs its message, consuming the nonce (see `Request::sign_with_nonce`).
+#[allow(clippy::type_complexity)]
+#[derive(Clone, PartialEq, Eq)]
+pub struct SignerCommitment<N: Network> {
+    /// The compute key of the signer.
+    compute_key: ComputeKey<N>,
+    /// The tag secret key.
+    sk_tag: Field<N>,
+    /// The transition public key `tpk`, as `r * G`.
+    tpk: Group<N>,
+    /// The transition view key `tvk`, as `r * signer`.
+    tvk: Field<N>,
+    /// The record inputs, as (`commitment`, `H`, `r * H`, `gamma`) for each record commitment.
+    record_inputs: Vec<(Field<N>, Group<N>, Group<N>, Group<N>)>,
+}
+
+/// The secret nonce of a signer commitment (see `Request::commit_signer`).
+///
+/// The nonce is consumed when signing, as signing two messages with the same nonce reveals the private key.
+/// For this reason, it can neither be copied nor constructed outside of `Request::commit_signer`.
+pub struct SignerNonce<N: Network>(Field<N>);
+
+impl<N: Network> SignerCommitment<N> {
+    /// Returns the compute key of the signer.
+    pub const fn compute_key(&self) -> &ComputeKey<N> {
+        &self.compute_key
+    }
+
+    /// Returns the transition public key `tpk`.
+    pub const fn tpk(&self) -> &Group<N> {
+        &self.tpk
+    }
+
+    /// Returns the transition view key `tvk`.
+    pub const fn tvk(&self) -> &Field<N> {
+        &self.tvk
+    }
+}
+
+impl<N: Network> Request<N> {
+    /// Returns a secret nonce and the signer commitment for the given private key and record commitments,
+    /// where the record commitments are those of the record inputs, in order (see `UnsignedRequest::record_commitments`).
+    ///
+    /// Note: The nonce must be kept secret by the signer, and is consumed by `Request::sign_with_nonce`.
+    pub fn commit_signer<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        record_commitments: &[Field<N>],
+        rng: &mut R,
+    ) -> Result<(SignerNonce<N>, SignerCommitment<N>)> {
+        // Retrieve `sk_sig`.
+        let sk_sig = private_key.sk_sig();
+
+        // Derive the compute key.
+        let compute_key = ComputeKey::try_from(private_key)?;
+        // Derive the view key.
+        let view_key = ViewKey::try_from((private_key, &compute_key))?;
+        // Derive `sk_tag` from the graph key.
+        let sk_tag = GraphKey::try_from(view_key)?.sk_tag();
+
+        // Sample a random nonce.
+        let nonce = Field::<N>::rand(rng);
+        // Compute a `r` as `HashToScalar(sk_sig || nonce)`. Note: This is the transition secret key `tsk`.
+        let r = N::hash_to_scalar_psd4(&[N::serial_number_domain(), sk_sig.to_field()?, nonce])?;
+        // Compute `g_r` as `r * G`. Note: This is the transition public key `tpk`.
+        let g_r = N::g_scalar_multiply(&r);
+
+        // Derive the signer from the compute key.
+        let signer = Address::try_from(compute_key)?;
+        // Compute the transition view key `tvk` as `r * signer`.
+        let tvk = (*signer * r).to_x_coordinate();
+
+        // Compute (`H`, `r * H`, `gamma`) for each record commitment.
+        let record_inputs = record_commitments
+            .iter()
+            .map(|commitment| {
+                // Compute the generator `H` as `HashToGroup(commitment)`.
+                let h = N::hash_to_group_psd2(&[N::serial_number_domain(), *commitment])?;
+                // Compute `h_r` as `r * H`, and `gamma` as `sk_sig * H`.
+                Ok((*commitment, h, h * r, h * sk_sig))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok((SignerNonce(nonce), SignerCommitment { compute_key, sk_tag, tpk: g_r, tvk, record_inputs }))
+    }
+
+    /// Returns the signature of the given message, for the nonce of the signer commitment, where:
+    ///     challenge := HashToScalar(r * G, pk_sig, pr_sig, signer, message)
+    ///     response := r - challenge * sk_sig
+    ///
+    /// Note: The message is the one of the unsigned request (see `UnsignedRequest::to_sign_message`),
+    /// and the nonce is consumed, so that it cannot be used to sign another message.
+    pub fn sign_with_nonce(
+        private_key: &PrivateKey<N>,
+        nonce: SignerNonce<N>,
+        message: &[Field<N>],
+    ) -> Result<Signature<N>> {
+        // Retrieve the nonce.
+        let SignerNonce(nonce) = nonce;
+        // Retrieve `sk_sig`.
+        let sk_sig = private_key.sk_sig();
+        // Derive the compute key.
+        let compute_key = ComputeKey::try_from(private_key)?;
+        // Derive the signer from the compute key.
+        let signer = Address::try_from(compute_key)?;
+
+        // Compute a `r` as `HashToScalar(sk_sig || nonce)`. Note: This is the transition secret key `tsk`.
+        let r = N::hash_to_scalar_psd4(&[N::serial_number_domain(), sk_sig.to_field()?, nonce])?;
+        // Compute `g_r` as `r * G`. Note: This is the transition public key `tpk`.
+        let g_r = N::g_scalar_multiply(&r);
+
+        // Retrieve `pk_sig` and `pr_sig`.
+        let (pk_sig, pr_sig) = (compute_key.pk_sig(), compute_key.pr_sig());
+
+        // Construct the hash input as `(r * G, pk_sig, pr_sig, signer, message)`.
+        let mut preimage = Vec::with_capacity(4 + message.len());
+        preimage.extend([g_r, pk_sig, pr_sig, *signer].map(|point| point.to_x_coordinate()));
+        preimage.extend_from_slice(message);
+
+        // Compute `challenge` as `HashToScalar(r * G, pk_sig, pr_sig, signer, message)`.
+        let challenge = N::hash_to_scalar_psd8(&preimage)?;
+        // Compute `response` as `r - challenge * sk_sig`.
+        let response = r - challenge * sk_sig;
+
+        Ok(Signature::from((challenge, response, compute_key)))
+    }
+}
+
+/// A request that awaits the signature of its signer (see `SignerCommitment`).
+#[derive(Clone, PartialEq, Eq)]
+pub struct UnsignedRequest<N: Network> {
+    /// The request signer.
+    signer: Address<N>,
+    /// The compute key of the signer.
+    compute_key: ComputeKey<N>,
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The input ID for the transition.
+    input_ids: Vec<InputID<N>>,
+    /// The function inputs.
+    inputs: Vec<Value<N>>,
+    /// The tag secret key.
+    sk_tag: Field<N>,
+    /// The transition view key.
+    tvk: Field<N>,
+    /// The transition commitment.
+    tcm: Field<N>,
+    /// The message to be signed, as `[tvk, tcm, function ID, input IDs]`.
+    message: Vec<Field<N>>,
+}
+
+impl<N: Network> UnsignedRequest<N> {
+    /// Returns the record commitments of the record inputs, in order, which are committed to by the signer.
+    /// Note: An input for a record type that is not a record is skipped, and rejected by `UnsignedRequest::new`.
+    pub fn record_commitments(
+        program_id: &ProgramID<N>,
+        inputs: &[Value<N>],
+        input_types: &[ValueType<N>],
+    ) -> Result<Vec<Field<N>>> {
+        inputs
+            .iter()
+            .zip_eq(input_types)
+            .filter_map(|(input, input_type)| match (input, input_type) {
+                (Value::Record(record), ValueType::Record(record_name)) => {
+                    Some(record.to_commitment(program_id, record_name))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Initializes a new unsigned request, given the signer commitment, program ID, function name, inputs, and input types.
+    ///
+    /// Note: The record commitments are taken from the signer commitment, and are checked by `Request::verify`.
+    pub fn new(
+        commitment: &SignerCommitment<N>,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        input_types: &[ValueType<N>],
+    ) -> Result<Self> {
+        // Ensure the number of inputs matches the number of input types.
+        if input_types.len() != inputs.len() {
+            bail!(
+                "'{program_id}/{function_name}' expects {} inputs, but {} were provided.",
+                input_types.len(),
+                inputs.len()
+            )
+        }
+
+        // Retrieve the signer commitment.
+        let SignerCommitment { compute_key, sk_tag, tvk, record_inputs, .. } = commitment;
+        // Derive the signer from the compute key.
+        let signer = Address::try_from(compute_key)?;
+        // Compute the transition commitment `tcm` as `Hash(tvk)`.
+        let tcm = N::hash_psd2(&[*tvk])?;
+
+        // Compute the function ID as `Hash(network_id, program_id, function_name)`.
+        let function_id = N::hash_bhp1024(
+            &(U16::<N>::new(N::ID), program_id.name(), program_id.network(), function_name).to_bits_le(),
+        )?;
+
+        // Construct the signature message as `[tvk, tcm, function ID, input IDs]`.
+        let mut message = Vec::with_capacity(3 + 2 * inputs.len());
+        message.extend([*tvk, tcm, function_id]);
+
+        // Initialize a vector to store the prepared inputs.
+        let mut prepared_inputs = Vec::with_capacity(inputs.len());
+        // Initialize a vector to store the input IDs.
+        let mut input_ids = Vec::with_capacity(inputs.len());
+        // Initialize an iterator over the committed record inputs.
+        let mut record_inputs = record_inputs.iter();
+
+        // Prepare the inputs.
+        for (index, (input, input_type)) in inputs.zip_eq(input_types).enumerate() {
+            // Prepare the input.
+            let input = input.try_into().map_err(|_| {
+                anyhow!("Failed to parse input #{index} ('{input_type}') for '{program_id}/{function_name}'")
+            })?;
+            // Store the prepared input.
+            prepared_inputs.push(input.clone());
+
+            match input_type {
+                // A constant input is hashed (using `tcm`) to a field element.
+                ValueType::Constant(..) => {
+                    // Ensure the input is a plaintext.
+                    ensure!(matches!(input, Value::Plaintext(..)), "Expected a plaintext input");
+
+                    // Construct the (console) input index as a field element.
+                    let index = Field::from_u16(u16::try_from(index).or_halt_with::<N>("Input index exceeds u16"));
+                    // Construct the preimage as `(function ID || input || tcm || index)`.
+                    let mut preimage = Vec::new();
+                    preimage.push(function_id);
+                    preimage.extend(input.to_fields()?);
+                    preimage.push(tcm);
+                    preimage.push(index);
+                    // Hash the input to a field element.
+                    let input_hash = N::hash_psd8(&preimage)?;
+
+                    // Add the input hash to the message.
+                    message.push(input_hash);
+                    // Add the input ID to the inputs.
+                    input_ids.push(InputID::Constant(input_hash));
+                }
+                // A public input is hashed (using `tcm`) to a field element.
+                ValueType::Public(..) => {
+                    // Ensure the input is a plaintext.
+                    ensure!(matches!(input, Value::Plaintext(..)), "Expected a plaintext input");
+
+                    // Construct the (console) input index as a field element.
+                    let index = Field::from_u16(u16::try_from(index).or_halt_with::<N>("Input index exceeds u16"));
+                    // Construct the preimage as `(function ID || input || tcm || index)`.
+                    let mut preimage = Vec::new();
+                    preimage.push(function_id);
+                    preimage.extend(input.to_fields()?);
+                    preimage.push(tcm);
+                    preimage.push(index);
+                    // Hash the input to a field element.
+                    let input_hash = N::hash_psd8(&preimage)?;
+
+                    // Add the input hash to the message.
+                    message.push(input_hash);
+                    // Add the input ID to the inputs.
+                    input_ids.push(InputID::Public(input_hash));
+                }
+                // A private input is encrypted (using `tvk`) and hashed to a field element.
+                ValueType::Private(..) => {
+                    // Ensure the input is a plaintext.
+                    ensure!(matches!(input, Value::Plaintext(..)), "Expected a plaintext input");
+
+                    // Construct the (console) input index as a field element.
+                    let index = Field::from_u16(u16::try_from(index).or_halt_with::<N>("Input index exceeds u16"));
+                    // Compute the input view key as `Hash(function ID || tvk || index)`.
+                    let input_view_key = N::hash_psd4(&[function_id, *tvk, index])?;
+                    // Compute the ciphertext.
+                    let ciphertext = match &input {
+                        Value::Plaintext(plaintext) => plaintext.encrypt_symmetric(input_view_key)?,
+                        // Ensure the input is a plaintext.
+                        Value::Record(..) => bail!("Expected a plaintext input, found a record input"),
+                        Value::Future(..) => bail!("Expected a plaintext input, found a future input"),
+                    };
+                    // Hash the ciphertext to a field element.
+                    let input_hash = N::hash_psd8(&ciphertext.to_fields()?)?;
+
+                    // Add the input hash to the message.
+                    message.push(input_hash);
+                    // Add the input hash to the inputs.
+                    input_ids.push(InputID::Private(input_hash));
+                }
+                // A record input is computed to its serial number.
+                ValueType::Record(..) => {
+                    // Retrieve the record.
+                    let record = match &input {
+                        Value::Record(record) => record,
+                        // Ensure the input is a record.
+                        Value::Plaintext(..) => bail!("Expected a record input, found a plaintext input"),
+                        Value::Future(..) => bail!("Expected a record input, found a future input"),
+                    };
+                    // Ensure the record belongs to the signer.
+                    ensure!(**record.owner() == signer, "Input record for '{program_id}' must belong to the signer");
+
+                    // Retrieve the committed record input, as (`commitment`, `H`, `r * H`, `gamma`).
+                    let Some((commitment, h, h_r, gamma)) = record_inputs.next() else {
+                        bail!(
+                            "The signer did not commit to the record input #{index} for '{program_id}/{function_name}'"
+                        )
+                    };
+
+                    // Compute the `serial_number` from `gamma`.
+                    let serial_number = Record::<N, Plaintext<N>>::serial_number_from_gamma(gamma, *commitment)?;
+                    // Compute the tag.
+                    let tag = Record::<N, Plaintext<N>>::tag(*sk_tag, *commitment)?;
+
+                    // Add (`H`, `r * H`, `gamma`, `tag`) to the message.
+                    message.extend([h, h_r, gamma].iter().map(|point| point.to_x_coordinate()));
+                    message.push(tag);
+
+                    // Add the input ID.
+                    input_ids.push(InputID::Record(*commitment, *gamma, serial_number, tag));
+                }
+                // An external record input is hashed (using `tvk`) to a field element.
+                ValueType::ExternalRecord(..) => {
+                    // Ensure the input is a record.
+                    ensure!(matches!(input, Value::Record(..)), "Expected a record input");
+
+                    // Construct the (console) input index as a field element.
+                    let index = Field::from_u16(u16::try_from(index).or_halt_with::<N>("Input index exceeds u16"));
+                    // Construct the preimage as `(function ID || input || tvk || index)`.
+                    let mut preimage = Vec::new();
+                    preimage.push(function_id);
+                    preimage.extend(input.to_fields()?);
+                    preimage.push(*tvk);
+                    preimage.push(index);
+                    // Hash the input to a field element.
+                    let input_hash = N::hash_psd8(&preimage)?;
+
+                    // Add the input hash to the message.
+                    message.push(input_hash);
+                    // Add the input hash to the inputs.
+                    input_ids.push(InputID::ExternalRecord(input_hash));
+                }
+                // A future is not a valid input.
+                ValueType::Future(..) => bail!("A future is not a valid input"),
+            }
+        }
+
+        // Ensure each committed record input is used.
+        ensure!(record_inputs.next().is_none(), "The signer committed to more record inputs than provided");
+
+        Ok(Self {
+            signer,
+            compute_key: *compute_key,
+            program_id,
+            function_name,
+            input_ids,
+            inputs: prepared_inputs,
+            sk_tag: *sk_tag,
+            tvk: *tvk,
+            tcm,
+            message,
+        })
+    }
+
+    /// Returns the message to be signed with the nonce of the signer commitment, as `[tvk, tcm, function ID, input IDs]`.
+    pub fn to_sign_message(&self) -> &[Field<N>] {
+        &self.message
+    }
+
+    /// Returns the request, given the signature of the message (see `Request::sign_with_nonce`).
+    ///
+    /// Note: The signature is not verified here; use `Request::verify` to validate the resulting request.
+    pub fn into_request(self, signature: Signature<N>) -> Result<Request<N>> {
+        // Ensure the signature is from the signer.
+        ensure!(signature.compute_key() == self.compute_key, "The signature is not from the request signer");
+
+        Ok(Request {
+            signer: self.signer,
+            network_id: U16::new(N::ID),
+            program_id: self.program_id,
+            function_name: self.function_name,
+            input_ids: self.input_ids,
+            inputs: self.inputs,
+            signature,
+            sk_tag: self.sk_tag,
+            tvk: self.tvk,
+            tcm: self.tcm,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_sign_out_of_band() {
+        let rng = &mut TestRng::default();
+
+        // Sample a random private key and address.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Construct a program ID and function name.
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let function_name = Identifier::from_str("transfer").unwrap();
+
+        // Prepare the inputs, including a record input.
+        let record_string = format!(
+            "{{ owner: {address}.private, token_amount: 100u64.private, _nonce: 2293253577170800572742339369209137467208538700597121244293392265726446806023group.public }}"
+        );
+        let inputs = vec![
+            Value::from_str(&record_string).unwrap(),
+            Value::from_str("5u64").unwrap(),
+            Value::from_str("10field").unwrap(),
+        ];
+        let input_types = [
+            ValueType::from_str("token.record").unwrap(),
+            ValueType::from_str("u64.public").unwrap(),
+            ValueType::from_str("field.private").unwrap(),
+        ];
+
+        // Phase 1: The signer derives the nonce, and commits to it.
+        let record_commitments = UnsignedRequest::record_commitments(&program_id, &inputs, &input_types).unwrap();
+        assert_eq!(record_commitments.len(), 1);
+        let (nonce, commitment) = Request::commit_signer(&private_key, &record_commitments, rng).unwrap();
+
+        // Construct the unsigned request.
+        let unsigned =
+            UnsignedRequest::new(&commitment, program_id, function_name, inputs.clone().into_iter(), &input_types)
+                .unwrap();
+
+        // Phase 2: The signer signs the message with the same nonce, and the request verifies.
+        let signature = Request::sign_with_nonce(&private_key, nonce, unsigned.to_sign_message()).unwrap();
+        let request = unsigned.clone().into_request(signature).unwrap();
+        assert!(request.verify(&input_types));
+        assert_eq!(request.tvk(), commitment.tvk());
+        assert_eq!(&request.to_tpk(), commitment.tpk());
+
+        // Ensure a signature with a different nonce is rejected.
+        let (other_nonce, _) = Request::commit_signer(&private_key, &record_commitments, rng).unwrap();
+        let signature = Request::sign_with_nonce(&private_key, other_nonce, unsigned.to_sign_message()).unwrap();
+        assert!(!unsigned.clone().into_request(signature).unwrap().verify(&input_types));
+
+        // Ensure a signature from a different signer is rejected.
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let (other_nonce, _) = Request::commit_signer(&other_private_key, &record_commitments, rng).unwrap();
+        let signature = Request::sign_with_nonce(&other_private_key, other_nonce, unsigned.to_sign_message()).unwrap();
+        assert!(unsigned.into_request(signature).is_err());
+
+        // Ensure the unsigned request requires a commitment for each record input.
+        let (_, commitment) = Request::commit_signer(&private_key, &[], rng).unwrap();
+        assert!(
+            UnsignedRequest::new(&commitment, program_id, function_name, inputs.into_iter(), &input_types).is_err()
+        );
+    }
+}
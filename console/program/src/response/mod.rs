@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{Identifier, ProgramID, Register, Value, ValueType};
+use crate::{Future, Identifier, Plaintext, ProgramID, Record, Register, Value, ValueType};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
@@ -38,12 +38,14 @@ pub struct Response<N: Network> {
     output_ids: Vec<OutputID<N>>,
     /// The function outputs.
     outputs: Vec<Value<N>>,
+    /// The function output types.
+    output_types: Vec<ValueType<N>>,
 }
 
-impl<N: Network> From<(Vec<OutputID<N>>, Vec<Value<N>>)> for Response<N> {
+impl<N: Network> From<(Vec<OutputID<N>>, Vec<Value<N>>, Vec<ValueType<N>>)> for Response<N> {
     /// Note: This method is used to eject from a circuit.
-    fn from((output_ids, outputs): (Vec<OutputID<N>>, Vec<Value<N>>)) -> Self {
-        Self { output_ids, outputs }
+    fn from((output_ids, outputs, output_types): (Vec<OutputID<N>>, Vec<Value<N>>, Vec<ValueType<N>>)) -> Self {
+        Self { output_ids, outputs, output_types }
     }
 }
 
@@ -214,7 +216,7 @@ impl<N: Network> Response<N> {
             })
             .collect::<Result<Vec<_>>>()?;
 
-        Ok(Self { output_ids, outputs })
+        Ok(Self { output_ids, outputs, output_types: output_types.to_vec() })
     }
 
     /// Returns the output ID for the transition.
@@ -226,4 +228,45 @@ impl<N: Network> Response<N> {
     pub fn outputs(&self) -> &[Value<N>] {
         &self.outputs
     }
+
+    /// Returns the function output types.
+    pub fn output_types(&self) -> &[ValueType<N>] {
+        &self.output_types
+    }
+
+    /// Returns the function outputs, each paired with its output type.
+    pub fn typed_outputs(&self) -> Result<Vec<(ValueType<N>, Value<N>)>> {
+        // Ensure the number of outputs matches the number of output types.
+        ensure!(
+            self.outputs.len() == self.output_types.len(),
+            "Response has {} outputs, but {} output types",
+            self.outputs.len(),
+            self.output_types.len()
+        );
+        Ok(self.output_types.iter().cloned().zip(self.outputs.iter().cloned()).collect())
+    }
+
+    /// Returns the record outputs, including external records.
+    pub fn records(&self) -> impl '_ + Iterator<Item = &Record<N, Plaintext<N>>> {
+        self.outputs.iter().filter_map(|output| match output {
+            Value::Record(record) => Some(record),
+            _ => None,
+        })
+    }
+
+    /// Returns the future outputs.
+    pub fn futures(&self) -> impl '_ + Iterator<Item = &Future<N>> {
+        self.outputs.iter().filter_map(|output| match output {
+            Value::Future(future) => Some(future),
+            _ => None,
+        })
+    }
+
+    /// Returns the plaintext outputs, including constant, public, and private outputs.
+    pub fn plaintexts(&self) -> impl '_ + Iterator<Item = &Plaintext<N>> {
+        self.outputs.iter().filter_map(|output| match output {
+            Value::Plaintext(plaintext) => Some(plaintext),
+            _ => None,
+        })
+    }
 }
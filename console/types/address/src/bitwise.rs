@@ -14,6 +14,13 @@
 
 use super::*;
 
+impl<E: Environment> Address<E> {
+    /// Returns `true` if `self` and `other` are equal, in constant time.
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        self.address.ct_eq(&other.address)
+    }
+}
+
 impl<E: Environment> Equal for Address<E> {
     type Output = Boolean<E>;
 
@@ -223,6 +223,23 @@ impl<E: Environment> Inverse for Field<E> {
     }
 }
 
+impl<E: Environment> Field<E> {
+    /// Inverts each of the given field elements in place, using Montgomery's batch inversion.
+    /// If any of the field elements is zero, an error is returned and the elements are left unchanged.
+    pub fn batch_inverse(values: &mut [Self]) -> Result<()> {
+        // Ensure none of the field elements are zero.
+        if let Some(index) = values.iter().position(|value| value.is_zero()) {
+            bail!("Failed to batch invert field elements: the element at index {index} is zero")
+        }
+
+        // Invert the field elements.
+        let mut elements: Vec<_> = values.iter().map(|value| value.field).collect();
+        batch_inversion(&mut elements);
+        values.iter_mut().zip(elements).for_each(|(value, element)| *value = Field::new(element));
+        Ok(())
+    }
+}
+
 impl<E: Environment> Square for Field<E> {
     type Output = Field<E>;
 
@@ -274,6 +291,19 @@ impl<E: Environment> Field<E> {
             None => bail!("Failed to square root a field element: {self}"),
         }
     }
+
+    /// Returns `true` if `self` is a square (including zero), using the Legendre symbol.
+    /// This requires a single exponentiation, and is cheaper than attempting a square root.
+    #[inline]
+    pub fn is_square(&self) -> bool {
+        !self.field.legendre().is_qnr()
+    }
+
+    /// Returns the `square_root` of each of the given field elements, or `None` for each non-square element.
+    /// If there are two square roots, the bitwise lesser one is returned, as in `square_root`.
+    pub fn square_roots(values: &[Self]) -> Vec<Option<Self>> {
+        values.iter().map(|value| value.square_root().ok()).collect()
+    }
 }
 
 impl<E: Environment> Sum<Field<E>> for Field<E> {
@@ -323,4 +353,89 @@ mod tests {
         let result = std::panic::catch_unwind(|| one / zero);
         assert!(result.is_err()); // Probe further for specific error type here, if desired
     }
+
+    #[test]
+    fn test_batch_inverse() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_elements in [0, 1, 2, 10, 100] {
+            // Sample random nonzero field elements.
+            let elements: Vec<_> = (0..num_elements)
+                .map(|_| loop {
+                    let element = Field::<CurrentEnvironment>::rand(&mut rng);
+                    if !element.is_zero() {
+                        break element;
+                    }
+                })
+                .collect();
+
+            // Ensure the batch inversion is consistent with the element-wise inversion.
+            let mut candidate = elements.clone();
+            Field::batch_inverse(&mut candidate)?;
+            let expected = elements.iter().map(|element| element.inverse()).collect::<Result<Vec<_>>>()?;
+            assert_eq!(candidate, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_inverse_with_zero() {
+        let mut rng = TestRng::default();
+
+        // Sample random field elements, with a zero in the middle.
+        let mut elements: Vec<_> = (0..10).map(|_| Field::<CurrentEnvironment>::rand(&mut rng)).collect();
+        elements[5] = Field::zero();
+
+        // Ensure the batch inversion fails, and leaves the elements unchanged.
+        let mut candidate = elements.clone();
+        assert!(Field::batch_inverse(&mut candidate).is_err());
+        assert_eq!(candidate, elements);
+    }
+
+    #[test]
+    fn test_is_square() {
+        let mut rng = TestRng::default();
+
+        // Ensure the known squares are detected.
+        assert!(Field::<CurrentEnvironment>::zero().is_square());
+        assert!(Field::<CurrentEnvironment>::one().is_square());
+        assert!(Field::<CurrentEnvironment>::from_u8(4).is_square());
+        // Ensure the multiplicative generator (22) is detected as a non-residue.
+        let non_residue = Field::<CurrentEnvironment>::from_u8(22);
+        assert!(!non_residue.is_square());
+
+        for _ in 0..100 {
+            let element = Field::<CurrentEnvironment>::rand(&mut rng);
+            // Ensure the check is consistent with the square root.
+            assert_eq!(element.is_square(), element.square_root().is_ok());
+            // Ensure a square is a residue, and a square times a non-residue is a non-residue.
+            assert!(element.square().is_square());
+            if !element.is_zero() {
+                assert!(!(element.square() * non_residue).is_square());
+            }
+        }
+    }
+
+    #[test]
+    fn test_square_roots() {
+        let mut rng = TestRng::default();
+
+        for num_elements in [0, 1, 2, 10, 100] {
+            // Sample random field elements, including squares and non-squares.
+            let elements: Vec<_> = (0..num_elements)
+                .map(|i| {
+                    let element = Field::<CurrentEnvironment>::rand(&mut rng);
+                    match i % 2 == 0 {
+                        true => element.square(),
+                        false => element,
+                    }
+                })
+                .collect();
+
+            // Ensure the square roots are consistent with the element-wise square root.
+            let candidate = Field::square_roots(&elements);
+            let expected: Vec<_> = elements.iter().map(|element| element.square_root().ok()).collect();
+            assert_eq!(candidate, expected);
+        }
+    }
 }
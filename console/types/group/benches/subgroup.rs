@@ -0,0 +1,44 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_console_network_environment::Console;
+use snarkvm_console_types_group::{Group, TestRng, Uniform};
+
+use criterion::Criterion;
+
+fn subgroup_check(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    for num_points in [16, 128, 1024] {
+        let points = (0..num_points).map(|_| Group::<Console>::rand(rng)).collect::<Vec<_>>();
+
+        c.bench_function(&format!("Group::is_in_subgroup - {num_points} points"), |b| {
+            b.iter(|| points.iter().all(|point| point.is_in_subgroup()))
+        });
+        c.bench_function(&format!("Group::batch_subgroup_check - {num_points} points"), |b| {
+            b.iter(|| Group::batch_subgroup_check(&points, rng))
+        });
+    }
+}
+
+criterion_group! {
+    name = group;
+    config = Criterion::default().sample_size(10);
+    targets = subgroup_check
+}
+
+criterion_main!(group);
@@ -14,6 +14,16 @@
 
 use super::*;
 
+impl<E: Environment> Group<E> {
+    /// Returns `true` if `self` and `other` are equal, in constant time.
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        let (self_x, self_y) = self.to_xy_coordinates();
+        let (other_x, other_y) = other.to_xy_coordinates();
+        // Note: The non-short-circuiting `&` is used to compare both coordinates.
+        self_x.ct_eq(&other_x) & self_y.ct_eq(&other_y)
+    }
+}
+
 impl<E: Environment> Equal for Group<E> {
     type Output = Boolean<E>;
 
@@ -31,3 +31,35 @@ impl<E: Environment> Group<E> {
         bail!("Failed to recover an affine group from an x-coordinate of {x_coordinate}")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 1_000;
+
+    #[test]
+    fn test_from_x_coordinate() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Ensure the x-coordinate of a random group element is recovered.
+            let expected = Group::<CurrentEnvironment>::new(Uniform::rand(&mut rng));
+            let candidate = Group::<CurrentEnvironment>::from_x_coordinate(expected.to_x_coordinate())?;
+            assert_eq!(expected, candidate);
+
+            // Ensure a random x-coordinate is recovered if and only if the unchecked recovery succeeds.
+            let x_coordinate = Field::<CurrentEnvironment>::rand(&mut rng);
+            let expected = [true, false].into_iter().find_map(|greatest| {
+                <CurrentEnvironment as Environment>::Affine::from_x_coordinate(*x_coordinate, greatest)
+                    .filter(|point| point.is_in_correct_subgroup_assuming_on_curve())
+                    .map(Group::new)
+            });
+            assert_eq!(expected, Group::from_x_coordinate(x_coordinate).ok());
+        }
+        Ok(())
+    }
+}
@@ -28,6 +28,7 @@ mod random;
 mod serialize;
 mod size_in_bits;
 mod size_in_bytes;
+mod subgroup;
 mod to_bits;
 mod to_field;
 mod to_fields;
@@ -0,0 +1,148 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Group<E> {
+    /// The number of random linear combinations checked in `batch_subgroup_check`.
+    const BATCH_SUBGROUP_CHECK_ROUNDS: usize = 64;
+
+    /// Returns `true` if `self` is in the prime-order subgroup.
+    pub fn is_in_subgroup(&self) -> bool {
+        self.group.to_affine().is_in_correct_subgroup_assuming_on_curve()
+    }
+
+    /// Returns `Ok(())` if all of the given group elements are in the prime-order subgroup,
+    /// and otherwise returns the index of the first group element that is not.
+    ///
+    /// Each group element is the sum of a prime-order component and a small-order component, and only
+    /// the small-order components remain after a multiplication by the subgroup order. As such, this method
+    /// checks random linear combinations of the group elements, with coefficients sampled modulo the cofactor,
+    /// which requires a single subgroup check per round (instead of one per group element). A group element
+    /// outside the subgroup survives each round with probability at most 1/2, so an invalid batch is accepted
+    /// with probability at most `2^-BATCH_SUBGROUP_CHECK_ROUNDS`. If the batch is invalid, the group elements
+    /// are checked individually to locate the offending group element.
+    pub fn batch_subgroup_check<R: Rng + CryptoRng>(points: &[Self], rng: &mut R) -> Result<(), usize> {
+        // (For advanced users) The cofactor for this curve is `4`, so the coefficients are sampled from `[0, 4)`.
+        debug_assert!(E::Affine::cofactor().len() == 1 && E::Affine::cofactor()[0] == 4);
+
+        // If the batch is small, check the group elements individually, as this is cheaper.
+        if points.len() <= Self::BATCH_SUBGROUP_CHECK_ROUNDS {
+            return Self::check_subgroup_individually(points);
+        }
+
+        let is_valid = (0..Self::BATCH_SUBGROUP_CHECK_ROUNDS).all(|_| {
+            // Accumulate the group elements into buckets, by their sampled coefficient.
+            let mut buckets = [Group::zero(); 3];
+            for point in points {
+                if let Some(coefficient) = rng.gen_range(0..4usize).checked_sub(1) {
+                    buckets[coefficient] += point;
+                }
+            }
+            // Compute the linear combination `1 * B_1 + 2 * B_2 + 3 * B_3 = (B_1 + B_3) + 2 * (B_2 + B_3)`.
+            let [one, two, three] = buckets;
+            let combination = (one + three) + (two + three).double();
+            // Ensure the linear combination is in the subgroup.
+            combination.is_in_subgroup()
+        });
+
+        match is_valid {
+            true => Ok(()),
+            false => Self::check_subgroup_individually(points),
+        }
+    }
+
+    /// Returns `Ok(())` if all of the given group elements are in the prime-order subgroup,
+    /// and otherwise returns the index of the first group element that is not.
+    fn check_subgroup_individually(points: &[Self]) -> Result<(), usize> {
+        match points.iter().position(|point| !point.is_in_subgroup()) {
+            Some(index) => Err(index),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const NUM_POINTS: usize = 100;
+
+    /// Returns the point `(0, -1)`, which has order 2.
+    fn sample_small_order_point() -> Group<CurrentEnvironment> {
+        Group::from_xy_coordinates_unchecked(Field::zero(), -Field::one())
+    }
+
+    /// Returns a batch of random group elements in the prime-order subgroup.
+    fn sample_points(rng: &mut TestRng) -> Vec<Group<CurrentEnvironment>> {
+        (0..NUM_POINTS).map(|_| Group::rand(rng)).collect()
+    }
+
+    #[test]
+    fn test_is_in_subgroup() {
+        let mut rng = TestRng::default();
+
+        let small_order_point = sample_small_order_point();
+        assert!(!small_order_point.is_in_subgroup());
+        assert!(small_order_point.double().is_zero());
+
+        for point in sample_points(&mut rng) {
+            assert!(point.is_in_subgroup());
+            assert!(!(point + small_order_point).is_in_subgroup());
+        }
+    }
+
+    #[test]
+    fn test_batch_subgroup_check() {
+        let mut rng = TestRng::default();
+
+        // Ensure a batch of valid group elements passes, for both the individual and the batched checks.
+        let points = sample_points(&mut rng);
+        assert_eq!(Group::<CurrentEnvironment>::batch_subgroup_check(&[], &mut rng), Ok(()));
+        assert_eq!(Group::batch_subgroup_check(&points[..10], &mut rng), Ok(()));
+        assert_eq!(Group::batch_subgroup_check(&points, &mut rng), Ok(()));
+    }
+
+    #[test]
+    fn test_batch_subgroup_check_detects_small_order_point() {
+        let mut rng = TestRng::default();
+        let small_order_point = sample_small_order_point();
+
+        for index in [0, NUM_POINTS / 2, NUM_POINTS - 1] {
+            // Ensure a small-order point mixed into a valid batch is detected.
+            let mut points = sample_points(&mut rng);
+            points[index] = small_order_point;
+            assert_eq!(Group::batch_subgroup_check(&points, &mut rng), Err(index));
+
+            // Ensure a valid point shifted by a small-order point is detected.
+            let mut points = sample_points(&mut rng);
+            points[index] += small_order_point;
+            assert_eq!(Group::batch_subgroup_check(&points, &mut rng), Err(index));
+        }
+
+        // Ensure a small batch, which is checked individually, reports the offending index.
+        let mut points = sample_points(&mut rng);
+        points[3] = small_order_point;
+        assert_eq!(Group::batch_subgroup_check(&points[..10], &mut rng), Err(3));
+
+        // Ensure two small-order components, which cancel under odd coefficients, are detected.
+        let mut points = sample_points(&mut rng);
+        points[0] += small_order_point;
+        points[1] += small_order_point;
+        assert_eq!(Group::batch_subgroup_check(&points, &mut rng), Err(0));
+    }
+}
@@ -59,6 +59,22 @@ impl<E: Environment, I: IntegerType> AbsWrapped for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> Signum for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    /// Returns the `sign` of `self`, as `-1`, `0`, or `1`.
+    #[inline]
+    fn signum(self) -> Self::Output {
+        if self.integer.is_zero() {
+            Integer::zero()
+        } else if self.integer < I::zero() {
+            Integer::new(I::one().wrapping_neg())
+        } else {
+            Integer::one()
+        }
+    }
+}
+
 impl<E: Environment, I: IntegerType> Add<Integer<E, I>> for Integer<E, I> {
     type Output = Integer<E, I>;
 
@@ -277,6 +277,28 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShrAssign<Integer<E, M>> for
     }
 }
 
+impl<E: Environment, I: IntegerType, M: Magnitude> RotateLeft<Integer<E, M>> for Integer<E, I> {
+    type Output = Self;
+
+    /// Rotates `self` to the left by `n` bits, wrapping the truncated bits to the end.
+    /// Note: The rotation amount is taken modulo the number of bits in `self`.
+    #[inline]
+    fn rotate_left(&self, n: &Integer<E, M>) -> Self::Output {
+        Integer::new(self.integer.rotate_left(n.integer.to_u32().unwrap()))
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> RotateRight<Integer<E, M>> for Integer<E, I> {
+    type Output = Self;
+
+    /// Rotates `self` to the right by `n` bits, wrapping the truncated bits to the beginning.
+    /// Note: The rotation amount is taken modulo the number of bits in `self`.
+    #[inline]
+    fn rotate_right(&self, n: &Integer<E, M>) -> Self::Output {
+        Integer::new(self.integer.rotate_right(n.integer.to_u32().unwrap()))
+    }
+}
+
 impl<E: Environment, I: IntegerType> Ternary for Integer<E, I> {
     type Boolean = Boolean<E>;
     type Output = Self;
@@ -223,6 +223,23 @@ impl<E: Environment> Inverse for Scalar<E> {
     }
 }
 
+impl<E: Environment> Scalar<E> {
+    /// Inverts each of the given scalar elements in place, using Montgomery's batch inversion.
+    /// If any of the scalar elements is zero, an error is returned and the elements are left unchanged.
+    pub fn batch_inverse(values: &mut [Self]) -> Result<()> {
+        // Ensure none of the scalar elements are zero.
+        if let Some(index) = values.iter().position(|value| value.is_zero()) {
+            bail!("Failed to batch invert scalar elements: the element at index {index} is zero")
+        }
+
+        // Invert the scalar elements.
+        let mut elements: Vec<_> = values.iter().map(|value| value.scalar).collect();
+        batch_inversion(&mut elements);
+        values.iter_mut().zip(elements).for_each(|(value, element)| *value = Scalar::new(element));
+        Ok(())
+    }
+}
+
 impl<E: Environment> Square for Scalar<E> {
     type Output = Scalar<E>;
 
@@ -280,4 +297,42 @@ mod tests {
         let result = std::panic::catch_unwind(|| one / zero);
         assert!(result.is_err()); // Probe further for specific error type here, if desired
     }
+
+    #[test]
+    fn test_batch_inverse() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_elements in [0, 1, 2, 10, 100] {
+            // Sample random nonzero scalar elements.
+            let elements: Vec<_> = (0..num_elements)
+                .map(|_| loop {
+                    let element = Scalar::<CurrentEnvironment>::rand(&mut rng);
+                    if !element.is_zero() {
+                        break element;
+                    }
+                })
+                .collect();
+
+            // Ensure the batch inversion is consistent with the element-wise inversion.
+            let mut candidate = elements.clone();
+            Scalar::batch_inverse(&mut candidate)?;
+            let expected = elements.iter().map(|element| element.inverse()).collect::<Result<Vec<_>>>()?;
+            assert_eq!(candidate, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_inverse_with_zero() {
+        let mut rng = TestRng::default();
+
+        // Sample random scalar elements, with a zero in the middle.
+        let mut elements: Vec<_> = (0..10).map(|_| Scalar::<CurrentEnvironment>::rand(&mut rng)).collect();
+        elements[5] = Scalar::zero();
+
+        // Ensure the batch inversion fails, and leaves the elements unchanged.
+        let mut candidate = elements.clone();
+        assert!(Scalar::batch_inverse(&mut candidate).is_err());
+        assert_eq!(candidate, elements);
+    }
 }
@@ -14,6 +14,13 @@
 
 use super::*;
 
+impl<E: Environment> Scalar<E> {
+    /// Returns `true` if `self` and `other` are equal, in constant time.
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        ct_eq_limbs(self.to_bigint().as_ref(), other.to_bigint().as_ref())
+    }
+}
+
 impl<E: Environment> Ord for Scalar<E> {
     /// Returns the lexicographic ordering of `self` and `other`.
     #[inline]
@@ -23,7 +23,8 @@ impl<N: Network> FromBytes for Authority<N> {
         match variant {
             0 => Ok(Self::Beacon(FromBytes::read_le(&mut reader)?)),
             1 => Ok(Self::Quorum(FromBytes::read_le(&mut reader)?)),
-            2.. => Err(error("Invalid authority variant")),
+            2 => Ok(Self::GenesisQuorum(FromBytes::read_le(&mut reader)?)),
+            3.. => Err(error("Invalid authority variant")),
         }
     }
 }
@@ -45,6 +46,12 @@ impl<N: Network> ToBytes for Authority<N> {
                 // Write the subdag.
                 subdag.write_le(&mut writer)
             }
+            Self::GenesisQuorum(committee) => {
+                // Write the variant.
+                2u8.write_le(&mut writer)?;
+                // Write the committee.
+                committee.write_le(&mut writer)
+            }
         }
     }
 }
@@ -48,15 +48,17 @@ use console::{
     },
     types::Field,
 };
+use ledger_committee::Committee;
 use narwhal_subdag::Subdag;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use rand::{CryptoRng, Rng};
 
 #[derive(Clone, PartialEq, Eq)]
 pub enum Authority<N: Network> {
     Beacon(Signature<N>),
     Quorum(Subdag<N>),
+    GenesisQuorum(Committee<N>),
 }
 
 impl<N: Network> Authority<N> {
@@ -76,6 +78,11 @@ impl<N: Network> Authority<N> {
     pub fn new_quorum(subdag: Subdag<N>) -> Self {
         Self::Quorum(subdag)
     }
+
+    /// Initializes a new genesis quorum authority.
+    pub fn new_genesis_quorum(committee: Committee<N>) -> Self {
+        Self::GenesisQuorum(committee)
+    }
 }
 
 impl<N: Network> Authority<N> {
@@ -88,6 +95,11 @@ impl<N: Network> Authority<N> {
     pub const fn from_quorum(subdag: Subdag<N>) -> Self {
         Self::Quorum(subdag)
     }
+
+    /// Initializes a new genesis quorum authority.
+    pub const fn from_genesis_quorum(committee: Committee<N>) -> Self {
+        Self::GenesisQuorum(committee)
+    }
 }
 
 impl<N: Network> Authority<N> {
@@ -100,16 +112,26 @@ impl<N: Network> Authority<N> {
     pub const fn is_quorum(&self) -> bool {
         matches!(self, Self::Quorum(_))
     }
+
+    /// Returns `true` if the authority is a genesis quorum.
+    pub const fn is_genesis_quorum(&self) -> bool {
+        matches!(self, Self::GenesisQuorum(_))
+    }
 }
 
 impl<N: Network> Authority<N> {
     /// Returns address of the authority.
     /// If the authority is a beacon, the address of the signer is returned.
     /// If the authority is a quorum, the address of the leader is returned.
-    pub fn to_address(&self) -> Address<N> {
+    /// If the authority is a genesis quorum, the address of the first committee member is returned.
+    pub fn to_address(&self) -> Result<Address<N>> {
         match self {
-            Self::Beacon(signature) => signature.to_address(),
-            Self::Quorum(subdag) => subdag.leader_address(),
+            Self::Beacon(signature) => Ok(signature.to_address()),
+            Self::Quorum(subdag) => Ok(subdag.leader_address()),
+            Self::GenesisQuorum(committee) => match committee.members().keys().next() {
+                Some(address) => Ok(*address),
+                None => bail!("The genesis quorum authority has an empty committee"),
+            },
         }
     }
 }
@@ -132,8 +154,13 @@ pub mod test_helpers {
         Authority::new_quorum(narwhal_subdag::test_helpers::sample_subdag(rng))
     }
 
+    /// Returns a sample genesis quorum authority.
+    pub fn sample_genesis_quorum_authority(rng: &mut TestRng) -> Authority<CurrentNetwork> {
+        Authority::new_genesis_quorum(ledger_committee::test_helpers::sample_committee(rng))
+    }
+
     /// Returns a list of sample authorities.
     pub fn sample_authorities(rng: &mut TestRng) -> Vec<Authority<CurrentNetwork>> {
-        vec![sample_beacon_authority(rng), sample_quorum_authority(rng)]
+        vec![sample_beacon_authority(rng), sample_quorum_authority(rng), sample_genesis_quorum_authority(rng)]
     }
 }
@@ -30,6 +30,10 @@ impl<N: Network> Serialize for Authority<N> {
                         authority.serialize_field("type", "quorum")?;
                         authority.serialize_field("subdag", subdag)?;
                     }
+                    Self::GenesisQuorum(committee) => {
+                        authority.serialize_field("type", "genesis_quorum")?;
+                        authority.serialize_field("committee", committee)?;
+                    }
                 }
                 authority.end()
             }
@@ -55,6 +59,9 @@ impl<'de, N: Network> Deserialize<'de> for Authority<N> {
                     "quorum" => Ok(Self::from_quorum(
                         DeserializeExt::take_from_value::<D>(&mut authority, "subdag").map_err(de::Error::custom)?,
                     )),
+                    "genesis_quorum" => Ok(Self::from_genesis_quorum(
+                        DeserializeExt::take_from_value::<D>(&mut authority, "committee").map_err(de::Error::custom)?,
+                    )),
                     _ => Err(error("Invalid authority type")).map_err(de::Error::custom),
                 }
             }
@@ -15,8 +15,8 @@
 #[macro_use]
 extern crate criterion;
 
-use console::{network::Testnet3, prelude::*};
-use ledger_block::Block;
+use console::{network::Testnet3, prelude::*, program::Plaintext};
+use ledger_block::{Block, Input, Output, Ratifications, Ratify};
 
 use criterion::Criterion;
 
@@ -24,7 +24,7 @@ type CurrentNetwork = Testnet3;
 
 /// Loads the genesis block.
 fn load_genesis_block() -> Block<CurrentNetwork> {
-    Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap()
+    Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes().unwrap()).unwrap()
 }
 
 /// Helper method to benchmark serialization.
@@ -108,10 +108,66 @@ fn transition_serialization(c: &mut Criterion) {
     bench_serialization(c, "Transition", transition);
 }
 
+fn ratifications_root(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    // Sample 10,000 ratifications.
+    const NUM_RATIFICATIONS: usize = 10_000;
+    let ratifications =
+        Ratifications::<CurrentNetwork>::try_from_iter((0..NUM_RATIFICATIONS).map(|_| Ratify::BlockReward(rng.gen())))
+            .unwrap();
+
+    c.bench_function(&format!("Ratifications::to_ratifications_root ({NUM_RATIFICATIONS} ratifications)"), |b| {
+        b.iter(|| ratifications.to_ratifications_root().unwrap())
+    });
+}
+
+/// Returns the number of bytes retained by the plaintext bit caches in the given block.
+fn plaintext_bits_cache_size(block: &Block<CurrentNetwork>) -> usize {
+    block
+        .transitions()
+        .map(|transition| {
+            let inputs = transition.inputs().iter().map(|input| match input {
+                Input::Constant(_, Some(plaintext)) | Input::Public(_, Some(plaintext)) => {
+                    plaintext.cached_bits_size_in_bytes()
+                }
+                _ => 0,
+            });
+            let outputs = transition.outputs().iter().map(|output| match output {
+                Output::Constant(_, Some(plaintext)) | Output::Public(_, Some(plaintext)) => {
+                    plaintext.cached_bits_size_in_bytes()
+                }
+                _ => 0,
+            });
+            inputs.sum::<usize>() + outputs.sum::<usize>()
+        })
+        .sum()
+}
+
+fn block_plaintext_bits_cache(c: &mut Criterion) {
+    let buffer = CurrentNetwork::genesis_bytes().unwrap();
+
+    for (name, threshold) in [("cached", usize::MAX), ("uncached", 0)] {
+        // Set the plaintext bits cache threshold.
+        Plaintext::<CurrentNetwork>::set_bits_cache_threshold(threshold);
+
+        // Report the memory retained by the plaintext bit caches after decoding the block.
+        let block = Block::<CurrentNetwork>::from_bytes_le(buffer).unwrap();
+        println!("Block::from_bytes_le ({name}) retains {} bytes of plaintext bits", plaintext_bits_cache_size(&block));
+
+        c.bench_function(&format!("Block::from_bytes_le ({name} plaintext bits)"), |b| {
+            b.iter(|| Block::<CurrentNetwork>::from_bytes_le(buffer).unwrap())
+        });
+    }
+
+    // Restore the default threshold.
+    Plaintext::<CurrentNetwork>::set_bits_cache_threshold(usize::MAX);
+}
+
 criterion_group! {
     name = block;
     config = Criterion::default().sample_size(10);
-    targets = block_serialization, block_header_serialization, block_transactions_serialization, transaction_serialization, transition_serialization
+    targets = block_serialization, block_header_serialization, block_transactions_serialization, transaction_serialization, transition_serialization, ratifications_root, block_plaintext_bits_cache
 }
 
 criterion_main!(block);
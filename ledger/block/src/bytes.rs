@@ -142,7 +142,7 @@ mod tests {
     #[test]
     fn test_genesis_bytes() -> Result<()> {
         // Load the genesis block.
-        let genesis_block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes()).unwrap();
+        let genesis_block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes().unwrap()).unwrap();
 
         // Check the byte representation.
         let expected_bytes = genesis_block.to_bytes_le()?;
@@ -13,19 +13,40 @@
 // limitations under the License.
 
 use super::*;
+use synthesizer_program::FinalizeOperation;
 
 impl<N: Network> Block<N> {
     /// Specifies the number of genesis transactions.
     pub const NUM_GENESIS_TRANSACTIONS: usize = 4;
 
+    /// Initializes a new genesis block for a quorum chain from the given committee,
+    /// ratifications, solutions, transactions, and ratified finalize operations.
+    /// The block is authorized by the committee itself, instead of a beacon signature.
+    pub fn genesis_quorum(
+        committee: Committee<N>,
+        ratifications: Ratifications<N>,
+        solutions: Option<CoinbaseSolution<N>>,
+        transactions: Transactions<N>,
+        ratified_finalize_operations: Vec<FinalizeOperation<N>>,
+    ) -> Result<Self> {
+        // Prepare the genesis block header.
+        let header = Header::genesis(&ratifications, &transactions, ratified_finalize_operations)?;
+        // Prepare the previous block hash.
+        let previous_hash = N::BlockHash::default();
+        // Construct the genesis quorum authority.
+        let authority = Authority::new_genesis_quorum(committee);
+        // Construct the block.
+        Self::from(previous_hash, header, authority, ratifications, solutions, transactions, vec![])
+    }
+
     /// Returns `true` if the block is a genesis block.
     pub fn is_genesis(&self) -> bool {
         // Ensure the previous block hash is zero.
         self.previous_hash == N::BlockHash::default()
             // Ensure the header is a genesis block header.
             && self.header.is_genesis()
-            // Ensure the genesis authority is a beacon.
-            && self.authority.is_beacon()
+            // Ensure the genesis authority is a beacon or a genesis quorum.
+            && (self.authority.is_beacon() || self.authority.is_genesis_quorum())
             // Ensure there is the correct number of ratification operations in the genesis block.
             && self.ratifications.len() == 1
             // Ensure there are no solutions in the genesis block.
@@ -51,7 +72,7 @@ mod tests {
     #[test]
     fn test_genesis() {
         // Load the genesis block.
-        let genesis_block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes()).unwrap();
+        let genesis_block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes().unwrap()).unwrap();
         assert!(genesis_block.is_genesis());
     }
 }
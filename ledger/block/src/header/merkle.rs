@@ -26,6 +26,35 @@ impl<N: Network> Header<N> {
         self.to_tree()?.prove(leaf.index() as usize, &leaf.to_bits_le())
     }
 
+    /// Returns the Merkle path for the leaf at the given index in the block header.
+    pub fn to_path_for_index(&self, leaf_index: u8) -> Result<HeaderPath<N>> {
+        // Compute the Merkle path.
+        self.to_path(&self.to_leaf_for_index(leaf_index)?)
+    }
+
+    /// Returns `true` if the given Merkle path is valid for the given block header root and leaf.
+    pub fn verify_path(path: &HeaderPath<N>, root: &Field<N>, leaf: &HeaderLeaf<N>) -> bool {
+        // Ensure the path is for the index of the given leaf.
+        *path.leaf_index() == leaf.index() as u64
+            // Ensure the path is valid for the given root and leaf.
+            && N::verify_merkle_path_bhp(path, root, &leaf.to_bits_le())
+    }
+
+    /// Returns the Merkle leaf at the given index in the block header.
+    pub fn to_leaf_for_index(&self, leaf_index: u8) -> Result<HeaderLeaf<N>> {
+        match leaf_index {
+            0 => Ok(HeaderLeaf::<N>::new(0, *self.previous_state_root)),
+            1 => Ok(HeaderLeaf::<N>::new(1, self.transactions_root)),
+            2 => Ok(HeaderLeaf::<N>::new(2, self.finalize_root)),
+            3 => Ok(HeaderLeaf::<N>::new(3, self.ratifications_root)),
+            4 => Ok(HeaderLeaf::<N>::new(4, self.solutions_root)),
+            5 => Ok(HeaderLeaf::<N>::new(5, self.subdag_root)),
+            6 => Ok(HeaderLeaf::<N>::new(6, Field::zero())),
+            7 => Ok(HeaderLeaf::<N>::new(7, self.metadata.to_hash()?)),
+            _ => bail!("Non-existent block header leaf index: {leaf_index}"),
+        }
+    }
+
     /// Returns the Merkle leaf for the given ID in the header.
     pub fn to_leaf(&self, id: &Field<N>) -> Result<HeaderLeaf<N>> {
         // If the ID is the previous state root, return the 0th leaf.
@@ -176,4 +205,59 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_merkle_path_for_index() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS / 10 {
+            let coinbase_target = u64::rand(rng);
+            let proof_target = rng.gen_range(0..coinbase_target);
+
+            let header = Header::<CurrentNetwork>::from(
+                Into::<<CurrentNetwork as Network>::StateRoot>::into(Field::rand(rng)),
+                Field::rand(rng),
+                Field::rand(rng),
+                Field::rand(rng),
+                Field::rand(rng),
+                Field::rand(rng),
+                Metadata::new(
+                    CurrentNetwork::ID,
+                    u64::rand(rng),
+                    u32::rand(rng),
+                    u128::rand(rng),
+                    u128::rand(rng),
+                    coinbase_target,
+                    proof_target,
+                    u64::rand(rng),
+                    rng.gen_range(0..i64::MAX),
+                    rng.gen_range(0..i64::MAX),
+                )?,
+            )?;
+
+            // Compute the header root.
+            let root = header.to_root()?;
+
+            for index in 0..(1u8 << HEADER_DEPTH) {
+                // Check the path for the leaf at the given index.
+                let leaf = header.to_leaf_for_index(index)?;
+                assert_eq!(leaf.index(), index);
+                let path = header.to_path_for_index(index)?;
+                assert!(Header::verify_path(&path, &root, &leaf));
+                check_path(path.clone(), root, &leaf)?;
+
+                // Ensure the path is rejected under a different index.
+                let other_index = (index + 1) % (1u8 << HEADER_DEPTH);
+                let wrong_leaf = HeaderLeaf::<CurrentNetwork>::new(other_index, leaf.id());
+                assert!(!Header::verify_path(&path, &root, &wrong_leaf));
+                // Ensure the path is rejected for the leaf at a different index.
+                assert!(!Header::verify_path(&path, &root, &header.to_leaf_for_index(other_index)?));
+            }
+
+            // Ensure an out-of-bounds index is rejected.
+            assert!(header.to_path_for_index(1u8 << HEADER_DEPTH).is_err());
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,469 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A builder for block metadata, which validates each field and reports the offending field on failure.
+#[derive(Clone, Debug)]
+pub struct MetadataBuilder<N: Network> {
+    /// The network ID of the block.
+    network: u16,
+    /// The round that produced this block.
+    round: Option<u64>,
+    /// The height of this block.
+    height: Option<u32>,
+    /// The cumulative weight for this block.
+    cumulative_weight: Option<u128>,
+    /// The cumulative proof target for this block.
+    cumulative_proof_target: Option<u128>,
+    /// The coinbase target for this block.
+    coinbase_target: Option<u64>,
+    /// The proof target for this block.
+    proof_target: Option<u64>,
+    /// The coinbase target for the last coinbase.
+    last_coinbase_target: Option<u64>,
+    /// The Unix timestamp (UTC) for the last coinbase.
+    last_coinbase_timestamp: Option<i64>,
+    /// The Unix timestamp (UTC) for this block.
+    timestamp: Option<i64>,
+    /// The metadata of the previous block, if one is provided.
+    previous: Option<Metadata<N>>,
+}
+
+impl<N: Network> Default for MetadataBuilder<N> {
+    /// Initializes a new metadata builder for the current network.
+    fn default() -> Self {
+        Self {
+            network: N::ID,
+            round: None,
+            height: None,
+            cumulative_weight: None,
+            cumulative_proof_target: None,
+            coinbase_target: None,
+            proof_target: None,
+            last_coinbase_target: None,
+            last_coinbase_timestamp: None,
+            timestamp: None,
+            previous: None,
+        }
+    }
+}
+
+impl<N: Network> MetadataBuilder<N> {
+    /// Initializes a new metadata builder for the current network.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the network ID of the block.
+    pub fn network(mut self, network: u16) -> Self {
+        self.network = network;
+        self
+    }
+
+    /// Sets the round that produced the block.
+    pub fn round(mut self, round: u64) -> Self {
+        self.round = Some(round);
+        self
+    }
+
+    /// Sets the height of the block.
+    pub fn height(mut self, height: u32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Sets the cumulative weight for the block.
+    pub fn cumulative_weight(mut self, cumulative_weight: u128) -> Self {
+        self.cumulative_weight = Some(cumulative_weight);
+        self
+    }
+
+    /// Sets the cumulative proof target for the block.
+    pub fn cumulative_proof_target(mut self, cumulative_proof_target: u128) -> Self {
+        self.cumulative_proof_target = Some(cumulative_proof_target);
+        self
+    }
+
+    /// Sets the coinbase target for the block.
+    pub fn coinbase_target(mut self, coinbase_target: u64) -> Self {
+        self.coinbase_target = Some(coinbase_target);
+        self
+    }
+
+    /// Sets the proof target for the block.
+    pub fn proof_target(mut self, proof_target: u64) -> Self {
+        self.proof_target = Some(proof_target);
+        self
+    }
+
+    /// Sets the coinbase target of the last coinbase.
+    pub fn last_coinbase_target(mut self, last_coinbase_target: u64) -> Self {
+        self.last_coinbase_target = Some(last_coinbase_target);
+        self
+    }
+
+    /// Sets the Unix timestamp (UTC) of the last coinbase.
+    pub fn last_coinbase_timestamp(mut self, last_coinbase_timestamp: i64) -> Self {
+        self.last_coinbase_timestamp = Some(last_coinbase_timestamp);
+        self
+    }
+
+    /// Sets the Unix timestamp (UTC) for the block.
+    pub fn timestamp(mut self, timestamp: i64) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Sets the metadata of the previous block, which the new metadata is additionally checked against.
+    pub fn previous(mut self, previous: Metadata<N>) -> Self {
+        self.previous = Some(previous);
+        self
+    }
+
+    /// Returns the block metadata, ensuring each field is set and well-formed.
+    pub fn build(self) -> Result<Metadata<N>> {
+        /// Returns the value of the given field, or an error naming the field if it is not set.
+        fn require<T>(value: Option<T>, field: &str) -> Result<T> {
+            value.ok_or_else(|| anyhow!("Invalid block metadata: '{field}' is not set"))
+        }
+
+        // Construct the metadata.
+        let metadata = Metadata {
+            network: self.network,
+            round: require(self.round, "round")?,
+            height: require(self.height, "height")?,
+            cumulative_weight: require(self.cumulative_weight, "cumulative_weight")?,
+            cumulative_proof_target: require(self.cumulative_proof_target, "cumulative_proof_target")?,
+            coinbase_target: require(self.coinbase_target, "coinbase_target")?,
+            proof_target: require(self.proof_target, "proof_target")?,
+            last_coinbase_target: require(self.last_coinbase_target, "last_coinbase_target")?,
+            last_coinbase_timestamp: require(self.last_coinbase_timestamp, "last_coinbase_timestamp")?,
+            timestamp: require(self.timestamp, "timestamp")?,
+            _phantom: PhantomData,
+        };
+
+        // Ensure the metadata is well-formed.
+        if !metadata.is_valid() {
+            // Report the offending field.
+            match metadata.height == 0u32 {
+                true => metadata.check_genesis_fields()?,
+                false => metadata.check_fields()?,
+            }
+            bail!("Invalid block metadata")
+        }
+        // If a previous metadata is provided, ensure the metadata follows it.
+        if let Some(previous) = &self.previous {
+            metadata.check_against_previous(previous)?;
+        }
+        Ok(metadata)
+    }
+}
+
+impl<N: Network> Metadata<N> {
+    /// Ensures each field of a (non-genesis) metadata is well-formed, naming the offending field.
+    /// Note: `Metadata::is_valid` decides validity, this check only explains why it failed.
+    fn check_fields(&self) -> Result<()> {
+        // Ensure the network ID is correct.
+        ensure!(
+            self.network == N::ID,
+            "Invalid block metadata: 'network' must be '{}' (found '{}')",
+            N::ID,
+            self.network
+        );
+        // Ensure the round is nonzero.
+        ensure!(self.round != 0u64, "Invalid block metadata: 'round' must be nonzero");
+        // Ensure the height is nonzero.
+        ensure!(self.height != 0u32, "Invalid block metadata: 'height' must be nonzero");
+        // Ensure the round is at least as large as the height.
+        ensure!(
+            self.round >= self.height as u64,
+            "Invalid block metadata: 'round' must be at least the height (found '{}', height '{}')",
+            self.round,
+            self.height
+        );
+        // Ensure the coinbase target is at or above the minimum.
+        ensure!(
+            self.coinbase_target >= N::GENESIS_COINBASE_TARGET,
+            "Invalid block metadata: 'coinbase_target' must be at least '{}' (found '{}')",
+            N::GENESIS_COINBASE_TARGET,
+            self.coinbase_target
+        );
+        // Ensure the proof target is at or above the minimum.
+        ensure!(
+            self.proof_target >= N::GENESIS_PROOF_TARGET,
+            "Invalid block metadata: 'proof_target' must be at least '{}' (found '{}')",
+            N::GENESIS_PROOF_TARGET,
+            self.proof_target
+        );
+        // Ensure the coinbase target is larger than the proof target.
+        ensure!(
+            self.coinbase_target > self.proof_target,
+            "Invalid block metadata: 'coinbase_target' must be larger than the proof target (found '{}', proof target '{}')",
+            self.coinbase_target,
+            self.proof_target
+        );
+        // Ensure the last coinbase target is at or above the minimum.
+        ensure!(
+            self.last_coinbase_target >= N::GENESIS_COINBASE_TARGET,
+            "Invalid block metadata: 'last_coinbase_target' must be at least '{}' (found '{}')",
+            N::GENESIS_COINBASE_TARGET,
+            self.last_coinbase_target
+        );
+        // Ensure the last coinbase timestamp is after the genesis timestamp.
+        ensure!(
+            self.last_coinbase_timestamp >= N::GENESIS_TIMESTAMP,
+            "Invalid block metadata: 'last_coinbase_timestamp' must be at or after the genesis timestamp '{}' (found '{}')",
+            N::GENESIS_TIMESTAMP,
+            self.last_coinbase_timestamp
+        );
+        // Ensure the timestamp in the block is after the genesis timestamp.
+        ensure!(
+            self.timestamp > N::GENESIS_TIMESTAMP,
+            "Invalid block metadata: 'timestamp' must be after the genesis timestamp '{}' (found '{}')",
+            N::GENESIS_TIMESTAMP,
+            self.timestamp
+        );
+        Ok(())
+    }
+
+    /// Ensures each field of a genesis metadata is well-formed, naming the offending field.
+    /// Note: `Metadata::is_genesis` decides validity, this check only explains why it failed.
+    fn check_genesis_fields(&self) -> Result<()> {
+        /// Ensures the given genesis field has the expected value.
+        fn check<T: PartialEq + Display>(field: &str, found: T, expected: T) -> Result<()> {
+            match found == expected {
+                true => Ok(()),
+                false => bail!("Invalid genesis block metadata: '{field}' must be '{expected}' (found '{found}')"),
+            }
+        }
+
+        check("network", self.network, N::ID)?;
+        check("round", self.round, 0u64)?;
+        check("height", self.height, 0u32)?;
+        check("cumulative_weight", self.cumulative_weight, 0u128)?;
+        check("cumulative_proof_target", self.cumulative_proof_target, 0u128)?;
+        check("coinbase_target", self.coinbase_target, N::GENESIS_COINBASE_TARGET)?;
+        check("proof_target", self.proof_target, N::GENESIS_PROOF_TARGET)?;
+        check("last_coinbase_target", self.last_coinbase_target, N::GENESIS_COINBASE_TARGET)?;
+        check("last_coinbase_timestamp", self.last_coinbase_timestamp, N::GENESIS_TIMESTAMP)?;
+        check("timestamp", self.timestamp, N::GENESIS_TIMESTAMP)
+    }
+
+    /// Ensures the metadata follows the given metadata of the previous block.
+    fn check_against_previous(&self, previous: &Self) -> Result<()> {
+        // Ensure the height follows the previous height.
+        ensure!(
+            self.height == previous.height.saturating_add(1),
+            "Invalid block metadata: 'height' must follow the previous height (found '{}', expected '{}')",
+            self.height,
+            previous.height.saturating_add(1)
+        );
+        // Ensure the round is after the previous round.
+        ensure!(
+            self.round > previous.round,
+            "Invalid block metadata: 'round' must be after the previous round (found '{}', previous '{}')",
+            self.round,
+            previous.round
+        );
+        // Ensure the cumulative weight does not decrease.
+        ensure!(
+            self.cumulative_weight >= previous.cumulative_weight,
+            "Invalid block metadata: 'cumulative_weight' must not decrease (found '{}', previous '{}')",
+            self.cumulative_weight,
+            previous.cumulative_weight
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns a builder for a well-formed (non-genesis) metadata.
+    fn sample_builder() -> MetadataBuilder<CurrentNetwork> {
+        MetadataBuilder::new()
+            .round(2)
+            .height(1)
+            .cumulative_weight(10)
+            .cumulative_proof_target(5)
+            .coinbase_target(CurrentNetwork::GENESIS_COINBASE_TARGET)
+            .proof_target(CurrentNetwork::GENESIS_PROOF_TARGET)
+            .last_coinbase_target(CurrentNetwork::GENESIS_COINBASE_TARGET)
+            .last_coinbase_timestamp(CurrentNetwork::GENESIS_TIMESTAMP)
+            .timestamp(CurrentNetwork::GENESIS_TIMESTAMP + 1)
+    }
+
+    /// Ensures the builder fails, and that the error names the given field.
+    fn assert_fails_on(builder: MetadataBuilder<CurrentNetwork>, field: &str) {
+        let error = builder.build().unwrap_err().to_string();
+        assert!(error.contains(&format!("'{field}'")), "Expected an error for '{field}', found: {error}");
+    }
+
+    #[test]
+    fn test_builder_matches_new() {
+        // Check a non-genesis metadata.
+        let expected = Metadata::<CurrentNetwork>::new(
+            CurrentNetwork::ID,
+            2,
+            1,
+            10,
+            5,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+            CurrentNetwork::GENESIS_PROOF_TARGET,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+            CurrentNetwork::GENESIS_TIMESTAMP,
+            CurrentNetwork::GENESIS_TIMESTAMP + 1,
+        )
+        .unwrap();
+        let candidate = sample_builder().build().unwrap();
+        assert_eq!(expected, candidate);
+        assert!(candidate.is_valid());
+
+        // Check the genesis metadata.
+        let expected = Metadata::<CurrentNetwork>::genesis().unwrap();
+        let candidate = MetadataBuilder::new()
+            .round(0)
+            .height(0)
+            .cumulative_weight(0)
+            .cumulative_proof_target(0)
+            .coinbase_target(CurrentNetwork::GENESIS_COINBASE_TARGET)
+            .proof_target(CurrentNetwork::GENESIS_PROOF_TARGET)
+            .last_coinbase_target(CurrentNetwork::GENESIS_COINBASE_TARGET)
+            .last_coinbase_timestamp(CurrentNetwork::GENESIS_TIMESTAMP)
+            .timestamp(CurrentNetwork::GENESIS_TIMESTAMP)
+            .build()
+            .unwrap();
+        assert_eq!(expected, candidate);
+
+        // Check the builder against a previous metadata.
+        let candidate = sample_builder().previous(expected).build().unwrap();
+        assert_eq!(sample_builder().build().unwrap(), candidate);
+    }
+
+    #[test]
+    fn test_builder_missing_field() {
+        let builder = MetadataBuilder::<CurrentNetwork>::new().round(2).height(1);
+        assert_fails_on(builder, "cumulative_weight");
+    }
+
+    #[test]
+    fn test_builder_invalid_fields() {
+        let genesis_coinbase_target = CurrentNetwork::GENESIS_COINBASE_TARGET;
+        let genesis_proof_target = CurrentNetwork::GENESIS_PROOF_TARGET;
+        let genesis_timestamp = CurrentNetwork::GENESIS_TIMESTAMP;
+
+        assert_fails_on(sample_builder().network(CurrentNetwork::ID + 1), "network");
+        assert_fails_on(sample_builder().round(0), "round");
+        assert_fails_on(sample_builder().round(1).height(2), "round");
+        assert_fails_on(sample_builder().coinbase_target(genesis_coinbase_target - 1), "coinbase_target");
+        assert_fails_on(sample_builder().proof_target(genesis_proof_target - 1), "proof_target");
+        assert_fails_on(sample_builder().proof_target(genesis_coinbase_target), "coinbase_target");
+        assert_fails_on(sample_builder().last_coinbase_target(genesis_coinbase_target - 1), "last_coinbase_target");
+        assert_fails_on(sample_builder().last_coinbase_timestamp(genesis_timestamp - 1), "last_coinbase_timestamp");
+        assert_fails_on(sample_builder().timestamp(genesis_timestamp), "timestamp");
+
+        // Ensure the positional constructor reports the offending field.
+        let error = Metadata::<CurrentNetwork>::new(
+            CurrentNetwork::ID,
+            0,
+            1,
+            10,
+            5,
+            genesis_coinbase_target,
+            genesis_proof_target,
+            genesis_coinbase_target,
+            genesis_timestamp,
+            genesis_timestamp + 1,
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("'round'"));
+    }
+
+    #[test]
+    fn test_builder_matches_is_valid() {
+        let metadata = sample_builder().build().unwrap();
+
+        // Ensure the builder accepts a metadata iff it is valid, for each boundary of each field.
+        for delta in [-1i64, 0, 1] {
+            let shift = |value: u64| value.saturating_add_signed(delta);
+            let candidates = [
+                Metadata { network: metadata.network.wrapping_add(delta as u16), ..metadata },
+                Metadata { round: shift(metadata.height as u64), ..metadata },
+                Metadata { height: shift(metadata.round) as u32, ..metadata },
+                Metadata { coinbase_target: shift(CurrentNetwork::GENESIS_COINBASE_TARGET), ..metadata },
+                Metadata { proof_target: shift(CurrentNetwork::GENESIS_PROOF_TARGET), ..metadata },
+                Metadata { proof_target: shift(metadata.coinbase_target), ..metadata },
+                Metadata { last_coinbase_target: shift(CurrentNetwork::GENESIS_COINBASE_TARGET), ..metadata },
+                Metadata { last_coinbase_timestamp: CurrentNetwork::GENESIS_TIMESTAMP + delta, ..metadata },
+                Metadata { timestamp: CurrentNetwork::GENESIS_TIMESTAMP + delta, ..metadata },
+            ];
+            for candidate in candidates {
+                let builder = MetadataBuilder::<CurrentNetwork>::new()
+                    .network(candidate.network)
+                    .round(candidate.round)
+                    .height(candidate.height)
+                    .cumulative_weight(candidate.cumulative_weight)
+                    .cumulative_proof_target(candidate.cumulative_proof_target)
+                    .coinbase_target(candidate.coinbase_target)
+                    .proof_target(candidate.proof_target)
+                    .last_coinbase_target(candidate.last_coinbase_target)
+                    .last_coinbase_timestamp(candidate.last_coinbase_timestamp)
+                    .timestamp(candidate.timestamp);
+                assert_eq!(builder.build().is_ok(), candidate.is_valid());
+            }
+        }
+    }
+
+    #[test]
+    fn test_builder_invalid_genesis_fields() {
+        let genesis = || {
+            MetadataBuilder::<CurrentNetwork>::new()
+                .round(0)
+                .height(0)
+                .cumulative_weight(0)
+                .cumulative_proof_target(0)
+                .coinbase_target(CurrentNetwork::GENESIS_COINBASE_TARGET)
+                .proof_target(CurrentNetwork::GENESIS_PROOF_TARGET)
+                .last_coinbase_target(CurrentNetwork::GENESIS_COINBASE_TARGET)
+                .last_coinbase_timestamp(CurrentNetwork::GENESIS_TIMESTAMP)
+                .timestamp(CurrentNetwork::GENESIS_TIMESTAMP)
+        };
+        assert_fails_on(genesis().round(1), "round");
+        assert_fails_on(genesis().cumulative_weight(1), "cumulative_weight");
+        assert_fails_on(genesis().cumulative_proof_target(1), "cumulative_proof_target");
+        assert_fails_on(genesis().timestamp(CurrentNetwork::GENESIS_TIMESTAMP + 1), "timestamp");
+    }
+
+    #[test]
+    fn test_builder_invalid_against_previous() {
+        let previous = sample_builder().build().unwrap();
+
+        // Ensure the height must follow the previous height.
+        assert_fails_on(sample_builder().previous(previous), "height");
+        // Ensure the round must be after the previous round.
+        assert_fails_on(sample_builder().height(2).previous(previous), "round");
+        // Ensure the cumulative weight must not decrease.
+        assert_fails_on(
+            sample_builder().round(3).height(2).cumulative_weight(9).previous(previous),
+            "cumulative_weight",
+        );
+        // Ensure a well-formed successor is accepted.
+        assert!(sample_builder().round(3).height(2).cumulative_weight(10).previous(previous).build().is_ok());
+    }
+}
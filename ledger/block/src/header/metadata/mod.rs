@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod builder;
+pub use builder::*;
+
 mod bytes;
 mod genesis;
 mod serialize;
@@ -65,25 +68,19 @@ impl<N: Network> Metadata<N> {
         last_coinbase_timestamp: i64,
         timestamp: i64,
     ) -> Result<Self> {
-        // Construct a new metadata.
-        let metadata = Self {
-            network,
-            round,
-            height,
-            cumulative_weight,
-            cumulative_proof_target,
-            coinbase_target,
-            proof_target,
-            last_coinbase_target,
-            last_coinbase_timestamp,
-            timestamp,
-            _phantom: PhantomData,
-        };
-        // Ensure the header is valid.
-        match metadata.is_valid() {
-            true => Ok(metadata),
-            false => bail!("Invalid block metadata: {:?}", metadata),
-        }
+        // Construct and validate the metadata.
+        MetadataBuilder::new()
+            .network(network)
+            .round(round)
+            .height(height)
+            .cumulative_weight(cumulative_weight)
+            .cumulative_proof_target(cumulative_proof_target)
+            .coinbase_target(coinbase_target)
+            .proof_target(proof_target)
+            .last_coinbase_target(last_coinbase_target)
+            .last_coinbase_timestamp(last_coinbase_timestamp)
+            .timestamp(timestamp)
+            .build()
     }
 
     /// Returns `true` if the block metadata is well-formed.
@@ -40,6 +40,27 @@ pub const fn puzzle_reward(coinbase_reward: u64) -> u64 {
     coinbase_reward / 2
 }
 
+/// Calculate the proving reward of a solution, given the puzzle reward, its proof target, and the combined proof target.
+///     R_proving = floor(R_puzzle * P / P_C).
+///     R_puzzle = Puzzle reward.
+///     P = Proof target of the solution.
+///     P_C = Combined proof target.
+/// Returns an error if the proving reward exceeds the maximum coinbase reward, in which case the solution is not rewarded.
+pub fn proving_reward(puzzle_reward: u64, proof_target: u64, combined_proof_target: u128) -> Result<u64> {
+    // If the combined proof target is 0, there is no proving reward.
+    // Note: This guarantees the denominator cannot be 0 (to prevent a div by 0).
+    if combined_proof_target == 0 {
+        return Ok(0);
+    }
+    // Calculate the proving reward.
+    let reward = (puzzle_reward as u128).saturating_mul(proof_target as u128).saturating_div(combined_proof_target);
+    // Ensure the proving reward is less than the maximum coinbase reward.
+    ensure!(reward <= MAX_COINBASE_REWARD as u128, "Prover reward ({reward}) exceeds maximum {MAX_COINBASE_REWARD}");
+    // Return the proving reward.
+    // Note: This '.expect' is guaranteed to be safe, as we ensure the reward is within a safe bound.
+    Ok(u64::try_from(reward).expect("Prover reward exceeds u64::MAX"))
+}
+
 /// Calculates the coinbase reward for a given block.
 ///     R_coinbase = max(0, H_Y10 - H) * R_anchor * min(P, C_R) / C
 ///     R_anchor = Anchor reward.
@@ -289,6 +310,32 @@ mod tests {
         assert!(reward > smaller_reward);
     }
 
+    #[test]
+    fn test_proving_reward() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let puzzle_reward = rng.gen_range(0..=MAX_COINBASE_REWARD);
+            let proof_target = rng.gen_range(0..u64::MAX);
+            let combined_proof_target = (proof_target as u128).saturating_add(rng.gen::<u64>() as u128);
+
+            // Ensure the proving reward is attributed in proportion to the proof target.
+            let reward = proving_reward(puzzle_reward, proof_target, combined_proof_target).unwrap();
+            let expected =
+                (puzzle_reward as u128 * proof_target as u128).checked_div(combined_proof_target).unwrap_or(0);
+            assert_eq!(reward as u128, expected);
+            assert!(reward <= puzzle_reward);
+        }
+
+        // Ensure the proving reward is 0 if the combined proof target is 0.
+        assert_eq!(proving_reward(MAX_COINBASE_REWARD, 0, 0).unwrap(), 0);
+        // Ensure the proving reward is 0 if the puzzle reward is 0.
+        assert_eq!(proving_reward(0, u64::MAX, 1).unwrap(), 0);
+        // Ensure a proving reward above the maximum coinbase reward is rejected.
+        assert!(proving_reward(MAX_COINBASE_REWARD, 2, 1).is_err());
+        assert!(proving_reward(u64::MAX, 2, 2).is_err());
+    }
+
     #[test]
     fn test_coinbase_reward() {
         let coinbase_target: u64 = 10000;
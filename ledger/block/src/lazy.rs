@@ -0,0 +1,284 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+use std::sync::OnceLock;
+
+/// A block whose header is decoded eagerly, and whose solutions and transactions
+/// are decoded on first use from the retained byte buffer.
+///
+/// This is useful when a caller only needs the header of a block (e.g. for header-first sync),
+/// as it avoids parsing every transaction, transition, and proof in the block.
+pub struct LazyBlock<N: Network> {
+    /// The hash of this block.
+    block_hash: N::BlockHash,
+    /// The hash of the previous block.
+    previous_hash: N::BlockHash,
+    /// The header of this block.
+    header: Header<N>,
+    /// The authority for this block.
+    authority: Authority<N>,
+    /// The ratifications in this block.
+    ratifications: Ratifications<N>,
+    /// The serialized block.
+    bytes: Vec<u8>,
+    /// The byte offset of the solutions section.
+    solutions_offset: usize,
+    /// The solutions in the block, and the byte offset of the transactions section.
+    solutions: OnceLock<(Option<CoinbaseSolution<N>>, usize)>,
+    /// The transactions and aborted transaction IDs in this block.
+    transactions: OnceLock<(Transactions<N>, Vec<N::TransactionID>)>,
+}
+
+impl<N: Network> LazyBlock<N> {
+    /// Initializes a new lazy block from the given serialized block.
+    pub fn from_buffer(bytes: Vec<u8>) -> Result<Self> {
+        let mut reader = &bytes[..];
+
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        ensure!(version == 1, "Invalid block version");
+
+        // Read the block hash.
+        let block_hash: N::BlockHash = FromBytes::read_le(&mut reader)?;
+        // Read the previous block hash.
+        let previous_hash: N::BlockHash = FromBytes::read_le(&mut reader)?;
+        // Read the header.
+        let header: Header<N> = FromBytes::read_le(&mut reader)?;
+        // Read the authority.
+        let authority = FromBytes::read_le(&mut reader)?;
+        // Read the ratifications.
+        let ratifications = FromBytes::read_le(&mut reader)?;
+
+        // Ensure the block hash matches.
+        let expected_hash = N::hash_bhp1024(&to_bits_le![previous_hash, header.to_root()?])?;
+        ensure!(block_hash == N::BlockHash::from(expected_hash), "Mismatching block hash, possible data corruption");
+
+        // Compute the byte offset of the solutions section.
+        let solutions_offset = bytes.len() - reader.len();
+
+        Ok(Self {
+            block_hash,
+            previous_hash,
+            header,
+            authority,
+            ratifications,
+            bytes,
+            solutions_offset,
+            solutions: OnceLock::new(),
+            transactions: OnceLock::new(),
+        })
+    }
+
+    /// Returns the block hash.
+    pub const fn hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the previous block hash.
+    pub const fn previous_hash(&self) -> N::BlockHash {
+        self.previous_hash
+    }
+
+    /// Returns the block header.
+    pub const fn header(&self) -> &Header<N> {
+        &self.header
+    }
+
+    /// Returns the authority.
+    pub const fn authority(&self) -> &Authority<N> {
+        &self.authority
+    }
+
+    /// Returns the ratifications in this block.
+    pub const fn ratifications(&self) -> &Ratifications<N> {
+        &self.ratifications
+    }
+
+    /// Returns the height of this block.
+    pub const fn height(&self) -> u32 {
+        self.header.height()
+    }
+
+    /// Returns the solutions in this block, decoding them on first use.
+    pub fn solutions(&self) -> Result<Option<&CoinbaseSolution<N>>> {
+        Ok(self.decode_solutions()?.0.as_ref())
+    }
+
+    /// Returns the transactions in this block, decoding them on first use.
+    pub fn transactions(&self) -> Result<&Transactions<N>> {
+        Ok(&self.decode_transactions()?.0)
+    }
+
+    /// Returns the aborted transaction IDs in this block, decoding them on first use.
+    pub fn aborted_transaction_ids(&self) -> Result<&Vec<N::TransactionID>> {
+        Ok(&self.decode_transactions()?.1)
+    }
+
+    /// Decodes the remaining sections, and returns the fully-validated block.
+    pub fn into_block(self) -> Result<Block<N>> {
+        // Decode the remaining sections.
+        self.decode_transactions()?;
+        // Retrieve the decoded sections.
+        let (solutions, _) = self.solutions.into_inner().ok_or_else(|| anyhow!("Missing solutions"))?;
+        let (transactions, aborted_transaction_ids) =
+            self.transactions.into_inner().ok_or_else(|| anyhow!("Missing transactions"))?;
+
+        // Construct the block.
+        let block = Block::from(
+            self.previous_hash,
+            self.header,
+            self.authority,
+            self.ratifications,
+            solutions,
+            transactions,
+            aborted_transaction_ids,
+        )?;
+
+        // Ensure the block hash matches.
+        ensure!(self.block_hash == block.hash(), "Mismatching block hash, possible data corruption");
+        Ok(block)
+    }
+}
+
+impl<N: Network> LazyBlock<N> {
+    /// Decodes the solutions section, returning the solutions and the byte offset of the transactions section.
+    fn decode_solutions(&self) -> Result<&(Option<CoinbaseSolution<N>>, usize)> {
+        if let Some(solutions) = self.solutions.get() {
+            return Ok(solutions);
+        }
+
+        let mut reader = &self.bytes[self.solutions_offset..];
+        // Read the solutions.
+        let solutions_variant = u8::read_le(&mut reader)?;
+        let solutions = match solutions_variant {
+            0 => None,
+            1 => Some(FromBytes::read_le(&mut reader)?),
+            _ => bail!("Invalid solutions variant in the block"),
+        };
+        // Compute the byte offset of the transactions section.
+        let transactions_offset = self.bytes.len() - reader.len();
+
+        // Note: If another thread decoded the solutions first, its result is retained.
+        let _ = self.solutions.set((solutions, transactions_offset));
+        self.solutions.get().ok_or_else(|| anyhow!("Failed to decode the solutions"))
+    }
+
+    /// Decodes the transactions section, returning the transactions and the aborted transaction IDs.
+    fn decode_transactions(&self) -> Result<&(Transactions<N>, Vec<N::TransactionID>)> {
+        if let Some(transactions) = self.transactions.get() {
+            return Ok(transactions);
+        }
+
+        // Retrieve the byte offset of the transactions section.
+        let (_, transactions_offset) = self.decode_solutions()?;
+
+        let mut reader = &self.bytes[*transactions_offset..];
+        // Read the transactions.
+        let transactions = Transactions::read_le(&mut reader)?;
+
+        // Read the number of aborted transaction IDs.
+        let num_aborted = u32::read_le(&mut reader)?;
+        // Ensure the number of aborted transaction IDs is within bounds (this is an early safety check).
+        ensure!(
+            num_aborted as usize <= Transactions::<N>::MAX_TRANSACTIONS,
+            "Invalid number of aborted transaction IDs in the block"
+        );
+        // Read the aborted transaction IDs.
+        let mut aborted_transaction_ids = Vec::with_capacity(num_aborted as usize);
+        for _ in 0..num_aborted {
+            aborted_transaction_ids.push(FromBytes::read_le(&mut reader)?);
+        }
+
+        // Note: If another thread decoded the transactions first, its result is retained.
+        let _ = self.transactions.set((transactions, aborted_transaction_ids));
+        self.transactions.get().ok_or_else(|| anyhow!("Failed to decode the transactions"))
+    }
+}
+
+impl<N: Network> FromBytes for LazyBlock<N> {
+    /// Reads the lazy block from the buffer, retaining the remaining bytes of the reader.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the serialized block.
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        // Initialize the lazy block.
+        Self::from_buffer(bytes).map_err(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_lazy_block() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a block.
+        let expected = crate::test_helpers::sample_genesis_block(rng);
+        let expected_bytes = expected.to_bytes_le()?;
+
+        // Lazily decode the block.
+        let lazy = LazyBlock::<CurrentNetwork>::read_le(&expected_bytes[..])?;
+        // Ensure the header fields match the eager block.
+        assert_eq!(lazy.hash(), expected.hash());
+        assert_eq!(lazy.previous_hash(), expected.previous_hash());
+        assert_eq!(lazy.header(), expected.header());
+        assert_eq!(lazy.authority(), expected.authority());
+        assert_eq!(lazy.ratifications(), expected.ratifications());
+        assert_eq!(lazy.height(), expected.height());
+
+        // Ensure the lazily-decoded sections match the eager block.
+        assert_eq!(lazy.solutions()?, expected.solutions());
+        assert_eq!(lazy.transactions()?, expected.transactions());
+        assert_eq!(lazy.aborted_transaction_ids()?, expected.aborted_transaction_ids());
+        assert_eq!(lazy.into_block()?, expected);
+
+        // Ensure an invalid version is rejected eagerly.
+        assert!(LazyBlock::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_block_corrupted_transactions() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a block.
+        let block = crate::test_helpers::sample_genesis_block(rng);
+        let mut bytes = block.to_bytes_le()?;
+
+        // Corrupt the version of the transactions section.
+        let aborted_size = 4 + block.aborted_transaction_ids().to_bytes_le()?.len();
+        let transactions_offset = bytes.len() - aborted_size - block.transactions().to_bytes_le()?.len();
+        bytes[transactions_offset] = u8::MAX;
+
+        // Ensure the eager block rejects the corrupted bytes.
+        assert!(Block::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+
+        // Ensure the lazy block only detects the corruption when the transactions are accessed.
+        let lazy = LazyBlock::<CurrentNetwork>::from_buffer(bytes)?;
+        assert_eq!(lazy.header(), block.header());
+        assert_eq!(lazy.solutions()?, block.solutions());
+        assert!(lazy.transactions().is_err());
+        assert!(lazy.aborted_transaction_ids().is_err());
+        assert!(lazy.into_block().is_err());
+        Ok(())
+    }
+}
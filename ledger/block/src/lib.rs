@@ -20,6 +20,9 @@
 pub mod header;
 pub use header::*;
 
+mod lazy;
+pub use lazy::*;
+
 mod helpers;
 pub use helpers::*;
 
@@ -42,16 +45,18 @@ mod bytes;
 mod genesis;
 mod serialize;
 mod string;
+
 mod verify;
+pub use verify::*;
 
 use console::{
     account::PrivateKey,
     network::prelude::*,
-    program::{Ciphertext, Record},
+    program::{Ciphertext, HeaderPath, Record},
     types::{Field, Group, U64},
 };
 use ledger_authority::Authority;
-use ledger_coinbase::{CoinbaseSolution, ProverSolution, PuzzleCommitment};
+use ledger_coinbase::{CoinbaseSolution, ProverSolution, PuzzleCommitment, SolutionDetail};
 use ledger_committee::Committee;
 use ledger_narwhal_subdag::Subdag;
 use ledger_narwhal_transmission_id::TransmissionID;
@@ -148,6 +153,17 @@ impl<N: Network> Block<N> {
                 // Ensure the transmission IDs from the subdag correspond to the block.
                 Self::check_subdag_transmissions(subdag, &solutions, &transactions, &aborted_transaction_ids)?;
             }
+            Authority::GenesisQuorum(committee) => {
+                // Ensure the genesis quorum authority is only used for the genesis block.
+                ensure!(header.height() == 0, "Genesis quorum authority is invalid for block {}", header.height());
+                // Ensure the committee matches the genesis ratification.
+                ensure!(
+                    ratifications
+                        .iter()
+                        .any(|ratify| matches!(ratify, Ratify::Genesis(genesis, ..) if genesis == committee)),
+                    "The genesis quorum authority does not match the genesis committee"
+                );
+            }
         }
 
         // Ensure that coinbase accumulator matches the solutions.
@@ -161,7 +177,7 @@ impl<N: Network> Block<N> {
 
         // Ensure that the subdag root matches the authority.
         let subdag_root = match &authority {
-            Authority::Beacon(_) => Field::<N>::zero(),
+            Authority::Beacon(_) | Authority::GenesisQuorum(_) => Field::<N>::zero(),
             Authority::Quorum(subdag) => subdag.to_subdag_root()?,
         };
         if header.subdag_root() != subdag_root {
@@ -233,6 +249,37 @@ impl<N: Network> Block<N> {
         self.solutions.as_ref()
     }
 
+    /// Returns the details of the solutions in this block, with the proving rewards attributed
+    /// from the puzzle reward ratification. If the block has no solutions, this returns an empty list.
+    pub fn solution_details(&self) -> Result<Vec<SolutionDetail<N>>> {
+        // Retrieve the solutions.
+        let Some(solutions) = &self.solutions else {
+            return Ok(Vec::new());
+        };
+        // Retrieve the puzzle reward.
+        let puzzle_reward = self.ratifications.iter().find_map(|ratify| match ratify {
+            Ratify::PuzzleReward(puzzle_reward) => Some(*puzzle_reward),
+            _ => None,
+        });
+        // Retrieve the details of the solutions.
+        let details = solutions.solution_details()?;
+        // If the puzzle reward was not ratified, return the details without the proving rewards.
+        let Some(puzzle_reward) = puzzle_reward else {
+            return Ok(details.to_vec());
+        };
+        // Compute the combined proof target.
+        let combined_proof_target = solutions.to_combined_proof_target()?;
+        // Attribute the proving reward to each solution, as it is paid out in finalize.
+        // Note: A solution whose proving reward exceeds the safety bound is skipped, and attributed a reward of 0.
+        Ok(details
+            .iter()
+            .map(|detail| {
+                let reward = proving_reward(puzzle_reward, detail.target(), combined_proof_target).unwrap_or(0);
+                SolutionDetail::new(detail.commitment(), detail.address(), detail.target(), Some(reward))
+            })
+            .collect())
+    }
+
     /// Returns the transactions in this block.
     pub const fn transactions(&self) -> &Transactions<N> {
         &self.transactions
@@ -242,6 +289,12 @@ impl<N: Network> Block<N> {
     pub const fn aborted_transaction_ids(&self) -> &Vec<N::TransactionID> {
         &self.aborted_transaction_ids
     }
+
+    /// Returns the number of finalize operations from the transactions in this block.
+    /// Note: This excludes the ratified finalize operations, which are not stored in the block.
+    pub fn num_finalize_operations(&self) -> usize {
+        self.transactions.num_finalize()
+    }
 }
 
 impl<N: Network> Block<N> {
@@ -336,6 +389,22 @@ impl<N: Network> Block<N> {
     }
 }
 
+impl<N: Network> Block<N> {
+    /// Returns the Merkle path for the transactions root in the block header,
+    /// along with the previous block hash and the block header root.
+    /// The block hash is the BHP-1024 hash of the previous block hash and the block header root.
+    pub fn prove_transactions_root(&self) -> Result<(HeaderPath<N>, N::BlockHash, Field<N>)> {
+        // Compute the Merkle tree of the block header.
+        let tree = self.header.to_tree()?;
+        // Retrieve the Merkle leaf for the transactions root.
+        let leaf = self.header.to_leaf_for_index(1)?;
+        // Compute the Merkle path.
+        let path = tree.prove(leaf.index() as usize, &leaf.to_bits_le())?;
+        // Return the Merkle path, previous block hash, and block header root.
+        Ok((path, self.previous_hash, *tree.root()))
+    }
+}
+
 impl<N: Network> Block<N> {
     /// Returns `true` if the block contains the given transition ID.
     pub fn contains_transition(&self, transition_id: &N::TransitionID) -> bool {
@@ -651,6 +720,29 @@ mod tests {
 
     use indexmap::IndexMap;
 
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_prove_transactions_root() {
+        let rng = &mut TestRng::default();
+
+        let (block, _) = crate::test_helpers::sample_genesis_block_and_transaction(rng);
+
+        // Prove the transactions root.
+        let (path, previous_hash, header_root) = block.prove_transactions_root().unwrap();
+        // Ensure the path is valid for the transactions root.
+        let leaf = console::program::HeaderLeaf::new(1, block.transactions_root());
+        assert!(Header::verify_path(&path, &header_root, &leaf));
+        // Ensure the previous block hash and header root hash to the block hash.
+        let candidate_hash = CurrentNetwork::hash_bhp1024(&to_bits_le![previous_hash, header_root]).unwrap();
+        assert_eq!(candidate_hash, *block.hash());
+        // Ensure the path is rejected for a different transactions root.
+        let leaf = console::program::HeaderLeaf::new(1, Field::rand(rng));
+        assert!(!Header::verify_path(&path, &header_root, &leaf));
+        // Ensure the path round-trips through serialization.
+        assert_eq!(path, serde_json::from_str(&serde_json::to_string(&path).unwrap()).unwrap());
+    }
+
     #[test]
     fn test_find_transaction_for_transition_id() {
         let rng = &mut TestRng::default();
@@ -676,6 +768,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_constants_summary_checklist() {
+        /// Returns the names of the network constants referenced as `N::CONSTANT` in the given source.
+        fn referenced_constants(source: &str) -> Vec<&str> {
+            source
+                .split("N::")
+                .skip(1)
+                .filter_map(|rest| {
+                    let end = rest.find(|c: char| !(c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_'));
+                    let name = &rest[..end.unwrap_or(rest.len())];
+                    let is_constant =
+                        name.len() > 1 && !rest[name.len()..].starts_with(|c: char| c.is_ascii_lowercase());
+                    is_constant.then_some(name)
+                })
+                .collect()
+        }
+
+        // The modules containing the target, reward, and limit logic of the ledger.
+        let sources =
+            [include_str!("helpers/target.rs"), include_str!("transactions/mod.rs"), include_str!("verify.rs")];
+
+        // Ensure every referenced constant is listed in the constants summary.
+        let summary = CurrentNetwork::constants_summary();
+        let constants = sources.iter().flat_map(|source| referenced_constants(source)).collect::<Vec<_>>();
+        assert!(!constants.is_empty());
+        for constant in constants {
+            assert!(summary.get(constant).is_some(), "Add '{constant}' to the network constants summary");
+        }
+    }
+
+    #[test]
+    fn test_solution_details_without_solutions() {
+        let rng = &mut TestRng::default();
+
+        // Ensure a block without solutions returns no solution details.
+        let block = crate::test_helpers::sample_genesis_block(rng);
+        assert!(block.solutions().is_none());
+        assert!(block.solution_details().unwrap().is_empty());
+    }
+
     #[test]
     fn test_find_transaction_for_commitment() {
         let rng = &mut TestRng::default();
@@ -30,13 +30,11 @@ impl<N: Network> Ratifications<N> {
 
     /// The Merkle tree of ratification IDs for the block.
     pub fn to_tree(&self) -> Result<RatificationsTree<N>> {
-        Self::ratifications_tree(self.ratifications.keys())
+        Self::ratifications_tree(&self.ratifications)
     }
 
     /// Returns the Merkle tree for the given ratifications.
-    fn ratifications_tree<'a>(
-        ratifications: impl ExactSizeIterator<Item = &'a N::RatificationID>,
-    ) -> Result<RatificationsTree<N>> {
+    fn ratifications_tree(ratifications: &IndexMap<N::RatificationID, Ratify<N>>) -> Result<RatificationsTree<N>> {
         // Ensure the number of ratifications is within the allowed range.
         ensure!(
             ratifications.len() <= Self::MAX_RATIFICATIONS,
@@ -45,9 +43,9 @@ impl<N: Network> Ratifications<N> {
             ratifications.len()
         );
         // Prepare the leaves.
-        let leaves = ratifications.map(|id| id.to_bits_le());
+        let leaves = cfg_keys!(ratifications).map(|id| id.to_bits_le()).collect::<Vec<_>>();
         // Compute the ratifications tree.
-        N::merkle_tree_bhp::<RATIFICATIONS_DEPTH>(&leaves.collect::<Vec<_>>())
+        N::merkle_tree_bhp::<RATIFICATIONS_DEPTH>(&leaves)
     }
 }
 
@@ -63,4 +61,25 @@ mod tests {
         // Ensure the log2 relationship between depth and the maximum number of ratifications.
         assert_eq!(2usize.pow(RATIFICATIONS_DEPTH as u32), Ratifications::<CurrentNetwork>::MAX_RATIFICATIONS);
     }
+
+    #[test]
+    fn test_ratifications_root_matches_sequential_computation() {
+        let rng = &mut TestRng::default();
+
+        for num_ratifications in [0, 1, 2, 7, 64] {
+            // Sample the ratifications.
+            let ratifications = Ratifications::<CurrentNetwork>::try_from_iter(
+                (0..num_ratifications).map(|_| Ratify::BlockReward(rng.gen())),
+            )
+            .unwrap();
+            assert_eq!(ratifications.len(), num_ratifications);
+
+            // Compute the expected root, by hashing the leaves sequentially.
+            let leaves = ratifications.ratification_ids().map(|id| id.to_bits_le()).collect::<Vec<_>>();
+            let expected_root = *CurrentNetwork::merkle_tree_bhp::<RATIFICATIONS_DEPTH>(&leaves).unwrap().root();
+
+            // Ensure the ratifications root matches.
+            assert_eq!(ratifications.to_ratifications_root().unwrap(), expected_root);
+        }
+    }
 }
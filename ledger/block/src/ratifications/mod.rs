@@ -37,12 +37,12 @@ pub struct Ratifications<N: Network> {
 impl<N: Network> Ratifications<N> {
     /// Initializes from an iterator of ratifications.
     pub fn try_from_iter<T: IntoIterator<Item = Ratify<N>>>(iter: T) -> Result<Self> {
-        Ok(Self {
-            ratifications: iter
-                .into_iter()
-                .map(|ratification| Ok::<_, Error>((ratification.to_id()?, ratification)))
-                .collect::<Result<IndexMap<_, _>, _>>()?,
-        })
+        // Collect the ratifications.
+        let ratifications = iter.into_iter().collect::<Vec<_>>();
+        // Compute the ratification IDs.
+        let ratification_ids = cfg_iter!(ratifications).map(Ratify::to_id).collect::<Result<Vec<_>>>()?;
+        // Return the ratifications.
+        Ok(Self { ratifications: ratification_ids.into_iter().zip_eq(ratifications).collect() })
     }
 }
 
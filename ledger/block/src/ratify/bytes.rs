@@ -20,7 +20,8 @@ impl<N: Network> FromBytes for Ratify<N> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        // Note: Version 2 is only used for a genesis ratification with bonded balances.
+        if version != 1 && version != 2 {
             return Err(error("Invalid ratify version"));
         }
 
@@ -38,12 +39,40 @@ impl<N: Network> FromBytes for Ratify<N> {
                     let address: Address<N> = FromBytes::read_le(&mut reader)?;
                     // Read the amount.
                     let amount: u64 = FromBytes::read_le(&mut reader)?;
-                    // Insert the public balance.
-                    public_balances.insert(address, amount);
+                    // Insert the public balance, ensuring the address is unique.
+                    if public_balances.insert(address, amount).is_some() {
+                        return Err(error(format!("Duplicate address '{address}' in the public balances")));
+                    }
                 }
+                // Read the bonded balances.
+                let mut bonded_balances = BondedBalances::new();
+                if version == 2 {
+                    // Read the number of bonded balances.
+                    let num_bonded_balances: u16 = FromBytes::read_le(&mut reader)?;
+                    // Ensure the bonded balances are not empty, as they would be written with version 1.
+                    if num_bonded_balances == 0 {
+                        return Err(error("Invalid ratify version for empty bonded balances"));
+                    }
+                    bonded_balances.reserve(num_bonded_balances as usize);
+                    for _ in 0..num_bonded_balances {
+                        // Read the staker.
+                        let staker: Address<N> = FromBytes::read_le(&mut reader)?;
+                        // Read the validator.
+                        let validator: Address<N> = FromBytes::read_le(&mut reader)?;
+                        // Read the amount.
+                        let amount: u64 = FromBytes::read_le(&mut reader)?;
+                        // Insert the bonded balance, ensuring the staker is unique.
+                        if bonded_balances.insert(staker, (validator, amount)).is_some() {
+                            return Err(error(format!("Duplicate address '{staker}' in the bonded balances")));
+                        }
+                    }
+                }
+                // Ensure the bonded balances are valid for the committee.
+                Self::check_bonded_balances(&committee, &bonded_balances).map_err(|e| error(e.to_string()))?;
                 // Return the ratify object.
-                Self::Genesis(committee, public_balances)
+                Self::Genesis(committee, public_balances, bonded_balances)
             }
+            1 | 2 if version != 1 => return Err(error("Invalid ratify version for a reward")),
             1 => {
                 // Read the amount.
                 let amount: u64 = FromBytes::read_le(&mut reader)?;
@@ -65,11 +94,13 @@ impl<N: Network> FromBytes for Ratify<N> {
 impl<N: Network> ToBytes for Ratify<N> {
     /// Writes the ratify object to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        // Write the version.
-        1u8.write_le(&mut writer)?;
-
         match self {
-            Self::Genesis(committee, public_balances) => {
+            Self::Genesis(committee, public_balances, bonded_balances) => {
+                // Write the version.
+                match bonded_balances.is_empty() {
+                    true => 1u8.write_le(&mut writer)?,
+                    false => 2u8.write_le(&mut writer)?,
+                }
                 (0 as Variant).write_le(&mut writer)?;
                 committee.write_le(&mut writer)?;
                 u16::try_from(public_balances.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
@@ -77,13 +108,25 @@ impl<N: Network> ToBytes for Ratify<N> {
                     address.write_le(&mut writer)?;
                     amount.write_le(&mut writer)?;
                 }
+                if !bonded_balances.is_empty() {
+                    u16::try_from(bonded_balances.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+                    for (staker, (validator, amount)) in bonded_balances {
+                        staker.write_le(&mut writer)?;
+                        validator.write_le(&mut writer)?;
+                        amount.write_le(&mut writer)?;
+                    }
+                }
                 Ok(())
             }
             Self::BlockReward(amount) => {
+                // Write the version.
+                1u8.write_le(&mut writer)?;
                 (1 as Variant).write_le(&mut writer)?;
                 amount.write_le(&mut writer)
             }
             Self::PuzzleReward(amount) => {
+                // Write the version.
+                1u8.write_le(&mut writer)?;
                 (2 as Variant).write_le(&mut writer)?;
                 amount.write_le(&mut writer)
             }
@@ -109,4 +152,104 @@ mod tests {
             assert!(Ratify::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
         }
     }
+
+    #[test]
+    fn test_bytes_large_balances() {
+        let rng = &mut TestRng::default();
+
+        // Sample a genesis ratification with large balance maps.
+        let committee = ledger_committee::test_helpers::sample_committee_for_round(0, rng);
+        let expected = crate::ratify::test_helpers::sample_genesis_ratification(committee, 10_000, rng);
+        assert_eq!(expected.as_balances().unwrap().0.len(), 10_000);
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        assert_eq!(expected, Ratify::read_le(&expected_bytes[..]).unwrap());
+    }
+
+    /// Samples a genesis committee at round 0, where every validator is open to stakers.
+    fn sample_open_committee(rng: &mut TestRng) -> Committee<CurrentNetwork> {
+        let members = (0..4).map(|_| (Address::new(rng.gen()), (2 * MIN_VALIDATOR_STAKE, true))).collect();
+        Committee::new(0, members).unwrap()
+    }
+
+    #[test]
+    fn test_bytes_rejects_duplicate_balances() {
+        let rng = &mut TestRng::default();
+
+        let committee = sample_open_committee(rng);
+        let validator = *committee.members().keys().next().unwrap();
+        let address = Address::<CurrentNetwork>::new(rng.gen());
+
+        // A helper to write a genesis ratification, with the same address repeated in the given balances.
+        let write = |num_public: u16, num_bonded: u16| {
+            let mut bytes = vec![if num_bonded == 0 { 1u8 } else { 2u8 }, 0u8];
+            committee.write_le(&mut bytes).unwrap();
+            num_public.write_le(&mut bytes).unwrap();
+            for _ in 0..num_public {
+                address.write_le(&mut bytes).unwrap();
+                1u64.write_le(&mut bytes).unwrap();
+            }
+            if num_bonded > 0 {
+                num_bonded.write_le(&mut bytes).unwrap();
+                for _ in 0..num_bonded {
+                    address.write_le(&mut bytes).unwrap();
+                    validator.write_le(&mut bytes).unwrap();
+                    MIN_DELEGATOR_STAKE.write_le(&mut bytes).unwrap();
+                }
+            }
+            bytes
+        };
+
+        // Ensure unique addresses are accepted.
+        assert!(Ratify::<CurrentNetwork>::read_le(&write(1, 1)[..]).is_ok());
+        // Ensure duplicate addresses are rejected.
+        assert!(Ratify::<CurrentNetwork>::read_le(&write(2, 0)[..]).is_err());
+        assert!(Ratify::<CurrentNetwork>::read_le(&write(1, 2)[..]).is_err());
+    }
+
+    #[test]
+    fn test_bytes_rejects_invalid_bonded_balances() {
+        let rng = &mut TestRng::default();
+
+        let mut members = sample_open_committee(rng).members().clone();
+        // Close the last validator to stakers.
+        members.last_mut().unwrap().1.1 = false;
+        let committee = Committee::<CurrentNetwork>::new(0, members).unwrap();
+        let open_validator = *committee.members().keys().next().unwrap();
+        let closed_validator = *committee.members().keys().last().unwrap();
+        let staker = Address::<CurrentNetwork>::new(rng.gen());
+
+        // A helper to write a genesis ratification with a single bonded balance.
+        let write = |staker: Address<CurrentNetwork>, validator: Address<CurrentNetwork>, amount: u64| {
+            let mut bytes = vec![2u8, 0u8];
+            committee.write_le(&mut bytes).unwrap();
+            0u16.write_le(&mut bytes).unwrap();
+            1u16.write_le(&mut bytes).unwrap();
+            staker.write_le(&mut bytes).unwrap();
+            validator.write_le(&mut bytes).unwrap();
+            amount.write_le(&mut bytes).unwrap();
+            bytes
+        };
+
+        // Ensure a valid bonded balance is accepted.
+        assert!(Ratify::<CurrentNetwork>::read_le(&write(staker, open_validator, MIN_DELEGATOR_STAKE)[..]).is_ok());
+        // Ensure an amount below the minimum delegator stake is rejected.
+        assert!(
+            Ratify::<CurrentNetwork>::read_le(&write(staker, open_validator, MIN_DELEGATOR_STAKE - 1)[..]).is_err()
+        );
+        // Ensure bonding to a closed validator is rejected.
+        assert!(Ratify::<CurrentNetwork>::read_le(&write(staker, closed_validator, MIN_DELEGATOR_STAKE)[..]).is_err());
+        // Ensure bonding to an address outside the committee is rejected.
+        assert!(Ratify::<CurrentNetwork>::read_le(&write(staker, staker, MIN_DELEGATOR_STAKE)[..]).is_err());
+        // Ensure a validator cannot be a staker.
+        assert!(
+            Ratify::<CurrentNetwork>::read_le(&write(closed_validator, open_validator, MIN_DELEGATOR_STAKE)[..])
+                .is_err()
+        );
+        // Ensure the validator keeps at least the minimum validator stake as its self-bond.
+        assert!(
+            Ratify::<CurrentNetwork>::read_le(&write(staker, open_validator, MIN_VALIDATOR_STAKE + 1)[..]).is_err()
+        );
+    }
 }
@@ -17,18 +17,21 @@ mod serialize;
 mod string;
 
 use console::{network::prelude::*, types::Address};
-use ledger_committee::Committee;
+use ledger_committee::{Committee, MIN_DELEGATOR_STAKE, MIN_VALIDATOR_STAKE};
 
 use indexmap::IndexMap;
 
 type Variant = u8;
 /// A helper type to represent the public balances.
-type PublicBalances<N> = IndexMap<Address<N>, u64>;
+pub type PublicBalances<N> = IndexMap<Address<N>, u64>;
+/// A helper type to represent the bonded balances, as a map of `staker => (validator, amount)`.
+pub type BondedBalances<N> = IndexMap<Address<N>, (Address<N>, u64)>;
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, PartialEq, Eq)]
 pub enum Ratify<N: Network> {
     /// The genesis.
-    Genesis(Committee<N>, PublicBalances<N>),
+    Genesis(Committee<N>, PublicBalances<N>, BondedBalances<N>),
     /// The block reward.
     BlockReward(u64),
     /// The puzzle reward.
@@ -36,6 +39,87 @@ pub enum Ratify<N: Network> {
 }
 
 impl<N: Network> Ratify<N> {
+    /// Initializes a new genesis ratification.
+    ///
+    /// The bonded balances delegate stake to the open validators in the committee.
+    /// The committee stake of each validator includes its delegated stake, and the remainder is self-bonded.
+    pub fn genesis(
+        committee: Committee<N>,
+        public_balances: PublicBalances<N>,
+        bonded_balances: BondedBalances<N>,
+    ) -> Result<Self> {
+        // Ensure the bonded balances are valid for the committee.
+        Self::check_bonded_balances(&committee, &bonded_balances)?;
+        Ok(Self::Genesis(committee, public_balances, bonded_balances))
+    }
+
+    /// Ensures the given genesis committee and bonded balances are valid, i.e. the committee starts at round 0,
+    /// each staker is not a validator and bonds at least `MIN_DELEGATOR_STAKE` to an open validator in the committee,
+    /// and each validator self-bonds at least `MIN_VALIDATOR_STAKE` after the stake delegated to it.
+    pub fn check_bonded_balances(committee: &Committee<N>, bonded_balances: &BondedBalances<N>) -> Result<()> {
+        // Ensure the genesis committee starts at round 0.
+        ensure!(committee.starting_round() == 0, "The genesis committee must start at round 0");
+
+        // Compute the delegated stake for each validator.
+        let mut delegated = IndexMap::<Address<N>, u64>::with_capacity(committee.members().len());
+        for (staker, (validator, amount)) in bonded_balances {
+            // Ensure the staker is not a validator, as validators are bonded to themselves.
+            ensure!(!committee.is_committee_member(*staker), "The staker '{staker}' is a validator in the committee");
+            // Ensure the validator is in the committee.
+            ensure!(committee.is_committee_member(*validator), "The validator '{validator}' is not in the committee");
+            // Ensure the validator is open to stakers.
+            ensure!(
+                committee.is_committee_member_open(*validator),
+                "The validator '{validator}' is not open to stakers"
+            );
+            // Ensure the amount meets the minimum delegator stake.
+            ensure!(
+                *amount >= MIN_DELEGATOR_STAKE,
+                "The bonded amount for staker '{staker}' is below the minimum of {MIN_DELEGATOR_STAKE} microcredits"
+            );
+            // Update the delegated stake.
+            let entry = delegated.entry(*validator).or_default();
+            *entry = entry.checked_add(*amount).ok_or_else(|| anyhow!("The delegated stake overflowed"))?;
+        }
+
+        // Ensure each validator self-bonds at least the minimum validator stake.
+        for (validator, (stake, _)) in committee.members() {
+            let delegated = delegated.get(validator).copied().unwrap_or_default();
+            let self_bonded = stake.checked_sub(delegated).ok_or_else(|| {
+                anyhow!("The delegated stake for validator '{validator}' exceeds its committee stake")
+            })?;
+            ensure!(
+                self_bonded >= MIN_VALIDATOR_STAKE,
+                "The self-bonded stake of validator '{validator}' is below the minimum of {MIN_VALIDATOR_STAKE} microcredits"
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the committee, if this is a genesis ratification.
+    pub const fn as_committee(&self) -> Option<&Committee<N>> {
+        match self {
+            Self::Genesis(committee, ..) => Some(committee),
+            Self::BlockReward(..) | Self::PuzzleReward(..) => None,
+        }
+    }
+
+    /// Returns the public and bonded balances, if this is a genesis ratification.
+    pub const fn as_balances(&self) -> Option<(&PublicBalances<N>, &BondedBalances<N>)> {
+        match self {
+            Self::Genesis(_, public_balances, bonded_balances) => Some((public_balances, bonded_balances)),
+            Self::BlockReward(..) | Self::PuzzleReward(..) => None,
+        }
+    }
+
+    /// Returns the amount, if this is a block reward or puzzle reward ratification.
+    pub const fn amount(&self) -> Option<u64> {
+        match self {
+            Self::Genesis(..) => None,
+            Self::BlockReward(amount) | Self::PuzzleReward(amount) => Some(*amount),
+        }
+    }
+
     /// Returns the ratification ID.
     pub fn to_id(&self) -> Result<N::RatificationID> {
         Ok(N::hash_bhp1024(&self.to_bytes_le()?.to_bits_le())?.into())
@@ -50,16 +134,39 @@ pub(crate) mod test_helpers {
     type CurrentNetwork = Testnet3;
 
     pub(crate) fn sample_ratifications(rng: &mut TestRng) -> Vec<Ratify<CurrentNetwork>> {
-        let committee = ledger_committee::test_helpers::sample_committee(rng);
+        let committee = ledger_committee::test_helpers::sample_committee_for_round(0, rng);
         let mut public_balances = PublicBalances::new();
         for (address, _) in committee.members().iter() {
             public_balances.insert(*address, rng.gen());
         }
 
         vec![
-            Ratify::Genesis(committee, public_balances),
+            Ratify::genesis(committee.clone(), public_balances, BondedBalances::new()).unwrap(),
+            sample_genesis_ratification(committee, 10, rng),
             Ratify::BlockReward(rng.gen()),
             Ratify::PuzzleReward(rng.gen()),
         ]
     }
+
+    /// Samples a genesis ratification with the given number of public and bonded balances.
+    pub(crate) fn sample_genesis_ratification(
+        committee: Committee<CurrentNetwork>,
+        num_balances: usize,
+        rng: &mut TestRng,
+    ) -> Ratify<CurrentNetwork> {
+        // Select the open validators to bond to.
+        let validators: Vec<_> =
+            committee.members().iter().filter(|(_, (_, is_open))| *is_open).map(|(address, _)| *address).collect();
+
+        let mut public_balances = PublicBalances::new();
+        let mut bonded_balances = BondedBalances::new();
+        for i in 0..num_balances {
+            let address = Address::new(rng.gen());
+            public_balances.insert(address, rng.gen());
+            if !validators.is_empty() {
+                bonded_balances.insert(address, (validators[i % validators.len()], MIN_DELEGATOR_STAKE));
+            }
+        }
+        Ratify::genesis(committee, public_balances, bonded_balances).unwrap()
+    }
 }
@@ -14,16 +14,21 @@
 
 use super::*;
 
+use core::marker::PhantomData;
+
 impl<N: Network> Serialize for Ratify<N> {
     /// Serializes the ratify object into string or bytes.
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => match self {
-                Self::Genesis(committee, public_balances) => {
-                    let mut input = serializer.serialize_struct("Ratify", 3)?;
+                Self::Genesis(committee, public_balances, bonded_balances) => {
+                    let mut input = serializer.serialize_struct("Ratify", 3 + !bonded_balances.is_empty() as usize)?;
                     input.serialize_field("type", "genesis")?;
                     input.serialize_field("committee", &committee)?;
                     input.serialize_field("public_balances", &public_balances)?;
+                    if !bonded_balances.is_empty() {
+                        input.serialize_field("bonded_balances", &bonded_balances)?;
+                    }
                     input.end()
                 }
                 Self::BlockReward(amount) => {
@@ -49,33 +54,16 @@ impl<'de, N: Network> Deserialize<'de> for Ratify<N> {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         match deserializer.is_human_readable() {
             true => {
-                // Parse the input from a string into a value.
-                let mut object = serde_json::Value::deserialize(deserializer)?;
-
-                // Recover the ratify object.
-                let ratify = match object.get("type").and_then(|t| t.as_str()) {
-                    Some("genesis") => {
-                        // Retrieve the committee.
-                        let committee: Committee<N> = DeserializeExt::take_from_value::<D>(&mut object, "committee")?;
-                        // Retrieve the public balances.
-                        let public_balances: PublicBalances<N> =
-                            DeserializeExt::take_from_value::<D>(&mut object, "public_balances")?;
-                        // Construct the ratify object.
-                        Ratify::Genesis(committee, public_balances)
-                    }
-                    Some("block_reward") => {
-                        // Retrieve the amount.
-                        let amount: u64 = DeserializeExt::take_from_value::<D>(&mut object, "amount")?;
+                // Note: The ratify object is not parsed into a `serde_json::Value`, as it silently drops duplicate keys.
+                let ratify = match RatifyJson::<N>::deserialize(deserializer)? {
+                    RatifyJson::Genesis { committee, public_balances, bonded_balances } => {
+                        // Ensure the bonded balances are valid for the committee.
+                        Ratify::check_bonded_balances(&committee, &bonded_balances).map_err(de::Error::custom)?;
                         // Construct the ratify object.
-                        Ratify::BlockReward(amount)
+                        Ratify::Genesis(committee, public_balances, bonded_balances)
                     }
-                    Some("puzzle_reward") => {
-                        // Retrieve the amount.
-                        let amount: u64 = DeserializeExt::take_from_value::<D>(&mut object, "amount")?;
-                        // Construct the ratify object.
-                        Ratify::PuzzleReward(amount)
-                    }
-                    _ => return Err(de::Error::custom("Invalid ratify object type")),
+                    RatifyJson::BlockReward { amount } => Ratify::BlockReward(amount),
+                    RatifyJson::PuzzleReward { amount } => Ratify::PuzzleReward(amount),
                 };
                 // Return the ratify object.
                 Ok(ratify)
@@ -85,9 +73,60 @@ impl<'de, N: Network> Deserialize<'de> for Ratify<N> {
     }
 }
 
+/// The human-readable representation of a ratify object.
+#[allow(clippy::large_enum_variant)]
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", bound = "")]
+enum RatifyJson<N: Network> {
+    Genesis {
+        committee: Committee<N>,
+        #[serde(deserialize_with = "deserialize_unique_balances")]
+        public_balances: PublicBalances<N>,
+        #[serde(default, deserialize_with = "deserialize_unique_balances")]
+        bonded_balances: BondedBalances<N>,
+    },
+    BlockReward {
+        amount: u64,
+    },
+    PuzzleReward {
+        amount: u64,
+    },
+}
+
+/// Deserializes a map of balances, ensuring each address is unique.
+fn deserialize_unique_balances<'de, D: Deserializer<'de>, N: Network, V: Deserialize<'de>>(
+    deserializer: D,
+) -> Result<IndexMap<Address<N>, V>, D::Error> {
+    struct BalancesVisitor<N, V>(PhantomData<(N, V)>);
+
+    impl<'de, N: Network, V: Deserialize<'de>> Visitor<'de> for BalancesVisitor<N, V> {
+        type Value = IndexMap<Address<N>, V>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a map of balances with unique addresses")
+        }
+
+        fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut balances = IndexMap::with_capacity(map.size_hint().unwrap_or_default());
+            while let Some((address, balance)) = map.next_entry::<Address<N>, V>()? {
+                // Insert the balance, ensuring the address is unique.
+                if balances.insert(address, balance).is_some() {
+                    return Err(de::Error::custom(format!("Duplicate address '{address}' in the balances")));
+                }
+            }
+            Ok(balances)
+        }
+    }
+
+    deserializer.deserialize_map(BalancesVisitor(PhantomData))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
 
     fn check_serde_json<
         T: Serialize + for<'a> Deserialize<'a> + Debug + Display + PartialEq + Eq + FromStr + ToBytes + FromBytes,
@@ -139,4 +178,27 @@ mod tests {
             check_bincode(expected);
         }
     }
+
+    #[test]
+    fn test_serde_json_rejects_invalid_balances() {
+        let rng = &mut TestRng::default();
+
+        // Sample a genesis ratification, where every validator is open to stakers.
+        let members = (0..4).map(|_| (Address::new(rng.gen()), (2 * MIN_VALIDATOR_STAKE, true))).collect();
+        let committee = Committee::<CurrentNetwork>::new(0, members).unwrap();
+        let expected = crate::ratify::test_helpers::sample_genesis_ratification(committee, 2, rng);
+        let (public_balances, bonded_balances) = expected.as_balances().unwrap();
+        let expected_string = serde_json::to_string(&expected).unwrap();
+
+        // Ensure a duplicate address in the balances is rejected.
+        let (address, amount) = public_balances.first().unwrap();
+        let duplicate = format!("\"public_balances\":{{\"{address}\":{amount},");
+        let candidate = expected_string.replacen("\"public_balances\":{", &duplicate, 1);
+        assert!(serde_json::from_str::<Ratify<CurrentNetwork>>(&candidate).is_err());
+
+        // Ensure a bonded amount below the minimum delegator stake is rejected.
+        let (_, (_, amount)) = bonded_balances.first().unwrap();
+        let candidate = expected_string.replace(&format!(",{amount}]"), &format!(",{}]", amount - 1));
+        assert!(serde_json::from_str::<Ratify<CurrentNetwork>>(&candidate).is_err());
+    }
 }
@@ -18,6 +18,12 @@ mod bytes;
 mod serialize;
 mod string;
 
+mod cost;
+pub use cost::*;
+
+mod report;
+pub use report::*;
+
 use crate::Transaction;
 use console::{
     network::prelude::*,
@@ -104,6 +110,11 @@ impl<N: Network> Deployment<N> {
         Ok(u64::try_from(self.to_bytes_le()?.len())?)
     }
 
+    /// Returns the namespace cost in microcredits of the program ID, which is `10^(10 - num_characters)` credits.
+    pub fn namespace_cost(&self) -> Result<u64> {
+        cost::namespace_cost(self.program_id())
+    }
+
     /// Returns the edition.
     pub const fn edition(&self) -> u16 {
         self.edition
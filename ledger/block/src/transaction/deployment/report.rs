@@ -0,0 +1,260 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The size and cost of a single function in a deployment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionReport<N: Network> {
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The number of constraints in the function circuit.
+    num_constraints: u64,
+    /// The number of public variables in the function circuit (after padding).
+    num_public_variables: u64,
+    /// The number of private variables in the function circuit (after padding).
+    num_private_variables: u64,
+    /// The size in bytes of the verifying key.
+    verifying_key_size_in_bytes: u64,
+    /// The size in bytes of the certificate.
+    certificate_size_in_bytes: u64,
+    /// The storage cost in microcredits of the verifying key and certificate.
+    storage_cost: u64,
+}
+
+impl<N: Network> FunctionReport<N> {
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the number of constraints in the function circuit.
+    pub const fn num_constraints(&self) -> u64 {
+        self.num_constraints
+    }
+
+    /// Returns the number of public variables in the function circuit (after padding).
+    pub const fn num_public_variables(&self) -> u64 {
+        self.num_public_variables
+    }
+
+    /// Returns the number of private variables in the function circuit (after padding).
+    pub const fn num_private_variables(&self) -> u64 {
+        self.num_private_variables
+    }
+
+    /// Returns the size in bytes of the verifying key.
+    pub const fn verifying_key_size_in_bytes(&self) -> u64 {
+        self.verifying_key_size_in_bytes
+    }
+
+    /// Returns the size in bytes of the certificate.
+    pub const fn certificate_size_in_bytes(&self) -> u64 {
+        self.certificate_size_in_bytes
+    }
+
+    /// Returns the storage cost in microcredits of the verifying key and certificate.
+    pub const fn storage_cost(&self) -> u64 {
+        self.storage_cost
+    }
+}
+
+/// The size and cost of a deployment, broken down by function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeploymentReport<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The report for each function, in program order.
+    functions: Vec<FunctionReport<N>>,
+    /// The size in bytes of the deployment.
+    size_in_bytes: u64,
+    /// The storage cost in microcredits of the deployment.
+    storage_cost: u64,
+    /// The namespace cost in microcredits of the program ID.
+    namespace_cost: u64,
+}
+
+impl<N: Network> DeploymentReport<N> {
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the report for each function, in program order.
+    pub fn functions(&self) -> &[FunctionReport<N>] {
+        &self.functions
+    }
+
+    /// Returns the size in bytes of the deployment.
+    pub const fn size_in_bytes(&self) -> u64 {
+        self.size_in_bytes
+    }
+
+    /// Returns the storage cost in microcredits of the deployment.
+    ///
+    /// This includes the storage cost of each function, as well as the program and the deployment metadata.
+    pub const fn storage_cost(&self) -> u64 {
+        self.storage_cost
+    }
+
+    /// Returns the namespace cost in microcredits of the program ID.
+    pub const fn namespace_cost(&self) -> u64 {
+        self.namespace_cost
+    }
+
+    /// Returns the total number of constraints across all functions.
+    pub fn num_constraints(&self) -> u64 {
+        self.functions.iter().map(FunctionReport::num_constraints).sum()
+    }
+
+    /// Returns the total size in bytes of the verifying keys.
+    pub fn verifying_keys_size_in_bytes(&self) -> u64 {
+        self.functions.iter().map(FunctionReport::verifying_key_size_in_bytes).sum()
+    }
+
+    /// Returns the total storage cost in microcredits of the verifying keys and certificates.
+    pub fn functions_storage_cost(&self) -> u64 {
+        self.functions.iter().map(FunctionReport::storage_cost).sum()
+    }
+
+    /// Returns the total cost in microcredits of the deployment, which is the minimum deployment fee.
+    pub fn total_cost(&self) -> Result<u64> {
+        self.storage_cost
+            .checked_add(self.namespace_cost)
+            .ok_or(anyhow!("The total cost computation overflowed for a deployment"))
+    }
+}
+
+impl<N: Network> DeploymentReport<N> {
+    /// Returns the size and cost of deploying the given program with the given verifying keys, broken down by function.
+    ///
+    /// The certificates are not needed, as every certificate is `Certificate::SIZE_IN_BYTES` bytes.
+    pub fn new<'a>(
+        edition: u16,
+        program: &Program<N>,
+        verifying_keys: impl ExactSizeIterator<Item = (&'a Identifier<N>, &'a VerifyingKey<N>)>,
+    ) -> Result<Self> {
+        // Determine the number of bytes in a certificate.
+        let certificate_size_in_bytes = u64::try_from(Certificate::<N>::SIZE_IN_BYTES)?;
+
+        // Determine the number of bytes in the version, edition, program, and number of entries of the deployment.
+        let mut size_in_bytes = 1u8.to_bytes_le()?.len()
+            + edition.to_bytes_le()?.len()
+            + program.to_bytes_le()?.len()
+            + u16::try_from(verifying_keys.len())?.to_bytes_le()?.len();
+
+        // Construct the report for each function.
+        let functions = verifying_keys
+            .map(|(function_name, verifying_key)| {
+                // Retrieve the circuit information.
+                let circuit_info = &verifying_key.circuit_info;
+                // Determine the number of bytes in the verifying key.
+                let verifying_key_size_in_bytes = verifying_key.to_bytes_le()?.len();
+                // Add the function name, verifying key, and certificate to the size of the deployment.
+                size_in_bytes +=
+                    function_name.to_bytes_le()?.len() + verifying_key_size_in_bytes + Certificate::<N>::SIZE_IN_BYTES;
+
+                let verifying_key_size_in_bytes = u64::try_from(verifying_key_size_in_bytes)?;
+                Ok(FunctionReport {
+                    function_name: *function_name,
+                    num_constraints: u64::try_from(circuit_info.num_constraints)?,
+                    num_public_variables: u64::try_from(circuit_info.num_public_inputs)?,
+                    num_private_variables: u64::try_from(
+                        circuit_info.num_variables.saturating_sub(circuit_info.num_public_inputs),
+                    )?,
+                    verifying_key_size_in_bytes,
+                    certificate_size_in_bytes,
+                    storage_cost: storage_cost::<N>(
+                        verifying_key_size_in_bytes.saturating_add(certificate_size_in_bytes),
+                    )?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Compute the cost of the deployment.
+        let size_in_bytes = u64::try_from(size_in_bytes)?;
+        let (_, (storage_cost, namespace_cost)) = deployment_cost_from_size(program.id(), size_in_bytes)?;
+
+        Ok(Self { program_id: *program.id(), functions, size_in_bytes, storage_cost, namespace_cost })
+    }
+}
+
+impl<N: Network> Deployment<N> {
+    /// Returns the size and cost of the deployment, broken down by function.
+    pub fn report(&self) -> Result<DeploymentReport<N>> {
+        DeploymentReport::new(
+            self.edition,
+            &self.program,
+            self.verifying_keys.iter().map(|(function_name, (verifying_key, _))| (function_name, verifying_key)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_report() {
+        let rng = &mut TestRng::default();
+
+        // Sample the deployment.
+        let deployment = test_helpers::sample_deployment(rng);
+        // Compute the report.
+        let report = deployment.report().unwrap();
+
+        assert_eq!(report.program_id(), deployment.program_id());
+        assert_eq!(report.functions().len(), deployment.verifying_keys().len());
+        assert_eq!(report.size_in_bytes(), deployment.size_in_bytes().unwrap());
+
+        // Ensure each function report matches its verifying key and certificate.
+        for (function, (function_name, (verifying_key, certificate))) in
+            report.functions().iter().zip_eq(deployment.verifying_keys())
+        {
+            assert_eq!(function.function_name(), function_name);
+            assert_eq!(function.num_constraints(), verifying_key.circuit_info.num_constraints as u64);
+            assert_eq!(
+                function.num_public_variables() + function.num_private_variables(),
+                verifying_key.circuit_info.num_variables as u64
+            );
+            assert_eq!(function.verifying_key_size_in_bytes(), verifying_key.to_bytes_le().unwrap().len() as u64);
+            assert_eq!(function.certificate_size_in_bytes(), certificate.to_bytes_le().unwrap().len() as u64);
+            assert_eq!(
+                function.storage_cost(),
+                (function.verifying_key_size_in_bytes() + function.certificate_size_in_bytes())
+                    * CurrentNetwork::DEPLOYMENT_FEE_MULTIPLIER
+            );
+        }
+
+        // Ensure the totals are the sum of the parts.
+        assert_eq!(report.num_constraints(), report.functions().iter().map(|f| f.num_constraints()).sum::<u64>());
+        assert_eq!(
+            report.verifying_keys_size_in_bytes(),
+            report.functions().iter().map(|f| f.verifying_key_size_in_bytes()).sum::<u64>()
+        );
+        assert_eq!(report.functions_storage_cost(), report.functions().iter().map(|f| f.storage_cost()).sum::<u64>());
+        assert!(report.functions_storage_cost() < report.storage_cost());
+        assert_eq!(report.storage_cost(), report.size_in_bytes() * CurrentNetwork::DEPLOYMENT_FEE_MULTIPLIER);
+        assert_eq!(report.total_cost().unwrap(), report.storage_cost() + report.namespace_cost());
+
+        // Ensure the report matches the deployment cost.
+        let (total_cost, (storage_cost, namespace_cost)) = deployment_cost(&deployment).unwrap();
+        assert_eq!(report.total_cost().unwrap(), total_cost);
+        assert_eq!(report.storage_cost(), storage_cost);
+        assert_eq!(report.namespace_cost(), namespace_cost);
+    }
+}
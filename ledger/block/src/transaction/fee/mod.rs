@@ -55,6 +55,11 @@ impl<N: Network> Fee<N> {
 }
 
 impl<N: Network> Fee<N> {
+    /// Returns the size in bytes.
+    pub fn size_in_bytes(&self) -> Result<u64> {
+        Ok(u64::try_from(self.to_bytes_le()?.len())?)
+    }
+
     /// Returns `true` if this is a `fee_private` transition.
     #[inline]
     pub fn is_fee_private(&self) -> bool {
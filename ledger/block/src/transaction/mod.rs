@@ -21,6 +21,9 @@ pub use execution::*;
 mod fee;
 pub use fee::*;
 
+mod summary;
+pub use summary::*;
+
 mod bytes;
 mod merkle;
 mod serialize;
@@ -28,7 +31,7 @@ mod string;
 
 use crate::Transition;
 use console::{
-    network::prelude::*,
+    network::{prelude::*, Bech32ID},
     program::{Ciphertext, ProgramOwner, Record, TransactionLeaf, TransactionPath, TransactionTree, TRANSACTION_DEPTH},
     types::{Field, Group, U64},
 };
@@ -182,6 +185,28 @@ impl<N: Network> Transaction<N> {
         }
     }
 
+    /// Returns the size of the transaction in bytes.
+    pub fn size_in_bytes(&self) -> Result<u64> {
+        // Compute the size of the version, variant, and ID.
+        let header_size = 2 + N::TransactionID::size_in_bytes() as u64;
+        // Compute the size of the transaction body.
+        let body_size = match self {
+            Self::Deploy(_, owner, deployment, fee) => {
+                u64::try_from(owner.to_bytes_le()?.len())? + deployment.size_in_bytes()? + fee.size_in_bytes()?
+            }
+            Self::Execute(_, execution, fee) => {
+                // Include the fee variant byte.
+                let fee_size = match fee {
+                    Some(fee) => 1 + fee.size_in_bytes()?,
+                    None => 1,
+                };
+                execution.size_in_bytes()? + fee_size
+            }
+            Self::Fee(_, fee) => fee.size_in_bytes()?,
+        };
+        Ok(header_size + body_size)
+    }
+
     /// Returns the transaction total fee.
     pub fn fee_amount(&self) -> Result<U64<N>> {
         match self {
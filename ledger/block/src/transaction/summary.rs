@@ -0,0 +1,174 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use console::program::ProgramID;
+
+use indexmap::IndexSet;
+
+/// A lightweight summary of a transaction, suitable for JSON APIs.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TransactionSummary<N: Network> {
+    /// The transaction ID.
+    id: N::TransactionID,
+    /// The transaction type.
+    variant: &'static str,
+    /// The size of the transaction in bytes.
+    size_in_bytes: u64,
+    /// The total fee, in microcredits.
+    fee: u64,
+    /// The base fee, in microcredits.
+    base_fee: u64,
+    /// The priority fee, in microcredits.
+    priority_fee: u64,
+    /// The number of transitions.
+    num_transitions: usize,
+    /// The program IDs involved in the transaction.
+    program_ids: Vec<ProgramID<N>>,
+}
+
+impl<N: Network> Transaction<N> {
+    /// Returns a summary of the transaction.
+    pub fn summary(&self) -> Result<TransactionSummary<N>> {
+        // Determine the transaction type.
+        let variant = match self {
+            Self::Deploy(..) => "deploy",
+            Self::Execute(..) => "execute",
+            Self::Fee(..) => "fee",
+        };
+        // Collect the unique program IDs, starting with the deployed program (if any).
+        let mut program_ids = IndexSet::new();
+        if let Some(deployment) = self.deployment() {
+            program_ids.insert(*deployment.program_id());
+        }
+        program_ids.extend(self.transitions().map(|transition| *transition.program_id()));
+
+        Ok(TransactionSummary {
+            id: self.id(),
+            variant,
+            size_in_bytes: self.size_in_bytes()?,
+            fee: *self.fee_amount()?,
+            base_fee: *self.base_fee_amount()?,
+            priority_fee: *self.priority_fee_amount()?,
+            num_transitions: self.transitions().count(),
+            program_ids: program_ids.into_iter().collect(),
+        })
+    }
+}
+
+impl<N: Network> TransactionSummary<N> {
+    /// Returns the transaction ID.
+    pub const fn id(&self) -> N::TransactionID {
+        self.id
+    }
+
+    /// Returns the transaction type, as one of `deploy`, `execute`, or `fee`.
+    pub const fn variant(&self) -> &'static str {
+        self.variant
+    }
+
+    /// Returns the size of the transaction in bytes.
+    pub const fn size_in_bytes(&self) -> u64 {
+        self.size_in_bytes
+    }
+
+    /// Returns the total fee, in microcredits.
+    pub const fn fee(&self) -> u64 {
+        self.fee
+    }
+
+    /// Returns the base fee, in microcredits.
+    pub const fn base_fee(&self) -> u64 {
+        self.base_fee
+    }
+
+    /// Returns the priority fee, in microcredits.
+    pub const fn priority_fee(&self) -> u64 {
+        self.priority_fee
+    }
+
+    /// Returns the number of transitions.
+    pub const fn num_transitions(&self) -> usize {
+        self.num_transitions
+    }
+
+    /// Returns the program IDs involved in the transaction.
+    pub fn program_ids(&self) -> &[ProgramID<N>] {
+        &self.program_ids
+    }
+}
+
+impl<N: Network> Serialize for TransactionSummary<N> {
+    /// Serializes the transaction summary to a JSON-string.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut summary = serializer.serialize_struct("TransactionSummary", 8)?;
+        summary.serialize_field("id", &self.id)?;
+        summary.serialize_field("type", self.variant)?;
+        summary.serialize_field("size_in_bytes", &self.size_in_bytes)?;
+        summary.serialize_field("fee", &self.fee)?;
+        summary.serialize_field("base_fee", &self.base_fee)?;
+        summary.serialize_field("priority_fee", &self.priority_fee)?;
+        summary.serialize_field("num_transitions", &self.num_transitions)?;
+        summary.serialize_field("program_ids", &self.program_ids)?;
+        summary.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size_in_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for transaction in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_deployment_transaction(false, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_private_fee_transaction(rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            let expected = u64::try_from(transaction.to_bytes_le()?.len())?;
+            assert_eq!(transaction.size_in_bytes()?, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_summary() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for transaction in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            let summary = transaction.summary()?;
+            assert_eq!(summary.id(), transaction.id());
+            assert_eq!(summary.size_in_bytes(), u64::try_from(transaction.to_bytes_le()?.len())?);
+            assert_eq!(summary.fee(), *transaction.fee_amount()?);
+            assert_eq!(summary.base_fee() + summary.priority_fee(), summary.fee());
+            assert_eq!(summary.num_transitions(), transaction.transitions().count());
+            assert!(!summary.program_ids().is_empty());
+
+            // Ensure the summary serializes to JSON.
+            let json = serde_json::to_value(&summary)?;
+            assert_eq!(json["type"], summary.variant());
+            assert_eq!(json["size_in_bytes"], summary.size_in_bytes());
+            assert_eq!(json["num_transitions"], summary.num_transitions());
+        }
+        Ok(())
+    }
+}
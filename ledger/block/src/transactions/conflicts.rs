@@ -0,0 +1,365 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::ProgramID;
+
+use std::collections::HashMap;
+
+/// A conflict between two transactions, where a transaction reuses an identifier of an earlier transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransactionConflict<N: Network> {
+    /// The transactions contain the same transition ID.
+    TransitionID { transition_id: N::TransitionID, first: N::TransactionID, duplicate: N::TransactionID },
+    /// The transactions contain the same serial number (i.e. they spend the same record).
+    SerialNumber { serial_number: Field<N>, first: N::TransactionID, duplicate: N::TransactionID },
+    /// The transactions contain the same output commitment.
+    Commitment { commitment: Field<N>, first: N::TransactionID, duplicate: N::TransactionID },
+    /// The transactions deploy the same program ID.
+    ProgramID { program_id: ProgramID<N>, first: N::TransactionID, duplicate: N::TransactionID },
+}
+
+impl<N: Network> TransactionConflict<N> {
+    /// Returns the ID of the earlier transaction, which is kept on deduplication.
+    pub const fn first(&self) -> &N::TransactionID {
+        match self {
+            Self::TransitionID { first, .. }
+            | Self::SerialNumber { first, .. }
+            | Self::Commitment { first, .. }
+            | Self::ProgramID { first, .. } => first,
+        }
+    }
+
+    /// Returns the ID of the later transaction, which is dropped on deduplication.
+    pub const fn duplicate(&self) -> &N::TransactionID {
+        match self {
+            Self::TransitionID { duplicate, .. }
+            | Self::SerialNumber { duplicate, .. }
+            | Self::Commitment { duplicate, .. }
+            | Self::ProgramID { duplicate, .. } => duplicate,
+        }
+    }
+}
+
+impl<N: Network> Display for TransactionConflict<N> {
+    /// Prints the conflict as a human-readable message.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::TransitionID { transition_id, first, duplicate } => {
+                write!(f, "Transaction '{duplicate}' reuses the transition ID '{transition_id}' of '{first}'")
+            }
+            Self::SerialNumber { serial_number, first, duplicate } => {
+                write!(f, "Transaction '{duplicate}' reuses the serial number '{serial_number}' of '{first}'")
+            }
+            Self::Commitment { commitment, first, duplicate } => {
+                write!(f, "Transaction '{duplicate}' reuses the commitment '{commitment}' of '{first}'")
+            }
+            Self::ProgramID { program_id, first, duplicate } => {
+                write!(f, "Transaction '{duplicate}' redeploys the program '{program_id}' of '{first}'")
+            }
+        }
+    }
+}
+
+impl<N: Network> Transactions<N> {
+    /// Returns the conflicts between the transactions, in order.
+    /// Each conflict pairs a transaction with the first earlier transaction that contains
+    /// the same transition ID, serial number, output commitment, or deployed program ID.
+    pub fn find_conflicts(&self) -> Vec<TransactionConflict<N>> {
+        // Initialize the tracker.
+        let mut tracker = ConflictTracker::default();
+        // Find the conflicts of each transaction against the preceding transactions.
+        let mut conflicts = Vec::new();
+        for transaction in self.iter() {
+            conflicts.extend(tracker.find_conflicts(transaction));
+            tracker.insert(transaction);
+        }
+        conflicts
+    }
+
+    /// Removes every transaction that conflicts with an earlier (kept) transaction, reindexes the remaining
+    /// transactions, invalidates the cached transactions tree, and returns the conflicts that were found.
+    ///
+    /// Note: The finalize operations of the removed transactions are not undone,
+    /// so the remaining transactions must be speculated on again before they are included in a block.
+    pub fn deduplicate(&mut self) -> Vec<TransactionConflict<N>> {
+        // Initialize the tracker.
+        let mut tracker = ConflictTracker::default();
+        // Initialize the list of conflicts.
+        let mut conflicts = Vec::new();
+        // Keep a transaction only if it does not conflict with the kept transactions.
+        self.transactions.retain(|_, transaction| tracker.keep(transaction, &mut conflicts));
+        if !conflicts.is_empty() {
+            // Reindex the remaining transactions, so that their indices are contiguous.
+            for (index, transaction) in (0u32..).zip(self.transactions.values_mut()) {
+                match transaction {
+                    ConfirmedTransaction::AcceptedDeploy(transaction_index, ..)
+                    | ConfirmedTransaction::AcceptedExecute(transaction_index, ..)
+                    | ConfirmedTransaction::RejectedDeploy(transaction_index, ..)
+                    | ConfirmedTransaction::RejectedExecute(transaction_index, ..) => *transaction_index = index,
+                }
+            }
+            // Invalidate the cached transactions tree.
+            self.tree.take();
+        }
+        conflicts
+    }
+
+    /// Removes every given (unconfirmed) transaction that conflicts with an earlier (kept) transaction,
+    /// and returns the conflicts that were found.
+    ///
+    /// Note: This does not depend on the outcome of speculation, so it can be used to drop conflicting
+    /// candidate transactions before they are speculated on.
+    pub fn deduplicate_unconfirmed(transactions: &mut Vec<Transaction<N>>) -> Vec<TransactionConflict<N>> {
+        // Initialize the tracker.
+        let mut tracker = ConflictTracker::default();
+        // Initialize the list of conflicts.
+        let mut conflicts = Vec::new();
+        // Keep a transaction only if it does not conflict with the kept transactions.
+        transactions.retain(|transaction| tracker.keep(transaction, &mut conflicts));
+        conflicts
+    }
+}
+
+/// Tracks the transition IDs, serial numbers, commitments, and deployed program IDs of the transactions seen so far,
+/// along with the ID of the first transaction that contained each of them.
+struct ConflictTracker<N: Network> {
+    transition_ids: HashMap<N::TransitionID, N::TransactionID>,
+    serial_numbers: HashMap<Field<N>, N::TransactionID>,
+    commitments: HashMap<Field<N>, N::TransactionID>,
+    program_ids: HashMap<ProgramID<N>, N::TransactionID>,
+}
+
+impl<N: Network> Default for ConflictTracker<N> {
+    /// Initializes an empty tracker.
+    fn default() -> Self {
+        Self {
+            transition_ids: Default::default(),
+            serial_numbers: Default::default(),
+            commitments: Default::default(),
+            program_ids: Default::default(),
+        }
+    }
+}
+
+impl<N: Network> ConflictTracker<N> {
+    /// Returns the conflicts between the given transaction and the tracked transactions.
+    fn find_conflicts(&self, transaction: &Transaction<N>) -> Vec<TransactionConflict<N>> {
+        let duplicate = transaction.id();
+
+        let mut conflicts = Vec::new();
+        for transition_id in transaction.transition_ids() {
+            if let Some(first) = self.transition_ids.get(transition_id) {
+                conflicts.push(TransactionConflict::TransitionID {
+                    transition_id: *transition_id,
+                    first: *first,
+                    duplicate,
+                });
+            }
+        }
+        for serial_number in transaction.serial_numbers() {
+            if let Some(first) = self.serial_numbers.get(serial_number) {
+                conflicts.push(TransactionConflict::SerialNumber {
+                    serial_number: *serial_number,
+                    first: *first,
+                    duplicate,
+                });
+            }
+        }
+        for commitment in transaction.commitments() {
+            if let Some(first) = self.commitments.get(commitment) {
+                conflicts.push(TransactionConflict::Commitment { commitment: *commitment, first: *first, duplicate });
+            }
+        }
+        if let Some(deployment) = transaction.deployment() {
+            if let Some(first) = self.program_ids.get(deployment.program_id()) {
+                conflicts.push(TransactionConflict::ProgramID {
+                    program_id: *deployment.program_id(),
+                    first: *first,
+                    duplicate,
+                });
+            }
+        }
+        conflicts
+    }
+
+    /// Tracks the given transaction and returns `true` if it does not conflict with the tracked transactions.
+    /// Otherwise, appends its conflicts to the given list, and returns `false`.
+    fn keep(&mut self, transaction: &Transaction<N>, conflicts: &mut Vec<TransactionConflict<N>>) -> bool {
+        let transaction_conflicts = self.find_conflicts(transaction);
+        match transaction_conflicts.is_empty() {
+            true => {
+                self.insert(transaction);
+                true
+            }
+            false => {
+                conflicts.extend(transaction_conflicts);
+                false
+            }
+        }
+    }
+
+    /// Tracks the given transaction, keeping the first transaction for any identifier that is already tracked.
+    fn insert(&mut self, transaction: &Transaction<N>) {
+        let id = transaction.id();
+
+        for transition_id in transaction.transition_ids() {
+            self.transition_ids.entry(*transition_id).or_insert(id);
+        }
+        for serial_number in transaction.serial_numbers() {
+            self.serial_numbers.entry(*serial_number).or_insert(id);
+        }
+        for commitment in transaction.commitments() {
+            self.commitments.entry(*commitment).or_insert(id);
+        }
+        if let Some(deployment) = transaction.deployment() {
+            self.program_ids.entry(*deployment.program_id()).or_insert(id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transactions::confirmed::test_helpers;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_find_conflicts_transition_id_and_commitment() {
+        let rng = &mut TestRng::default();
+
+        // Sample two execute transactions that share the same execution, but pay different fees.
+        let first = test_helpers::sample_accepted_execute(0, true, rng);
+        let second = test_helpers::sample_accepted_execute(1, false, rng);
+        assert_ne!(first.id(), second.id());
+        let transactions = Transactions::<CurrentNetwork>::from(&[first.clone(), second.clone()]);
+
+        // Ensure the shared transition IDs and commitments are detected.
+        let conflicts = transactions.find_conflicts();
+        let num_transition_ids = first.transaction().execution().unwrap().transitions().count();
+        let num_commitments = first.transaction().execution().unwrap().commitments().count();
+        assert!(num_commitments > 0);
+        assert_eq!(conflicts.len(), num_transition_ids + num_commitments);
+        for conflict in &conflicts {
+            assert!(matches!(
+                conflict,
+                TransactionConflict::TransitionID { .. } | TransactionConflict::Commitment { .. }
+            ));
+            assert_eq!(conflict.first(), &first.id());
+            assert_eq!(conflict.duplicate(), &second.id());
+        }
+        assert!(conflicts.iter().any(|conflict| matches!(conflict, TransactionConflict::TransitionID { .. })));
+        assert!(conflicts.iter().any(|conflict| matches!(conflict, TransactionConflict::Commitment { .. })));
+    }
+
+    #[test]
+    fn test_find_conflicts_serial_number() {
+        let rng = &mut TestRng::default();
+
+        // Sample a deploy and an execute transaction, whose private fees spend the same record.
+        let first = test_helpers::sample_accepted_deploy(0, true, rng);
+        let second = test_helpers::sample_accepted_execute(1, true, rng);
+        let transactions = Transactions::<CurrentNetwork>::from(&[first.clone(), second.clone()]);
+
+        // Ensure only the shared serial number is detected.
+        let conflicts = transactions.find_conflicts();
+        assert_eq!(conflicts.len(), 1);
+        let TransactionConflict::SerialNumber { serial_number, first: first_id, duplicate } = &conflicts[0] else {
+            panic!("Expected a serial number conflict, found '{}'", conflicts[0]);
+        };
+        assert!(first.serial_numbers().any(|candidate| candidate == serial_number));
+        assert!(second.serial_numbers().any(|candidate| candidate == serial_number));
+        assert_eq!(first_id, &first.id());
+        assert_eq!(duplicate, &second.id());
+    }
+
+    #[test]
+    fn test_find_conflicts_program_id() {
+        let rng = &mut TestRng::default();
+
+        // Sample two deploy transactions of the same program, with different public fees.
+        let first = test_helpers::sample_accepted_deploy(0, false, rng);
+        let second = test_helpers::sample_accepted_deploy(1, false, rng);
+        assert_ne!(first.id(), second.id());
+        let transactions = Transactions::<CurrentNetwork>::from(&[first.clone(), second.clone()]);
+
+        // Ensure only the redeployed program ID is detected.
+        let program_id = *first.transaction().deployment().unwrap().program_id();
+        let conflicts = transactions.find_conflicts();
+        assert_eq!(conflicts, vec![TransactionConflict::ProgramID {
+            program_id,
+            first: first.id(),
+            duplicate: second.id()
+        }]);
+    }
+
+    #[test]
+    fn test_deduplicate() {
+        let rng = &mut TestRng::default();
+
+        // Sample a list with a conflict of each class.
+        let execute = test_helpers::sample_accepted_execute(0, true, rng);
+        let execute_duplicate = test_helpers::sample_accepted_execute(1, false, rng);
+        let deploy = test_helpers::sample_accepted_deploy(2, false, rng);
+        let deploy_duplicate = test_helpers::sample_accepted_deploy(3, false, rng);
+        let deploy_spending_duplicate = test_helpers::sample_accepted_deploy(4, true, rng);
+        let mut transactions = Transactions::<CurrentNetwork>::from(&[
+            execute.clone(),
+            execute_duplicate.clone(),
+            deploy.clone(),
+            deploy_duplicate.clone(),
+            deploy_spending_duplicate.clone(),
+        ]);
+        let expected_conflicts = transactions.find_conflicts();
+
+        // Deduplicate the transactions, and ensure the first occurrences are kept.
+        let conflicts = transactions.deduplicate();
+        assert_eq!(conflicts, expected_conflicts);
+        assert_eq!(transactions.transaction_ids().copied().collect::<Vec<_>>(), vec![execute.id(), deploy.id()]);
+        assert!(transactions.find_conflicts().is_empty());
+        // Ensure the kept transactions are reindexed.
+        assert_eq!(transactions.iter().map(|transaction| transaction.index()).collect::<Vec<_>>(), vec![0, 1]);
+
+        // Ensure the dropped transactions are reported as duplicates of the kept transactions.
+        for conflict in &conflicts {
+            assert!(transactions.get(conflict.first()).is_some());
+            assert!(transactions.get(conflict.duplicate()).is_none());
+        }
+        assert!(conflicts.iter().any(|conflict| conflict.duplicate() == &execute_duplicate.id()));
+        assert!(conflicts.iter().any(|conflict| conflict.duplicate() == &deploy_duplicate.id()));
+        assert!(conflicts.iter().any(|conflict| conflict.duplicate() == &deploy_spending_duplicate.id()));
+    }
+
+    #[test]
+    fn test_deduplicate_unconfirmed() {
+        let rng = &mut TestRng::default();
+
+        // Sample a list of unconfirmed transactions with a conflict of each class.
+        let execute = test_helpers::sample_accepted_execute(0, true, rng);
+        let execute_duplicate = test_helpers::sample_accepted_execute(1, false, rng);
+        let deploy = test_helpers::sample_accepted_deploy(2, false, rng);
+        let deploy_duplicate = test_helpers::sample_accepted_deploy(3, false, rng);
+        let deploy_spending_duplicate = test_helpers::sample_accepted_deploy(4, true, rng);
+        let confirmed = [execute, execute_duplicate, deploy, deploy_duplicate, deploy_spending_duplicate];
+        let expected_conflicts = Transactions::<CurrentNetwork>::from(&confirmed).find_conflicts();
+        let mut transactions = confirmed.iter().map(|transaction| transaction.transaction().clone()).collect();
+
+        // Deduplicate the transactions, and ensure the same conflicts are found, and the first occurrences are kept.
+        let conflicts = Transactions::deduplicate_unconfirmed(&mut transactions);
+        assert_eq!(conflicts, expected_conflicts);
+        let ids = transactions.iter().map(|transaction| transaction.id()).collect::<Vec<_>>();
+        assert_eq!(ids, vec![confirmed[0].id(), confirmed[2].id()]);
+    }
+}
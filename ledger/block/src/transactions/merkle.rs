@@ -30,20 +30,28 @@ impl<N: Network> Transactions<N> {
 impl<N: Network> Transactions<N> {
     /// Returns the transactions root, by computing the root for a Merkle tree of the transaction IDs.
     pub fn to_transactions_root(&self) -> Result<Field<N>> {
-        Ok(*self.to_tree()?.root())
+        Ok(*self.to_transactions_tree()?.root())
     }
 
     /// Returns the Merkle path for the transactions leaf.
     pub fn to_path(&self, transaction_id: N::TransactionID) -> Result<TransactionsPath<N>> {
         match self.transactions.get_index_of(&transaction_id) {
-            Some(transaction_index) => self.to_tree()?.prove(transaction_index, &transaction_id.to_bits_le()),
+            Some(transaction_index) => {
+                self.to_transactions_tree()?.prove(transaction_index, &transaction_id.to_bits_le())
+            }
             None => bail!("The transaction '{transaction_id}' is not in the block transactions"),
         }
     }
 
     /// The Merkle tree of transaction IDs for the block.
     pub fn to_tree(&self) -> Result<TransactionsTree<N>> {
-        Self::transactions_tree(&self.transactions)
+        self.to_transactions_tree().cloned()
+    }
+
+    /// Returns the (cached) Merkle tree of transaction IDs for the block.
+    /// Note: The tree is computed once, and reused until the transactions are mutated.
+    pub fn to_transactions_tree(&self) -> Result<&TransactionsTree<N>> {
+        self.tree.get_or_try_init(|| Self::transactions_tree(&self.transactions).map(Arc::new)).map(|tree| &**tree)
     }
 
     /// Returns the Merkle tree for the given transactions.
@@ -76,4 +84,116 @@ mod tests {
         // Ensure the log2 relationship between depth and the maximum number of transactions.
         assert_eq!(2usize.pow(TRANSACTIONS_DEPTH as u32), Transactions::<CurrentNetwork>::MAX_TRANSACTIONS);
     }
+
+    /// Samples the given number of key-value updates.
+    fn sample_updates(num_updates: usize, rng: &mut TestRng) -> Vec<FinalizeOperation<CurrentNetwork>> {
+        (0..num_updates)
+            .map(|_| {
+                FinalizeOperation::UpdateKeyValue(
+                    Uniform::rand(rng),
+                    Uniform::rand(rng),
+                    Uniform::rand(rng),
+                    Uniform::rand(rng),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_iter_finalize_operations() {
+        let rng = &mut TestRng::default();
+
+        let (first_updates, second_updates, rejected_updates) =
+            (sample_updates(2, rng), sample_updates(3, rng), sample_updates(1, rng));
+
+        // Construct accepted executions, an accepted deployment, and a rejected execution.
+        let first_execution = crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng);
+        let first = ConfirmedTransaction::accepted_execute(0, first_execution, first_updates).unwrap();
+        let deployment = crate::transactions::confirmed::test_helpers::sample_accepted_deploy(1, false, rng);
+        let second_execution = crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng);
+        let second = ConfirmedTransaction::accepted_execute(2, second_execution, second_updates).unwrap();
+        let rejected = ConfirmedTransaction::rejected_execute(
+            3,
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+            crate::rejected::test_helpers::sample_rejected_execution(false, rng),
+            rejected_updates,
+        )
+        .unwrap();
+        let confirmed = [first, deployment, second, rejected];
+        let transactions = Transactions::from(&confirmed);
+
+        // Construct the expected flattened sequence.
+        let mut expected = Vec::new();
+        for tx in &confirmed {
+            for (operation_index, operation) in tx.finalize_operations().iter().enumerate() {
+                expected.push((tx.index(), tx.id(), operation_index, operation));
+            }
+        }
+        assert_eq!(expected.len(), 8);
+
+        // Ensure the flattened sequence and its provenance are correct.
+        let candidate = transactions.iter_finalize_operations().collect::<Vec<_>>();
+        assert_eq!(candidate, expected);
+        assert_eq!(candidate.len(), transactions.num_finalize());
+
+        // Ensure the finalize root is recomputed from the flattened sequence.
+        let ratified_finalize_operations = sample_updates(2, rng);
+        let leaves = candidate
+            .iter()
+            .map(|(_, _, _, operation)| *operation)
+            .chain(&ratified_finalize_operations)
+            .map(ToBits::to_bits_le)
+            .collect::<Vec<_>>();
+        let tree = CurrentNetwork::merkle_tree_bhp::<FINALIZE_OPERATIONS_DEPTH>(&leaves).unwrap();
+        assert_eq!(*tree.root(), transactions.to_finalize_root(ratified_finalize_operations).unwrap());
+    }
+
+    #[test]
+    fn test_transactions_tree_cache() {
+        let confirmed = crate::transactions::confirmed::test_helpers::sample_confirmed_transactions();
+
+        // Returns the transactions root, computed without the cache.
+        let uncached_root = |transactions: &Transactions<CurrentNetwork>| {
+            *Transactions::transactions_tree(&transactions.transactions).unwrap().root()
+        };
+
+        // Ensure the cached root matches on construction.
+        let mut transactions = Transactions::from(&confirmed[..2]);
+        assert_eq!(transactions.to_transactions_root().unwrap(), uncached_root(&transactions));
+
+        // Ensure the cached root is invalidated by a push.
+        transactions.push(confirmed[2].clone());
+        assert_eq!(transactions.to_transactions_root().unwrap(), uncached_root(&transactions));
+
+        // Ensure the cached root is invalidated by an extend.
+        transactions.extend(confirmed[3..].iter().cloned());
+        let root = transactions.to_transactions_root().unwrap();
+        assert_eq!(root, uncached_root(&transactions));
+        assert_eq!(root, Transactions::from(&confirmed).to_transactions_root().unwrap());
+        assert_eq!(root, *transactions.to_transactions_tree().unwrap().root());
+
+        // Ensure the paths are computed from the cached tree.
+        for transaction_id in transactions.transaction_ids() {
+            let path = transactions.to_path(*transaction_id).unwrap();
+            assert!(CurrentNetwork::verify_merkle_path_bhp(&path, &root, &transaction_id.to_bits_le()));
+        }
+
+        // Ensure the cache is not serialized.
+        let bytes = transactions.to_bytes_le().unwrap();
+        assert_eq!(bytes, Transactions::from(&confirmed).to_bytes_le().unwrap());
+        // Ensure the serialization round-trips produce the same root.
+        let candidate = Transactions::<CurrentNetwork>::read_le(&bytes[..]).unwrap();
+        assert_eq!(root, candidate.to_transactions_root().unwrap());
+        let candidate = Transactions::<CurrentNetwork>::from_str(&transactions.to_string()).unwrap();
+        assert_eq!(root, candidate.to_transactions_root().unwrap());
+    }
+
+    #[test]
+    fn test_block_num_finalize_operations() {
+        let rng = &mut TestRng::default();
+
+        let block = crate::test_helpers::sample_genesis_block(rng);
+        assert!(block.num_finalize_operations() > 0);
+        assert_eq!(block.num_finalize_operations(), block.transactions().iter_finalize_operations().count());
+    }
 }
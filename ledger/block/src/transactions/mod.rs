@@ -18,6 +18,9 @@ pub use confirmed::*;
 pub mod rejected;
 pub use rejected::*;
 
+mod conflicts;
+pub use conflicts::*;
+
 mod bytes;
 mod merkle;
 mod serialize;
@@ -40,14 +43,19 @@ use console::{
 use synthesizer_program::FinalizeOperation;
 
 use indexmap::IndexMap;
+use once_cell::sync::OnceCell;
+use std::sync::Arc;
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone)]
 pub struct Transactions<N: Network> {
     /// The transactions included in a block.
     transactions: IndexMap<N::TransactionID, ConfirmedTransaction<N>>,
+    /// The cached Merkle tree of the transaction IDs.
+    /// Note: This is invalidated on any mutation, and is not serialized.
+    tree: OnceCell<Arc<TransactionsTree<N>>>,
 }
 
 impl<N: Network> Transactions<N> {
@@ -60,7 +68,10 @@ impl<N: Network> Transactions<N> {
 impl<N: Network> FromIterator<ConfirmedTransaction<N>> for Transactions<N> {
     /// Initializes from an iterator of transactions.
     fn from_iter<T: IntoIterator<Item = ConfirmedTransaction<N>>>(iter: T) -> Self {
-        Self { transactions: iter.into_iter().map(|transaction| (transaction.id(), transaction)).collect() }
+        Self {
+            transactions: iter.into_iter().map(|transaction| (transaction.id(), transaction)).collect(),
+            tree: OnceCell::new(),
+        }
     }
 }
 
@@ -71,6 +82,31 @@ impl<'a, N: Network> FromIterator<&'a ConfirmedTransaction<N>> for Transactions<
     }
 }
 
+impl<N: Network> Extend<ConfirmedTransaction<N>> for Transactions<N> {
+    /// Appends the given transactions, invalidating the cached transactions tree.
+    fn extend<T: IntoIterator<Item = ConfirmedTransaction<N>>>(&mut self, iter: T) {
+        self.transactions.extend(iter.into_iter().map(|transaction| (transaction.id(), transaction)));
+        self.tree.take();
+    }
+}
+
+impl<N: Network> PartialEq for Transactions<N> {
+    /// Returns `true` if the transactions are equal, ignoring the cached transactions tree.
+    fn eq(&self, other: &Self) -> bool {
+        self.transactions == other.transactions
+    }
+}
+
+impl<N: Network> Eq for Transactions<N> {}
+
+impl<N: Network> Transactions<N> {
+    /// Appends the given transaction, invalidating the cached transactions tree.
+    pub fn push(&mut self, transaction: ConfirmedTransaction<N>) {
+        self.transactions.insert(transaction.id(), transaction);
+        self.tree.take();
+    }
+}
+
 impl<N: Network> Transactions<N> {
     /// Returns the transaction for the given transaction ID.
     pub fn get(&self, transaction_id: &N::TransactionID) -> Option<&ConfirmedTransaction<N>> {
@@ -251,6 +287,31 @@ impl<N: Network> Transactions<N> {
     pub fn finalize_operations(&self) -> impl '_ + Iterator<Item = &FinalizeOperation<N>> {
         self.iter().flat_map(|tx| tx.finalize_operations())
     }
+
+    /// Returns an iterator over the finalize operations, for all transactions, along with their provenance,
+    /// as `(transaction index, transaction ID, operation index, finalize operation)`.
+    ///
+    /// The operations are yielded in the same order as the leaves of the finalize tree, namely the
+    /// transactions in block order, and for each transaction, its finalize operations in order.
+    /// An accepted transaction contributes the finalize operations of its execution or deployment, and its fee.
+    /// A rejected transaction contributes only the finalize operations of its fee transition.
+    ///
+    /// The transaction index is the confirmed index, and the transaction ID is the confirmed ID,
+    /// which for a rejected transaction is the ID of its fee transaction (see `to_unconfirmed_transaction_id`).
+    /// The operation index is the position of the operation within its transaction.
+    ///
+    /// Note: The finalize root also commits to the ratified finalize operations, which follow these
+    /// operations in the finalize tree, and are not included here as they are not stored in the block.
+    pub fn iter_finalize_operations(
+        &self,
+    ) -> impl '_ + Iterator<Item = (u32, N::TransactionID, usize, &FinalizeOperation<N>)> {
+        self.transactions.iter().flat_map(|(transaction_id, tx)| {
+            tx.finalize_operations()
+                .iter()
+                .enumerate()
+                .map(move |(operation_index, operation)| (tx.index(), *transaction_id, operation_index, operation))
+        })
+    }
 }
 
 impl<N: Network> IntoIterator for Transactions<N> {
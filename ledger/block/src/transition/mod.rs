@@ -24,12 +24,15 @@ mod serialize;
 mod string;
 
 use console::{
+    account::ViewKey,
     network::prelude::*,
     program::{
         Ciphertext,
+        Future,
         Identifier,
         InputID,
         OutputID,
+        Plaintext,
         ProgramID,
         Record,
         Register,
@@ -376,6 +379,11 @@ impl<N: Network> Transition<N> {
             Output::Future(_, _) => None,
         })
     }
+
+    /// Returns the output with the corresponding ID, if it exists.
+    pub fn find_output(&self, id: &Field<N>) -> Option<&Output<N>> {
+        self.outputs.iter().find(|output| output.id() == id)
+    }
 }
 
 impl<N: Network> Transition<N> {
@@ -417,6 +425,33 @@ impl<N: Network> Transition<N> {
     pub fn records(&self) -> impl '_ + Iterator<Item = (&Field<N>, &Record<N, Ciphertext<N>>)> {
         self.outputs.iter().flat_map(Output::record)
     }
+
+    /// Returns an iterator over the output records owned by the given view key.
+    ///
+    /// This method only decrypts the owner of each record, and does not decrypt the record data.
+    pub fn owned_records<'a>(
+        &'a self,
+        view_key: &'a ViewKey<N>,
+    ) -> impl 'a + Iterator<Item = (&'a Field<N>, &'a Record<N, Ciphertext<N>>)> {
+        // Compute the x-coordinate of the address once, for all records.
+        let address_x_coordinate = view_key.to_address().to_x_coordinate();
+        // Filter the records for the owner.
+        self.records()
+            .filter(move |(_, record)| record.is_owner_with_address_x_coordinate(view_key, &address_x_coordinate))
+    }
+
+    /// Returns an iterator over the output futures.
+    pub fn futures(&self) -> impl '_ + Iterator<Item = &Future<N>> {
+        self.outputs.iter().flat_map(Output::future)
+    }
+
+    /// Returns an iterator over the output plaintexts, for outputs that are public.
+    pub fn public_outputs(&self) -> impl '_ + Iterator<Item = &Plaintext<N>> {
+        self.outputs.iter().flat_map(|output| match output {
+            Output::Public(_, Some(plaintext)) => Some(plaintext),
+            _ => None,
+        })
+    }
 }
 
 impl<N: Network> Transition<N> {
@@ -478,3 +513,128 @@ pub mod test_helpers {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{
+        account::PrivateKey,
+        program::{Literal, Owner},
+    };
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    /// Samples a transition with a mix of output kinds.
+    fn sample_transition_with_mixed_outputs(rng: &mut TestRng) -> Transition<CurrentNetwork> {
+        // Sample a transition.
+        let transition = test_helpers::sample_transition(rng);
+        // Sample a public fee transaction, whose fee transition outputs a future.
+        let transaction = crate::transaction::test_helpers::sample_fee_public_transaction(rng);
+        let fee = transaction.transitions().next().unwrap();
+
+        // Collect a mix of outputs.
+        let mut outputs: Vec<_> =
+            output::test_helpers::sample_outputs().into_iter().map(|(_, output)| output).collect();
+        outputs.extend(fee.outputs().iter().cloned());
+
+        Transition::new(
+            *transition.program_id(),
+            *transition.function_name(),
+            transition.inputs().to_vec(),
+            outputs,
+            *transition.tpk(),
+            *transition.tcm(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_owned_records() {
+        let rng = &mut TestRng::default();
+
+        // Sample the view keys.
+        let view_keys = (0..3)
+            .map(|_| ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap())
+            .collect::<Vec<_>>();
+
+        // Sample records owned by each view key, with a mix of public and private owners.
+        let outputs = (0..12)
+            .map(|i| {
+                let owner = view_keys[i % view_keys.len()].to_address();
+                let owner = match i % 2 == 0 {
+                    true => Owner::Public(owner),
+                    false => Owner::Private(Plaintext::from(Literal::Address(owner))),
+                };
+                let randomizer = Uniform::rand(rng);
+                let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_plaintext(
+                    owner,
+                    Default::default(),
+                    CurrentNetwork::g_scalar_multiply(&randomizer),
+                )
+                .unwrap();
+                Output::Record(Uniform::rand(rng), Uniform::rand(rng), Some(record.encrypt(randomizer).unwrap()))
+            })
+            .collect::<Vec<_>>();
+
+        // Construct a transition with the records.
+        let transition = test_helpers::sample_transition(rng);
+        let transition = Transition::new(
+            *transition.program_id(),
+            *transition.function_name(),
+            transition.inputs().to_vec(),
+            outputs,
+            *transition.tpk(),
+            *transition.tcm(),
+        )
+        .unwrap();
+
+        // Ensure the owned records match those that fully decrypt under each view key.
+        for view_key in &view_keys {
+            let expected =
+                transition.records().filter(|(_, record)| record.decrypt(view_key).is_ok()).collect::<Vec<_>>();
+            assert_eq!(expected.len(), 4);
+            assert_eq!(transition.owned_records(view_key).collect::<Vec<_>>(), expected);
+        }
+
+        // Ensure a random view key owns no records.
+        let view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        assert_eq!(transition.owned_records(&view_key).count(), 0);
+    }
+
+    #[test]
+    fn test_output_accessors() {
+        let rng = &mut TestRng::default();
+
+        // Sample a transition.
+        let transition = sample_transition_with_mixed_outputs(rng);
+
+        // Check the records.
+        let expected_records: Vec<_> = transition.outputs().iter().filter_map(Output::record).collect();
+        assert!(!expected_records.is_empty());
+        assert_eq!(transition.records().collect::<Vec<_>>(), expected_records);
+
+        // Check the futures.
+        let expected_futures: Vec<_> = transition.outputs().iter().filter_map(Output::future).collect();
+        assert!(!expected_futures.is_empty());
+        assert_eq!(transition.futures().collect::<Vec<_>>(), expected_futures);
+
+        // Check the public outputs.
+        let expected_public_outputs: Vec<_> = transition
+            .outputs()
+            .iter()
+            .filter_map(|output| match output {
+                Output::Public(_, plaintext) => plaintext.as_ref(),
+                _ => None,
+            })
+            .collect();
+        assert!(!expected_public_outputs.is_empty());
+        assert_eq!(transition.public_outputs().collect::<Vec<_>>(), expected_public_outputs);
+
+        // Check that each output can be found by its ID.
+        for output in transition.outputs() {
+            assert_eq!(transition.find_output(output.id()), Some(output));
+        }
+        // Ensure an unknown ID is not found.
+        assert!(transition.find_output(&Uniform::rand(rng)).is_none());
+    }
+}
@@ -13,7 +13,6 @@
 // limitations under the License.
 
 #![allow(clippy::too_many_arguments)]
-#![allow(clippy::type_complexity)]
 
 use super::*;
 use ledger_coinbase::{CoinbasePuzzle, EpochChallenge};
@@ -24,6 +23,38 @@ use std::collections::HashSet;
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
+/// The expected values of a block, as determined by `Block::verify_authority`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ExpectedAuthority {
+    /// The expected round of the block.
+    pub round: u64,
+    /// The expected height of the block.
+    pub height: u32,
+    /// The expected timestamp of the block.
+    pub timestamp: i64,
+}
+
+/// The expected values of a block, as determined by `Block::verify_solutions`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ExpectedSolutions {
+    /// The expected cumulative weight of the block.
+    pub cumulative_weight: u128,
+    /// The expected cumulative proof target of the block.
+    pub cumulative_proof_target: u128,
+    /// The expected coinbase target of the block.
+    pub coinbase_target: u64,
+    /// The expected proof target of the block.
+    pub proof_target: u64,
+    /// The expected last coinbase target of the block.
+    pub last_coinbase_target: u64,
+    /// The expected last coinbase timestamp of the block.
+    pub last_coinbase_timestamp: i64,
+    /// The expected block reward of the block.
+    pub block_reward: u64,
+    /// The expected puzzle reward of the block.
+    pub puzzle_reward: u64,
+}
+
 impl<N: Network> Block<N> {
     /// Ensures the block is correct.
     pub fn verify(
@@ -40,27 +71,38 @@ impl<N: Network> Block<N> {
         self.verify_hash(previous_block.height(), previous_block.hash())?;
 
         // Ensure the block authority is correct.
-        let (expected_round, expected_height, expected_timestamp) =
+        let expected_authority =
             self.verify_authority(previous_block.round(), previous_block.height(), current_committee)?;
 
         // Ensure the block solutions are correct.
-        let (
-            expected_cumulative_weight,
-            expected_cumulative_proof_target,
-            expected_coinbase_target,
-            expected_proof_target,
-            expected_last_coinbase_target,
-            expected_last_coinbase_timestamp,
-            expected_block_reward,
-            expected_puzzle_reward,
-        ) = self.verify_solutions(previous_block, current_puzzle, current_epoch_challenge)?;
+        let expected_solutions = self.verify_solutions(previous_block, current_puzzle, current_epoch_challenge)?;
 
         // Ensure the block ratifications are correct.
-        self.verify_ratifications(expected_block_reward, expected_puzzle_reward)?;
+        self.verify_ratifications(expected_solutions.block_reward, expected_solutions.puzzle_reward)?;
 
         // Ensure the block transactions are correct.
         self.verify_transactions()?;
 
+        // Ensure the block header is correct.
+        self.verify_header(
+            current_state_root,
+            current_timestamp,
+            ratified_finalize_operations,
+            expected_authority,
+            expected_solutions,
+        )
+    }
+
+    /// Ensures the block header is correct, given the expected values from `Block::verify_authority`
+    /// and `Block::verify_solutions`, and the ratified finalize operations from speculation.
+    pub fn verify_header(
+        &self,
+        current_state_root: N::StateRoot,
+        current_timestamp: i64,
+        ratified_finalize_operations: Vec<FinalizeOperation<N>>,
+        expected_authority: ExpectedAuthority,
+        expected_solutions: ExpectedSolutions,
+    ) -> Result<()> {
         // Set the expected previous state root.
         let expected_previous_state_root = current_state_root;
         // Compute the expected transactions root.
@@ -82,15 +124,15 @@ impl<N: Network> Block<N> {
             expected_ratifications_root,
             expected_solutions_root,
             expected_subdag_root,
-            expected_round,
-            expected_height,
-            expected_cumulative_weight,
-            expected_cumulative_proof_target,
-            expected_coinbase_target,
-            expected_proof_target,
-            expected_last_coinbase_target,
-            expected_last_coinbase_timestamp,
-            expected_timestamp,
+            expected_authority.round,
+            expected_authority.height,
+            expected_solutions.cumulative_weight,
+            expected_solutions.cumulative_proof_target,
+            expected_solutions.coinbase_target,
+            expected_solutions.proof_target,
+            expected_solutions.last_coinbase_target,
+            expected_solutions.last_coinbase_timestamp,
+            expected_authority.timestamp,
             current_timestamp,
         )
     }
@@ -98,7 +140,7 @@ impl<N: Network> Block<N> {
 
 impl<N: Network> Block<N> {
     /// Ensures the block hash is correct.
-    fn verify_hash(&self, previous_height: u32, previous_hash: N::BlockHash) -> Result<(), Error> {
+    pub fn verify_hash(&self, previous_height: u32, previous_hash: N::BlockHash) -> Result<(), Error> {
         // Determine the expected height.
         let expected_height = previous_height.saturating_add(1);
 
@@ -131,17 +173,21 @@ impl<N: Network> Block<N> {
     }
 
     /// Ensures the block authority is correct.
-    fn verify_authority(
+    /// Returns the expected round, height, and timestamp of the block.
+    pub fn verify_authority(
         &self,
         previous_round: u64,
         previous_height: u32,
         current_committee: &Committee<N>,
-    ) -> Result<(u64, u32, i64)> {
+    ) -> Result<ExpectedAuthority> {
         // Determine the expected height.
         let expected_height = previous_height.saturating_add(1);
         // Ensure the block type is correct.
         match expected_height == 0 {
-            true => ensure!(self.authority.is_beacon(), "The genesis block must be a beacon block"),
+            true => ensure!(
+                self.authority.is_beacon() || self.authority.is_genesis_quorum(),
+                "The genesis block must be a beacon or genesis quorum block"
+            ),
             false => {
                 #[cfg(not(any(test, feature = "test")))]
                 ensure!(self.authority.is_quorum(), "The next block must be a quorum block");
@@ -165,6 +211,8 @@ impl<N: Network> Block<N> {
                 // Output the subdag anchor round.
                 subdag.anchor_round()
             }
+            // Genesis quorum blocks start at the committee starting round.
+            Authority::GenesisQuorum(committee) => committee.starting_round(),
         };
         // Ensure the block round is at least the starting round of the committee.
         ensure!(
@@ -207,22 +255,31 @@ impl<N: Network> Block<N> {
                     &self.aborted_transaction_ids,
                 )?;
             }
+            Authority::GenesisQuorum(committee) => {
+                // Ensure the genesis quorum authority is only used for the genesis block.
+                ensure!(expected_height == 0, "Genesis quorum authority is invalid for block {expected_height}");
+                // Ensure the block references the current committee.
+                ensure!(
+                    committee == current_committee,
+                    "Genesis quorum block references a committee that does not match the current committee"
+                );
+            }
         }
 
         // Determine the expected timestamp.
         let expected_timestamp = match &self.authority {
-            // Beacon blocks do not have a timestamp check.
-            Authority::Beacon(..) => self.timestamp(),
+            // Beacon and genesis quorum blocks do not have a timestamp check.
+            Authority::Beacon(..) | Authority::GenesisQuorum(..) => self.timestamp(),
             // Quorum blocks use the median timestamp from the subdag.
             Authority::Quorum(subdag) => subdag.timestamp(),
         };
 
         // Return success.
-        Ok((expected_round, expected_height, expected_timestamp))
+        Ok(ExpectedAuthority { round: expected_round, height: expected_height, timestamp: expected_timestamp })
     }
 
     /// Ensures the block ratifications are correct.
-    fn verify_ratifications(&self, expected_block_reward: u64, expected_puzzle_reward: u64) -> Result<()> {
+    pub fn verify_ratifications(&self, expected_block_reward: u64, expected_puzzle_reward: u64) -> Result<()> {
         let height = self.height();
 
         // Ensure there are sufficient ratifications.
@@ -256,12 +313,14 @@ impl<N: Network> Block<N> {
     }
 
     /// Ensures the block solutions are correct.
-    fn verify_solutions(
+    /// Returns the expected cumulative weight, cumulative proof target, coinbase target, proof target,
+    /// last coinbase target, last coinbase timestamp, block reward, and puzzle reward of the block.
+    pub fn verify_solutions(
         &self,
         previous_block: &Block<N>,
         current_puzzle: &CoinbasePuzzle<N>,
         current_epoch_challenge: &EpochChallenge<N>,
-    ) -> Result<(u128, u128, u64, u64, u64, i64, u64, u64)> {
+    ) -> Result<ExpectedSolutions> {
         let height = self.height();
         let timestamp = self.timestamp();
 
@@ -370,20 +429,20 @@ impl<N: Network> Block<N> {
         // Compute the expected puzzle reward.
         let expected_puzzle_reward = puzzle_reward(expected_coinbase_reward);
 
-        Ok((
-            expected_cumulative_weight,
-            expected_cumulative_proof_target,
-            expected_coinbase_target,
-            expected_proof_target,
-            expected_last_coinbase_target,
-            expected_last_coinbase_timestamp,
-            expected_block_reward,
-            expected_puzzle_reward,
-        ))
+        Ok(ExpectedSolutions {
+            cumulative_weight: expected_cumulative_weight,
+            cumulative_proof_target: expected_cumulative_proof_target,
+            coinbase_target: expected_coinbase_target,
+            proof_target: expected_proof_target,
+            last_coinbase_target: expected_last_coinbase_target,
+            last_coinbase_timestamp: expected_last_coinbase_timestamp,
+            block_reward: expected_block_reward,
+            puzzle_reward: expected_puzzle_reward,
+        })
     }
 
     /// Ensures the block transactions are correct.
-    fn verify_transactions(&self) -> Result<()> {
+    pub fn verify_transactions(&self) -> Result<()> {
         let height = self.height();
 
         // Ensure there are transactions.
@@ -484,7 +543,7 @@ impl<N: Network> Block<N> {
     fn compute_subdag_root(&self) -> Result<Field<N>> {
         match self.authority {
             Authority::Quorum(ref subdag) => subdag.to_subdag_root(),
-            Authority::Beacon(_) => Ok(Field::zero()),
+            Authority::Beacon(_) | Authority::GenesisQuorum(_) => Ok(Field::zero()),
         }
     }
 
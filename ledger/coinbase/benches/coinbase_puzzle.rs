@@ -112,10 +112,50 @@ fn coinbase_puzzle_verify(c: &mut Criterion) {
     }
 }
 
+#[cfg(feature = "setup")]
+fn coinbase_puzzle_verify_parallel(c: &mut Criterion) {
+    let rng = &mut thread_rng();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let universal_srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    for degree in [(1 << 13) - 1] {
+        let config = PuzzleConfig { degree };
+        let puzzle = CoinbasePuzzleInst::trim(&universal_srs, config).unwrap();
+        let epoch_challenge = sample_epoch_challenge(degree, rng);
+
+        for batch_size in [10, 100, <Testnet3 as Network>::MAX_PROVER_SOLUTIONS] {
+            let solutions = (0..batch_size)
+                .map(|_| {
+                    let (address, nonce) = sample_address_and_nonce(rng);
+                    puzzle.prove(&epoch_challenge, address, nonce, None).unwrap()
+                })
+                .collect::<Vec<_>>();
+            let solution_refs = solutions.iter().collect::<Vec<_>>();
+
+            c.bench_function(
+                &format!("CoinbasePuzzle::VerifySerial {batch_size} of 2^{}", ((degree + 1) as f64).log2()),
+                |b| {
+                    b.iter(|| {
+                        for solution in &solutions {
+                            assert!(solution.verify(puzzle.coinbase_verifying_key(), &epoch_challenge, 0u64).unwrap());
+                        }
+                    })
+                },
+            );
+            c.bench_function(
+                &format!("CoinbasePuzzle::VerifyParallel {batch_size} of 2^{}", ((degree + 1) as f64).log2()),
+                |b| b.iter(|| puzzle.check_solutions_parallel(&solution_refs, &epoch_challenge, 0u64).unwrap()),
+            );
+        }
+    }
+}
+
 criterion_group! {
     name = coinbase_puzzle;
     config = Criterion::default().sample_size(10);
-    targets = coinbase_puzzle_trim, coinbase_puzzle_prove, coinbase_puzzle_verify,
+    targets = coinbase_puzzle_trim, coinbase_puzzle_prove, coinbase_puzzle_verify, coinbase_puzzle_verify_parallel,
 }
 
 criterion_main!(coinbase_puzzle);
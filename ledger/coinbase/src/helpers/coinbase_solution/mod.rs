@@ -19,12 +19,15 @@ mod string;
 use super::*;
 
 use indexmap::IndexMap;
+use once_cell::sync::OnceCell;
 
 /// The coinbase puzzle solution is composed of individual prover solutions.
-#[derive(Clone, Eq, PartialEq)]
+#[derive(Clone)]
 pub struct CoinbaseSolution<N: Network> {
     /// The prover solutions for the coinbase puzzle.
     solutions: IndexMap<PuzzleCommitment<N>, ProverSolution<N>>,
+    /// The details of the prover solutions, computed on first access.
+    details: OnceCell<Vec<SolutionDetail<N>>>,
 }
 
 impl<N: Network> CoinbaseSolution<N> {
@@ -45,7 +48,10 @@ impl<N: Network> CoinbaseSolution<N> {
             bail!("The solutions contain duplicate puzzle commitments");
         }
         // Return the solutions.
-        Ok(Self { solutions: solutions.into_iter().map(|solution| (solution.commitment(), solution)).collect() })
+        Ok(Self {
+            solutions: solutions.into_iter().map(|solution| (solution.commitment(), solution)).collect(),
+            details: Default::default(),
+        })
     }
 
     /// Returns the puzzle commitments.
@@ -68,11 +74,26 @@ impl<N: Network> CoinbaseSolution<N> {
         self.solutions.get(puzzle_commitment)
     }
 
+    /// Returns the details of the prover solutions, without the proving rewards.
+    /// Note: The details are computed once, from the targets only, and cached for subsequent calls.
+    pub fn solution_details(&self) -> Result<&[SolutionDetail<N>]> {
+        self.details
+            .get_or_try_init(|| {
+                self.solutions
+                    .iter()
+                    .map(|(commitment, solution)| {
+                        Ok(SolutionDetail::new(*commitment, solution.address(), solution.to_target()?, None))
+                    })
+                    .collect()
+            })
+            .map(Vec::as_slice)
+    }
+
     /// Returns the combined sum of the prover solutions.
     pub fn to_combined_proof_target(&self) -> Result<u128> {
         // Compute the combined proof target as a u128.
-        self.solutions.values().try_fold(0u128, |combined, solution| {
-            combined.checked_add(solution.to_target()? as u128).ok_or_else(|| anyhow!("Combined target overflowed"))
+        self.solution_details()?.iter().try_fold(0u128, |combined, detail| {
+            combined.checked_add(detail.target() as u128).ok_or_else(|| anyhow!("Combined target overflowed"))
         })
     }
 
@@ -89,6 +110,15 @@ impl<N: Network> CoinbaseSolution<N> {
     }
 }
 
+impl<N: Network> PartialEq for CoinbaseSolution<N> {
+    /// Returns `true` if the prover solutions are equal.
+    fn eq(&self, other: &Self) -> bool {
+        self.solutions == other.solutions
+    }
+}
+
+impl<N: Network> Eq for CoinbaseSolution<N> {}
+
 impl<N: Network> Deref for CoinbaseSolution<N> {
     type Target = IndexMap<PuzzleCommitment<N>, ProverSolution<N>>;
 
@@ -96,3 +126,36 @@ impl<N: Network> Deref for CoinbaseSolution<N> {
         &self.solutions
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solution_details() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample random solutions.
+        let solutions = crate::helpers::coinbase_solution::serialize::tests::sample_solutions(&mut rng);
+        assert!(solutions.details.get().is_none());
+
+        // Ensure repeated calls return the same cached slice.
+        let details = solutions.solution_details()?;
+        assert!(std::ptr::eq(details, solutions.solution_details()?));
+
+        // Ensure the details match an independent computation.
+        assert_eq!(details.len(), solutions.len());
+        for (detail, (commitment, solution)) in details.iter().zip_eq(solutions.iter()) {
+            assert_eq!(detail.commitment(), *commitment);
+            assert_eq!(detail.address(), solution.address());
+            assert_eq!(detail.target(), solution.to_target()?);
+            assert_eq!(detail.reward(), None);
+        }
+
+        // Ensure the cached details do not affect equality.
+        let candidate = CoinbaseSolution::new(solutions.values().cloned().collect())?;
+        assert!(candidate.details.get().is_none());
+        assert_eq!(solutions, candidate);
+        Ok(())
+    }
+}
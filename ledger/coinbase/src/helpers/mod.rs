@@ -27,6 +27,9 @@ pub use prover_solution::*;
 mod puzzle_commitment;
 pub use puzzle_commitment::*;
 
+mod solution_detail;
+pub use solution_detail::*;
+
 use crate::{hash_commitment, hash_commitments, CoinbasePuzzle};
 use console::{account::Address, prelude::*, types::Field};
 use snarkvm_algorithms::{
@@ -40,6 +43,7 @@ use anyhow::Result;
 use std::{
     borrow::Cow,
     io::{Read, Result as IoResult, Write},
+    sync::Arc,
 };
 
 /// The proof of opening the polynomial, for the solution.
@@ -56,7 +60,7 @@ pub type CoinbaseVerifyingKey<N> = VerifierKey<<N as Environment>::PairingCurve>
 #[derive(Clone, Debug)]
 pub struct CoinbaseProvingKey<N: Network> {
     /// The key used to commit to polynomials in Lagrange basis.
-    pub lagrange_basis_at_beta_g: Vec<<N::PairingCurve as PairingEngine>::G1Affine>,
+    pub lagrange_basis_at_beta_g: Arc<Vec<<N::PairingCurve as PairingEngine>::G1Affine>>,
     /// Domain used to compute the product of the epoch polynomial and the prover polynomial.
     pub product_domain: EvaluationDomain<<N::PairingCurve as PairingEngine>::Fr>,
     /// Precomputation to speed up FFTs.
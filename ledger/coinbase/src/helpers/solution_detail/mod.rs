@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The details of a prover solution, which are derived without verifying the solution.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SolutionDetail<N: Network> {
+    /// The puzzle commitment of the solution.
+    commitment: PuzzleCommitment<N>,
+    /// The address of the prover.
+    address: Address<N>,
+    /// The proof target of the solution.
+    target: u64,
+    /// The proving reward attributed to the solution, if the puzzle reward is known.
+    reward: Option<u64>,
+}
+
+impl<N: Network> SolutionDetail<N> {
+    /// Initializes a new instance of the solution detail.
+    pub const fn new(commitment: PuzzleCommitment<N>, address: Address<N>, target: u64, reward: Option<u64>) -> Self {
+        Self { commitment, address, target, reward }
+    }
+
+    /// Returns the puzzle commitment of the solution.
+    pub const fn commitment(&self) -> PuzzleCommitment<N> {
+        self.commitment
+    }
+
+    /// Returns the address of the prover.
+    pub const fn address(&self) -> Address<N> {
+        self.address
+    }
+
+    /// Returns the proof target of the solution.
+    pub const fn target(&self) -> u64 {
+        self.target
+    }
+
+    /// Returns the proving reward attributed to the solution, if the puzzle reward is known.
+    pub const fn reward(&self) -> Option<u64> {
+        self.reward
+    }
+}
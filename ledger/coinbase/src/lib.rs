@@ -27,7 +27,7 @@ mod tests;
 
 use console::{
     account::Address,
-    prelude::{anyhow, bail, cfg_iter, ensure, has_duplicates, Network, Result, ToBytes},
+    prelude::{anyhow, bail, cfg_into_iter, cfg_iter, ensure, has_duplicates, Network, Result, ToBytes},
 };
 use snarkvm_algorithms::{
     fft::{DensePolynomial, EvaluationDomain},
@@ -38,7 +38,13 @@ use snarkvm_fields::Zero;
 use snarkvm_synthesizer_snark::UniversalSRS;
 
 use aleo_std::prelude::*;
-use std::sync::Arc;
+use std::{
+    collections::HashSet,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -186,16 +192,92 @@ impl<N: Network> CoinbasePuzzle<N> {
         lap!(timer, "Perform initial checks");
 
         // Verify each prover solution.
-        if !cfg_iter!(solutions).all(|(_, solution)| {
-            solution.verify(self.coinbase_verifying_key(), epoch_challenge, proof_target).unwrap_or(false)
-        }) {
-            bail!("The solutions contain an invalid prover solution");
-        }
+        self.check_solutions_parallel(&solutions.values().collect::<Vec<_>>(), epoch_challenge, proof_target)?;
         finish!(timer, "Verify each solution");
 
         Ok(())
     }
 
+    /// Checks the given prover solutions in parallel, and returns an error identifying the first invalid solution.
+    /// Once an invalid solution is found, the solutions after it are skipped, while the solutions before it are
+    /// still verified, so that the reported solution is always the invalid solution with the lowest index.
+    pub fn check_solutions_parallel(
+        &self,
+        solutions: &[&ProverSolution<N>],
+        epoch_challenge: &EpochChallenge<N>,
+        proof_target: u64,
+    ) -> Result<()> {
+        // Retrieve the coinbase verifying key.
+        let verifying_key = self.coinbase_verifying_key();
+        // Initialize the lowest index of an invalid solution.
+        let first_failure = AtomicUsize::new(usize::MAX);
+
+        // Verify each prover solution.
+        cfg_iter!(solutions).enumerate().for_each(|(index, solution)| {
+            // Skip the solution, if an invalid solution was already found before it.
+            if index > first_failure.load(Ordering::Relaxed) {
+                return;
+            }
+            // Verify the prover solution, and record the index if it is invalid.
+            if !solution.verify(verifying_key, epoch_challenge, proof_target).unwrap_or(false) {
+                first_failure.fetch_min(index, Ordering::Relaxed);
+            }
+        });
+
+        // Return the first invalid solution, if one was found.
+        match first_failure.into_inner() {
+            usize::MAX => Ok(()),
+            index => bail!("Prover solution {index} ('{}') is invalid", solutions[index].commitment()),
+        }
+    }
+
+    /// Selects the solutions for a block from the given candidate solutions, and returns the selected and dropped solutions.
+    /// The candidate solutions are deduplicated by puzzle commitment, sorted in descending order of proof target
+    /// (with ties broken by puzzle commitment), truncated to `N::MAX_PROVER_SOLUTIONS`, and verified.
+    /// Note: The selected solutions are deterministic for a given set of candidate solutions.
+    /// Note: At most `N::MAX_PROVER_SOLUTIONS` solutions are verified, so an invalid solution is not replaced.
+    pub fn select_solutions(
+        &self,
+        candidate_solutions: Vec<ProverSolution<N>>,
+        epoch_challenge: &EpochChallenge<N>,
+        proof_target: u64,
+    ) -> (Vec<ProverSolution<N>>, Vec<ProverSolution<N>>) {
+        // Initialize a list for the dropped solutions.
+        let mut dropped_solutions = Vec::new();
+
+        // Deduplicate the candidate solutions, and compute the proof target of each solution once.
+        let mut puzzle_commitments = HashSet::with_capacity(candidate_solutions.len());
+        let mut candidates = Vec::with_capacity(candidate_solutions.len());
+        for solution in candidate_solutions {
+            match (
+                puzzle_commitments.insert(solution.commitment()),
+                solution.to_target(),
+                solution.commitment().to_bytes_le(),
+            ) {
+                (true, Ok(target), Ok(commitment_bytes)) => candidates.push((target, commitment_bytes, solution)),
+                _ => dropped_solutions.push(solution),
+            }
+        }
+        // Sort the candidate solutions in descending order of proof target, with ties broken by puzzle commitment.
+        candidates.sort_unstable_by(|(target_a, bytes_a, _), (target_b, bytes_b, _)| {
+            target_b.cmp(target_a).then_with(|| bytes_a.cmp(bytes_b))
+        });
+
+        // Truncate the candidate solutions to the maximum number of solutions, before verifying them.
+        if candidates.len() > N::MAX_PROVER_SOLUTIONS {
+            dropped_solutions.extend(candidates.split_off(N::MAX_PROVER_SOLUTIONS).into_iter().map(|(_, _, s)| s));
+        }
+
+        // Verify the candidate solutions.
+        let verifying_key = self.coinbase_verifying_key();
+        let (solutions, invalid_solutions): (Vec<_>, Vec<_>) = cfg_into_iter!(candidates)
+            .map(|(_, _, solution)| solution)
+            .partition(|solution| solution.verify(verifying_key, epoch_challenge, proof_target).unwrap_or(false));
+        dropped_solutions.extend(invalid_solutions);
+
+        (solutions, dropped_solutions)
+    }
+
     /// Returns the coinbase proving key.
     pub fn coinbase_proving_key(&self) -> Result<&CoinbaseProvingKey<N>> {
         match self {
@@ -52,6 +52,113 @@ fn test_coinbase_puzzle() {
     }
 }
 
+#[test]
+fn test_check_solutions_parallel() {
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    let degree = (1 << 5) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    // Sample the prover solutions.
+    let mut solutions = (0..101)
+        .map(|_| {
+            let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+            let address = Address::try_from(private_key).unwrap();
+            let nonce = u64::rand(&mut rng);
+            puzzle.prove(&epoch_challenge, address, nonce, None).unwrap()
+        })
+        .collect::<Vec<_>>();
+    assert!(puzzle.check_solutions_parallel(&solutions.iter().collect::<Vec<_>>(), &epoch_challenge, 0u64).is_ok());
+
+    // A helper function to replace the nonce of a prover solution with a wrong nonce.
+    let with_wrong_nonce = |solution: &ProverSolution<Testnet3>| {
+        let partial_solution = PartialSolution::new(solution.address(), solution.nonce() + 1, solution.commitment());
+        ProverSolution::new(partial_solution, *solution.proof())
+    };
+
+    // Ensure the error identifies the solution with the wrong nonce.
+    solutions[57] = with_wrong_nonce(&solutions[57]);
+    let error =
+        puzzle.check_solutions_parallel(&solutions.iter().collect::<Vec<_>>(), &epoch_challenge, 0u64).unwrap_err();
+    assert_eq!(error.to_string(), format!("Prover solution 57 ('{}') is invalid", solutions[57].commitment()));
+
+    // Ensure the error identifies the first invalid solution, when there are several.
+    solutions[90] = with_wrong_nonce(&solutions[90]);
+    solutions[3] = with_wrong_nonce(&solutions[3]);
+    let error =
+        puzzle.check_solutions_parallel(&solutions.iter().collect::<Vec<_>>(), &epoch_challenge, 0u64).unwrap_err();
+    assert_eq!(error.to_string(), format!("Prover solution 3 ('{}') is invalid", solutions[3].commitment()));
+
+    // Ensure the checked verification path reports the same error.
+    let full_solution = CoinbaseSolution::new(solutions).unwrap();
+    let error = puzzle.check_solutions(&full_solution, &epoch_challenge, 0u64).unwrap_err();
+    assert!(error.to_string().starts_with("Prover solution 3 "));
+}
+
+#[test]
+fn test_select_solutions() {
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    let degree = (1 << 5) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    // Sample more prover solutions than are allowed in a block.
+    let num_solutions = <Testnet3 as Network>::MAX_PROVER_SOLUTIONS + 10;
+    let solutions = (0..num_solutions)
+        .map(|_| {
+            let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+            let address = Address::try_from(private_key).unwrap();
+            let nonce = u64::rand(&mut rng);
+            puzzle.prove(&epoch_challenge, address, nonce, None).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    // Ensure duplicate candidate solutions are dropped.
+    let candidates = [&solutions[..5], &solutions[..3]].concat();
+    let (selected, dropped) = puzzle.select_solutions(candidates, &epoch_challenge, 0u64);
+    assert_eq!(selected.len(), 5);
+    assert_eq!(dropped.len(), 3);
+    assert!(CoinbaseSolution::new(selected.clone()).is_ok());
+    // Ensure the selected solutions are sorted in descending order of proof target.
+    let targets = selected.iter().map(|solution| solution.to_target().unwrap()).collect::<Vec<_>>();
+    assert!(targets.windows(2).all(|pair| pair[0] >= pair[1]));
+
+    // Ensure the candidate solutions are truncated, keeping the solutions with the highest proof targets.
+    let (selected, dropped) = puzzle.select_solutions(solutions.clone(), &epoch_challenge, 0u64);
+    assert_eq!(selected.len(), <Testnet3 as Network>::MAX_PROVER_SOLUTIONS);
+    assert_eq!(dropped.len(), 10);
+    let min_selected_target = selected.iter().map(|solution| solution.to_target().unwrap()).min().unwrap();
+    assert!(dropped.iter().all(|solution| solution.to_target().unwrap() <= min_selected_target));
+    assert!(puzzle.check_solutions(&CoinbaseSolution::new(selected.clone()).unwrap(), &epoch_challenge, 0u64).is_ok());
+
+    // Ensure the selection is deterministic, regardless of the order of the candidate solutions.
+    let mut reversed = solutions.clone();
+    reversed.reverse();
+    let (selected_reversed, _) = puzzle.select_solutions(reversed, &epoch_challenge, 0u64);
+    assert_eq!(selected, selected_reversed);
+
+    // Ensure invalid candidate solutions are dropped, and do not take the place of valid solutions.
+    let bad_epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+    let (selected, dropped) = puzzle.select_solutions(solutions[..5].to_vec(), &bad_epoch_challenge, 0u64);
+    assert!(selected.is_empty());
+    assert_eq!(dropped.len(), 5);
+
+    // Ensure every candidate solution is dropped, including the truncated solutions, if they are all invalid.
+    let (selected, dropped) = puzzle.select_solutions(solutions, &bad_epoch_challenge, 0u64);
+    assert!(selected.is_empty());
+    assert_eq!(dropped.len(), num_solutions);
+}
+
 #[test]
 fn test_prover_solution_minimum_target() {
     let mut rng = TestRng::default();
@@ -0,0 +1,57 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use console::{network::Testnet3, prelude::*, types::Address};
+use snarkvm_ledger_committee::{test_helpers::sample_committee_for_round_and_size, Committee};
+
+use criterion::Criterion;
+
+type CurrentNetwork = Testnet3;
+
+fn is_committee_member(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    for num_members in [4, 100, Committee::<CurrentNetwork>::MAX_COMMITTEE_SIZE] {
+        // Sample the committee.
+        let committee = sample_committee_for_round_and_size(1, num_members, rng);
+        // Sample a member, and an address that is not a member.
+        let member = *committee.members().keys().last().unwrap();
+        let non_member = Address::<CurrentNetwork>::new(rng.gen());
+
+        c.bench_function(&format!("is_committee_member - member, {num_members} members"), |b| {
+            b.iter(|| committee.is_committee_member(member))
+        });
+        c.bench_function(&format!("is_committee_member - non-member, {num_members} members"), |b| {
+            b.iter(|| committee.is_committee_member(non_member))
+        });
+        // Compare against a lookup in the members map.
+        c.bench_function(&format!("members map lookup - member, {num_members} members"), |b| {
+            b.iter(|| committee.members().contains_key(&member))
+        });
+        c.bench_function(&format!("members map lookup - non-member, {num_members} members"), |b| {
+            b.iter(|| committee.members().contains_key(&non_member))
+        });
+    }
+}
+
+criterion_group! {
+    name = committee;
+    config = Criterion::default().sample_size(100);
+    targets = is_committee_member
+}
+
+criterion_main!(committee);
@@ -28,6 +28,10 @@ impl<N: Network> FromBytes for Committee<N> {
         let starting_round = u64::read_le(&mut reader)?;
         // Read the number of members.
         let num_members = u16::read_le(&mut reader)?;
+        // Ensure the number of members is within the bounds, before allocating for the members.
+        if num_members > Self::MAX_COMMITTEE_SIZE {
+            return Err(error(format!("Committee must have no more than {} members", Self::MAX_COMMITTEE_SIZE)));
+        }
         // Read the members.
         let mut members = IndexMap::with_capacity(num_members as usize);
         for _ in 0..num_members {
@@ -93,4 +97,26 @@ mod tests {
             assert!(Committee::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
         }
     }
+
+    #[test]
+    fn test_bytes_size_limit() {
+        let rng = &mut TestRng::default();
+
+        // Ensure a committee at the size limit is accepted.
+        let expected =
+            crate::test_helpers::sample_committee_custom(Committee::<CurrentNetwork>::MAX_COMMITTEE_SIZE, rng);
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        assert_eq!(expected, Committee::read_le(&expected_bytes[..]).unwrap());
+
+        // Ensure a committee above the size limit is rejected, without reading its members.
+        let mut candidate_bytes = vec![1u8];
+        candidate_bytes.extend(expected.starting_round().to_bytes_le().unwrap());
+        candidate_bytes.extend((Committee::<CurrentNetwork>::MAX_COMMITTEE_SIZE + 1).to_bytes_le().unwrap());
+        assert!(Committee::<CurrentNetwork>::read_le(&candidate_bytes[..]).is_err());
+        // Ensure the maximum number of members is rejected.
+        let mut candidate_bytes = vec![1u8];
+        candidate_bytes.extend(expected.starting_round().to_bytes_le().unwrap());
+        candidate_bytes.extend(u16::MAX.to_bytes_le().unwrap());
+        assert!(Committee::<CurrentNetwork>::read_le(&candidate_bytes[..]).is_err());
+    }
 }
@@ -26,7 +26,7 @@ use console::{
 };
 
 use indexmap::IndexMap;
-use std::collections::HashSet;
+use std::{collections::HashSet, ops::Range};
 
 /// The minimum amount of stake required for a validator to bond.
 pub const MIN_VALIDATOR_STAKE: u64 = 1_000_000_000_000u64; // microcredits
@@ -41,6 +41,8 @@ pub struct Committee<N: Network> {
     members: IndexMap<Address<N>, (u64, bool)>,
     /// The total stake of all `members`.
     total_stake: u64,
+    /// The affine coordinates of all `members` as (reduced) big integers, sorted for fast membership checks.
+    membership: Vec<(N::BigInteger, N::BigInteger)>,
 }
 
 impl<N: Network> Committee<N> {
@@ -72,8 +74,10 @@ impl<N: Network> Committee<N> {
         );
         // Compute the total stake of the committee for this round.
         let total_stake = Self::compute_total_stake(&members)?;
+        // Compute the sorted membership of the committee.
+        let membership = Self::compute_membership(&members);
         // Return the new committee.
-        Ok(Self { starting_round, members, total_stake })
+        Ok(Self { starting_round, members, total_stake, membership })
     }
 }
 
@@ -95,7 +99,7 @@ impl<N: Network> Committee<N> {
 
     /// Returns `true` if the given address is in the committee.
     pub fn is_committee_member(&self, address: Address<N>) -> bool {
-        self.members.contains_key(&address)
+        self.membership.binary_search(&Self::membership_key(&address)).is_ok()
     }
 
     /// Returns `true` if the given address is in the committee and is open.
@@ -193,6 +197,15 @@ impl<N: Network> Committee<N> {
         Ok(leader.unwrap())
     }
 
+    /// Returns the leader address for each round in the given range, in round order.
+    /// Note: Each entry matches the result of `get_leader` for the corresponding round.
+    pub fn leader_schedule(&self, rounds: Range<u64>) -> Result<Vec<Address<N>>> {
+        // Ensure the rounds start at or after the starting round.
+        ensure!(rounds.start >= self.starting_round, "Leader schedule must start at or after the starting round");
+        // Compute the leader for each round.
+        rounds.map(|round| self.get_leader(round)).collect()
+    }
+
     /// Returns the committee members sorted by stake in decreasing order.
     /// For members with matching stakes, we further sort by their address' x-coordinate in decreasing order.
     /// Note: This ensures the method returns a deterministic result that is SNARK-friendly.
@@ -220,6 +233,20 @@ impl<N: Network> Committee<N> {
         }
         Ok(power)
     }
+
+    /// Returns the affine coordinates of the given members as big integers, sorted for binary search.
+    fn compute_membership(members: &IndexMap<Address<N>, (u64, bool)>) -> Vec<(N::BigInteger, N::BigInteger)> {
+        let mut membership = members.keys().map(Self::membership_key).collect::<Vec<_>>();
+        membership.sort_unstable();
+        membership
+    }
+
+    /// Returns the affine coordinates of the given address as big integers.
+    /// Note: The coordinates are reduced once here, so that the comparisons in a binary search compare limbs only.
+    fn membership_key(address: &Address<N>) -> (N::BigInteger, N::BigInteger) {
+        let (x, y) = address.to_group().to_xy_coordinates();
+        (x.to_bigint(), y.to_bigint())
+    }
 }
 
 #[cfg(any(test, feature = "test-helpers"))]
@@ -357,6 +384,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_is_committee_member() {
+        let rng = &mut TestRng::default();
+
+        for committee in crate::test_helpers::sample_committees(rng) {
+            // Ensure every member is found, matching the lookup in the members map.
+            for address in committee.members().keys() {
+                assert!(committee.is_committee_member(*address));
+                assert_eq!(committee.members().contains_key(address), committee.is_committee_member(*address));
+            }
+            // Ensure random addresses and their negations are not found, matching the lookup in the members map.
+            for _ in 0..100 {
+                let address = Address::<CurrentNetwork>::new(rng.gen());
+                assert_eq!(committee.members().contains_key(&address), committee.is_committee_member(address));
+                let negation = Address::<CurrentNetwork>::new(-*committee.members().keys().next().unwrap().to_group());
+                assert!(!committee.is_committee_member(negation));
+                assert_eq!(committee.members().contains_key(&negation), committee.is_committee_member(negation));
+            }
+        }
+    }
+
     #[test]
     fn test_get_leader_distribution_simple() {
         // Initialize the RNG.
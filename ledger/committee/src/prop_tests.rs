@@ -201,3 +201,56 @@ fn invalid_stakes(#[strategy(too_low_stake_committee())] committee: Result<Commi
 fn invalid_member_count(#[strategy(too_small_committee())] committee: Result<Committee<CurrentNetwork>>) {
     assert!(matches!(committee, Err(e) if e.to_string().as_str() == "Committee must have at least 4 members"))
 }
+
+#[proptest(proptest::prelude::ProptestConfig::with_cases(16))]
+fn leader_schedule_is_independent_of_insertion_order(
+    input: CommitteeContext,
+    #[strategy(1u64..1_000_000)] start_round: u64,
+) {
+    let CommitteeContext(committee, ValidatorSet(validators)) = input;
+
+    // Construct the same committee with the members inserted in the reverse order.
+    let members = committee.members().iter().rev().map(|(address, state)| (*address, *state)).collect();
+    let reversed = Committee::new(committee.starting_round(), members).unwrap();
+    assert_eq!(reversed.num_members(), validators.len());
+
+    // Ensure both committees produce the same schedule, matching the leader of each round.
+    let rounds = start_round..start_round + 16;
+    let schedule = committee.leader_schedule(rounds.clone()).unwrap();
+    assert_eq!(schedule, reversed.leader_schedule(rounds.clone()).unwrap());
+    for (round, leader) in rounds.zip(schedule) {
+        assert_eq!(leader, committee.get_leader(round).unwrap());
+        assert!(committee.is_committee_member(leader));
+    }
+}
+
+#[proptest(proptest::prelude::ProptestConfig::with_cases(4))]
+fn leader_schedule_approximates_stake(input: CommitteeContext) {
+    // The number of rounds to sample.
+    const NUM_ROUNDS: u64 = 2_000;
+
+    let CommitteeContext(committee, _) = input;
+
+    // Count the number of rounds led by each member.
+    let mut counts = std::collections::HashMap::new();
+    for leader in committee.leader_schedule(1..NUM_ROUNDS + 1).unwrap() {
+        *counts.entry(leader).or_insert(0u64) += 1;
+    }
+
+    // Ensure the share of rounds led by each member is close to its share of the stake.
+    for (address, (stake, _)) in committee.members() {
+        let target = *stake as f64 / committee.total_stake() as f64;
+        let actual = counts.get(address).copied().unwrap_or_default() as f64 / NUM_ROUNDS as f64;
+        assert!((actual - target).abs() < 0.05, "Leader share {actual:.3} deviates from stake share {target:.3}");
+    }
+}
+
+#[proptest]
+fn leader_schedule_rejects_rounds_before_start(input: CommitteeContext) {
+    let CommitteeContext(committee, _) = input;
+
+    // Ensure the schedule cannot start before the starting round.
+    assert!(committee.leader_schedule(0..committee.starting_round() + 1).is_err());
+    // Ensure an empty range yields an empty schedule.
+    assert!(committee.leader_schedule(committee.starting_round()..committee.starting_round()).unwrap().is_empty());
+}
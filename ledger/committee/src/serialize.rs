@@ -14,6 +14,8 @@
 
 use super::*;
 
+use core::marker::PhantomData;
+
 impl<N: Network> Serialize for Committee<N> {
     /// Serializes the committee to a JSON-string or buffer.
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
@@ -35,13 +37,9 @@ impl<'de, N: Network> Deserialize<'de> for Committee<N> {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         match deserializer.is_human_readable() {
             true => {
-                let mut value = serde_json::Value::deserialize(deserializer)?;
-                let total_stake: u64 = DeserializeExt::take_from_value::<D>(&mut value, "total_stake")?;
-                let committee = Self::new(
-                    DeserializeExt::take_from_value::<D>(&mut value, "starting_round")?,
-                    DeserializeExt::take_from_value::<D>(&mut value, "members")?,
-                )
-                .map_err(de::Error::custom)?;
+                let committee = CommitteeJson::<N>::deserialize(deserializer)?;
+                let total_stake = committee.total_stake;
+                let committee = Self::new(committee.starting_round, committee.members).map_err(de::Error::custom)?;
                 match committee.total_stake == total_stake {
                     true => Ok(committee),
                     false => Err(de::Error::custom("total stake mismatch")),
@@ -52,10 +50,56 @@ impl<'de, N: Network> Deserialize<'de> for Committee<N> {
     }
 }
 
+/// The members of a committee, as a map of `address` to `(stake, is_open)` state.
+type Members<N> = IndexMap<Address<N>, (u64, bool)>;
+
+/// The human-readable representation of a committee.
+#[derive(Deserialize)]
+#[serde(bound = "")]
+struct CommitteeJson<N: Network> {
+    starting_round: u64,
+    #[serde(deserialize_with = "deserialize_bounded_members")]
+    members: Members<N>,
+    total_stake: u64,
+}
+
+/// Deserializes the members of a committee, ensuring there are no more than `MAX_COMMITTEE_SIZE` members.
+fn deserialize_bounded_members<'de, D: Deserializer<'de>, N: Network>(
+    deserializer: D,
+) -> Result<Members<N>, D::Error> {
+    struct MembersVisitor<N>(PhantomData<N>);
+
+    impl<'de, N: Network> Visitor<'de> for MembersVisitor<N> {
+        type Value = Members<N>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a map of no more than {} committee members", Committee::<N>::MAX_COMMITTEE_SIZE)
+        }
+
+        fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            // Retrieve the maximum number of members.
+            let max_members = Committee::<N>::MAX_COMMITTEE_SIZE as usize;
+            let mut members = IndexMap::with_capacity(map.size_hint().unwrap_or_default().min(max_members));
+            while let Some((address, member)) = map.next_entry::<Address<N>, (u64, bool)>()? {
+                // Ensure the number of members is within the bounds, before inserting the member.
+                if members.len() >= max_members {
+                    return Err(de::Error::custom(format!("Committee must have no more than {max_members} members")));
+                }
+                members.insert(address, member);
+            }
+            Ok(members)
+        }
+    }
+
+    deserializer.deserialize_map(MembersVisitor(PhantomData))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    type CurrentNetwork = console::network::Testnet3;
+
     fn check_serde_json<
         T: Serialize + for<'a> Deserialize<'a> + Debug + Display + PartialEq + Eq + FromStr + ToBytes + FromBytes,
     >(
@@ -106,4 +150,24 @@ mod tests {
             check_bincode(expected);
         }
     }
+
+    #[test]
+    fn test_serde_json_size_limit() {
+        let rng = &mut TestRng::default();
+
+        // Ensure a committee at the size limit is accepted.
+        let expected =
+            crate::test_helpers::sample_committee_custom(Committee::<CurrentNetwork>::MAX_COMMITTEE_SIZE, rng);
+        let mut value = serde_json::to_value(&expected).unwrap();
+        assert_eq!(expected, serde_json::from_value(value.clone()).unwrap());
+
+        // Ensure a committee above the size limit is rejected.
+        let address = Address::<CurrentNetwork>::new(rng.gen());
+        value["members"]
+            .as_object_mut()
+            .unwrap()
+            .insert(address.to_string(), serde_json::to_value((MIN_VALIDATOR_STAKE, false)).unwrap());
+        let error = serde_json::from_value::<Committee<CurrentNetwork>>(value).unwrap_err();
+        assert!(error.to_string().contains("no more than"));
+    }
 }
@@ -150,6 +150,12 @@ impl<N: Network> Hash for BatchCertificate<N> {
 impl<N: Network> BatchCertificate<N> {
     /// Returns the certificate ID.
     pub fn compute_certificate_id(batch_id: Field<N>, signatures: &IndexMap<Signature<N>, i64>) -> Result<Field<N>> {
+        // Hash the preimage.
+        N::hash_bhp1024(&Self::certificate_id_preimage(batch_id, signatures)?.to_bits_le())
+    }
+
+    /// Returns the preimage of the certificate ID.
+    fn certificate_id_preimage(batch_id: Field<N>, signatures: &IndexMap<Signature<N>, i64>) -> Result<Vec<u8>> {
         let mut preimage = Vec::new();
         // Insert the batch ID.
         batch_id.write_le(&mut preimage)?;
@@ -160,8 +166,7 @@ impl<N: Network> BatchCertificate<N> {
             // Insert the timestamp.
             timestamp.write_le(&mut preimage)?;
         }
-        // Hash the preimage.
-        N::hash_bhp1024(&preimage.to_bits_le())
+        Ok(preimage)
     }
 }
 
@@ -256,3 +261,35 @@ pub mod test_helpers {
         (certificate, previous_certificates)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_certificate_id_preimage() {
+        let rng = &mut TestRng::default();
+
+        for certificate in crate::test_helpers::sample_batch_certificates(rng) {
+            let preimage =
+                BatchCertificate::certificate_id_preimage(certificate.batch_id(), &certificate.signatures).unwrap();
+
+            // Ensure the preimage is the batch ID, followed by each `(signature, timestamp)` pair in order.
+            let (batch_id, mut remaining) = preimage.split_at(32);
+            assert_eq!(batch_id, certificate.batch_id().to_bytes_le().unwrap());
+            for (signature, timestamp) in &certificate.signatures {
+                let signature_bytes = signature.to_bytes_le().unwrap();
+                let (candidate_signature, rest) = remaining.split_at(signature_bytes.len());
+                assert_eq!(candidate_signature, signature_bytes);
+                let (candidate_timestamp, rest) = rest.split_at(8);
+                assert_eq!(candidate_timestamp, timestamp.to_le_bytes());
+                remaining = rest;
+            }
+            assert!(remaining.is_empty());
+
+            // Ensure the certificate ID is the BHP-1024 hash of the preimage.
+            let expected = <console::network::Testnet3 as Network>::hash_bhp1024(&preimage.to_bits_le()).unwrap();
+            assert_eq!(certificate.certificate_id(), expected);
+        }
+    }
+}
@@ -14,15 +14,34 @@
 
 use super::*;
 
+/// The version of the JSON representation of a batch certificate.
+const JSON_VERSION: u8 = 1;
+
+/// A `(signature, timestamp)` pair, serialized as a JSON object.
+struct SignatureEntry<'a, N: Network>(&'a Signature<N>, &'a i64);
+
+impl<'a, N: Network> Serialize for SignatureEntry<'a, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut entry = serializer.serialize_struct("SignatureEntry", 2)?;
+        entry.serialize_field("signature", self.0)?;
+        entry.serialize_field("timestamp", self.1)?;
+        entry.end()
+    }
+}
+
 impl<N: Network> Serialize for BatchCertificate<N> {
     /// Serializes the batch certificate to a JSON-string or buffer.
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut certificate = serializer.serialize_struct("BatchCertificate", 3)?;
+                // Note: The signatures are serialized as an array, to preserve their order in the certificate ID.
+                let signatures = self.signatures.iter().map(|(s, t)| SignatureEntry(s, t)).collect::<Vec<_>>();
+
+                let mut certificate = serializer.serialize_struct("BatchCertificate", 4)?;
+                certificate.serialize_field("version", &JSON_VERSION)?;
                 certificate.serialize_field("certificate_id", &self.certificate_id)?;
                 certificate.serialize_field("batch_header", &self.batch_header)?;
-                certificate.serialize_field("signatures", &self.signatures)?;
+                certificate.serialize_field("signatures", &signatures)?;
                 certificate.end()
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -36,10 +55,40 @@ impl<'de, N: Network> Deserialize<'de> for BatchCertificate<N> {
         match deserializer.is_human_readable() {
             true => {
                 let mut value = serde_json::Value::deserialize(deserializer)?;
+                // Retrieve the signatures.
+                let signatures = match value.get("version") {
+                    // The legacy representation stores the signatures as an object, keyed by signature.
+                    None => DeserializeExt::take_from_value::<D>(&mut value, "signatures")?,
+                    Some(_) => {
+                        // Ensure the version is supported.
+                        let version: u8 = DeserializeExt::take_from_value::<D>(&mut value, "version")?;
+                        if version != JSON_VERSION {
+                            return Err(de::Error::custom(format!("Unsupported batch certificate version {version}")));
+                        }
+                        // Retrieve the signature entries.
+                        let entries: Vec<serde_json::Value> =
+                            DeserializeExt::take_from_value::<D>(&mut value, "signatures")?;
+                        let num_entries = entries.len();
+                        let signatures = entries
+                            .into_iter()
+                            .map(|mut entry| {
+                                Ok((
+                                    DeserializeExt::take_from_value::<D>(&mut entry, "signature")?,
+                                    DeserializeExt::take_from_value::<D>(&mut entry, "timestamp")?,
+                                ))
+                            })
+                            .collect::<Result<IndexMap<Signature<N>, i64>, D::Error>>()?;
+                        // Ensure there are no duplicate signatures.
+                        if signatures.len() != num_entries {
+                            return Err(de::Error::custom("Found duplicate signatures in the batch certificate"));
+                        }
+                        signatures
+                    }
+                };
                 Ok(Self::from(
                     DeserializeExt::take_from_value::<D>(&mut value, "certificate_id")?,
                     DeserializeExt::take_from_value::<D>(&mut value, "batch_header")?,
-                    DeserializeExt::take_from_value::<D>(&mut value, "signatures")?,
+                    signatures,
                 )
                 .map_err(de::Error::custom)?)
             }
@@ -94,6 +143,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_serde_json_preserves_certificate_id() {
+        let rng = &mut TestRng::default();
+
+        for expected in crate::test_helpers::sample_batch_certificates(rng) {
+            let json = serde_json::to_value(&expected).unwrap();
+            // Ensure the JSON representation is versioned, and stores the signatures as an ordered array.
+            assert_eq!(json["version"], serde_json::json!(JSON_VERSION));
+            let entries = json["signatures"].as_array().unwrap();
+            assert_eq!(entries.len(), expected.signatures.len());
+            for (entry, (signature, timestamp)) in entries.iter().zip(&expected.signatures) {
+                assert_eq!(entry["signature"].as_str().unwrap(), signature.to_string());
+                assert_eq!(entry["timestamp"].as_i64().unwrap(), *timestamp);
+            }
+
+            // Ensure the deserialized certificate reproduces the certificate ID.
+            let candidate: BatchCertificate<_> = serde_json::from_value(json).unwrap();
+            assert_eq!(expected.certificate_id(), candidate.certificate_id());
+            assert_eq!(
+                expected.certificate_id(),
+                BatchCertificate::compute_certificate_id(candidate.batch_id(), &candidate.signatures).unwrap()
+            );
+            assert_eq!(expected, candidate);
+        }
+    }
+
+    #[test]
+    fn test_serde_json_legacy() {
+        let rng = &mut TestRng::default();
+
+        for expected in crate::test_helpers::sample_batch_certificates(rng) {
+            // Construct the legacy representation, with the signatures as an object.
+            let legacy = serde_json::json!({
+                "certificate_id": expected.certificate_id,
+                "batch_header": expected.batch_header,
+                "signatures": expected.signatures,
+            });
+            // Ensure the legacy representation is still accepted.
+            let candidate: BatchCertificate<_> = serde_json::from_value(legacy).unwrap();
+            assert_eq!(expected, candidate);
+        }
+    }
+
+    #[test]
+    fn test_serde_json_rejects_unsupported_version() {
+        let rng = &mut TestRng::default();
+
+        let certificate = crate::test_helpers::sample_batch_certificate(rng);
+        let mut json = serde_json::to_value(&certificate).unwrap();
+        json["version"] = serde_json::json!(JSON_VERSION + 1);
+        assert!(serde_json::from_value::<BatchCertificate<console::network::Testnet3>>(json).is_err());
+    }
+
     #[test]
     fn test_bincode() {
         let rng = &mut TestRng::default();
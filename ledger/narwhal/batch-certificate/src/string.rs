@@ -36,3 +36,36 @@ impl<N: Network> Display for BatchCertificate<N> {
         write!(f, "{}", serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_string() {
+        let rng = &mut TestRng::default();
+
+        for expected in crate::test_helpers::sample_batch_certificates(rng) {
+            // Check the string representation.
+            let candidate = format!("{expected}");
+            assert_eq!(expected, BatchCertificate::from_str(&candidate).unwrap());
+            assert!(BatchCertificate::<CurrentNetwork>::from_str(&candidate[1..]).is_err());
+            // Check that the debug representation matches the string representation.
+            assert_eq!(format!("{expected:?}"), candidate);
+        }
+    }
+
+    #[test]
+    fn test_string_is_consistent_with_bytes() {
+        let rng = &mut TestRng::default();
+
+        for expected in crate::test_helpers::sample_batch_certificates(rng) {
+            // Check that parsing the string representation yields the same bytes.
+            let candidate = BatchCertificate::<CurrentNetwork>::from_str(&expected.to_string()).unwrap();
+            assert_eq!(expected.to_bytes_le().unwrap(), candidate.to_bytes_le().unwrap());
+        }
+    }
+}
@@ -16,7 +16,15 @@ use super::*;
 
 impl<N: Network> FromBytes for BatchHeader<N> {
     /// Reads the batch header from the buffer.
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_le_with_ordering(reader, Self::STRICT_ORDERING)
+    }
+}
+
+impl<N: Network> BatchHeader<N> {
+    /// Reads the batch header from the buffer.
+    /// If `strict` is `true`, the transmission IDs and previous certificate IDs must be in canonical order.
+    pub fn read_le_with_ordering<R: Read>(mut reader: R, strict: bool) -> IoResult<Self> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
@@ -55,8 +63,16 @@ impl<N: Network> FromBytes for BatchHeader<N> {
         let signature = Signature::read_le(&mut reader)?;
 
         // Construct the batch.
-        let batch = Self::from(author, round, timestamp, transmission_ids, previous_certificate_ids, signature)
-            .map_err(|e| error(e.to_string()))?;
+        let batch = Self::from_with_ordering(
+            author,
+            round,
+            timestamp,
+            transmission_ids,
+            previous_certificate_ids,
+            signature,
+            strict,
+        )
+        .map_err(|e| error(e.to_string()))?;
 
         // Return the batch.
         match batch.batch_id == batch_id {
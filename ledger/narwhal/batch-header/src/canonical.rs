@@ -0,0 +1,231 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use core::hash::Hash;
+
+impl<N: Network> BatchHeader<N> {
+    /// If `true`, batch headers with non-canonically ordered sets are rejected when deserialized.
+    /// This is `false`, so that batch headers created before canonical ordering remain readable.
+    pub const STRICT_ORDERING: bool = false;
+
+    /// Returns the given transmission IDs in canonical order.
+    pub fn canonicalize_transmission_ids(
+        transmission_ids: IndexSet<TransmissionID<N>>,
+    ) -> Result<IndexSet<TransmissionID<N>>> {
+        canonicalize(transmission_ids)
+    }
+
+    /// Returns the given previous certificate IDs in canonical order.
+    pub fn canonicalize_certificate_ids(certificate_ids: IndexSet<Field<N>>) -> Result<IndexSet<Field<N>>> {
+        canonicalize(certificate_ids)
+    }
+
+    /// Returns `true` if the transmission IDs and previous certificate IDs are in canonical order.
+    pub fn is_canonical(&self) -> Result<bool> {
+        Ok(is_canonical(&self.transmission_ids)? && is_canonical(&self.previous_certificate_ids)?)
+    }
+}
+
+/// Returns the given set in canonical order, which sorts the elements by their byte representation.
+fn canonicalize<T: ToBytes + Hash + Eq>(set: IndexSet<T>) -> Result<IndexSet<T>> {
+    // Pair each element with its byte representation.
+    let mut elements =
+        set.into_iter().map(|element| Ok((element.to_bytes_le()?, element))).collect::<Result<Vec<_>>>()?;
+    // Sort the elements by their byte representation.
+    elements.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+    // Return the sorted set.
+    Ok(elements.into_iter().map(|(_, element)| element).collect())
+}
+
+/// Returns `true` if the given set is in canonical order.
+pub(crate) fn is_canonical<T: ToBytes>(set: &IndexSet<T>) -> Result<bool> {
+    // Retrieve the byte representation of each element.
+    let elements = set.iter().map(|element| element.to_bytes_le()).collect::<Result<Vec<_>>>()?;
+    // Ensure the elements are sorted by their byte representation.
+    Ok(elements.windows(2).all(|pair| pair[0] < pair[1]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns a copy of the given set, in a reversed and randomly rotated order.
+    fn shuffle<T: Clone + Hash + Eq>(set: &IndexSet<T>, rng: &mut TestRng) -> IndexSet<T> {
+        let mut elements = set.iter().rev().cloned().collect::<Vec<_>>();
+        if !elements.is_empty() {
+            let mid = rng.gen_range(0..elements.len());
+            elements.rotate_left(mid);
+        }
+        elements.into_iter().collect()
+    }
+
+    #[test]
+    fn test_canonicalize() {
+        let rng = &mut TestRng::default();
+
+        for expected in crate::test_helpers::sample_batch_headers(rng) {
+            // Ensure the sampled batch header is canonical.
+            assert!(expected.is_canonical().unwrap());
+
+            // Shuffle the transmission IDs and previous certificate IDs.
+            let transmission_ids = shuffle(expected.transmission_ids(), rng);
+            let previous_certificate_ids = shuffle(expected.previous_certificate_ids(), rng);
+
+            // Ensure canonicalization restores the original order.
+            let canonical_transmission_ids =
+                BatchHeader::canonicalize_transmission_ids(transmission_ids.clone()).unwrap();
+            let canonical_certificate_ids =
+                BatchHeader::canonicalize_certificate_ids(previous_certificate_ids.clone()).unwrap();
+            assert_eq!(
+                canonical_transmission_ids.iter().collect::<Vec<_>>(),
+                expected.transmission_ids().iter().collect::<Vec<_>>()
+            );
+            assert_eq!(
+                canonical_certificate_ids.iter().collect::<Vec<_>>(),
+                expected.previous_certificate_ids().iter().collect::<Vec<_>>()
+            );
+
+            // Ensure a batch header from the shuffled sets has the same batch ID.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+            let a = BatchHeader::new(
+                &private_key,
+                expected.round(),
+                expected.timestamp(),
+                transmission_ids,
+                previous_certificate_ids,
+                rng,
+            )
+            .unwrap();
+            let b = BatchHeader::new(
+                &private_key,
+                expected.round(),
+                expected.timestamp(),
+                canonical_transmission_ids,
+                canonical_certificate_ids,
+                rng,
+            )
+            .unwrap();
+            assert_eq!(a.batch_id(), b.batch_id());
+            assert!(a.is_canonical().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_strict_ordering() {
+        let rng = &mut TestRng::default();
+
+        // Sample a batch header with more than one previous certificate ID.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let expected = crate::test_helpers::sample_batch_header_for_round(2, rng);
+
+        // Construct a signed batch header with the previous certificate IDs in reverse order.
+        let reversed = expected.previous_certificate_ids().iter().rev().copied().collect::<IndexSet<_>>();
+        let author = Address::try_from(&private_key).unwrap();
+        let batch_id = BatchHeader::compute_batch_id(
+            author,
+            expected.round(),
+            expected.timestamp(),
+            expected.transmission_ids(),
+            &reversed,
+        )
+        .unwrap();
+        let signature = private_key.sign(&[batch_id], rng).unwrap();
+        let unsorted = BatchHeader::from(
+            author,
+            expected.round(),
+            expected.timestamp(),
+            expected.transmission_ids().clone(),
+            reversed,
+            signature,
+        )
+        .unwrap();
+        assert!(!unsorted.is_canonical().unwrap());
+
+        // Serialize the non-canonical batch header.
+        let bytes = unsorted.to_bytes_le().unwrap();
+        let string = serde_json::to_string(&unsorted).unwrap();
+
+        // Ensure the non-canonical batch header is accepted by default.
+        assert_eq!(unsorted, BatchHeader::read_le(&bytes[..]).unwrap());
+        assert_eq!(unsorted, serde_json::from_str(&string).unwrap());
+        assert_eq!(unsorted, BatchHeader::read_le_with_ordering(&bytes[..], false).unwrap());
+
+        // Ensure the non-canonical batch header is rejected under strict ordering.
+        assert!(BatchHeader::<CurrentNetwork>::read_le_with_ordering(&bytes[..], true).is_err());
+        let mut json = serde_json::Deserializer::from_str(&string);
+        assert!(BatchHeader::<CurrentNetwork>::deserialize_strict(&mut json).is_err());
+        let bincode_bytes = bincode::serialize(&unsorted).unwrap();
+        let options = {
+            use bincode::Options;
+            bincode::DefaultOptions::new().with_fixint_encoding().allow_trailing_bytes()
+        };
+        let mut bincode = bincode::Deserializer::from_slice(&bincode_bytes, options);
+        assert!(BatchHeader::<CurrentNetwork>::deserialize_strict(&mut bincode).is_err());
+        assert!(
+            BatchHeader::from_with_ordering(
+                author,
+                unsorted.round(),
+                unsorted.timestamp(),
+                unsorted.transmission_ids().clone(),
+                unsorted.previous_certificate_ids().clone(),
+                unsorted.signature,
+                true,
+            )
+            .is_err()
+        );
+
+        // Ensure the canonical batch header is accepted under strict ordering.
+        let canonical_bytes = expected.to_bytes_le().unwrap();
+        assert_eq!(expected, BatchHeader::read_le_with_ordering(&canonical_bytes[..], true).unwrap());
+        let canonical_string = serde_json::to_string(&expected).unwrap();
+        let mut json = serde_json::Deserializer::from_str(&canonical_string);
+        assert_eq!(expected, BatchHeader::deserialize_strict(&mut json).unwrap());
+    }
+
+    #[test]
+    fn test_canonical_order_is_pinned() {
+        // Sample the IDs, in an order that differs from their byte order.
+        let transaction_id = |id: u64| TransmissionID::Transaction(Field::<CurrentNetwork>::from_u64(id).into());
+        let transmission_ids =
+            [transaction_id(2), transaction_id(1), TransmissionID::Ratification, transaction_id(256)]
+                .into_iter()
+                .collect::<IndexSet<_>>();
+        let certificate_ids = [2u64, 1, 256, 3].into_iter().map(Field::from_u64).collect::<IndexSet<_>>();
+
+        // Ensure the IDs are sorted by their little-endian byte representation.
+        let expected_transmission_ids =
+            vec![TransmissionID::Ratification, transaction_id(256), transaction_id(1), transaction_id(2)];
+        let expected_certificate_ids = [256u64, 1, 2, 3].into_iter().map(Field::from_u64).collect::<Vec<_>>();
+        let transmission_ids = BatchHeader::canonicalize_transmission_ids(transmission_ids).unwrap();
+        let certificate_ids = BatchHeader::canonicalize_certificate_ids(certificate_ids).unwrap();
+        assert_eq!(transmission_ids.iter().copied().collect::<Vec<_>>(), expected_transmission_ids);
+        assert_eq!(certificate_ids.iter().copied().collect::<Vec<_>>(), expected_certificate_ids);
+
+        // Ensure the batch ID over the canonical order is pinned.
+        let rng = &mut TestRng::fixed(1);
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let batch_header =
+            BatchHeader::new(&private_key, 2, 1_700_000_000, transmission_ids, certificate_ids, rng).unwrap();
+        assert!(batch_header.is_canonical().unwrap());
+        assert_eq!(
+            batch_header.batch_id().to_string(),
+            "569920859277563115785674504268109595813121221116325911597174212779042100859field"
+        );
+    }
+}
@@ -16,6 +16,7 @@
 #![warn(clippy::cast_possible_truncation)]
 
 mod bytes;
+mod canonical;
 mod serialize;
 mod string;
 mod to_id;
@@ -48,6 +49,12 @@ pub struct BatchHeader<N: Network> {
 
 impl<N: Network> BatchHeader<N> {
     /// Initializes a new batch header.
+    ///
+    /// The transmission IDs and previous certificate IDs are sorted into canonical order,
+    /// so that the batch ID does not depend on the order in which they were received.
+    ///
+    /// Note: As the transmissions of a batch are ordered by their ID bytes, rather than in the given
+    /// order, the order of the transactions in a block built from the batch changes accordingly.
     pub fn new<R: Rng + CryptoRng>(
         private_key: &PrivateKey<N>,
         round: u64,
@@ -62,6 +69,9 @@ impl<N: Network> BatchHeader<N> {
             // If the round is not zero and not one, then there should be at least one previous certificate ID.
             _ => ensure!(!previous_certificate_ids.is_empty(), "Invalid round number, must have certificates"),
         }
+        // Sort the transmission IDs and previous certificate IDs into canonical order.
+        let transmission_ids = Self::canonicalize_transmission_ids(transmission_ids)?;
+        let previous_certificate_ids = Self::canonicalize_certificate_ids(previous_certificate_ids)?;
         // Retrieve the address.
         let author = Address::try_from(private_key)?;
         // Compute the batch ID.
@@ -80,6 +90,28 @@ impl<N: Network> BatchHeader<N> {
         transmission_ids: IndexSet<TransmissionID<N>>,
         previous_certificate_ids: IndexSet<Field<N>>,
         signature: Signature<N>,
+    ) -> Result<Self> {
+        Self::from_with_ordering(
+            author,
+            round,
+            timestamp,
+            transmission_ids,
+            previous_certificate_ids,
+            signature,
+            Self::STRICT_ORDERING,
+        )
+    }
+
+    /// Initializes a new batch header.
+    /// If `strict` is `true`, the transmission IDs and previous certificate IDs must be in canonical order.
+    pub fn from_with_ordering(
+        author: Address<N>,
+        round: u64,
+        timestamp: i64,
+        transmission_ids: IndexSet<TransmissionID<N>>,
+        previous_certificate_ids: IndexSet<Field<N>>,
+        signature: Signature<N>,
+        strict: bool,
     ) -> Result<Self> {
         match round {
             // If the round is zero or one, then there should be no previous certificate IDs.
@@ -87,6 +119,13 @@ impl<N: Network> BatchHeader<N> {
             // If the round is not zero and not one, then there should be at least one previous certificate ID.
             _ => ensure!(!previous_certificate_ids.is_empty(), "Invalid round number, must have certificates"),
         }
+        // If strict ordering is enabled, ensure the transmission IDs and previous certificate IDs are in canonical order.
+        if strict {
+            ensure!(
+                canonical::is_canonical(&transmission_ids)? && canonical::is_canonical(&previous_certificate_ids)?,
+                "Invalid batch header, the transmission IDs and previous certificate IDs must be in canonical order"
+            );
+        }
         // Compute the batch ID.
         let batch_id = Self::compute_batch_id(author, round, timestamp, &transmission_ids, &previous_certificate_ids)?;
         // Verify the signature.
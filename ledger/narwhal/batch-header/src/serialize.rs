@@ -37,19 +37,38 @@ impl<N: Network> Serialize for BatchHeader<N> {
 impl<'de, N: Network> Deserialize<'de> for BatchHeader<N> {
     #[inline]
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::deserialize_with_ordering(deserializer, Self::STRICT_ORDERING)
+    }
+}
+
+impl<N: Network> BatchHeader<N> {
+    /// Deserializes the batch header, with strict ordering.
+    /// This is intended for `#[serde(deserialize_with = "BatchHeader::deserialize_strict")]`.
+    #[inline]
+    pub fn deserialize_strict<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::deserialize_with_ordering(deserializer, true)
+    }
+
+    /// Deserializes the batch header.
+    /// If `strict` is `true`, the transmission IDs and previous certificate IDs must be in canonical order.
+    pub fn deserialize_with_ordering<'de, D: Deserializer<'de>>(
+        deserializer: D,
+        strict: bool,
+    ) -> Result<Self, D::Error> {
         match deserializer.is_human_readable() {
             true => {
                 let mut header = serde_json::Value::deserialize(deserializer)?;
                 let batch_id: Field<N> = DeserializeExt::take_from_value::<D>(&mut header, "batch_id")?;
 
                 // Recover the header.
-                let batch_header = Self::from(
+                let batch_header = Self::from_with_ordering(
                     DeserializeExt::take_from_value::<D>(&mut header, "author")?,
                     DeserializeExt::take_from_value::<D>(&mut header, "round")?,
                     DeserializeExt::take_from_value::<D>(&mut header, "timestamp")?,
                     DeserializeExt::take_from_value::<D>(&mut header, "transmission_ids")?,
                     DeserializeExt::take_from_value::<D>(&mut header, "previous_certificate_ids")?,
                     DeserializeExt::take_from_value::<D>(&mut header, "signature")?,
+                    strict,
                 )
                 .map_err(de::Error::custom)?;
 
@@ -62,7 +81,17 @@ impl<'de, N: Network> Deserialize<'de> for BatchHeader<N> {
                     }
                 }
             }
-            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "batch header"),
+            false => {
+                let batch_header: Self =
+                    FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "batch header")?;
+                // If strict ordering is enabled, ensure the transmission IDs and previous certificate IDs are in canonical order.
+                match !strict || batch_header.is_canonical().map_err(de::Error::custom)? {
+                    true => Ok(batch_header),
+                    false => Err(de::Error::custom(
+                        "Invalid batch header, the transmission IDs and previous certificate IDs must be in canonical order",
+                    )),
+                }
+            }
         }
     }
 }
@@ -140,6 +140,68 @@ impl<N: Network> Subdag<N> {
         self.leader_certificate().median_timestamp()
     }
 
+    /// Checks the subdag is consistent with the given previous (block) round and timestamp.
+    ///
+    /// This method ensures:
+    ///   1. The anchor round is even, and after the previous round.
+    ///   2. Every certificate is stored under its own round, and the anchor round contains only the leader certificate.
+    ///   3. The timestamp, the median timestamp of the leader certificate, is not before the previous timestamp.
+    ///   4. Every previous certificate ID resolves within the subdag, unless it is at or below the previous round.
+    pub fn sanity_check(&self, previous_round: u64, previous_timestamp: i64) -> Result<()> {
+        // Retrieve the anchor round.
+        let anchor_round = self.anchor_round();
+        // Ensure the anchor round is even.
+        ensure!(anchor_round % 2 == 0, "Subdag anchor round {anchor_round} must be even");
+        // Ensure the anchor round is after the previous round.
+        ensure!(
+            anchor_round > previous_round,
+            "Subdag anchor round {anchor_round} is not after the previous round {previous_round}"
+        );
+        // Ensure the anchor round contains only the leader certificate.
+        ensure!(
+            self.subdag.get(&anchor_round).map_or(0, |certificates| certificates.len()) == 1,
+            "Subdag anchor round {anchor_round} must contain exactly one leader certificate"
+        );
+
+        // Ensure the timestamp is not before the previous timestamp.
+        let timestamp = self.timestamp();
+        ensure!(
+            timestamp >= previous_timestamp,
+            "Subdag timestamp {timestamp} is before the previous timestamp {previous_timestamp}"
+        );
+
+        // Iterate over the certificates.
+        for (round, certificates) in &self.subdag {
+            for certificate in certificates {
+                // Ensure the certificate is stored under its own round.
+                ensure!(
+                    certificate.round() == *round,
+                    "Certificate '{}' is in subdag round {round}, but belongs to round {}",
+                    certificate.certificate_id(),
+                    certificate.round()
+                );
+                // Certificates from the previous round or earlier may already be committed, and are not in the subdag.
+                if round.saturating_sub(1) <= previous_round {
+                    continue;
+                }
+                // Retrieve the certificates from the preceding round.
+                let preceding = self.subdag.get(&(round - 1));
+                // Ensure every previous certificate ID resolves within the subdag.
+                for previous_certificate_id in certificate.previous_certificate_ids() {
+                    let is_resolved = preceding.map_or(false, |preceding| {
+                        preceding.iter().any(|previous| previous.certificate_id() == *previous_certificate_id)
+                    });
+                    ensure!(
+                        is_resolved,
+                        "Certificate '{}' in round {round} references the missing certificate '{previous_certificate_id}'",
+                        certificate.certificate_id()
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Returns the subdag root of the transactions.
     pub fn to_subdag_root(&self) -> Result<Field<N>> {
         // Prepare the leaves.
@@ -239,3 +301,70 @@ pub mod test_helpers {
         sample
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_anchor_round() {
+        let rng = &mut TestRng::default();
+
+        for subdag in crate::test_helpers::sample_subdags(rng) {
+            // Ensure the anchor round is the latest round, and is even.
+            let anchor_round = subdag.anchor_round();
+            assert_eq!(anchor_round, *subdag.keys().next_back().unwrap());
+            assert_eq!(anchor_round % 2, 0);
+            // Ensure the leader certificate is from the anchor round.
+            assert_eq!(subdag.leader_certificate().round(), anchor_round);
+            assert_eq!(subdag.timestamp(), subdag.leader_certificate().median_timestamp());
+        }
+    }
+
+    #[test]
+    fn test_sanity_check() {
+        let rng = &mut TestRng::default();
+
+        for subdag in crate::test_helpers::sample_subdags(rng) {
+            let starting_round = *subdag.keys().next().unwrap();
+            let anchor_round = subdag.anchor_round();
+            let timestamp = subdag.timestamp();
+
+            // Ensure a subdag committed after the preceding round is valid.
+            assert!(subdag.sanity_check(starting_round - 1, timestamp - 1).is_ok());
+            assert!(subdag.sanity_check(anchor_round - 1, timestamp - 1).is_ok());
+
+            // Ensure the anchor round must be after the previous round.
+            assert!(subdag.sanity_check(anchor_round, timestamp - 1).is_err());
+            assert!(subdag.sanity_check(anchor_round + 1, timestamp - 1).is_err());
+
+            // Ensure the timestamp must not be before the previous timestamp.
+            assert!(subdag.sanity_check(starting_round - 1, timestamp).is_ok());
+            assert!(subdag.sanity_check(starting_round - 1, timestamp + 1).is_err());
+
+            // Ensure the previous certificates of the earliest round must resolve, if they are not yet committed.
+            assert!(subdag.sanity_check(starting_round - 2, timestamp - 1).is_err());
+        }
+    }
+
+    #[test]
+    fn test_sanity_check_rejects_unresolved_previous_certificates() {
+        let rng = &mut TestRng::default();
+
+        // Sample a subdag, and drop a certificate from the middle round.
+        let subdag = crate::test_helpers::sample_subdag(rng);
+        let starting_round = *subdag.keys().next().unwrap();
+        let timestamp = subdag.timestamp();
+        let mut map = subdag.subdag.clone();
+        map.get_mut(&(starting_round + 1)).unwrap().pop();
+
+        // Note: The remaining structure still matches the commit, so the subdag is constructed successfully.
+        let candidate = Subdag::<CurrentNetwork>::from(map).unwrap();
+        // Ensure the missing certificate is detected.
+        let error = candidate.sanity_check(starting_round - 1, timestamp - 1).unwrap_err();
+        assert!(error.to_string().contains("references the missing certificate"));
+    }
+}
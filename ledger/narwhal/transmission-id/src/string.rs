@@ -19,7 +19,9 @@ impl<N: Network> FromStr for TransmissionID<N> {
 
     /// Initializes the transmission ID from a string.
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        if input.starts_with(PUZZLE_COMMITMENT_PREFIX) {
+        if input == "ratification" {
+            Ok(Self::Ratification)
+        } else if input.starts_with(PUZZLE_COMMITMENT_PREFIX) {
             Ok(Self::Solution(PuzzleCommitment::from_str(input)?))
         } else if input.starts_with(TRANSACTION_PREFIX) {
             Ok(Self::Transaction(
@@ -67,4 +69,29 @@ mod tests {
             assert!(TransmissionID::<CurrentNetwork>::from_str(&candidate[1..]).is_err());
         }
     }
+
+    #[test]
+    fn test_string_ratification() {
+        let expected = TransmissionID::<CurrentNetwork>::Ratification;
+        // Check the string representation.
+        let candidate = format!("{expected}");
+        assert_eq!("ratification", candidate);
+        assert_eq!(expected, TransmissionID::from_str(&candidate).unwrap());
+        assert!(TransmissionID::<CurrentNetwork>::from_str(&candidate[1..]).is_err());
+    }
+
+    #[test]
+    fn test_string_is_consistent_with_bytes() {
+        let rng = &mut TestRng::default();
+
+        let mut samples = crate::test_helpers::sample_transmission_ids(rng);
+        samples.push(TransmissionID::Ratification);
+        for expected in samples {
+            // Check that parsing the string representation yields the same bytes.
+            let candidate = TransmissionID::<CurrentNetwork>::from_str(&expected.to_string()).unwrap();
+            assert_eq!(expected.to_bytes_le().unwrap(), candidate.to_bytes_le().unwrap());
+            // Check that the debug representation matches the string representation.
+            assert_eq!(format!("{expected:?}"), format!("{expected}"));
+        }
+    }
 }
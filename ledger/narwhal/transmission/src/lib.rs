@@ -23,6 +23,7 @@ use console::prelude::*;
 use ledger_block::Transaction;
 use ledger_coinbase::ProverSolution;
 use ledger_narwhal_data::Data;
+use ledger_narwhal_transmission_id::TransmissionID;
 
 #[derive(Clone, PartialEq, Eq)]
 pub enum Transmission<N: Network> {
@@ -64,6 +65,31 @@ impl<N: Network> From<Data<Transaction<N>>> for Transmission<N> {
     }
 }
 
+impl<N: Network> Transmission<N> {
+    /// Returns `true` if the given transmission ID corresponds to this transmission.
+    ///
+    /// This method recomputes the puzzle commitment of a solution, or the transaction ID of a transaction,
+    /// and compares it against the given transmission ID. Mismatching variants return `false`.
+    pub fn matches(&self, transmission_id: &TransmissionID<N>) -> Result<bool> {
+        match (transmission_id, self) {
+            (TransmissionID::Ratification, Self::Ratification) => Ok(true),
+            (TransmissionID::Solution(commitment), Self::Solution(solution)) => {
+                // Deserialize the solution.
+                let solution = solution.clone().deserialize_blocking()?;
+                // Check the transmission ID corresponds to the solution.
+                Ok(*commitment == solution.commitment())
+            }
+            (TransmissionID::Transaction(transaction_id), Self::Transaction(transaction)) => {
+                // Deserialize the transaction.
+                let transaction = transaction.clone().deserialize_blocking()?;
+                // Check the transmission ID corresponds to the transaction.
+                Ok(*transaction_id == transaction.id())
+            }
+            _ => Ok(false),
+        }
+    }
+}
+
 #[cfg(any(test, feature = "test-helpers"))]
 pub mod test_helpers {
     use super::*;
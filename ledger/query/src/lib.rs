@@ -23,5 +23,8 @@ mod query;
 #[cfg(feature = "query")]
 pub use query::*;
 
+mod memory;
+pub use memory::*;
+
 mod traits;
 pub use traits::*;
@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::QueryTrait;
+use console::{network::prelude::*, program::StatePath, types::Field};
+
+use std::collections::HashMap;
+
+/// An in-memory query, seeded with a state root and the state paths for a set of commitments.
+#[derive(Clone)]
+pub struct MemoryQuery<N: Network> {
+    /// The current state root.
+    state_root: N::StateRoot,
+    /// The mapping of `commitment` to `state path`.
+    state_paths: HashMap<Field<N>, StatePath<N>>,
+}
+
+impl<N: Network> MemoryQuery<N> {
+    /// Initializes a new in-memory query with the given state root.
+    pub fn new(state_root: N::StateRoot) -> Self {
+        Self { state_root, state_paths: Default::default() }
+    }
+
+    /// Inserts the state path for the given `commitment`.
+    /// This method ensures the state path is for the given commitment and the current state root.
+    pub fn insert(&mut self, commitment: Field<N>, state_path: StatePath<N>) -> Result<()> {
+        ensure!(
+            state_path.global_state_root() == self.state_root,
+            "The state path for commitment '{commitment}' does not match the state root '{}'",
+            self.state_root
+        );
+        ensure!(
+            state_path.transition_leaf().id() == commitment,
+            "The state path does not correspond to commitment '{commitment}'"
+        );
+        self.state_paths.insert(commitment, state_path);
+        Ok(())
+    }
+
+    /// Returns the in-memory query, with the state path for the given `commitment` inserted.
+    pub fn with_state_path(mut self, commitment: Field<N>, state_path: StatePath<N>) -> Result<Self> {
+        self.insert(commitment, state_path)?;
+        Ok(self)
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait(?Send))]
+impl<N: Network> QueryTrait<N> for MemoryQuery<N> {
+    /// Returns the current state root.
+    fn current_state_root(&self) -> Result<N::StateRoot> {
+        Ok(self.state_root)
+    }
+
+    /// Returns the current state root.
+    #[cfg(feature = "async")]
+    async fn current_state_root_async(&self) -> Result<N::StateRoot> {
+        self.current_state_root()
+    }
+
+    /// Returns a state path for the given `commitment`.
+    fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        self.state_paths
+            .get(commitment)
+            .cloned()
+            .ok_or_else(|| anyhow!("Missing state path for commitment '{commitment}'"))
+    }
+
+    /// Returns a state path for the given `commitment`.
+    #[cfg(feature = "async")]
+    async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        self.get_state_path_for_commitment(commitment)
+    }
+}
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::QueryTrait;
+use crate::{MemoryQuery, QueryTrait};
 use console::{
     network::prelude::*,
     program::{ProgramID, StatePath},
@@ -27,6 +27,8 @@ pub enum Query<N: Network, B: BlockStorage<N>> {
     VM(BlockStore<N, B>),
     /// The base URL of the node.
     REST(String),
+    /// The in-memory query.
+    Memory(MemoryQuery<N>),
 }
 
 impl<N: Network, B: BlockStorage<N>> From<BlockStore<N, B>> for Query<N, B> {
@@ -41,6 +43,12 @@ impl<N: Network, B: BlockStorage<N>> From<&BlockStore<N, B>> for Query<N, B> {
     }
 }
 
+impl<N: Network, B: BlockStorage<N>> From<MemoryQuery<N>> for Query<N, B> {
+    fn from(memory_query: MemoryQuery<N>) -> Self {
+        Self::Memory(memory_query)
+    }
+}
+
 impl<N: Network, B: BlockStorage<N>> From<String> for Query<N, B> {
     fn from(url: String) -> Self {
         Self::REST(url)
@@ -69,6 +77,7 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
                 3 => Ok(Self::get_request(&format!("{url}/testnet3/latest/stateRoot"))?.into_json()?),
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
+            Self::Memory(memory_query) => memory_query.current_state_root(),
         }
     }
 
@@ -81,6 +90,7 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
                 3 => Ok(Self::get_request_async(&format!("{url}/testnet3/latest/stateRoot")).await?.json().await?),
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
+            Self::Memory(memory_query) => memory_query.current_state_root(),
         }
     }
 
@@ -92,6 +102,7 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
                 3 => Ok(Self::get_request(&format!("{url}/testnet3/statePath/{commitment}"))?.into_json()?),
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
+            Self::Memory(memory_query) => memory_query.get_state_path_for_commitment(commitment),
         }
     }
 
@@ -106,6 +117,7 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
                 }
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
+            Self::Memory(memory_query) => memory_query.get_state_path_for_commitment(commitment),
         }
     }
 }
@@ -121,6 +133,7 @@ impl<N: Network, B: BlockStorage<N>> Query<N, B> {
                 3 => Ok(Self::get_request(&format!("{url}/testnet3/program/{program_id}"))?.into_json()?),
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
+            Self::Memory(_) => bail!("Program {program_id} not found in the in-memory query"),
         }
     }
 
@@ -135,6 +148,7 @@ impl<N: Network, B: BlockStorage<N>> Query<N, B> {
                 3 => Ok(Self::get_request_async(&format!("{url}/testnet3/program/{program_id}")).await?.json().await?),
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
+            Self::Memory(_) => bail!("Program {program_id} not found in the in-memory query"),
         }
     }
 
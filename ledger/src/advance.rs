@@ -14,6 +14,10 @@
 
 use super::*;
 
+use anyhow::Context;
+use indexmap::IndexSet;
+use std::collections::HashSet;
+
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Returns a candidate for the next block in the ledger, using a committed subdag and its transmissions.
     pub fn prepare_advance_to_next_quorum_block(
@@ -24,13 +28,23 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Retrieve the latest block as the previous block (for the next block).
         let previous_block = self.latest_block();
 
+        // Ensure each transmission ID corresponds to its transmission, before performing any speculation.
+        check_transmissions(transmissions.iter())?;
+        // Ensure the subdag is consistent with the previous block.
+        subdag.sanity_check(previous_block.round(), previous_block.timestamp())?;
         // Decouple the transmissions into ratifications, solutions, and transactions.
         let (ratifications, solutions, transactions) = decouple_transmissions(transmissions.into_iter())?;
         // Currently, we do not support ratifications from the memory pool.
         ensure!(ratifications.is_empty(), "Ratifications are currently unsupported from the memory pool");
         // Construct the block template.
-        let (header, ratifications, solutions, transactions, aborted_transaction_ids) =
-            self.construct_block_template(&previous_block, Some(&subdag), ratifications, solutions, transactions)?;
+        let (header, ratifications, solutions, transactions, aborted_transaction_ids) = self.construct_block_template(
+            &previous_block,
+            Some(&subdag),
+            subdag.timestamp(),
+            ratifications,
+            solutions,
+            transactions,
+        )?;
 
         // Construct the new quorum block.
         Block::new_quorum(
@@ -52,6 +66,26 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         candidate_solutions: Vec<ProverSolution<N>>,
         candidate_transactions: Vec<Transaction<N>>,
         rng: &mut R,
+    ) -> Result<Block<N>> {
+        self.prepare_advance_to_next_beacon_block_at(
+            private_key,
+            OffsetDateTime::now_utc().unix_timestamp(),
+            candidate_ratifications,
+            candidate_solutions,
+            candidate_transactions,
+            rng,
+        )
+    }
+
+    /// Returns a candidate for the next block in the ledger, with the given timestamp.
+    pub(crate) fn prepare_advance_to_next_beacon_block_at<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        timestamp: i64,
+        candidate_ratifications: Vec<Ratify<N>>,
+        candidate_solutions: Vec<ProverSolution<N>>,
+        candidate_transactions: Vec<Transaction<N>>,
+        rng: &mut R,
     ) -> Result<Block<N>> {
         // Currently, we do not support ratifications from the memory pool.
         ensure!(candidate_ratifications.is_empty(), "Ratifications are currently unsupported from the memory pool");
@@ -63,6 +97,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let (header, ratifications, solutions, transactions, aborted_transaction_ids) = self.construct_block_template(
             &previous_block,
             None,
+            timestamp,
             candidate_ratifications,
             candidate_solutions,
             candidate_transactions,
@@ -83,11 +118,30 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
     /// Adds the given block as the next block in the ledger.
     pub fn advance_to_next_block(&self, block: &Block<N>) -> Result<()> {
+        // Ensure the storage is able to persist the block, before attempting to write it.
+        // Note: As the check performs a synced write, it is only performed once per interval, or after a failed write.
+        let is_check_due = match *self.storage_checked_at.read() {
+            Some(checked_at) => checked_at.elapsed() >= STORAGE_HEALTH_CHECK_INTERVAL,
+            None => true,
+        };
+        if is_check_due {
+            self.storage_health()
+                .ensure_healthy()
+                .with_context(|| format!("Refusing to advance to block {} - storage is unhealthy", block.height()))?;
+            *self.storage_checked_at.write() = Some(Instant::now());
+        }
+
         // Acquire the write lock on the current block.
         let mut current_block = self.current_block.write();
         // Update the VM.
-        self.vm.add_next_block(block)?;
+        if let Err(error) = self.vm.add_next_block(block) {
+            // Ensure the storage is checked before the next write.
+            *self.storage_checked_at.write() = None;
+            return Err(error);
+        }
         // Update the current block.
+        // Note: The new height is published only after the block and its finalize state have been committed
+        // in full, so that concurrent readers never observe a height whose block is not yet readable.
         *current_block = block.clone();
         // Drop the write lock on the current block.
         drop(current_block);
@@ -100,11 +154,43 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // If the block is the start of a new epoch, or the epoch challenge has not been set, update the current epoch challenge.
         if block.height() % N::NUM_BLOCKS_PER_EPOCH == 0 || self.current_epoch_challenge.read().is_none() {
             // Update the current epoch challenge.
-            self.current_epoch_challenge.write().clone_from(&self.get_epoch_challenge(block.height()).ok());
+            self.current_epoch_challenge.write().clone_from(&self.get_cached_epoch_challenge(block.height()).ok());
         }
 
         Ok(())
     }
+
+    /// Reverts the ledger to the given block height, and returns the detached blocks in ascending order of height.
+    /// This allows a node that followed a dead branch to roll back, instead of deleting its storage.
+    ///
+    /// Note: Only the most recent `VM::MAX_REVERTIBLE_BLOCKS` blocks can be reverted.
+    pub fn revert_to_height(&self, height: u32) -> Result<Vec<Block<N>>> {
+        // Acquire the write lock on the current block.
+        let mut current_block = self.current_block.write();
+        // Revert the VM.
+        // Note: The blocks are reverted atomically, but the cached state is updated from storage regardless of the result.
+        let result = self.vm.revert_to_height(height);
+        // Retrieve the latest block height in storage.
+        let latest_height = match self.vm.block_store().heights().max() {
+            Some(latest_height) => *latest_height,
+            None => bail!("Failed to revert to block {height} - there are no blocks in storage"),
+        };
+        // Update the current block.
+        *current_block = self.get_block(latest_height)?;
+        // Drop the write lock on the current block.
+        drop(current_block);
+
+        // Update the cached committee from storage.
+        *self.current_committee.write() = self.vm.finalize_store().committee_store().current_committee().ok();
+        // Remove the cached epoch challenges that depend on the detached blocks.
+        self.epoch_challenges.write().retain(|epoch_number, _| {
+            epoch_number.saturating_mul(N::NUM_BLOCKS_PER_EPOCH) <= latest_height.saturating_add(1)
+        });
+        // Update the current epoch challenge.
+        self.current_epoch_challenge.write().clone_from(&self.get_cached_epoch_challenge(latest_height).ok());
+
+        result
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
@@ -114,27 +200,36 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         &self,
         previous_block: &Block<N>,
         subdag: Option<&Subdag<N>>,
+        next_timestamp: i64,
         candidate_ratifications: Vec<Ratify<N>>,
         candidate_solutions: Vec<ProverSolution<N>>,
-        candidate_transactions: Vec<Transaction<N>>,
+        mut candidate_transactions: Vec<Transaction<N>>,
     ) -> Result<(Header<N>, Ratifications<N>, Option<CoinbaseSolution<N>>, Transactions<N>, Vec<N::TransactionID>)>
     {
-        // Construct the solutions.
-        let (solutions, solutions_root, combined_proof_target) = match candidate_solutions.is_empty() {
-            true => (None, Field::<N>::zero(), 0u128),
+        // Select the valid candidate solutions.
+        let valid_candidate_solutions = match candidate_solutions.is_empty() {
+            true => vec![],
             false => {
-                // Retrieve the coinbase verifying key.
-                let coinbase_verifying_key = self.coinbase_puzzle.coinbase_verifying_key();
                 // Retrieve the latest epoch challenge.
-                let latest_epoch_challenge = self.latest_epoch_challenge()?;
-                // Separate the candidate solutions into valid and aborted solutions.
+                let epoch_challenge = self.latest_epoch_challenge()?;
+                // Retrieve the latest proof target.
+                let proof_target = self.latest_proof_target();
+                // Deduplicate, sort, verify, and truncate the candidate solutions.
                 // TODO: Add `aborted_solution_ids` to the block.
-                let (valid_candidate_solutions, _aborted_candidate_solutions): (Vec<_>, Vec<_>) =
-                    cfg_into_iter!(candidate_solutions).partition(|solution| {
-                        solution
-                            .verify(coinbase_verifying_key, &latest_epoch_challenge, self.latest_proof_target())
-                            .unwrap_or(false)
-                    });
+                let (valid_solutions, aborted_solutions) =
+                    self.coinbase_puzzle.select_solutions(candidate_solutions, &epoch_challenge, proof_target);
+                // Log the aborted candidate solutions.
+                for solution in &aborted_solutions {
+                    debug!("Dropped candidate solution '{}' from the block template", solution.commitment());
+                }
+                valid_solutions
+            }
+        };
+
+        // Construct the solutions.
+        let (solutions, solutions_root, combined_proof_target) = match valid_candidate_solutions.is_empty() {
+            true => (None, Field::<N>::zero(), 0u128),
+            false => {
                 // Construct the solutions.
                 let solutions = CoinbaseSolution::new(valid_candidate_solutions)?;
                 // Compute the solutions root.
@@ -160,11 +255,6 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         };
         // Compute the next height.
         let next_height = previous_block.height().saturating_add(1);
-        // Determine the timestamp for the next block.
-        let next_timestamp = match subdag {
-            Some(subdag) => subdag.timestamp(),
-            None => OffsetDateTime::now_utc().unix_timestamp(),
-        };
         // Compute the next cumulative weight.
         let next_cumulative_weight = previous_block.cumulative_weight().saturating_add(combined_proof_target);
         // Compute the next cumulative proof target.
@@ -213,14 +303,59 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             next_cumulative_proof_target,
             previous_block.hash(),
         )?;
+
+        // Drop the candidate transactions that are copies of an earlier candidate transaction.
+        let mut candidate_ids = HashSet::with_capacity(candidate_transactions.len());
+        candidate_transactions.retain(|transaction| candidate_ids.insert(transaction.id()));
+
+        // Drop the candidate transactions that conflict with an earlier candidate transaction.
+        let mut conflicting_transaction_ids = IndexSet::new();
+        for conflict in Transactions::deduplicate_unconfirmed(&mut candidate_transactions) {
+            debug!("Dropped candidate transaction '{}' from the block template - {conflict}", conflict.duplicate());
+            conflicting_transaction_ids.insert(*conflict.duplicate());
+        }
+
         // Speculate over the ratifications, solutions, and transactions.
-        let (ratifications, transactions, aborted_transaction_ids, ratified_finalize_operations) = self.vm.speculate(
-            state,
-            Some(coinbase_reward),
-            candidate_ratifications,
-            solutions.as_ref(),
-            candidate_transactions.iter(),
-        )?;
+        // Note: A rejected transaction is confirmed as its fee only, so it may still conflict with an earlier
+        // confirmed transaction. Such a candidate is dropped, and the remaining candidates are speculated on again,
+        // up to `MAX_BLOCK_TEMPLATE_SPECULATIONS` times, after which the remaining candidates are aborted.
+        let mut num_speculations = 0;
+        let (ratifications, transactions, mut aborted_transaction_ids, ratified_finalize_operations) = loop {
+            let (ratifications, transactions, aborted_transaction_ids, ratified_finalize_operations) =
+                self.vm.speculate(
+                    state,
+                    Some(coinbase_reward),
+                    candidate_ratifications.clone(),
+                    solutions.as_ref(),
+                    candidate_transactions.iter(),
+                )?;
+            num_speculations += 1;
+            // If the confirmed transactions do not conflict, the speculation is complete.
+            if transactions.find_conflicts().is_empty() {
+                break (ratifications, transactions, aborted_transaction_ids, ratified_finalize_operations);
+            }
+            // If the speculation limit is reached, abort the remaining candidate transactions.
+            if num_speculations >= MAX_BLOCK_TEMPLATE_SPECULATIONS {
+                debug!("Dropped {} candidate transactions from the block template", candidate_transactions.len());
+                conflicting_transaction_ids
+                    .extend(candidate_transactions.drain(..).map(|transaction| transaction.id()));
+                continue;
+            }
+            // Find the confirmed transactions that conflict with an earlier kept confirmed transaction.
+            let conflicts = transactions.clone().deduplicate();
+            let mut duplicate_ids = IndexSet::with_capacity(conflicts.len());
+            for conflict in &conflicts {
+                debug!("Dropped candidate transaction '{}' from the block template - {conflict}", conflict.duplicate());
+                if let Some(transaction) = transactions.get(conflict.duplicate()) {
+                    duplicate_ids.insert(transaction.to_unconfirmed_transaction_id()?);
+                }
+            }
+            // Abort the conflicting candidate transactions, and speculate over the remaining candidate transactions.
+            candidate_transactions.retain(|transaction| !duplicate_ids.contains(&transaction.id()));
+            conflicting_transaction_ids.extend(duplicate_ids);
+        };
+        // Abort the conflicting candidate transactions.
+        aborted_transaction_ids.extend(conflicting_transaction_ids);
 
         // Compute the ratifications root.
         let ratifications_root = ratifications.to_ratifications_root()?;
@@ -232,18 +367,18 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         };
 
         // Construct the metadata.
-        let metadata = Metadata::new(
-            N::ID,
-            next_round,
-            next_height,
-            next_cumulative_weight,
-            next_cumulative_proof_target,
-            next_coinbase_target,
-            next_proof_target,
-            next_last_coinbase_target,
-            next_last_coinbase_timestamp,
-            next_timestamp,
-        )?;
+        let metadata = MetadataBuilder::new()
+            .round(next_round)
+            .height(next_height)
+            .cumulative_weight(next_cumulative_weight)
+            .cumulative_proof_target(next_cumulative_proof_target)
+            .coinbase_target(next_coinbase_target)
+            .proof_target(next_proof_target)
+            .last_coinbase_target(next_last_coinbase_target)
+            .last_coinbase_timestamp(next_last_coinbase_timestamp)
+            .timestamp(next_timestamp)
+            .previous(*previous_block.metadata())
+            .build()?;
 
         // Construct the header.
         let header = Header::from(
@@ -14,9 +14,156 @@
 
 use super::*;
 
+use ledger_block::{ExpectedAuthority, ExpectedSolutions};
+
+use std::time::{Duration, Instant};
+
+/// A stage of the check for the next block.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BlockCheckStage {
+    /// Checks the block is not already in the ledger, and the block hash is correct.
+    Header,
+    /// Checks the block authority.
+    Authority,
+    /// Checks the block solutions.
+    Solutions,
+    /// Checks the block ratifications and transactions.
+    Transactions,
+    /// Checks the speculation over the block, and the block header.
+    Finalize,
+}
+
+impl BlockCheckStage {
+    /// The stages, in the order they are checked.
+    pub const ALL: [Self; 5] = [Self::Header, Self::Authority, Self::Solutions, Self::Transactions, Self::Finalize];
+}
+
+impl Display for BlockCheckStage {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Header => write!(f, "header"),
+            Self::Authority => write!(f, "authority"),
+            Self::Solutions => write!(f, "solutions"),
+            Self::Transactions => write!(f, "transactions"),
+            Self::Finalize => write!(f, "finalize"),
+        }
+    }
+}
+
+/// The time taken by a stage of the check for the next block.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BlockCheckTiming {
+    /// The stage.
+    pub stage: BlockCheckStage,
+    /// The time taken by the stage.
+    pub duration: Duration,
+}
+
+/// The timings of the stages of the check for the next block.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BlockCheckReport {
+    /// The timings of the stages that were run, in order.
+    timings: Vec<BlockCheckTiming>,
+    /// The total time taken by the check.
+    total: Duration,
+}
+
+impl BlockCheckReport {
+    /// Returns the timings of the stages that were run, in order.
+    pub fn timings(&self) -> &[BlockCheckTiming] {
+        &self.timings
+    }
+
+    /// Returns the time taken by the given stage, if it was run.
+    pub fn duration(&self, stage: BlockCheckStage) -> Option<Duration> {
+        self.timings.iter().find(|timing| timing.stage == stage).map(|timing| timing.duration)
+    }
+
+    /// Returns the total time taken by the check.
+    pub const fn total(&self) -> Duration {
+        self.total
+    }
+}
+
+/// An error from the check for the next block, with the stage that failed.
+#[derive(Debug)]
+pub struct BlockCheckError {
+    /// The stage that failed.
+    stage: BlockCheckStage,
+    /// The timings of the stages up to, and including, the failed stage.
+    report: BlockCheckReport,
+    /// The error.
+    error: Error,
+}
+
+impl BlockCheckError {
+    /// Returns the stage that failed.
+    pub const fn stage(&self) -> BlockCheckStage {
+        self.stage
+    }
+
+    /// Returns the timings of the stages up to, and including, the failed stage.
+    pub const fn report(&self) -> &BlockCheckReport {
+        &self.report
+    }
+
+    /// Returns the error.
+    pub const fn error(&self) -> &Error {
+        &self.error
+    }
+
+    /// Returns the error, discarding the stage and timings.
+    pub fn into_error(self) -> Error {
+        self.error
+    }
+}
+
+impl Display for BlockCheckError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.error)
+    }
+}
+
+impl std::error::Error for BlockCheckError {}
+
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Checks the given block is valid next block.
     pub fn check_next_block(&self, block: &Block<N>) -> Result<()> {
+        self.check_next_block_with_report(block).map(|_| ()).map_err(BlockCheckError::into_error)
+    }
+
+    /// Checks the given block is valid next block, and returns the time taken by each stage of the check.
+    /// On failure, the error contains the stage that failed.
+    pub fn check_next_block_with_report(&self, block: &Block<N>) -> Result<BlockCheckReport, BlockCheckError> {
+        let start = Instant::now();
+        let mut report = BlockCheckReport::default();
+
+        // Retrieve the latest block as the previous block (for the next block).
+        let previous_block = self.latest_block();
+
+        // Check the block header.
+        run_stage(&mut report, start, BlockCheckStage::Header, || self.check_header(block, &previous_block))?;
+        // Check the block authority.
+        let expected_authority =
+            run_stage(&mut report, start, BlockCheckStage::Authority, || self.check_authority(block, &previous_block))?;
+        // Check the block solutions.
+        let expected_solutions =
+            run_stage(&mut report, start, BlockCheckStage::Solutions, || self.check_solutions(block, &previous_block))?;
+        // Check the block ratifications and transactions.
+        run_stage(&mut report, start, BlockCheckStage::Transactions, || {
+            self.check_transactions(block, expected_solutions)
+        })?;
+        // Check the speculation over the block, and the block header.
+        run_stage(&mut report, start, BlockCheckStage::Finalize, || {
+            self.check_finalize(block, expected_authority, expected_solutions)
+        })?;
+
+        report.total = start.elapsed();
+        Ok(report)
+    }
+
+    /// Ensures the block is not already in the ledger, and the block hash is correct.
+    fn check_header(&self, block: &Block<N>, previous_block: &Block<N>) -> Result<()> {
         let height = block.height();
 
         // Ensure the block hash does not already exist.
@@ -29,6 +176,17 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             bail!("Block height '{height}' already exists in the ledger")
         }
 
+        // Ensure the block hash is correct.
+        block.verify_hash(previous_block.height(), previous_block.hash())
+    }
+
+    /// Ensures the block authority is correct, and returns the expected round, height, and timestamp.
+    fn check_authority(&self, block: &Block<N>, previous_block: &Block<N>) -> Result<ExpectedAuthority> {
+        block.verify_authority(previous_block.round(), previous_block.height(), &self.latest_committee()?)
+    }
+
+    /// Ensures the block solutions are new and correct, and returns the expected values for the block header.
+    fn check_solutions(&self, block: &Block<N>, previous_block: &Block<N>) -> Result<ExpectedSolutions> {
         // Ensure the solutions do not already exist.
         if let Some(solutions) = block.solutions() {
             for puzzle_commitment in solutions.puzzle_commitments() {
@@ -38,14 +196,36 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             }
         }
 
+        // Ensure the block solutions are correct.
+        block.verify_solutions(previous_block, self.coinbase_puzzle(), &self.latest_epoch_challenge()?)
+    }
+
+    /// Ensures the block ratifications are correct, and each transaction is well-formed and unique.
+    fn check_transactions(&self, block: &Block<N>, expected_solutions: ExpectedSolutions) -> Result<()> {
         // Ensure each transaction is well-formed and unique.
-        // TODO: this intermediate allocation shouldn't be necessary; this is most likely https://github.com/rust-lang/rust/issues/89418.
-        let transactions = block.transactions().iter().collect::<Vec<_>>();
-        cfg_iter!(transactions).try_for_each(|transaction| {
-            self.check_transaction_basic(*transaction, transaction.to_rejected_id()?)
-                .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))
-        })?;
+        // Note: The executions are verified in a single batch across all of the transactions in the block.
+        let transactions = block
+            .transactions()
+            .iter()
+            .map(|transaction| Ok((&**transaction, transaction.to_rejected_id()?)))
+            .collect::<Result<Vec<_>>>()?;
+        self.vm
+            .check_transactions(&transactions)
+            .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))?;
+
+        // Ensure the block ratifications are correct.
+        block.verify_ratifications(expected_solutions.block_reward, expected_solutions.puzzle_reward)?;
+        // Ensure the block transactions are correct.
+        block.verify_transactions()
+    }
 
+    /// Ensures the speculation over the block, and the block header, are correct.
+    fn check_finalize(
+        &self,
+        block: &Block<N>,
+        expected_authority: ExpectedAuthority,
+        expected_solutions: ExpectedSolutions,
+    ) -> Result<()> {
         // TODO (howardwu): Remove this after moving the total supply into credits.aleo.
         {
             // // Retrieve the latest total supply.
@@ -82,17 +262,29 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let ratified_finalize_operations =
             self.vm.check_speculate(state, block.ratifications(), block.solutions(), block.transactions())?;
 
-        // Ensure the block is correct.
-        block.verify(
-            &self.latest_block(),
+        // Ensure the block header is correct.
+        block.verify_header(
             self.latest_state_root(),
-            &self.latest_committee()?,
-            self.coinbase_puzzle(),
-            &self.latest_epoch_challenge()?,
             OffsetDateTime::now_utc().unix_timestamp(),
             ratified_finalize_operations,
-        )?;
-
-        Ok(())
+            expected_authority,
+            expected_solutions,
+        )
     }
 }
+
+/// Runs the given stage of the check for the next block, and records its timing in the report.
+fn run_stage<T>(
+    report: &mut BlockCheckReport,
+    start: Instant,
+    stage: BlockCheckStage,
+    check: impl FnOnce() -> Result<T>,
+) -> Result<T, BlockCheckError> {
+    let stage_start = Instant::now();
+    let result = check();
+    report.timings.push(BlockCheckTiming { stage, duration: stage_start.elapsed() });
+    result.map_err(|error| {
+        report.total = start.elapsed();
+        BlockCheckError { stage, report: report.clone(), error }
+    })
+}
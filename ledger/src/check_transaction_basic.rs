@@ -16,7 +16,15 @@ use super::*;
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Checks the given transaction is well-formed and unique.
+    ///
+    /// Note: This check is for new transactions (e.g. in the mempool), and so a deployment must also pass the rules
+    /// for new deployments. The transactions in a block are checked by `VM::check_transaction` alone, so that
+    /// deployments accepted prior to these rules remain valid.
     pub fn check_transaction_basic(&self, transaction: &Transaction<N>, rejected_id: Option<Field<N>>) -> Result<()> {
+        // Ensure a deployment passes the checks for new deployments.
+        if let Transaction::Deploy(_, _, deployment, _) = transaction {
+            self.vm().process().read().check_new_deployment(deployment.program())?;
+        }
         self.vm().check_transaction(transaction, rejected_id)
     }
 }
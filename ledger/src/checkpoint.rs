@@ -0,0 +1,424 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ledger_store::atomic_batch_scope;
+use std::collections::HashSet;
+use tiny_keccak::{Hasher, Sha3};
+
+/// A checkpoint of the ledger at a given block height, from which a pruned ledger can be loaded,
+/// without the blocks below the checkpoint.
+#[derive(Clone, PartialEq, Eq)]
+pub struct LedgerCheckpoint<N: Network> {
+    /// The checkpoint block.
+    block: Block<N>,
+    /// The hashes of every block, up to and including the checkpoint block.
+    block_hashes: Vec<N::BlockHash>,
+    /// The state roots of every block, up to and including the checkpoint block.
+    state_roots: Vec<N::StateRoot>,
+    /// The transaction IDs of every block below the checkpoint block, including rejected and aborted transactions.
+    transaction_ids: Vec<Vec<N::TransactionID>>,
+    /// The `(serial number, tag)` pairs of the records spent below the checkpoint block.
+    spent: Vec<(Field<N>, Field<N>)>,
+    /// The finalize state of every program, as exported by `FinalizeStore::export_program_state`.
+    finalize_state: Vec<Vec<u8>>,
+    /// The deployment transactions for the programs in the finalize state.
+    deployments: Vec<Transaction<N>>,
+    /// The committee at the checkpoint block.
+    committee: Committee<N>,
+}
+
+impl<N: Network> LedgerCheckpoint<N> {
+    /// Initializes a new ledger checkpoint.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        block: Block<N>,
+        block_hashes: Vec<N::BlockHash>,
+        state_roots: Vec<N::StateRoot>,
+        transaction_ids: Vec<Vec<N::TransactionID>>,
+        spent: Vec<(Field<N>, Field<N>)>,
+        finalize_state: Vec<Vec<u8>>,
+        deployments: Vec<Transaction<N>>,
+        committee: Committee<N>,
+    ) -> Result<Self> {
+        // Ensure the block hashes end with the checkpoint block.
+        ensure!(
+            block_hashes.len() == block.height() as usize + 1 && block_hashes.last() == Some(&block.hash()),
+            "The block hashes do not end with the checkpoint block {}",
+            block.height()
+        );
+        // Ensure the state roots end with the previous state root of the checkpoint block, and its own state root.
+        ensure!(
+            state_roots.len() == block_hashes.len()
+                && state_roots.iter().rev().nth(1) == Some(&block.previous_state_root()),
+            "The state roots do not end with the checkpoint block {}",
+            block.height()
+        );
+        // Ensure there are transaction IDs for every block below the checkpoint block.
+        ensure!(
+            transaction_ids.len() == block.height() as usize,
+            "The transaction IDs do not end below the checkpoint block {}",
+            block.height()
+        );
+        // Ensure the deployments are all deployment transactions.
+        ensure!(deployments.iter().all(Transaction::is_deploy), "The checkpoint contains a non-deployment transaction");
+        // Ensure the committee starts at the checkpoint block round.
+        ensure!(
+            committee.starting_round() == block.round(),
+            "The committee does not start at the round of the checkpoint block {}",
+            block.height()
+        );
+        Ok(Self { block, block_hashes, state_roots, transaction_ids, spent, finalize_state, deployments, committee })
+    }
+
+    /// Returns the checkpoint block height.
+    pub fn height(&self) -> u32 {
+        self.block.height()
+    }
+
+    /// Returns the checkpoint block.
+    pub const fn block(&self) -> &Block<N> {
+        &self.block
+    }
+
+    /// Returns the hashes of every block, up to and including the checkpoint block.
+    pub fn block_hashes(&self) -> &[N::BlockHash] {
+        &self.block_hashes
+    }
+
+    /// Returns the state roots of every block, up to and including the checkpoint block.
+    pub fn state_roots(&self) -> &[N::StateRoot] {
+        &self.state_roots
+    }
+
+    /// Returns the transaction IDs of every block below the checkpoint block.
+    pub fn transaction_ids(&self) -> &[Vec<N::TransactionID>] {
+        &self.transaction_ids
+    }
+
+    /// Returns the `(serial number, tag)` pairs of the records spent below the checkpoint block.
+    pub fn spent(&self) -> &[(Field<N>, Field<N>)] {
+        &self.spent
+    }
+
+    /// Returns the finalize state of every program.
+    pub fn finalize_state(&self) -> &[Vec<u8>] {
+        &self.finalize_state
+    }
+
+    /// Returns the deployment transactions for the programs in the finalize state.
+    pub fn deployments(&self) -> &[Transaction<N>] {
+        &self.deployments
+    }
+
+    /// Returns the committee at the checkpoint block.
+    pub const fn committee(&self) -> &Committee<N> {
+        &self.committee
+    }
+
+    /// Returns the checkpoint hash, which commits to the entire checkpoint.
+    pub fn to_hash(&self) -> Result<Field<N>> {
+        /// A writer that absorbs the bytes written to it into a SHA3-256 hasher.
+        struct Sha3Writer(Sha3);
+
+        impl Write for Sha3Writer {
+            fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+                self.0.update(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> IoResult<()> {
+                Ok(())
+            }
+        }
+
+        // Note: The checkpoint is hashed with SHA-3 first, as it is too large to hash with BHP directly.
+        // The checkpoint bytes are streamed into the hasher, rather than collected and expanded into bits.
+        let mut writer = Sha3Writer(Sha3::v256());
+        self.write_le(&mut writer)?;
+        let mut digest = [0u8; 32];
+        writer.0.finalize(&mut digest);
+        N::hash_bhp1024(&digest.to_bits_le())
+    }
+}
+
+impl<N: Network> FromBytes for LedgerCheckpoint<N> {
+    /// Reads the ledger checkpoint from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid ledger checkpoint version"));
+        }
+
+        // Read the checkpoint block.
+        let block = Block::read_le(&mut reader)?;
+        // Read the block hashes.
+        // Note: The number of block hashes is implied by the checkpoint block height.
+        let block_hashes =
+            (0..=block.height()).map(|_| FromBytes::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the state roots.
+        // Note: The number of state roots is implied by the checkpoint block height.
+        let state_roots =
+            (0..=block.height()).map(|_| FromBytes::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the transaction IDs.
+        // Note: The number of transaction ID lists is implied by the checkpoint block height.
+        let mut transaction_ids = Vec::new();
+        for _ in 0..block.height() {
+            // Read the number of transaction IDs.
+            let num_transaction_ids = u32::read_le(&mut reader)?;
+            // Read the transaction IDs.
+            transaction_ids
+                .push((0..num_transaction_ids).map(|_| FromBytes::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?);
+        }
+        // Read the number of spent records.
+        let num_spent = u32::read_le(&mut reader)?;
+        // Read the spent records.
+        let spent = (0..num_spent)
+            .map(|_| Ok((FromBytes::read_le(&mut reader)?, FromBytes::read_le(&mut reader)?)))
+            .collect::<IoResult<Vec<_>>>()?;
+        // Read the number of program states.
+        let num_states = u32::read_le(&mut reader)?;
+        // Read the program states.
+        let mut finalize_state = Vec::new();
+        for _ in 0..num_states {
+            // Read the number of bytes.
+            let num_bytes = u32::read_le(&mut reader)?;
+            // Read the bytes.
+            let mut bytes = Vec::new();
+            (&mut reader).take(num_bytes as u64).read_to_end(&mut bytes)?;
+            // Ensure the program state is complete.
+            if bytes.len() != num_bytes as usize {
+                return Err(error("Incomplete program state in the ledger checkpoint"));
+            }
+            finalize_state.push(bytes);
+        }
+        // Read the number of deployments.
+        let num_deployments = u32::read_le(&mut reader)?;
+        // Read the deployments.
+        let deployments =
+            (0..num_deployments).map(|_| Transaction::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the committee.
+        let committee = Committee::read_le(&mut reader)?;
+
+        // Return the ledger checkpoint.
+        Self::new(block, block_hashes, state_roots, transaction_ids, spent, finalize_state, deployments, committee)
+            .map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for LedgerCheckpoint<N> {
+    /// Writes the ledger checkpoint to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the checkpoint block.
+        self.block.write_le(&mut writer)?;
+        // Write the block hashes.
+        for block_hash in &self.block_hashes {
+            block_hash.write_le(&mut writer)?;
+        }
+        // Write the state roots.
+        for state_root in &self.state_roots {
+            state_root.write_le(&mut writer)?;
+        }
+        // Write the transaction IDs.
+        for transaction_ids in &self.transaction_ids {
+            // Write the number of transaction IDs.
+            u32::try_from(transaction_ids.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+            // Write the transaction IDs.
+            for transaction_id in transaction_ids {
+                transaction_id.write_le(&mut writer)?;
+            }
+        }
+        // Write the number of spent records.
+        u32::try_from(self.spent.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the spent records.
+        for (serial_number, tag) in &self.spent {
+            serial_number.write_le(&mut writer)?;
+            tag.write_le(&mut writer)?;
+        }
+        // Write the number of program states.
+        u32::try_from(self.finalize_state.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the program states.
+        for bytes in &self.finalize_state {
+            // Write the number of bytes.
+            u32::try_from(bytes.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+            // Write the bytes.
+            writer.write_all(bytes)?;
+        }
+        // Write the number of deployments.
+        u32::try_from(self.deployments.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the deployments.
+        for deployment in &self.deployments {
+            deployment.write_le(&mut writer)?;
+        }
+        // Write the committee.
+        self.committee.write_le(&mut writer)
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Loads a pruned ledger from the given checkpoint, which must match the operator-supplied `trusted_hash`.
+    /// The storage must be empty. The checkpoint is stored in a single atomic batch,
+    /// so that the storage remains empty if the checkpoint fails to be stored.
+    ///
+    /// Note: The blocks below the checkpoint are unavailable, and return an `ErrorCode::Pruned` error.
+    pub fn load_from_checkpoint(
+        genesis_block: Block<N>,
+        checkpoint: LedgerCheckpoint<N>,
+        trusted_hash: Field<N>,
+        dev: Option<u16>,
+    ) -> Result<Self> {
+        let timer = timer!("Ledger::load_from_checkpoint");
+
+        // Ensure the checkpoint matches the trusted hash.
+        ensure!(
+            checkpoint.to_hash()? == trusted_hash,
+            "The checkpoint does not match the trusted hash '{trusted_hash}'"
+        );
+        // Ensure the checkpoint is above the genesis block.
+        ensure!(checkpoint.height() > 0, "The checkpoint must be above the genesis block");
+        // Ensure the checkpoint starts from the genesis block.
+        ensure!(
+            checkpoint.block_hashes().first() == Some(&genesis_block.hash()),
+            "The checkpoint does not start from the genesis block"
+        );
+        lap!(timer, "Verify the checkpoint");
+
+        // Initialize the consensus store.
+        let Ok(store) = ConsensusStore::<N, C>::open(dev) else {
+            bail!("Failed to load ledger (run 'snarkos clean' and try again)");
+        };
+        // Ensure the storage is empty.
+        ensure!(
+            store.block_store().heights().max().is_none(),
+            "Storage must be empty to load a checkpoint (run 'snarkos clean' and try again)"
+        );
+        lap!(timer, "Load consensus store");
+
+        atomic_batch_scope!(store, {
+            // Store the checkpoint block, along with the pruned blocks.
+            store.block_store().insert_checkpoint(
+                checkpoint.block_hashes(),
+                checkpoint.state_roots(),
+                checkpoint.transaction_ids(),
+                checkpoint.spent(),
+                checkpoint.block(),
+            )?;
+            // Store the committee.
+            let committee_store = store.finalize_store().committee_store();
+            committee_store.insert_checkpoint(checkpoint.height(), checkpoint.committee().clone())?;
+            // Store the finalize state.
+            for program_state in checkpoint.finalize_state() {
+                store.finalize_store().import_program_state(program_state.as_slice())?;
+            }
+            // Store the deployments, that are not already stored with the checkpoint block.
+            // Note: The checkpoint block is checked directly, as its transactions are not yet committed.
+            let block_transaction_ids = checkpoint.block().transaction_ids().collect::<HashSet<_>>();
+            for transaction in checkpoint.deployments() {
+                if !block_transaction_ids.contains(&transaction.id()) {
+                    store.transaction_store().insert(transaction)?;
+                }
+            }
+            Ok(())
+        })?;
+        lap!(timer, "Store the checkpoint");
+
+        // Initialize the ledger from the consensus store.
+        let ledger = Self::from_store(genesis_block, store)?;
+
+        finish!(timer, "Initialize ledger");
+        Ok(ledger)
+    }
+
+    /// Returns a checkpoint of the ledger at the given block height, from which a pruned ledger can be loaded.
+    /// As the finalize store only contains the latest state, the height must be the latest block height.
+    pub fn export_checkpoint(&self, height: u32) -> Result<LedgerCheckpoint<N>> {
+        // Acquire the read lock on the current block, to ensure the ledger does not advance during the export.
+        let block = self.current_block.read();
+        // Ensure the height is the latest block height.
+        ensure!(
+            height == block.height(),
+            "A checkpoint can only be exported at the latest block height {}",
+            block.height()
+        );
+
+        // Retrieve the block hashes.
+        let block_hashes = (0..=height).map(|height| self.get_hash(height)).collect::<Result<Vec<_>>>()?;
+        // Retrieve the state roots.
+        let state_roots = (0..=height)
+            .map(|height| match self.get_state_root(height)? {
+                Some(state_root) => Ok(state_root),
+                None => bail!("Missing state root for block {height}"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Retrieve the transaction IDs and spent records of the blocks below the checkpoint.
+        // Note: The blocks must be available, so a checkpoint can not be exported from a pruned ledger.
+        let mut transaction_ids = Vec::with_capacity(height as usize);
+        let mut spent = Vec::new();
+        for height in 0..height {
+            let pruned_block = self.get_block(height)?;
+            // Retrieve the confirmed, rejected, and aborted transaction IDs.
+            let mut ids = pruned_block.transaction_ids().copied().collect::<Vec<_>>();
+            for confirmed in pruned_block.transactions().iter().filter(|confirmed| confirmed.is_rejected()) {
+                ids.push(confirmed.to_unconfirmed_transaction_id()?);
+            }
+            ids.extend(pruned_block.aborted_transaction_ids());
+            transaction_ids.push(ids);
+            // Retrieve the spent records.
+            spent.extend(pruned_block.transitions().flat_map(Transition::inputs).filter_map(|input| match input {
+                Input::Record(serial_number, tag) => Some((*serial_number, *tag)),
+                _ => None,
+            }));
+        }
+
+        // Retrieve the program IDs, in canonical order.
+        let mut program_ids = self.vm.finalize_store().program_ids().map(|id| *id).collect::<Vec<_>>();
+        program_ids.sort_unstable_by_key(|program_id| program_id.to_string());
+
+        // Export the finalize state and deployment of every program.
+        let mut finalize_state = Vec::with_capacity(program_ids.len());
+        let mut deployments = Vec::with_capacity(program_ids.len());
+        for program_id in &program_ids {
+            // Export the finalize state.
+            let mut bytes = Vec::new();
+            self.vm.finalize_store().export_program_state(program_id, &mut bytes)?;
+            finalize_state.push(bytes);
+            // Retrieve the deployment, if the program is not built into the VM (e.g. 'credits.aleo').
+            if let Some(transaction_id) = self.vm.transaction_store().find_transaction_id_from_program_id(program_id)? {
+                deployments.push(self.get_transaction(transaction_id)?);
+            }
+        }
+
+        // Retrieve the committee.
+        let Some(committee) = self.get_committee(height)? else {
+            bail!("Missing committee for block {height}");
+        };
+
+        LedgerCheckpoint::new(
+            block.clone(),
+            block_hashes,
+            state_roots,
+            transaction_ids,
+            spent,
+            finalize_state,
+            deployments,
+            committee,
+        )
+    }
+}
@@ -0,0 +1,725 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A property-style harness for the `credits.aleo` microcredit invariants.
+//!
+//! The harness drives a ledger with randomized sequences of `credits.aleo` operations from a small set of
+//! accounts, and tracks the expected balances in a model. After every block, it asserts that:
+//!   1. The `account`, `bonded`, and `unbonding` mappings, and the decryptable records, match the model.
+//!   2. No value in the relevant mappings has underflowed (i.e. exceeds the total supply).
+//!   3. The committee stake of each validator, and the total stake, equals the sum of the bonded amounts.
+//!   4. The global supply equals the initial supply, minus the fees burned, plus the rewards minted.
+//!
+//! Every operation is appended to a log, which is included in the error on failure, so that a failing
+//! sequence can be reproduced from its seed, and shrunk by replaying a prefix of the logged blocks.
+
+use crate::{Ledger, RecordsFilter};
+use console::{
+    account::{Address, PrivateKey, ViewKey},
+    network::prelude::*,
+    program::{Entry, Identifier, Literal, Plaintext, ProgramID, Record, Value},
+    types::Field,
+};
+use ledger_block::{Block, Ratify, Transaction};
+use ledger_committee::{MIN_DELEGATOR_STAKE, MIN_VALIDATOR_STAKE};
+use ledger_store::ConsensusStorage;
+
+use indexmap::IndexMap;
+
+/// A `credits.aleo` operation, where accounts are referenced by their index in the harness.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CreditsOperation<N: Network> {
+    /// A `transfer_public` from the sender to the receiver.
+    TransferPublic { sender: usize, receiver: usize, amount: u64 },
+    /// A `transfer_public_to_private` from the sender to the receiver.
+    TransferPublicToPrivate { sender: usize, receiver: usize, amount: u64 },
+    /// A `transfer_private_to_public` from a record of the sender to the receiver.
+    TransferPrivateToPublic { sender: usize, receiver: usize, amount: u64 },
+    /// A `bond_public` from the staker to the validator.
+    BondPublic { staker: usize, validator: Address<N>, amount: u64 },
+    /// An `unbond_public` from the staker.
+    UnbondPublic { staker: usize, amount: u64 },
+    /// A `claim_unbond_public` from the staker.
+    ClaimUnbondPublic { staker: usize },
+}
+
+impl<N: Network> CreditsOperation<N> {
+    /// Returns the index of the account that signs (and pays the fee for) the operation.
+    pub const fn caller(&self) -> usize {
+        match self {
+            Self::TransferPublic { sender, .. }
+            | Self::TransferPublicToPrivate { sender, .. }
+            | Self::TransferPrivateToPublic { sender, .. } => *sender,
+            Self::BondPublic { staker, .. }
+            | Self::UnbondPublic { staker, .. }
+            | Self::ClaimUnbondPublic { staker } => *staker,
+        }
+    }
+
+    /// Returns the name of the `credits.aleo` function for the operation.
+    pub const fn function_name(&self) -> &'static str {
+        match self {
+            Self::TransferPublic { .. } => "transfer_public",
+            Self::TransferPublicToPrivate { .. } => "transfer_public_to_private",
+            Self::TransferPrivateToPublic { .. } => "transfer_private_to_public",
+            Self::BondPublic { .. } => "bond_public",
+            Self::UnbondPublic { .. } => "unbond_public",
+            Self::ClaimUnbondPublic { .. } => "claim_unbond_public",
+        }
+    }
+}
+
+impl<N: Network> Display for CreditsOperation<N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::TransferPublic { sender, receiver, amount }
+            | Self::TransferPublicToPrivate { sender, receiver, amount }
+            | Self::TransferPrivateToPublic { sender, receiver, amount } => {
+                write!(f, "{} (#{sender} -> #{receiver}, {amount} microcredits)", self.function_name())
+            }
+            Self::BondPublic { staker, validator, amount } => {
+                write!(f, "bond_public (#{staker} -> {validator}, {amount} microcredits)")
+            }
+            Self::UnbondPublic { staker, amount } => write!(f, "unbond_public (#{staker}, {amount} microcredits)"),
+            Self::ClaimUnbondPublic { staker } => write!(f, "claim_unbond_public (#{staker})"),
+        }
+    }
+}
+
+/// The model of the expected `credits.aleo` balances.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreditsModel<N: Network> {
+    /// The expected public balances, as in the `account` mapping.
+    public: IndexMap<Address<N>, u64>,
+    /// The expected private balances of the tracked accounts.
+    private: IndexMap<Address<N>, u64>,
+    /// The expected bonds, as in the `bonded` mapping, of the form `staker => (validator, microcredits)`.
+    bonded: IndexMap<Address<N>, (Address<N>, u64)>,
+    /// The expected unbonding amounts, as in the `unbonding` mapping.
+    unbonding: IndexMap<Address<N>, u64>,
+    /// The initial supply, in microcredits.
+    initial_supply: u64,
+    /// The total fees burned, in microcredits.
+    fees_burned: u64,
+    /// The total rewards minted, in microcredits.
+    rewards_minted: u64,
+}
+
+impl<N: Network> CreditsModel<N> {
+    /// Returns the expected supply, in microcredits.
+    pub fn supply(&self) -> Result<u64> {
+        self.initial_supply
+            .checked_sub(self.fees_burned)
+            .and_then(|supply| supply.checked_add(self.rewards_minted))
+            .ok_or_else(|| anyhow!("The expected supply overflowed"))
+    }
+
+    /// Returns the total fees burned, in microcredits.
+    pub const fn fees_burned(&self) -> u64 {
+        self.fees_burned
+    }
+
+    /// Returns the total rewards minted, in microcredits.
+    pub const fn rewards_minted(&self) -> u64 {
+        self.rewards_minted
+    }
+
+    /// Returns the expected public balance for the given address.
+    pub fn public_balance(&self, address: &Address<N>) -> u64 {
+        self.public.get(address).copied().unwrap_or_default()
+    }
+
+    /// Returns the expected private balance for the given address.
+    pub fn private_balance(&self, address: &Address<N>) -> u64 {
+        self.private.get(address).copied().unwrap_or_default()
+    }
+
+    /// Returns the expected bond for the given address.
+    pub fn bond(&self, address: &Address<N>) -> Option<(Address<N>, u64)> {
+        self.bonded.get(address).copied()
+    }
+
+    /// Returns the expected unbonding amount for the given address.
+    pub fn unbonding(&self, address: &Address<N>) -> u64 {
+        self.unbonding.get(address).copied().unwrap_or_default()
+    }
+
+    /// Returns the expected stake of each validator, as the sum of the amounts bonded to it.
+    pub fn validator_stakes(&self) -> IndexMap<Address<N>, u64> {
+        let mut stakes = IndexMap::new();
+        for (validator, amount) in self.bonded.values() {
+            let stake: &mut u64 = stakes.entry(*validator).or_default();
+            *stake = stake.saturating_add(*amount);
+        }
+        stakes
+    }
+
+    /// Debits the given amount from the given balances, failing if the balance would underflow.
+    fn debit(balances: &mut IndexMap<Address<N>, u64>, address: Address<N>, amount: u64, kind: &str) -> Result<()> {
+        let balance = balances.entry(address).or_default();
+        *balance = balance.checked_sub(amount).ok_or_else(|| {
+            anyhow!("The {kind} balance of {address} underflowed ({balance} - {amount}) in an accepted operation")
+        })?;
+        Ok(())
+    }
+
+    /// Credits the given amount to the given balances, failing if the balance would overflow.
+    fn credit(balances: &mut IndexMap<Address<N>, u64>, address: Address<N>, amount: u64, kind: &str) -> Result<()> {
+        let balance = balances.entry(address).or_default();
+        *balance = balance
+            .checked_add(amount)
+            .ok_or_else(|| anyhow!("The {kind} balance of {address} overflowed ({balance} + {amount})"))?;
+        Ok(())
+    }
+
+    /// Applies the fee paid by the given address.
+    fn apply_fee(&mut self, payer: Address<N>, fee: u64) -> Result<()> {
+        Self::debit(&mut self.public, payer, fee, "public")?;
+        self.fees_burned = self.fees_burned.checked_add(fee).ok_or_else(|| anyhow!("The fees burned overflowed"))?;
+        Ok(())
+    }
+
+    /// Applies the given accepted operation, where `accounts` are the addresses of the tracked accounts.
+    fn apply_operation(&mut self, operation: &CreditsOperation<N>, accounts: &[Address<N>]) -> Result<()> {
+        match *operation {
+            CreditsOperation::TransferPublic { sender, receiver, amount } => {
+                Self::debit(&mut self.public, accounts[sender], amount, "public")?;
+                Self::credit(&mut self.public, accounts[receiver], amount, "public")
+            }
+            CreditsOperation::TransferPublicToPrivate { sender, receiver, amount } => {
+                Self::debit(&mut self.public, accounts[sender], amount, "public")?;
+                Self::credit(&mut self.private, accounts[receiver], amount, "private")
+            }
+            CreditsOperation::TransferPrivateToPublic { sender, receiver, amount } => {
+                Self::debit(&mut self.private, accounts[sender], amount, "private")?;
+                Self::credit(&mut self.public, accounts[receiver], amount, "public")
+            }
+            CreditsOperation::BondPublic { staker, validator, amount } => {
+                let staker = accounts[staker];
+                Self::debit(&mut self.public, staker, amount, "public")?;
+                let bonded = self.bond(&staker).map(|(_, bonded)| bonded).unwrap_or_default();
+                let bonded = bonded.checked_add(amount).ok_or_else(|| anyhow!("The bond of {staker} overflowed"))?;
+                self.bonded.insert(staker, (validator, bonded));
+                Ok(())
+            }
+            CreditsOperation::UnbondPublic { staker, amount } => {
+                let staker = accounts[staker];
+                let (validator, bonded) =
+                    self.bond(&staker).ok_or_else(|| anyhow!("An unbond was accepted for {staker} without a bond"))?;
+                let remaining = bonded
+                    .checked_sub(amount)
+                    .ok_or_else(|| anyhow!("The bond of {staker} underflowed ({bonded} - {amount})"))?;
+                // Determine the minimum remaining bond, below which the entire bond is unbonded.
+                let minimum = match validator == staker {
+                    true => MIN_VALIDATOR_STAKE,
+                    false => MIN_DELEGATOR_STAKE,
+                };
+                match remaining >= minimum {
+                    true => {
+                        self.bonded.insert(staker, (validator, remaining));
+                        Self::credit(&mut self.unbonding, staker, amount, "unbonding")
+                    }
+                    false => {
+                        self.bonded.shift_remove(&staker);
+                        Self::credit(&mut self.unbonding, staker, bonded, "unbonding")
+                    }
+                }
+            }
+            CreditsOperation::ClaimUnbondPublic { staker } => {
+                let staker = accounts[staker];
+                let unbonding = self.unbonding.shift_remove(&staker).unwrap_or_default();
+                Self::credit(&mut self.public, staker, unbonding, "public")
+            }
+        }
+    }
+
+    /// Applies the staking rewards for the given block reward.
+    ///
+    /// Note: This is an independent re-statement of the staking reward rule, which stakers bonded to
+    /// a validator with more than 25% of the total stake, or with less than the minimum delegator stake,
+    /// are not eligible for.
+    fn apply_block_reward(&mut self, block_reward: u64) -> Result<()> {
+        let stakes = self.validator_stakes();
+        let total_stake = stakes.values().fold(0u64, |total, stake| total.saturating_add(*stake));
+        if total_stake == 0 || block_reward == 0 {
+            return Ok(());
+        }
+        for (validator, bonded) in self.bonded.values_mut() {
+            if stakes.get(&*validator).copied().unwrap_or_default() > total_stake / 4 || *bonded < MIN_DELEGATOR_STAKE {
+                continue;
+            }
+            let reward = (block_reward as u128) * (*bonded as u128) / (total_stake as u128);
+            if reward > ledger_block::MAX_COINBASE_REWARD as u128 {
+                continue;
+            }
+            let reward = u64::try_from(reward)?;
+            *bonded = bonded.checked_add(reward).ok_or_else(|| anyhow!("A bond overflowed from a staking reward"))?;
+            self.rewards_minted =
+                self.rewards_minted.checked_add(reward).ok_or_else(|| anyhow!("The rewards minted overflowed"))?;
+        }
+        Ok(())
+    }
+}
+
+/// A snapshot of the `credits.aleo` state in the ledger.
+struct CreditsSnapshot<N: Network> {
+    /// The `account` mapping.
+    public: IndexMap<Address<N>, u64>,
+    /// The unspent record balances of the tracked accounts.
+    private: IndexMap<Address<N>, u64>,
+    /// The `bonded` mapping.
+    bonded: IndexMap<Address<N>, (Address<N>, u64)>,
+    /// The `unbonding` mapping (without the unbonding heights).
+    unbonding: IndexMap<Address<N>, u64>,
+    /// The `committee` mapping (without the open states).
+    committee: IndexMap<Address<N>, u64>,
+}
+
+impl<N: Network> CreditsSnapshot<N> {
+    /// Returns the total amount of microcredits in the snapshot.
+    fn total(&self) -> Result<u64> {
+        self.public
+            .values()
+            .chain(self.private.values())
+            .chain(self.bonded.values().map(|(_, amount)| amount))
+            .chain(self.unbonding.values())
+            .try_fold(0u64, |total, amount| total.checked_add(*amount))
+            .ok_or_else(|| anyhow!("The total amount of microcredits overflowed"))
+    }
+}
+
+/// The harness for the `credits.aleo` microcredit invariants.
+pub struct CreditsInvariants<N: Network, C: ConsensusStorage<N>> {
+    /// The ledger.
+    ledger: Ledger<N, C>,
+    /// The private key of the beacon, which produces the blocks.
+    beacon_private_key: PrivateKey<N>,
+    /// The private keys of the tracked accounts.
+    private_keys: Vec<PrivateKey<N>>,
+    /// The addresses of the tracked accounts.
+    addresses: Vec<Address<N>>,
+    /// The model of the expected balances.
+    model: CreditsModel<N>,
+    /// The log of the blocks and operations, for reproducing failures.
+    log: Vec<String>,
+}
+
+impl<N: Network, C: ConsensusStorage<N>> CreditsInvariants<N, C> {
+    /// Initializes the harness from the given ledger, beacon private key, and tracked accounts.
+    /// The model is initialized from the current state of the ledger.
+    pub fn new(
+        ledger: Ledger<N, C>,
+        beacon_private_key: PrivateKey<N>,
+        private_keys: Vec<PrivateKey<N>>,
+    ) -> Result<Self> {
+        ensure!(!private_keys.is_empty(), "The credits invariants harness requires at least one account");
+        // Compute the addresses.
+        let addresses = private_keys.iter().map(Address::try_from).collect::<Result<Vec<_>>>()?;
+        // Initialize the harness with an empty model.
+        let model = CreditsModel {
+            public: Default::default(),
+            private: Default::default(),
+            bonded: Default::default(),
+            unbonding: Default::default(),
+            initial_supply: 0,
+            fees_burned: 0,
+            rewards_minted: 0,
+        };
+        let mut harness = Self { ledger, beacon_private_key, private_keys, addresses, model, log: Vec::new() };
+        // Initialize the model from the current state.
+        let snapshot = harness.snapshot()?;
+        harness.model.initial_supply = snapshot.total()?;
+        harness.model.public = snapshot.public;
+        harness.model.private = snapshot.private;
+        harness.model.bonded = snapshot.bonded;
+        harness.model.unbonding = snapshot.unbonding;
+        // Ensure the initial state satisfies the invariants.
+        harness.check_invariants()?;
+        Ok(harness)
+    }
+
+    /// Returns the ledger.
+    pub const fn ledger(&self) -> &Ledger<N, C> {
+        &self.ledger
+    }
+
+    /// Returns the model of the expected balances.
+    pub const fn model(&self) -> &CreditsModel<N> {
+        &self.model
+    }
+
+    /// Returns the addresses of the tracked accounts.
+    pub fn addresses(&self) -> &[Address<N>] {
+        &self.addresses
+    }
+
+    /// Returns the log of the blocks and operations.
+    pub fn log(&self) -> &[String] {
+        &self.log
+    }
+
+    /// Advances the ledger by the given number of blocks, each with up to `max_operations` random operations,
+    /// and checks the invariants after every block. On failure, the error includes the operation log.
+    pub fn run<R: Rng + CryptoRng>(&mut self, num_blocks: usize, max_operations: usize, rng: &mut R) -> Result<()> {
+        for _ in 0..num_blocks {
+            let num_operations = rng.gen_range(0..=max_operations);
+            let operations = self.sample_operations(num_operations, rng)?;
+            if let Err(error) = self.advance(operations, rng) {
+                bail!("{error}\n\nThe `credits.aleo` invariants failed after:\n{}", self.log.join("\n"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Samples the given number of random operations, based on the current model.
+    /// The operations are biased towards succeeding, though some are expected to be rejected.
+    pub fn sample_operations<R: Rng + CryptoRng>(
+        &self,
+        num_operations: usize,
+        rng: &mut R,
+    ) -> Result<Vec<CreditsOperation<N>>> {
+        // Retrieve the current validators.
+        let validators = self.ledger.latest_committee()?.members().keys().copied().collect::<Vec<_>>();
+
+        let mut operations = Vec::with_capacity(num_operations);
+        while operations.len() < num_operations {
+            let caller = rng.gen_range(0..self.addresses.len());
+            let address = self.addresses[caller];
+            let receiver = rng.gen_range(0..self.addresses.len());
+
+            let operation = match rng.gen_range(0..6) {
+                0 => {
+                    let amount = sample_amount(self.model.public_balance(&address), rng);
+                    CreditsOperation::TransferPublic { sender: caller, receiver, amount }
+                }
+                1 => {
+                    let amount = sample_amount(self.model.public_balance(&address), rng);
+                    CreditsOperation::TransferPublicToPrivate { sender: caller, receiver, amount }
+                }
+                2 => {
+                    // Only sample a private transfer if the sender has a record to spend.
+                    if self.model.private_balance(&address) == 0 {
+                        continue;
+                    }
+                    let amount = sample_amount(self.model.private_balance(&address) / 2, rng);
+                    CreditsOperation::TransferPrivateToPublic { sender: caller, receiver, amount }
+                }
+                3 => {
+                    // Bond to the existing validator of the staker, or to a random validator.
+                    let validator = match self.model.bond(&address) {
+                        Some((validator, _)) => validator,
+                        None => validators[rng.gen_range(0..validators.len())],
+                    };
+                    let amount = MIN_DELEGATOR_STAKE.saturating_add(rng.gen_range(0..MIN_DELEGATOR_STAKE));
+                    CreditsOperation::BondPublic { staker: caller, validator, amount }
+                }
+                4 => {
+                    let Some((validator, bonded)) = self.model.bond(&address) else {
+                        continue;
+                    };
+                    // Ensure a validator remains in the committee, as the committee must retain its size.
+                    let unbondable = match validator == address {
+                        true => bonded.saturating_sub(MIN_VALIDATOR_STAKE),
+                        false => bonded,
+                    };
+                    if unbondable == 0 {
+                        continue;
+                    }
+                    CreditsOperation::UnbondPublic { staker: caller, amount: rng.gen_range(1..=unbondable) }
+                }
+                _ => CreditsOperation::ClaimUnbondPublic { staker: caller },
+            };
+            operations.push(operation);
+        }
+        Ok(operations)
+    }
+
+    /// Advances the ledger by one block with the given operations, applies the block to the model,
+    /// and checks the invariants.
+    pub fn advance<R: Rng + CryptoRng>(&mut self, operations: Vec<CreditsOperation<N>>, rng: &mut R) -> Result<()> {
+        self.log.push(format!("block {}:", self.ledger.latest_height().saturating_add(1)));
+
+        // Construct the transactions, skipping operations that cannot be authorized.
+        let mut transactions = Vec::with_capacity(operations.len());
+        let mut pending = IndexMap::with_capacity(operations.len());
+        let mut spent_records = Vec::new();
+        for operation in operations {
+            match self.create_transaction(&operation, &mut spent_records, rng) {
+                Ok(transaction) => {
+                    self.log.push(format!("    {operation}"));
+                    pending.insert(transaction.id(), operation);
+                    transactions.push(transaction);
+                }
+                Err(error) => self.log.push(format!("    {operation} (skipped - {error})")),
+            }
+        }
+
+        // Construct the next block.
+        let block = self.ledger.prepare_advance_to_next_beacon_block(
+            &self.beacon_private_key,
+            vec![],
+            vec![],
+            transactions,
+            rng,
+        )?;
+        // Advance to the next block.
+        self.ledger.check_next_block(&block)?;
+        self.ledger.advance_to_next_block(&block)?;
+
+        // Apply the block to the model, and check the invariants.
+        self.apply_block(&block, &pending)?;
+        self.check_invariants()
+    }
+
+    /// Returns a transaction for the given operation, with a public fee paid by the caller.
+    /// The records spent by the transaction are appended to `spent_records`, to avoid double-spends in a block.
+    fn create_transaction<R: Rng + CryptoRng>(
+        &self,
+        operation: &CreditsOperation<N>,
+        spent_records: &mut Vec<Field<N>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        let amount_of = |amount: &u64| Value::from_str(&format!("{amount}u64"));
+        let address_of = |index: &usize| Value::from_str(&self.addresses[*index].to_string());
+
+        // Prepare the inputs.
+        let inputs = match operation {
+            CreditsOperation::TransferPublic { receiver, amount, .. }
+            | CreditsOperation::TransferPublicToPrivate { receiver, amount, .. } => {
+                vec![address_of(receiver)?, amount_of(amount)?]
+            }
+            CreditsOperation::TransferPrivateToPublic { sender, receiver, amount } => {
+                // Find an unspent record of the sender, which has not been spent in this block.
+                let view_key = ViewKey::try_from(&self.private_keys[*sender])?;
+                let (commitment, record) = self
+                    .ledger
+                    .find_unspent_credits_records(&view_key)?
+                    .into_iter()
+                    .find(|(commitment, record)| {
+                        !spent_records.contains(commitment)
+                            && record_microcredits(record).map_or(false, |microcredits| microcredits >= *amount)
+                    })
+                    .ok_or_else(|| anyhow!("no unspent record with at least {amount} microcredits"))?;
+                spent_records.push(commitment);
+                vec![Value::Record(record), address_of(receiver)?, amount_of(amount)?]
+            }
+            CreditsOperation::BondPublic { validator, amount, .. } => {
+                vec![Value::from_str(&validator.to_string())?, amount_of(amount)?]
+            }
+            CreditsOperation::UnbondPublic { amount, .. } => vec![amount_of(amount)?],
+            CreditsOperation::ClaimUnbondPublic { .. } => vec![],
+        };
+
+        // Construct the transaction.
+        self.ledger.vm().execute(
+            &self.private_keys[operation.caller()],
+            ("credits.aleo", operation.function_name()),
+            inputs.iter(),
+            None,
+            0,
+            None,
+            rng,
+        )
+    }
+
+    /// Applies the given block to the model, where `pending` maps the transaction IDs to their operations.
+    fn apply_block(
+        &mut self,
+        block: &Block<N>,
+        pending: &IndexMap<N::TransactionID, CreditsOperation<N>>,
+    ) -> Result<()> {
+        // Apply the transactions, in the order they were finalized.
+        for confirmed in block.transactions().iter() {
+            let id = confirmed.to_unconfirmed_transaction_id()?;
+            let operation =
+                pending.get(&id).ok_or_else(|| anyhow!("Found an unknown transaction '{id}' in the block"))?;
+            // Apply the fee, which is paid by the caller whether the transaction is accepted or rejected.
+            self.model.apply_fee(self.addresses[operation.caller()], *confirmed.fee_amount()?)?;
+            // Apply the operation, if it was accepted.
+            match confirmed.is_accepted() {
+                true => self.model.apply_operation(operation, &self.addresses)?,
+                false => self.log.push(format!("    {operation} (rejected)")),
+            }
+        }
+        // Log the aborted transactions.
+        for (id, operation) in pending {
+            if block.aborted_transaction_ids().contains(id) {
+                self.log.push(format!("    {operation} (aborted)"));
+            }
+        }
+        // Apply the staking rewards, which are ratified after the transactions.
+        for ratification in block.ratifications().iter() {
+            if let Ratify::BlockReward(block_reward) = ratification {
+                self.model.apply_block_reward(*block_reward)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures the `credits.aleo` state in the ledger satisfies the invariants.
+    pub fn check_invariants(&self) -> Result<()> {
+        let snapshot = self.snapshot()?;
+        let supply = self.model.supply()?;
+
+        // Ensure no value has underflowed.
+        for (address, amount) in snapshot
+            .public
+            .iter()
+            .chain(snapshot.unbonding.iter())
+            .chain(snapshot.committee.iter())
+            .chain(snapshot.bonded.iter().map(|(address, (_, amount))| (address, amount)))
+        {
+            ensure!(*amount <= supply, "Found an underflowed value of {amount} microcredits for {address}");
+        }
+
+        // Ensure each account, bond, and unbonding matches the model.
+        ensure_balances_match("account", &snapshot.public, &self.model.public)?;
+        ensure_balances_match("private", &snapshot.private, &self.model.private)?;
+        ensure_balances_match("unbonding", &snapshot.unbonding, &self.model.unbonding)?;
+        for (staker, bond) in snapshot.bonded.iter() {
+            let expected = self.model.bond(staker);
+            ensure!(Some(*bond) == expected, "The 'bonded' mapping for {staker} is {bond:?} (expected {expected:?})");
+        }
+        for staker in self.model.bonded.keys() {
+            ensure!(snapshot.bonded.contains_key(staker), "The 'bonded' mapping is missing {staker}");
+        }
+
+        // Ensure the committee stake of each validator is the sum of the amounts bonded to it.
+        let stakes = self.model.validator_stakes();
+        ensure_balances_match("committee", &snapshot.committee, &stakes)?;
+        // Ensure the total stake of the committee is the sum of the bonded amounts.
+        let total_bonded = snapshot.bonded.values().try_fold(0u64, |total, (_, amount)| total.checked_add(*amount));
+        let total_stake = self.ledger.latest_committee()?.total_stake();
+        ensure!(
+            Some(total_stake) == total_bonded,
+            "The committee total stake is {total_stake} microcredits (expected {total_bonded:?})"
+        );
+
+        // Ensure the global supply is conserved.
+        let total = snapshot.total()?;
+        ensure!(
+            total == supply,
+            "The total supply is {total} microcredits (expected {} - {} fees + {} rewards = {supply})",
+            self.model.initial_supply,
+            self.model.fees_burned,
+            self.model.rewards_minted,
+        );
+        Ok(())
+    }
+
+    /// Returns a snapshot of the `credits.aleo` state in the ledger.
+    fn snapshot(&self) -> Result<CreditsSnapshot<N>> {
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        let finalize_store = self.ledger.vm().finalize_store();
+        let mapping = |name: &str| finalize_store.get_mapping_confirmed(program_id, Identifier::from_str(name)?);
+
+        // Retrieve the `account` mapping.
+        let mut public = IndexMap::new();
+        for (key, value) in mapping("account")? {
+            let Value::Plaintext(value) = value else { bail!("Malformed 'account' value for '{key}'") };
+            public.insert(plaintext_to_address(&key)?, plaintext_to_u64(&value)?);
+        }
+        // Retrieve the `bonded` mapping.
+        let mut bonded = IndexMap::new();
+        for (key, value) in mapping("bonded")? {
+            let Value::Plaintext(value) = value else { bail!("Malformed 'bonded' value for '{key}'") };
+            let validator = plaintext_to_address(&struct_member(&value, "validator")?)?;
+            let microcredits = plaintext_to_u64(&struct_member(&value, "microcredits")?)?;
+            bonded.insert(plaintext_to_address(&key)?, (validator, microcredits));
+        }
+        // Retrieve the `unbonding` mapping.
+        let mut unbonding = IndexMap::new();
+        for (key, value) in mapping("unbonding")? {
+            let Value::Plaintext(value) = value else { bail!("Malformed 'unbonding' value for '{key}'") };
+            unbonding.insert(plaintext_to_address(&key)?, plaintext_to_u64(&struct_member(&value, "microcredits")?)?);
+        }
+        // Retrieve the `committee` mapping.
+        let mut committee = IndexMap::new();
+        for (key, value) in mapping("committee")? {
+            let Value::Plaintext(value) = value else { bail!("Malformed 'committee' value for '{key}'") };
+            committee.insert(plaintext_to_address(&key)?, plaintext_to_u64(&struct_member(&value, "microcredits")?)?);
+        }
+        // Retrieve the unspent record balances of the tracked accounts.
+        let mut private = IndexMap::new();
+        for (private_key, address) in self.private_keys.iter().zip_eq(&self.addresses) {
+            let view_key = ViewKey::try_from(private_key)?;
+            let mut balance = 0u64;
+            for (_, record) in self.ledger.find_records(&view_key, RecordsFilter::Unspent)? {
+                balance = balance
+                    .checked_add(record_microcredits(&record)?)
+                    .ok_or_else(|| anyhow!("The private balance of {address} overflowed"))?;
+            }
+            private.insert(*address, balance);
+        }
+
+        Ok(CreditsSnapshot { public, private, bonded, unbonding, committee })
+    }
+}
+
+/// Samples an amount up to the given balance, which occasionally exceeds the balance (to exercise rejections).
+fn sample_amount<R: Rng>(balance: u64, rng: &mut R) -> u64 {
+    match rng.gen_bool(0.1) {
+        true => balance.saturating_add(1),
+        false => rng.gen_range(1..=balance.clamp(1, 1_000_000_000)),
+    }
+}
+
+/// Ensures the given balances match the expected balances, where a missing balance is zero.
+fn ensure_balances_match<N: Network>(
+    name: &str,
+    balances: &IndexMap<Address<N>, u64>,
+    expected: &IndexMap<Address<N>, u64>,
+) -> Result<()> {
+    for address in balances.keys().chain(expected.keys()) {
+        let balance = balances.get(address).copied().unwrap_or_default();
+        let expected = expected.get(address).copied().unwrap_or_default();
+        ensure!(balance == expected, "The '{name}' balance of {address} is {balance} (expected {expected})");
+    }
+    Ok(())
+}
+
+/// Returns the address in the given plaintext.
+fn plaintext_to_address<N: Network>(plaintext: &Plaintext<N>) -> Result<Address<N>> {
+    match plaintext {
+        Plaintext::Literal(Literal::Address(address), _) => Ok(*address),
+        _ => bail!("Expected an address, found '{plaintext}'"),
+    }
+}
+
+/// Returns the `u64` in the given plaintext.
+fn plaintext_to_u64<N: Network>(plaintext: &Plaintext<N>) -> Result<u64> {
+    match plaintext {
+        Plaintext::Literal(Literal::U64(value), _) => Ok(**value),
+        _ => bail!("Expected a u64, found '{plaintext}'"),
+    }
+}
+
+/// Returns the member with the given name in the given struct plaintext.
+fn struct_member<N: Network>(plaintext: &Plaintext<N>, name: &str) -> Result<Plaintext<N>> {
+    match plaintext {
+        Plaintext::Struct(members, _) => members
+            .get(&Identifier::from_str(name)?)
+            .cloned()
+            .ok_or_else(|| anyhow!("Missing the member '{name}' in '{plaintext}'")),
+        _ => bail!("Expected a struct, found '{plaintext}'"),
+    }
+}
+
+/// Returns the microcredits in the given `credits.aleo` record.
+fn record_microcredits<N: Network>(record: &Record<N, Plaintext<N>>) -> Result<u64> {
+    match record.data().get(&Identifier::from_str("microcredits")?) {
+        Some(Entry::Private(plaintext)) => plaintext_to_u64(plaintext),
+        _ => bail!("Missing the microcredits in a `credits.aleo` record"),
+    }
+}
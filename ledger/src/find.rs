@@ -73,41 +73,9 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             };
 
             // Determine whether to decrypt this record (or not), based on the filter.
-            let commitment = match filter {
-                RecordsFilter::All => Ok(Some(commitment)),
-                RecordsFilter::Spent => Record::<N, Plaintext<N>>::tag(sk_tag, commitment).and_then(|tag| {
-                    // Determine if the record is spent.
-                    self.contains_tag(&tag).map(|is_spent| match is_spent {
-                        true => Some(commitment),
-                        false => None,
-                    })
-                }),
-                RecordsFilter::Unspent => Record::<N, Plaintext<N>>::tag(sk_tag, commitment).and_then(|tag| {
-                    // Determine if the record is spent.
-                    self.contains_tag(&tag).map(|is_spent| match is_spent {
-                        true => None,
-                        false => Some(commitment),
-                    })
-                }),
-                RecordsFilter::SlowSpent(private_key) => {
-                    Record::<N, Plaintext<N>>::serial_number(private_key, commitment).and_then(|serial_number| {
-                        // Determine if the record is spent.
-                        self.contains_serial_number(&serial_number).map(|is_spent| match is_spent {
-                            true => Some(commitment),
-                            false => None,
-                        })
-                    })
-                }
-                RecordsFilter::SlowUnspent(private_key) => {
-                    Record::<N, Plaintext<N>>::serial_number(private_key, commitment).and_then(|serial_number| {
-                        // Determine if the record is spent.
-                        self.contains_serial_number(&serial_number).map(|is_spent| match is_spent {
-                            true => None,
-                            false => Some(commitment),
-                        })
-                    })
-                }
-            };
+            let commitment = self
+                .matches_records_filter(&commitment, &filter, sk_tag)
+                .map(|is_included| is_included.then_some(commitment));
 
             match commitment {
                 Ok(Some(commitment)) => {
@@ -141,4 +109,95 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             })
         })
     }
+
+    /// Returns the records that belong to the given view key, in the blocks of the given range of heights.
+    /// The records are returned in the order of their blocks, and the records of each block are decrypted in parallel.
+    ///
+    /// Note: To determine if a record is spent, use `RecordsFilter::SlowSpent` or `RecordsFilter::SlowUnspent`,
+    /// which look up the serial number of each owned record in the serial number store.
+    pub fn find_records_in_range<'a>(
+        &'a self,
+        view_key: &'a ViewKey<N>,
+        block_range: Range<u32>,
+        filter: RecordsFilter<N>,
+    ) -> Result<impl '_ + Iterator<Item = (Field<N>, Record<N, Plaintext<N>>)>> {
+        // Ensure the block range is valid.
+        ensure!(block_range.start <= block_range.end, "Invalid block range: {block_range:?}");
+        ensure!(
+            block_range.end <= self.latest_height().saturating_add(1),
+            "Invalid block range: {block_range:?} exceeds the latest height {}",
+            self.latest_height()
+        );
+
+        // Derive the x-coordinate of the address corresponding to the given view key.
+        let address_x_coordinate = view_key.to_address().to_x_coordinate();
+        // Derive the `sk_tag` from the graph key.
+        let sk_tag = match GraphKey::try_from(view_key) {
+            Ok(graph_key) => graph_key.sk_tag(),
+            Err(e) => bail!("Failed to derive the graph key from the view key: {e}"),
+        };
+
+        Ok(block_range.flat_map(move |height| {
+            // Retrieve the records in the block.
+            let records = match self.get_transactions(height) {
+                Ok(transactions) => transactions.into_records().collect::<Vec<_>>(),
+                Err(e) => {
+                    warn!("Failed to retrieve the transactions of block {height}: {e}");
+                    vec![]
+                }
+            };
+
+            // Decrypt the records that belong to the view key, and satisfy the filter.
+            cfg_into_iter!(records)
+                .filter_map(|(commitment, record)| {
+                    // Ensure the record belongs to the view key.
+                    if !record.is_owner_with_address_x_coordinate(view_key, &address_x_coordinate) {
+                        return None;
+                    }
+                    // Ensure the record satisfies the filter.
+                    match self.matches_records_filter(&commitment, &filter, sk_tag) {
+                        Ok(true) => (),
+                        Ok(false) => return None,
+                        Err(e) => {
+                            warn!("Failed to process 'find_records_in_range({:?})': {e}", filter);
+                            return None;
+                        }
+                    }
+                    // Decrypt the record.
+                    match record.decrypt(view_key) {
+                        Ok(record) => Some((commitment, record)),
+                        Err(e) => {
+                            warn!("Failed to decrypt the record: {e}");
+                            None
+                        }
+                    }
+                })
+                .collect::<Vec<_>>()
+        }))
+    }
+
+    /// Returns `true` if the record with the given commitment satisfies the given filter.
+    fn matches_records_filter(
+        &self,
+        commitment: &Field<N>,
+        filter: &RecordsFilter<N>,
+        sk_tag: Field<N>,
+    ) -> Result<bool> {
+        match filter {
+            RecordsFilter::All => Ok(true),
+            // Determine if the record is spent, using the tag.
+            RecordsFilter::Spent => self.contains_tag(&Record::<N, Plaintext<N>>::tag(sk_tag, *commitment)?),
+            // Determine if the record is not spent, using the tag.
+            RecordsFilter::Unspent => Ok(!self.contains_tag(&Record::<N, Plaintext<N>>::tag(sk_tag, *commitment)?)?),
+            // Determine if the record is spent, using the serial number.
+            RecordsFilter::SlowSpent(private_key) => {
+                self.contains_serial_number(&Record::<N, Plaintext<N>>::serial_number(*private_key, *commitment)?)
+            }
+            // Determine if the record is not spent, using the serial number.
+            RecordsFilter::SlowUnspent(private_key) => {
+                Ok(!self
+                    .contains_serial_number(&Record::<N, Plaintext<N>>::serial_number(*private_key, *commitment)?)?)
+            }
+        }
+    }
 }
@@ -37,14 +37,54 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
     /// Returns the epoch challenge for the given block height.
     pub fn get_epoch_challenge(&self, block_height: u32) -> Result<EpochChallenge<N>> {
+        self.get_cached_epoch_challenge(block_height).map(|challenge| (*challenge).clone())
+    }
+
+    /// Returns the epoch challenges for the given range of epoch numbers.
+    /// The range is inclusive of the start and exclusive of the end.
+    pub fn get_epoch_challenges(&self, epoch_numbers: Range<u32>) -> Result<Vec<Arc<EpochChallenge<N>>>> {
+        cfg_into_iter!(epoch_numbers)
+            .map(|epoch_number| match epoch_number.checked_mul(N::NUM_BLOCKS_PER_EPOCH) {
+                Some(epoch_starting_height) => self.get_cached_epoch_challenge(epoch_starting_height),
+                None => bail!("Epoch {epoch_number} is out of range"),
+            })
+            .collect()
+    }
+
+    /// Returns the epoch challenge for the given block height, from the cache if it exists.
+    pub(crate) fn get_cached_epoch_challenge(&self, block_height: u32) -> Result<Arc<EpochChallenge<N>>> {
         // Compute the epoch number from the current block height.
         let epoch_number = block_height / N::NUM_BLOCKS_PER_EPOCH;
+
+        // If the epoch challenge is cached, return it.
+        if let Some(challenge) = self.epoch_challenges.read().get(&epoch_number) {
+            return Ok(challenge.clone());
+        }
+
         // Compute the epoch starting height (a multiple of `NUM_BLOCKS_PER_EPOCH`).
         let epoch_starting_height = epoch_number * N::NUM_BLOCKS_PER_EPOCH;
         // Retrieve the epoch block hash, defined as the 'previous block hash' from the epoch starting height.
         let epoch_block_hash = self.get_previous_hash(epoch_starting_height)?;
-        // Construct the epoch challenge.
-        EpochChallenge::new(epoch_number, epoch_block_hash, N::COINBASE_PUZZLE_DEGREE)
+        // Construct the epoch challenge, without holding the lock on the cache.
+        let challenge = Arc::new(EpochChallenge::new(epoch_number, epoch_block_hash, N::COINBASE_PUZZLE_DEGREE)?);
+
+        // Acquire the write lock on the cache.
+        let mut epoch_challenges = self.epoch_challenges.write();
+        // If the epoch challenge was cached in the meantime, return the cached epoch challenge.
+        if let Some(challenge) = epoch_challenges.get(&epoch_number) {
+            return Ok(challenge.clone());
+        }
+        // Ensure the epoch block hash was not detached in the meantime, before caching the epoch challenge.
+        // Note: A revert removes the detached blocks from storage before it evicts the epoch challenges,
+        // so an epoch challenge that passes this check is either valid, or evicted by the revert.
+        if self.get_previous_hash(epoch_starting_height)? == epoch_block_hash {
+            // Cache the epoch challenge, evicting the oldest epoch challenge if the cache is full.
+            epoch_challenges.insert(epoch_number, challenge.clone());
+            if epoch_challenges.len() > MAX_CACHED_EPOCH_CHALLENGES {
+                epoch_challenges.shift_remove_index(0);
+            }
+        }
+        Ok(challenge)
     }
 
     /// Returns the block for the given block height.
@@ -58,6 +98,8 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             Some(block_hash) => block_hash,
             None => bail!("Block {height} does not exist in storage"),
         };
+        // Ensure the block is not pruned.
+        self.ensure_not_pruned(height, &block_hash)?;
         // Retrieve the block.
         match self.vm.block_store().get_block(&block_hash)? {
             Some(block) => Ok(block),
@@ -126,7 +168,11 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Retrieve the block header.
         match self.vm.block_store().get_block_header(&block_hash)? {
             Some(header) => Ok(header),
-            None => bail!("Missing block header for block {height}"),
+            None => {
+                // Ensure the block is not pruned.
+                self.ensure_not_pruned(height, &block_hash)?;
+                bail!("Missing block header for block {height}")
+            }
         }
     }
 
@@ -140,6 +186,8 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let Some(block_hash) = self.vm.block_store().get_block_hash(height)? else {
             bail!("Block {height} does not exist in storage");
         };
+        // Ensure the block is not pruned.
+        self.ensure_not_pruned(height, &block_hash)?;
         // Retrieve the block transaction.
         match self.vm.block_store().get_block_transactions(&block_hash)? {
             Some(transactions) => Ok(transactions),
@@ -157,6 +205,8 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let Some(block_hash) = self.vm.block_store().get_block_hash(height)? else {
             bail!("Block {height} does not exist in storage");
         };
+        // Ensure the block is not pruned.
+        self.ensure_not_pruned(height, &block_hash)?;
         // Retrieve the aborted transaction IDs.
         match self.vm.block_store().get_block_aborted_transaction_ids(&block_hash)? {
             Some(aborted_transaction_ids) => Ok(aborted_transaction_ids),
@@ -201,6 +251,8 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             Some(block_hash) => block_hash,
             None => bail!("Block {height} does not exist in storage"),
         };
+        // Ensure the block is not pruned.
+        self.ensure_not_pruned(height, &block_hash)?;
         // Retrieve the block solutions.
         self.vm.block_store().get_block_solutions(&block_hash)
     }
@@ -221,6 +273,8 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             Some(block_hash) => block_hash,
             None => bail!("Block {height} does not exist in storage"),
         };
+        // Ensure the block is not pruned.
+        self.ensure_not_pruned(height, &block_hash)?;
         // Retrieve the block authority.
         match self.vm.block_store().get_block_authority(&block_hash)? {
             Some(authority) => Ok(authority),
@@ -232,6 +286,15 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     pub fn get_batch_certificate(&self, certificate_id: &Field<N>) -> Result<Option<BatchCertificate<N>>> {
         self.vm.block_store().get_batch_certificate(certificate_id)
     }
+
+    /// Returns an `ErrorCode::Pruned` error if the block for the given height is pruned from the ledger.
+    fn ensure_not_pruned(&self, height: u32, block_hash: &N::BlockHash) -> Result<()> {
+        // Note: The blocks below the checkpoint of a pruned ledger are only stored by their hash.
+        match height != 0 && self.vm.block_store().get_block_header(block_hash)?.is_none() {
+            true => Err(ErrorCode::Pruned(height).into()),
+            false => Ok(()),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -245,7 +308,7 @@ mod tests {
     #[test]
     fn test_get_block() {
         // Load the genesis block.
-        let genesis = Block::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        let genesis = Block::from_bytes_le(CurrentNetwork::genesis_bytes().unwrap()).unwrap();
 
         // Initialize a new ledger.
         let ledger = CurrentLedger::load(genesis.clone(), None).unwrap();
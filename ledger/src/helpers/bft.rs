@@ -20,8 +20,54 @@ use ledger_coinbase::ProverSolution;
 use ledger_narwhal::{Transmission, TransmissionID};
 
 use anyhow::{bail, ensure, Result};
+use core::fmt;
 use std::collections::HashSet;
 
+/// The error returned when one or more transmission IDs do not correspond to their given transmissions.
+///
+/// This error is surfaced through the `anyhow::Error` returned by the ledger, and may be recovered
+/// with `error.downcast_ref::<MismatchedTransmissions<N>>()`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MismatchedTransmissions<N: Network> {
+    /// The transmission IDs that do not correspond to their transmissions, in the order they were given.
+    transmission_ids: Vec<TransmissionID<N>>,
+}
+
+impl<N: Network> MismatchedTransmissions<N> {
+    /// Returns the mismatched transmission IDs.
+    pub fn transmission_ids(&self) -> &[TransmissionID<N>] {
+        &self.transmission_ids
+    }
+}
+
+impl<N: Network> fmt::Display for MismatchedTransmissions<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let transmission_ids = self.transmission_ids.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(", ");
+        write!(f, "Found {} mismatching transmission ID(s) - [{transmission_ids}]", self.transmission_ids.len())
+    }
+}
+
+impl<N: Network> std::error::Error for MismatchedTransmissions<N> {}
+
+/// Ensures each transmission ID corresponds to its given transmission.
+///
+/// On failure, this method returns a `MismatchedTransmissions` error listing every mismatching transmission ID.
+/// A transmission that fails to deserialize is treated as a mismatch.
+pub fn check_transmissions<'a, N: Network>(
+    transmissions: impl Iterator<Item = (&'a TransmissionID<N>, &'a Transmission<N>)>,
+) -> Result<()> {
+    // Collect the transmission IDs that do not correspond to their transmissions.
+    let transmission_ids = transmissions
+        .filter(|(transmission_id, transmission)| !matches!(transmission.matches(transmission_id), Ok(true)))
+        .map(|(transmission_id, _)| *transmission_id)
+        .collect::<Vec<_>>();
+    // Ensure there are no mismatching transmissions.
+    match transmission_ids.is_empty() {
+        true => Ok(()),
+        false => Err(MismatchedTransmissions { transmission_ids }.into()),
+    }
+}
+
 /// Takes in an iterator of transmissions and returns a tuple of ratifications, solutions, and transactions.
 ///
 /// This method ensures each transmission ID corresponds to its given transmission.
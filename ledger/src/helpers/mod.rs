@@ -15,5 +15,8 @@
 mod bft;
 pub use bft::*;
 
+mod error_code;
+pub use error_code::*;
+
 mod supply;
 pub use supply::*;
@@ -35,16 +35,25 @@ mod helpers;
 pub use helpers::*;
 
 mod advance;
+mod checkpoint;
+pub use checkpoint::*;
 mod check_next_block;
+pub use check_next_block::*;
 mod check_transaction_basic;
 mod contains;
 mod find;
 mod get;
 mod iterators;
 
+#[cfg(any(test, feature = "test-helpers"))]
+pub mod credits_invariants;
+
 #[cfg(test)]
 mod tests;
 
+#[cfg(any(test, feature = "test-helpers"))]
+pub mod test_helpers;
+
 use console::{
     account::{Address, GraphKey, PrivateKey, ViewKey},
     network::prelude::*,
@@ -52,12 +61,12 @@ use console::{
     types::{Field, Group},
 };
 use ledger_authority::Authority;
-use ledger_block::{Block, ConfirmedTransaction, Header, Metadata, Ratify, Transaction, Transactions};
+use ledger_block::{Block, ConfirmedTransaction, Header, MetadataBuilder, Ratify, Transaction, Transactions};
 use ledger_coinbase::{CoinbasePuzzle, CoinbaseSolution, EpochChallenge, ProverSolution, PuzzleCommitment};
 use ledger_committee::Committee;
 use ledger_narwhal::{BatchCertificate, Subdag, Transmission, TransmissionID};
 use ledger_query::Query;
-use ledger_store::{ConsensusStorage, ConsensusStore};
+use ledger_store::{helpers::StorageHealth, ConsensusStorage, ConsensusStore};
 use synthesizer::{
     program::{FinalizeGlobalState, Program},
     vm::VM,
@@ -69,7 +78,11 @@ use core::ops::Range;
 use indexmap::IndexMap;
 use parking_lot::RwLock;
 use rand::{prelude::IteratorRandom, rngs::OsRng};
-use std::{borrow::Cow, sync::Arc};
+use std::{
+    borrow::Cow,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 use time::OffsetDateTime;
 
 #[cfg(not(feature = "serial"))]
@@ -77,6 +90,13 @@ use rayon::prelude::*;
 
 pub type RecordMap<N> = IndexMap<Field<N>, Record<N, Plaintext<N>>>;
 
+/// The maximum number of epoch challenges to cache.
+const MAX_CACHED_EPOCH_CHALLENGES: usize = 8;
+/// The maximum number of speculations over the candidate transactions of a block template.
+const MAX_BLOCK_TEMPLATE_SPECULATIONS: usize = 4;
+/// The minimum interval between two storage health checks on the write path, unless a write fails.
+const STORAGE_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
 #[derive(Copy, Clone, Debug)]
 pub enum RecordsFilter<N: Network> {
     /// Returns all records associated with the account.
@@ -100,11 +120,15 @@ pub struct Ledger<N: Network, C: ConsensusStorage<N>> {
     /// The coinbase puzzle.
     coinbase_puzzle: CoinbasePuzzle<N>,
     /// The current epoch challenge.
-    current_epoch_challenge: Arc<RwLock<Option<EpochChallenge<N>>>>,
+    current_epoch_challenge: Arc<RwLock<Option<Arc<EpochChallenge<N>>>>>,
+    /// The most recently cached epoch challenges, keyed by epoch number (in order of least to most recent insertion).
+    epoch_challenges: Arc<RwLock<IndexMap<u32, Arc<EpochChallenge<N>>>>>,
     /// The current committee.
     current_committee: Arc<RwLock<Option<Committee<N>>>>,
     /// The current block.
     current_block: Arc<RwLock<Block<N>>>,
+    /// The time of the last storage health check, or `None` if the storage must be checked before the next write.
+    storage_checked_at: Arc<RwLock<Option<Instant>>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
@@ -130,9 +154,10 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Sample random block heights.
         let block_heights: Vec<u32> =
             (0..=latest_height).choose_multiple(&mut OsRng, (latest_height as usize).min(NUM_BLOCKS));
-        cfg_into_iter!(block_heights).try_for_each(|height| {
-            ledger.get_block(height)?;
-            Ok::<_, Error>(())
+        cfg_into_iter!(block_heights).try_for_each(|height| match ledger.get_block(height) {
+            // Note: The blocks below the checkpoint of a pruned ledger are unavailable.
+            Err(error) if ErrorCode::of(&error) == Some(ErrorCode::Pruned(height)) => Ok(()),
+            result => result.map(|_| ()),
         })?;
         lap!(timer, "Check existence of {NUM_BLOCKS} random blocks");
 
@@ -150,6 +175,17 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         };
         lap!(timer, "Load consensus store");
 
+        // Initialize the ledger from the consensus store.
+        let ledger = Self::from_store(genesis_block, store)?;
+
+        finish!(timer, "Initialize ledger");
+        Ok(ledger)
+    }
+
+    /// Initializes the ledger from the given consensus store, without performing integrity checks.
+    fn from_store(genesis_block: Block<N>, store: ConsensusStore<N, C>) -> Result<Self> {
+        let timer = timer!("Ledger::from_store");
+
         // Initialize a new VM.
         let vm = VM::from(store)?;
         lap!(timer, "Initialize a new VM");
@@ -163,8 +199,10 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             genesis_block: genesis_block.clone(),
             coinbase_puzzle: CoinbasePuzzle::<N>::load()?,
             current_epoch_challenge: Default::default(),
+            epoch_challenges: Default::default(),
             current_committee: Arc::new(RwLock::new(current_committee)),
             current_block: Arc::new(RwLock::new(genesis_block.clone())),
+            storage_checked_at: Default::default(),
         };
 
         // If the block store is empty, initialize the genesis block.
@@ -187,9 +225,9 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Set the current committee (and ensures the latest committee exists).
         ledger.current_committee = Arc::new(RwLock::new(Some(ledger.latest_committee()?)));
         // Set the current epoch challenge.
-        ledger.current_epoch_challenge = Arc::new(RwLock::new(Some(ledger.get_epoch_challenge(latest_height)?)));
+        ledger.current_epoch_challenge = Arc::new(RwLock::new(Some(ledger.get_cached_epoch_challenge(latest_height)?)));
 
-        finish!(timer, "Initialize ledger");
+        finish!(timer);
         Ok(ledger)
     }
 
@@ -203,6 +241,11 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         &self.coinbase_puzzle
     }
 
+    /// Returns the health of the storage write path, including the available disk space (if known).
+    pub fn storage_health(&self) -> StorageHealth {
+        self.vm.storage_health()
+    }
+
     /// Returns the latest committee.
     pub fn latest_committee(&self) -> Result<Committee<N>> {
         match self.current_committee.read().as_ref() {
@@ -224,7 +267,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Returns the latest epoch challenge.
     pub fn latest_epoch_challenge(&self) -> Result<EpochChallenge<N>> {
         match self.current_epoch_challenge.read().as_ref() {
-            Some(challenge) => Ok(challenge.clone()),
+            Some(challenge) => Ok((**challenge).clone()),
             None => self.get_epoch_challenge(self.latest_height()),
         }
     }
@@ -382,58 +425,3 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         )
     }
 }
-
-#[cfg(test)]
-pub(crate) mod test_helpers {
-    use crate::Ledger;
-    use console::{
-        account::{Address, PrivateKey, ViewKey},
-        network::Testnet3,
-        prelude::*,
-    };
-    use ledger_block::Block;
-    use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
-    use synthesizer::vm::VM;
-
-    pub(crate) type CurrentNetwork = Testnet3;
-    pub(crate) type CurrentLedger = Ledger<CurrentNetwork, ConsensusMemory<CurrentNetwork>>;
-
-    #[allow(dead_code)]
-    pub(crate) struct TestEnv {
-        pub ledger: CurrentLedger,
-        pub private_key: PrivateKey<CurrentNetwork>,
-        pub view_key: ViewKey<CurrentNetwork>,
-        pub address: Address<CurrentNetwork>,
-    }
-
-    pub(crate) fn sample_test_env(rng: &mut (impl Rng + CryptoRng)) -> TestEnv {
-        // Sample the genesis private key.
-        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
-        let view_key = ViewKey::try_from(&private_key).unwrap();
-        let address = Address::try_from(&private_key).unwrap();
-        // Sample the ledger.
-        let ledger = sample_ledger(private_key, rng);
-        // Return the test environment.
-        TestEnv { ledger, private_key, view_key, address }
-    }
-
-    pub(crate) fn sample_genesis_block() -> Block<CurrentNetwork> {
-        Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap()
-    }
-
-    pub(crate) fn sample_ledger(
-        private_key: PrivateKey<CurrentNetwork>,
-        rng: &mut (impl Rng + CryptoRng),
-    ) -> CurrentLedger {
-        // Initialize the store.
-        let store = ConsensusStore::<_, ConsensusMemory<_>>::open(None).unwrap();
-        // Create a genesis block.
-        let genesis = VM::from(store).unwrap().genesis_beacon(&private_key, rng).unwrap();
-        // Initialize the ledger with the genesis block.
-        let ledger = CurrentLedger::load(genesis.clone(), None).unwrap();
-        // Ensure the genesis block is correct.
-        assert_eq!(genesis, ledger.get_block(0).unwrap());
-        // Return the ledger.
-        ledger
-    }
-}
@@ -0,0 +1,169 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Ledger;
+use console::{
+    account::{Address, PrivateKey},
+    network::prelude::*,
+    program::Value,
+};
+use ledger_block::{Block, Transaction};
+use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
+use synthesizer::{program::Program, vm::VM};
+
+use core::marker::PhantomData;
+
+/// The public balance (in microcredits) that each generated account is funded with.
+pub const TEST_ACCOUNT_BALANCE: u64 = 1_000_000_000_000;
+
+/// A builder for a ledger test fixture, which is deterministic for a given seed.
+///
+/// The ledger is initialized with a beacon genesis block, where the first generated private key
+/// holds the starting public balance. Every subsequent block is produced by this private key,
+/// with a timestamp of `N::BLOCK_TIME` seconds after the previous block. As the genesis block is
+/// timestamped at `N::GENESIS_TIMESTAMP`, the timestamps are increasing and never in the future,
+/// so every block is checked with `Ledger::check_next_block` before it is added.
+pub struct TestLedgerBuilder<N: Network> {
+    /// The seed for the RNG.
+    seed: u64,
+    /// The number of funded accounts, in addition to the genesis account.
+    num_accounts: usize,
+    /// The source code of the programs to deploy, in order.
+    programs: Vec<String>,
+    /// The number of blocks to append.
+    num_blocks: u32,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network> TestLedgerBuilder<N> {
+    /// Initializes a new builder, with the given seed for the RNG.
+    pub fn new(seed: u64) -> Self {
+        Self { seed, num_accounts: 0, programs: vec![], num_blocks: 0, _phantom: PhantomData }
+    }
+
+    /// Adds `num_accounts` accounts, each funded with `TEST_ACCOUNT_BALANCE` public microcredits.
+    pub fn with_accounts(mut self, num_accounts: usize) -> Self {
+        self.num_accounts = self.num_accounts.saturating_add(num_accounts);
+        self
+    }
+
+    /// Adds a program to deploy, in its own block, from the genesis account.
+    pub fn with_deployed_program(mut self, source: &str) -> Self {
+        self.programs.push(source.to_string());
+        self
+    }
+
+    /// Adds `num_blocks` blocks, after the accounts are funded and the programs are deployed.
+    /// As a block must contain at least one transaction, each block contains a public transfer
+    /// of one microcredit from the genesis account to itself.
+    pub fn with_blocks(mut self, num_blocks: u32) -> Self {
+        self.num_blocks = self.num_blocks.saturating_add(num_blocks);
+        self
+    }
+
+    /// Returns the ledger, and the generated private keys.
+    /// The first private key is the genesis account, followed by the funded accounts.
+    #[allow(clippy::type_complexity)]
+    pub fn build(self) -> Result<(Ledger<N, ConsensusMemory<N>>, Vec<PrivateKey<N>>)> {
+        // Initialize the RNG.
+        let rng = &mut TestRng::fixed(self.seed);
+
+        // Sample the private keys.
+        let private_keys = (0..=self.num_accounts).map(|_| PrivateKey::new(rng)).collect::<Result<Vec<_>>>()?;
+        let genesis_private_key = &private_keys[0];
+
+        // Initialize the store.
+        let store = ConsensusStore::<N, ConsensusMemory<N>>::open(None)?;
+        // Create a genesis block.
+        let genesis = VM::from(store)?.genesis_beacon(genesis_private_key, rng)?;
+        // Initialize the ledger with the genesis block.
+        let ledger = Ledger::load(genesis, None)?;
+
+        // Fund the accounts.
+        if self.num_accounts > 0 {
+            let transactions = private_keys[1..]
+                .iter()
+                .map(|private_key| {
+                    let inputs = [
+                        Value::from_str(&Address::try_from(private_key)?.to_string())?,
+                        Value::from_str(&format!("{TEST_ACCOUNT_BALANCE}u64"))?,
+                    ];
+                    ledger.vm().execute(
+                        genesis_private_key,
+                        ("credits.aleo", "transfer_public"),
+                        inputs.iter(),
+                        None,
+                        0,
+                        None,
+                        rng,
+                    )
+                })
+                .collect::<Result<Vec<_>>>()?;
+            advance(&ledger, genesis_private_key, transactions, rng)?;
+        }
+
+        // Deploy the programs.
+        for source in &self.programs {
+            let program = Program::from_str(source)?;
+            let transaction = ledger.vm().deploy(genesis_private_key, &program, None, 0, None, rng)?;
+            advance(&ledger, genesis_private_key, vec![transaction], rng)?;
+        }
+
+        // Append the blocks.
+        let genesis_address = Address::try_from(genesis_private_key)?;
+        for _ in 0..self.num_blocks {
+            let inputs = [Value::from_str(&genesis_address.to_string())?, Value::from_str("1u64")?];
+            let transaction = ledger.vm().execute(
+                genesis_private_key,
+                ("credits.aleo", "transfer_public"),
+                inputs.iter(),
+                None,
+                0,
+                None,
+                rng,
+            )?;
+            advance(&ledger, genesis_private_key, vec![transaction], rng)?;
+        }
+
+        Ok((ledger, private_keys))
+    }
+}
+
+/// Advances the ledger by one block with the given transactions, and ensures they are all accepted.
+fn advance<N: Network>(
+    ledger: &Ledger<N, ConsensusMemory<N>>,
+    private_key: &PrivateKey<N>,
+    transactions: Vec<Transaction<N>>,
+    rng: &mut TestRng,
+) -> Result<Block<N>> {
+    let num_transactions = transactions.len();
+
+    // Construct the next block, with a deterministic timestamp.
+    let timestamp = ledger.latest_timestamp().saturating_add(i64::from(N::BLOCK_TIME));
+    let block =
+        ledger.prepare_advance_to_next_beacon_block_at(private_key, timestamp, vec![], vec![], transactions, rng)?;
+    // Ensure every transaction is accepted.
+    ensure!(
+        block.transactions().num_accepted() == num_transactions,
+        "Expected {num_transactions} accepted transactions in block {}",
+        block.height()
+    );
+
+    // Check the block.
+    ledger.check_next_block(&block)?;
+    // Advance to the next block.
+    ledger.advance_to_next_block(&block)?;
+    Ok(block)
+}
@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Ledger;
+use console::{
+    account::{Address, PrivateKey, ViewKey},
+    network::Testnet3,
+    prelude::*,
+};
+use ledger_block::Block;
+use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
+use synthesizer::vm::VM;
+
+pub(crate) type CurrentNetwork = Testnet3;
+pub(crate) type CurrentLedger = Ledger<CurrentNetwork, ConsensusMemory<CurrentNetwork>>;
+
+#[allow(dead_code)]
+pub(crate) struct TestEnv {
+    pub ledger: CurrentLedger,
+    pub private_key: PrivateKey<CurrentNetwork>,
+    pub view_key: ViewKey<CurrentNetwork>,
+    pub address: Address<CurrentNetwork>,
+}
+
+pub(crate) fn sample_test_env(rng: &mut (impl Rng + CryptoRng)) -> TestEnv {
+    // Sample the genesis private key.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let view_key = ViewKey::try_from(&private_key).unwrap();
+    let address = Address::try_from(&private_key).unwrap();
+    // Sample the ledger.
+    let ledger = sample_ledger(private_key, rng);
+    // Return the test environment.
+    TestEnv { ledger, private_key, view_key, address }
+}
+
+pub(crate) fn sample_genesis_block() -> Block<CurrentNetwork> {
+    Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes().unwrap()).unwrap()
+}
+
+pub(crate) fn sample_ledger(
+    private_key: PrivateKey<CurrentNetwork>,
+    rng: &mut (impl Rng + CryptoRng),
+) -> CurrentLedger {
+    // Initialize the store.
+    let store = ConsensusStore::<_, ConsensusMemory<_>>::open(None).unwrap();
+    // Create a genesis block.
+    let genesis = VM::from(store).unwrap().genesis_beacon(&private_key, rng).unwrap();
+    // Initialize the ledger with the genesis block.
+    let ledger = CurrentLedger::load(genesis.clone(), None).unwrap();
+    // Ensure the genesis block is correct.
+    assert_eq!(genesis, ledger.get_block(0).unwrap());
+    // Return the ledger.
+    ledger
+}
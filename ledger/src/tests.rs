@@ -14,16 +14,34 @@
 
 use crate::{
     test_helpers::{CurrentLedger, CurrentNetwork},
+    BlockCheckStage,
+    ErrorCode,
+    Ledger,
+    LedgerCheckpoint,
     RecordsFilter,
 };
+use circuit::AleoDevnetV0;
 use console::{
-    account::{Address, PrivateKey},
-    network::prelude::*,
+    account::{Address, PrivateKey, ViewKey},
+    network::{prelude::*, DevnetV0},
     program::{Entry, Identifier, Literal, Plaintext, ProgramID, Value},
+    types::Field,
 };
-use ledger_block::{ConfirmedTransaction, Rejected, Transaction};
+use ledger_block::{Block, ConfirmedTransaction, Ratify, Rejected, Transaction};
+use ledger_coinbase::{
+    CoinbaseSolution,
+    EpochChallenge,
+    PartialSolution,
+    ProverSolution,
+    PuzzleCommitment,
+    PuzzleProof,
+};
+use ledger_committee::{Committee, MIN_DELEGATOR_STAKE, MIN_VALIDATOR_STAKE};
+use ledger_narwhal::{BatchCertificate, BatchHeader, Subdag, Transmission, TransmissionID};
 use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
-use synthesizer::{program::Program, vm::VM};
+use synthesizer::{program::Program, vm::VM, Process};
+
+use std::{sync::Arc, time::Duration};
 
 #[test]
 fn test_load() {
@@ -44,6 +62,240 @@ fn test_load() {
     assert_eq!(ledger.latest_block(), genesis);
 }
 
+#[test]
+fn test_load_genesis_quorum() {
+    let rng = &mut TestRng::default();
+
+    // Sample the committee members.
+    let private_keys = (0..4).map(|_| PrivateKey::<CurrentNetwork>::new(rng).unwrap()).collect::<Vec<_>>();
+    let members = private_keys
+        .iter()
+        .map(|private_key| (Address::try_from(private_key).unwrap(), (MIN_VALIDATOR_STAKE, true)))
+        .collect::<indexmap::IndexMap<_, _>>();
+    let committee = Committee::<CurrentNetwork>::new_genesis(members).unwrap();
+    // Fund the first committee member with the remaining supply.
+    let private_key = private_keys[0];
+    let public_balances = indexmap::indexmap! {
+        Address::try_from(private_key).unwrap() => CurrentNetwork::STARTING_SUPPLY - committee.total_stake(),
+    };
+
+    // Initialize the store.
+    let store = ConsensusStore::<_, ConsensusMemory<_>>::open(None).unwrap();
+    let vm = VM::from(store).unwrap();
+    // Ensure the default genesis block for a quorum chain remains signed by a beacon.
+    let genesis = vm.genesis_quorum(&private_key, committee.clone(), public_balances.clone(), rng).unwrap();
+    assert!(genesis.is_genesis());
+    assert!(genesis.authority().is_beacon());
+    // Create a genesis block for a quorum chain, authorized by the committee.
+    let genesis = vm
+        .genesis_quorum_from_committee(&private_key, committee.clone(), public_balances, Default::default(), rng)
+        .unwrap();
+    assert!(genesis.is_genesis());
+    assert!(genesis.authority().is_genesis_quorum());
+    assert_eq!(genesis.authority().to_address().unwrap(), Address::try_from(private_key).unwrap());
+
+    // Initialize the ledger with the genesis block.
+    let ledger = CurrentLedger::load(genesis.clone(), None).unwrap();
+    assert_eq!(ledger.latest_block(), genesis);
+    assert_eq!(ledger.latest_committee().unwrap(), committee);
+
+    // Construct a transaction to include in the next quorum block.
+    let inputs = [
+        Value::from_str(&format!("{}", committee.members().keys().nth(1).unwrap())).unwrap(),
+        Value::from_str("1u64").unwrap(),
+    ];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let transmission_id = TransmissionID::from(&transaction.id());
+
+    // A helper function to certify a batch, signed by the rest of the committee.
+    let timestamp = time::OffsetDateTime::now_utc().unix_timestamp();
+    let certify = |batch_header: BatchHeader<CurrentNetwork>, rng: &mut TestRng| {
+        let signatures = private_keys
+            .iter()
+            .filter(|private_key| Address::try_from(*private_key).unwrap() != batch_header.author())
+            .map(|private_key| {
+                let preimage = [batch_header.batch_id(), Field::from_u64(timestamp as u64)];
+                (private_key.sign(&preimage, rng).unwrap(), timestamp)
+            })
+            .collect();
+        BatchCertificate::new(batch_header, signatures).unwrap()
+    };
+
+    // Construct the certificates for the first round.
+    let previous_certificates = private_keys
+        .iter()
+        .map(|private_key| {
+            let batch_header =
+                BatchHeader::new(private_key, 1, timestamp, Default::default(), Default::default(), rng).unwrap();
+            certify(batch_header, rng)
+        })
+        .collect::<indexmap::IndexSet<_>>();
+    let previous_certificate_ids = previous_certificates.iter().map(BatchCertificate::certificate_id).collect();
+
+    // Construct the leader certificate for the anchor round.
+    let round = 2;
+    let leader = committee.get_leader(round).unwrap();
+    let leader_private_key =
+        private_keys.iter().find(|private_key| Address::try_from(*private_key).unwrap() == leader).unwrap();
+    let batch_header =
+        BatchHeader::new(leader_private_key, round, timestamp, [transmission_id].into(), previous_certificate_ids, rng)
+            .unwrap();
+    let certificate = certify(batch_header, rng);
+    let subdag = Subdag::from([(1, previous_certificates), (round, [certificate].into())].into()).unwrap();
+
+    // Construct the next quorum block.
+    let transmissions = [(transmission_id, Transmission::from(transaction))].into();
+    let block = ledger.prepare_advance_to_next_quorum_block(subdag, transmissions).unwrap();
+    assert!(block.authority().is_quorum());
+
+    // Check that the next block is valid.
+    ledger.check_next_block(&block).unwrap();
+    // Add the quorum block to the ledger.
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.latest_height(), 1);
+    assert_eq!(ledger.latest_round(), round);
+    assert_eq!(ledger.latest_block(), block);
+}
+
+#[test]
+fn test_load_genesis_with_bonded_balances() {
+    let rng = &mut TestRng::default();
+
+    // Sample the committee members, with enough stake to accept delegations.
+    let private_keys = (0..4).map(|_| PrivateKey::<CurrentNetwork>::new(rng).unwrap()).collect::<Vec<_>>();
+    let members = private_keys
+        .iter()
+        .map(|private_key| (Address::try_from(private_key).unwrap(), (2 * MIN_VALIDATOR_STAKE, true)))
+        .collect::<indexmap::IndexMap<_, _>>();
+    let committee = Committee::<CurrentNetwork>::new_genesis(members).unwrap();
+    let validators = committee.members().keys().copied().collect::<Vec<_>>();
+
+    // Delegate stake from two stakers to the first validator, and one staker to the second validator.
+    let stakers = (0..3).map(|_| Address::try_from(PrivateKey::new(rng).unwrap()).unwrap()).collect::<Vec<_>>();
+    let bonded_balances = indexmap::indexmap! {
+        stakers[0] => (validators[0], MIN_DELEGATOR_STAKE),
+        stakers[1] => (validators[0], 2 * MIN_DELEGATOR_STAKE),
+        stakers[2] => (validators[1], MIN_DELEGATOR_STAKE),
+    };
+    // Fund the first committee member with the remaining supply.
+    let private_key = private_keys[0];
+    let public_balances = indexmap::indexmap! {
+        Address::try_from(private_key).unwrap() => CurrentNetwork::STARTING_SUPPLY - committee.total_stake(),
+    };
+
+    // Ensure an invalid delegation is rejected.
+    let invalid_bonded_balances = indexmap::indexmap! { validators[1] => (validators[0], MIN_DELEGATOR_STAKE) };
+    assert!(Ratify::genesis(committee.clone(), public_balances.clone(), invalid_bonded_balances).is_err());
+
+    // Create a genesis block for a quorum chain.
+    let store = ConsensusStore::<_, ConsensusMemory<_>>::open(None).unwrap();
+    let genesis = VM::from(store)
+        .unwrap()
+        .genesis_quorum_with_bonded_balances(
+            &private_key,
+            committee.clone(),
+            public_balances.clone(),
+            bonded_balances.clone(),
+            rng,
+        )
+        .unwrap();
+    assert!(genesis.is_genesis());
+
+    // Ensure the genesis ratification is recoverable through its accessors.
+    let ratify = genesis.ratifications().iter().find(|ratify| ratify.as_committee().is_some()).unwrap();
+    assert_eq!(ratify.as_committee(), Some(&committee));
+    assert_eq!(ratify.as_balances(), Some((&public_balances, &bonded_balances)));
+    assert_eq!(ratify.amount(), None);
+    assert_eq!(Ratify::read_le(&ratify.to_bytes_le().unwrap()[..]).unwrap(), *ratify);
+
+    // Initialize the ledger with the genesis block.
+    let ledger = CurrentLedger::load(genesis.clone(), None).unwrap();
+    assert_eq!(ledger.latest_block(), genesis);
+    assert_eq!(ledger.latest_committee().unwrap(), committee);
+
+    // Ensure the bonded mapping contains the delegations and the remaining self-bonded stake.
+    let get_bonded = |staker: Address<CurrentNetwork>| {
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let mapping_name = Identifier::from_str("bonded").unwrap();
+        let key = Plaintext::from(Literal::Address(staker));
+        ledger.vm.finalize_store().get_value_confirmed(program_id, mapping_name, &key).unwrap().unwrap().to_string()
+    };
+    let expected_bonded = |validator: Address<CurrentNetwork>, amount: u64| {
+        Value::<CurrentNetwork>::from_str(&format!("{{ validator: {validator}, microcredits: {amount}u64 }}"))
+            .unwrap()
+            .to_string()
+    };
+    for (staker, (validator, amount)) in &bonded_balances {
+        assert_eq!(get_bonded(*staker), expected_bonded(*validator, *amount));
+    }
+    assert_eq!(
+        get_bonded(validators[0]),
+        expected_bonded(validators[0], 2 * MIN_VALIDATOR_STAKE - 3 * MIN_DELEGATOR_STAKE)
+    );
+    assert_eq!(
+        get_bonded(validators[1]),
+        expected_bonded(validators[1], 2 * MIN_VALIDATOR_STAKE - MIN_DELEGATOR_STAKE)
+    );
+    assert_eq!(get_bonded(validators[2]), expected_bonded(validators[2], 2 * MIN_VALIDATOR_STAKE));
+}
+
+#[test]
+fn test_prepare_advance_to_next_quorum_block_rejects_mismatched_transmissions() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Construct two transactions.
+    let mut sample_transaction = |amount: &str| {
+        let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str(amount).unwrap()];
+        ledger.vm.execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng).unwrap()
+    };
+    let transaction_1 = sample_transaction("1u64");
+    let transaction_2 = sample_transaction("2u64");
+    let transmission_id_1 = TransmissionID::from(&transaction_1.id());
+    let transmission_id_2 = TransmissionID::from(&transaction_2.id());
+
+    // Ensure each transaction only matches its own transmission ID.
+    let transmission_1 = Transmission::from(transaction_1);
+    let transmission_2 = Transmission::from(transaction_2);
+    assert!(transmission_1.matches(&transmission_id_1).unwrap());
+    assert!(!transmission_1.matches(&transmission_id_2).unwrap());
+    assert!(!transmission_1.matches(&TransmissionID::Ratification).unwrap());
+    assert!(transmission_2.matches(&transmission_id_2).unwrap());
+
+    // Construct a subdag for the transmissions.
+    let signer = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let timestamp = time::OffsetDateTime::now_utc().unix_timestamp();
+    let batch_header = BatchHeader::new(
+        &private_key,
+        2,
+        timestamp,
+        [transmission_id_1, transmission_id_2].into(),
+        [Field::rand(rng)].into(),
+        rng,
+    )
+    .unwrap();
+    let preimage = [batch_header.batch_id(), Field::from_u64(timestamp as u64)];
+    let signatures = [(signer.sign(&preimage, rng).unwrap(), timestamp)].into();
+    let certificate = BatchCertificate::new(batch_header, signatures).unwrap();
+    let subdag = Subdag::from([(2, [certificate].into())].into()).unwrap();
+
+    // Swap the transmission of the second entry with the first transaction.
+    let transmissions = [(transmission_id_1, transmission_1.clone()), (transmission_id_2, transmission_1)].into();
+
+    // Ensure the ledger rejects the transmissions, identifying the swapped entry.
+    let error = ledger.prepare_advance_to_next_quorum_block(subdag, transmissions).unwrap_err();
+    let mismatched = error.downcast_ref::<crate::MismatchedTransmissions<CurrentNetwork>>().unwrap();
+    assert_eq!(mismatched.transmission_ids(), [transmission_id_2]);
+    assert!(error.to_string().contains(&transmission_id_2.to_string()));
+    // Ensure the ledger did not advance.
+    assert_eq!(ledger.latest_height(), 0);
+}
+
 #[test]
 fn test_load_unchecked() {
     // Load the genesis block.
@@ -64,6 +316,272 @@ fn test_load_unchecked() {
     assert_eq!(ledger.latest_block(), genesis);
 }
 
+#[test]
+fn test_test_ledger_builder_is_deterministic() {
+    // Prepare the builder.
+    let builder = || {
+        crate::test_helpers::TestLedgerBuilder::<CurrentNetwork>::new(1234)
+            .with_accounts(2)
+            .with_deployed_program(
+                r"
+program dummy_program.aleo;
+function foo:
+    input r0 as u8.private;
+    add r0 r0 into r1;
+    output r1 as u8.private;",
+            )
+            .with_blocks(2)
+    };
+
+    // Build two ledgers with the same seed.
+    let (ledger_a, private_keys_a) = builder().build().unwrap();
+    let (ledger_b, private_keys_b) = builder().build().unwrap();
+
+    // Ensure the ledgers are identical.
+    assert_eq!(private_keys_a, private_keys_b);
+    assert_eq!(private_keys_a.len(), 3);
+    assert_eq!(ledger_a.latest_height(), 4);
+    assert_eq!(ledger_a.latest_hash(), ledger_b.latest_hash());
+    assert!(ledger_a.contains_program_id(&ProgramID::from_str("dummy_program.aleo").unwrap()).unwrap());
+}
+
+#[test]
+fn test_load_from_checkpoint() {
+    let rng = &mut TestRng::fixed(1234);
+
+    // Build a ledger with 18 blocks.
+    let (ledger, private_keys) =
+        crate::test_helpers::TestLedgerBuilder::<CurrentNetwork>::new(1234).with_blocks(18).build().unwrap();
+    let private_key = private_keys[0];
+    let view_key = ViewKey::try_from(&private_key).unwrap();
+    let address = Address::try_from(&private_key).unwrap();
+    let genesis = ledger.get_block(0).unwrap();
+
+    // A helper function to create a public transfer to the genesis account.
+    let transfer_public = |ledger: &CurrentLedger, rng: &mut TestRng| {
+        let inputs = [Value::from_str(&address.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+        ledger.vm.execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng).unwrap()
+    };
+    // A helper function to create a private transfer of the given record to the genesis account.
+    let transfer_private = |ledger: &CurrentLedger, record: Value<_>, amount: u64, rng: &mut TestRng| {
+        let inputs =
+            [record, Value::from_str(&address.to_string()).unwrap(), Value::from_str(&format!("{amount}u64")).unwrap()];
+        ledger
+            .vm
+            .execute(&private_key, ("credits.aleo", "transfer_private"), inputs.iter(), None, 0, None, rng)
+            .unwrap()
+    };
+    // A helper function to construct the next block, with a deterministic timestamp.
+    let next_block = |ledger: &CurrentLedger, transactions: Vec<Transaction<_>>, rng: &mut TestRng| {
+        let timestamp = ledger.latest_timestamp() + i64::from(CurrentNetwork::BLOCK_TIME);
+        let block = ledger
+            .prepare_advance_to_next_beacon_block_at(&private_key, timestamp, vec![], vec![], transactions, rng)
+            .unwrap();
+        assert!(block.aborted_transaction_ids().is_empty());
+        block
+    };
+
+    // Retrieve two of the records of the genesis account.
+    let records = ledger
+        .find_records(&view_key, RecordsFilter::SlowUnspent(private_key))
+        .unwrap()
+        .map(|(_, record)| Value::Record(record))
+        .take(2)
+        .collect::<Vec<_>>();
+    assert_eq!(records.len(), 2);
+
+    // Spend the first record in block 19, and prepare a double-spend of it.
+    let spend = transfer_private(&ledger, records[0].clone(), 1, rng);
+    let double_spend = transfer_private(&ledger, records[0].clone(), 2, rng);
+    // Prepare a valid spend of the second record, against the state root of block 18.
+    let valid_spend = transfer_private(&ledger, records[1].clone(), 1, rng);
+    let block = next_block(&ledger, vec![spend.clone()], rng);
+    ledger.check_next_block(&block).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    // Add block 20, with a public transfer.
+    let block = next_block(&ledger, vec![transfer_public(&ledger, rng)], rng);
+    ledger.check_next_block(&block).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.latest_height(), 20);
+
+    // Export a checkpoint at the latest height.
+    assert!(ledger.export_checkpoint(10).is_err());
+    let checkpoint = ledger.export_checkpoint(20).unwrap();
+    assert_eq!(checkpoint.state_roots().len(), 21);
+    assert_eq!(checkpoint.transaction_ids().len(), 20);
+    assert_eq!(checkpoint.transaction_ids()[19], vec![spend.id()]);
+    assert!(spend.serial_numbers().all(|serial_number| checkpoint.spent().iter().any(|(s, _)| s == serial_number)));
+    // Ensure the checkpoint round trips through bytes.
+    let checkpoint = LedgerCheckpoint::read_le(&checkpoint.to_bytes_le().unwrap()[..]).unwrap();
+    let trusted_hash = checkpoint.to_hash().unwrap();
+    // Ensure the streamed hash matches the SHA-3 hash of the checkpoint bits.
+    let checkpoint_bits = checkpoint.to_bytes_le().unwrap().to_bits_le();
+    let expected_hash =
+        CurrentNetwork::hash_bhp1024(&CurrentNetwork::hash_sha3_256(&checkpoint_bits).unwrap()).unwrap();
+    assert_eq!(trusted_hash, expected_hash);
+
+    // Ensure a checkpoint is rejected if it does not match the trusted hash.
+    assert!(CurrentLedger::load_from_checkpoint(genesis.clone(), checkpoint.clone(), Field::zero(), None).is_err());
+    // Load a pruned ledger from the checkpoint, into a fresh store.
+    let pruned = CurrentLedger::load_from_checkpoint(genesis, checkpoint, trusted_hash, None).unwrap();
+    assert_eq!(pruned.latest_block(), ledger.latest_block());
+    assert_eq!(pruned.latest_state_root(), ledger.latest_state_root());
+    assert_eq!(pruned.latest_committee().unwrap(), ledger.latest_committee().unwrap());
+    assert_eq!(
+        pruned.vm.finalize_store().get_checksum_confirmed().unwrap(),
+        ledger.vm.finalize_store().get_checksum_confirmed().unwrap()
+    );
+
+    // Ensure the blocks below the checkpoint are pruned, while their hashes and state roots remain available.
+    assert_eq!(pruned.get_block(0).unwrap(), ledger.get_block(0).unwrap());
+    assert_eq!(pruned.get_hash(10).unwrap(), ledger.get_hash(10).unwrap());
+    assert_eq!(pruned.get_state_root(10).unwrap(), ledger.get_state_root(10).unwrap());
+    assert!(pruned.contains_state_root(&ledger.get_state_root(18).unwrap().unwrap()).unwrap());
+    assert_eq!(ErrorCode::of(&pruned.get_block(10).unwrap_err()), Some(ErrorCode::Pruned(10)));
+    assert_eq!(ErrorCode::of(&pruned.get_header(19).unwrap_err()), Some(ErrorCode::Pruned(19)));
+    assert_eq!(ErrorCode::of(&pruned.get_transactions(1).unwrap_err()), Some(ErrorCode::Pruned(1)));
+
+    // Ensure the pruned transactions can not be replayed.
+    let pruned_transaction = ledger.get_block(5).unwrap().transactions().iter().next().unwrap().transaction().clone();
+    for transaction in [&spend, &pruned_transaction] {
+        assert!(pruned.contains_transaction_id(&transaction.id()).unwrap());
+        assert!(pruned.check_transaction_basic(transaction, None).is_err());
+    }
+    // Ensure the pruned spent records can not be double-spent.
+    assert!(double_spend.serial_numbers().all(|serial_number| pruned.contains_serial_number(serial_number).unwrap()));
+    assert!(ledger.check_transaction_basic(&double_spend, None).is_err());
+    assert!(pruned.check_transaction_basic(&double_spend, None).is_err());
+    // Ensure an unspent record can be spent, against a pruned state root.
+    assert!(ledger.check_transaction_basic(&valid_spend, None).is_ok());
+    assert!(pruned.check_transaction_basic(&valid_spend, None).is_ok());
+
+    // Advance both ledgers with the same blocks.
+    for i in 0..3 {
+        // Include the valid spend in the first block.
+        let transactions = match i {
+            0 => vec![valid_spend.clone()],
+            _ => vec![transfer_public(&ledger, rng)],
+        };
+        let block = next_block(&ledger, transactions, rng);
+
+        // Check and advance both ledgers to the block.
+        ledger.check_next_block(&block).unwrap();
+        pruned.check_next_block(&block).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+        pruned.advance_to_next_block(&block).unwrap();
+
+        // Ensure both ledgers advanced identically.
+        assert_eq!(pruned.latest_block(), ledger.latest_block());
+        assert_eq!(pruned.latest_state_root(), ledger.latest_state_root());
+        assert_eq!(pruned.latest_committee().unwrap(), ledger.latest_committee().unwrap());
+    }
+}
+
+#[test]
+fn test_check_next_block_with_report() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // A helper function to create a public transfer to the genesis account.
+    let transfer = |amount: u64, rng: &mut TestRng| {
+        let inputs =
+            [Value::from_str(&address.to_string()).unwrap(), Value::from_str(&format!("{amount}u64")).unwrap()];
+        ledger.vm.execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng).unwrap()
+    };
+    // A helper function to ensure the given block fails the check at the given stage.
+    let check_failure = |block: &Block<CurrentNetwork>, expected_stage: BlockCheckStage| {
+        let error = ledger.check_next_block_with_report(block).unwrap_err();
+        assert_eq!(error.stage(), expected_stage, "{error}");
+        assert_eq!(error.report().timings().last().unwrap().stage, expected_stage);
+        // Ensure `check_next_block` returns the same error.
+        assert_eq!(ledger.check_next_block(block).unwrap_err().to_string(), error.to_string());
+    };
+
+    // Construct two candidate blocks.
+    let transaction = transfer(1, rng);
+    let other_transaction = transfer(2, rng);
+    let block = ledger
+        .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction.clone()], rng)
+        .unwrap();
+    let other_block = ledger
+        .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![other_transaction], rng)
+        .unwrap();
+
+    // Ensure the report contains all of the stages, in order, and sums to approximately the total.
+    let report = ledger.check_next_block_with_report(&block).unwrap();
+    let stages = report.timings().iter().map(|timing| timing.stage).collect::<Vec<_>>();
+    assert_eq!(stages, BlockCheckStage::ALL);
+    let sum = report.timings().iter().map(|timing| timing.duration).sum::<Duration>();
+    assert!(sum <= report.total());
+    assert!(report.total() - sum <= report.total() / 10 + Duration::from_millis(10));
+
+    // Ensure a block signed by an account outside the committee fails the authority stage.
+    let invalid_block = Block::new_beacon(
+        &PrivateKey::new(rng).unwrap(),
+        block.previous_hash(),
+        *block.header(),
+        block.ratifications().clone(),
+        block.solutions().cloned(),
+        block.transactions().clone(),
+        block.aborted_transaction_ids().clone(),
+        rng,
+    )
+    .unwrap();
+    check_failure(&invalid_block, BlockCheckStage::Authority);
+
+    // Ensure a block with an invalid solution fails the solutions stage.
+    let partial_solution = PartialSolution::new(address, u64::rand(rng), PuzzleCommitment::default());
+    let solution =
+        ProverSolution::new(partial_solution, PuzzleProof::<CurrentNetwork> { w: rng.gen(), random_v: None });
+    let invalid_block = Block::from_unchecked(
+        block.hash(),
+        block.previous_hash(),
+        *block.header(),
+        block.authority().clone(),
+        block.ratifications().clone(),
+        Some(CoinbaseSolution::new(vec![solution]).unwrap()),
+        block.transactions().clone(),
+        block.aborted_transaction_ids().clone(),
+    )
+    .unwrap();
+    check_failure(&invalid_block, BlockCheckStage::Solutions);
+
+    // Ensure a block that aborts its own transaction fails the transactions stage.
+    let invalid_block = Block::from_unchecked(
+        block.hash(),
+        block.previous_hash(),
+        *block.header(),
+        block.authority().clone(),
+        block.ratifications().clone(),
+        block.solutions().cloned(),
+        block.transactions().clone(),
+        vec![transaction.id()],
+    )
+    .unwrap();
+    check_failure(&invalid_block, BlockCheckStage::Transactions);
+
+    // Ensure a block with transactions that do not match its header fails the finalize stage.
+    let invalid_block = Block::from_unchecked(
+        block.hash(),
+        block.previous_hash(),
+        *block.header(),
+        block.authority().clone(),
+        block.ratifications().clone(),
+        block.solutions().cloned(),
+        other_block.transactions().clone(),
+        block.aborted_transaction_ids().clone(),
+    )
+    .unwrap();
+    check_failure(&invalid_block, BlockCheckStage::Finalize);
+
+    // Ensure a block that already exists fails the header stage.
+    ledger.advance_to_next_block(&block).unwrap();
+    check_failure(&block, BlockCheckStage::Header);
+    check_failure(&other_block, BlockCheckStage::Header);
+}
+
 #[test]
 fn test_state_path() {
     let rng = &mut TestRng::default();
@@ -394,6 +912,84 @@ finalize failed_assert:
     ledger.advance_to_next_block(&next_block).unwrap();
 }
 
+#[test]
+fn test_find_records_in_range() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Sample a second account.
+    let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let other_view_key = ViewKey::try_from(&other_private_key).unwrap();
+    let other_address = Address::try_from(&other_private_key).unwrap();
+
+    // Fetch the unspent records of the first account.
+    let microcredits = Identifier::from_str("microcredits").unwrap();
+    let records = ledger
+        .find_records(&view_key, RecordsFilter::SlowUnspent(private_key))
+        .unwrap()
+        .filter(|(_, record)| match record.data().get(&microcredits) {
+            Some(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => !amount.is_zero(),
+            _ => false,
+        })
+        .collect::<indexmap::IndexMap<_, _>>();
+    let (record_1_commitment, record_1) = records.get_index(0).map(|(c, r)| (*c, r.clone())).unwrap();
+    let record_2 = records[1].clone();
+
+    // Transfer a record to the second account.
+    let inputs = [
+        Value::Record(record_1),
+        Value::from_str(&format!("{other_address}")).unwrap(),
+        Value::from_str("100u64").unwrap(),
+    ];
+    let transaction = ledger
+        .vm()
+        .execute(&private_key, ("credits.aleo", "transfer_private"), inputs.into_iter(), Some(record_2), 0, None, rng)
+        .unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.check_next_block(&block).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.latest_height(), 1);
+
+    // Check that the spent record is classified as spent, and only in the genesis block.
+    let spent = ledger.find_records_in_range(&view_key, 0..2, RecordsFilter::SlowSpent(private_key)).unwrap();
+    let spent = spent.map(|(commitment, _)| commitment).collect::<Vec<_>>();
+    assert!(spent.contains(&record_1_commitment));
+    let unspent = ledger.find_records_in_range(&view_key, 0..2, RecordsFilter::SlowUnspent(private_key)).unwrap();
+    let unspent = unspent.map(|(commitment, _)| commitment).collect::<Vec<_>>();
+    assert!(!unspent.contains(&record_1_commitment));
+    assert!(spent.iter().all(|commitment| !unspent.contains(commitment)));
+
+    // Check that the first account sees its change record in the new block, and none of the second account's records.
+    let first_records = ledger.find_records_in_range(&view_key, 1..2, RecordsFilter::All).unwrap().collect::<Vec<_>>();
+    assert!(!first_records.is_empty());
+    assert!(first_records.iter().all(|(_, record)| **record.owner() == Address::try_from(&view_key).unwrap()));
+
+    // Check that the second account only sees the transferred record, as unspent.
+    let other_records = ledger
+        .find_records_in_range(&other_view_key, 0..2, RecordsFilter::SlowUnspent(other_private_key))
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(other_records.len(), 1);
+    assert_eq!(**other_records[0].1.owner(), other_address);
+    match other_records[0].1.data().get(&microcredits) {
+        Some(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => assert_eq!(**amount, 100),
+        _ => panic!("Expected a private 'microcredits' entry"),
+    }
+    assert!(first_records.iter().all(|(commitment, _)| *commitment != other_records[0].0));
+    let other_spent = ledger
+        .find_records_in_range(&other_view_key, 0..2, RecordsFilter::SlowSpent(other_private_key))
+        .unwrap()
+        .count();
+    assert_eq!(other_spent, 0);
+    assert_eq!(ledger.find_records_in_range(&other_view_key, 0..1, RecordsFilter::All).unwrap().count(), 0);
+
+    // Check that an invalid range is rejected.
+    assert!(ledger.find_records_in_range(&view_key, 0..3, RecordsFilter::All).is_err());
+}
+
 #[test]
 fn test_deploy_with_public_fees() {
     let rng = &mut TestRng::default();
@@ -432,6 +1028,80 @@ finalize foo:
     assert_eq!(program, ledger.get_program(program_id).unwrap())
 }
 
+#[test]
+fn test_construct_block_template_drops_conflicts_after_speculation() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Prepare a test program.
+    let program_id = ProgramID::<CurrentNetwork>::from_str("dummy_program.aleo").unwrap();
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        "
+program {program_id};
+function foo:
+    input r0 as u8.private;
+    async foo r0 into r1;
+    output r1 as {program_id}/foo.future;
+finalize foo:
+    input r0 as u8.public;
+    add r0 r0 into r1;",
+    ))
+    .unwrap();
+
+    // Deploy the program from an account without a public balance, so that the deployment aborts.
+    let unfunded_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let aborted = ledger.vm.deploy(&unfunded_private_key, &program, None, 0, None, rng).unwrap();
+    // Deploy the same program from the funded account.
+    let deployed = ledger.vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+
+    // Ensure the earlier, aborted deployment does not cause the later deployment of the same program to be dropped.
+    let block = ledger
+        .prepare_advance_to_next_beacon_block(
+            &private_key,
+            vec![],
+            vec![],
+            vec![aborted.clone(), deployed.clone()],
+            rng,
+        )
+        .unwrap();
+    assert_eq!(block.transaction_ids().copied().collect::<Vec<_>>(), vec![deployed.id()]);
+    assert_eq!(block.aborted_transaction_ids(), &vec![aborted.id()]);
+    ledger.check_next_block(&block).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(program, ledger.get_program(program_id).unwrap());
+
+    // Prepare two `split` executions, which spend the same record.
+    let record = ledger
+        .find_records(&view_key, RecordsFilter::SlowUnspent(private_key))
+        .unwrap()
+        .map(|(_, record)| record)
+        .next()
+        .unwrap();
+    let mut split = || {
+        let inputs = [Value::Record(record.clone()), Value::from_str("100u64").unwrap()];
+        ledger.vm.execute(&private_key, ("credits.aleo", "split"), inputs.into_iter(), None, 0, None, rng).unwrap()
+    };
+    let (first, double_spend) = (split(), split());
+
+    // Ensure the double spend is dropped and aborted, and the confirmed transactions are contiguously indexed.
+    let block = ledger
+        .prepare_advance_to_next_beacon_block(
+            &private_key,
+            vec![],
+            vec![],
+            vec![first.clone(), double_spend.clone()],
+            rng,
+        )
+        .unwrap();
+    assert_eq!(block.transaction_ids().copied().collect::<Vec<_>>(), vec![first.id()]);
+    assert_eq!(block.aborted_transaction_ids(), &vec![double_spend.id()]);
+    assert_eq!(block.transactions().iter().map(|transaction| transaction.index()).collect::<Vec<_>>(), vec![0]);
+    ledger.check_next_block(&block).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+}
+
 #[test]
 fn test_bond_and_unbond_validator() {
     let rng = &mut TestRng::default();
@@ -517,3 +1187,413 @@ fn test_bond_and_unbond_validator() {
     let committee = ledger.latest_committee().unwrap();
     assert!(!committee.is_committee_member(new_member_address));
 }
+
+#[test]
+fn test_revert_to_height() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Sample a recipient.
+    let recipient = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+    // A helper function to fetch the public balances of the sender and recipient.
+    let program_id = ProgramID::from_str("credits.aleo").unwrap();
+    let account_mapping = Identifier::from_str("account").unwrap();
+    let get_balances = || {
+        [address, recipient].map(|address| {
+            let key = Plaintext::from(Literal::Address(address));
+            ledger.vm.finalize_store().get_value_confirmed(program_id, account_mapping, &key).unwrap()
+        })
+    };
+
+    // Advance the ledger by 5 blocks, each with a public transfer.
+    let mut snapshot = None;
+    for amount in 1..=5 {
+        let inputs =
+            [Value::from_str(&format!("{recipient}")).unwrap(), Value::from_str(&format!("{amount}u64")).unwrap()];
+        let transaction = ledger
+            .vm
+            .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+            .unwrap();
+        let block =
+            ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+        ledger.check_next_block(&block).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+
+        // Take a snapshot of the ledger at height 3.
+        if ledger.latest_height() == 3 {
+            snapshot = Some((get_balances(), ledger.latest_state_root(), ledger.latest_block()));
+        }
+    }
+    assert_eq!(ledger.latest_height(), 5);
+    let (balances, state_root, latest_block) = snapshot.unwrap();
+    assert_ne!(get_balances(), balances);
+
+    // Ensure reverting above the latest height fails.
+    assert!(ledger.revert_to_height(6).is_err());
+
+    // Revert the last 2 blocks.
+    let detached_blocks = ledger.revert_to_height(3).unwrap();
+    assert_eq!(detached_blocks.iter().map(|block| block.height()).collect::<Vec<_>>(), vec![4, 5]);
+
+    // Ensure the ledger matches the snapshot at height 3.
+    assert_eq!(ledger.latest_height(), 3);
+    assert_eq!(get_balances(), balances);
+    assert_eq!(ledger.latest_state_root(), state_root);
+    assert_eq!(ledger.latest_block(), latest_block);
+    assert!(!ledger.contains_block_hash(&detached_blocks[0].hash()).unwrap());
+
+    // Ensure the detached blocks can be re-applied.
+    for block in &detached_blocks {
+        ledger.check_next_block(block).unwrap();
+        ledger.advance_to_next_block(block).unwrap();
+    }
+    assert_eq!(ledger.latest_height(), 5);
+    assert_eq!(ledger.latest_block(), detached_blocks[1]);
+}
+
+#[test]
+fn test_epoch_challenge_cache() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // A helper function to compute the epoch challenge for the given epoch number, without the cache.
+    let compute_epoch_challenge = |epoch_number: u32| {
+        let epoch_block_hash = ledger.get_previous_hash(epoch_number * CurrentNetwork::NUM_BLOCKS_PER_EPOCH).unwrap();
+        EpochChallenge::new(epoch_number, epoch_block_hash, CurrentNetwork::COINBASE_PUZZLE_DEGREE).unwrap()
+    };
+    // A helper function to advance the ledger by one block.
+    let advance = |rng: &mut TestRng| {
+        let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+    };
+
+    // Advance the ledger across the first epoch boundary.
+    for _ in 0..CurrentNetwork::NUM_BLOCKS_PER_EPOCH {
+        advance(rng);
+    }
+    assert_eq!(ledger.latest_epoch_number(), 1);
+
+    // Ensure the cached epoch challenges match a fresh computation.
+    assert_eq!(ledger.latest_epoch_challenge().unwrap(), compute_epoch_challenge(1));
+    assert_eq!(ledger.get_epoch_challenge(0).unwrap(), compute_epoch_challenge(0));
+    let expected_epoch_challenges = vec![Arc::new(compute_epoch_challenge(0)), Arc::new(compute_epoch_challenge(1))];
+    assert_eq!(ledger.get_epoch_challenges(0..2).unwrap(), expected_epoch_challenges);
+    assert!(ledger.get_epoch_challenges(0..3).is_err());
+
+    // Revert the block that defines the epoch challenge of the new epoch, and advance on a different branch.
+    let epoch_challenge = ledger.latest_epoch_challenge().unwrap();
+    ledger.revert_to_height(CurrentNetwork::NUM_BLOCKS_PER_EPOCH - 2).unwrap();
+    assert_eq!(ledger.latest_epoch_challenge().unwrap(), compute_epoch_challenge(0));
+    advance(rng);
+    advance(rng);
+
+    // Ensure the cached epoch challenge was invalidated.
+    assert_eq!(ledger.latest_epoch_number(), 1);
+    assert_ne!(ledger.latest_epoch_challenge().unwrap(), epoch_challenge);
+    assert_eq!(ledger.latest_epoch_challenge().unwrap(), compute_epoch_challenge(1));
+    assert_eq!(ledger.get_epoch_challenge(CurrentNetwork::NUM_BLOCKS_PER_EPOCH).unwrap(), compute_epoch_challenge(1));
+}
+
+#[test]
+fn test_devnet_epoch_challenge_rotation() {
+    type DevnetLedger = Ledger<DevnetV0, ConsensusMemory<DevnetV0>>;
+
+    // Note: This test only runs with a short devnet epoch, set with `SNARKVM_DEVNET_NUM_BLOCKS_PER_EPOCH` (as in CI).
+    let Some(num_blocks_per_epoch) = option_env!("SNARKVM_DEVNET_NUM_BLOCKS_PER_EPOCH") else {
+        return;
+    };
+    // Ensure the devnet uses the configured epoch.
+    assert_eq!(DevnetV0::NUM_BLOCKS_PER_EPOCH, num_blocks_per_epoch.parse::<u32>().unwrap());
+
+    let rng = &mut TestRng::default();
+
+    // Synthesize the 'credits.aleo' keys for the devnet.
+    Process::<DevnetV0>::load_or_setup::<AleoDevnetV0>().unwrap();
+
+    // Sample the genesis private key.
+    let private_key = PrivateKey::<DevnetV0>::new(rng).unwrap();
+    // Initialize the store.
+    let store = ConsensusStore::<_, ConsensusMemory<_>>::open(None).unwrap();
+    // Create a genesis block.
+    let genesis = VM::from(store).unwrap().genesis_beacon(&private_key, rng).unwrap();
+    assert_eq!(genesis.network(), DevnetV0::ID);
+
+    // Initialize the ledger with the genesis block.
+    let ledger = DevnetLedger::load(genesis, None).unwrap();
+    let mut epoch_challenge = ledger.latest_epoch_challenge().unwrap();
+
+    // Advance the ledger across two epochs, and ensure the epoch challenge rotates at the configured cadence.
+    for height in 1..=2 * DevnetV0::NUM_BLOCKS_PER_EPOCH {
+        let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+        assert_eq!(ledger.latest_epoch_number(), height / DevnetV0::NUM_BLOCKS_PER_EPOCH);
+
+        let candidate = ledger.latest_epoch_challenge().unwrap();
+        match height % DevnetV0::NUM_BLOCKS_PER_EPOCH == 0 {
+            true => assert_ne!(candidate, epoch_challenge, "The epoch challenge did not rotate at height {height}"),
+            false => assert_eq!(candidate, epoch_challenge, "The epoch challenge rotated at height {height}"),
+        }
+        epoch_challenge = candidate;
+    }
+}
+
+#[test]
+fn test_devnet_transfer_public() {
+    type DevnetLedger = Ledger<DevnetV0, ConsensusMemory<DevnetV0>>;
+
+    let rng = &mut TestRng::default();
+
+    // Sample the genesis private key.
+    let private_key = PrivateKey::<DevnetV0>::new(rng).unwrap();
+    let address = Address::try_from(&private_key).unwrap();
+    // Synthesize the 'credits.aleo' keys for the devnet.
+    Process::<DevnetV0>::load_or_setup::<AleoDevnetV0>().unwrap();
+    // Initialize the store.
+    let store = ConsensusStore::<_, ConsensusMemory<_>>::open(None).unwrap();
+    // Create a genesis block.
+    let genesis = VM::from(store).unwrap().genesis_beacon(&private_key, rng).unwrap();
+
+    // Initialize the ledger with the genesis block.
+    let ledger = DevnetLedger::load(genesis, None).unwrap();
+
+    // Ensure the 'credits.aleo' keys are synthesized for the devnet, rather than taken from Testnet3.
+    let verifying_key = DevnetV0::get_credits_verifying_key("transfer_public".to_string()).unwrap();
+    let testnet3_verifying_key = CurrentNetwork::get_credits_verifying_key("transfer_public".to_string()).unwrap();
+    assert_ne!(verifying_key.to_bytes_le().unwrap(), testnet3_verifying_key.to_bytes_le().unwrap());
+
+    // Execute a public transfer on the devnet.
+    let inputs = [Value::from_str(&address.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    // Ensure the transaction is verified against the devnet keys.
+    ledger.vm.check_transaction(&transaction, None).unwrap();
+
+    // Ensure the transaction is accepted into the next block.
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.check_next_block(&block).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(block.transactions().num_accepted(), 1);
+}
+
+#[test]
+fn test_advance_to_next_block_refused_on_unhealthy_storage() {
+    use ledger_store::{
+        helpers::{
+            memory::{BlockMemory, FinalizeMemory, TransactionMemory, TransitionMemory},
+            StorageError,
+            StorageHealth,
+        },
+        BlockStore,
+        ConsensusStorage,
+        FinalizeStore,
+    };
+
+    /// A consensus storage that reports the disk as full.
+    #[derive(Clone)]
+    struct DiskFullMemory(ConsensusMemory<CurrentNetwork>);
+
+    impl ConsensusStorage<CurrentNetwork> for DiskFullMemory {
+        type BlockStorage = BlockMemory<CurrentNetwork>;
+        type FinalizeStorage = FinalizeMemory<CurrentNetwork>;
+        type TransactionStorage = TransactionMemory<CurrentNetwork>;
+        type TransitionStorage = TransitionMemory<CurrentNetwork>;
+
+        fn open(dev: Option<u16>) -> Result<Self> {
+            Ok(Self(ConsensusMemory::open(dev)?))
+        }
+
+        fn finalize_store(&self) -> &FinalizeStore<CurrentNetwork, Self::FinalizeStorage> {
+            self.0.finalize_store()
+        }
+
+        fn block_store(&self) -> &BlockStore<CurrentNetwork, Self::BlockStorage> {
+            self.0.block_store()
+        }
+
+        fn storage_health(&self) -> StorageHealth {
+            StorageHealth::new(Some(StorageError::DiskFull("No space left on device".to_string())), Some(0))
+        }
+    }
+
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+
+    // Ensure a healthy storage reports as such.
+    let ledger = CurrentLedger::load(genesis.clone(), None).unwrap();
+    assert!(ledger.storage_health().is_healthy());
+
+    // Ensure the ledger refuses to write the genesis block to a full disk.
+    let error = crate::Ledger::<CurrentNetwork, DiskFullMemory>::load(genesis, None).map(|_| ()).unwrap_err();
+    assert!(error.downcast_ref::<StorageError>().unwrap().is_disk_full());
+    assert!(error.to_string().contains("storage is unhealthy"));
+}
+
+#[test]
+fn test_advance_to_next_block_rate_limits_storage_health_checks() {
+    use ledger_store::{
+        helpers::{
+            memory::{BlockMemory, FinalizeMemory, TransactionMemory, TransitionMemory},
+            StorageHealth,
+        },
+        BlockStore,
+        ConsensusStorage,
+        FinalizeStore,
+    };
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// The number of storage health checks performed.
+    static NUM_CHECKS: AtomicUsize = AtomicUsize::new(0);
+
+    /// A consensus storage that counts its storage health checks.
+    #[derive(Clone)]
+    struct CountingMemory(ConsensusMemory<CurrentNetwork>);
+
+    impl ConsensusStorage<CurrentNetwork> for CountingMemory {
+        type BlockStorage = BlockMemory<CurrentNetwork>;
+        type FinalizeStorage = FinalizeMemory<CurrentNetwork>;
+        type TransactionStorage = TransactionMemory<CurrentNetwork>;
+        type TransitionStorage = TransitionMemory<CurrentNetwork>;
+
+        fn open(dev: Option<u16>) -> Result<Self> {
+            Ok(Self(ConsensusMemory::open(dev)?))
+        }
+
+        fn finalize_store(&self) -> &FinalizeStore<CurrentNetwork, Self::FinalizeStorage> {
+            self.0.finalize_store()
+        }
+
+        fn block_store(&self) -> &BlockStore<CurrentNetwork, Self::BlockStorage> {
+            self.0.block_store()
+        }
+
+        fn storage_health(&self) -> StorageHealth {
+            NUM_CHECKS.fetch_add(1, Ordering::SeqCst);
+            StorageHealth::healthy(None)
+        }
+    }
+
+    let rng = &mut TestRng::default();
+
+    // Initialize the ledger, which checks the storage before writing the genesis block.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let store = ConsensusStore::<_, CountingMemory>::open(None).unwrap();
+    let genesis = VM::from(store).unwrap().genesis_beacon(&private_key, rng).unwrap();
+    let ledger = crate::Ledger::<CurrentNetwork, CountingMemory>::load(genesis, None).unwrap();
+    assert_eq!(NUM_CHECKS.load(Ordering::SeqCst), 1);
+
+    // Ensure the storage is not checked again within the interval.
+    for _ in 0..2 {
+        let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+    }
+    assert_eq!(NUM_CHECKS.load(Ordering::SeqCst), 1);
+
+    // Ensure the storage is checked again after a failed write.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert!(ledger.advance_to_next_block(&block).is_err());
+    assert_eq!(NUM_CHECKS.load(Ordering::SeqCst), 1);
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(NUM_CHECKS.load(Ordering::SeqCst), 2);
+}
+
+/// Returns a credits invariants harness, with the genesis account and the given number of funded accounts.
+fn sample_credits_invariants(
+    num_accounts: usize,
+    rng: &mut TestRng,
+) -> crate::credits_invariants::CreditsInvariants<CurrentNetwork, ConsensusMemory<CurrentNetwork>> {
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Sample the accounts.
+    let accounts = (0..num_accounts).map(|_| PrivateKey::<CurrentNetwork>::new(rng).unwrap()).collect::<Vec<_>>();
+
+    // Fund the accounts.
+    let transactions = accounts
+        .iter()
+        .map(|account| {
+            let inputs = [
+                Value::from_str(&format!("{}", Address::try_from(account).unwrap())).unwrap(),
+                Value::from_str("1000000000u64").unwrap(), // 1 thousand credits.
+            ];
+            ledger.vm.execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        })
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], transactions, rng).unwrap();
+    ledger.check_next_block(&block).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Initialize the harness.
+    let private_keys = std::iter::once(private_key).chain(accounts).collect();
+    crate::credits_invariants::CreditsInvariants::new(ledger, private_key, private_keys).unwrap()
+}
+
+#[test]
+fn test_credits_invariants() {
+    // Note: Set `SNARKVM_TEST_SEED` to reproduce a failing sequence.
+    let rng = &mut TestRng::from_env_or(1234567890);
+
+    // Initialize the harness.
+    let mut harness = sample_credits_invariants(3, rng);
+
+    // Run the randomized operations, checking the invariants after every block.
+    harness.run(8, 3, rng).unwrap();
+
+    // Ensure the sequence exercised the fees and staking rewards.
+    assert!(harness.model().fees_burned() > 0);
+    assert!(harness.model().rewards_minted() > 0);
+}
+
+#[test]
+fn test_credits_invariants_detect_reward_off_by_one() {
+    use synthesizer::program::FinalizeStoreTrait;
+
+    let rng = &mut TestRng::default();
+
+    // Initialize the harness.
+    let mut harness = sample_credits_invariants(0, rng);
+    // Advance by one block, which ratifies the staking rewards.
+    harness.advance(vec![], rng).unwrap();
+    assert!(harness.model().rewards_minted() > 0);
+
+    // Retrieve a staker, whose bond has received a staking reward.
+    let staker = harness.addresses()[0];
+    let (validator, microcredits) = harness.model().bond(&staker).unwrap();
+    let (stake, is_open) = harness.ledger().latest_committee().unwrap().members()[&validator];
+
+    // Emulate an off-by-one in the staking reward, by adding a microcredit to the bond and committee stake.
+    let program_id = ProgramID::from_str("credits.aleo").unwrap();
+    let finalize_store = harness.ledger().vm().finalize_store();
+    let bond_state = format!("{{ validator: {validator}, microcredits: {}u64 }}", microcredits + 1);
+    let committee_state = format!("{{ microcredits: {}u64, is_open: {is_open} }}", stake + 1);
+    finalize_store
+        .update_key_value(
+            program_id,
+            Identifier::from_str("bonded").unwrap(),
+            Plaintext::from(Literal::Address(staker)),
+            Value::from_str(&bond_state).unwrap(),
+        )
+        .unwrap();
+    finalize_store
+        .update_key_value(
+            program_id,
+            Identifier::from_str("committee").unwrap(),
+            Plaintext::from(Literal::Address(validator)),
+            Value::from_str(&committee_state).unwrap(),
+        )
+        .unwrap();
+
+    // Ensure the invariants detect the off-by-one.
+    let error = harness.check_invariants().unwrap_err();
+    assert!(error.to_string().contains("'bonded' mapping"), "{error}");
+}
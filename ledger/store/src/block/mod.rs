@@ -196,6 +196,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     type RejectedOrAbortedTransactionIDMap: for<'a> Map<'a, N::TransactionID, N::BlockHash>;
     /// The mapping of `transaction ID` to `(block hash, confirmed tx type, confirmed blob)`.
     type ConfirmedTransactionsMap: for<'a> Map<'a, N::TransactionID, (N::BlockHash, ConfirmedTxType, Vec<u8>)>;
+    /// The mapping of pruned `transaction ID` to `block hash`.
+    type PrunedTransactionIDMap: for<'a> Map<'a, N::TransactionID, N::BlockHash>;
     /// The transaction storage.
     type TransactionStorage: TransactionStorage<N, TransitionStorage = Self::TransitionStorage>;
     /// The transition storage.
@@ -232,6 +234,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     fn rejected_or_aborted_transaction_id_map(&self) -> &Self::RejectedOrAbortedTransactionIDMap;
     /// Returns the confirmed transactions map.
     fn confirmed_transactions_map(&self) -> &Self::ConfirmedTransactionsMap;
+    /// Returns the pruned transaction ID map.
+    fn pruned_transaction_id_map(&self) -> &Self::PrunedTransactionIDMap;
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage>;
 
@@ -245,6 +249,14 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.transition_store().dev()
     }
 
+    /// Runs the given closure, with all confirmed reads served from a consistent snapshot of the storage,
+    /// so that the reads do not observe an atomic batch that is committed in between them.
+    ///
+    /// Note: By default, the closure is run directly against the latest committed state.
+    fn read_at_snapshot<T>(&self, f: impl FnOnce() -> Result<T>) -> Result<T> {
+        f()
+    }
+
     /// Starts an atomic batch write operation.
     fn start_atomic(&self) {
         self.state_root_map().start_atomic();
@@ -261,6 +273,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.aborted_transaction_ids_map().start_atomic();
         self.rejected_or_aborted_transaction_id_map().start_atomic();
         self.confirmed_transactions_map().start_atomic();
+        self.pruned_transaction_id_map().start_atomic();
         self.transaction_store().start_atomic();
     }
 
@@ -280,26 +293,28 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.aborted_transaction_ids_map().is_atomic_in_progress()
             || self.rejected_or_aborted_transaction_id_map().is_atomic_in_progress()
             || self.confirmed_transactions_map().is_atomic_in_progress()
+            || self.pruned_transaction_id_map().is_atomic_in_progress()
             || self.transaction_store().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
-    fn atomic_checkpoint(&self) {
-        self.state_root_map().atomic_checkpoint();
-        self.reverse_state_root_map().atomic_checkpoint();
-        self.id_map().atomic_checkpoint();
-        self.reverse_id_map().atomic_checkpoint();
-        self.header_map().atomic_checkpoint();
-        self.authority_map().atomic_checkpoint();
-        self.certificate_map().atomic_checkpoint();
-        self.ratifications_map().atomic_checkpoint();
-        self.solutions_map().atomic_checkpoint();
-        self.puzzle_commitments_map().atomic_checkpoint();
-        self.transactions_map().atomic_checkpoint();
-        self.aborted_transaction_ids_map().atomic_checkpoint();
-        self.rejected_or_aborted_transaction_id_map().atomic_checkpoint();
-        self.confirmed_transactions_map().atomic_checkpoint();
-        self.transaction_store().atomic_checkpoint();
+    fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        self.state_root_map().atomic_checkpoint(label);
+        self.reverse_state_root_map().atomic_checkpoint(label);
+        self.id_map().atomic_checkpoint(label);
+        self.reverse_id_map().atomic_checkpoint(label);
+        self.header_map().atomic_checkpoint(label);
+        self.authority_map().atomic_checkpoint(label);
+        self.certificate_map().atomic_checkpoint(label);
+        self.ratifications_map().atomic_checkpoint(label);
+        self.solutions_map().atomic_checkpoint(label);
+        self.puzzle_commitments_map().atomic_checkpoint(label);
+        self.transactions_map().atomic_checkpoint(label);
+        self.aborted_transaction_ids_map().atomic_checkpoint(label);
+        self.rejected_or_aborted_transaction_id_map().atomic_checkpoint(label);
+        self.confirmed_transactions_map().atomic_checkpoint(label);
+        self.pruned_transaction_id_map().atomic_checkpoint(label);
+        self.transaction_store().atomic_checkpoint(label);
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -318,6 +333,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.aborted_transaction_ids_map().clear_latest_checkpoint();
         self.rejected_or_aborted_transaction_id_map().clear_latest_checkpoint();
         self.confirmed_transactions_map().clear_latest_checkpoint();
+        self.pruned_transaction_id_map().clear_latest_checkpoint();
         self.transaction_store().clear_latest_checkpoint();
     }
 
@@ -337,6 +353,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.aborted_transaction_ids_map().atomic_rewind();
         self.rejected_or_aborted_transaction_id_map().atomic_rewind();
         self.confirmed_transactions_map().atomic_rewind();
+        self.pruned_transaction_id_map().atomic_rewind();
         self.transaction_store().atomic_rewind();
     }
 
@@ -356,6 +373,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.aborted_transaction_ids_map().abort_atomic();
         self.rejected_or_aborted_transaction_id_map().abort_atomic();
         self.confirmed_transactions_map().abort_atomic();
+        self.pruned_transaction_id_map().abort_atomic();
         self.transaction_store().abort_atomic();
     }
 
@@ -375,6 +393,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.aborted_transaction_ids_map().finish_atomic()?;
         self.rejected_or_aborted_transaction_id_map().finish_atomic()?;
         self.confirmed_transactions_map().finish_atomic()?;
+        self.pruned_transaction_id_map().finish_atomic()?;
         self.transaction_store().finish_atomic()
     }
 
@@ -390,7 +409,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
 
         // Retrieve the certificate IDs to store.
         let certificates_to_store = match block.authority() {
-            Authority::Beacon(_) => Vec::new(),
+            Authority::Beacon(_) | Authority::GenesisQuorum(_) => Vec::new(),
             Authority::Quorum(subdag) => subdag
                 .iter()
                 .flat_map(|(round, certificates)| certificates.iter().map(|c| (c.certificate_id(), *round)))
@@ -509,7 +528,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         // Determine the certificate IDs to remove.
         let certificate_ids_to_remove = match self.authority_map().get_confirmed(block_hash)? {
             Some(authority) => match authority {
-                Cow::Owned(Authority::Beacon(_)) | Cow::Borrowed(Authority::Beacon(_)) => Vec::new(),
+                Cow::Owned(Authority::Beacon(_) | Authority::GenesisQuorum(_))
+                | Cow::Borrowed(Authority::Beacon(_) | Authority::GenesisQuorum(_)) => Vec::new(),
                 Cow::Owned(Authority::Quorum(ref subdag)) | Cow::Borrowed(Authority::Quorum(ref subdag)) => {
                     subdag.values().flatten().map(|c| c.certificate_id()).collect()
                 }
@@ -962,6 +982,87 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         Ok(())
     }
 
+    /// Stores the given checkpoint block into storage, along with the pruned blocks below it.
+    /// The storage must be empty, and `block_hashes` and `state_roots` must contain the hash and state root
+    /// of every block up to, and including, the checkpoint block. For every pruned block, `transaction_ids`
+    /// contains the IDs of its (confirmed, rejected, and aborted) transactions, and `spent` contains the
+    /// `(serial number, tag)` pairs of the records spent below the checkpoint.
+    ///
+    /// Note: The pruned blocks are only stored by their hash, state root, transaction IDs, and spent records,
+    /// so the block tree (and the state root) matches the full ledger, and the pruned transactions and records
+    /// can not be replayed, while the contents of the pruned blocks are unavailable.
+    pub fn insert_checkpoint(
+        &self,
+        block_hashes: &[N::BlockHash],
+        state_roots: &[N::StateRoot],
+        transaction_ids: &[Vec<N::TransactionID>],
+        spent: &[(Field<N>, Field<N>)],
+        block: &Block<N>,
+    ) -> Result<()> {
+        // Acquire the write lock on the block tree.
+        let mut tree = self.tree.write();
+        // Ensure the storage is empty.
+        ensure!(tree.number_of_leaves() == 0, "Block storage must be empty to insert a checkpoint");
+        // Ensure the block hashes end with the checkpoint block.
+        let num_blocks = block.height() as usize + 1;
+        ensure!(
+            block_hashes.len() == num_blocks && block_hashes.last() == Some(&block.hash()),
+            "The block hashes do not end with the checkpoint block {}",
+            block.height()
+        );
+        // Ensure there is a state root for every block, and a list of transaction IDs for every pruned block.
+        ensure!(state_roots.len() == num_blocks, "Incorrect number of state roots for the checkpoint");
+        ensure!(transaction_ids.len() == num_blocks - 1, "Incorrect number of transaction ID lists for the checkpoint");
+
+        // Construct the block tree for the pruned blocks.
+        let pruned_hashes = &block_hashes[..num_blocks - 1];
+        let leaves = pruned_hashes.iter().map(|hash| hash.to_bits_le()).collect::<Vec<_>>();
+        let pruned_tree: BlockTree<N> = N::merkle_tree_bhp(&leaves)?;
+        // Ensure the pruned blocks correspond to the previous state root of the checkpoint block.
+        ensure!(
+            N::StateRoot::from(*pruned_tree.root()) == block.previous_state_root()
+                && state_roots[num_blocks - 2] == block.previous_state_root(),
+            "The block hashes do not match the previous state root of the checkpoint block {}",
+            block.height()
+        );
+        // Prepare an updated Merkle tree containing the checkpoint block hash.
+        let updated_tree = pruned_tree.prepare_append(&[block.hash().to_bits_le()])?;
+        // Ensure the checkpoint block corresponds to the last state root.
+        ensure!(
+            N::StateRoot::from(*updated_tree.root()) == state_roots[num_blocks - 1],
+            "The state root does not match the checkpoint block {}",
+            block.height()
+        );
+
+        atomic_batch_scope!(self, {
+            // Store the pruned blocks.
+            for (height, ((block_hash, state_root), transaction_ids)) in
+                pruned_hashes.iter().zip(state_roots).zip(transaction_ids).enumerate()
+            {
+                let height = u32::try_from(height)?;
+                // Store the block hash.
+                self.storage.id_map().insert(height, *block_hash)?;
+                self.storage.reverse_id_map().insert(*block_hash, height)?;
+                // Store the state root.
+                self.storage.state_root_map().insert(height, *state_root)?;
+                self.storage.reverse_state_root_map().insert(*state_root, height)?;
+                // Store the transaction IDs.
+                for transaction_id in transaction_ids {
+                    self.storage.pruned_transaction_id_map().insert(*transaction_id, *block_hash)?;
+                }
+            }
+            // Store the spent records.
+            self.storage.transition_store().insert_spent(spent)?;
+            // Store the checkpoint block.
+            self.storage.insert((*updated_tree.root()).into(), block)
+        })?;
+
+        // Update the block tree.
+        *tree = updated_tree;
+        // Return success.
+        Ok(())
+    }
+
     /// Removes the last 'n' blocks from storage.
     pub fn remove_last_n(&self, n: u32) -> Result<()> {
         // Ensure 'n' is non-zero.
@@ -1033,8 +1134,8 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     }
 
     /// Checkpoints the atomic batch.
-    pub fn atomic_checkpoint(&self) {
-        self.storage.atomic_checkpoint();
+    pub fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        self.storage.atomic_checkpoint(label);
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -1096,7 +1197,8 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
 
     /// Returns a state path for the given `commitment`.
     pub fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
-        self.storage.get_state_path_for_commitment(commitment, &self.tree.read())
+        let tree = self.tree.read();
+        self.storage.read_at_snapshot(|| self.storage.get_state_path_for_commitment(commitment, &tree))
     }
 
     /// Returns the previous block hash of the given `block height`.
@@ -1136,12 +1238,12 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
 
     /// Returns the prover solution for the given solution ID.
     pub fn get_solution(&self, solution_id: &PuzzleCommitment<N>) -> Result<ProverSolution<N>> {
-        self.storage.get_solution(solution_id)
+        self.storage.read_at_snapshot(|| self.storage.get_solution(solution_id))
     }
 
     /// Returns the block transactions for the given `block hash`.
     pub fn get_block_transactions(&self, block_hash: &N::BlockHash) -> Result<Option<Transactions<N>>> {
-        self.storage.get_block_transactions(block_hash)
+        self.storage.read_at_snapshot(|| self.storage.get_block_transactions(block_hash))
     }
 
     /// Returns the block aborted transaction IDs for the given `block hash`.
@@ -1157,54 +1259,62 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         &self,
         transaction_id: &N::TransactionID,
     ) -> Result<Option<ConfirmedTransaction<N>>> {
-        self.storage.get_confirmed_transaction(*transaction_id)
+        self.storage.read_at_snapshot(|| self.storage.get_confirmed_transaction(*transaction_id))
     }
 
     /// Returns the block for the given `block hash`.
     pub fn get_block(&self, block_hash: &N::BlockHash) -> Result<Option<Block<N>>> {
-        self.storage.get_block(block_hash)
+        // Acquire the read lock on the block tree, so that a concurrent insertion or removal is not observed partially.
+        let _tree = self.tree.read();
+        // Retrieve the block from a consistent snapshot of the storage.
+        self.storage.read_at_snapshot(|| self.storage.get_block(block_hash))
     }
 
     /// Returns the program for the given `program ID`.
     pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
-        self.storage.transaction_store().get_program(program_id)
+        self.storage.read_at_snapshot(|| self.storage.transaction_store().get_program(program_id))
     }
 
     /// Returns the batch certificate for the given `certificate ID`.
     pub fn get_batch_certificate(&self, certificate_id: &Field<N>) -> Result<Option<BatchCertificate<N>>> {
-        self.storage.get_batch_certificate(certificate_id)
+        self.storage.read_at_snapshot(|| self.storage.get_batch_certificate(certificate_id))
     }
 }
 
 impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     /// Returns `true` if the given state root exists.
     pub fn contains_state_root(&self, state_root: &N::StateRoot) -> Result<bool> {
-        self.storage.reverse_state_root_map().contains_key_confirmed(state_root)
+        Ok(self.storage.reverse_state_root_map().contains_key_confirmed(state_root)?)
     }
 
     /// Returns `true` if the given block height exists.
     pub fn contains_block_height(&self, height: u32) -> Result<bool> {
-        self.storage.id_map().contains_key_confirmed(&height)
+        Ok(self.storage.id_map().contains_key_confirmed(&height)?)
     }
 
     /// Returns `true` if the given block hash exists.
     pub fn contains_block_hash(&self, block_hash: &N::BlockHash) -> Result<bool> {
-        self.storage.reverse_id_map().contains_key_confirmed(block_hash)
+        Ok(self.storage.reverse_id_map().contains_key_confirmed(block_hash)?)
     }
 
     /// Returns `true` if the given rejected or aborted transaction ID exists.
     pub fn contains_rejected_or_aborted_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<bool> {
-        self.storage.rejected_or_aborted_transaction_id_map().contains_key_confirmed(transaction_id)
+        Ok(self.storage.rejected_or_aborted_transaction_id_map().contains_key_confirmed(transaction_id)?)
+    }
+
+    /// Returns `true` if the given transaction ID is in a block pruned below a ledger checkpoint.
+    pub fn contains_pruned_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<bool> {
+        Ok(self.storage.pruned_transaction_id_map().contains_key_confirmed(transaction_id)?)
     }
 
     /// Returns `true` if the given certificate ID exists.
     pub fn contains_certificate(&self, certificate_id: &Field<N>) -> Result<bool> {
-        self.storage.certificate_map().contains_key_confirmed(certificate_id)
+        Ok(self.storage.certificate_map().contains_key_confirmed(certificate_id)?)
     }
 
     /// Returns `true` if the given puzzle commitment exists.
     pub fn contains_puzzle_commitment(&self, puzzle_commitment: &PuzzleCommitment<N>) -> Result<bool> {
-        self.storage.puzzle_commitments_map().contains_key_confirmed(puzzle_commitment)
+        Ok(self.storage.puzzle_commitments_map().contains_key_confirmed(puzzle_commitment)?)
     }
 }
 
@@ -1307,4 +1417,39 @@ mod tests {
             assert_eq!(None, candidate);
         }
     }
+
+    #[test]
+    fn test_get_block_during_concurrent_writes() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+        let is_writing = std::sync::atomic::AtomicBool::new(true);
+        std::thread::scope(|scope| {
+            // Spawn a writer that repeatedly inserts and removes the block.
+            scope.spawn(|| {
+                for _ in 0..50 {
+                    block_store.insert(&block).unwrap();
+                    block_store.remove_last_n(1).unwrap();
+                }
+                is_writing.store(false, std::sync::atomic::Ordering::SeqCst);
+            });
+
+            // Poll for the block, until the writer is done.
+            while is_writing.load(std::sync::atomic::Ordering::SeqCst) {
+                if let Some(block_hash) = block_store.get_block_hash(0).unwrap() {
+                    // Ensure the block is either fully present or fully absent.
+                    match block_store.get_block(&block_hash) {
+                        Ok(Some(candidate)) => assert_eq!(block.transactions(), candidate.transactions()),
+                        Ok(None) => (),
+                        Err(error) => panic!("Observed a partially written block: {error}"),
+                    }
+                }
+            }
+        });
+    }
 }
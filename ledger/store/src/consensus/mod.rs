@@ -13,8 +13,10 @@
 // limitations under the License.
 
 use crate::{
+    helpers::{StorageHealth, StorageOptions},
     BlockStorage,
     BlockStore,
+    FinalizeHistory,
     FinalizeStorage,
     FinalizeStore,
     TransactionStorage,
@@ -41,6 +43,13 @@ pub trait ConsensusStorage<N: Network>: 'static + Clone + Send + Sync {
     /// Initializes the consensus storage.
     fn open(dev: Option<u16>) -> Result<Self>;
 
+    /// Initializes the consensus storage with the given storage options.
+    /// By default, the storage has no tuning options, so any non-default options are rejected.
+    fn open_with_options(dev: Option<u16>, options: StorageOptions) -> Result<Self> {
+        ensure!(options == StorageOptions::default(), "The storage does not support tuning options: {options:?}");
+        Self::open(dev)
+    }
+
     /// Returns the finalize storage.
     fn finalize_store(&self) -> &FinalizeStore<N, Self::FinalizeStorage>;
     /// Returns the block storage.
@@ -60,6 +69,16 @@ pub trait ConsensusStorage<N: Network>: 'static + Clone + Send + Sync {
         self.transition_store().dev()
     }
 
+    /// Returns the health of the storage write path.
+    fn storage_health(&self) -> StorageHealth {
+        StorageHealth::healthy(None)
+    }
+
+    /// Compacts the storage, discarding deleted and overwritten entries.
+    fn compact_all(&self) -> Result<()> {
+        Ok(())
+    }
+
     /// Starts an atomic batch write operation.
     fn start_atomic(&self) {
         self.finalize_store().start_atomic();
@@ -72,9 +91,9 @@ pub trait ConsensusStorage<N: Network>: 'static + Clone + Send + Sync {
     }
 
     /// Checkpoints the atomic batch.
-    fn atomic_checkpoint(&self) {
-        self.finalize_store().atomic_checkpoint();
-        self.block_store().atomic_checkpoint();
+    fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        self.finalize_store().atomic_checkpoint(label);
+        self.block_store().atomic_checkpoint(label);
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -120,6 +139,24 @@ impl<N: Network, C: ConsensusStorage<N>> ConsensusStore<N, C> {
         Ok(Self { storage, _phantom: PhantomData })
     }
 
+    /// Initializes the consensus store with the given storage options.
+    pub fn open_with_options(dev: Option<u16>, options: StorageOptions) -> Result<Self> {
+        // Initialize the consensus storage.
+        let storage = C::open_with_options(dev, options)?;
+        // Return the consensus store.
+        Ok(Self { storage, _phantom: PhantomData })
+    }
+
+    /// Initializes the consensus store, with the given history mode enabled in the finalize store.
+    pub fn open_with_history(dev: Option<u16>, history: FinalizeHistory) -> Result<Self> {
+        // Initialize the consensus storage.
+        let storage = C::open(dev)?;
+        // Enable the history mode of the finalize store.
+        storage.finalize_store().enable_history(history)?;
+        // Return the consensus store.
+        Ok(Self { storage, _phantom: PhantomData })
+    }
+
     /// Initializes a consensus store from storage.
     pub fn from(storage: C) -> Self {
         Self { storage, _phantom: PhantomData }
@@ -145,6 +182,16 @@ impl<N: Network, C: ConsensusStorage<N>> ConsensusStore<N, C> {
         self.storage.transition_store()
     }
 
+    /// Returns the health of the storage write path.
+    pub fn storage_health(&self) -> StorageHealth {
+        self.storage.storage_health()
+    }
+
+    /// Compacts the storage, discarding deleted and overwritten entries.
+    pub fn compact_all(&self) -> Result<()> {
+        self.storage.compact_all()
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -156,8 +203,8 @@ impl<N: Network, C: ConsensusStorage<N>> ConsensusStore<N, C> {
     }
 
     /// Checkpoints the atomic batch.
-    pub fn atomic_checkpoint(&self) {
-        self.storage.atomic_checkpoint();
+    pub fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        self.storage.atomic_checkpoint(label);
     }
 
     /// Clears the latest atomic batch checkpoint.
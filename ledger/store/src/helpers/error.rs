@@ -0,0 +1,167 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt;
+
+/// The classification of a failure reported by the storage backend.
+///
+/// Storage errors are returned by the fallible methods of the `Map` and `NestedMap` traits.
+/// Once converted into an `anyhow::Error` by a higher layer, they may be recovered with
+/// `error.downcast_ref::<StorageError>()`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StorageError {
+    /// The device backing the storage has no space left.
+    DiskFull(String),
+    /// The storage backend detected corrupted data.
+    ///
+    /// If the corruption was detected while decoding a stored value, the ID of the map and the raw key are included.
+    Corruption { map: Option<u16>, key_bytes: Option<Vec<u8>>, message: String },
+    /// A key or value could not be serialized.
+    Serialization(String),
+    /// The filesystem backing the storage is mounted read-only.
+    ReadOnlyFs(String),
+    /// The storage backend is temporarily unavailable (e.g. locked or timed out).
+    Busy(String),
+    /// The storage backend failed to read from or write to the filesystem.
+    Io(String),
+    /// Any other storage failure.
+    Other(String),
+}
+
+impl StorageError {
+    /// Returns a corruption error that is not attributed to a specific map entry.
+    pub fn corruption(message: impl Into<String>) -> Self {
+        Self::Corruption { map: None, key_bytes: None, message: message.into() }
+    }
+
+    /// Returns `true` if the error indicates that the disk is full.
+    pub const fn is_disk_full(&self) -> bool {
+        matches!(self, Self::DiskFull(_))
+    }
+
+    /// Returns `true` if retrying the operation at a later time may succeed.
+    pub const fn is_transient(&self) -> bool {
+        matches!(self, Self::Busy(_))
+    }
+
+    /// Returns `true` if the error indicates that the stored data is corrupted.
+    pub const fn is_corruption(&self) -> bool {
+        matches!(self, Self::Corruption { .. })
+    }
+
+    /// Returns the underlying error message.
+    pub fn message(&self) -> &str {
+        match self {
+            Self::DiskFull(message)
+            | Self::Corruption { message, .. }
+            | Self::Serialization(message)
+            | Self::ReadOnlyFs(message)
+            | Self::Busy(message)
+            | Self::Io(message)
+            | Self::Other(message) => message,
+        }
+    }
+}
+
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::DiskFull(message) => write!(f, "Storage error (disk full): {message}"),
+            Self::Corruption { map: Some(map), message, .. } => {
+                write!(f, "Storage error (corruption in map {map}): {message}")
+            }
+            Self::Corruption { map: None, message, .. } => write!(f, "Storage error (corruption): {message}"),
+            Self::Serialization(message) => write!(f, "Storage error (serialization): {message}"),
+            Self::ReadOnlyFs(message) => write!(f, "Storage error (read-only filesystem): {message}"),
+            Self::Busy(message) => write!(f, "Storage error (busy): {message}"),
+            Self::Io(message) => write!(f, "Storage error (IO): {message}"),
+            Self::Other(message) => write!(f, "Storage error: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for StorageError {}
+
+impl From<std::io::Error> for StorageError {
+    /// Classifies the given IO error.
+    fn from(error: std::io::Error) -> Self {
+        // Note: `std::io::ErrorKind::StorageFull` and `ReadOnlyFilesystem` are not yet stable,
+        // so the raw OS error code is inspected instead.
+        #[cfg(unix)]
+        match error.raw_os_error() {
+            // ENOSPC and EDQUOT.
+            Some(28) | Some(122) => return Self::DiskFull(error.to_string()),
+            // EROFS.
+            Some(30) => return Self::ReadOnlyFs(error.to_string()),
+            _ => (),
+        }
+        match error.kind() {
+            std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut | std::io::ErrorKind::Interrupted => {
+                Self::Busy(error.to_string())
+            }
+            std::io::ErrorKind::InvalidData | std::io::ErrorKind::UnexpectedEof => Self::corruption(error.to_string()),
+            _ => Self::Io(error.to_string()),
+        }
+    }
+}
+
+impl From<bincode::Error> for StorageError {
+    /// Wraps the given serialization error.
+    fn from(error: bincode::Error) -> Self {
+        Self::Serialization(error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_storage_error_downcast() {
+        let error = anyhow::Error::from(StorageError::DiskFull("No space left on device".to_string()));
+        let storage_error = error.downcast_ref::<StorageError>().unwrap();
+        assert!(storage_error.is_disk_full());
+        assert!(!storage_error.is_transient());
+        assert_eq!(storage_error.message(), "No space left on device");
+        assert_eq!(error.to_string(), "Storage error (disk full): No space left on device");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_storage_error_from_io_error() {
+        let error = StorageError::from(std::io::Error::from_raw_os_error(28));
+        assert!(error.is_disk_full());
+
+        let error = StorageError::from(std::io::Error::from_raw_os_error(30));
+        assert!(matches!(error, StorageError::ReadOnlyFs(_)));
+
+        let error = StorageError::from(std::io::Error::new(std::io::ErrorKind::TimedOut, "timed out"));
+        assert!(error.is_transient());
+
+        let error = StorageError::from(std::io::Error::new(std::io::ErrorKind::NotFound, "not found"));
+        assert_eq!(error, StorageError::Io("not found".to_string()));
+
+        let error = StorageError::from(std::io::Error::new(std::io::ErrorKind::InvalidData, "bad data"));
+        assert_eq!(error, StorageError::Corruption { map: None, key_bytes: None, message: "bad data".to_string() });
+    }
+
+    #[test]
+    fn test_storage_error_corruption_display() {
+        let error = StorageError::Corruption { map: Some(7), key_bytes: Some(vec![1, 2]), message: "bad".to_string() };
+        assert!(error.is_corruption());
+        assert_eq!(error.message(), "bad");
+        assert_eq!(error.to_string(), "Storage error (corruption in map 7): bad");
+        assert_eq!(StorageError::corruption("bad").to_string(), "Storage error (corruption): bad");
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::StorageError;
+
+/// The result of a storage health check.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageHealth {
+    /// The error encountered while probing the write path, if any.
+    error: Option<StorageError>,
+    /// The available disk space (in bytes) for the data directory, if it can be determined.
+    available_space: Option<u64>,
+}
+
+impl StorageHealth {
+    /// Initializes a new storage health report.
+    pub const fn new(error: Option<StorageError>, available_space: Option<u64>) -> Self {
+        Self { error, available_space }
+    }
+
+    /// Initializes a storage health report for a healthy storage.
+    pub const fn healthy(available_space: Option<u64>) -> Self {
+        Self::new(None, available_space)
+    }
+
+    /// Returns `true` if the storage write path is healthy.
+    pub const fn is_healthy(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Returns the error encountered while probing the write path, if any.
+    pub const fn error(&self) -> Option<&StorageError> {
+        self.error.as_ref()
+    }
+
+    /// Returns the available disk space (in bytes) for the data directory, if it can be determined.
+    pub const fn available_space(&self) -> Option<u64> {
+        self.available_space
+    }
+
+    /// Returns `Ok(())` if the storage is healthy, and the storage error otherwise.
+    pub fn ensure_healthy(&self) -> Result<(), StorageError> {
+        match &self.error {
+            Some(error) => Err(error.clone()),
+            None => Ok(()),
+        }
+    }
+}
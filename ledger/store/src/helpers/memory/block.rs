@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::{
-    helpers::memory::{MemoryMap, TransactionMemory, TransitionMemory},
+    helpers::memory::{MemoryDB, MemoryMap, TransactionMemory, TransitionMemory},
     BlockStorage,
     ConfirmedTxType,
     TransactionStore,
@@ -55,6 +55,8 @@ pub struct BlockMemory<N: Network> {
     rejected_or_aborted_transaction_id_map: MemoryMap<N::TransactionID, N::BlockHash>,
     /// The confirmed transactions map.
     confirmed_transactions_map: MemoryMap<N::TransactionID, (N::BlockHash, ConfirmedTxType, Vec<u8>)>,
+    /// The pruned transaction ID map.
+    pruned_transaction_id_map: MemoryMap<N::TransactionID, N::BlockHash>,
     /// The transaction store.
     transaction_store: TransactionStore<N, TransactionMemory<N>>,
 }
@@ -75,32 +77,37 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     type AbortedTransactionIDsMap = MemoryMap<N::BlockHash, Vec<N::TransactionID>>;
     type RejectedOrAbortedTransactionIDMap = MemoryMap<N::TransactionID, N::BlockHash>;
     type ConfirmedTransactionsMap = MemoryMap<N::TransactionID, (N::BlockHash, ConfirmedTxType, Vec<u8>)>;
+    type PrunedTransactionIDMap = MemoryMap<N::TransactionID, N::BlockHash>;
     type TransactionStorage = TransactionMemory<N>;
     type TransitionStorage = TransitionMemory<N>;
 
     /// Initializes the block storage.
     fn open(dev: Option<u16>) -> Result<Self> {
-        // Initialize the transition store.
-        let transition_store = TransitionStore::<N, TransitionMemory<N>>::open(dev)?;
-        // Initialize the transaction store.
-        let transaction_store = TransactionStore::<N, TransactionMemory<N>>::open(transition_store)?;
-        // Return the block storage.
-        Ok(Self {
-            state_root_map: MemoryMap::default(),
-            reverse_state_root_map: MemoryMap::default(),
-            id_map: MemoryMap::default(),
-            reverse_id_map: MemoryMap::default(),
-            header_map: MemoryMap::default(),
-            authority_map: MemoryMap::default(),
-            certificate_map: MemoryMap::default(),
-            ratifications_map: MemoryMap::default(),
-            solutions_map: MemoryMap::default(),
-            puzzle_commitments_map: MemoryMap::default(),
-            transactions_map: MemoryMap::default(),
-            aborted_transaction_ids_map: MemoryMap::default(),
-            rejected_or_aborted_transaction_id_map: MemoryMap::default(),
-            confirmed_transactions_map: MemoryMap::default(),
-            transaction_store,
+        // Initialize the in-memory database, shared with the nested storages.
+        MemoryDB::open_shared(|database| {
+            // Initialize the transition store.
+            let transition_store = TransitionStore::<N, TransitionMemory<N>>::open(dev)?;
+            // Initialize the transaction store.
+            let transaction_store = TransactionStore::<N, TransactionMemory<N>>::open(transition_store)?;
+            // Return the block storage.
+            Ok(Self {
+                state_root_map: MemoryMap::open_from_db(database),
+                reverse_state_root_map: MemoryMap::open_from_db(database),
+                id_map: MemoryMap::open_from_db(database),
+                reverse_id_map: MemoryMap::open_from_db(database),
+                header_map: MemoryMap::open_from_db(database),
+                authority_map: MemoryMap::open_from_db(database),
+                certificate_map: MemoryMap::open_from_db(database),
+                ratifications_map: MemoryMap::open_from_db(database),
+                solutions_map: MemoryMap::open_from_db(database),
+                puzzle_commitments_map: MemoryMap::open_from_db(database),
+                transactions_map: MemoryMap::open_from_db(database),
+                aborted_transaction_ids_map: MemoryMap::open_from_db(database),
+                rejected_or_aborted_transaction_id_map: MemoryMap::open_from_db(database),
+                confirmed_transactions_map: MemoryMap::open_from_db(database),
+                pruned_transaction_id_map: MemoryMap::open_from_db(database),
+                transaction_store,
+            })
         })
     }
 
@@ -174,6 +181,11 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
         &self.confirmed_transactions_map
     }
 
+    /// Returns the pruned transaction ID map.
+    fn pruned_transaction_id_map(&self) -> &Self::PrunedTransactionIDMap {
+        &self.pruned_transaction_id_map
+    }
+
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
         &self.transaction_store
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::{
-    helpers::memory::{BlockMemory, FinalizeMemory, TransactionMemory, TransitionMemory},
+    helpers::memory::{BlockMemory, FinalizeMemory, MemoryDB, TransactionMemory, TransitionMemory},
     BlockStore,
     ConsensusStorage,
     FinalizeStore,
@@ -38,14 +38,17 @@ impl<N: Network> ConsensusStorage<N> for ConsensusMemory<N> {
 
     /// Initializes the consensus storage.
     fn open(dev: Option<u16>) -> Result<Self> {
-        // Initialize the finalize store.
-        let finalize_store = FinalizeStore::<N, FinalizeMemory<N>>::open(dev)?;
-        // Initialize the block store.
-        let block_store = BlockStore::<N, BlockMemory<N>>::open(dev)?;
-        // Return the consensus storage.
-        Ok(Self {
-            finalize_store,
-            block_store,
+        // Initialize the in-memory database, shared by the finalize store and the block store.
+        MemoryDB::open_shared(|_| {
+            // Initialize the finalize store.
+            let finalize_store = FinalizeStore::<N, FinalizeMemory<N>>::open(dev)?;
+            // Initialize the block store.
+            let block_store = BlockStore::<N, BlockMemory<N>>::open(dev)?;
+            // Return the consensus storage.
+            Ok(Self {
+                finalize_store,
+                block_store,
+            })
         })
     }
 
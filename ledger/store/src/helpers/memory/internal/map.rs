@@ -14,7 +14,8 @@
 
 #![allow(clippy::type_complexity)]
 
-use crate::helpers::{Map, MapRead};
+use super::{MemoryDB, StagedStatus};
+use crate::helpers::{Map, MapRead, StorageError};
 use console::network::prelude::*;
 use indexmap::IndexMap;
 
@@ -40,7 +41,10 @@ pub struct MemoryMap<
     map: Arc<RwLock<BTreeMap<Vec<u8>, V>>>,
     batch_in_progress: Arc<AtomicBool>,
     atomic_batch: Arc<Mutex<Vec<(K, Option<V>)>>>,
-    checkpoint: Arc<Mutex<Vec<usize>>>,
+    checkpoint: Arc<Mutex<Vec<(Option<&'static str>, usize)>>>,
+    staged: Arc<Mutex<Vec<(Vec<u8>, Option<V>)>>>,
+    staged_status: StagedStatus,
+    database: MemoryDB,
 }
 
 impl<
@@ -54,6 +58,41 @@ impl<
             batch_in_progress: Default::default(),
             atomic_batch: Default::default(),
             checkpoint: Default::default(),
+            staged: Default::default(),
+            staged_status: Default::default(),
+            database: Default::default(),
+        }
+    }
+}
+
+impl<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> MemoryMap<K, V>
+{
+    /// Initializes a new, empty `MemoryMap` that shares the atomic state of the given database.
+    pub fn open_from_db(database: &MemoryDB) -> Self {
+        Self { database: database.clone(), ..Default::default() }
+    }
+
+    /// Applies or discards the staged operations, once the database-wide atomic batch is resolved.
+    fn settle(&self) {
+        // Acquire the lock on the staged operations.
+        let mut staged = self.staged.lock();
+        match self.staged_status.resolve() {
+            // Perform all the staged operations.
+            Some(true) => {
+                let mut locked_map = self.map.write();
+                for (key, value) in staged.drain(..) {
+                    match value {
+                        Some(value) => locked_map.insert(key, value),
+                        None => locked_map.remove(&key),
+                    };
+                }
+            }
+            // Discard all the staged operations.
+            Some(false) => staged.clear(),
+            None => (),
         }
     }
 }
@@ -74,6 +113,9 @@ impl<
             batch_in_progress: Default::default(),
             atomic_batch: Default::default(),
             checkpoint: Default::default(),
+            staged: Default::default(),
+            staged_status: Default::default(),
+            database: Default::default(),
         }
     }
 }
@@ -87,7 +129,9 @@ impl<
     ///
     /// Inserts the given key-value pair into the map.
     ///
-    fn insert(&self, key: K, value: V) -> Result<()> {
+    fn insert(&self, key: K, value: V) -> Result<(), StorageError> {
+        // Apply any committed operations first.
+        self.settle();
         // Determine if an atomic batch is in progress.
         match self.is_atomic_in_progress() {
             // If a batch is in progress, add the key-value pair to the batch.
@@ -106,7 +150,9 @@ impl<
     ///
     /// Removes the key-value pair for the given key from the map.
     ///
-    fn remove(&self, key: &K) -> Result<()> {
+    fn remove(&self, key: &K) -> Result<(), StorageError> {
+        // Apply any committed operations first.
+        self.settle();
         // Determine if an atomic batch is in progress.
         match self.is_atomic_in_progress() {
             // If a batch is in progress, add the key-None pair to the batch.
@@ -127,8 +173,12 @@ impl<
     /// without an actual write taking place until `finish_atomic` is called.
     ///
     fn start_atomic(&self) {
+        // Apply any committed operations first.
+        self.settle();
         // Set the atomic batch flag to `true`.
         self.batch_in_progress.store(true, Ordering::SeqCst);
+        // Increment the database-wide atomic depth.
+        self.database.start_atomic();
         // Ensure that the atomic batch is empty.
         assert!(self.atomic_batch.lock().is_empty());
     }
@@ -146,9 +196,16 @@ impl<
     /// Saves the current list of pending operations, so that if `atomic_rewind` is called,
     /// we roll back all future operations, and return to the start of this checkpoint.
     ///
-    fn atomic_checkpoint(&self) {
-        // Push the current length of the atomic batch to the checkpoint stack.
-        self.checkpoint.lock().push(self.atomic_batch.lock().len());
+    fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        // Push the label and the current length of the atomic batch to the checkpoint stack.
+        self.checkpoint.lock().push((label, self.atomic_batch.lock().len()));
+    }
+
+    ///
+    /// Returns the current checkpoint stack, as a list of (label, pending operations length) pairs.
+    ///
+    fn debug_checkpoint_stack(&self) -> Vec<(Option<&'static str>, usize)> {
+        self.checkpoint.lock().clone()
     }
 
     ///
@@ -168,7 +225,7 @@ impl<
         let mut atomic_batch = self.atomic_batch.lock();
 
         // Retrieve the last checkpoint.
-        let checkpoint = self.checkpoint.lock().pop().unwrap_or(0);
+        let checkpoint = self.checkpoint.lock().pop().map(|(_, checkpoint)| checkpoint).unwrap_or(0);
 
         // Remove all operations after the checkpoint.
         atomic_batch.truncate(checkpoint);
@@ -184,12 +241,14 @@ impl<
         *self.checkpoint.lock() = Default::default();
         // Set the atomic batch flag to `false`.
         self.batch_in_progress.store(false, Ordering::SeqCst);
+        // Clear the database-wide atomic state.
+        self.database.abort_atomic();
     }
 
     ///
     /// Finishes an atomic operation, performing all the queued writes.
     ///
-    fn finish_atomic(&self) -> Result<()> {
+    fn finish_atomic(&self) -> Result<(), StorageError> {
         // Retrieve the atomic batch.
         let operations = core::mem::take(&mut *self.atomic_batch.lock());
 
@@ -197,9 +256,6 @@ impl<
         let operations: IndexMap<_, _> = IndexMap::from_iter(operations.into_iter());
 
         if !operations.is_empty() {
-            // Acquire a write lock on the map.
-            let mut locked_map = self.map.write();
-
             // Prepare the key and value for each queued operation.
             //
             // Note: This step is taken to ensure (with 100% certainty) that there will be
@@ -210,15 +266,12 @@ impl<
             let prepared_operations = operations
                 .into_iter()
                 .map(|(key, value)| Ok((bincode::serialize(&key)?, value)))
-                .collect::<Result<Vec<_>>>()?;
-
-            // Perform all the queued operations.
-            for (key, value) in prepared_operations {
-                match value {
-                    Some(value) => locked_map.insert(key, value),
-                    None => locked_map.remove(&key),
-                };
-            }
+                .collect::<Result<Vec<_>, StorageError>>()?;
+
+            // Stage the operations from the map in the database-wide batch.
+            let mut staged = self.staged.lock();
+            staged.extend(prepared_operations);
+            self.database.enqueue(&self.staged_status);
         }
 
         // Clear the checkpoint stack.
@@ -226,6 +279,11 @@ impl<
         // Set the atomic batch flag to `false`.
         self.batch_in_progress.store(false, Ordering::SeqCst);
 
+        // Commit the database-wide batch, if this is the outermost atomic batch.
+        self.database.finish_atomic();
+        // Apply the staged operations, if they were committed.
+        self.settle();
+
         Ok(())
     }
 }
@@ -245,11 +303,13 @@ impl<
     ///
     /// Returns `true` if the given key exists in the map.
     ///
-    fn contains_key_confirmed<Q>(&self, key: &Q) -> Result<bool>
+    fn contains_key_confirmed<Q>(&self, key: &Q) -> Result<bool, StorageError>
     where
         K: Borrow<Q>,
         Q: PartialEq + Eq + Hash + Serialize + ?Sized,
     {
+        // Apply any committed operations first.
+        self.settle();
         Ok(self.map.read().contains_key(&bincode::serialize(key)?))
     }
 
@@ -257,7 +317,7 @@ impl<
     /// Returns `true` if the given key exists in the map.
     /// This method first checks the atomic batch, and if it does not exist, then checks the map.
     ///
-    fn contains_key_speculative<Q>(&self, key: &Q) -> Result<bool>
+    fn contains_key_speculative<Q>(&self, key: &Q) -> Result<bool, StorageError>
     where
         K: Borrow<Q>,
         Q: PartialEq + Eq + Hash + Serialize + ?Sized,
@@ -280,11 +340,13 @@ impl<
     ///
     /// Returns the value for the given key from the map, if it exists.
     ///
-    fn get_confirmed<Q>(&'a self, key: &Q) -> Result<Option<Cow<'a, V>>>
+    fn get_confirmed<Q>(&'a self, key: &Q) -> Result<Option<Cow<'a, V>>, StorageError>
     where
         K: Borrow<Q>,
         Q: PartialEq + Eq + Hash + Serialize + ?Sized,
     {
+        // Apply any committed operations first.
+        self.settle();
         Ok(self.map.read().get(&bincode::serialize(key)?).cloned().map(Cow::Owned))
     }
 
@@ -322,6 +384,8 @@ impl<
     /// Returns an iterator visiting each key-value pair in the map.
     ///
     fn iter_confirmed(&'a self) -> Self::Iterator {
+        // Apply any committed operations first.
+        self.settle();
         // Note: The 'unwrap' is safe here, because the keys are defined by us.
         self.map.read().clone().into_iter().map(|(k, v)| (Cow::Owned(bincode::deserialize(&k).unwrap()), Cow::Owned(v)))
     }
@@ -330,6 +394,8 @@ impl<
     /// Returns an iterator over each key in the map.
     ///
     fn keys_confirmed(&'a self) -> Self::Keys {
+        // Apply any committed operations first.
+        self.settle();
         // Note: The 'unwrap' is safe here, because the keys are defined by us.
         self.map.read().clone().into_keys().map(|k| Cow::Owned(bincode::deserialize(&k).unwrap()))
     }
@@ -338,6 +404,8 @@ impl<
     /// Returns an iterator over each value in the map.
     ///
     fn values_confirmed(&'a self) -> Self::Values {
+        // Apply any committed operations first.
+        self.settle();
         self.map.read().clone().into_values().map(Cow::Owned)
     }
 }
@@ -350,6 +418,8 @@ impl<
     type Target = Arc<RwLock<BTreeMap<Vec<u8>, V>>>;
 
     fn deref(&self) -> &Self::Target {
+        // Apply any committed operations first.
+        self.settle();
         &self.map
     }
 }
@@ -378,6 +448,42 @@ mod tests {
         assert!(map.contains_key_confirmed(&address).unwrap());
     }
 
+    /// A key that fails to serialize.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+    struct UnserializableKey;
+
+    impl Serialize for UnserializableKey {
+        fn serialize<S: Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+            Err(ser::Error::custom("The key cannot be serialized"))
+        }
+    }
+
+    impl<'de> Deserialize<'de> for UnserializableKey {
+        fn deserialize<D: Deserializer<'de>>(_deserializer: D) -> Result<Self, D::Error> {
+            Err(de::Error::custom("The key cannot be deserialized"))
+        }
+    }
+
+    #[test]
+    fn test_serialization_error_is_typed() {
+        // Initialize a map.
+        let map: MemoryMap<UnserializableKey, String> = Default::default();
+
+        // Ensure the reads and writes fail with a typed serialization error.
+        let key = UnserializableKey;
+        assert!(matches!(map.insert(key, "1".to_string()), Err(StorageError::Serialization(_))));
+        assert!(matches!(map.remove(&key), Err(StorageError::Serialization(_))));
+        assert!(matches!(map.contains_key_confirmed(&key), Err(StorageError::Serialization(_))));
+        assert!(matches!(map.get_confirmed(&key), Err(StorageError::Serialization(_))));
+
+        // Ensure a failed atomic batch reports the typed error once converted into an `anyhow::Error`.
+        map.start_atomic();
+        map.insert(key, "1".to_string()).unwrap();
+        let error = anyhow::Error::from(map.finish_atomic().unwrap_err());
+        assert!(matches!(error.downcast_ref::<StorageError>(), Some(StorageError::Serialization(_))));
+        map.abort_atomic();
+    }
+
     #[test]
     fn test_insert_and_get_speculative() {
         // Initialize a map.
@@ -436,7 +542,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
+        assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Start an atomic write batch.
         map.start_atomic();
@@ -451,15 +557,15 @@ mod tests {
             // The pending batch should contain NUM_ITEMS / 2 items.
             assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoint.lock().last(), None);
+            assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
         }
 
         // Run the same sequence of checks 3 times.
         for _ in 0..3 {
             // Perform a checkpoint.
-            map.atomic_checkpoint();
+            map.atomic_checkpoint(None);
             // Make sure the checkpoint index is NUM_ITEMS / 2.
-            assert_eq!(map.checkpoint.lock().last(), Some(&(NUM_ITEMS / 2)));
+            assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), Some(&(NUM_ITEMS / 2)));
 
             {
                 // Queue (since a batch is in progress) another NUM_ITEMS / 2 insertions.
@@ -471,13 +577,13 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS);
                 // Make sure the checkpoint index is NUM_ITEMS / 2.
-                assert_eq!(map.checkpoint.lock().last(), Some(&(NUM_ITEMS / 2)));
+                assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), Some(&(NUM_ITEMS / 2)));
             }
 
             // Abort the current atomic write batch.
             map.atomic_rewind();
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoint.lock().last(), None);
+            assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             {
                 // The map should still contain no items.
@@ -485,7 +591,7 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS / 2 items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
                 // Make sure the checkpoint index is None.
-                assert_eq!(map.checkpoint.lock().last(), None);
+                assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
             }
         }
 
@@ -496,7 +602,7 @@ mod tests {
         // The pending batch should contain no items.
         assert!(map.iter_pending().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
+        assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
     }
 
     #[test]
@@ -509,7 +615,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
+        assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Start a nested atomic batch scope that completes successfully.
         atomic_batch_scope!(map, {
@@ -522,7 +628,7 @@ mod tests {
             // The pending batch should contain NUM_ITEMS / 2 items.
             assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoint.lock().last(), None);
+            assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             // Start a nested atomic batch scope that completes successfully.
             atomic_batch_scope!(map, {
@@ -535,7 +641,7 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS);
                 // Make sure the checkpoint index is NUM_ITEMS / 2.
-                assert_eq!(map.checkpoint.lock().last(), Some(&(NUM_ITEMS / 2)));
+                assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), Some(&(NUM_ITEMS / 2)));
 
                 Ok(())
             })?;
@@ -545,7 +651,7 @@ mod tests {
             // The pending batch should contain NUM_ITEMS items.
             assert_eq!(map.iter_pending().count(), NUM_ITEMS);
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoint.lock().last(), None);
+            assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             Ok(())
         })?;
@@ -555,7 +661,7 @@ mod tests {
         // The pending batch should contain no items.
         assert!(map.iter_pending().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
+        assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         Ok(())
     }
@@ -570,7 +676,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
+        assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Start an atomic write batch.
         let run_nested_atomic_batch_scope = || -> Result<()> {
@@ -585,7 +691,7 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS / 2 items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
                 // Make sure the checkpoint index is None.
-                assert_eq!(map.checkpoint.lock().last(), None);
+                assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
                 // Start a nested atomic write batch that completes correctly.
                 atomic_batch_scope!(map, {
@@ -598,7 +704,7 @@ mod tests {
                     // The pending batch should contain NUM_ITEMS items.
                     assert_eq!(map.iter_pending().count(), NUM_ITEMS);
                     // Make sure the checkpoint index is NUM_ITEMS / 2.
-                    assert_eq!(map.checkpoint.lock().last(), Some(&(NUM_ITEMS / 2)));
+                    assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), Some(&(NUM_ITEMS / 2)));
 
                     bail!("This batch should fail.");
                 })?;
@@ -623,7 +729,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
+        assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Start an atomic finalize.
         let outcome = atomic_finalize!(map, FinalizeMode::RealRun, {
@@ -638,7 +744,7 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS / 2 items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
                 // Make sure the checkpoint index is 0.
-                assert_eq!(map.checkpoint.lock().last(), Some(&0));
+                assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), Some(&0));
 
                 Ok(())
             })
@@ -649,7 +755,7 @@ mod tests {
             // The pending batch should contain NUM_ITEMS / 2 items.
             assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoint.lock().last(), None);
+            assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             // Start a nested atomic write batch that completes correctly.
             atomic_batch_scope!(map, {
@@ -662,7 +768,7 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS);
                 // Make sure the checkpoint index is NUM_ITEMS / 2.
-                assert_eq!(map.checkpoint.lock().last(), Some(&(NUM_ITEMS / 2)));
+                assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), Some(&(NUM_ITEMS / 2)));
 
                 Ok(())
             })
@@ -673,7 +779,7 @@ mod tests {
             // The pending batch should contain NUM_ITEMS items.
             assert_eq!(map.iter_pending().count(), NUM_ITEMS);
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoint.lock().last(), None);
+            assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             Ok((true, 0, "a"))
         });
@@ -686,175 +792,115 @@ mod tests {
         // The pending batch should contain no items.
         assert!(map.iter_pending().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
+        assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         Ok(())
     }
 
     #[test]
     fn test_atomic_finalize_failing_internal_scope() -> Result<()> {
-        // The number of items that will be queued to be inserted into the map.
-        const NUM_ITEMS: usize = 10;
-
         // Initialize a map.
         let map: MemoryMap<usize, String> = Default::default();
-        // Sanity check.
-        assert!(map.iter_confirmed().next().is_none());
-        // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
-
-        // Start an atomic finalize.
-        let outcome = atomic_finalize!(map, FinalizeMode::RealRun, {
-            // Start a nested atomic batch scope that completes successfully.
-            atomic_batch_scope!(map, {
-                // Queue (since a batch is in progress) NUM_ITEMS / 2 insertions.
-                for i in 0..NUM_ITEMS / 2 {
-                    map.insert(i, i.to_string()).unwrap();
-                }
-                // The map should still contain no items.
-                assert!(map.iter_confirmed().next().is_none());
-                // The pending batch should contain NUM_ITEMS / 2 items.
-                assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
-                // Make sure the checkpoint index is 0.
-                assert_eq!(map.checkpoint.lock().last(), Some(&0));
-
-                Ok(())
-            })
-            .unwrap();
-
-            // The map should still contain no items.
-            assert!(map.iter_confirmed().next().is_none());
-            // The pending batch should contain NUM_ITEMS / 2 items.
-            assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
-            // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoint.lock().last(), None);
-
-            // Start a nested atomic write batch that fails.
-            let result: Result<()> = atomic_batch_scope!(map, {
-                // Queue (since a batch is in progress) another NUM_ITEMS / 2 insertions.
-                for i in (NUM_ITEMS / 2)..NUM_ITEMS {
-                    map.insert(i, i.to_string()).unwrap();
-                }
-                // The map should still contain no items.
-                assert!(map.iter_confirmed().next().is_none());
-                // The pending batch should contain NUM_ITEMS items.
-                assert_eq!(map.iter_pending().count(), NUM_ITEMS);
-                // Make sure the checkpoint index is NUM_ITEMS / 2.
-                assert_eq!(map.checkpoint.lock().last(), Some(&(NUM_ITEMS / 2)));
-
-                bail!("This batch scope should fail.");
-            });
-
-            // Ensure that the batch scope failed.
-            assert!(result.is_err());
 
-            // The map should still contain no items.
-            assert!(map.iter_confirmed().next().is_none());
-            // The pending batch should contain NUM_ITEMS / 2 items.
-            assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
-            // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoint.lock().last(), None);
+        crate::helpers::test_helpers::map::check_atomic_finalize_failing_internal_scope(map)
+    }
 
-            Ok(())
-        });
+    #[test]
+    fn test_atomic_finalize_fails_to_start() {
+        // Initialize a map.
+        let map: MemoryMap<usize, String> = Default::default();
 
-        // The atomic finalize should have succeeded.
-        assert!(outcome.is_ok());
+        crate::helpers::test_helpers::map::check_atomic_finalize_fails_to_start(map)
+    }
 
-        // The map should contain NUM_ITEMS / 2.
-        assert_eq!(map.iter_confirmed().count(), NUM_ITEMS / 2);
-        // The pending batch should contain no items.
-        assert!(map.iter_pending().next().is_none());
-        // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
+    #[test]
+    fn test_atomic_checkpoint_truncation() {
+        // Initialize a map.
+        let map: MemoryMap<usize, String> = Default::default();
 
-        Ok(())
+        crate::helpers::test_helpers::map::check_atomic_checkpoint_truncation(map)
     }
 
     #[test]
-    fn test_atomic_finalize_fails_to_start() {
+    fn test_atomic_scope_outcome_top_level_abort() {
         // Initialize a map.
         let map: MemoryMap<usize, String> = Default::default();
-        // Sanity check.
-        assert!(map.iter_confirmed().next().is_none());
-        // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
 
-        // Construct an atomic batch scope.
-        let outcome: Result<()> = atomic_batch_scope!(map, {
-            // Start an atomic finalize.
-            let outcome = atomic_finalize!(map, FinalizeMode::RealRun, { Ok(()) });
-            // Ensure that the atomic finalize fails.
-            assert!(outcome.is_err());
-
-            unreachable!("The batch scope should fail before we reach this point.");
-        });
+        crate::helpers::test_helpers::map::check_atomic_scope_outcome_top_level_abort(map)
+    }
 
-        // Ensure that the atomic batch scope fails.
-        assert!(outcome.is_err());
+    #[test]
+    fn test_atomic_scope_outcome_nested_abort() -> Result<()> {
+        // Initialize a map.
+        let map: MemoryMap<usize, String> = Default::default();
 
-        // Start an atomic operation.
-        map.start_atomic();
+        crate::helpers::test_helpers::map::check_atomic_scope_outcome_nested_abort(map)
+    }
 
-        // We need to catch the `atomic_finalize` here, otherwise it will end the test early.
-        let outcome = || atomic_finalize!(map, FinalizeMode::RealRun, { Ok(()) });
+    #[test]
+    fn test_atomic_finalize_with_aborted_scope() -> Result<()> {
+        // Initialize a map.
+        let map: MemoryMap<usize, String> = Default::default();
 
-        // Ensure that the atomic finalize fails if an atomic batch is in progress.
-        assert!(outcome().is_err());
+        crate::helpers::test_helpers::map::check_atomic_finalize_with_aborted_scope(map)
     }
 
     #[test]
-    fn test_atomic_checkpoint_truncation() {
+    fn test_checkpoint_labels() {
         // Initialize a map.
         let map: MemoryMap<usize, String> = Default::default();
-        // Sanity check.
-        assert!(map.iter_confirmed().next().is_none());
-        // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
-
-        // Insert the key.
-        map.insert(0, "0".to_string()).unwrap();
+        // Make sure the checkpoint stack is empty.
+        assert!(map.debug_checkpoint_stack().is_empty());
 
-        // Start an atomic finalize.
+        // Start an atomic batch scope.
         let outcome = atomic_batch_scope!(map, {
-            // Insert the key.
-            map.insert(0, "1".to_string()).unwrap();
+            // Ensure the top-level scope does not push a checkpoint.
+            assert!(map.debug_checkpoint_stack().is_empty());
+            map.insert(0, "0".to_string()).unwrap();
 
-            // Create a failing atomic batch scope that will reset the checkpoint.
-            let result: Result<()> = atomic_batch_scope!(map, {
-                // Make sure the checkpoint index is 1.
-                assert_eq!(map.checkpoint.lock().last(), Some(&1));
+            // Start a nested atomic batch scope.
+            atomic_batch_scope!(map, {
+                map.insert(1, "1".to_string()).unwrap();
+                let outer_stack = map.debug_checkpoint_stack();
+                assert_eq!(outer_stack.len(), 1);
+                assert_eq!(outer_stack[0].1, 1);
 
-                // Update the key.
-                map.insert(0, "2".to_string()).unwrap();
+                // Push a manual checkpoint with a custom label.
+                map.atomic_checkpoint(Some("manual"));
+                map.insert(2, "2".to_string()).unwrap();
 
-                bail!("This batch scope should fail.")
-            });
+                // Start a doubly-nested atomic batch scope.
+                let result: Result<()> = atomic_batch_scope!(map, {
+                    let inner_stack = map.debug_checkpoint_stack();
+                    assert_eq!(inner_stack.len(), 3);
+                    assert_eq!(inner_stack[..2], [outer_stack[0], (Some("manual"), 2)]);
+                    assert_eq!(inner_stack[2].1, 3);
+                    // Ensure the default labels identify their call sites.
+                    assert!(inner_stack[2].0.unwrap().contains(file!()));
+                    assert_ne!(inner_stack[2].0, outer_stack[0].0);
+
+                    map.insert(3, "3".to_string()).unwrap();
+                    bail!("This batch scope should fail.")
+                });
+                assert!(result.is_err());
 
-            // Ensure that the batch scope failed.
-            assert!(result.is_err());
-            // The map should contain 1 item.
-            assert_eq!(map.iter_confirmed().count(), 1);
-            // The pending batch should contain 1 item.
-            assert_eq!(map.iter_pending().count(), 1);
-            // Ensure the pending operations still has the initial insertion.
-            assert_eq!(map.get_pending(&0), Some(Some("1".to_string())));
-            // Ensure the confirmed value has not changed.
-            assert_eq!(*map.iter_confirmed().next().unwrap().1, "0");
+                // Ensure the failing scope popped only its own checkpoint.
+                assert_eq!(map.debug_checkpoint_stack(), [outer_stack[0], (Some("manual"), 2)]);
+                // Ensure the manual checkpoint is popped next.
+                map.clear_latest_checkpoint();
+                assert_eq!(map.debug_checkpoint_stack(), outer_stack);
+                Ok(())
+            })?;
 
+            // Ensure the nested scope popped its checkpoint.
+            assert!(map.debug_checkpoint_stack().is_empty());
             Ok(())
         });
 
         assert!(outcome.is_ok());
-        // The map should contain 1 item.
-        assert_eq!(map.iter_confirmed().count(), 1);
-        // The pending batch should contain no items.
-        assert!(map.iter_pending().next().is_none());
-        // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
-
-        // Ensure that the map value is correct.
-        assert_eq!(*map.iter_confirmed().next().unwrap().1, "1");
+        // Ensure the rewind behavior is unchanged.
+        assert_eq!(map.iter_confirmed().count(), 3);
+        assert!(map.debug_checkpoint_stack().is_empty());
     }
 
     #[test]
@@ -864,7 +910,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
+        assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Insert the key.
         map.insert(0, "0".to_string()).unwrap();
@@ -875,7 +921,7 @@ mod tests {
             // Simulates an accepted transaction.
             let result: Result<()> = atomic_batch_scope!(map, {
                 // Make sure the checkpoint index is 0.
-                assert_eq!(map.checkpoint.lock().last(), Some(&0));
+                assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), Some(&0));
 
                 // Insert the key.
                 map.insert(0, "1".to_string()).unwrap();
@@ -896,7 +942,7 @@ mod tests {
             // Simulates a rejected transaction.
             let result: Result<()> = atomic_batch_scope!(map, {
                 // Make sure the checkpoint index is 1.
-                assert_eq!(map.checkpoint.lock().last(), Some(&1));
+                assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), Some(&1));
 
                 // Simulate an instruction
                 let result: Result<()> = atomic_batch_scope!(map, {
@@ -910,7 +956,7 @@ mod tests {
                 // Simulates an instruction that fails.
                 let result: Result<()> = atomic_batch_scope!(map, {
                     // Make sure the checkpoint index is 2.
-                    assert_eq!(map.checkpoint.lock().last(), Some(&2));
+                    assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), Some(&2));
 
                     // Update the key.
                     map.insert(0, "3".to_string()).unwrap();
@@ -940,11 +986,61 @@ mod tests {
         // The pending batch should contain no items.
         assert!(map.iter_pending().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
+        assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Ensure that the map value is correct.
         assert_eq!(*map.iter_confirmed().next().unwrap().1, "1");
 
         Ok(())
     }
+
+    #[test]
+    fn test_shared_database_atomic_batch() -> Result<()> {
+        // Initialize two maps that share a database.
+        let database = MemoryDB::default();
+        let map_a: MemoryMap<usize, String> = MemoryMap::open_from_db(&database);
+        let map_b: MemoryMap<usize, String> = MemoryMap::open_from_db(&database);
+
+        // Start an atomic batch on both maps.
+        map_a.start_atomic();
+        map_b.start_atomic();
+
+        // Queue an insertion in each map.
+        map_a.insert(0, "0".to_string())?;
+        map_b.insert(1, "1".to_string())?;
+
+        // Finish the atomic batch of the first map.
+        map_a.finish_atomic()?;
+        // Neither map should contain any items until the second map finishes its atomic batch.
+        assert!(map_a.iter_confirmed().next().is_none());
+        assert!(map_b.iter_confirmed().next().is_none());
+
+        // Finish the atomic batch of the second map.
+        map_b.finish_atomic()?;
+        // Both maps should now contain their item.
+        assert_eq!(map_a.get_confirmed(&0)?, Some(Cow::Owned("0".to_string())));
+        assert_eq!(map_b.get_confirmed(&1)?, Some(Cow::Owned("1".to_string())));
+
+        // Start another atomic batch on both maps.
+        map_a.start_atomic();
+        map_b.start_atomic();
+
+        // Queue a removal in the first map, and finish its atomic batch.
+        map_a.remove(&0)?;
+        map_a.finish_atomic()?;
+
+        // Abort the atomic batch of the second map.
+        map_b.abort_atomic();
+        // The queued removal from the first map should have been discarded.
+        assert_eq!(map_a.get_confirmed(&0)?, Some(Cow::Owned("0".to_string())));
+
+        // Ensure a new atomic batch can be started on the database.
+        atomic_batch_scope!(map_a, {
+            map_a.insert(2, "2".to_string())?;
+            Ok(())
+        })?;
+        assert_eq!(map_a.iter_confirmed().count(), 2);
+
+        Ok(())
+    }
 }
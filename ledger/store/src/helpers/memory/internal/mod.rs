@@ -17,3 +17,132 @@ pub use map::*;
 
 mod nested_map;
 pub use nested_map::*;
+
+use parking_lot::Mutex;
+use std::{
+    cell::RefCell,
+    sync::{
+        atomic::{AtomicU8, AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+thread_local! {
+    /// The database handle that is shared by the storages that are currently being opened.
+    static OPENING_DATABASE: RefCell<Option<MemoryDB>> = RefCell::new(None);
+}
+
+/// An in-memory database handle, shared by the maps opened from it.
+///
+/// This mirrors the database-wide atomic state of `RocksDB`: the staged writes of every map
+/// are only committed once each map that joined the atomic batch has finished it.
+#[derive(Clone, Default)]
+pub struct MemoryDB {
+    /// The status flags of the maps that have staged operations in the current atomic batch.
+    atomic_batch: Arc<Mutex<Vec<StagedStatus>>>,
+    /// The depth of the current atomic write batch; it gets incremented with every call
+    /// to `start_atomic` and decremented with each call to `finish_atomic`.
+    atomic_depth: Arc<AtomicUsize>,
+}
+
+impl MemoryDB {
+    /// Runs the given closure with the database handle shared by all the storages opened
+    /// within it, so that a store and each of its nested stores use a single database.
+    pub fn open_shared<T>(open: impl FnOnce(&MemoryDB) -> T) -> T {
+        // Reuse the database of an enclosing call, if there is one.
+        if let Some(database) = OPENING_DATABASE.with(|current| current.borrow().clone()) {
+            return open(&database);
+        }
+
+        /// Resets the shared database handle, even if the closure panics.
+        struct Reset;
+
+        impl Drop for Reset {
+            fn drop(&mut self) {
+                OPENING_DATABASE.with(|current| *current.borrow_mut() = None);
+            }
+        }
+
+        // Initialize a new database, and share it for the duration of the closure.
+        let database = MemoryDB::default();
+        OPENING_DATABASE.with(|current| *current.borrow_mut() = Some(database.clone()));
+        let _reset = Reset;
+        open(&database)
+    }
+
+    /// Registers the start of an atomic batch for one of the maps.
+    pub(super) fn start_atomic(&self) {
+        // Increment the atomic depth index.
+        let previous_atomic_depth = self.atomic_depth.fetch_add(1, Ordering::SeqCst);
+        // Ensure that the database atomic batch is empty when a new batch begins.
+        if previous_atomic_depth == 0 {
+            assert!(self.atomic_batch.lock().is_empty());
+        }
+    }
+
+    /// Registers the staged operations of a map, to be committed at the end of the outermost atomic batch.
+    pub(super) fn enqueue(&self, status: &StagedStatus) {
+        status.set(StagedStatus::PENDING);
+        self.atomic_batch.lock().push(status.clone());
+    }
+
+    /// Registers the end of an atomic batch for one of the maps, and commits
+    /// all of the staged operations if this is the final call to `finish_atomic`.
+    pub(super) fn finish_atomic(&self) {
+        // Subtract the atomic depth index.
+        let previous_atomic_depth = self.atomic_depth.fetch_sub(1, Ordering::SeqCst);
+
+        // Ensure that the value of `atomic_depth` doesn't overflow, meaning that all the
+        // calls to `start_atomic` have corresponding calls to `finish_atomic`.
+        assert!(previous_atomic_depth != 0);
+
+        // If we're at depth 0, it is the final call to `finish_atomic` and the
+        // staged operations can be committed.
+        if previous_atomic_depth == 1 {
+            // Note: Each map applies its committed operations before its next read or write.
+            core::mem::take(&mut *self.atomic_batch.lock()).iter().for_each(|status| status.set(StagedStatus::COMMITTED));
+        }
+    }
+
+    /// Discards the staged operations and resets the atomic depth.
+    pub(super) fn abort_atomic(&self) {
+        // Discard the staged operations of each map.
+        core::mem::take(&mut *self.atomic_batch.lock()).iter().for_each(|status| status.set(StagedStatus::ABORTED));
+        // Reset the atomic batch depth.
+        self.atomic_depth.store(0, Ordering::SeqCst);
+    }
+}
+
+/// The status of the operations that a single map has staged in the database-wide atomic batch.
+#[derive(Clone, Default)]
+pub(super) struct StagedStatus(Arc<AtomicU8>);
+
+impl StagedStatus {
+    /// The map has no staged operations.
+    const NONE: u8 = 0;
+    /// The staged operations await the end of the outermost atomic batch.
+    const PENDING: u8 = 1;
+    /// The staged operations are committed, and should be applied to the map.
+    const COMMITTED: u8 = 2;
+    /// The staged operations are aborted, and should be discarded.
+    const ABORTED: u8 = 3;
+
+    /// Sets the status to the given value.
+    fn set(&self, status: u8) {
+        self.0.store(status, Ordering::SeqCst);
+    }
+
+    /// Returns `Some(true)` if the staged operations are committed, `Some(false)` if they
+    /// are aborted, and `None` otherwise; a resolved status is reset to `NONE`.
+    pub(super) fn resolve(&self) -> Option<bool> {
+        match self.0.load(Ordering::SeqCst) {
+            status @ (Self::COMMITTED | Self::ABORTED) => {
+                match self.0.compare_exchange(status, Self::NONE, Ordering::SeqCst, Ordering::SeqCst) {
+                    Ok(_) => Some(status == Self::COMMITTED),
+                    Err(_) => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
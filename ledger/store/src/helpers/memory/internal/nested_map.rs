@@ -14,7 +14,8 @@
 
 #![allow(clippy::type_complexity)]
 
-use crate::helpers::{NestedMap, NestedMapRead};
+use super::{MemoryDB, StagedStatus};
+use crate::helpers::{NestedMap, NestedMapRead, StorageError};
 use console::network::prelude::*;
 
 use core::hash::Hash;
@@ -41,7 +42,10 @@ pub struct NestedMemoryMap<
     map_inner: Arc<RwLock<BTreeMap<Vec<u8>, V>>>,           // map-key -> value
     batch_in_progress: Arc<AtomicBool>,
     atomic_batch: Arc<Mutex<Vec<(M, Option<K>, Option<V>)>>>,
-    checkpoint: Arc<Mutex<Vec<usize>>>,
+    checkpoint: Arc<Mutex<Vec<(Option<&'static str>, usize)>>>,
+    staged: Arc<Mutex<Vec<(M, Option<K>, Option<V>)>>>,
+    staged_status: StagedStatus,
+    database: MemoryDB,
 }
 
 impl<
@@ -57,6 +61,45 @@ impl<
             batch_in_progress: Default::default(),
             atomic_batch: Default::default(),
             checkpoint: Default::default(),
+            staged: Default::default(),
+            staged_status: Default::default(),
+            database: Default::default(),
+        }
+    }
+}
+
+impl<
+    M: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    K: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> NestedMemoryMap<M, K, V>
+{
+    /// Initializes a new, empty `NestedMemoryMap` that shares the atomic state of the given database.
+    pub fn open_from_db(database: &MemoryDB) -> Self {
+        Self { database: database.clone(), ..Default::default() }
+    }
+
+    /// Applies or discards the staged operations, once the database-wide atomic batch is resolved.
+    fn settle(&self) {
+        // Acquire the lock on the staged operations.
+        let mut staged = self.staged.lock();
+        match self.staged_status.resolve() {
+            // Perform all the staged operations.
+            Some(true) => {
+                let mut map = self.map.write();
+                let mut map_inner = self.map_inner.write();
+                for (m, k, v) in staged.drain(..) {
+                    match (k, v) {
+                        (Some(k), Some(v)) => insert(&mut map, &mut map_inner, &m, &k, v),
+                        (None, None) => remove_map(&mut map, &mut map_inner, &m),
+                        (Some(k), None) => remove_key(&mut map, &mut map_inner, &m, &k),
+                        (None, Some(_)) => unreachable!("Cannot remove a key-value pair from a map without a key."),
+                    }
+                }
+            }
+            // Discard all the staged operations.
+            Some(false) => staged.clear(),
+            None => (),
         }
     }
 }
@@ -85,6 +128,9 @@ impl<
             batch_in_progress: Default::default(),
             atomic_batch: Default::default(),
             checkpoint: Default::default(),
+            staged: Default::default(),
+            staged_status: Default::default(),
+            database: Default::default(),
         }
     }
 }
@@ -99,7 +145,9 @@ impl<
     ///
     /// Inserts the given map-key-value pair.
     ///
-    fn insert(&self, map: M, key: K, value: V) -> Result<()> {
+    fn insert(&self, map: M, key: K, value: V) -> Result<(), StorageError> {
+        // Apply any committed operations first.
+        self.settle();
         // Determine if an atomic batch is in progress.
         match self.is_atomic_in_progress() {
             // If a batch is in progress, add the map-key-value pair to the batch.
@@ -113,7 +161,9 @@ impl<
     ///
     /// Removes the given map.
     ///
-    fn remove_map(&self, map: &M) -> Result<()> {
+    fn remove_map(&self, map: &M) -> Result<(), StorageError> {
+        // Apply any committed operations first.
+        self.settle();
         // Determine if an atomic batch is in progress.
         match self.is_atomic_in_progress() {
             // If a batch is in progress, add the map-None pair to the batch.
@@ -127,7 +177,9 @@ impl<
     ///
     /// Removes the key-value pair for the given map and key.
     ///
-    fn remove_key(&self, map: &M, key: &K) -> Result<()> {
+    fn remove_key(&self, map: &M, key: &K) -> Result<(), StorageError> {
+        // Apply any committed operations first.
+        self.settle();
         // Determine if an atomic batch is in progress.
         match self.is_atomic_in_progress() {
             // If a batch is in progress, add the key-None pair to the batch.
@@ -143,8 +195,12 @@ impl<
     /// without an actual write taking place until `finish_atomic` is called.
     ///
     fn start_atomic(&self) {
+        // Apply any committed operations first.
+        self.settle();
         // Set the atomic batch flag to `true`.
         self.batch_in_progress.store(true, Ordering::SeqCst);
+        // Increment the database-wide atomic depth.
+        self.database.start_atomic();
         // Ensure that the atomic batch is empty.
         assert!(self.atomic_batch.lock().is_empty());
     }
@@ -162,9 +218,16 @@ impl<
     /// Saves the current list of pending operations, so that if `atomic_rewind` is called,
     /// we roll back all future operations, and return to the start of this checkpoint.
     ///
-    fn atomic_checkpoint(&self) {
-        // Push the current length of the atomic batch to the checkpoint stack.
-        self.checkpoint.lock().push(self.atomic_batch.lock().len());
+    fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        // Push the label and the current length of the atomic batch to the checkpoint stack.
+        self.checkpoint.lock().push((label, self.atomic_batch.lock().len()));
+    }
+
+    ///
+    /// Returns the current checkpoint stack, as a list of (label, pending operations length) pairs.
+    ///
+    fn debug_checkpoint_stack(&self) -> Vec<(Option<&'static str>, usize)> {
+        self.checkpoint.lock().clone()
     }
 
     ///
@@ -184,7 +247,7 @@ impl<
         let mut atomic_batch = self.atomic_batch.lock();
 
         // Retrieve the last checkpoint.
-        let checkpoint = self.checkpoint.lock().pop().unwrap_or(0);
+        let checkpoint = self.checkpoint.lock().pop().map(|(_, checkpoint)| checkpoint).unwrap_or(0);
 
         // Remove all operations after the checkpoint.
         atomic_batch.truncate(checkpoint);
@@ -200,29 +263,22 @@ impl<
         *self.checkpoint.lock() = Default::default();
         // Set the atomic batch flag to `false`.
         self.batch_in_progress.store(false, Ordering::SeqCst);
+        // Clear the database-wide atomic state.
+        self.database.abort_atomic();
     }
 
     ///
     /// Finishes an atomic operation, performing all the queued writes.
     ///
-    fn finish_atomic(&self) -> Result<()> {
+    fn finish_atomic(&self) -> Result<(), StorageError> {
         // Retrieve the atomic batch.
         let operations = core::mem::take(&mut *self.atomic_batch.lock());
 
         if !operations.is_empty() {
-            // Acquire a write lock on the map.
-            let mut map = self.map.write();
-            let mut map_inner = self.map_inner.write();
-
-            // Perform all the queued operations.
-            for (m, k, v) in operations {
-                match (k, v) {
-                    (Some(k), Some(v)) => insert(&mut map, &mut map_inner, &m, &k, v),
-                    (None, None) => remove_map(&mut map, &mut map_inner, &m),
-                    (Some(k), None) => remove_key(&mut map, &mut map_inner, &m, &k),
-                    (None, Some(_)) => unreachable!("Cannot remove a key-value pair from a map without a key."),
-                }
-            }
+            // Stage the operations from the map in the database-wide batch.
+            let mut staged = self.staged.lock();
+            staged.extend(operations);
+            self.database.enqueue(&self.staged_status);
         }
 
         // Clear the checkpoint stack.
@@ -230,6 +286,11 @@ impl<
         // Set the atomic batch flag to `false`.
         self.batch_in_progress.store(false, Ordering::SeqCst);
 
+        // Commit the database-wide batch, if this is the outermost atomic batch.
+        self.database.finish_atomic();
+        // Apply the staged operations, if they were committed.
+        self.settle();
+
         Ok(())
     }
 }
@@ -266,7 +327,9 @@ impl<
     ///
     /// Returns `true` if the given key exists in the map.
     ///
-    fn contains_key_confirmed(&self, map: &M, key: &K) -> Result<bool> {
+    fn contains_key_confirmed(&self, map: &M, key: &K) -> Result<bool, StorageError> {
+        // Apply any committed operations first.
+        self.settle();
         // Serialize 'm'.
         let m = bincode::serialize(map)?;
         // Concatenate 'm' and 'k' with a 0-byte separator.
@@ -279,7 +342,7 @@ impl<
     /// Returns `true` if the given key exists in the map.
     /// This method first checks the atomic batch, and if it does not exist, then checks the map.
     ///
-    fn contains_key_speculative(&self, map: &M, key: &K) -> Result<bool> {
+    fn contains_key_speculative(&self, map: &M, key: &K) -> Result<bool, StorageError> {
         // If a batch is in progress, check the atomic batch first.
         if self.is_atomic_in_progress() {
             // We iterate from the back of the `atomic_batch` to find the latest value.
@@ -307,7 +370,9 @@ impl<
     ///
     /// Returns the confirmed key-value pairs for the given map, if it exists.
     ///
-    fn get_map_confirmed(&'a self, map: &M) -> Result<Vec<(K, V)>> {
+    fn get_map_confirmed(&'a self, map: &M) -> Result<Vec<(K, V)>, StorageError> {
+        // Apply any committed operations first.
+        self.settle();
         // Serialize 'm'.
         let m = bincode::serialize(map)?;
         // Retrieve the keys for the serialized map.
@@ -337,7 +402,7 @@ impl<
     ///
     /// Returns the speculative key-value pairs for the given map, if it exists.
     ///
-    fn get_map_speculative(&'a self, map: &M) -> Result<Vec<(K, V)>> {
+    fn get_map_speculative(&'a self, map: &M) -> Result<Vec<(K, V)>, StorageError> {
         // If there is no atomic batch in progress, then return the confirmed key-value pairs.
         if !self.is_atomic_in_progress() {
             return self.get_map_confirmed(map);
@@ -384,7 +449,9 @@ impl<
     ///
     /// Returns the value for the given key from the map, if it exists.
     ///
-    fn get_value_confirmed(&'a self, map: &M, key: &K) -> Result<Option<Cow<'a, V>>> {
+    fn get_value_confirmed(&'a self, map: &M, key: &K) -> Result<Option<Cow<'a, V>>, StorageError> {
+        // Apply any committed operations first.
+        self.settle();
         // Serialize 'm'.
         let m = bincode::serialize(map)?;
         // Concatenate 'm' and 'k' with a 0-byte separator.
@@ -441,6 +508,8 @@ impl<
     /// Returns an iterator visiting each confirmed map-key-value pair.
     ///
     fn iter_confirmed(&'a self) -> Self::Iterator {
+        // Apply any committed operations first.
+        self.settle();
         // Note: The 'unwrap' is safe here, because the maps and keys are defined by us.
         self.map
             .read()
@@ -469,6 +538,8 @@ impl<
     /// Returns an iterator over each confirmed key.
     ///
     fn keys_confirmed(&'a self) -> Self::Keys {
+        // Apply any committed operations first.
+        self.settle();
         // Note: The 'unwrap' is safe here, because the maps and keys are defined by us.
         self.map
             .read()
@@ -488,6 +559,8 @@ impl<
     /// Returns an iterator over each confirmed value.
     ///
     fn values_confirmed(&'a self) -> Self::Values {
+        // Apply any committed operations first.
+        self.settle();
         self.map_inner.read().clone().into_values().map(Cow::Owned)
     }
 }
@@ -671,7 +744,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
+        assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Start an atomic write batch.
         map.start_atomic();
@@ -686,15 +759,15 @@ mod tests {
             // The pending batch should contain NUM_ITEMS / 2 items.
             assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoint.lock().last(), None);
+            assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
         }
 
         // Run the same sequence of checks 3 times.
         for _ in 0..3 {
             // Perform a checkpoint.
-            map.atomic_checkpoint();
+            map.atomic_checkpoint(None);
             // Make sure the checkpoint index is NUM_ITEMS / 2.
-            assert_eq!(map.checkpoint.lock().last(), Some(&(NUM_ITEMS / 2)));
+            assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), Some(&(NUM_ITEMS / 2)));
 
             {
                 // Queue (since a batch is in progress) another NUM_ITEMS / 2 insertions.
@@ -706,13 +779,13 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS);
                 // Make sure the checkpoint index is NUM_ITEMS / 2.
-                assert_eq!(map.checkpoint.lock().last(), Some(&(NUM_ITEMS / 2)));
+                assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), Some(&(NUM_ITEMS / 2)));
             }
 
             // Abort the current atomic write batch.
             map.atomic_rewind();
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoint.lock().last(), None);
+            assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             {
                 // The map should still contain no items.
@@ -720,7 +793,7 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS / 2 items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
                 // Make sure the checkpoint index is None.
-                assert_eq!(map.checkpoint.lock().last(), None);
+                assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
             }
         }
 
@@ -731,7 +804,7 @@ mod tests {
         // The pending batch should contain no items.
         assert!(map.iter_pending().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
+        assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
     }
 
     #[test]
@@ -744,7 +817,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
+        assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Start a nested atomic batch scope that completes successfully.
         atomic_batch_scope!(map, {
@@ -757,7 +830,7 @@ mod tests {
             // The pending batch should contain NUM_ITEMS / 2 items.
             assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoint.lock().last(), None);
+            assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             // Start a nested atomic batch scope that completes successfully.
             atomic_batch_scope!(map, {
@@ -770,7 +843,7 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS);
                 // Make sure the checkpoint index is NUM_ITEMS / 2.
-                assert_eq!(map.checkpoint.lock().last(), Some(&(NUM_ITEMS / 2)));
+                assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), Some(&(NUM_ITEMS / 2)));
 
                 Ok(())
             })?;
@@ -780,7 +853,7 @@ mod tests {
             // The pending batch should contain NUM_ITEMS items.
             assert_eq!(map.iter_pending().count(), NUM_ITEMS);
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoint.lock().last(), None);
+            assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             Ok(())
         })?;
@@ -790,7 +863,7 @@ mod tests {
         // The pending batch should contain no items.
         assert!(map.iter_pending().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
+        assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         Ok(())
     }
@@ -805,7 +878,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
+        assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Start an atomic write batch.
         let run_nested_atomic_batch_scope = || -> Result<()> {
@@ -820,7 +893,7 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS / 2 items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
                 // Make sure the checkpoint index is None.
-                assert_eq!(map.checkpoint.lock().last(), None);
+                assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
                 // Start a nested atomic write batch that completes correctly.
                 atomic_batch_scope!(map, {
@@ -833,7 +906,7 @@ mod tests {
                     // The pending batch should contain NUM_ITEMS items.
                     assert_eq!(map.iter_pending().count(), NUM_ITEMS);
                     // Make sure the checkpoint index is NUM_ITEMS / 2.
-                    assert_eq!(map.checkpoint.lock().last(), Some(&(NUM_ITEMS / 2)));
+                    assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), Some(&(NUM_ITEMS / 2)));
 
                     bail!("This batch should fail.");
                 })?;
@@ -858,7 +931,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
+        assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Start an atomic finalize.
         let outcome = atomic_finalize!(map, FinalizeMode::RealRun, {
@@ -873,7 +946,7 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS / 2 items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
                 // Make sure the checkpoint index is 0.
-                assert_eq!(map.checkpoint.lock().last(), Some(&0));
+                assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), Some(&0));
 
                 Ok(())
             })
@@ -884,7 +957,7 @@ mod tests {
             // The pending batch should contain NUM_ITEMS / 2 items.
             assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoint.lock().last(), None);
+            assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             // Start a nested atomic write batch that completes correctly.
             atomic_batch_scope!(map, {
@@ -897,7 +970,7 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS);
                 // Make sure the checkpoint index is NUM_ITEMS / 2.
-                assert_eq!(map.checkpoint.lock().last(), Some(&(NUM_ITEMS / 2)));
+                assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), Some(&(NUM_ITEMS / 2)));
 
                 Ok(())
             })
@@ -908,7 +981,7 @@ mod tests {
             // The pending batch should contain NUM_ITEMS items.
             assert_eq!(map.iter_pending().count(), NUM_ITEMS);
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoint.lock().last(), None);
+            assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             Ok((true, 0, "a"))
         });
@@ -921,7 +994,7 @@ mod tests {
         // The pending batch should contain no items.
         assert!(map.iter_pending().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
+        assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         Ok(())
     }
@@ -936,7 +1009,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
+        assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Start an atomic finalize.
         let outcome = atomic_finalize!(map, FinalizeMode::RealRun, {
@@ -951,7 +1024,7 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS / 2 items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
                 // Make sure the checkpoint index is 0.
-                assert_eq!(map.checkpoint.lock().last(), Some(&0));
+                assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), Some(&0));
 
                 Ok(())
             })
@@ -962,7 +1035,7 @@ mod tests {
             // The pending batch should contain NUM_ITEMS / 2 items.
             assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoint.lock().last(), None);
+            assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             // Start a nested atomic write batch that fails.
             let result: Result<()> = atomic_batch_scope!(map, {
@@ -975,7 +1048,7 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS);
                 // Make sure the checkpoint index is NUM_ITEMS / 2.
-                assert_eq!(map.checkpoint.lock().last(), Some(&(NUM_ITEMS / 2)));
+                assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), Some(&(NUM_ITEMS / 2)));
 
                 bail!("This batch scope should fail.");
             });
@@ -988,7 +1061,7 @@ mod tests {
             // The pending batch should contain NUM_ITEMS / 2 items.
             assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoint.lock().last(), None);
+            assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             Ok(())
         });
@@ -1001,7 +1074,7 @@ mod tests {
         // The pending batch should contain no items.
         assert!(map.iter_pending().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
+        assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         Ok(())
     }
@@ -1013,7 +1086,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
+        assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Construct an atomic batch scope.
         let outcome: Result<()> = atomic_batch_scope!(map, {
@@ -1045,7 +1118,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
+        assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Insert the key.
         map.insert(0, 0, "0".to_string()).unwrap();
@@ -1058,7 +1131,7 @@ mod tests {
             // Create a failing atomic batch scope that will reset the checkpoint.
             let result: Result<()> = atomic_batch_scope!(map, {
                 // Make sure the checkpoint index is 1.
-                assert_eq!(map.checkpoint.lock().last(), Some(&1));
+                assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), Some(&1));
 
                 // Update the key.
                 map.insert(0, 0, "2".to_string()).unwrap();
@@ -1089,7 +1162,7 @@ mod tests {
         // The pending batch should contain no items.
         assert!(map.iter_pending().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
+        assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Ensure that the map value is correct.
         assert_eq!(map.iter_confirmed().next().unwrap(), (Cow::Owned(0), Cow::Owned(0), Cow::Owned("1".to_string())));
@@ -1102,7 +1175,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
+        assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Insert the key.
         map.insert(0, 0, "0".to_string()).unwrap();
@@ -1113,7 +1186,7 @@ mod tests {
             // Simulates an accepted transaction.
             let result: Result<()> = atomic_batch_scope!(map, {
                 // Make sure the checkpoint index is 0.
-                assert_eq!(map.checkpoint.lock().last(), Some(&0));
+                assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), Some(&0));
 
                 // Insert the key.
                 map.insert(0, 0, "1".to_string()).unwrap();
@@ -1134,7 +1207,7 @@ mod tests {
             // Simulates a rejected transaction.
             let result: Result<()> = atomic_batch_scope!(map, {
                 // Make sure the checkpoint index is 1.
-                assert_eq!(map.checkpoint.lock().last(), Some(&1));
+                assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), Some(&1));
 
                 // Simulate an instruction
                 let result: Result<()> = atomic_batch_scope!(map, {
@@ -1148,7 +1221,7 @@ mod tests {
                 // Simulates an instruction that fails.
                 let result: Result<()> = atomic_batch_scope!(map, {
                     // Make sure the checkpoint index is 2.
-                    assert_eq!(map.checkpoint.lock().last(), Some(&2));
+                    assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), Some(&2));
 
                     // Update the key.
                     map.insert(0, 0, "3".to_string()).unwrap();
@@ -1178,7 +1251,7 @@ mod tests {
         // The pending batch should contain no items.
         assert!(map.iter_pending().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoint.lock().last(), None);
+        assert_eq!(map.checkpoint.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Ensure that the map value is correct.
         assert_eq!(map.iter_confirmed().next().unwrap(), (Cow::Owned(0), Cow::Owned(0), Cow::Owned("1".to_string())));
@@ -15,14 +15,16 @@
 #![allow(clippy::type_complexity)]
 
 use crate::{
-    helpers::memory::{MemoryMap, NestedMemoryMap},
+    helpers::memory::{MemoryDB, MemoryMap, NestedMemoryMap},
     CommitteeStorage,
     CommitteeStore,
     FinalizeStorage,
+    FinalizeUndo,
 };
 use console::{
     prelude::*,
     program::{Identifier, Plaintext, ProgramID, Value},
+    types::Field,
 };
 use ledger_committee::Committee;
 
@@ -37,6 +39,14 @@ pub struct FinalizeMemory<N: Network> {
     program_id_map: MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The key-value map.
     key_value_map: NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
+    /// The key history map.
+    key_history_map: NestedMemoryMap<Field<N>, u32, Option<Value<N>>>,
+    /// The block history map.
+    block_history_map: MemoryMap<u32, Vec<Field<N>>>,
+    /// The undo log map.
+    undo_log_map: MemoryMap<u32, Vec<FinalizeUndo<N>>>,
+    /// The history height map.
+    history_height_map: MemoryMap<u8, u32>,
     /// The optional development ID.
     dev: Option<u16>,
 }
@@ -46,17 +56,28 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
     type CommitteeStorage = CommitteeMemory<N>;
     type ProgramIDMap = MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>;
     type KeyValueMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    type KeyHistoryMap = NestedMemoryMap<Field<N>, u32, Option<Value<N>>>;
+    type BlockHistoryMap = MemoryMap<u32, Vec<Field<N>>>;
+    type UndoLogMap = MemoryMap<u32, Vec<FinalizeUndo<N>>>;
+    type HistoryHeightMap = MemoryMap<u8, u32>;
 
     /// Initializes the finalize storage.
     fn open(dev: Option<u16>) -> Result<Self> {
-        // Initialize the committee store.
-        let committee_store = CommitteeStore::<N, CommitteeMemory<N>>::open(dev)?;
-        // Return the finalize store.
-        Ok(Self {
-            committee_store,
-            program_id_map: MemoryMap::default(),
-            key_value_map: NestedMemoryMap::default(),
-            dev,
+        // Initialize the in-memory database, shared with the nested storages.
+        MemoryDB::open_shared(|database| {
+            // Initialize the committee store.
+            let committee_store = CommitteeStore::<N, CommitteeMemory<N>>::open(dev)?;
+            // Return the finalize store.
+            Ok(Self {
+                committee_store,
+                program_id_map: MemoryMap::open_from_db(database),
+                key_value_map: NestedMemoryMap::open_from_db(database),
+                key_history_map: NestedMemoryMap::open_from_db(database),
+                block_history_map: MemoryMap::open_from_db(database),
+                undo_log_map: MemoryMap::open_from_db(database),
+                history_height_map: MemoryMap::open_from_db(database),
+                dev,
+            })
         })
     }
 
@@ -81,6 +102,26 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
         &self.key_value_map
     }
 
+    /// Returns the key history map.
+    fn key_history_map(&self) -> &Self::KeyHistoryMap {
+        &self.key_history_map
+    }
+
+    /// Returns the block history map.
+    fn block_history_map(&self) -> &Self::BlockHistoryMap {
+        &self.block_history_map
+    }
+
+    /// Returns the undo log map.
+    fn undo_log_map(&self) -> &Self::UndoLogMap {
+        &self.undo_log_map
+    }
+
+    /// Returns the history height map.
+    fn history_height_map(&self) -> &Self::HistoryHeightMap {
+        &self.history_height_map
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
         self.dev
@@ -108,11 +149,14 @@ impl<N: Network> CommitteeStorage<N> for CommitteeMemory<N> {
 
     /// Initializes the committee storage.
     fn open(dev: Option<u16>) -> Result<Self> {
-        Ok(Self {
-            current_round_map: MemoryMap::default(),
-            round_to_height_map: MemoryMap::default(),
-            committee_map: MemoryMap::default(),
-            dev,
+        // Initialize the in-memory database, shared with the nested storages.
+        MemoryDB::open_shared(|database| {
+            Ok(Self {
+                current_round_map: MemoryMap::open_from_db(database),
+                round_to_height_map: MemoryMap::open_from_db(database),
+                committee_map: MemoryMap::open_from_db(database),
+                dev,
+            })
         })
     }
 
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::{
-    helpers::memory::{MemoryMap, TransitionMemory},
+    helpers::memory::{MemoryDB, MemoryMap, TransitionMemory},
     DeploymentStorage,
     DeploymentStore,
     ExecutionStorage,
@@ -54,14 +54,17 @@ impl<N: Network> TransactionStorage<N> for TransactionMemory<N> {
 
     /// Initializes the transaction storage.
     fn open(transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self> {
-        // Initialize the fee store.
-        let fee_store = FeeStore::<N, FeeMemory<N>>::open(transition_store)?;
-        // Initialize the deployment store.
-        let deployment_store = DeploymentStore::<N, DeploymentMemory<N>>::open(fee_store.clone())?;
-        // Initialize the execution store.
-        let execution_store = ExecutionStore::<N, ExecutionMemory<N>>::open(fee_store.clone())?;
-        // Return the transaction storage.
-        Ok(Self { id_map: MemoryMap::default(), deployment_store, execution_store, fee_store })
+        // Initialize the in-memory database, shared with the nested storages.
+        MemoryDB::open_shared(|database| {
+            // Initialize the fee store.
+            let fee_store = FeeStore::<N, FeeMemory<N>>::open(transition_store)?;
+            // Initialize the deployment store.
+            let deployment_store = DeploymentStore::<N, DeploymentMemory<N>>::open(fee_store.clone())?;
+            // Initialize the execution store.
+            let execution_store = ExecutionStore::<N, ExecutionMemory<N>>::open(fee_store.clone())?;
+            // Return the transaction storage.
+            Ok(Self { id_map: MemoryMap::open_from_db(database), deployment_store, execution_store, fee_store })
+        })
     }
 
     /// Returns the ID map.
@@ -120,15 +123,18 @@ impl<N: Network> DeploymentStorage<N> for DeploymentMemory<N> {
 
     /// Initializes the deployment storage.
     fn open(fee_store: FeeStore<N, Self::FeeStorage>) -> Result<Self> {
-        Ok(Self {
-            id_map: MemoryMap::default(),
-            edition_map: MemoryMap::default(),
-            reverse_id_map: MemoryMap::default(),
-            owner_map: MemoryMap::default(),
-            program_map: MemoryMap::default(),
-            verifying_key_map: MemoryMap::default(),
-            certificate_map: MemoryMap::default(),
-            fee_store,
+        // Initialize the in-memory database, shared with the nested storages.
+        MemoryDB::open_shared(|database| {
+            Ok(Self {
+                id_map: MemoryMap::open_from_db(database),
+                edition_map: MemoryMap::open_from_db(database),
+                reverse_id_map: MemoryMap::open_from_db(database),
+                owner_map: MemoryMap::open_from_db(database),
+                program_map: MemoryMap::open_from_db(database),
+                verifying_key_map: MemoryMap::open_from_db(database),
+                certificate_map: MemoryMap::open_from_db(database),
+                fee_store,
+            })
         })
     }
 
@@ -196,11 +202,14 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
 
     /// Initializes the execution storage.
     fn open(fee_store: FeeStore<N, Self::FeeStorage>) -> Result<Self> {
-        Ok(Self {
-            id_map: MemoryMap::default(),
-            reverse_id_map: MemoryMap::default(),
-            inclusion_map: MemoryMap::default(),
-            fee_store
+        // Initialize the in-memory database, shared with the nested storages.
+        MemoryDB::open_shared(|database| {
+            Ok(Self {
+                id_map: MemoryMap::open_from_db(database),
+                reverse_id_map: MemoryMap::open_from_db(database),
+                inclusion_map: MemoryMap::open_from_db(database),
+                fee_store
+            })
         })
     }
 
@@ -245,10 +254,13 @@ impl<N: Network> FeeStorage<N> for FeeMemory<N> {
 
     /// Initializes the fee storage.
     fn open(transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self> {
-        Ok(Self {
-            fee_map: MemoryMap::default(),
-            reverse_fee_map: MemoryMap::default(),
-            transition_store,
+        // Initialize the in-memory database, shared with the nested storages.
+        MemoryDB::open_shared(|database| {
+            Ok(Self {
+                fee_map: MemoryMap::open_from_db(database),
+                reverse_fee_map: MemoryMap::open_from_db(database),
+                transition_store,
+            })
         })
     }
 
@@ -12,7 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{helpers::memory::MemoryMap, InputStorage, InputStore, OutputStorage, OutputStore, TransitionStorage};
+use crate::{
+    helpers::memory::{MemoryDB, MemoryMap},
+    InputStorage,
+    InputStore,
+    OutputStorage,
+    OutputStore,
+    TransitionStorage,
+};
 use console::{
     prelude::*,
     program::{Ciphertext, Future, Identifier, Plaintext, ProgramID, Record},
@@ -50,14 +57,17 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
 
     /// Initializes the transition storage.
     fn open(dev: Option<u16>) -> Result<Self> {
-        Ok(Self {
-            locator_map: MemoryMap::default(),
-            input_store: InputStore::open(dev)?,
-            output_store: OutputStore::open(dev)?,
-            tpk_map: MemoryMap::default(),
-            reverse_tpk_map: MemoryMap::default(),
-            tcm_map: MemoryMap::default(),
-            reverse_tcm_map: MemoryMap::default(),
+        // Initialize the in-memory database, shared with the nested storages.
+        MemoryDB::open_shared(|database| {
+            Ok(Self {
+                locator_map: MemoryMap::open_from_db(database),
+                input_store: InputStore::open(dev)?,
+                output_store: OutputStore::open(dev)?,
+                tpk_map: MemoryMap::open_from_db(database),
+                reverse_tpk_map: MemoryMap::open_from_db(database),
+                tcm_map: MemoryMap::open_from_db(database),
+                reverse_tcm_map: MemoryMap::open_from_db(database),
+            })
         })
     }
 
@@ -133,16 +143,19 @@ impl<N: Network> InputStorage<N> for InputMemory<N> {
 
     /// Initializes the transition input storage.
     fn open(dev: Option<u16>) -> Result<Self> {
-        Ok(Self {
-            id_map: MemoryMap::default(),
-            reverse_id_map: MemoryMap::default(),
-            constant: MemoryMap::default(),
-            public: MemoryMap::default(),
-            private: MemoryMap::default(),
-            record: MemoryMap::default(),
-            record_tag: MemoryMap::default(),
-            external_record: MemoryMap::default(),
-            dev,
+        // Initialize the in-memory database, shared with the nested storages.
+        MemoryDB::open_shared(|database| {
+            Ok(Self {
+                id_map: MemoryMap::open_from_db(database),
+                reverse_id_map: MemoryMap::open_from_db(database),
+                constant: MemoryMap::open_from_db(database),
+                public: MemoryMap::open_from_db(database),
+                private: MemoryMap::open_from_db(database),
+                record: MemoryMap::open_from_db(database),
+                record_tag: MemoryMap::open_from_db(database),
+                external_record: MemoryMap::open_from_db(database),
+                dev,
+            })
         })
     }
 
@@ -232,17 +245,20 @@ impl<N: Network> OutputStorage<N> for OutputMemory<N> {
 
     /// Initializes the transition output storage.
     fn open(dev: Option<u16>) -> Result<Self> {
-        Ok(Self {
-            id_map: Default::default(),
-            reverse_id_map: Default::default(),
-            constant: Default::default(),
-            public: Default::default(),
-            private: Default::default(),
-            record: Default::default(),
-            record_nonce: Default::default(),
-            external_record: Default::default(),
-            future: Default::default(),
-            dev,
+        // Initialize the in-memory database, shared with the nested storages.
+        MemoryDB::open_shared(|database| {
+            Ok(Self {
+                id_map: MemoryMap::open_from_db(database),
+                reverse_id_map: MemoryMap::open_from_db(database),
+                constant: MemoryMap::open_from_db(database),
+                public: MemoryMap::open_from_db(database),
+                private: MemoryMap::open_from_db(database),
+                record: MemoryMap::open_from_db(database),
+                record_nonce: MemoryMap::open_from_db(database),
+                external_record: MemoryMap::open_from_db(database),
+                future: MemoryMap::open_from_db(database),
+                dev,
+            })
         })
     }
 
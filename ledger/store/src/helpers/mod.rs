@@ -19,6 +19,15 @@ pub mod rocksdb;
 #[cfg(test)]
 pub(crate) mod test_helpers;
 
+mod error;
+pub use error::*;
+
+mod health;
+pub use health::*;
+
+mod options;
+pub use options::*;
+
 mod traits;
 pub use traits::*;
 
@@ -26,8 +35,27 @@ pub use traits::*;
 /// atomic write batch in progress yet. This ensures that complex atomic operations consisting of
 /// multiple lower-level operations - which might also need to be atomic if executed individually -
 /// are executed as a single large atomic operation regardless.
+///
+/// This is an `atomic_batch_scope_outcome` whose block always commits on success.
 #[macro_export]
 macro_rules! atomic_batch_scope {
+    ($self:expr, $ops:block) => {{
+        $crate::atomic_batch_scope_outcome!($self, {
+            // Wrap the operations in a closure, so that they may return early.
+            let run_ops = || -> Result<_> { $ops };
+            run_ops().map($crate::helpers::AtomicScopeOutcome::Commit)
+        })
+        .map($crate::helpers::AtomicScopeOutcome::into_inner)
+    }};
+}
+
+/// This macro executes the given block of operations as a new atomic write batch IFF there is no
+/// atomic write batch in progress yet, like `atomic_batch_scope`, except that the block
+/// returns an `AtomicScopeOutcome`. If the outcome is `Abort`, the writes of this scope are discarded
+/// (rewinding to the scope's checkpoint, or aborting the batch if this is the top-level scope), and the
+/// outcome is still returned to the caller as `Ok`.
+#[macro_export]
+macro_rules! atomic_batch_scope_outcome {
     ($self:expr, $ops:block) => {{
         // Check if an atomic batch write is already in progress. If there isn't one, this means
         // this operation is a "top-level" one and is the one to start and finalize the batch.
@@ -35,29 +63,46 @@ macro_rules! atomic_batch_scope {
 
         // Start an atomic batch write operation IFF it's not already part of one.
         match is_atomic_in_progress {
-            true => $self.atomic_checkpoint(),
+            true => $self.atomic_checkpoint(Some(concat!(file!(), ":", line!()))),
             false => $self.start_atomic(),
         }
 
         // Wrap the operations that should be batched in a closure to be able to rewind the batch on error.
-        let run_atomic_ops = || -> Result<_> { $ops };
+        let run_atomic_ops = || -> Result<$crate::helpers::AtomicScopeOutcome<_>> { $ops };
 
         // Run the atomic operations.
         match run_atomic_ops() {
             // Save this atomic batch scope and return.
-            Ok(result) => match is_atomic_in_progress {
+            Ok(outcome @ $crate::helpers::AtomicScopeOutcome::Commit(_)) => match is_atomic_in_progress {
                 // A 'true' implies this is a nested atomic batch scope.
                 true => {
                     // Once a nested batch scope is completed, clear its checkpoint.
                     // Until a new checkpoint is established,
                     // we can now only rewind to a previous (higher-level) checkpoint.
                     $self.clear_latest_checkpoint();
-                    Ok(result)
+                    Ok(outcome)
                 }
                 // A 'false' implies this is the top-level calling scope.
                 // Commit the atomic batch IFF it's the top-level calling scope.
-                false => $self.finish_atomic().map(|_| result),
+                false => match $self.finish_atomic() {
+                    Ok(()) => Ok(outcome),
+                    // If the commit failed, abort the atomic batch to ensure no atomic state lingers.
+                    Err(err) => {
+                        $self.abort_atomic();
+                        Err(err.into())
+                    }
+                },
             },
+            // Discard this atomic batch scope, without reporting an error.
+            Ok(outcome @ $crate::helpers::AtomicScopeOutcome::Abort(_)) => {
+                match is_atomic_in_progress {
+                    // A nested scope rewinds to its own checkpoint.
+                    true => $self.atomic_rewind(),
+                    // The top-level scope discards the entire atomic batch.
+                    false => $self.abort_atomic(),
+                }
+                Ok(outcome)
+            }
             // Rewind this atomic batch scope.
             Err(err) => {
                 $self.atomic_rewind();
@@ -87,10 +132,14 @@ macro_rules! atomic_finalize {
         // write batch if any of them fails.
         match ($finalize_mode, || -> Result<_, String> { $ops }()) {
             // If this is a successful real run, commit the atomic batch.
-            (FinalizeMode::RealRun, Ok(result)) => {
-                $self.finish_atomic()?;
-                Ok(result)
-            }
+            (FinalizeMode::RealRun, Ok(result)) => match $self.finish_atomic() {
+                Ok(()) => Ok(result),
+                // If the commit failed, abort the atomic batch to ensure no atomic state lingers.
+                Err(error) => {
+                    $self.abort_atomic();
+                    Err(error.into())
+                }
+            },
             // If this is a failed real run, abort the atomic batch.
             (FinalizeMode::RealRun, Err(error_msg)) => {
                 $self.abort_atomic();
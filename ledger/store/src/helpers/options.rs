@@ -0,0 +1,37 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The tuning options for the persistent storage.
+///
+/// The default options match the settings the storage is opened with otherwise.
+/// A storage without tuning options, such as the in-memory storage, rejects any non-default options.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageOptions {
+    /// The size (in bytes) of a single memtable, if overriding the storage default.
+    pub write_buffer_size: Option<usize>,
+    /// The maximum number of concurrent background jobs (compactions and flushes).
+    pub max_background_jobs: i32,
+    /// The target size (in bytes) of a level-1 file, if overriding the storage default.
+    pub target_file_size: Option<u64>,
+    /// If `true`, files are compressed with zstd instead of lz4.
+    /// Note: This option requires the `rocks-zstd` feature.
+    pub zstd_compression: bool,
+}
+
+impl Default for StorageOptions {
+    /// Returns the default storage options.
+    fn default() -> Self {
+        Self { write_buffer_size: None, max_background_jobs: 4, target_file_size: None, zstd_compression: false }
+    }
+}
@@ -61,6 +61,8 @@ pub struct BlockDB<N: Network> {
     rejected_or_aborted_transaction_id_map: DataMap<N::TransactionID, N::BlockHash>,
     /// The confirmed transactions map.
     confirmed_transactions_map: DataMap<N::TransactionID, (N::BlockHash, ConfirmedTxType, Vec<u8>)>,
+    /// The pruned transaction ID map.
+    pruned_transaction_id_map: DataMap<N::TransactionID, N::BlockHash>,
     /// The transaction store.
     transaction_store: TransactionStore<N, TransactionDB<N>>,
 }
@@ -81,6 +83,7 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
     type AbortedTransactionIDsMap = DataMap<N::BlockHash, Vec<N::TransactionID>>;
     type RejectedOrAbortedTransactionIDMap = DataMap<N::TransactionID, N::BlockHash>;
     type ConfirmedTransactionsMap = DataMap<N::TransactionID, (N::BlockHash, ConfirmedTxType, Vec<u8>)>;
+    type PrunedTransactionIDMap = DataMap<N::TransactionID, N::BlockHash>;
     type TransactionStorage = TransactionDB<N>;
     type TransitionStorage = TransitionDB<N>;
 
@@ -106,6 +109,7 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
             aborted_transaction_ids_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::AbortedTransactionIDs))?,
             rejected_or_aborted_transaction_id_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::RejectedOrAbortedTransactionID))?,
             confirmed_transactions_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::ConfirmedTransactions))?,
+            pruned_transaction_id_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::PrunedTransactionID))?,
             transaction_store,
         })
     }
@@ -180,8 +184,18 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
         &self.confirmed_transactions_map
     }
 
+    /// Returns the pruned transaction ID map.
+    fn pruned_transaction_id_map(&self) -> &Self::PrunedTransactionIDMap {
+        &self.pruned_transaction_id_map
+    }
+
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
         &self.transaction_store
     }
+
+    /// Runs the given closure, with all confirmed reads served from a single snapshot of the database.
+    fn read_at_snapshot<T>(&self, f: impl FnOnce() -> Result<T>) -> Result<T> {
+        self.id_map.read_at_snapshot(f)
+    }
 }
@@ -13,7 +13,18 @@
 // limitations under the License.
 
 use crate::{
-    helpers::rocksdb::{BlockDB, FinalizeDB, TransactionDB, TransitionDB},
+    helpers::{
+        rocksdb::{
+            internal::{Database, RocksDB},
+            BlockDB,
+            FinalizeDB,
+            TransactionDB,
+            TransitionDB,
+        },
+        StorageError,
+        StorageHealth,
+        StorageOptions,
+    },
     BlockStore,
     ConsensusStorage,
     FinalizeStore,
@@ -49,6 +60,14 @@ impl<N: Network> ConsensusStorage<N> for ConsensusDB<N> {
         })
     }
 
+    /// Initializes the consensus storage with the given storage options.
+    fn open_with_options(dev: Option<u16>, options: StorageOptions) -> Result<Self> {
+        // Open the database with the given storage options.
+        RocksDB::open_with_options(N::ID, dev, options)?;
+        // Initialize the consensus storage.
+        Self::open(dev)
+    }
+
     /// Returns the finalize store.
     fn finalize_store(&self) -> &FinalizeStore<N, Self::FinalizeStorage> {
         &self.finalize_store
@@ -58,4 +77,17 @@ impl<N: Network> ConsensusStorage<N> for ConsensusDB<N> {
     fn block_store(&self) -> &BlockStore<N, Self::BlockStorage> {
         &self.block_store
     }
+
+    /// Returns the health of the storage write path.
+    fn storage_health(&self) -> StorageHealth {
+        match RocksDB::open(N::ID, self.dev()) {
+            Ok(database) => database.health_check(),
+            Err(error) => StorageHealth::new(Some(StorageError::Other(error.to_string())), None),
+        }
+    }
+
+    /// Compacts the storage, discarding deleted and overwritten entries.
+    fn compact_all(&self) -> Result<()> {
+        RocksDB::open(N::ID, self.dev())?.compact_all()
+    }
 }
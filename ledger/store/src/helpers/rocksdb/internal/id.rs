@@ -27,6 +27,7 @@ pub enum MapID {
     TransitionInput(TransitionInputMap),
     TransitionOutput(TransitionOutputMap),
     Program(ProgramMap),
+    Health(HealthMap),
     #[cfg(test)]
     Test(TestMap),
 }
@@ -44,6 +45,7 @@ impl From<MapID> for u16 {
             MapID::TransitionInput(id) => id as u16,
             MapID::TransitionOutput(id) => id as u16,
             MapID::Program(id) => id as u16,
+            MapID::Health(id) => id as u16,
             #[cfg(test)]
             MapID::Test(id) => id as u16,
         }
@@ -70,6 +72,7 @@ pub enum BlockMap {
     AbortedTransactionIDs = DataID::BlockAbortedTransactionIDsMap as u16,
     RejectedOrAbortedTransactionID = DataID::BlockRejectedOrAbortedTransactionIDMap as u16,
     ConfirmedTransactions = DataID::BlockConfirmedTransactionsMap as u16,
+    PrunedTransactionID = DataID::BlockPrunedTransactionIDMap as u16,
 }
 
 /// The RocksDB map prefix for committee-related entries.
@@ -182,6 +185,19 @@ pub enum TransitionMap {
 pub enum ProgramMap {
     ProgramID = DataID::ProgramIDMap as u16,
     KeyValueID = DataID::KeyValueMap as u16,
+    KeyHistoryID = DataID::KeyHistoryMap as u16,
+    HistoryHeightID = DataID::HistoryHeightMap as u16,
+    BlockHistoryID = DataID::BlockHistoryMap as u16,
+    UndoLogID = DataID::UndoLogMap as u16,
+}
+
+/// The RocksDB map prefix for storage health entries.
+// Note: the order of these variants can be changed at any point in time,
+// as long as the corresponding DataID values remain the same.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum HealthMap {
+    Probe = DataID::HealthProbeMap as u16,
 }
 
 /// The RocksDB map prefix for test-related entries.
@@ -268,6 +284,17 @@ enum DataID {
     // Program
     ProgramIDMap,
     KeyValueMap,
+    // Health
+    HealthProbeMap,
+    // Program history
+    KeyHistoryMap,
+    HistoryHeightMap,
+    // Block pruning
+    BlockPrunedTransactionIDMap,
+    // Program history
+    BlockHistoryMap,
+    // Program undo logs
+    UndoLogMap,
 
     // Testing
     #[cfg(test)]
@@ -15,7 +15,7 @@
 #![allow(clippy::type_complexity)]
 
 use super::*;
-use crate::helpers::{Map, MapRead};
+use crate::helpers::{Map, MapRead, StorageError};
 
 use core::{fmt, fmt::Debug, hash::Hash, mem};
 use indexmap::IndexMap;
@@ -43,7 +43,7 @@ pub struct InnerDataMap<K: Serialize + DeserializeOwned, V: Serialize + Deserial
     /// The database transaction.
     pub(super) atomic_batch: Mutex<Vec<(K, Option<V>)>>,
     /// The checkpoint stack for the batched operations within the map.
-    pub(super) checkpoints: Mutex<Vec<usize>>,
+    pub(super) checkpoints: Mutex<Vec<(Option<&'static str>, usize)>>,
 }
 
 impl<
@@ -55,7 +55,7 @@ impl<
     ///
     /// Inserts the given key-value pair into the map.
     ///
-    fn insert(&self, key: K, value: V) -> Result<()> {
+    fn insert(&self, key: K, value: V) -> Result<(), StorageError> {
         // Determine if an atomic batch is in progress.
         match self.is_atomic_in_progress() {
             // If a batch is in progress, add the key-value pair to the batch.
@@ -77,7 +77,7 @@ impl<
     ///
     /// Removes the key-value pair for the given key from the map.
     ///
-    fn remove(&self, key: &K) -> Result<()> {
+    fn remove(&self, key: &K) -> Result<(), StorageError> {
         // Determine if an atomic batch is in progress.
         match self.is_atomic_in_progress() {
             // If a batch is in progress, add the key to the batch.
@@ -124,9 +124,16 @@ impl<
     /// Saves the current list of pending operations, so that if `atomic_rewind` is called,
     /// we roll back all future operations, and return to the start of this checkpoint.
     ///
-    fn atomic_checkpoint(&self) {
-        // Push the current length of the atomic batch to the checkpoint stack.
-        self.checkpoints.lock().push(self.atomic_batch.lock().len());
+    fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        // Push the label and the current length of the atomic batch to the checkpoint stack.
+        self.checkpoints.lock().push((label, self.atomic_batch.lock().len()));
+    }
+
+    ///
+    /// Returns the current checkpoint stack, as a list of (label, pending operations length) pairs.
+    ///
+    fn debug_checkpoint_stack(&self) -> Vec<(Option<&'static str>, usize)> {
+        self.checkpoints.lock().clone()
     }
 
     ///
@@ -146,7 +153,7 @@ impl<
         let mut atomic_batch = self.atomic_batch.lock();
 
         // Retrieve the last checkpoint.
-        let checkpoint = self.checkpoints.lock().pop().unwrap_or(0);
+        let checkpoint = self.checkpoints.lock().pop().map(|(_, checkpoint)| checkpoint).unwrap_or(0);
 
         // Remove all operations after the checkpoint.
         atomic_batch.truncate(checkpoint);
@@ -171,7 +178,7 @@ impl<
     ///
     /// Finishes an atomic operation, performing all the queued writes.
     ///
-    fn finish_atomic(&self) -> Result<()> {
+    fn finish_atomic(&self) -> Result<(), StorageError> {
         // Retrieve the atomic batch belonging to the map.
         let operations = core::mem::take(&mut *self.atomic_batch.lock());
 
@@ -186,13 +193,24 @@ impl<
             //
             // The expected behavior is that either all the operations will be committed
             // or none of them will be.
-            let prepared_operations = operations
+            let prepared_operations = match operations
                 .into_iter()
                 .map(|(key, value)| match value {
-                    Some(value) => Ok((self.create_prefixed_key(&key)?, Some(bincode::serialize(&value)?))),
+                    Some(value) => Ok((
+                        self.create_prefixed_key(&key)?,
+                        Some(bincode::serialize(&value)?),
+                    )),
                     None => Ok((self.create_prefixed_key(&key)?, None)),
                 })
-                .collect::<Result<Vec<_>>>()?;
+                .collect::<Result<Vec<_>, StorageError>>()
+            {
+                Ok(prepared_operations) => prepared_operations,
+                Err(error) => {
+                    // Reset the atomic state, so that no atomic batch remains in progress.
+                    self.abort_atomic();
+                    return Err(error);
+                }
+            };
 
             // Enqueue all the operations from the map in the database-wide batch.
             let mut atomic_batch = self.database.atomic_batch.lock();
@@ -222,7 +240,8 @@ impl<
             // Empty the collection of pending operations.
             let batch = mem::take(&mut *self.database.atomic_batch.lock());
             // Execute all the operations atomically.
-            self.database.rocksdb.write(batch)?;
+            // Note: On failure, the atomic state has already been reset at this point.
+            self.database.execute_batch(batch, false)?;
             // Ensure that the database atomic batch is empty.
             assert!(self.database.atomic_batch.lock().is_empty());
         }
@@ -246,7 +265,7 @@ impl<
     ///
     /// Returns `true` if the given key exists in the map.
     ///
-    fn contains_key_confirmed<Q>(&self, key: &Q) -> Result<bool>
+    fn contains_key_confirmed<Q>(&self, key: &Q) -> Result<bool, StorageError>
     where
         K: Borrow<Q>,
         Q: PartialEq + Eq + Hash + Serialize + ?Sized,
@@ -258,7 +277,7 @@ impl<
     /// Returns `true` if the given key exists in the map.
     /// This method first checks the atomic batch, and if it does not exist, then checks the map.
     ///
-    fn contains_key_speculative<Q>(&self, key: &Q) -> Result<bool>
+    fn contains_key_speculative<Q>(&self, key: &Q) -> Result<bool, StorageError>
     where
         K: Borrow<Q>,
         Q: PartialEq + Eq + Hash + Serialize + ?Sized,
@@ -281,15 +300,18 @@ impl<
     ///
     /// Returns the value for the given key from the map, if it exists.
     ///
-    fn get_confirmed<Q>(&'a self, key: &Q) -> Result<Option<Cow<'a, V>>>
+    fn get_confirmed<Q>(&'a self, key: &Q) -> Result<Option<Cow<'a, V>>, StorageError>
     where
         K: Borrow<Q>,
         Q: PartialEq + Eq + Hash + Serialize + ?Sized,
     {
-        match self.get_raw(key) {
-            Ok(Some(bytes)) => Ok(Some(Cow::Owned(bincode::deserialize(&bytes)?))),
-            Ok(None) => Ok(None),
-            Err(e) => Err(e),
+        let raw_key = self.create_prefixed_key(key)?;
+        match self.database.get_pinned_confirmed(&raw_key)? {
+            Some(bytes) => match bincode::deserialize(&bytes) {
+                Ok(value) => Ok(Some(Cow::Owned(value))),
+                Err(error) => Err(corruption_error(&raw_key, error)),
+            },
+            None => Ok(None),
         }
     }
 
@@ -470,8 +492,13 @@ impl<'a, V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned> Iterator
 }
 
 impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> DataMap<K, V> {
+    /// Runs the given closure, serving all confirmed point reads on this thread from a single snapshot of the database.
+    pub fn read_at_snapshot<T>(&self, f: impl FnOnce() -> T) -> T {
+        self.database.read_at_snapshot(f)
+    }
+
     #[inline]
-    fn create_prefixed_key<Q>(&self, key: &Q) -> Result<Vec<u8>>
+    fn create_prefixed_key<Q>(&self, key: &Q) -> Result<Vec<u8>, StorageError>
     where
         K: Borrow<Q>,
         Q: Serialize + ?Sized,
@@ -481,13 +508,13 @@ impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> DataMap<K
         Ok(raw_key)
     }
 
-    fn get_raw<Q>(&self, key: &Q) -> Result<Option<rocksdb::DBPinnableSlice>>
+    fn get_raw<Q>(&self, key: &Q) -> Result<Option<rocksdb::DBPinnableSlice>, StorageError>
     where
         K: Borrow<Q>,
         Q: Serialize + ?Sized,
     {
         let raw_key = self.create_prefixed_key(key)?;
-        match self.database.get_pinned(&raw_key)? {
+        match self.database.get_pinned_confirmed(&raw_key)? {
             Some(data) => Ok(Some(data)),
             None => Ok(None),
         }
@@ -566,9 +593,9 @@ mod tests {
             self.own_map.is_atomic_in_progress() || self.extra_maps.is_atomic_in_progress()
         }
 
-        fn atomic_checkpoint(&self) {
-            self.own_map.atomic_checkpoint();
-            self.extra_maps.atomic_checkpoint();
+        fn atomic_checkpoint(&self, label: Option<&'static str>) {
+            self.own_map.atomic_checkpoint(label);
+            self.extra_maps.atomic_checkpoint(label);
         }
 
         fn clear_latest_checkpoint(&self) {
@@ -581,6 +608,11 @@ mod tests {
             self.extra_maps.atomic_rewind();
         }
 
+        fn abort_atomic(&self) {
+            self.own_map.abort_atomic();
+            self.extra_maps.abort_atomic();
+        }
+
         fn finish_atomic(&self) -> Result<()> {
             self.own_map.finish_atomic()?;
             self.extra_maps.finish_atomic()
@@ -622,10 +654,10 @@ mod tests {
                 || self.extra_maps.is_atomic_in_progress()
         }
 
-        fn atomic_checkpoint(&self) {
-            self.own_map1.atomic_checkpoint();
-            self.own_map2.atomic_checkpoint();
-            self.extra_maps.atomic_checkpoint();
+        fn atomic_checkpoint(&self, label: Option<&'static str>) {
+            self.own_map1.atomic_checkpoint(label);
+            self.own_map2.atomic_checkpoint(label);
+            self.extra_maps.atomic_checkpoint(label);
         }
 
         fn clear_latest_checkpoint(&self) {
@@ -640,6 +672,12 @@ mod tests {
             self.extra_maps.atomic_rewind();
         }
 
+        fn abort_atomic(&self) {
+            self.own_map1.abort_atomic();
+            self.own_map2.abort_atomic();
+            self.extra_maps.abort_atomic();
+        }
+
         fn finish_atomic(&self) -> Result<()> {
             self.own_map1.finish_atomic()?;
             self.own_map2.finish_atomic()?;
@@ -664,8 +702,8 @@ mod tests {
             self.own_map.is_atomic_in_progress()
         }
 
-        fn atomic_checkpoint(&self) {
-            self.own_map.atomic_checkpoint();
+        fn atomic_checkpoint(&self, label: Option<&'static str>) {
+            self.own_map.atomic_checkpoint(label);
         }
 
         fn clear_latest_checkpoint(&self) {
@@ -676,6 +714,10 @@ mod tests {
             self.own_map.atomic_rewind();
         }
 
+        fn abort_atomic(&self) {
+            self.own_map.abort_atomic();
+        }
+
         fn finish_atomic(&self) -> Result<()> {
             self.own_map.finish_atomic()
         }
@@ -773,7 +815,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
+        assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Start an atomic write batch.
         map.start_atomic();
@@ -788,15 +830,15 @@ mod tests {
             // The pending batch should contain NUM_ITEMS / 2 items.
             assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoints.lock().last(), None);
+            assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
         }
 
         // Run the same sequence of checks 3 times.
         for _ in 0..3 {
             // Perform a checkpoint.
-            map.atomic_checkpoint();
+            map.atomic_checkpoint(None);
             // Make sure the checkpoint index is NUM_ITEMS / 2.
-            assert_eq!(map.checkpoints.lock().last(), Some(&(NUM_ITEMS / 2)));
+            assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), Some(&(NUM_ITEMS / 2)));
 
             {
                 // Queue (since a batch is in progress) another NUM_ITEMS / 2 insertions.
@@ -808,13 +850,13 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS);
                 // Make sure the checkpoint index is NUM_ITEMS / 2.
-                assert_eq!(map.checkpoints.lock().last(), Some(&(NUM_ITEMS / 2)));
+                assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), Some(&(NUM_ITEMS / 2)));
             }
 
             // Abort the current atomic write batch.
             map.atomic_rewind();
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoints.lock().last(), None);
+            assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             {
                 // The map should still contain no items.
@@ -822,7 +864,7 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS / 2 items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
                 // Make sure the checkpoint index is None.
-                assert_eq!(map.checkpoints.lock().last(), None);
+                assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
             }
         }
 
@@ -833,7 +875,7 @@ mod tests {
         // The pending batch should contain no items.
         assert!(map.iter_pending().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
+        assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
     }
 
     #[test]
@@ -847,7 +889,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
+        assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Start a nested atomic batch scope that completes successfully.
         atomic_batch_scope!(map, {
@@ -860,7 +902,7 @@ mod tests {
             // The pending batch should contain NUM_ITEMS / 2 items.
             assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoints.lock().last(), None);
+            assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             // Start a nested atomic batch scope that completes successfully.
             atomic_batch_scope!(map, {
@@ -873,7 +915,7 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS);
                 // Make sure the checkpoint index is NUM_ITEMS / 2.
-                assert_eq!(map.checkpoints.lock().last(), Some(&(NUM_ITEMS / 2)));
+                assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), Some(&(NUM_ITEMS / 2)));
 
                 Ok(())
             })?;
@@ -883,7 +925,7 @@ mod tests {
             // The pending batch should contain NUM_ITEMS items.
             assert_eq!(map.iter_pending().count(), NUM_ITEMS);
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoints.lock().last(), None);
+            assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             Ok(())
         })?;
@@ -893,7 +935,7 @@ mod tests {
         // The pending batch should contain no items.
         assert!(map.iter_pending().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
+        assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         Ok(())
     }
@@ -909,7 +951,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
+        assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Start an atomic write batch.
         let run_nested_atomic_batch_scope = || -> Result<()> {
@@ -924,7 +966,7 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS / 2 items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
                 // Make sure the checkpoint index is None.
-                assert_eq!(map.checkpoints.lock().last(), None);
+                assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
                 // Start a nested atomic write batch that completes correctly.
                 atomic_batch_scope!(map, {
@@ -937,7 +979,7 @@ mod tests {
                     // The pending batch should contain NUM_ITEMS items.
                     assert_eq!(map.iter_pending().count(), NUM_ITEMS);
                     // Make sure the checkpoint index is NUM_ITEMS / 2.
-                    assert_eq!(map.checkpoints.lock().last(), Some(&(NUM_ITEMS / 2)));
+                    assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), Some(&(NUM_ITEMS / 2)));
 
                     bail!("This batch should fail.");
                 })?;
@@ -963,7 +1005,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
+        assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Start an atomic finalize.
         let outcome = atomic_finalize!(map, FinalizeMode::RealRun, {
@@ -978,7 +1020,7 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS / 2 items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
                 // Make sure the checkpoint index is 0.
-                assert_eq!(map.checkpoints.lock().last(), Some(&0));
+                assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), Some(&0));
 
                 Ok(())
             })
@@ -989,7 +1031,7 @@ mod tests {
             // The pending batch should contain NUM_ITEMS / 2 items.
             assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoints.lock().last(), None);
+            assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             // Start a nested atomic write batch that completes correctly.
             atomic_batch_scope!(map, {
@@ -1002,7 +1044,7 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS);
                 // Make sure the checkpoint index is NUM_ITEMS / 2.
-                assert_eq!(map.checkpoints.lock().last(), Some(&(NUM_ITEMS / 2)));
+                assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), Some(&(NUM_ITEMS / 2)));
 
                 Ok(())
             })
@@ -1013,7 +1055,7 @@ mod tests {
             // The pending batch should contain NUM_ITEMS items.
             assert_eq!(map.iter_pending().count(), NUM_ITEMS);
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoints.lock().last(), None);
+            assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             Ok(())
         });
@@ -1026,181 +1068,122 @@ mod tests {
         // The pending batch should contain no items.
         assert!(map.iter_pending().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
+        assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         Ok(())
     }
 
     #[test]
     fn test_atomic_finalize_failing_internal_scope() -> Result<()> {
-        // The number of items that will be queued to be inserted into the map.
-        const NUM_ITEMS: usize = 10;
-
         // Initialize a map.
         let map: DataMap<usize, String> =
             RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
-        // Sanity check.
-        assert!(map.iter_confirmed().next().is_none());
-        // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
-
-        // Start an atomic finalize.
-        let outcome = atomic_finalize!(map, FinalizeMode::RealRun, {
-            // Start a nested atomic batch scope that completes successfully.
-            atomic_batch_scope!(map, {
-                // Queue (since a batch is in progress) NUM_ITEMS / 2 insertions.
-                for i in 0..NUM_ITEMS / 2 {
-                    map.insert(i, i.to_string()).unwrap();
-                }
-                // The map should still contain no items.
-                assert!(map.iter_confirmed().next().is_none());
-                // The pending batch should contain NUM_ITEMS / 2 items.
-                assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
-                // Make sure the checkpoint index is 0.
-                assert_eq!(map.checkpoints.lock().last(), Some(&0));
-
-                Ok(())
-            })
-            .unwrap();
-
-            // The map should still contain no items.
-            assert!(map.iter_confirmed().next().is_none());
-            // The pending batch should contain NUM_ITEMS / 2 items.
-            assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
-            // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoints.lock().last(), None);
-
-            // Start a nested atomic write batch that fails.
-            let result: Result<()> = atomic_batch_scope!(map, {
-                // Queue (since a batch is in progress) another NUM_ITEMS / 2 insertions.
-                for i in (NUM_ITEMS / 2)..NUM_ITEMS {
-                    map.insert(i, i.to_string()).unwrap();
-                }
-                // The map should still contain no items.
-                assert!(map.iter_confirmed().next().is_none());
-                // The pending batch should contain NUM_ITEMS items.
-                assert_eq!(map.iter_pending().count(), NUM_ITEMS);
-                // Make sure the checkpoint index is NUM_ITEMS / 2.
-                assert_eq!(map.checkpoints.lock().last(), Some(&(NUM_ITEMS / 2)));
-
-                bail!("This batch scope should fail.");
-            });
 
-            // Ensure that the batch scope failed.
-            assert!(result.is_err());
-
-            // The map should still contain no items.
-            assert!(map.iter_confirmed().next().is_none());
-            // The pending batch should contain NUM_ITEMS / 2 items.
-            assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
-            // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoints.lock().last(), None);
+        crate::helpers::test_helpers::map::check_atomic_finalize_failing_internal_scope(map)
+    }
 
-            Ok(())
-        });
+    #[test]
+    fn test_atomic_finalize_fails_to_start() {
+        // Initialize a map.
+        let map: DataMap<usize, String> =
+            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
 
-        // The atomic finalize should have succeeded.
-        assert!(outcome.is_ok());
+        crate::helpers::test_helpers::map::check_atomic_finalize_fails_to_start(map)
+    }
 
-        // The map should contain NUM_ITEMS / 2.
-        assert_eq!(map.iter_confirmed().count(), NUM_ITEMS / 2);
-        // The pending batch should contain no items.
-        assert!(map.iter_pending().next().is_none());
-        // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
+    #[test]
+    fn test_atomic_checkpoint_truncation() {
+        // Initialize a map.
+        let map: DataMap<usize, String> =
+            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
 
-        Ok(())
+        crate::helpers::test_helpers::map::check_atomic_checkpoint_truncation(map)
     }
 
     #[test]
-    fn test_atomic_finalize_fails_to_start() {
+    fn test_atomic_scope_outcome_top_level_abort() {
         // Initialize a map.
         let map: DataMap<usize, String> =
             RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
-        // Sanity check.
-        assert!(map.iter_confirmed().next().is_none());
-        // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
 
-        // Construct an atomic batch scope.
-        let outcome: Result<()> = atomic_batch_scope!(map, {
-            // Start an atomic finalize.
-            let outcome = atomic_finalize!(map, FinalizeMode::RealRun, { Ok(()) });
-            // Ensure that the atomic finalize fails.
-            assert!(outcome.is_err());
-
-            unreachable!("The batch scope should fail before we reach this point.");
-        });
+        crate::helpers::test_helpers::map::check_atomic_scope_outcome_top_level_abort(map)
+    }
 
-        // Ensure that the atomic batch scope fails.
-        assert!(outcome.is_err());
+    #[test]
+    fn test_atomic_scope_outcome_nested_abort() -> Result<()> {
+        // Initialize a map.
+        let map: DataMap<usize, String> =
+            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
 
-        // Start an atomic operation.
-        map.start_atomic();
+        crate::helpers::test_helpers::map::check_atomic_scope_outcome_nested_abort(map)
+    }
 
-        // We need to catch the `atomic_finalize` here, otherwise it will end the test early.
-        let outcome = || atomic_finalize!(map, FinalizeMode::RealRun, { Ok(()) });
+    #[test]
+    fn test_atomic_finalize_with_aborted_scope() -> Result<()> {
+        // Initialize a map.
+        let map: DataMap<usize, String> =
+            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
 
-        // Ensure that the atomic finalize fails if an atomic batch is in progress.
-        assert!(outcome().is_err());
+        crate::helpers::test_helpers::map::check_atomic_finalize_with_aborted_scope(map)
     }
 
     #[test]
-    fn test_atomic_checkpoint_truncation() {
+    fn test_checkpoint_labels() {
         // Initialize a map.
         let map: DataMap<usize, String> =
             RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
-        // Sanity check.
-        assert!(map.iter_confirmed().next().is_none());
-        // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
-
-        // Insert the key.
-        map.insert(0, "0".to_string()).unwrap();
+        // Make sure the checkpoint stack is empty.
+        assert!(map.debug_checkpoint_stack().is_empty());
 
-        // Start an atomic finalize.
+        // Start an atomic batch scope.
         let outcome = atomic_batch_scope!(map, {
-            // Insert the key.
-            map.insert(0, "1".to_string()).unwrap();
-
-            assert_eq!(map.checkpoints.lock().last(), None);
+            // Ensure the top-level scope does not push a checkpoint.
+            assert!(map.debug_checkpoint_stack().is_empty());
+            map.insert(0, "0".to_string()).unwrap();
 
-            // Create a failing atomic batch scope that will reset the checkpoint.
-            let result: Result<()> = atomic_batch_scope!(map, {
-                // Make sure the checkpoint index is 1.
-                assert_eq!(map.checkpoints.lock().last(), Some(&1));
+            // Start a nested atomic batch scope.
+            atomic_batch_scope!(map, {
+                map.insert(1, "1".to_string()).unwrap();
+                let outer_stack = map.debug_checkpoint_stack();
+                assert_eq!(outer_stack.len(), 1);
+                assert_eq!(outer_stack[0].1, 1);
 
-                // Update the key.
-                map.insert(0, "2".to_string()).unwrap();
+                // Push a manual checkpoint with a custom label.
+                map.atomic_checkpoint(Some("manual"));
+                map.insert(2, "2".to_string()).unwrap();
 
-                bail!("This batch scope should fail.")
-            });
+                // Start a doubly-nested atomic batch scope.
+                let result: Result<()> = atomic_batch_scope!(map, {
+                    let inner_stack = map.debug_checkpoint_stack();
+                    assert_eq!(inner_stack.len(), 3);
+                    assert_eq!(inner_stack[..2], [outer_stack[0], (Some("manual"), 2)]);
+                    assert_eq!(inner_stack[2].1, 3);
+                    // Ensure the default labels identify their call sites.
+                    assert!(inner_stack[2].0.unwrap().contains(file!()));
+                    assert_ne!(inner_stack[2].0, outer_stack[0].0);
+
+                    map.insert(3, "3".to_string()).unwrap();
+                    bail!("This batch scope should fail.")
+                });
+                assert!(result.is_err());
 
-            // Ensure that the batch scope failed.
-            assert!(result.is_err());
-            // The map should contain 1 item.
-            assert_eq!(map.iter_confirmed().count(), 1);
-            // The pending batch should contain 1 item.
-            assert_eq!(map.iter_pending().count(), 1);
-            // Ensure the pending operations still has the initial insertion.
-            assert_eq!(map.get_pending(&0), Some(Some("1".to_string())));
-            // Ensure the confirmed value has not changed.
-            assert_eq!(*map.iter_confirmed().next().unwrap().1, "0");
-            assert_eq!(map.checkpoints.lock().last(), None);
+                // Ensure the failing scope popped only its own checkpoint.
+                assert_eq!(map.debug_checkpoint_stack(), [outer_stack[0], (Some("manual"), 2)]);
+                // Ensure the manual checkpoint is popped next.
+                map.clear_latest_checkpoint();
+                assert_eq!(map.debug_checkpoint_stack(), outer_stack);
+                Ok(())
+            })?;
 
+            // Ensure the nested scope popped its checkpoint.
+            assert!(map.debug_checkpoint_stack().is_empty());
             Ok(())
         });
 
         assert!(outcome.is_ok());
-        // The map should contain 1 item.
-        assert_eq!(map.iter_confirmed().count(), 1);
-        // The pending batch should contain no items.
-        assert!(map.iter_pending().next().is_none());
-        // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
-
-        // Ensure that the map value is correct.
-        assert_eq!(*map.iter_confirmed().next().unwrap().1, "1");
+        // Ensure the rewind behavior is unchanged.
+        assert_eq!(map.iter_confirmed().count(), 3);
+        assert!(map.debug_checkpoint_stack().is_empty());
     }
 
     #[test]
@@ -1211,7 +1194,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
+        assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Insert the key.
         map.insert(0, "0".to_string()).unwrap();
@@ -1222,7 +1205,7 @@ mod tests {
             // Simulates an accepted transaction.
             let result: Result<()> = atomic_batch_scope!(map, {
                 // Make sure the checkpoint index is 0.
-                assert_eq!(map.checkpoints.lock().last(), Some(&0));
+                assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), Some(&0));
 
                 // Insert the key.
                 map.insert(0, "1".to_string()).unwrap();
@@ -1231,7 +1214,7 @@ mod tests {
             });
 
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoints.lock().last(), None);
+            assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             // The atomic finalize should have succeeded.
             assert!(result.is_ok());
@@ -1246,12 +1229,12 @@ mod tests {
             // Simulates a rejected transaction.
             let result: Result<()> = atomic_batch_scope!(map, {
                 // Make sure the checkpoint index is 1.
-                assert_eq!(map.checkpoints.lock().last(), Some(&1));
+                assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), Some(&1));
 
                 // Simulate an instruction
                 let result: Result<()> = atomic_batch_scope!(map, {
                     // Make sure the checkpoint index is 1.
-                    assert_eq!(map.checkpoints.lock().last(), Some(&1));
+                    assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), Some(&1));
 
                     // Update the key.
                     map.insert(0, "2".to_string()).unwrap();
@@ -1261,7 +1244,7 @@ mod tests {
                 assert!(result.is_ok());
 
                 // Make sure the checkpoint index is 1.
-                assert_eq!(map.checkpoints.lock().last(), Some(&1));
+                assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), Some(&1));
                 // Ensure that the atomic batch length is 2.
                 assert_eq!(map.atomic_batch.lock().len(), 2);
                 // Ensure that the database atomic batch is empty.
@@ -1272,7 +1255,7 @@ mod tests {
                 // Simulates an instruction that fails.
                 let result: Result<()> = atomic_batch_scope!(map, {
                     // Make sure the checkpoint index is 2.
-                    assert_eq!(map.checkpoints.lock().last(), Some(&2));
+                    assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), Some(&2));
 
                     // Update the key.
                     map.insert(0, "3".to_string()).unwrap();
@@ -1293,7 +1276,7 @@ mod tests {
             // Make sure the pending operations still has the initial insertion.
             assert_eq!(map.get_pending(&0), Some(Some("1".to_string())));
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoints.lock().last(), None);
+            assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
             // Ensure that the atomic batch length is 1.
             assert_eq!(map.atomic_batch.lock().len(), 1);
             // Ensure that the database atomic batch is empty.
@@ -1310,7 +1293,7 @@ mod tests {
         // The pending batch should contain no items.
         assert!(map.iter_pending().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
+        assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
         // Ensure that the atomic batch is empty.
         assert!(map.atomic_batch.lock().is_empty());
         // Ensure that the database atomic batch is empty.
@@ -1337,7 +1320,7 @@ mod tests {
         assert!(test_storage.extra_maps.own_map2.iter_confirmed().next().is_none());
         assert!(test_storage.extra_maps.extra_maps.own_map.iter_confirmed().next().is_none());
 
-        assert_eq!(test_storage.own_map.checkpoints.lock().last(), None);
+        assert_eq!(test_storage.own_map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Note: all the checks going through .database can be performed on any one
         // of the objects, as all of them share the same instance of the database.
@@ -1348,7 +1331,7 @@ mod tests {
         atomic_batch_scope!(test_storage, {
             assert!(test_storage.is_atomic_in_progress_everywhere());
 
-            assert_eq!(test_storage.own_map.checkpoints.lock().last(), None);
+            assert_eq!(test_storage.own_map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             // Write an item into the first map.
             test_storage.own_map.insert(0, 0.to_string()).unwrap();
@@ -1469,4 +1452,42 @@ mod tests {
         assert_eq!(test_storage.extra_maps.own_map2.iter_confirmed().count(), 1);
         assert_eq!(test_storage.extra_maps.extra_maps.own_map.iter_confirmed().count(), 0);
     }
+
+    #[test]
+    #[serial]
+    fn test_read_at_snapshot_across_maps() {
+        const NUM_BATCHES: usize = 1_000;
+
+        // Initialize a multi-layer test storage.
+        let test_storage = TestStorage::open();
+        let (map_1, map_2) = (&test_storage.own_map, &test_storage.extra_maps.own_map1);
+
+        std::thread::scope(|scope| {
+            // Write the same value into both maps, in one atomic batch at a time.
+            scope.spawn(|| {
+                for i in 0..NUM_BATCHES {
+                    let result: Result<()> = atomic_batch_scope!(test_storage, {
+                        map_1.insert(0, i.to_string())?;
+                        map_2.insert(0, i.to_string())?;
+                        Ok(())
+                    });
+                    result.unwrap();
+                }
+            });
+
+            // Read both maps at a snapshot, until the last batch is observed.
+            scope.spawn(|| loop {
+                let (value_1, value_2) = map_1.read_at_snapshot(|| {
+                    let value_1 = map_1.get_confirmed(&0).unwrap().map(|value| value.into_owned());
+                    let value_2 = map_2.get_confirmed(&0).unwrap().map(|value| value.into_owned());
+                    (value_1, value_2)
+                });
+                // Ensure the reads never observe a batch partially.
+                assert_eq!(value_1, value_2);
+                if value_1 == Some((NUM_BATCHES - 1).to_string()) {
+                    break;
+                }
+            });
+        });
+    }
 }
@@ -24,7 +24,9 @@ pub use nested_map::*;
 #[cfg(test)]
 mod tests;
 
-use anyhow::{bail, Result};
+use crate::helpers::{StorageError, StorageHealth, StorageOptions};
+
+use anyhow::{bail, ensure, Result};
 use once_cell::sync::OnceCell;
 use parking_lot::Mutex;
 use serde::{de::DeserializeOwned, Serialize};
@@ -40,6 +42,26 @@ use std::{
 
 pub const PREFIX_LEN: usize = 4; // N::ID (u16) + DataID (u16)
 
+#[cfg(test)]
+thread_local! {
+    /// The storage error to be returned by the next atomic batch write on this thread, if any.
+    pub(crate) static INJECTED_FAULT: core::cell::RefCell<Option<StorageError>> = Default::default();
+}
+
+thread_local! {
+    /// The database address and read options of the snapshot that confirmed reads on this thread are served from, if any.
+    static READ_SNAPSHOT: core::cell::RefCell<Option<(usize, rocksdb::ReadOptions)>> = Default::default();
+}
+
+/// A guard that stops serving confirmed reads on this thread from a snapshot, when dropped.
+struct ReadSnapshotGuard;
+
+impl Drop for ReadSnapshotGuard {
+    fn drop(&mut self) {
+        READ_SNAPSHOT.with(|snapshot| *snapshot.borrow_mut() = None);
+    }
+}
+
 pub trait Database {
     /// Opens the database.
     fn open(network_id: u16, dev: Option<u16>) -> Result<Self>
@@ -75,6 +97,8 @@ pub struct RocksDB {
     network_id: u16,
     /// The optional development ID.
     dev: Option<u16>,
+    /// The storage options the database was opened with.
+    options: StorageOptions,
     /// The low-level database transaction that gets executed atomically at the end
     /// of a real-run `atomic_finalize` or the outermost `atomic_batch_scope`.
     pub(super) atomic_batch: Arc<Mutex<rocksdb::WriteBatch>>,
@@ -97,43 +121,7 @@ impl Database for RocksDB {
     /// In production mode, the database opens directory `~/.aleo/storage/ledger-{network}`.
     /// In development mode, the database opens directory `/path/to/repo/.ledger-{network}-{id}`.
     fn open(network_id: u16, dev: Option<u16>) -> Result<Self> {
-        static DB: OnceCell<RocksDB> = OnceCell::new();
-
-        // Retrieve the database.
-        let database = DB
-            .get_or_try_init(|| {
-                // Customize database options.
-                let mut options = rocksdb::Options::default();
-                options.set_compression_type(rocksdb::DBCompressionType::Lz4);
-
-                // Register the prefix length.
-                let prefix_extractor = rocksdb::SliceTransform::create_fixed_prefix(PREFIX_LEN);
-                options.set_prefix_extractor(prefix_extractor);
-
-                let primary = aleo_std::aleo_ledger_dir(network_id, dev);
-                let rocksdb = {
-                    options.increase_parallelism(2);
-                    options.set_max_background_jobs(4);
-                    options.create_if_missing(true);
-
-                    Arc::new(rocksdb::DB::open(&options, primary)?)
-                };
-
-                Ok::<_, anyhow::Error>(RocksDB {
-                    rocksdb,
-                    network_id,
-                    dev,
-                    atomic_batch: Default::default(),
-                    atomic_depth: Default::default(),
-                })
-            })?
-            .clone();
-
-        // Ensure the database network ID and development ID match.
-        match database.network_id == network_id && database.dev == dev {
-            true => Ok(database),
-            false => bail!("Mismatching network ID or development ID in the database"),
-        }
+        Self::open_database(network_id, dev, None)
     }
 
     /// Opens the map with the given `network_id`, `(optional) development ID`, and `map_id` from storage.
@@ -188,21 +176,256 @@ impl Database for RocksDB {
     }
 }
 
+impl RocksDB {
+    /// Opens the database with the given storage options.
+    ///
+    /// The options only take effect if the database is not open yet;
+    /// otherwise, they must match the options that the database was opened with.
+    pub fn open_with_options(network_id: u16, dev: Option<u16>, options: StorageOptions) -> Result<Self> {
+        Self::open_database(network_id, dev, Some(options))
+    }
+
+    /// Opens the database, with the given storage options if it is not open yet.
+    fn open_database(network_id: u16, dev: Option<u16>, options: Option<StorageOptions>) -> Result<Self> {
+        static DB: OnceCell<RocksDB> = OnceCell::new();
+
+        // Retrieve the database.
+        let database = DB
+            .get_or_try_init(|| {
+                // Customize database options.
+                let options = options.clone().unwrap_or_default();
+                let primary = aleo_std::aleo_ledger_dir(network_id, dev);
+                let rocksdb = Arc::new(rocksdb::DB::open(&rocksdb_options(&options)?, primary)?);
+
+                Ok::<_, anyhow::Error>(RocksDB {
+                    rocksdb,
+                    network_id,
+                    dev,
+                    options,
+                    atomic_batch: Default::default(),
+                    atomic_depth: Default::default(),
+                })
+            })?
+            .clone();
+
+        // Ensure the database network ID and development ID match.
+        if database.network_id != network_id || database.dev != dev {
+            bail!("Mismatching network ID or development ID in the database")
+        }
+        // Ensure the storage options match, if they were given.
+        if let Some(options) = options {
+            ensure!(database.options == options, "The database is already open with different storage options");
+        }
+        Ok(database)
+    }
+
+    /// Returns the storage options the database was opened with.
+    pub const fn options(&self) -> &StorageOptions {
+        &self.options
+    }
+
+    /// Compacts the entries of the given map, discarding deleted and overwritten entries.
+    pub fn compact_map(&self, map_id: MapID) -> Result<()> {
+        // Prepare the map prefix.
+        let mut start = self.network_id.to_le_bytes().to_vec();
+        start.extend_from_slice(&u16::from(map_id).to_le_bytes());
+        // Compact the range of keys with the map prefix.
+        self.rocksdb.compact_range(Some(&start), prefix_successor(&start).as_deref());
+        Ok(())
+    }
+
+    /// Compacts all entries in the database, discarding deleted and overwritten entries.
+    pub fn compact_all(&self) -> Result<()> {
+        self.rocksdb.compact_range::<&[u8], &[u8]>(None, None);
+        Ok(())
+    }
+
+    /// Returns the approximate size (in bytes) of the database files on disk.
+    pub fn approximate_size(&self) -> Result<u64> {
+        match self.rocksdb.property_int_value("rocksdb.total-sst-files-size")? {
+            Some(size) => Ok(size),
+            None => bail!("Failed to retrieve the approximate size of the database"),
+        }
+    }
+
+    /// Returns a snapshot of the database, which provides a read-only view of the committed state
+    /// at the time of the call, unaffected by any atomic batch written after it.
+    pub fn snapshot(&self) -> rocksdb::Snapshot<'_> {
+        self.rocksdb.snapshot()
+    }
+
+    /// Runs the given closure, serving all confirmed point reads on this thread from a single snapshot of the database.
+    /// This ensures that the reads do not observe an atomic batch that is committed in between them.
+    ///
+    /// Note: Iterators are not affected, and continue to read the latest committed state.
+    /// If the thread is already reading at a snapshot, the closure is run at the existing snapshot.
+    pub fn read_at_snapshot<T>(&self, f: impl FnOnce() -> T) -> T {
+        // If the thread is already reading at a snapshot, continue reading at it.
+        if READ_SNAPSHOT.with(|snapshot| snapshot.borrow().is_some()) {
+            return f();
+        }
+
+        // Take a snapshot of the database.
+        let snapshot = self.snapshot();
+        let mut read_options = rocksdb::ReadOptions::default();
+        read_options.set_snapshot(&snapshot);
+        // Serve the confirmed reads on this thread from the snapshot, until the guard is dropped.
+        // Note: The guard is declared after the snapshot, so it is dropped before the snapshot is released.
+        READ_SNAPSHOT.with(|current| *current.borrow_mut() = Some((self.address(), read_options)));
+        let _guard = ReadSnapshotGuard;
+
+        f()
+    }
+
+    /// Returns the committed value for the given raw key, read at the snapshot of this thread, if there is one.
+    pub(super) fn get_pinned_confirmed(&self, key: &[u8]) -> Result<Option<rocksdb::DBPinnableSlice>, rocksdb::Error> {
+        READ_SNAPSHOT.with(|snapshot| match &*snapshot.borrow() {
+            Some((address, read_options)) if *address == self.address() => self.rocksdb.get_pinned_opt(key, read_options),
+            _ => self.rocksdb.get_pinned(key),
+        })
+    }
+
+    /// Returns the address of the underlying database, which identifies it among the open databases.
+    fn address(&self) -> usize {
+        Arc::as_ptr(&self.rocksdb) as usize
+    }
+
+    /// Executes the given write batch atomically, classifying any failure into a `StorageError`.
+    pub(super) fn execute_batch(&self, batch: rocksdb::WriteBatch, sync: bool) -> Result<(), StorageError> {
+        // If a failure was injected for testing, return it instead of writing.
+        #[cfg(test)]
+        if let Some(error) = INJECTED_FAULT.with(|fault| fault.borrow_mut().take()) {
+            return Err(error);
+        }
+
+        // Prepare the write options.
+        let mut options = rocksdb::WriteOptions::default();
+        options.set_sync(sync);
+        // Execute the write batch.
+        self.rocksdb.write_opt(batch, &options).map_err(StorageError::from)
+    }
+
+    /// Checks the health of the database write path, by performing a synced write and delete
+    /// of a reserved key, and reports the available disk space for the data directory.
+    pub fn health_check(&self) -> StorageHealth {
+        // Retrieve the data directory.
+        let path = self.rocksdb.path();
+        // Determine the available disk space for the data directory.
+        let available_space = fs2::available_space(path).ok();
+
+        // Prepare the reserved key.
+        let mut key = self.network_id.to_le_bytes().to_vec();
+        key.extend_from_slice(&u16::from(MapID::Health(HealthMap::Probe)).to_le_bytes());
+
+        // Prepare a write batch that leaves no trace in the database.
+        let mut batch = rocksdb::WriteBatch::default();
+        batch.put(&key, [0u8]);
+        batch.delete(&key);
+
+        // Attempt the write, and check the remaining disk space.
+        let error = match (self.execute_batch(batch, true), available_space) {
+            (Err(error), _) => Some(error),
+            (Ok(()), Some(0)) => Some(StorageError::DiskFull(format!("No space left in '{}'", path.display()))),
+            (Ok(()), _) => None,
+        };
+
+        StorageHealth::new(error, available_space)
+    }
+}
+
+/// Returns the RocksDB options for the given storage options.
+fn rocksdb_options(storage_options: &StorageOptions) -> Result<rocksdb::Options> {
+    let mut options = rocksdb::Options::default();
+    match storage_options.zstd_compression {
+        #[cfg(feature = "rocks-zstd")]
+        true => options.set_compression_type(rocksdb::DBCompressionType::Zstd),
+        #[cfg(not(feature = "rocks-zstd"))]
+        true => bail!("The zstd compression option requires snarkVM to be built with the 'rocks-zstd' feature"),
+        false => options.set_compression_type(rocksdb::DBCompressionType::Lz4),
+    }
+
+    // Register the prefix length.
+    let prefix_extractor = rocksdb::SliceTransform::create_fixed_prefix(PREFIX_LEN);
+    options.set_prefix_extractor(prefix_extractor);
+
+    options.increase_parallelism(2);
+    options.set_max_background_jobs(storage_options.max_background_jobs);
+    if let Some(write_buffer_size) = storage_options.write_buffer_size {
+        options.set_write_buffer_size(write_buffer_size);
+    }
+    if let Some(target_file_size) = storage_options.target_file_size {
+        options.set_target_file_size_base(target_file_size);
+    }
+    options.create_if_missing(true);
+    Ok(options)
+}
+
+/// Returns the smallest key that is greater than every key with the given prefix, if one exists.
+pub(crate) fn prefix_successor(prefix: &[u8]) -> Option<Vec<u8>> {
+    let mut successor = prefix.to_vec();
+    // Increment the last byte that is not `u8::MAX`, and truncate the bytes after it.
+    while let Some(last) = successor.pop() {
+        if last < u8::MAX {
+            successor.push(last + 1);
+            return Some(successor);
+        }
+    }
+    None
+}
+
+impl From<rocksdb::Error> for StorageError {
+    /// Classifies the given RocksDB error.
+    fn from(error: rocksdb::Error) -> Self {
+        classify_error(error.kind(), error.into_string())
+    }
+}
+
+/// Returns a corruption error for the value stored under the given raw key, which failed to deserialize.
+///
+/// The raw key starts with the map context, which is the network ID followed by the map ID.
+pub(crate) fn corruption_error(raw_key: &[u8], error: bincode::Error) -> StorageError {
+    let map = raw_key.get(2..PREFIX_LEN).map(|map_id| u16::from_le_bytes([map_id[0], map_id[1]]));
+    StorageError::Corruption { map, key_bytes: Some(raw_key.to_vec()), message: error.to_string() }
+}
+
+/// Classifies a RocksDB error with the given kind and message into a `StorageError`.
+pub(crate) fn classify_error(kind: rocksdb::ErrorKind, message: String) -> StorageError {
+    // Note: RocksDB reports filesystem failures as generic IO errors, so the message is inspected as well.
+    let lowercase = message.to_lowercase();
+    match kind {
+        _ if lowercase.contains("no space left on device") || lowercase.contains("disk quota exceeded") => {
+            StorageError::DiskFull(message)
+        }
+        _ if lowercase.contains("read-only file system") => StorageError::ReadOnlyFs(message),
+        rocksdb::ErrorKind::Corruption => StorageError::corruption(message),
+        rocksdb::ErrorKind::Busy | rocksdb::ErrorKind::TimedOut | rocksdb::ErrorKind::TryAgain => {
+            StorageError::Busy(message)
+        }
+        rocksdb::ErrorKind::IOError if lowercase.contains("resource temporarily unavailable") => {
+            StorageError::Busy(message)
+        }
+        rocksdb::ErrorKind::IOError => StorageError::Io(message),
+        _ => StorageError::Other(message),
+    }
+}
+
 impl RocksDB {
     /// Opens the test database.
     #[cfg(any(test, feature = "test"))]
     pub fn open_testing(temp_dir: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
+        Self::open_testing_with_options(temp_dir, dev, StorageOptions::default())
+    }
+
+    /// Opens the test database with the given storage options.
+    #[cfg(any(test, feature = "test"))]
+    pub fn open_testing_with_options(
+        temp_dir: std::path::PathBuf,
+        dev: Option<u16>,
+        options: StorageOptions,
+    ) -> Result<Self> {
         use console::prelude::{Rng, TestRng};
 
         let database = {
-            // Customize database options.
-            let mut options = rocksdb::Options::default();
-            options.set_compression_type(rocksdb::DBCompressionType::Lz4);
-
-            // Register the prefix length.
-            let prefix_extractor = rocksdb::SliceTransform::create_fixed_prefix(PREFIX_LEN);
-            options.set_prefix_extractor(prefix_extractor);
-
             // Ensure the `temp_dir` is unique.
             let temp_dir = temp_dir.join(Rng::gen::<u64>(&mut TestRng::default()).to_string());
 
@@ -212,30 +435,25 @@ impl RocksDB {
                 None => temp_dir,
             };
 
-            let rocksdb = {
-                options.increase_parallelism(2);
-                options.set_max_background_jobs(4);
-                options.create_if_missing(true);
-
-                // Keep these around as options for configuration testing.
+            // Keep these around as options for configuration testing.
 
-                // options.set_max_subcompactions(4);
-                // options.set_use_direct_io_for_flush_and_compaction(true);
-                // options.set_bytes_per_sync(1 << 28);
-                // options.set_compaction_readahead_size(1 << 28);
-                // options.set_max_write_buffer_number(16);
-                // options.set_min_write_buffer_number_to_merge(8);
-                // options.set_compression_type(rocksdb::DBCompressionType::None);
-                // options.set_bottommost_compression_type(rocksdb::DBCompressionType::None);
-                // options.set_write_buffer_size(1 << 28);
+            // options.set_max_subcompactions(4);
+            // options.set_use_direct_io_for_flush_and_compaction(true);
+            // options.set_bytes_per_sync(1 << 28);
+            // options.set_compaction_readahead_size(1 << 28);
+            // options.set_max_write_buffer_number(16);
+            // options.set_min_write_buffer_number_to_merge(8);
+            // options.set_compression_type(rocksdb::DBCompressionType::None);
+            // options.set_bottommost_compression_type(rocksdb::DBCompressionType::None);
+            // options.set_write_buffer_size(1 << 28);
 
-                Arc::new(rocksdb::DB::open(&options, primary)?)
-            };
+            let rocksdb = Arc::new(rocksdb::DB::open(&rocksdb_options(&options)?, primary)?);
 
             Ok::<_, anyhow::Error>(RocksDB {
                 rocksdb,
                 network_id: u16::MAX,
                 dev,
+                options,
                 atomic_batch: Default::default(),
                 atomic_depth: Default::default(),
             })
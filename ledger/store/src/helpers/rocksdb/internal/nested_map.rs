@@ -15,7 +15,7 @@
 #![allow(clippy::type_complexity)]
 
 use super::*;
-use crate::helpers::{NestedMap, NestedMapRead};
+use crate::helpers::{NestedMap, NestedMapRead, StorageError};
 use console::prelude::FromBytes;
 
 use core::{fmt, fmt::Debug, hash::Hash, mem};
@@ -37,7 +37,7 @@ pub struct NestedDataMap<
     /// The database transaction.
     pub(super) atomic_batch: Arc<Mutex<Vec<(M, Option<K>, Option<V>)>>>,
     /// The checkpoint stack for the batched operations within the map.
-    pub(super) checkpoints: Arc<Mutex<Vec<usize>>>,
+    pub(super) checkpoints: Arc<Mutex<Vec<(Option<&'static str>, usize)>>>,
 }
 
 impl<M: Serialize + DeserializeOwned, K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> Debug
@@ -52,10 +52,12 @@ impl<M: Serialize + DeserializeOwned, K: Serialize + DeserializeOwned, V: Serial
     NestedDataMap<M, K, V>
 {
     #[inline]
-    fn create_prefixed_map(&self, map: &M) -> Result<Vec<u8>> {
+    fn create_prefixed_map(&self, map: &M) -> Result<Vec<u8>, StorageError> {
         let mut raw_map = self.context.clone();
 
-        let map_size: u32 = bincode::serialized_size(&map)?.try_into()?;
+        let map_size: u32 = bincode::serialized_size(&map)?
+            .try_into()
+            .map_err(|_| StorageError::Serialization("The serialized map is too large".to_string()))?;
         raw_map.extend_from_slice(&map_size.to_le_bytes());
 
         bincode::serialize_into(&mut raw_map, map)?;
@@ -63,24 +65,25 @@ impl<M: Serialize + DeserializeOwned, K: Serialize + DeserializeOwned, V: Serial
     }
 
     #[inline]
-    fn create_prefixed_map_key(&self, map: &M, key: &K) -> Result<Vec<u8>> {
+    pub(super) fn create_prefixed_map_key(&self, map: &M, key: &K) -> Result<Vec<u8>, StorageError> {
         let mut raw_map_key = self.create_prefixed_map(map)?;
         bincode::serialize_into(&mut raw_map_key, key)?;
         Ok(raw_map_key)
     }
 
     #[inline]
-    fn get_map_key_raw(&self, map: &M, key: &K) -> Result<Option<rocksdb::DBPinnableSlice>> {
+    fn get_map_key_raw(&self, map: &M, key: &K) -> Result<Option<rocksdb::DBPinnableSlice>, StorageError> {
         let raw_map_key = self.create_prefixed_map_key(map, key)?;
-        match self.database.get_pinned(&raw_map_key)? {
+        match self.database.get_pinned_confirmed(&raw_map_key)? {
             Some(data) => Ok(Some(data)),
             None => Ok(None),
         }
     }
 }
 #[inline]
-fn get_map_and_key(map_key: &[u8]) -> Result<(&[u8], &[u8])> {
-    let map_len = u32::from_bytes_le(&map_key[PREFIX_LEN..][..4])? as usize;
+fn get_map_and_key(map_key: &[u8]) -> Result<(&[u8], &[u8]), StorageError> {
+    let map_len = u32::from_bytes_le(&map_key[PREFIX_LEN..][..4])
+        .map_err(|error| StorageError::corruption(error.to_string()))? as usize;
     let map = &map_key[PREFIX_LEN + 4..][..map_len];
     let key = &map_key[PREFIX_LEN + 4 + map_len..];
 
@@ -97,7 +100,7 @@ impl<
     ///
     /// Inserts the given map-key-value pair.
     ///
-    fn insert(&self, map: M, key: K, value: V) -> Result<()> {
+    fn insert(&self, map: M, key: K, value: V) -> Result<(), StorageError> {
         // Determine if an atomic batch is in progress.
         match self.is_atomic_in_progress() {
             // If a batch is in progress, add the map-key-value pair to the batch.
@@ -116,7 +119,7 @@ impl<
     ///
     /// Removes the given map.
     ///
-    fn remove_map(&self, map: &M) -> Result<()> {
+    fn remove_map(&self, map: &M) -> Result<(), StorageError> {
         // Determine if an atomic batch is in progress.
         match self.is_atomic_in_progress() {
             // If a batch is in progress, add the map-None pair to the batch.
@@ -153,7 +156,7 @@ impl<
                 }
 
                 // Deleting the batched keys atomically from RocksDB.
-                self.database.write(batch)?;
+                self.database.execute_batch(batch, false)?;
             }
         }
         Ok(())
@@ -162,7 +165,7 @@ impl<
     ///
     /// Removes the key-value pair for the given map and key.
     ///
-    fn remove_key(&self, map: &M, key: &K) -> Result<()> {
+    fn remove_key(&self, map: &M, key: &K) -> Result<(), StorageError> {
         // Determine if an atomic batch is in progress.
         match self.is_atomic_in_progress() {
             // If a batch is in progress, add the key to the batch.
@@ -206,9 +209,16 @@ impl<
     /// Saves the current list of pending operations, so that if `atomic_rewind` is called,
     /// we roll back all future operations, and return to the start of this checkpoint.
     ///
-    fn atomic_checkpoint(&self) {
-        // Push the current length of the atomic batch to the checkpoint stack.
-        self.checkpoints.lock().push(self.atomic_batch.lock().len());
+    fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        // Push the label and the current length of the atomic batch to the checkpoint stack.
+        self.checkpoints.lock().push((label, self.atomic_batch.lock().len()));
+    }
+
+    ///
+    /// Returns the current checkpoint stack, as a list of (label, pending operations length) pairs.
+    ///
+    fn debug_checkpoint_stack(&self) -> Vec<(Option<&'static str>, usize)> {
+        self.checkpoints.lock().clone()
     }
 
     ///
@@ -228,7 +238,7 @@ impl<
         let mut atomic_batch = self.atomic_batch.lock();
 
         // Retrieve the last checkpoint.
-        let checkpoint = self.checkpoints.lock().pop().unwrap_or(0);
+        let checkpoint = self.checkpoints.lock().pop().map(|(_, checkpoint)| checkpoint).unwrap_or(0);
 
         // Remove all operations after the checkpoint.
         atomic_batch.truncate(checkpoint);
@@ -253,49 +263,59 @@ impl<
     ///
     /// Finishes an atomic operation, performing all the queued writes.
     ///
-    fn finish_atomic(&self) -> Result<()> {
+    fn finish_atomic(&self) -> Result<(), StorageError> {
         // Retrieve the atomic batch belonging to the map.
         let operations = core::mem::take(&mut *self.atomic_batch.lock());
 
         if !operations.is_empty() {
             // Enqueue all the operations from the map in the database-wide batch.
-            let mut atomic_batch = self.database.atomic_batch.lock();
-
-            for (map, key, value) in operations {
-                match (key, value) {
-                    (Some(key), Some(value)) => {
-                        atomic_batch.put(self.create_prefixed_map_key(&map, &key)?, bincode::serialize(&value)?)
-                    }
-                    (Some(key), None) => atomic_batch.delete(self.create_prefixed_map_key(&map, &key)?),
-                    (None, None) => {
-                        // Serialize the map.
-                        let serialized_map = bincode::serialize(&map)?;
-
-                        // Construct an iterator over the DB with the specified prefix.
-                        let iterator = self.database.iterator(rocksdb::IteratorMode::From(
-                            &self.create_prefixed_map(&map)?,
-                            rocksdb::Direction::Forward,
-                        ));
-
-                        // Iterate over the entries in the DB with the specified prefix.
-                        for entry in iterator {
-                            let (map_key, _) = entry?;
-
-                            // Extract the bytes belonging to the map and the key.
-                            let (entry_map, _) = get_map_and_key(&map_key)?;
-
-                            // If the 'entry_map' matches 'serialized_map', delete the key.
-                            if entry_map == serialized_map {
-                                atomic_batch.delete(map_key);
-                            } else {
-                                // If the 'entry_map' no longer matches the 'serialized_map',
-                                // we've moved past the relevant keys and can break the loop.
-                                break;
+            let enqueue_operations = || -> Result<(), StorageError> {
+                let mut atomic_batch = self.database.atomic_batch.lock();
+
+                for (map, key, value) in operations {
+                    match (key, value) {
+                        (Some(key), Some(value)) => atomic_batch.put(
+                            self.create_prefixed_map_key(&map, &key)?,
+                            bincode::serialize(&value)?,
+                        ),
+                        (Some(key), None) => atomic_batch.delete(self.create_prefixed_map_key(&map, &key)?),
+                        (None, None) => {
+                            // Serialize the map.
+                            let serialized_map = bincode::serialize(&map)?;
+
+                            // Construct an iterator over the DB with the specified prefix.
+                            let iterator = self.database.iterator(rocksdb::IteratorMode::From(
+                                &self.create_prefixed_map(&map)?,
+                                rocksdb::Direction::Forward,
+                            ));
+
+                            // Iterate over the entries in the DB with the specified prefix.
+                            for entry in iterator {
+                                let (map_key, _) = entry?;
+
+                                // Extract the bytes belonging to the map and the key.
+                                let (entry_map, _) = get_map_and_key(&map_key)?;
+
+                                // If the 'entry_map' matches 'serialized_map', delete the key.
+                                if entry_map == serialized_map {
+                                    atomic_batch.delete(map_key);
+                                } else {
+                                    // If the 'entry_map' no longer matches the 'serialized_map',
+                                    // we've moved past the relevant keys and can break the loop.
+                                    break;
+                                }
                             }
                         }
+                        (None, Some(_)) => unreachable!("Cannot insert a value without a key"),
                     }
-                    (None, Some(_)) => unreachable!("Cannot insert a value without a key"),
                 }
+                Ok(())
+            };
+
+            if let Err(error) = enqueue_operations() {
+                // Reset the atomic state, so that no atomic batch remains in progress.
+                self.abort_atomic();
+                return Err(error);
             }
         }
 
@@ -317,7 +337,8 @@ impl<
             // Empty the collection of pending operations.
             let batch = mem::take(&mut *self.database.atomic_batch.lock());
             // Execute all the operations atomically.
-            self.database.rocksdb.write(batch)?;
+            // Note: On failure, the atomic state has already been reset at this point.
+            self.database.execute_batch(batch, false)?;
             // Ensure that the database atomic batch is empty.
             assert!(self.database.atomic_batch.lock().is_empty());
         }
@@ -344,7 +365,7 @@ impl<
     ///
     /// Returns `true` if the given map and key exists.
     ///
-    fn contains_key_confirmed(&self, map: &M, key: &K) -> Result<bool> {
+    fn contains_key_confirmed(&self, map: &M, key: &K) -> Result<bool, StorageError> {
         self.get_map_key_raw(map, key).map(|v| v.is_some())
     }
 
@@ -352,7 +373,7 @@ impl<
     /// Returns `true` if the given map and key exists.
     /// This method first checks the atomic batch, and if it does not exist, then checks the map.
     ///
-    fn contains_key_speculative(&self, map: &M, key: &K) -> Result<bool> {
+    fn contains_key_speculative(&self, map: &M, key: &K) -> Result<bool, StorageError> {
         // If a batch is in progress, check the atomic batch first.
         if self.is_atomic_in_progress() {
             // We iterate from the back of the `atomic_batch` to find the latest value.
@@ -380,7 +401,7 @@ impl<
     ///
     /// Returns the key-value pairs for the given map, if it exists.
     ///
-    fn get_map_confirmed(&'a self, map: &M) -> Result<Vec<(K, V)>> {
+    fn get_map_confirmed(&'a self, map: &M) -> Result<Vec<(K, V)>, StorageError> {
         // Serialize the map.
         let serialized_map = bincode::serialize(map)?;
 
@@ -402,9 +423,9 @@ impl<
             // If the 'entry_map' matches 'serialized_map', deserialize the key and value.
             if entry_map == serialized_map {
                 // Deserialize the key.
-                let key = bincode::deserialize(entry_key)?;
+                let key = bincode::deserialize(entry_key).map_err(|error| corruption_error(&map_key, error))?;
                 // Deserialize the value.
-                let value = bincode::deserialize(&value)?;
+                let value = bincode::deserialize(&value).map_err(|error| corruption_error(&map_key, error))?;
                 // Push the key-value pair to the vector.
                 entries.push((key, value));
             } else {
@@ -420,7 +441,7 @@ impl<
     ///
     /// Returns the speculative key-value pairs for the given map, if it exists.
     ///
-    fn get_map_speculative(&'a self, map: &M) -> Result<Vec<(K, V)>> {
+    fn get_map_speculative(&'a self, map: &M) -> Result<Vec<(K, V)>, StorageError> {
         // Retrieve the confirmed key-value pairs for the given map.
         let mut key_values = self.get_map_confirmed(map)?;
 
@@ -462,11 +483,14 @@ impl<
     ///
     /// Returns the value for the given map and key, if it exists.
     ///
-    fn get_value_confirmed(&'a self, map: &M, key: &K) -> Result<Option<Cow<'a, V>>> {
-        match self.get_map_key_raw(map, key) {
-            Ok(Some(bytes)) => Ok(Some(Cow::Owned(bincode::deserialize(&bytes)?))),
-            Ok(None) => Ok(None),
-            Err(e) => Err(e),
+    fn get_value_confirmed(&'a self, map: &M, key: &K) -> Result<Option<Cow<'a, V>>, StorageError> {
+        let raw_map_key = self.create_prefixed_map_key(map, key)?;
+        match self.database.get_pinned_confirmed(&raw_map_key)? {
+            Some(bytes) => match bincode::deserialize(&bytes) {
+                Ok(value) => Ok(Some(Cow::Owned(value))),
+                Err(error) => Err(corruption_error(&raw_map_key, error)),
+            },
+            None => Ok(None),
         }
     }
 
@@ -773,9 +797,9 @@ mod tests {
             self.own_map.is_atomic_in_progress() || self.extra_maps.is_atomic_in_progress()
         }
 
-        fn atomic_checkpoint(&self) {
-            self.own_map.atomic_checkpoint();
-            self.extra_maps.atomic_checkpoint();
+        fn atomic_checkpoint(&self, label: Option<&'static str>) {
+            self.own_map.atomic_checkpoint(label);
+            self.extra_maps.atomic_checkpoint(label);
         }
 
         fn clear_latest_checkpoint(&self) {
@@ -788,6 +812,11 @@ mod tests {
             self.extra_maps.atomic_rewind();
         }
 
+        fn abort_atomic(&self) {
+            self.own_map.abort_atomic();
+            self.extra_maps.abort_atomic();
+        }
+
         fn finish_atomic(&self) -> Result<()> {
             self.own_map.finish_atomic()?;
             self.extra_maps.finish_atomic()
@@ -829,10 +858,10 @@ mod tests {
                 || self.extra_maps.is_atomic_in_progress()
         }
 
-        fn atomic_checkpoint(&self) {
-            self.own_map1.atomic_checkpoint();
-            self.own_map2.atomic_checkpoint();
-            self.extra_maps.atomic_checkpoint();
+        fn atomic_checkpoint(&self, label: Option<&'static str>) {
+            self.own_map1.atomic_checkpoint(label);
+            self.own_map2.atomic_checkpoint(label);
+            self.extra_maps.atomic_checkpoint(label);
         }
 
         fn clear_latest_checkpoint(&self) {
@@ -847,6 +876,12 @@ mod tests {
             self.extra_maps.atomic_rewind();
         }
 
+        fn abort_atomic(&self) {
+            self.own_map1.abort_atomic();
+            self.own_map2.abort_atomic();
+            self.extra_maps.abort_atomic();
+        }
+
         fn finish_atomic(&self) -> Result<()> {
             self.own_map1.finish_atomic()?;
             self.own_map2.finish_atomic()?;
@@ -871,8 +906,8 @@ mod tests {
             self.own_map.is_atomic_in_progress()
         }
 
-        fn atomic_checkpoint(&self) {
-            self.own_map.atomic_checkpoint();
+        fn atomic_checkpoint(&self, label: Option<&'static str>) {
+            self.own_map.atomic_checkpoint(label);
         }
 
         fn clear_latest_checkpoint(&self) {
@@ -883,6 +918,10 @@ mod tests {
             self.own_map.atomic_rewind();
         }
 
+        fn abort_atomic(&self) {
+            self.own_map.abort_atomic();
+        }
+
         fn finish_atomic(&self) -> Result<()> {
             self.own_map.finish_atomic()
         }
@@ -1002,7 +1041,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
+        assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Start an atomic write batch.
         map.start_atomic();
@@ -1017,15 +1056,15 @@ mod tests {
             // The pending batch should contain NUM_ITEMS / 2 items.
             assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoints.lock().last(), None);
+            assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
         }
 
         // Run the same sequence of checks 3 times.
         for _ in 0..3 {
             // Perform a checkpoint.
-            map.atomic_checkpoint();
+            map.atomic_checkpoint(None);
             // Make sure the checkpoint index is NUM_ITEMS / 2.
-            assert_eq!(map.checkpoints.lock().last(), Some(&(NUM_ITEMS / 2)));
+            assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), Some(&(NUM_ITEMS / 2)));
 
             {
                 // Queue (since a batch is in progress) another NUM_ITEMS / 2 insertions.
@@ -1037,13 +1076,13 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS);
                 // Make sure the checkpoint index is NUM_ITEMS / 2.
-                assert_eq!(map.checkpoints.lock().last(), Some(&(NUM_ITEMS / 2)));
+                assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), Some(&(NUM_ITEMS / 2)));
             }
 
             // Abort the current atomic write batch.
             map.atomic_rewind();
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoints.lock().last(), None);
+            assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             {
                 // The map should still contain no items.
@@ -1051,7 +1090,7 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS / 2 items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
                 // Make sure the checkpoint index is None.
-                assert_eq!(map.checkpoints.lock().last(), None);
+                assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
             }
         }
 
@@ -1062,7 +1101,7 @@ mod tests {
         // The pending batch should contain no items.
         assert!(map.iter_pending().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
+        assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
     }
 
     #[test]
@@ -1077,7 +1116,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
+        assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Start a nested atomic batch scope that completes successfully.
         atomic_batch_scope!(map, {
@@ -1090,7 +1129,7 @@ mod tests {
             // The pending batch should contain NUM_ITEMS / 2 items.
             assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoints.lock().last(), None);
+            assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             // Start a nested atomic batch scope that completes successfully.
             atomic_batch_scope!(map, {
@@ -1103,7 +1142,7 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS);
                 // Make sure the checkpoint index is NUM_ITEMS / 2.
-                assert_eq!(map.checkpoints.lock().last(), Some(&(NUM_ITEMS / 2)));
+                assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), Some(&(NUM_ITEMS / 2)));
 
                 Ok(())
             })?;
@@ -1113,7 +1152,7 @@ mod tests {
             // The pending batch should contain NUM_ITEMS items.
             assert_eq!(map.iter_pending().count(), NUM_ITEMS);
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoints.lock().last(), None);
+            assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             Ok(())
         })?;
@@ -1123,7 +1162,7 @@ mod tests {
         // The pending batch should contain no items.
         assert!(map.iter_pending().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
+        assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         Ok(())
     }
@@ -1140,7 +1179,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
+        assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Start an atomic write batch.
         let run_nested_atomic_batch_scope = || -> Result<()> {
@@ -1155,7 +1194,7 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS / 2 items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
                 // Make sure the checkpoint index is None.
-                assert_eq!(map.checkpoints.lock().last(), None);
+                assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
                 // Start a nested atomic write batch that completes correctly.
                 atomic_batch_scope!(map, {
@@ -1168,7 +1207,7 @@ mod tests {
                     // The pending batch should contain NUM_ITEMS items.
                     assert_eq!(map.iter_pending().count(), NUM_ITEMS);
                     // Make sure the checkpoint index is NUM_ITEMS / 2.
-                    assert_eq!(map.checkpoints.lock().last(), Some(&(NUM_ITEMS / 2)));
+                    assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), Some(&(NUM_ITEMS / 2)));
 
                     bail!("This batch should fail.");
                 })?;
@@ -1195,7 +1234,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
+        assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Start an atomic finalize.
         let outcome = atomic_finalize!(map, FinalizeMode::RealRun, {
@@ -1210,7 +1249,7 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS / 2 items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
                 // Make sure the checkpoint index is 0.
-                assert_eq!(map.checkpoints.lock().last(), Some(&0));
+                assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), Some(&0));
 
                 Ok(())
             })
@@ -1221,7 +1260,7 @@ mod tests {
             // The pending batch should contain NUM_ITEMS / 2 items.
             assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoints.lock().last(), None);
+            assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             // Start a nested atomic write batch that completes correctly.
             atomic_batch_scope!(map, {
@@ -1234,7 +1273,7 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS);
                 // Make sure the checkpoint index is NUM_ITEMS / 2.
-                assert_eq!(map.checkpoints.lock().last(), Some(&(NUM_ITEMS / 2)));
+                assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), Some(&(NUM_ITEMS / 2)));
 
                 Ok(())
             })
@@ -1245,7 +1284,7 @@ mod tests {
             // The pending batch should contain NUM_ITEMS items.
             assert_eq!(map.iter_pending().count(), NUM_ITEMS);
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoints.lock().last(), None);
+            assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             Ok(())
         });
@@ -1258,7 +1297,7 @@ mod tests {
         // The pending batch should contain no items.
         assert!(map.iter_pending().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
+        assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         Ok(())
     }
@@ -1275,7 +1314,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
+        assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Start an atomic finalize.
         let outcome = atomic_finalize!(map, FinalizeMode::RealRun, {
@@ -1290,7 +1329,7 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS / 2 items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
                 // Make sure the checkpoint index is 0.
-                assert_eq!(map.checkpoints.lock().last(), Some(&0));
+                assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), Some(&0));
 
                 Ok(())
             })
@@ -1301,7 +1340,7 @@ mod tests {
             // The pending batch should contain NUM_ITEMS / 2 items.
             assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoints.lock().last(), None);
+            assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             // Start a nested atomic write batch that fails.
             let result: Result<()> = atomic_batch_scope!(map, {
@@ -1314,7 +1353,7 @@ mod tests {
                 // The pending batch should contain NUM_ITEMS items.
                 assert_eq!(map.iter_pending().count(), NUM_ITEMS);
                 // Make sure the checkpoint index is NUM_ITEMS / 2.
-                assert_eq!(map.checkpoints.lock().last(), Some(&(NUM_ITEMS / 2)));
+                assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), Some(&(NUM_ITEMS / 2)));
 
                 bail!("This batch scope should fail.");
             });
@@ -1327,7 +1366,7 @@ mod tests {
             // The pending batch should contain NUM_ITEMS / 2 items.
             assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoints.lock().last(), None);
+            assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             Ok(())
         });
@@ -1340,7 +1379,7 @@ mod tests {
         // The pending batch should contain no items.
         assert!(map.iter_pending().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
+        assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         Ok(())
     }
@@ -1354,7 +1393,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
+        assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Construct an atomic batch scope.
         let outcome: Result<()> = atomic_batch_scope!(map, {
@@ -1388,7 +1427,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
+        assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Insert the key.
         map.insert(0, 0, "0".to_string()).unwrap();
@@ -1398,12 +1437,12 @@ mod tests {
             // Insert the key.
             map.insert(0, 0, "1".to_string()).unwrap();
 
-            assert_eq!(map.checkpoints.lock().last(), None);
+            assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             // Create a failing atomic batch scope that will reset the checkpoint.
             let result: Result<()> = atomic_batch_scope!(map, {
                 // Make sure the checkpoint index is 1.
-                assert_eq!(map.checkpoints.lock().last(), Some(&1));
+                assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), Some(&1));
 
                 // Update the key.
                 map.insert(0, 0, "2".to_string()).unwrap();
@@ -1424,7 +1463,7 @@ mod tests {
                 map.iter_confirmed().next().unwrap(),
                 (Cow::Owned(0), Cow::Owned(0), Cow::Owned("0".to_string()))
             );
-            assert_eq!(map.checkpoints.lock().last(), None);
+            assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             Ok(())
         });
@@ -1435,7 +1474,7 @@ mod tests {
         // The pending batch should contain no items.
         assert!(map.iter_pending().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
+        assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Ensure that the map value is correct.
         assert_eq!(map.iter_confirmed().next().unwrap(), (Cow::Owned(0), Cow::Owned(0), Cow::Owned("1".to_string())));
@@ -1450,7 +1489,7 @@ mod tests {
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
+        assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Insert the key.
         map.insert(0, 0, "0".to_string()).unwrap();
@@ -1461,7 +1500,7 @@ mod tests {
             // Simulates an accepted transaction.
             let result: Result<()> = atomic_batch_scope!(map, {
                 // Make sure the checkpoint index is 0.
-                assert_eq!(map.checkpoints.lock().last(), Some(&0));
+                assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), Some(&0));
 
                 // Insert the key.
                 map.insert(0, 0, "1".to_string()).unwrap();
@@ -1470,7 +1509,7 @@ mod tests {
             });
 
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoints.lock().last(), None);
+            assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             // The atomic finalize should have succeeded.
             assert!(result.is_ok());
@@ -1485,12 +1524,12 @@ mod tests {
             // Simulates a rejected transaction.
             let result: Result<()> = atomic_batch_scope!(map, {
                 // Make sure the checkpoint index is 1.
-                assert_eq!(map.checkpoints.lock().last(), Some(&1));
+                assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), Some(&1));
 
                 // Simulate an instruction
                 let result: Result<()> = atomic_batch_scope!(map, {
                     // Make sure the checkpoint index is 1.
-                    assert_eq!(map.checkpoints.lock().last(), Some(&1));
+                    assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), Some(&1));
 
                     // Update the key.
                     map.insert(0, 0, "2".to_string()).unwrap();
@@ -1500,7 +1539,7 @@ mod tests {
                 assert!(result.is_ok());
 
                 // Make sure the checkpoint index is 1.
-                assert_eq!(map.checkpoints.lock().last(), Some(&1));
+                assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), Some(&1));
                 // Ensure that the atomic batch length is 2.
                 assert_eq!(map.atomic_batch.lock().len(), 2);
                 // Ensure that the database atomic batch is empty.
@@ -1511,7 +1550,7 @@ mod tests {
                 // Simulates an instruction that fails.
                 let result: Result<()> = atomic_batch_scope!(map, {
                     // Make sure the checkpoint index is 2.
-                    assert_eq!(map.checkpoints.lock().last(), Some(&2));
+                    assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), Some(&2));
 
                     // Update the key.
                     map.insert(0, 0, "3".to_string()).unwrap();
@@ -1532,7 +1571,7 @@ mod tests {
             // Make sure the pending operations still has the initial insertion.
             assert_eq!(map.get_value_pending(&0, &0), Some(Some("1".to_string())));
             // Make sure the checkpoint index is None.
-            assert_eq!(map.checkpoints.lock().last(), None);
+            assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
             // Ensure that the atomic batch length is 1.
             assert_eq!(map.atomic_batch.lock().len(), 1);
             // Ensure that the database atomic batch is empty.
@@ -1549,7 +1588,7 @@ mod tests {
         // The pending batch should contain no items.
         assert!(map.iter_pending().next().is_none());
         // Make sure the checkpoint index is None.
-        assert_eq!(map.checkpoints.lock().last(), None);
+        assert_eq!(map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
         // Ensure that the atomic batch is empty.
         assert!(map.atomic_batch.lock().is_empty());
         // Ensure that the database atomic batch is empty.
@@ -1576,7 +1615,7 @@ mod tests {
         assert!(test_storage.extra_maps.own_map2.iter_confirmed().next().is_none());
         assert!(test_storage.extra_maps.extra_maps.own_map.iter_confirmed().next().is_none());
 
-        assert_eq!(test_storage.own_map.checkpoints.lock().last(), None);
+        assert_eq!(test_storage.own_map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
         // Note: all the checks going through .database can be performed on any one
         // of the objects, as all of them share the same instance of the database.
@@ -1587,7 +1626,7 @@ mod tests {
         atomic_batch_scope!(test_storage, {
             assert!(test_storage.is_atomic_in_progress_everywhere());
 
-            assert_eq!(test_storage.own_map.checkpoints.lock().last(), None);
+            assert_eq!(test_storage.own_map.checkpoints.lock().last().map(|(_, checkpoint)| checkpoint), None);
 
             // Write an item into the first map.
             test_storage.own_map.insert(0, 0, 0.to_string()).unwrap();
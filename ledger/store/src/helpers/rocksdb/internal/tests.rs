@@ -12,10 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::helpers::{
-    rocksdb::{MapID, RocksDB, TestMap as TestMapID},
-    Map,
-    MapRead,
+use crate::{
+    atomic_batch_scope,
+    helpers::{
+        rocksdb::{classify_error, prefix_successor, MapID, RocksDB, TestMap as TestMapID, INJECTED_FAULT},
+        Map,
+        MapRead,
+        NestedMap,
+        NestedMapRead,
+        StorageError,
+        StorageOptions,
+    },
 };
 use console::{
     network::{Network, Testnet3},
@@ -23,6 +30,7 @@ use console::{
     types::Scalar,
 };
 
+use anyhow::Result;
 use serial_test::serial;
 
 pub(crate) fn temp_dir() -> std::path::PathBuf {
@@ -214,3 +222,236 @@ fn test_iterator_ordering() {
         assert_eq!(&*v1, v2);
     }
 }
+
+#[test]
+fn test_classify_error() {
+    use rocksdb::ErrorKind;
+
+    // Disk-full and read-only conditions are reported as IO errors.
+    let error = classify_error(ErrorKind::IOError, "IO error: No space left on device: While appending".to_string());
+    assert!(error.is_disk_full());
+    let error = classify_error(ErrorKind::IOError, "IO error: Disk quota exceeded".to_string());
+    assert!(error.is_disk_full());
+    let error = classify_error(ErrorKind::IOError, "IO error: Read-only file system: While open".to_string());
+    assert!(matches!(error, StorageError::ReadOnlyFs(_)));
+    let error = classify_error(ErrorKind::IOError, "IO error: Resource temporarily unavailable".to_string());
+    assert!(error.is_transient());
+    let error = classify_error(ErrorKind::IOError, "IO error: No such file or directory".to_string());
+    assert_eq!(error, StorageError::Io("IO error: No such file or directory".to_string()));
+
+    // The remaining conditions are classified by their kind.
+    let error = classify_error(ErrorKind::Corruption, "Corruption: block checksum mismatch".to_string());
+    assert!(error.is_corruption());
+    for kind in [ErrorKind::Busy, ErrorKind::TimedOut, ErrorKind::TryAgain] {
+        assert!(classify_error(kind, "Operation timed out".to_string()).is_transient());
+    }
+    let error = classify_error(ErrorKind::InvalidArgument, "Invalid argument: bad option".to_string());
+    assert_eq!(error, StorageError::Other("Invalid argument: bad option".to_string()));
+}
+
+#[test]
+#[serial]
+fn test_health_check() {
+    let database = RocksDB::open_testing(temp_dir(), None).expect("Failed to open storage");
+
+    // Ensure a healthy database reports its available space.
+    let health = database.health_check();
+    assert!(health.is_healthy());
+    assert!(health.ensure_healthy().is_ok());
+    assert!(health.available_space().is_some());
+
+    // Ensure the health check leaves no trace in the database.
+    assert_eq!(database.iterator(rocksdb::IteratorMode::Start).count(), 0);
+
+    // Ensure a failing write path is reported.
+    INJECTED_FAULT.with(|fault| *fault.borrow_mut() = Some(StorageError::DiskFull("injected".to_string())));
+    let health = database.health_check();
+    assert!(!health.is_healthy());
+    assert!(health.error().unwrap().is_disk_full());
+}
+
+#[test]
+#[serial]
+fn test_failed_finish_atomic_resets_state() {
+    let map =
+        RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMapID::Test)).expect("Failed to open data map");
+
+    // Inject a disk-full failure for the next batch write.
+    INJECTED_FAULT.with(|fault| *fault.borrow_mut() = Some(StorageError::DiskFull("injected".to_string())));
+
+    // Ensure the atomic batch fails with the typed storage error.
+    let result: Result<()> = atomic_batch_scope!(map, {
+        map.insert(1, "1".to_string())?;
+        map.insert(2, "2".to_string())?;
+        Ok(())
+    });
+    let error = result.unwrap_err();
+    assert!(error.downcast_ref::<StorageError>().unwrap().is_disk_full());
+
+    // Ensure the atomic state was reset, and nothing was written.
+    assert!(!map.is_atomic_in_progress());
+    assert_eq!(map.database.atomic_depth.load(std::sync::atomic::Ordering::SeqCst), 0);
+    assert!(map.database.atomic_batch.lock().is_empty());
+    assert!(map.iter_pending().next().is_none());
+    assert!(map.iter_confirmed().next().is_none());
+
+    // Ensure a subsequent atomic batch succeeds.
+    atomic_batch_scope!(map, {
+        map.insert(1, "1".to_string())?;
+        Ok(())
+    })
+    .unwrap();
+    assert_eq!(map.get_confirmed(&1).unwrap().unwrap().as_str(), "1");
+}
+
+#[test]
+#[serial]
+fn test_corrupted_value_reports_map_id() {
+    let map = RocksDB::open_map_testing::<u32, String, _>(temp_dir(), None, MapID::Test(TestMapID::Test))
+        .expect("Failed to open data map");
+
+    // Write bytes that do not deserialize into a `String` directly under the raw key.
+    let mut raw_key = map.context.clone();
+    raw_key.extend_from_slice(&1u32.to_le_bytes());
+    map.database.put(&raw_key, [0xff; 3]).unwrap();
+
+    // Ensure the read fails with a corruption error that points to the map and key.
+    match map.get_confirmed(&1).unwrap_err() {
+        StorageError::Corruption { map: map_id, key_bytes, .. } => {
+            assert_eq!(map_id, Some(u16::from(MapID::Test(TestMapID::Test))));
+            assert_eq!(key_bytes.as_deref(), Some(raw_key.as_slice()));
+        }
+        other => panic!("Expected a corruption error, found {other:?}"),
+    }
+
+    // Ensure a valid entry in the same map is still readable.
+    map.insert(2, "2".to_string()).unwrap();
+    assert_eq!(map.get_confirmed(&2).unwrap().unwrap().as_str(), "2");
+}
+
+#[test]
+#[serial]
+fn test_corrupted_nested_value_reports_map_id() {
+    let map = RocksDB::open_nested_map_testing::<u32, u32, String, _>(temp_dir(), None, MapID::Test(TestMapID::Test2))
+        .expect("Failed to open nested data map");
+
+    // Write bytes that do not deserialize into a `String` directly under the raw map-key.
+    map.insert(1, 1, "1".to_string()).unwrap();
+    let raw_map_key = map.create_prefixed_map_key(&1, &2).unwrap();
+    map.database.put(&raw_map_key, [0xff; 3]).unwrap();
+
+    // Ensure the point read and the map read fail with a corruption error that points to the map and key.
+    for error in [map.get_value_confirmed(&1, &2).unwrap_err(), map.get_map_confirmed(&1).unwrap_err()] {
+        match error {
+            StorageError::Corruption { map: map_id, key_bytes, .. } => {
+                assert_eq!(map_id, Some(u16::from(MapID::Test(TestMapID::Test2))));
+                assert_eq!(key_bytes.as_deref(), Some(raw_map_key.as_slice()));
+            }
+            other => panic!("Expected a corruption error, found {other:?}"),
+        }
+    }
+
+    // Ensure a valid entry in the same map is still readable.
+    assert_eq!(map.get_value_confirmed(&1, &1).unwrap().unwrap().as_str(), "1");
+}
+
+#[test]
+#[serial]
+fn test_read_at_snapshot() {
+    let map = RocksDB::open_map_testing::<u32, String, _>(temp_dir(), None, MapID::Test(TestMapID::Test))
+        .expect("Failed to open data map");
+    map.insert(1, "1".to_string()).unwrap();
+
+    map.read_at_snapshot(|| {
+        // Overwrite the value after the snapshot was taken.
+        map.insert(1, "2".to_string()).unwrap();
+        // Ensure the confirmed read is served from the snapshot.
+        assert_eq!(map.get_confirmed(&1).unwrap().unwrap().as_str(), "1");
+        // Ensure a nested snapshot read continues at the same snapshot.
+        map.read_at_snapshot(|| assert_eq!(map.get_confirmed(&1).unwrap().unwrap().as_str(), "1"));
+    });
+
+    // Ensure the confirmed read observes the new value once the snapshot is released.
+    assert_eq!(map.get_confirmed(&1).unwrap().unwrap().as_str(), "2");
+}
+
+#[test]
+#[serial]
+fn test_open_with_options() {
+    let options = StorageOptions {
+        write_buffer_size: Some(1 << 20),
+        max_background_jobs: 2,
+        target_file_size: Some(1 << 21),
+        zstd_compression: cfg!(feature = "rocks-zstd"),
+    };
+    let database =
+        RocksDB::open_testing_with_options(temp_dir(), None, options.clone()).expect("Failed to open storage");
+    assert_eq!(database.options(), &options);
+
+    // Ensure the zstd compression option is rejected, unless the 'rocks-zstd' feature is enabled.
+    let zstd_options = StorageOptions { zstd_compression: true, ..Default::default() };
+    let result = RocksDB::open_testing_with_options(temp_dir(), None, zstd_options);
+    assert_eq!(result.is_ok(), cfg!(feature = "rocks-zstd"));
+
+    // Ensure the default options are used otherwise.
+    let database = RocksDB::open_testing(temp_dir(), None).expect("Failed to open storage");
+    assert_eq!(database.options(), &StorageOptions::default());
+}
+
+#[test]
+fn test_prefix_successor() {
+    assert_eq!(prefix_successor(&[1, 2, 3, 4]), Some(vec![1, 2, 3, 5]));
+    assert_eq!(prefix_successor(&[1, 2, 3, 255]), Some(vec![1, 2, 4]));
+    assert_eq!(prefix_successor(&[1, 255, 255, 255]), Some(vec![2]));
+    assert_eq!(prefix_successor(&[255, 255, 255, 255]), None);
+}
+
+#[test]
+#[serial]
+fn test_compact_map() {
+    const NUM_KEYS: u32 = 100_000;
+
+    let map_id = MapID::Test(TestMapID::Test);
+    let map = RocksDB::open_map_testing(temp_dir(), None, map_id).expect("Failed to open data map");
+    let value = |key: u32| key.to_string().repeat(8);
+
+    // Insert a large number of keys.
+    atomic_batch_scope!(map, {
+        for key in 0..NUM_KEYS {
+            map.insert(key, value(key))?;
+        }
+        Ok(())
+    })
+    .unwrap();
+    map.database.flush().unwrap();
+    let size_before = map.database.approximate_size().unwrap();
+    assert!(size_before > 0);
+
+    // Remove most of the keys.
+    atomic_batch_scope!(map, {
+        for key in (0..NUM_KEYS).filter(|key| key % 100 != 0) {
+            map.remove(&key)?;
+        }
+        Ok(())
+    })
+    .unwrap();
+    map.database.flush().unwrap();
+
+    // Compact the map.
+    map.database.compact_map(map_id).unwrap();
+
+    // Ensure the reads still succeed.
+    for key in 0..NUM_KEYS {
+        match key % 100 {
+            0 => assert_eq!(map.get_confirmed(&key).unwrap().unwrap().as_str(), value(key)),
+            _ => assert!(map.get_confirmed(&key).unwrap().is_none()),
+        }
+    }
+
+    // Ensure the approximate size shrank.
+    assert!(map.database.approximate_size().unwrap() < size_before);
+
+    // Ensure compacting the entire database succeeds.
+    map.database.compact_all().unwrap();
+    assert_eq!(map.get_confirmed(&0).unwrap().unwrap().as_str(), value(0));
+}
@@ -19,10 +19,12 @@ use crate::{
     CommitteeStorage,
     CommitteeStore,
     FinalizeStorage,
+    FinalizeUndo,
 };
 use console::{
     prelude::*,
     program::{Identifier, Plaintext, ProgramID, Value},
+    types::Field,
 };
 use ledger_committee::Committee;
 
@@ -37,6 +39,14 @@ pub struct FinalizeDB<N: Network> {
     program_id_map: DataMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The key-value map.
     key_value_map: NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
+    /// The key history map.
+    key_history_map: NestedDataMap<Field<N>, u32, Option<Value<N>>>,
+    /// The block history map.
+    block_history_map: DataMap<u32, Vec<Field<N>>>,
+    /// The undo log map.
+    undo_log_map: DataMap<u32, Vec<FinalizeUndo<N>>>,
+    /// The history height map.
+    history_height_map: DataMap<u8, u32>,
     /// The optional development ID.
     dev: Option<u16>,
 }
@@ -46,6 +56,10 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
     type CommitteeStorage = CommitteeDB<N>;
     type ProgramIDMap = DataMap<ProgramID<N>, IndexSet<Identifier<N>>>;
     type KeyValueMap = NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    type KeyHistoryMap = NestedDataMap<Field<N>, u32, Option<Value<N>>>;
+    type BlockHistoryMap = DataMap<u32, Vec<Field<N>>>;
+    type UndoLogMap = DataMap<u32, Vec<FinalizeUndo<N>>>;
+    type HistoryHeightMap = DataMap<u8, u32>;
 
     /// Initializes the finalize storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -56,6 +70,10 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
             committee_store,
             program_id_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::ProgramID))?,
             key_value_map: rocksdb::RocksDB::open_nested_map(N::ID, dev, MapID::Program(ProgramMap::KeyValueID))?,
+            key_history_map: rocksdb::RocksDB::open_nested_map(N::ID, dev, MapID::Program(ProgramMap::KeyHistoryID))?,
+            block_history_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::BlockHistoryID))?,
+            undo_log_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::UndoLogID))?,
+            history_height_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::HistoryHeightID))?,
             dev,
         })
     }
@@ -70,6 +88,10 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
             committee_store,
             program_id_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::ProgramID))?,
             key_value_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::KeyValueID))?,
+            key_history_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::KeyHistoryID))?,
+            block_history_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::BlockHistoryID))?,
+            undo_log_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::UndoLogID))?,
+            history_height_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::HistoryHeightID))?,
             dev,
         })
     }
@@ -89,6 +111,26 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
         &self.key_value_map
     }
 
+    /// Returns the key history map.
+    fn key_history_map(&self) -> &Self::KeyHistoryMap {
+        &self.key_history_map
+    }
+
+    /// Returns the block history map.
+    fn block_history_map(&self) -> &Self::BlockHistoryMap {
+        &self.block_history_map
+    }
+
+    /// Returns the undo log map.
+    fn undo_log_map(&self) -> &Self::UndoLogMap {
+        &self.undo_log_map
+    }
+
+    /// Returns the history height map.
+    fn history_height_map(&self) -> &Self::HistoryHeightMap {
+        &self.history_height_map
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
         self.dev
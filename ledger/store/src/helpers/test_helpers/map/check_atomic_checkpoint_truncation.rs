@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{atomic_batch_scope, helpers::Map};
+
+use anyhow::{bail, Result};
+
+pub fn check_atomic_checkpoint_truncation(map: impl for<'a> Map<'a, usize, String>) {
+    // Sanity check.
+    assert!(map.iter_confirmed().next().is_none());
+    // Make sure the checkpoint index is None.
+    assert_eq!(map.debug_checkpoint_stack().last().map(|(_, checkpoint)| *checkpoint), None);
+
+    // Insert the key.
+    map.insert(0, "0".to_string()).unwrap();
+
+    // Start an atomic finalize.
+    let outcome = atomic_batch_scope!(map, {
+        // Insert the key.
+        map.insert(0, "1".to_string()).unwrap();
+
+        assert_eq!(map.debug_checkpoint_stack().last().map(|(_, checkpoint)| *checkpoint), None);
+
+        // Create a failing atomic batch scope that will reset the checkpoint.
+        let result: Result<()> = atomic_batch_scope!(map, {
+            // Make sure the checkpoint index is 1.
+            assert_eq!(map.debug_checkpoint_stack().last().map(|(_, checkpoint)| *checkpoint), Some(1));
+
+            // Update the key.
+            map.insert(0, "2".to_string()).unwrap();
+
+            bail!("This batch scope should fail.")
+        });
+
+        // Ensure that the batch scope failed.
+        assert!(result.is_err());
+        // The map should contain 1 item.
+        assert_eq!(map.iter_confirmed().count(), 1);
+        // The pending batch should contain 1 item.
+        assert_eq!(map.iter_pending().count(), 1);
+        // Ensure the pending operations still has the initial insertion.
+        assert_eq!(map.get_pending(&0), Some(Some("1".to_string())));
+        // Ensure the confirmed value has not changed.
+        assert_eq!(*map.iter_confirmed().next().unwrap().1, "0");
+        assert_eq!(map.debug_checkpoint_stack().last().map(|(_, checkpoint)| *checkpoint), None);
+
+        Ok(())
+    });
+
+    assert!(outcome.is_ok());
+    // The map should contain 1 item.
+    assert_eq!(map.iter_confirmed().count(), 1);
+    // The pending batch should contain no items.
+    assert!(map.iter_pending().next().is_none());
+    // Make sure the checkpoint index is None.
+    assert_eq!(map.debug_checkpoint_stack().last().map(|(_, checkpoint)| *checkpoint), None);
+
+    // Ensure that the map value is correct.
+    assert_eq!(*map.iter_confirmed().next().unwrap().1, "1");
+}
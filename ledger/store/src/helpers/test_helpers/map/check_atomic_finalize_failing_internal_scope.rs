@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{atomic_batch_scope, atomic_finalize, helpers::Map, FinalizeMode};
+
+use anyhow::{anyhow, bail, Result};
+
+pub fn check_atomic_finalize_failing_internal_scope(map: impl for<'a> Map<'a, usize, String>) -> Result<()> {
+    // The number of items that will be queued to be inserted into the map.
+    const NUM_ITEMS: usize = 10;
+
+    // Sanity check.
+    assert!(map.iter_confirmed().next().is_none());
+    // Make sure the checkpoint index is None.
+    assert_eq!(map.debug_checkpoint_stack().last().map(|(_, checkpoint)| *checkpoint), None);
+
+    // Start an atomic finalize.
+    let outcome = atomic_finalize!(map, FinalizeMode::RealRun, {
+        // Start a nested atomic batch scope that completes successfully.
+        atomic_batch_scope!(map, {
+            // Queue (since a batch is in progress) NUM_ITEMS / 2 insertions.
+            for i in 0..NUM_ITEMS / 2 {
+                map.insert(i, i.to_string()).unwrap();
+            }
+            // The map should still contain no items.
+            assert!(map.iter_confirmed().next().is_none());
+            // The pending batch should contain NUM_ITEMS / 2 items.
+            assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
+            // Make sure the checkpoint index is 0.
+            assert_eq!(map.debug_checkpoint_stack().last().map(|(_, checkpoint)| *checkpoint), Some(0));
+
+            Ok(())
+        })
+        .unwrap();
+
+        // The map should still contain no items.
+        assert!(map.iter_confirmed().next().is_none());
+        // The pending batch should contain NUM_ITEMS / 2 items.
+        assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
+        // Make sure the checkpoint index is None.
+        assert_eq!(map.debug_checkpoint_stack().last().map(|(_, checkpoint)| *checkpoint), None);
+
+        // Start a nested atomic write batch that fails.
+        let result: Result<()> = atomic_batch_scope!(map, {
+            // Queue (since a batch is in progress) another NUM_ITEMS / 2 insertions.
+            for i in (NUM_ITEMS / 2)..NUM_ITEMS {
+                map.insert(i, i.to_string()).unwrap();
+            }
+            // The map should still contain no items.
+            assert!(map.iter_confirmed().next().is_none());
+            // The pending batch should contain NUM_ITEMS items.
+            assert_eq!(map.iter_pending().count(), NUM_ITEMS);
+            // Make sure the checkpoint index is NUM_ITEMS / 2.
+            assert_eq!(map.debug_checkpoint_stack().last().map(|(_, checkpoint)| *checkpoint), Some(NUM_ITEMS / 2));
+
+            bail!("This batch scope should fail.");
+        });
+
+        // Ensure that the batch scope failed.
+        assert!(result.is_err());
+
+        // The map should still contain no items.
+        assert!(map.iter_confirmed().next().is_none());
+        // The pending batch should contain NUM_ITEMS / 2 items.
+        assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
+        // Make sure the checkpoint index is None.
+        assert_eq!(map.debug_checkpoint_stack().last().map(|(_, checkpoint)| *checkpoint), None);
+
+        Ok(())
+    });
+
+    // The atomic finalize should have succeeded.
+    assert!(outcome.is_ok());
+
+    // The map should contain NUM_ITEMS / 2.
+    assert_eq!(map.iter_confirmed().count(), NUM_ITEMS / 2);
+    // The pending batch should contain no items.
+    assert!(map.iter_pending().next().is_none());
+    // Make sure the checkpoint index is None.
+    assert_eq!(map.debug_checkpoint_stack().last().map(|(_, checkpoint)| *checkpoint), None);
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{atomic_batch_scope, atomic_finalize, helpers::Map, FinalizeMode};
+
+use anyhow::{anyhow, bail, Result};
+
+pub fn check_atomic_finalize_fails_to_start(map: impl for<'a> Map<'a, usize, String>) {
+    // Sanity check.
+    assert!(map.iter_confirmed().next().is_none());
+    // Make sure the checkpoint index is None.
+    assert_eq!(map.debug_checkpoint_stack().last().map(|(_, checkpoint)| *checkpoint), None);
+
+    // Construct an atomic batch scope.
+    let outcome: Result<()> = atomic_batch_scope!(map, {
+        // Start an atomic finalize.
+        let outcome = atomic_finalize!(map, FinalizeMode::RealRun, { Ok(()) });
+        // Ensure that the atomic finalize fails.
+        assert!(outcome.is_err());
+
+        unreachable!("The batch scope should fail before we reach this point.");
+    });
+
+    // Ensure that the atomic batch scope fails.
+    assert!(outcome.is_err());
+
+    // Start an atomic operation.
+    map.start_atomic();
+
+    // We need to catch the `atomic_finalize` here, otherwise it will end the test early.
+    let outcome = || atomic_finalize!(map, FinalizeMode::RealRun, { Ok(()) });
+
+    // Ensure that the atomic finalize fails if an atomic batch is in progress.
+    assert!(outcome().is_err());
+}
@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    atomic_batch_scope_outcome,
+    helpers::{AtomicScopeOutcome, Map},
+};
+
+use anyhow::Result;
+
+pub fn check_atomic_scope_outcome_nested_abort(map: impl for<'a> Map<'a, usize, String>) -> Result<()> {
+    // The number of items that will be queued to be inserted into the map.
+    const NUM_ITEMS: usize = 10;
+
+    // Sanity check.
+    assert!(map.iter_confirmed().next().is_none());
+    // Make sure the checkpoint index is None.
+    assert_eq!(map.debug_checkpoint_stack().last().map(|(_, checkpoint)| *checkpoint), None);
+
+    // Start an atomic batch scope that commits.
+    let outcome = atomic_batch_scope_outcome!(map, {
+        // Queue (since a batch is in progress) NUM_ITEMS / 2 insertions.
+        for i in 0..NUM_ITEMS / 2 {
+            map.insert(i, i.to_string()).unwrap();
+        }
+        // The pending batch should contain NUM_ITEMS / 2 items.
+        assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
+        // Make sure the checkpoint index is None.
+        assert_eq!(map.debug_checkpoint_stack().last().map(|(_, checkpoint)| *checkpoint), None);
+
+        // Start a nested atomic batch scope that discards its writes.
+        let nested = atomic_batch_scope_outcome!(map, {
+            // Queue (since a batch is in progress) another NUM_ITEMS / 2 insertions.
+            for i in (NUM_ITEMS / 2)..NUM_ITEMS {
+                map.insert(i, i.to_string()).unwrap();
+            }
+            // The pending batch should contain NUM_ITEMS items.
+            assert_eq!(map.iter_pending().count(), NUM_ITEMS);
+            // Make sure the checkpoint index is NUM_ITEMS / 2.
+            assert_eq!(map.debug_checkpoint_stack().last().map(|(_, checkpoint)| *checkpoint), Some(NUM_ITEMS / 2));
+
+            Ok(AtomicScopeOutcome::Abort("nested"))
+        })?;
+
+        // Ensure the nested value is still returned.
+        assert_eq!(nested, AtomicScopeOutcome::Abort("nested"));
+
+        // The map should still contain no items.
+        assert!(map.iter_confirmed().next().is_none());
+        // The pending batch should be rewound to NUM_ITEMS / 2 items.
+        assert_eq!(map.iter_pending().count(), NUM_ITEMS / 2);
+        // Make sure the checkpoint index is None.
+        assert_eq!(map.debug_checkpoint_stack().last().map(|(_, checkpoint)| *checkpoint), None);
+
+        Ok(AtomicScopeOutcome::Commit("outer"))
+    })?;
+
+    // Ensure the outer value is returned.
+    assert_eq!(outcome, AtomicScopeOutcome::Commit("outer"));
+
+    // The map should contain NUM_ITEMS / 2.
+    assert_eq!(map.iter_confirmed().count(), NUM_ITEMS / 2);
+    // The pending batch should contain no items.
+    assert!(map.iter_pending().next().is_none());
+    // Make sure the checkpoint index is None.
+    assert_eq!(map.debug_checkpoint_stack().last().map(|(_, checkpoint)| *checkpoint), None);
+
+    Ok(())
+}
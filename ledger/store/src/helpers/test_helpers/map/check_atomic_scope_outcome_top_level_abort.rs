@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::{AtomicScopeOutcome, Map};
+
+use anyhow::Result;
+
+pub fn check_atomic_scope_outcome_top_level_abort(map: impl for<'a> Map<'a, usize, String>) {
+    // The number of items that will be queued to be inserted into the map.
+    const NUM_ITEMS: usize = 10;
+
+    // Sanity check.
+    assert!(map.iter_confirmed().next().is_none());
+    // Make sure the checkpoint index is None.
+    assert_eq!(map.debug_checkpoint_stack().last().map(|(_, checkpoint)| *checkpoint), None);
+
+    // Start a top-level atomic scope that discards its writes.
+    let outcome = map.with_atomic_scope(|| {
+        // Queue (since a batch is in progress) NUM_ITEMS insertions.
+        for i in 0..NUM_ITEMS {
+            map.insert(i, i.to_string()).unwrap();
+        }
+        // The pending batch should contain NUM_ITEMS items.
+        assert_eq!(map.iter_pending().count(), NUM_ITEMS);
+
+        Ok(AtomicScopeOutcome::Abort(NUM_ITEMS))
+    });
+
+    // Ensure the value is still returned.
+    assert_eq!(outcome.unwrap(), AtomicScopeOutcome::Abort(NUM_ITEMS));
+
+    // The map should contain no items.
+    assert!(map.iter_confirmed().next().is_none());
+    // The pending batch should contain no items.
+    assert!(map.iter_pending().next().is_none());
+    // Ensure the atomic batch is no longer in progress.
+    assert!(!map.is_atomic_in_progress());
+    // Make sure the checkpoint index is None.
+    assert_eq!(map.debug_checkpoint_stack().last().map(|(_, checkpoint)| *checkpoint), None);
+
+    // Ensure a subsequent committed scope is written.
+    let outcome: Result<_> = map.with_atomic_scope(|| {
+        map.insert(0, "0".to_string())?;
+        Ok(AtomicScopeOutcome::Commit(()))
+    });
+    assert_eq!(outcome.unwrap(), AtomicScopeOutcome::Commit(()));
+    assert_eq!(map.iter_confirmed().count(), 1);
+}
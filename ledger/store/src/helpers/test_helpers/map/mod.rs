@@ -12,6 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod check_atomic_checkpoint_truncation;
+pub use check_atomic_checkpoint_truncation::*;
+
+mod check_atomic_finalize_fails_to_start;
+pub use check_atomic_finalize_fails_to_start::*;
+
+mod check_atomic_finalize_failing_internal_scope;
+pub use check_atomic_finalize_failing_internal_scope::*;
+
+mod check_atomic_finalize_with_aborted_scope;
+pub use check_atomic_finalize_with_aborted_scope::*;
+
+mod check_atomic_scope_outcome_nested_abort;
+pub use check_atomic_scope_outcome_nested_abort::*;
+
+mod check_atomic_scope_outcome_top_level_abort;
+pub use check_atomic_scope_outcome_top_level_abort::*;
+
 mod check_atomic_writes_are_batched;
 pub use check_atomic_writes_are_batched::*;
 
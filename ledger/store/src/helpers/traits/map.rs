@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::helpers::StorageError;
 use console::network::prelude::{Deserialize, Result, Serialize};
 
 use core::{borrow::Borrow, hash::Hash};
@@ -27,12 +28,12 @@ pub trait Map<
     ///
     /// Inserts the given key-value pair into the map.
     ///
-    fn insert(&self, key: K, value: V) -> Result<()>;
+    fn insert(&self, key: K, value: V) -> Result<(), StorageError>;
 
     ///
     /// Removes the key-value pair for the given key from the map.
     ///
-    fn remove(&self, key: &K) -> Result<()>;
+    fn remove(&self, key: &K) -> Result<(), StorageError>;
 
     ///
     /// Begins an atomic operation. Any further calls to `insert` and `remove` will be queued
@@ -50,8 +51,14 @@ pub trait Map<
     ///
     /// Saves the current list of pending operations, so that if `atomic_rewind` is called,
     /// we roll back all future operations, and return to the start of this checkpoint.
+    /// The optional label identifies the checkpoint when debugging rewinds.
     ///
-    fn atomic_checkpoint(&self);
+    fn atomic_checkpoint(&self, label: Option<&'static str>);
+
+    ///
+    /// Returns the current checkpoint stack, as a list of (label, pending operations length) pairs.
+    ///
+    fn debug_checkpoint_stack(&self) -> Vec<(Option<&'static str>, usize)>;
 
     ///
     /// Removes the latest atomic checkpoint.
@@ -72,10 +79,47 @@ pub trait Map<
     ///
     /// Finishes an atomic operation, performing all the queued writes.
     ///
-    fn finish_atomic(&self) -> Result<()>;
+    fn finish_atomic(&self) -> Result<(), StorageError>;
+
+    ///
+    /// Runs the given closure in an atomic batch scope (see `atomic_batch_scope_outcome`).
+    /// If the closure returns `AtomicScopeOutcome::Abort`, its writes are discarded.
+    ///
+    fn with_atomic_scope<T>(&self, f: impl FnOnce() -> Result<AtomicScopeOutcome<T>>) -> Result<AtomicScopeOutcome<T>>
+    where
+        Self: Sized,
+    {
+        crate::atomic_batch_scope_outcome!(self, { f() })
+    }
+}
+
+/// The outcome of an atomic batch scope that may discard its writes without failing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AtomicScopeOutcome<T> {
+    /// The writes of the scope are kept.
+    Commit(T),
+    /// The writes of the scope are discarded, but the value is still returned.
+    Abort(T),
+}
+
+impl<T> AtomicScopeOutcome<T> {
+    /// Returns `true` if the writes of the scope were discarded.
+    pub const fn is_abort(&self) -> bool {
+        matches!(self, Self::Abort(_))
+    }
+
+    /// Returns the value produced by the scope.
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Commit(value) | Self::Abort(value) => value,
+        }
+    }
 }
 
 /// A trait representing map-like storage operations with read-only capabilities.
+///
+/// Note: The iterators do not return a `StorageError`. If an entry fails to be read or deserialized,
+/// the error is logged and the iteration ends; use the getters to read individual entries with typed errors.
 pub trait MapRead<
     'a,
     K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + Deserialize<'a> + Sync,
@@ -90,7 +134,7 @@ pub trait MapRead<
     ///
     /// Returns `true` if the given key exists in the map.
     ///
-    fn contains_key_confirmed<Q>(&self, key: &Q) -> Result<bool>
+    fn contains_key_confirmed<Q>(&self, key: &Q) -> Result<bool, StorageError>
     where
         K: Borrow<Q>,
         Q: PartialEq + Eq + Hash + Serialize + ?Sized;
@@ -99,7 +143,7 @@ pub trait MapRead<
     /// Returns `true` if the given key exists in the map.
     /// This method first checks the atomic batch, and if it does not exist, then checks the map.
     ///
-    fn contains_key_speculative<Q>(&self, key: &Q) -> Result<bool>
+    fn contains_key_speculative<Q>(&self, key: &Q) -> Result<bool, StorageError>
     where
         K: Borrow<Q>,
         Q: PartialEq + Eq + Hash + Serialize + ?Sized;
@@ -107,7 +151,7 @@ pub trait MapRead<
     ///
     /// Returns the value for the given key from the map, if it exists.
     ///
-    fn get_confirmed<Q>(&'a self, key: &Q) -> Result<Option<Cow<'a, V>>>
+    fn get_confirmed<Q>(&'a self, key: &Q) -> Result<Option<Cow<'a, V>>, StorageError>
     where
         K: Borrow<Q>,
         Q: PartialEq + Eq + Hash + Serialize + ?Sized;
@@ -129,7 +173,7 @@ pub trait MapRead<
     /// Returns the value for the given key from the atomic batch first, if it exists,
     /// or return from the map, otherwise.
     ///
-    fn get_speculative<Q>(&'a self, key: &Q) -> Result<Option<Cow<'a, V>>>
+    fn get_speculative<Q>(&'a self, key: &Q) -> Result<Option<Cow<'a, V>>, StorageError>
     where
         K: Borrow<Q>,
         Q: PartialEq + Eq + Hash + Serialize + ?Sized,
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::helpers::StorageError;
 use console::network::prelude::{Deserialize, Result, Serialize};
 
 use core::hash::Hash;
@@ -28,17 +29,17 @@ pub trait NestedMap<
     ///
     /// Inserts the given key-value pair.
     ///
-    fn insert(&self, map: M, key: K, value: V) -> Result<()>;
+    fn insert(&self, map: M, key: K, value: V) -> Result<(), StorageError>;
 
     ///
     /// Removes the given map.
     ///
-    fn remove_map(&self, map: &M) -> Result<()>;
+    fn remove_map(&self, map: &M) -> Result<(), StorageError>;
 
     ///
     /// Removes the key-value pair for the given map and key.
     ///
-    fn remove_key(&self, map: &M, key: &K) -> Result<()>;
+    fn remove_key(&self, map: &M, key: &K) -> Result<(), StorageError>;
 
     ///
     /// Begins an atomic operation. Any further calls to `insert` and `remove` will be queued
@@ -56,8 +57,14 @@ pub trait NestedMap<
     ///
     /// Saves the current list of pending operations, so that if `atomic_rewind` is called,
     /// we roll back all future operations, and return to the start of this checkpoint.
+    /// The optional label identifies the checkpoint when debugging rewinds.
     ///
-    fn atomic_checkpoint(&self);
+    fn atomic_checkpoint(&self, label: Option<&'static str>);
+
+    ///
+    /// Returns the current checkpoint stack, as a list of (label, pending operations length) pairs.
+    ///
+    fn debug_checkpoint_stack(&self) -> Vec<(Option<&'static str>, usize)>;
 
     ///
     /// Removes the latest atomic checkpoint.
@@ -78,10 +85,13 @@ pub trait NestedMap<
     ///
     /// Finishes an atomic operation, performing all the queued writes.
     ///
-    fn finish_atomic(&self) -> Result<()>;
+    fn finish_atomic(&self) -> Result<(), StorageError>;
 }
 
 /// A trait representing 'nested map'-like storage operations with read-only capabilities.
+///
+/// Note: The iterators do not return a `StorageError`. If an entry fails to be read or deserialized,
+/// the error is logged and the iteration ends; use the getters to read individual entries with typed errors.
 pub trait NestedMapRead<
     'a,
     M: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + Deserialize<'a> + Sync,
@@ -97,28 +107,28 @@ pub trait NestedMapRead<
     ///
     /// Returns `true` if the given key exists in the map.
     ///
-    fn contains_key_confirmed(&self, map: &M, key: &K) -> Result<bool>;
+    fn contains_key_confirmed(&self, map: &M, key: &K) -> Result<bool, StorageError>;
 
     ///
     /// Returns `true` if the given key exists in the map.
     /// This method first checks the atomic batch, and if it does not exist, then checks the confirmed.
     ///
-    fn contains_key_speculative(&self, map: &M, key: &K) -> Result<bool>;
+    fn contains_key_speculative(&self, map: &M, key: &K) -> Result<bool, StorageError>;
 
     ///
     /// Returns the confirmed key-value pairs for the given map, if it exists.
     ///
-    fn get_map_confirmed(&'a self, map: &M) -> Result<Vec<(K, V)>>;
+    fn get_map_confirmed(&'a self, map: &M) -> Result<Vec<(K, V)>, StorageError>;
 
     ///
     /// Returns the speculative key-value pairs for the given map, if it exists.
     ///
-    fn get_map_speculative(&'a self, map: &M) -> Result<Vec<(K, V)>>;
+    fn get_map_speculative(&'a self, map: &M) -> Result<Vec<(K, V)>, StorageError>;
 
     ///
     /// Returns the value for the given key from the map, if it exists.
     ///
-    fn get_value_confirmed(&'a self, map: &M, key: &K) -> Result<Option<Cow<'a, V>>>;
+    fn get_value_confirmed(&'a self, map: &M, key: &K) -> Result<Option<Cow<'a, V>>, StorageError>;
 
     ///
     /// Returns the current value for the given key if it is scheduled
@@ -134,7 +144,7 @@ pub trait NestedMapRead<
     /// Returns the value for the given key from the atomic batch first, if it exists,
     /// or return from the map, otherwise.
     ///
-    fn get_value_speculative(&'a self, map: &M, key: &K) -> Result<Option<Cow<'a, V>>> {
+    fn get_value_speculative(&'a self, map: &M, key: &K) -> Result<Option<Cow<'a, V>>, StorageError> {
         // Return the atomic batch value, if it exists, or the map value, otherwise.
         match self.get_value_pending(map, key) {
             Some(Some(value)) => Ok(Some(Cow::Owned(value))),
@@ -67,10 +67,10 @@ pub trait CommitteeStorage<N: Network>: 'static + Clone + Send + Sync {
     }
 
     /// Checkpoints the atomic batch.
-    fn atomic_checkpoint(&self) {
-        self.current_round_map().atomic_checkpoint();
-        self.round_to_height_map().atomic_checkpoint();
-        self.committee_map().atomic_checkpoint();
+    fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        self.current_round_map().atomic_checkpoint(label);
+        self.round_to_height_map().atomic_checkpoint(label);
+        self.committee_map().atomic_checkpoint(label);
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -98,7 +98,7 @@ pub trait CommitteeStorage<N: Network>: 'static + Clone + Send + Sync {
     fn finish_atomic(&self) -> Result<()> {
         self.current_round_map().finish_atomic()?;
         self.round_to_height_map().finish_atomic()?;
-        self.committee_map().finish_atomic()
+        Ok(self.committee_map().finish_atomic()?)
     }
 
     /// Stores the given `(next height, committee)` pair into storage,
@@ -159,15 +159,49 @@ pub trait CommitteeStorage<N: Network>: 'static + Clone + Send + Sync {
         })
     }
 
+    /// Stores the given committee as the committee at the given checkpoint `height`.
+    /// The committee storage must be empty, as the committees before the checkpoint are not stored.
+    fn insert_checkpoint(&self, height: u32, committee: Committee<N>) -> Result<()> {
+        // Ensure the committee storage is empty.
+        ensure!(self.current_round().is_err(), "Committee storage must be empty to insert a checkpoint");
+        // Retrieve the round.
+        let round = committee.starting_round();
+        // Ensure the round is at least the height.
+        ensure!(round >= height as u64, "Checkpoint round must be at least the checkpoint height");
+
+        // Start an atomic batch.
+        atomic_batch_scope!(self, {
+            // Store the round.
+            self.current_round_map().insert(ROUND_KEY, round)?;
+            // Store the round's height.
+            self.round_to_height_map().insert(round, height)?;
+            // Store the committee.
+            self.committee_map().insert(height, committee)?;
+            Ok(())
+        })
+    }
+
     /// Removes the committee for the given `height`, in the process
     /// removing all round to height entries back to the previous committee.
+    ///
+    /// Note: The storage is read speculatively, so that several committees can be removed in one atomic batch.
     fn remove(&self, height: u32) -> Result<()> {
+        // Returns the height for the given round, including the pending writes.
+        let get_height_for_round = |round: u64| -> Result<Option<u32>> {
+            Ok(self.round_to_height_map().get_speculative(&round)?.map(|height| cow_to_copied!(height)))
+        };
+
         // Retrieve the current round.
-        let current_round = self.current_round()?;
+        let Some(current_round) = self.current_round_map().get_speculative(&ROUND_KEY)? else {
+            bail!("Current round not found in committee storage");
+        };
+        let current_round = cow_to_copied!(current_round);
         // Retrieve the current height.
-        let current_height = self.current_height()?;
+        let Some(current_height) = get_height_for_round(current_round)? else {
+            bail!("Current height not found in committee storage");
+        };
         // Retrieve the committee for the given height.
-        let Some(committee) = self.get_committee(height)? else {
+        let Some(committee) = self.committee_map().get_speculative(&height)? else {
             bail!("Committee not found for height {height} in committee storage");
         };
         // Retrieve the round for the given height.
@@ -179,7 +213,7 @@ pub trait CommitteeStorage<N: Network>: 'static + Clone + Send + Sync {
 
         // Find the earliest round to be removed (inclusive).
         let mut earliest_round = committee_round;
-        while earliest_round > 0 && self.get_height_for_round(earliest_round)? == Some(height) {
+        while earliest_round > 0 && get_height_for_round(earliest_round)? == Some(height) {
             earliest_round = earliest_round.saturating_sub(1);
         }
         let is_multiple = earliest_round != committee_round;
@@ -189,7 +223,7 @@ pub trait CommitteeStorage<N: Network>: 'static + Clone + Send + Sync {
 
         // Find the latest round to be removed (exclusive).
         let mut latest_round = committee_round;
-        while self.get_height_for_round(latest_round)? == Some(height) {
+        while get_height_for_round(latest_round)? == Some(height) {
             latest_round = latest_round.saturating_add(1);
         }
 
@@ -198,7 +232,7 @@ pub trait CommitteeStorage<N: Network>: 'static + Clone + Send + Sync {
         if is_latest_committee {
             while next_current_round > 0 {
                 // If the next current height is less than the current height, then we have found the next current round.
-                if let Some(next_current_height) = self.get_height_for_round(next_current_round)? {
+                if let Some(next_current_height) = get_height_for_round(next_current_round)? {
                     if next_current_height < current_height {
                         break;
                     }
@@ -328,8 +362,8 @@ impl<N: Network, C: CommitteeStorage<N>> CommitteeStore<N, C> {
     }
 
     /// Checkpoints the atomic batch.
-    pub fn atomic_checkpoint(&self) {
-        self.storage.atomic_checkpoint();
+    pub fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        self.storage.atomic_checkpoint(label);
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -365,6 +399,12 @@ impl<N: Network, C: CommitteeStorage<N>> CommitteeStore<N, C> {
         self.storage.insert(next_height, committee)
     }
 
+    /// Stores the given committee as the committee at the given checkpoint `height`.
+    /// The committee storage must be empty, as the committees before the checkpoint are not stored.
+    pub fn insert_checkpoint(&self, height: u32, committee: Committee<N>) -> Result<()> {
+        self.storage.insert_checkpoint(height, committee)
+    }
+
     /// Removes the committee for the given `height`, in the process
     /// removing all round to height entries back to the previous committee.
     pub fn remove(&self, height: u32) -> Result<()> {
@@ -532,6 +572,43 @@ mod tests {
         assert_eq!(store.get_committee_for_round(5).unwrap(), None);
     }
 
+    #[test]
+    fn test_remove_in_one_batch() {
+        let rng = &mut TestRng::default();
+
+        // Sample the committees.
+        let committee_0 = ledger_committee::test_helpers::sample_committee_for_round(0, rng);
+        let committee_1 = ledger_committee::test_helpers::sample_committee_for_round(5, rng);
+        let committee_2 = ledger_committee::test_helpers::sample_committee_for_round(9, rng);
+
+        // Initialize a new committee store, and insert the committees.
+        let store = CommitteeStore::<CurrentNetwork, CommitteeMemory<_>>::open(None).unwrap();
+        store.insert(0, committee_0.clone()).unwrap();
+        store.insert(1, committee_1).unwrap();
+        store.insert(2, committee_2).unwrap();
+        assert_eq!(store.current_round().unwrap(), 9);
+        assert_eq!(store.current_height().unwrap(), 2);
+
+        // Remove the latest two committees, in one atomic batch.
+        store.start_atomic();
+        store.remove(2).unwrap();
+        store.remove(1).unwrap();
+        store.finish_atomic().unwrap();
+
+        // Ensure the store matches the store after the first committee was inserted.
+        assert_eq!(store.current_round().unwrap(), 4);
+        assert_eq!(store.current_height().unwrap(), 0);
+        assert_eq!(store.current_committee().unwrap(), committee_0);
+        for round in 0..5 {
+            assert_eq!(store.get_height_for_round(round).unwrap(), Some(0));
+        }
+        for round in 5..10 {
+            assert_eq!(store.get_height_for_round(round).unwrap(), None);
+        }
+        assert_eq!(store.get_committee(1).unwrap(), None);
+        assert_eq!(store.get_committee(2).unwrap(), None);
+    }
+
     #[test]
     fn test_remove_hole() {
         let rng = &mut TestRng::default();
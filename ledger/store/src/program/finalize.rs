@@ -28,7 +28,16 @@ use synthesizer_program::{FinalizeOperation, FinalizeStoreTrait};
 
 use anyhow::Result;
 use core::marker::PhantomData;
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
+use parking_lot::RwLock;
+use std::{borrow::Cow, collections::HashSet, ops::RangeInclusive, sync::Arc};
+
+/// The key of the lowest block height from which the history of the finalize store is complete.
+const HISTORY_FLOOR_KEY: u8 = 0;
+/// The key of the latest block height recorded in the history of the finalize store.
+const HISTORY_LATEST_KEY: u8 = 1;
+/// The key of the number of most recent blocks for which the history is retained, where `u32::MAX` retains all.
+const HISTORY_RETENTION_KEY: u8 = 2;
 
 /// TODO (howardwu): Remove this.
 /// Returns the mapping ID for the given `program ID` and `mapping name`.
@@ -76,6 +85,14 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     type ProgramIDMap: for<'a> Map<'a, ProgramID<N>, IndexSet<Identifier<N>>>;
     /// The mapping of `(program ID, mapping name)` to `[(key, value)]`.
     type KeyValueMap: for<'a> NestedMap<'a, (ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    /// The mapping of `key ID` to `[(block height, prior value)]`, in history mode.
+    type KeyHistoryMap: for<'a> NestedMap<'a, Field<N>, u32, Option<Value<N>>>;
+    /// The mapping of `block height` to `[key ID]` of the keys written in the block, in history mode.
+    type BlockHistoryMap: for<'a> Map<'a, u32, Vec<Field<N>>>;
+    /// The mapping of `block height` to `[undo entry]` of the writes in the block, for the revertible blocks.
+    type UndoLogMap: for<'a> Map<'a, u32, Vec<FinalizeUndo<N>>>;
+    /// The mapping of the history metadata key to its value, in history mode.
+    type HistoryHeightMap: for<'a> Map<'a, u8, u32>;
 
     /// Initializes the program state storage.
     fn open(dev: Option<u16>) -> Result<Self>;
@@ -90,6 +107,14 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     fn program_id_map(&self) -> &Self::ProgramIDMap;
     /// Returns the key-value map.
     fn key_value_map(&self) -> &Self::KeyValueMap;
+    /// Returns the key history map.
+    fn key_history_map(&self) -> &Self::KeyHistoryMap;
+    /// Returns the block history map.
+    fn block_history_map(&self) -> &Self::BlockHistoryMap;
+    /// Returns the undo log map.
+    fn undo_log_map(&self) -> &Self::UndoLogMap;
+    /// Returns the history height map.
+    fn history_height_map(&self) -> &Self::HistoryHeightMap;
 
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16>;
@@ -99,6 +124,10 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().start_atomic();
         self.program_id_map().start_atomic();
         self.key_value_map().start_atomic();
+        self.key_history_map().start_atomic();
+        self.block_history_map().start_atomic();
+        self.undo_log_map().start_atomic();
+        self.history_height_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -106,13 +135,21 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().is_atomic_in_progress()
             || self.program_id_map().is_atomic_in_progress()
             || self.key_value_map().is_atomic_in_progress()
+            || self.key_history_map().is_atomic_in_progress()
+            || self.block_history_map().is_atomic_in_progress()
+            || self.undo_log_map().is_atomic_in_progress()
+            || self.history_height_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
-    fn atomic_checkpoint(&self) {
-        self.committee_store().atomic_checkpoint();
-        self.program_id_map().atomic_checkpoint();
-        self.key_value_map().atomic_checkpoint();
+    fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        self.committee_store().atomic_checkpoint(label);
+        self.program_id_map().atomic_checkpoint(label);
+        self.key_value_map().atomic_checkpoint(label);
+        self.key_history_map().atomic_checkpoint(label);
+        self.block_history_map().atomic_checkpoint(label);
+        self.undo_log_map().atomic_checkpoint(label);
+        self.history_height_map().atomic_checkpoint(label);
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -120,6 +157,10 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().clear_latest_checkpoint();
         self.program_id_map().clear_latest_checkpoint();
         self.key_value_map().clear_latest_checkpoint();
+        self.key_history_map().clear_latest_checkpoint();
+        self.block_history_map().clear_latest_checkpoint();
+        self.undo_log_map().clear_latest_checkpoint();
+        self.history_height_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -127,6 +168,10 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().atomic_rewind();
         self.program_id_map().atomic_rewind();
         self.key_value_map().atomic_rewind();
+        self.key_history_map().atomic_rewind();
+        self.block_history_map().atomic_rewind();
+        self.undo_log_map().atomic_rewind();
+        self.history_height_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -134,13 +179,21 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().abort_atomic();
         self.program_id_map().abort_atomic();
         self.key_value_map().abort_atomic();
+        self.key_history_map().abort_atomic();
+        self.block_history_map().abort_atomic();
+        self.undo_log_map().abort_atomic();
+        self.history_height_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
     fn finish_atomic(&self) -> Result<()> {
         self.committee_store().finish_atomic()?;
         self.program_id_map().finish_atomic()?;
-        self.key_value_map().finish_atomic()
+        self.key_value_map().finish_atomic()?;
+        self.key_history_map().finish_atomic()?;
+        self.block_history_map().finish_atomic()?;
+        self.undo_log_map().finish_atomic()?;
+        Ok(self.history_height_map().finish_atomic()?)
     }
 
     /// Initializes the given `program ID` and `mapping name` in storage.
@@ -353,7 +406,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
 
     /// Returns `true` if the given `program ID` exist.
     fn contains_program_confirmed(&self, program_id: &ProgramID<N>) -> Result<bool> {
-        self.program_id_map().contains_key_confirmed(program_id)
+        Ok(self.program_id_map().contains_key_confirmed(program_id)?)
     }
 
     /// Returns `true` if the given `program ID` and `mapping name` exist.
@@ -373,7 +426,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         mapping_name: Identifier<N>,
         key: &Plaintext<N>,
     ) -> Result<bool> {
-        self.key_value_map().contains_key_confirmed(&(program_id, mapping_name), key)
+        Ok(self.key_value_map().contains_key_confirmed(&(program_id, mapping_name), key)?)
     }
 
     /// Returns `true` if the given `program ID`, `mapping name`, and `key` exist.
@@ -383,7 +436,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         mapping_name: Identifier<N>,
         key: &Plaintext<N>,
     ) -> Result<bool> {
-        self.key_value_map().contains_key_speculative(&(program_id, mapping_name), key)
+        Ok(self.key_value_map().contains_key_speculative(&(program_id, mapping_name), key)?)
     }
 
     /// Returns the confirmed mapping names for the given `program ID`.
@@ -407,7 +460,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             bail!("Illegal operation: '{program_id}/{mapping_name}' is not initialized - cannot get mapping (C).")
         }
         // Retrieve the key-values for the mapping.
-        self.key_value_map().get_map_confirmed(&(program_id, mapping_name))
+        Ok(self.key_value_map().get_map_confirmed(&(program_id, mapping_name))?)
     }
 
     /// Returns the speculative mapping entries for the given `program ID` and `mapping name`.
@@ -421,7 +474,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             bail!("Illegal operation: '{program_id}/{mapping_name}' is not initialized - cannot get mapping (S).")
         }
         // Retrieve the key-values for the mapping.
-        self.key_value_map().get_map_speculative(&(program_id, mapping_name))
+        Ok(self.key_value_map().get_map_speculative(&(program_id, mapping_name))?)
     }
 
     /// Returns the confirmed value for the given `program ID`, `mapping name`, and `key`.
@@ -519,11 +572,51 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     }
 }
 
+/// An entry in the undo log of the finalize store, which restores the confirmed state prior to a write.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub enum FinalizeUndo<N: Network> {
+    /// Restores the key to its prior value, or removes the key if it did not exist.
+    Key(ProgramID<N>, Identifier<N>, Plaintext<N>, Option<Value<N>>),
+    /// Initializes the mapping if it existed, or removes the mapping if it did not exist.
+    /// Note: The prior entries of the mapping are restored by the `Key` entries recorded with it.
+    Mapping(ProgramID<N>, Identifier<N>, bool),
+}
+
+/// The history mode of the finalize store, in which the prior value of each key written in a block is recorded,
+/// so that the value of a key as of a past block height can be retrieved.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct FinalizeHistory {
+    /// The number of most recent blocks for which the history is retained, or `None` to retain the full history.
+    retention: Option<u32>,
+}
+
+impl FinalizeHistory {
+    /// Returns a history mode that retains the full history.
+    pub const fn full() -> Self {
+        Self { retention: None }
+    }
+
+    /// Returns a history mode that retains the history of the given number of most recent blocks.
+    pub const fn with_retention(num_blocks: u32) -> Self {
+        Self { retention: Some(num_blocks) }
+    }
+
+    /// Returns the number of most recent blocks for which the history is retained, or `None` if it is unbounded.
+    pub const fn retention(&self) -> Option<u32> {
+        self.retention
+    }
+}
+
 /// The finalize store.
 #[derive(Clone)]
 pub struct FinalizeStore<N: Network, P: FinalizeStorage<N>> {
     /// The finalize storage.
     storage: P,
+    /// The undo log of the writes, if recording is in progress.
+    undo_log: Arc<RwLock<Option<Vec<FinalizeUndo<N>>>>>,
+    /// The history mode, if it is enabled.
+    history: Arc<RwLock<Option<FinalizeHistory>>>,
     /// PhantomData.
     _phantom: PhantomData<N>,
 }
@@ -542,8 +635,21 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
 
     /// Initializes a finalize store from storage.
     pub fn from(storage: P) -> Result<Self> {
+        // Restore the history mode, if it was enabled.
+        let history = match storage.history_height_map().get_confirmed(&HISTORY_RETENTION_KEY)? {
+            Some(retention) => match cow_to_copied!(retention) {
+                u32::MAX => Some(FinalizeHistory::full()),
+                retention => Some(FinalizeHistory::with_retention(retention)),
+            },
+            None => None,
+        };
         // Return the finalize store.
-        Ok(Self { storage, _phantom: PhantomData })
+        Ok(Self {
+            storage,
+            undo_log: Default::default(),
+            history: Arc::new(RwLock::new(history)),
+            _phantom: PhantomData,
+        })
     }
 
     /// Starts an atomic batch write operation.
@@ -557,8 +663,8 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     }
 
     /// Checkpoints the atomic batch.
-    pub fn atomic_checkpoint(&self) {
-        self.storage.atomic_checkpoint();
+    pub fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        self.storage.atomic_checkpoint(label);
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -587,6 +693,346 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     }
 }
 
+impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
+    /// Starts recording an undo log of all subsequent writes to the finalize store.
+    pub fn start_undo_log(&self) {
+        *self.undo_log.write() = Some(Vec::new());
+    }
+
+    /// Stops recording the undo log, and returns the recorded entries.
+    pub fn finish_undo_log(&self) -> Vec<FinalizeUndo<N>> {
+        self.undo_log.write().take().unwrap_or_default()
+    }
+
+    /// Stores the undo log in progress as the undo log of the block at the given height, and removes the undo log
+    /// of the block that is `num_revertible_blocks` blocks prior, so that only the most recent blocks can be reverted.
+    ///
+    /// Note: This is intended to be called in the atomic batch of the block, after all of its writes,
+    /// so that the undo log is committed (or aborted) together with the block.
+    pub fn insert_undo_log(&self, height: u32, num_revertible_blocks: u32) -> Result<()> {
+        // Retrieve the undo log in progress.
+        let Some(undo_log) = self.undo_log.read().clone() else {
+            return Ok(());
+        };
+
+        atomic_batch_scope!(self, {
+            // Store the undo log of the block.
+            self.storage.undo_log_map().insert(height, undo_log)?;
+            // Remove the undo log beyond the revertible boundary.
+            if let Some(expired_height) = height.checked_sub(num_revertible_blocks) {
+                self.storage.undo_log_map().remove(&expired_height)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Returns the stored undo log of the block at the given height, if the block is revertible.
+    pub fn get_undo_log(&self, height: u32) -> Result<Option<Vec<FinalizeUndo<N>>>> {
+        Ok(self.storage.undo_log_map().get_confirmed(&height)?.map(|undo_log| cow_to_cloned!(undo_log)))
+    }
+
+    /// Removes the stored undo log of the block at the given height.
+    pub fn remove_undo_log(&self, height: u32) -> Result<()> {
+        Ok(self.storage.undo_log_map().remove(&height)?)
+    }
+
+    /// Restores the finalize store to the state prior to the given undo log.
+    pub fn apply_undo_log(&self, undo_log: &[FinalizeUndo<N>]) -> Result<()> {
+        atomic_batch_scope!(self, {
+            // Undo the writes in reverse order.
+            for entry in undo_log.iter().rev() {
+                match entry {
+                    FinalizeUndo::Key(program_id, mapping_name, key, Some(value)) => {
+                        if !self.storage.contains_mapping_speculative(program_id, mapping_name)? {
+                            self.storage.initialize_mapping(*program_id, *mapping_name)?;
+                        }
+                        self.storage.update_key_value(*program_id, *mapping_name, key.clone(), value.clone())?;
+                    }
+                    FinalizeUndo::Key(program_id, mapping_name, key, None) => {
+                        if self.storage.contains_mapping_speculative(program_id, mapping_name)? {
+                            self.storage.remove_key_value(*program_id, *mapping_name, key)?;
+                        }
+                    }
+                    FinalizeUndo::Mapping(program_id, mapping_name, true) => {
+                        if !self.storage.contains_mapping_speculative(program_id, mapping_name)? {
+                            self.storage.initialize_mapping(*program_id, *mapping_name)?;
+                        }
+                    }
+                    FinalizeUndo::Mapping(program_id, mapping_name, false) => {
+                        if self.storage.contains_mapping_speculative(program_id, mapping_name)? {
+                            self.storage.remove_mapping(*program_id, *mapping_name)?;
+                        }
+                        // If the program no longer has any mappings, remove the program.
+                        if let Some(mapping_names) = self.storage.program_id_map().get_speculative(program_id)? {
+                            if mapping_names.is_empty() {
+                                self.storage.remove_program(program_id)?;
+                            }
+                        }
+                    }
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Records the prior confirmed value of the given key, if recording is in progress.
+    fn record_key(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>, key: &Plaintext<N>) -> Result<()> {
+        if let Some(undo_log) = self.undo_log.write().as_mut() {
+            let value = self.storage.get_value_confirmed(program_id, mapping_name, key)?;
+            undo_log.push(FinalizeUndo::Key(program_id, mapping_name, key.clone(), value));
+        }
+        Ok(())
+    }
+
+    /// Records the prior confirmed value of each key in the given mapping that differs from the given entries,
+    /// followed by the prior confirmed existence of the mapping, if recording is in progress.
+    ///
+    /// Note: The given entries are the entries of the mapping after the write (i.e. empty for a removal),
+    /// so that only the keys whose value changes are recorded.
+    fn record_mapping(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        entries: &[(Plaintext<N>, Value<N>)],
+    ) -> Result<()> {
+        if let Some(undo_log) = self.undo_log.write().as_mut() {
+            let exists = self.storage.contains_mapping_confirmed(&program_id, &mapping_name)?;
+            // Retrieve the prior entries of the mapping, keyed by the bytes of each key.
+            let mut prior_entries = IndexMap::new();
+            if exists {
+                for (key, value) in self.storage.get_mapping_confirmed(program_id, mapping_name)? {
+                    prior_entries.insert(key.to_bytes_le()?, (key, value));
+                }
+            }
+            // Record the prior value of each key that is written with a different value.
+            for (key, value) in entries {
+                match prior_entries.shift_remove(&key.to_bytes_le()?) {
+                    Some((_, prior_value)) if &prior_value == value => (),
+                    prior => undo_log.push(FinalizeUndo::Key(
+                        program_id,
+                        mapping_name,
+                        key.clone(),
+                        prior.map(|(_, prior_value)| prior_value),
+                    )),
+                }
+            }
+            // Record the prior value of each key that is removed.
+            for (_, (key, value)) in prior_entries {
+                undo_log.push(FinalizeUndo::Key(program_id, mapping_name, key, Some(value)));
+            }
+            // Record the prior existence of the mapping.
+            undo_log.push(FinalizeUndo::Mapping(program_id, mapping_name, exists));
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
+    /// Enables the history mode of the finalize store, and persists it in storage.
+    /// Note: This is intended to be called when the store is opened, prior to adding any blocks.
+    pub fn enable_history(&self, history: FinalizeHistory) -> Result<()> {
+        // Persist the history mode, so that it is restored when the store is reopened.
+        self.storage.history_height_map().insert(HISTORY_RETENTION_KEY, history.retention().unwrap_or(u32::MAX))?;
+        *self.history.write() = Some(history);
+        Ok(())
+    }
+
+    /// Returns the history mode of the finalize store, if it is enabled.
+    pub fn history(&self) -> Option<FinalizeHistory> {
+        *self.history.read()
+    }
+
+    /// Returns the range of block heights for which the history is available, or `None` if no history is recorded.
+    pub fn history_range(&self) -> Result<Option<RangeInclusive<u32>>> {
+        let floor = self.storage.history_height_map().get_confirmed(&HISTORY_FLOOR_KEY)?;
+        let latest = self.storage.history_height_map().get_confirmed(&HISTORY_LATEST_KEY)?;
+        match (floor, latest) {
+            (Some(floor), Some(latest)) => Ok(Some(cow_to_copied!(floor)..=cow_to_copied!(latest))),
+            _ => Ok(None),
+        }
+    }
+
+    /// Records the prior value of each key written in the block at the given height, from the undo log in progress.
+    /// If the history mode is disabled, or no undo log is being recorded, this method does nothing.
+    ///
+    /// Note: This is intended to be called in the atomic batch of the block, after all of its writes,
+    /// so that the history is committed (or aborted) together with the block.
+    pub fn insert_history(&self, height: u32) -> Result<()> {
+        // If the history mode is disabled, there is nothing to record.
+        let Some(history) = self.history() else {
+            return Ok(());
+        };
+        // Retrieve the prior value of each key written in the block.
+        let prior_values = match self.undo_log.read().as_ref() {
+            Some(undo_log) => self.prior_values(undo_log)?,
+            None => return Ok(()),
+        };
+
+        // Retrieve the range of the recorded history, if it is contiguous with this block.
+        let (range, is_contiguous) = match self.history_range()? {
+            Some(range) => {
+                let is_contiguous = range.end().saturating_add(1) == height;
+                (Some(range), is_contiguous)
+            }
+            None => (None, false),
+        };
+        // Determine the lowest block height from which the history is complete.
+        let floor = match &range {
+            // If the history is contiguous with this block, the floor is unchanged.
+            Some(range) if is_contiguous => *range.start(),
+            // Otherwise, the history is complete from the block prior to this block.
+            _ => height.saturating_sub(1),
+        };
+        // If the history is bounded, raise the floor to the retained blocks.
+        let floor = match history.retention() {
+            Some(retention) => floor.max(height.saturating_sub(retention)),
+            None => floor,
+        };
+
+        atomic_batch_scope!(self, {
+            // Remove the entries at or below the floor, as they are no longer needed to retrieve any available value.
+            match range {
+                Some(range) if is_contiguous => self.remove_history_entries(*range.start()..=floor)?,
+                // If the history is not contiguous with this block, all of the prior history is removed.
+                Some(range) => self.remove_history_entries(range)?,
+                None => (),
+            }
+            // Record the prior value of each key, as one entry per key and block height.
+            let mut key_ids = Vec::with_capacity(prior_values.len());
+            for (key_id, prior_value) in prior_values {
+                self.storage.key_history_map().insert(key_id, height, prior_value)?;
+                key_ids.push(key_id);
+            }
+            // Record the keys written in the block, so that their entries can be removed by block height.
+            if !key_ids.is_empty() {
+                self.storage.block_history_map().insert(height, key_ids)?;
+            }
+            // Update the range of the history.
+            self.storage.history_height_map().insert(HISTORY_FLOOR_KEY, floor)?;
+            self.storage.history_height_map().insert(HISTORY_LATEST_KEY, height)?;
+            Ok(())
+        })
+    }
+
+    /// Removes the history recorded for the blocks above the given height.
+    /// This is used when the blocks are reverted.
+    pub fn remove_history_above(&self, height: u32) -> Result<()> {
+        // If no block above the given height is recorded, there is nothing to remove.
+        let (floor, latest) = match self.history_range()? {
+            Some(range) if *range.end() > height => (*range.start(), *range.end()),
+            _ => return Ok(()),
+        };
+
+        atomic_batch_scope!(self, {
+            // Remove the entries of the blocks.
+            self.remove_history_entries(floor.max(height + 1)..=latest)?;
+            // Update the range of the history.
+            match height >= floor {
+                true => self.storage.history_height_map().insert(HISTORY_LATEST_KEY, height)?,
+                false => {
+                    self.storage.history_height_map().remove(&HISTORY_FLOOR_KEY)?;
+                    self.storage.history_height_map().remove(&HISTORY_LATEST_KEY)?;
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Removes the history at or below the given block height,
+    /// so that values can only be retrieved as of later block heights.
+    pub fn prune_history(&self, height: u32) -> Result<()> {
+        // If no history is recorded, there is nothing to prune.
+        let Some(range) = self.history_range()? else {
+            return Ok(());
+        };
+        // Determine the new floor, which may not exceed the latest recorded block.
+        let floor = height.clamp(*range.start(), *range.end());
+
+        atomic_batch_scope!(self, {
+            // Remove the entries at or below the floor.
+            self.remove_history_entries(*range.start()..=floor)?;
+            // Update the range of the history.
+            self.storage.history_height_map().insert(HISTORY_FLOOR_KEY, floor)?;
+            Ok(())
+        })
+    }
+
+    /// Returns the confirmed value for the given `program ID`, `mapping name`, and `key`, as of the given block height,
+    /// from the current value and the prior values recorded in history mode.
+    pub fn get_value_at_height(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        height: u32,
+    ) -> Result<Option<Value<N>>> {
+        // Ensure the history of the given block height is available.
+        ensure!(self.history().is_some(), "History mode is not enabled in the finalize store");
+        let Some(range) = self.history_range()? else {
+            bail!("The history of block {height} is not available - no history is recorded")
+        };
+        ensure!(
+            range.contains(&height),
+            "The history of block {height} is not available - the history is of blocks {} to {}",
+            range.start(),
+            range.end()
+        );
+
+        // Retrieve the prior values of the key.
+        let key_id = to_key_id(&program_id, &mapping_name, key)?;
+        let entries = self.storage.key_history_map().get_map_confirmed(&key_id)?;
+        // The value as of the given block height is the prior value of the first write after the block height,
+        // or the current value if the key was not written after the block height.
+        match entries.into_iter().filter(|(block_height, _)| *block_height > height).min_by_key(|(h, _)| *h) {
+            Some((_, prior_value)) => Ok(prior_value),
+            None => self.storage.get_value_confirmed(program_id, mapping_name, key),
+        }
+    }
+
+    /// Removes the history entries of the blocks in the given range of block heights.
+    fn remove_history_entries(&self, heights: RangeInclusive<u32>) -> Result<()> {
+        for height in heights {
+            if let Some(key_ids) = self.storage.block_history_map().get_speculative(&height)? {
+                for key_id in cow_to_cloned!(key_ids) {
+                    self.storage.key_history_map().remove_key(&key_id, &height)?;
+                }
+                self.storage.block_history_map().remove(&height)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the key ID and prior value of each key written in a block, from the undo log of the block,
+    /// in order of first write, skipping the keys whose value did not change in the block.
+    #[allow(clippy::type_complexity)]
+    fn prior_values(&self, undo_log: &[FinalizeUndo<N>]) -> Result<Vec<(Field<N>, Option<Value<N>>)>> {
+        let mut prior_values = Vec::new();
+        // The keys that have already been written earlier in the block.
+        let mut seen = HashSet::new();
+        // Adds the prior value of the given key, if the key has not been written earlier in the block,
+        // and its current value differs from its prior value.
+        let mut insert = |program_id: &ProgramID<N>, mapping_name: &Identifier<N>, key: &Plaintext<N>, prior_value| {
+            if seen.insert((*program_id, *mapping_name, key.to_bytes_le()?))
+                && self.storage.get_value_speculative(*program_id, *mapping_name, key)? != prior_value
+            {
+                prior_values.push((to_key_id(program_id, mapping_name, key)?, prior_value));
+            }
+            Ok::<_, Error>(())
+        };
+
+        for entry in undo_log {
+            match entry {
+                FinalizeUndo::Key(program_id, mapping_name, key, value) => {
+                    insert(program_id, mapping_name, key, value.clone())?;
+                }
+                // Note: The prior entries of a mapping are recorded as `Key` entries.
+                FinalizeUndo::Mapping(..) => (),
+            }
+        }
+        Ok(prior_values)
+    }
+}
+
 impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     /// Returns the committee store.
     pub fn committee_store(&self) -> &CommitteeStore<N, P::CommitteeStorage> {
@@ -630,6 +1076,7 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         key: Plaintext<N>,
         value: Value<N>,
     ) -> Result<FinalizeOperation<N>> {
+        self.record_key(program_id, mapping_name, &key)?;
         self.storage.insert_key_value(program_id, mapping_name, key, value)
     }
 
@@ -644,6 +1091,7 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         key: Plaintext<N>,
         value: Value<N>,
     ) -> Result<FinalizeOperation<N>> {
+        self.record_key(program_id, mapping_name, &key)?;
         self.storage.update_key_value(program_id, mapping_name, key, value)
     }
 
@@ -654,6 +1102,7 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         mapping_name: Identifier<N>,
         key: &Plaintext<N>,
     ) -> Result<Option<FinalizeOperation<N>>> {
+        self.record_key(program_id, mapping_name, key)?;
         self.storage.remove_key_value(program_id, mapping_name, key)
     }
 }
@@ -666,6 +1115,7 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         program_id: ProgramID<N>,
         mapping_name: Identifier<N>,
     ) -> Result<FinalizeOperation<N>> {
+        self.record_mapping(program_id, mapping_name, &[])?;
         self.storage.initialize_mapping(program_id, mapping_name)
     }
 
@@ -677,6 +1127,7 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         mapping_name: Identifier<N>,
         entries: Vec<(Plaintext<N>, Value<N>)>,
     ) -> Result<FinalizeOperation<N>> {
+        self.record_mapping(program_id, mapping_name, &entries)?;
         self.storage.replace_mapping(program_id, mapping_name, entries)
     }
 
@@ -687,12 +1138,18 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         program_id: ProgramID<N>,
         mapping_name: Identifier<N>,
     ) -> Result<FinalizeOperation<N>> {
+        self.record_mapping(program_id, mapping_name, &[])?;
         self.storage.remove_mapping(program_id, mapping_name)
     }
 
     /// Removes the program for the given `program ID` from storage,
     /// along with all associated mappings and key-value pairs in storage.
     pub fn remove_program(&self, program_id: &ProgramID<N>) -> Result<()> {
+        if let Some(mapping_names) = self.storage.get_mapping_names_confirmed(program_id)? {
+            for mapping_name in mapping_names {
+                self.record_mapping(*program_id, mapping_name, &[])?;
+            }
+        }
         self.storage.remove_program(program_id)
     }
 }
@@ -715,6 +1172,11 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
 }
 
 impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
+    /// Returns an iterator over the confirmed program IDs, for all programs in the finalize store.
+    pub fn program_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, ProgramID<N>>> {
+        self.storage.program_id_map().keys_confirmed()
+    }
+
     /// Returns the confirmed mapping names for the given `program ID`.
     pub fn get_mapping_names_confirmed(&self, program_id: &ProgramID<N>) -> Result<Option<IndexSet<Identifier<N>>>> {
         self.storage.get_mapping_names_confirmed(program_id)
@@ -764,11 +1226,102 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     }
 }
 
+impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
+    /// Returns the confirmed mapping names for the given `program ID`.
+    pub fn mapping_names(&self, program_id: &ProgramID<N>) -> Result<Vec<Identifier<N>>> {
+        match self.storage.get_mapping_names_confirmed(program_id)? {
+            Some(mapping_names) => Ok(mapping_names.into_iter().collect()),
+            None => bail!("Program '{program_id}' does not exist in the finalize store"),
+        }
+    }
+
+    /// Returns the confirmed entries for the given `program ID` and `mapping name`, sorted by key bytes.
+    pub fn mapping_entries(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+    ) -> Result<Vec<(Plaintext<N>, Value<N>)>> {
+        // Retrieve the confirmed entries, along with the key bytes.
+        let mut entries = self
+            .storage
+            .get_mapping_confirmed(*program_id, *mapping_name)?
+            .into_iter()
+            .map(|(key, value)| Ok((key.to_bytes_le()?, key, value)))
+            .collect::<Result<Vec<_>>>()?;
+        // Sort the entries by key bytes.
+        entries.sort_unstable_by(|(a, ..), (b, ..)| a.cmp(b));
+        Ok(entries.into_iter().map(|(_, key, value)| (key, value)).collect())
+    }
+
+    /// Writes the confirmed state of the given `program ID` to the writer, as a canonical JSON document.
+    pub fn export_program_state<W: Write>(&self, program_id: &ProgramID<N>, writer: W) -> Result<()> {
+        let mut mappings = Vec::new();
+        for mapping_name in self.mapping_names(program_id)? {
+            // Retrieve the entries for the mapping.
+            let entries = self
+                .mapping_entries(program_id, &mapping_name)?
+                .into_iter()
+                .map(|(key, value)| serde_json::json!({ "key": key.to_string(), "value": value.to_string() }))
+                .collect::<Vec<_>>();
+            mappings.push(serde_json::json!({ "name": mapping_name.to_string(), "entries": entries }));
+        }
+        let state = serde_json::json!({ "program_id": program_id.to_string(), "mappings": mappings });
+        Ok(serde_json::to_writer(writer, &state)?)
+    }
+
+    /// Reads a program state exported by `export_program_state` into the finalize store,
+    /// and returns the program ID. The program must not already exist in the finalize store.
+    pub fn import_program_state<R: Read>(&self, reader: R) -> Result<ProgramID<N>> {
+        let state: serde_json::Value = serde_json::from_reader(reader)?;
+
+        // Retrieve the given field as a string.
+        let as_str = |value: &serde_json::Value, field: &str| -> Result<String> {
+            match value.get(field).and_then(|value| value.as_str()) {
+                Some(string) => Ok(string.to_string()),
+                None => bail!("Missing '{field}' in the program state"),
+            }
+        };
+        // Retrieve the given field as an array.
+        let as_array = |value: &serde_json::Value, field: &str| -> Result<Vec<serde_json::Value>> {
+            match value.get(field).and_then(|value| value.as_array()) {
+                Some(array) => Ok(array.clone()),
+                None => bail!("Missing '{field}' in the program state"),
+            }
+        };
+
+        // Parse the program ID.
+        let program_id = ProgramID::from_str(&as_str(&state, "program_id")?)?;
+        // Ensure the program does not already exist.
+        ensure!(!self.contains_program_confirmed(&program_id)?, "Program '{program_id}' already exists");
+
+        atomic_batch_scope!(self, {
+            for mapping in as_array(&state, "mappings")? {
+                // Initialize the mapping.
+                let mapping_name = Identifier::from_str(&as_str(&mapping, "name")?)?;
+                self.initialize_mapping(program_id, mapping_name)?;
+                // Insert the entries.
+                for entry in as_array(&mapping, "entries")? {
+                    let key = Plaintext::from_str(&as_str(&entry, "key")?)?;
+                    let value = Value::from_str(&as_str(&entry, "value")?)?;
+                    self.insert_key_value(program_id, mapping_name, key, value)?;
+                }
+            }
+            Ok(())
+        })?;
+
+        Ok(program_id)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::helpers::memory::FinalizeMemory;
-    use console::{network::Testnet3, program::Literal, types::U64};
+    use console::{
+        network::Testnet3,
+        program::{Literal, FINALIZE_OPERATIONS_DEPTH},
+        types::U64,
+    };
 
     type CurrentNetwork = Testnet3;
 
@@ -1140,6 +1693,71 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_export_import_program_state() {
+        // Initialize a program ID and mapping names.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let account = Identifier::from_str("account").unwrap();
+        let supply = Identifier::from_str("supply").unwrap();
+
+        // Initialize a new finalize store.
+        let finalize_store = FinalizeStore::from(FinalizeMemory::open(None).unwrap()).unwrap();
+        // Ensure a missing program cannot be exported.
+        assert!(finalize_store.mapping_names(&program_id).is_err());
+        assert!(finalize_store.export_program_state(&program_id, Vec::new()).is_err());
+
+        // Initialize the mappings, and insert the entries in reverse order.
+        finalize_store.initialize_mapping(program_id, account).unwrap();
+        finalize_store.initialize_mapping(program_id, supply).unwrap();
+        for item in (0..100u64).rev() {
+            let key = Plaintext::from_str(&format!("{item}field")).unwrap();
+            let value = Value::from_str(&format!("{item}u64")).unwrap();
+            finalize_store.insert_key_value(program_id, account, key, value).unwrap();
+        }
+        let key = Plaintext::from_str("0u8").unwrap();
+        let value = Value::from_str("1000u64").unwrap();
+        finalize_store.insert_key_value(program_id, supply, key, value).unwrap();
+
+        // Ensure the mapping names and entries are correct.
+        assert_eq!(finalize_store.mapping_names(&program_id).unwrap(), vec![account, supply]);
+        let entries = finalize_store.mapping_entries(&program_id, &account).unwrap();
+        assert_eq!(entries.len(), 100);
+        assert!(entries.windows(2).all(|w| w[0].0.to_bytes_le().unwrap() < w[1].0.to_bytes_le().unwrap()));
+
+        // Export the program state.
+        let mut expected = Vec::new();
+        finalize_store.export_program_state(&program_id, &mut expected).unwrap();
+
+        // Ensure the export does not observe pending writes.
+        finalize_store.start_atomic();
+        let key = Plaintext::from_str("100field").unwrap();
+        let value = Value::from_str("100u64").unwrap();
+        finalize_store.insert_key_value(program_id, account, key, value).unwrap();
+        let mut candidate = Vec::new();
+        finalize_store.export_program_state(&program_id, &mut candidate).unwrap();
+        assert_eq!(expected, candidate);
+        assert_eq!(finalize_store.mapping_entries(&program_id, &account).unwrap().len(), 100);
+        finalize_store.abort_atomic();
+
+        // Import the program state into a new finalize store.
+        let other_store = FinalizeStore::from(FinalizeMemory::open(None).unwrap()).unwrap();
+        assert_eq!(other_store.import_program_state(&expected[..]).unwrap(), program_id);
+        // Ensure the program state matches.
+        for mapping_name in [account, supply] {
+            assert_eq!(
+                finalize_store.mapping_entries(&program_id, &mapping_name).unwrap(),
+                other_store.mapping_entries(&program_id, &mapping_name).unwrap()
+            );
+        }
+        // Ensure the re-exported program state is identical.
+        let mut candidate = Vec::new();
+        other_store.export_program_state(&program_id, &mut candidate).unwrap();
+        assert_eq!(expected, candidate);
+
+        // Ensure the program state cannot be imported twice.
+        assert!(other_store.import_program_state(&expected[..]).is_err());
+    }
+
     #[test]
     fn test_remove_program() {
         // Initialize a program ID and mapping name.
@@ -1387,4 +2005,376 @@ mod tests {
         finalize_store.remove_program(&program_id).unwrap();
         println!("FinalizeStore::remove_program - {} μs", timer.elapsed().as_micros());
     }
+
+    /// Finalizes a block at the given height, which writes the given values to the given keys,
+    /// and records the history of the block.
+    fn add_block<N: Network, P: FinalizeStorage<N>>(
+        finalize_store: &FinalizeStore<N, P>,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        height: u32,
+        writes: &[(Plaintext<N>, Option<Value<N>>)],
+    ) {
+        finalize_store.start_undo_log();
+        for (key, value) in writes {
+            match value {
+                Some(value) => {
+                    finalize_store.update_key_value(program_id, mapping_name, key.clone(), value.clone()).unwrap();
+                }
+                None => {
+                    finalize_store.remove_key_value(program_id, mapping_name, key).unwrap();
+                }
+            }
+        }
+        finalize_store.insert_history(height).unwrap();
+        finalize_store.finish_undo_log();
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_truncation)]
+    fn test_history_get_value_at_height() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("0field").unwrap();
+        let other_key = Plaintext::from_str("1field").unwrap();
+
+        // Initialize a new finalize store, with history mode enabled.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        finalize_store.enable_history(FinalizeHistory::full()).unwrap();
+
+        // Block 0 initializes the mapping.
+        finalize_store.start_undo_log();
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        finalize_store.insert_history(0).unwrap();
+        finalize_store.finish_undo_log();
+
+        // Update the key across several blocks, with the expected value as of each block height.
+        let values = [None, Some(1u64), Some(2), Some(2), None, Some(5), Some(6)];
+        for (height, value) in values.iter().enumerate().skip(1) {
+            let value = value.map(|value| Value::from_str(&format!("{value}u64")).unwrap());
+            // Write the key in every block the value changes, and an unrelated key in every other block.
+            let writes = match values[height - 1] == values[height] {
+                true => vec![(other_key.clone(), Some(Value::from_str(&format!("{height}u64")).unwrap()))],
+                false => vec![(key.clone(), value)],
+            };
+            add_block(&finalize_store, program_id, mapping_name, height as u32, &writes);
+        }
+        assert_eq!(finalize_store.history_range().unwrap(), Some(0..=6));
+
+        // Ensure the value of the key as of each block height is correct.
+        for (height, value) in values.iter().enumerate() {
+            let expected = value.map(|value| Value::from_str(&format!("{value}u64")).unwrap());
+            let candidate = finalize_store.get_value_at_height(program_id, mapping_name, &key, height as u32).unwrap();
+            assert_eq!(expected, candidate, "Incorrect value as of block {height}");
+        }
+        // Ensure the value of the unrelated key as of each block height is correct.
+        let expected = [None, None, None, Some(3u64), Some(3), Some(3), Some(3)];
+        for (height, value) in expected.iter().enumerate() {
+            let expected = value.map(|value| Value::from_str(&format!("{value}u64")).unwrap());
+            let candidate =
+                finalize_store.get_value_at_height(program_id, mapping_name, &other_key, height as u32).unwrap();
+            assert_eq!(expected, candidate, "Incorrect value as of block {height}");
+        }
+        // Ensure a height beyond the recorded history fails.
+        assert!(finalize_store.get_value_at_height(program_id, mapping_name, &key, 7).is_err());
+
+        // Remove the history of the latest block, and revert the block.
+        let value = Value::from_str("5u64").unwrap();
+        let undo_log = vec![FinalizeUndo::Key(program_id, mapping_name, key.clone(), Some(value))];
+        finalize_store.apply_undo_log(&undo_log).unwrap();
+        finalize_store.remove_history_above(5).unwrap();
+        assert_eq!(finalize_store.history_range().unwrap(), Some(0..=5));
+        // Ensure the history is still correct.
+        for (height, value) in values.iter().enumerate().take(6) {
+            let expected = value.map(|value| Value::from_str(&format!("{value}u64")).unwrap());
+            let candidate = finalize_store.get_value_at_height(program_id, mapping_name, &key, height as u32).unwrap();
+            assert_eq!(expected, candidate, "Incorrect value as of block {height}");
+        }
+    }
+
+    #[test]
+    fn test_history_prune() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("0field").unwrap();
+
+        // Initialize a new finalize store, retaining the history of the 3 most recent blocks.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        finalize_store.enable_history(FinalizeHistory::with_retention(3)).unwrap();
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+
+        // Update the key in every block, to the value of the block height.
+        for height in 0..10u32 {
+            let value = Value::from_str(&format!("{height}u64")).unwrap();
+            add_block(&finalize_store, program_id, mapping_name, height, &[(key.clone(), Some(value))]);
+        }
+        // Ensure only the retained history is available.
+        assert_eq!(finalize_store.history_range().unwrap(), Some(6..=9));
+        for height in 0..10u32 {
+            let candidate = finalize_store.get_value_at_height(program_id, mapping_name, &key, height);
+            match height >= 6 {
+                true => assert_eq!(candidate.unwrap(), Some(Value::from_str(&format!("{height}u64")).unwrap())),
+                false => assert!(candidate.is_err()),
+            }
+        }
+        // Ensure the pruned entries are removed.
+        let key_id = to_key_id(&program_id, &mapping_name, &key).unwrap();
+        assert_eq!(finalize_store.storage.key_history_map().get_map_confirmed(&key_id).unwrap().len(), 3);
+        assert_eq!(finalize_store.storage.block_history_map().keys_confirmed().count(), 3);
+
+        // Prune the history further.
+        finalize_store.prune_history(8).unwrap();
+        assert_eq!(finalize_store.history_range().unwrap(), Some(8..=9));
+        assert!(finalize_store.get_value_at_height(program_id, mapping_name, &key, 7).is_err());
+        for height in 8..10u32 {
+            let candidate = finalize_store.get_value_at_height(program_id, mapping_name, &key, height).unwrap();
+            assert_eq!(candidate, Some(Value::from_str(&format!("{height}u64")).unwrap()));
+        }
+
+        // Prune the entire history.
+        finalize_store.prune_history(9).unwrap();
+        assert_eq!(finalize_store.history_range().unwrap(), Some(9..=9));
+        assert_eq!(finalize_store.storage.key_history_map().iter_confirmed().count(), 0);
+        assert_eq!(finalize_store.storage.block_history_map().iter_confirmed().count(), 0);
+        let candidate = finalize_store.get_value_at_height(program_id, mapping_name, &key, 9).unwrap();
+        assert_eq!(candidate, Some(Value::from_str("9u64").unwrap()));
+    }
+
+    #[test]
+    fn test_history_prune_cold_keys() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let cold_key = Plaintext::from_str("0field").unwrap();
+        let hot_key = Plaintext::from_str("1field").unwrap();
+
+        // Initialize a new finalize store, retaining the history of the 3 most recent blocks.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        finalize_store.enable_history(FinalizeHistory::with_retention(3)).unwrap();
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+
+        // Write the cold key only in block 1, and the hot key in every other block.
+        for height in 0..10u32 {
+            let key = if height == 1 { cold_key.clone() } else { hot_key.clone() };
+            let value = Value::from_str(&format!("{height}u64")).unwrap();
+            add_block(&finalize_store, program_id, mapping_name, height, &[(key, Some(value))]);
+        }
+        assert_eq!(finalize_store.history_range().unwrap(), Some(6..=9));
+
+        // Ensure the entry of the cold key is removed once it falls below the retained blocks.
+        let cold_key_id = to_key_id(&program_id, &mapping_name, &cold_key).unwrap();
+        assert!(finalize_store.storage.key_history_map().get_map_confirmed(&cold_key_id).unwrap().is_empty());
+        assert_eq!(finalize_store.storage.key_history_map().iter_confirmed().count(), 3);
+        let candidate = finalize_store.get_value_at_height(program_id, mapping_name, &cold_key, 6).unwrap();
+        assert_eq!(candidate, Some(Value::from_str("1u64").unwrap()));
+    }
+
+    #[test]
+    fn test_history_replace_mapping() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let entry = |key: u64, value: u64| {
+            (Plaintext::from_str(&format!("{key}field")).unwrap(), Value::from_str(&format!("{value}u64")).unwrap())
+        };
+
+        // Initialize a new finalize store, with history mode enabled.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        finalize_store.enable_history(FinalizeHistory::full()).unwrap();
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        finalize_store.replace_mapping(program_id, mapping_name, (0..10).map(|i| entry(i, i)).collect()).unwrap();
+
+        // Replace the mapping in block 1, changing one value, removing one key, and adding one key.
+        finalize_store.start_undo_log();
+        let mut entries = (0..10).map(|i| entry(i, i)).collect::<Vec<_>>();
+        entries[3] = entry(3, 30);
+        entries.remove(5);
+        entries.push(entry(10, 10));
+        finalize_store.replace_mapping(program_id, mapping_name, entries).unwrap();
+        finalize_store.insert_history(1).unwrap();
+        finalize_store.finish_undo_log();
+
+        // Ensure only the keys whose value changed are recorded.
+        assert_eq!(finalize_store.storage.key_history_map().iter_confirmed().count(), 3);
+        assert_eq!(finalize_store.storage.block_history_map().get_confirmed(&1).unwrap().unwrap().len(), 3);
+        // Ensure the values as of block 0 are restored.
+        for i in 0..11 {
+            let (key, _) = entry(i, i);
+            let expected = (i < 10).then(|| entry(i, i).1);
+            let candidate = finalize_store.get_value_at_height(program_id, mapping_name, &key, 0).unwrap();
+            assert_eq!(candidate, expected, "Incorrect value of key {i} as of block 0");
+        }
+    }
+
+    #[test]
+    fn test_undo_log_replace_remove_mapping() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let entry = |key: u64, value: u64| {
+            (Plaintext::from_str(&format!("{key}field")).unwrap(), Value::from_str(&format!("{value}u64")).unwrap())
+        };
+
+        // Initialize a new finalize store, with a mapping of ten entries.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        let prior_entries = (0..10).map(|i| entry(i, i)).collect::<Vec<_>>();
+        finalize_store.replace_mapping(program_id, mapping_name, prior_entries.clone()).unwrap();
+
+        // Replace the mapping, changing one value, removing one key, and adding one key.
+        finalize_store.start_undo_log();
+        let mut entries = prior_entries.clone();
+        entries[3] = entry(3, 30);
+        entries.remove(5);
+        entries.push(entry(10, 10));
+        finalize_store.replace_mapping(program_id, mapping_name, entries).unwrap();
+        let undo_log = finalize_store.finish_undo_log();
+
+        // Ensure only the prior values of the changed keys are recorded.
+        assert_eq!(undo_log, vec![
+            FinalizeUndo::Key(program_id, mapping_name, entry(3, 3).0, Some(entry(3, 3).1)),
+            FinalizeUndo::Key(program_id, mapping_name, entry(10, 10).0, None),
+            FinalizeUndo::Key(program_id, mapping_name, entry(5, 5).0, Some(entry(5, 5).1)),
+            FinalizeUndo::Mapping(program_id, mapping_name, true),
+        ]);
+        // Ensure the prior entries are restored.
+        finalize_store.apply_undo_log(&undo_log).unwrap();
+        let mut candidate = finalize_store.get_mapping_confirmed(program_id, mapping_name).unwrap();
+        candidate.sort_by_key(|(key, _)| key.to_string());
+        let mut expected = prior_entries.clone();
+        expected.sort_by_key(|(key, _)| key.to_string());
+        assert_eq!(candidate, expected);
+
+        // Remove the program, and ensure the prior entries are restored.
+        finalize_store.start_undo_log();
+        finalize_store.remove_program(&program_id).unwrap();
+        let undo_log = finalize_store.finish_undo_log();
+        assert!(!finalize_store.contains_program_confirmed(&program_id).unwrap());
+        finalize_store.apply_undo_log(&undo_log).unwrap();
+        let mut candidate = finalize_store.get_mapping_confirmed(program_id, mapping_name).unwrap();
+        candidate.sort_by_key(|(key, _)| key.to_string());
+        assert_eq!(candidate, expected);
+    }
+
+    #[test]
+    fn test_history_reopen() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("0field").unwrap();
+
+        // Initialize a new finalize store, retaining the history of the 3 most recent blocks.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory.clone()).unwrap();
+        finalize_store.enable_history(FinalizeHistory::with_retention(3)).unwrap();
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        for height in 0..3u32 {
+            let value = Value::from_str(&format!("{height}u64")).unwrap();
+            add_block(&finalize_store, program_id, mapping_name, height, &[(key.clone(), Some(value))]);
+        }
+
+        // Reopen the finalize store from its storage.
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        // Ensure the history mode and the recorded history are restored.
+        assert_eq!(finalize_store.history(), Some(FinalizeHistory::with_retention(3)));
+        assert_eq!(finalize_store.history_range().unwrap(), Some(0..=2));
+        let candidate = finalize_store.get_value_at_height(program_id, mapping_name, &key, 1).unwrap();
+        assert_eq!(candidate, Some(Value::from_str("1u64").unwrap()));
+    }
+
+    #[test]
+    fn test_history_disabled() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("0field").unwrap();
+
+        // Initialize a new finalize store, without history mode.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        assert!(finalize_store.history().is_none());
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+
+        // Update the key in several blocks.
+        for height in 0..5u32 {
+            let value = Value::from_str(&format!("{height}u64")).unwrap();
+            add_block(&finalize_store, program_id, mapping_name, height, &[(key.clone(), Some(value))]);
+        }
+
+        // Ensure nothing is written to the history maps.
+        assert_eq!(finalize_store.storage.key_history_map().iter_confirmed().count(), 0);
+        assert_eq!(finalize_store.storage.history_height_map().iter_confirmed().count(), 0);
+        assert_eq!(finalize_store.history_range().unwrap(), None);
+        // Ensure historical values cannot be retrieved.
+        assert!(finalize_store.get_value_at_height(program_id, mapping_name, &key, 4).is_err());
+    }
+
+    /// Applies a sequence of insert, update, and remove operations on a single key,
+    /// and returns the finalize operations, the resulting checksum, and the resulting finalize root.
+    fn replay_single_key<N: Network>(
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+    ) -> (Vec<FinalizeOperation<N>>, Field<N>, Field<N>) {
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+
+        let key = Plaintext::from_str("0field").unwrap();
+        let other_key = Plaintext::from_str("1field").unwrap();
+        let value = |value: u64| Value::from_str(&format!("{value}u64")).unwrap();
+
+        // Apply the operations, touching the same key multiple times.
+        let mut operations = vec![finalize_store.initialize_mapping(program_id, mapping_name).unwrap()];
+        operations.push(finalize_store.insert_key_value(program_id, mapping_name, key.clone(), value(1)).unwrap());
+        operations.push(finalize_store.update_key_value(program_id, mapping_name, key.clone(), value(2)).unwrap());
+        operations.push(finalize_store.update_key_value(program_id, mapping_name, key.clone(), value(3)).unwrap());
+        operations.push(finalize_store.insert_key_value(program_id, mapping_name, other_key, value(4)).unwrap());
+        operations.push(finalize_store.remove_key_value(program_id, mapping_name, &key).unwrap().unwrap());
+        operations.push(finalize_store.update_key_value(program_id, mapping_name, key.clone(), value(5)).unwrap());
+        operations.push(finalize_store.remove_key_value(program_id, mapping_name, &key).unwrap().unwrap());
+
+        // Compute the finalize root of the operations.
+        let leaves = operations.iter().map(ToBits::to_bits_le).collect::<Vec<_>>();
+        let finalize_root = *N::merkle_tree_bhp::<FINALIZE_OPERATIONS_DEPTH>(&leaves).unwrap().root();
+        // Return the operations, the checksum, and the finalize root.
+        (operations, finalize_store.get_checksum_confirmed().unwrap(), finalize_root)
+    }
+
+    #[test]
+    fn test_finalize_operations_deterministic() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Apply the same sequence of operations to two independent finalize stores.
+        let (expected_operations, expected_checksum, expected_root) = replay_single_key(program_id, mapping_name);
+        let (candidate_operations, candidate_checksum, candidate_root) = replay_single_key(program_id, mapping_name);
+
+        // Ensure the finalize operations are recorded in the order they were applied.
+        assert_eq!(expected_operations.len(), 8);
+        assert!(matches!(expected_operations[0], FinalizeOperation::InitializeMapping(..)));
+        assert!(matches!(expected_operations[1], FinalizeOperation::InsertKeyValue(..)));
+        assert!(matches!(expected_operations[2], FinalizeOperation::UpdateKeyValue(..)));
+        assert!(matches!(expected_operations[5], FinalizeOperation::RemoveKeyValue(..)));
+        assert!(matches!(expected_operations[7], FinalizeOperation::RemoveKeyValue(..)));
+        // Ensure repeated writes to the same key are distinguished by their values.
+        assert_ne!(expected_operations[2], expected_operations[3]);
+
+        // Ensure both stores produce the same finalize operations, checksum, and finalize root.
+        assert_eq!(expected_operations, candidate_operations);
+        assert_eq!(expected_checksum, candidate_checksum);
+        assert_eq!(expected_root, candidate_root);
+
+        // Ensure the checksum and finalize root match the pinned values.
+        // Note: A change to these values is a change to consensus, as the finalize root is included in the block header.
+        assert_eq!(expected_checksum.to_string(), "285320285696319960941907683633817425263840186875296215790128438906376843898field");
+        assert_eq!(expected_root.to_string(), "5909806397997867331297117897032906008829073158503032100884478043249045519696field");
+    }
 }
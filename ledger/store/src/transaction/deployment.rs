@@ -101,15 +101,15 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
     }
 
     /// Checkpoints the atomic batch.
-    fn atomic_checkpoint(&self) {
-        self.id_map().atomic_checkpoint();
-        self.edition_map().atomic_checkpoint();
-        self.reverse_id_map().atomic_checkpoint();
-        self.owner_map().atomic_checkpoint();
-        self.program_map().atomic_checkpoint();
-        self.verifying_key_map().atomic_checkpoint();
-        self.certificate_map().atomic_checkpoint();
-        self.fee_store().atomic_checkpoint();
+    fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        self.id_map().atomic_checkpoint(label);
+        self.edition_map().atomic_checkpoint(label);
+        self.reverse_id_map().atomic_checkpoint(label);
+        self.owner_map().atomic_checkpoint(label);
+        self.program_map().atomic_checkpoint(label);
+        self.verifying_key_map().atomic_checkpoint(label);
+        self.certificate_map().atomic_checkpoint(label);
+        self.fee_store().atomic_checkpoint(label);
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -523,8 +523,8 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
     }
 
     /// Checkpoints the atomic batch.
-    pub fn atomic_checkpoint(&self) {
-        self.storage.atomic_checkpoint();
+    pub fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        self.storage.atomic_checkpoint(label);
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -621,7 +621,7 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
 impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
     /// Returns `true` if the given program ID exists.
     pub fn contains_program_id(&self, program_id: &ProgramID<N>) -> Result<bool> {
-        self.storage.edition_map().contains_key_confirmed(program_id)
+        Ok(self.storage.edition_map().contains_key_confirmed(program_id)?)
     }
 }
 
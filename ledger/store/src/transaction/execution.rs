@@ -78,11 +78,11 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     }
 
     /// Checkpoints the atomic batch.
-    fn atomic_checkpoint(&self) {
-        self.id_map().atomic_checkpoint();
-        self.reverse_id_map().atomic_checkpoint();
-        self.inclusion_map().atomic_checkpoint();
-        self.fee_store().atomic_checkpoint();
+    fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        self.id_map().atomic_checkpoint(label);
+        self.reverse_id_map().atomic_checkpoint(label);
+        self.inclusion_map().atomic_checkpoint(label);
+        self.fee_store().atomic_checkpoint(label);
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -329,8 +329,8 @@ impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
     }
 
     /// Checkpoints the atomic batch.
-    pub fn atomic_checkpoint(&self) {
-        self.storage.atomic_checkpoint();
+    pub fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        self.storage.atomic_checkpoint(label);
     }
 
     /// Clears the latest atomic batch checkpoint.
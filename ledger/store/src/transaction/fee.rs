@@ -67,10 +67,10 @@ pub trait FeeStorage<N: Network>: Clone + Send + Sync {
     }
 
     /// Checkpoints the atomic batch.
-    fn atomic_checkpoint(&self) {
-        self.fee_map().atomic_checkpoint();
-        self.reverse_fee_map().atomic_checkpoint();
-        self.transition_store().atomic_checkpoint();
+    fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        self.fee_map().atomic_checkpoint(label);
+        self.reverse_fee_map().atomic_checkpoint(label);
+        self.transition_store().atomic_checkpoint(label);
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -211,8 +211,8 @@ impl<N: Network, F: FeeStorage<N>> FeeStore<N, F> {
     }
 
     /// Checkpoints the atomic batch.
-    pub fn atomic_checkpoint(&self) {
-        self.storage.atomic_checkpoint();
+    pub fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        self.storage.atomic_checkpoint(label);
     }
 
     /// Clears the latest atomic batch checkpoint.
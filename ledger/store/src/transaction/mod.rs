@@ -103,11 +103,11 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     }
 
     /// Checkpoints the atomic batch.
-    fn atomic_checkpoint(&self) {
-        self.id_map().atomic_checkpoint();
-        self.deployment_store().atomic_checkpoint();
-        self.execution_store().atomic_checkpoint();
-        self.fee_store().atomic_checkpoint();
+    fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        self.id_map().atomic_checkpoint(label);
+        self.deployment_store().atomic_checkpoint(label);
+        self.execution_store().atomic_checkpoint(label);
+        self.fee_store().atomic_checkpoint(label);
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -282,8 +282,8 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
     }
 
     /// Checkpoints the atomic batch.
-    pub fn atomic_checkpoint(&self) {
-        self.storage.atomic_checkpoint();
+    pub fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        self.storage.atomic_checkpoint(label);
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -426,7 +426,7 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
 impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
     /// Returns `true` if the given transaction ID exists.
     pub fn contains_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<bool> {
-        self.transaction_ids.contains_key_confirmed(transaction_id)
+        Ok(self.transaction_ids.contains_key_confirmed(transaction_id)?)
     }
 
     /// Returns `true` if the given program ID exists.
@@ -93,15 +93,15 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
     }
 
     /// Checkpoints the atomic batch.
-    fn atomic_checkpoint(&self) {
-        self.id_map().atomic_checkpoint();
-        self.reverse_id_map().atomic_checkpoint();
-        self.constant_map().atomic_checkpoint();
-        self.public_map().atomic_checkpoint();
-        self.private_map().atomic_checkpoint();
-        self.record_map().atomic_checkpoint();
-        self.record_tag_map().atomic_checkpoint();
-        self.external_record_map().atomic_checkpoint();
+    fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        self.id_map().atomic_checkpoint(label);
+        self.reverse_id_map().atomic_checkpoint(label);
+        self.constant_map().atomic_checkpoint(label);
+        self.public_map().atomic_checkpoint(label);
+        self.private_map().atomic_checkpoint(label);
+        self.record_map().atomic_checkpoint(label);
+        self.record_tag_map().atomic_checkpoint(label);
+        self.external_record_map().atomic_checkpoint(label);
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -149,7 +149,7 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
         self.private_map().finish_atomic()?;
         self.record_map().finish_atomic()?;
         self.record_tag_map().finish_atomic()?;
-        self.external_record_map().finish_atomic()
+        Ok(self.external_record_map().finish_atomic()?)
     }
 
     /// Stores the given `(transition ID, input)` pair into storage.
@@ -343,6 +343,18 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
         self.storage.remove(transition_id)
     }
 
+    /// Stores the given `(serial number, tag)` pairs of spent records into storage, without their inputs.
+    /// Note: This is only intended for the records spent in the blocks pruned below a ledger checkpoint.
+    pub fn insert_spent(&self, spent: &[(Field<N>, Field<N>)]) -> Result<()> {
+        atomic_batch_scope!(self, {
+            for (serial_number, tag) in spent {
+                self.record.insert(*serial_number, *tag)?;
+                self.record_tag.insert(*tag, *serial_number)?;
+            }
+            Ok(())
+        })
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -354,8 +366,8 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
     }
 
     /// Checkpoints the atomic batch.
-    pub fn atomic_checkpoint(&self) {
-        self.storage.atomic_checkpoint();
+    pub fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        self.storage.atomic_checkpoint(label);
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -406,17 +418,17 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
 impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
     /// Returns `true` if the given input ID exists.
     pub fn contains_input_id(&self, input_id: &Field<N>) -> Result<bool> {
-        self.storage.reverse_id_map().contains_key_confirmed(input_id)
+        Ok(self.storage.reverse_id_map().contains_key_confirmed(input_id)?)
     }
 
     /// Returns `true` if the given serial number exists.
     pub fn contains_serial_number(&self, serial_number: &Field<N>) -> Result<bool> {
-        self.record.contains_key_confirmed(serial_number)
+        Ok(self.record.contains_key_confirmed(serial_number)?)
     }
 
     /// Returns `true` if the given tag exists.
     pub fn contains_tag(&self, tag: &Field<N>) -> Result<bool> {
-        self.record_tag.contains_key_confirmed(tag)
+        Ok(self.record_tag.contains_key_confirmed(tag)?)
     }
 }
 
@@ -98,14 +98,14 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     }
 
     /// Checkpoints the atomic batch.
-    fn atomic_checkpoint(&self) {
-        self.locator_map().atomic_checkpoint();
-        self.input_store().atomic_checkpoint();
-        self.output_store().atomic_checkpoint();
-        self.tpk_map().atomic_checkpoint();
-        self.reverse_tpk_map().atomic_checkpoint();
-        self.tcm_map().atomic_checkpoint();
-        self.reverse_tcm_map().atomic_checkpoint();
+    fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        self.locator_map().atomic_checkpoint(label);
+        self.input_store().atomic_checkpoint(label);
+        self.output_store().atomic_checkpoint(label);
+        self.tpk_map().atomic_checkpoint(label);
+        self.reverse_tpk_map().atomic_checkpoint(label);
+        self.tcm_map().atomic_checkpoint(label);
+        self.reverse_tcm_map().atomic_checkpoint(label);
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -149,7 +149,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tpk_map().finish_atomic()?;
         self.reverse_tpk_map().finish_atomic()?;
         self.tcm_map().finish_atomic()?;
-        self.reverse_tcm_map().finish_atomic()
+        Ok(self.reverse_tcm_map().finish_atomic()?)
     }
 
     /// Stores the given `transition` into storage.
@@ -310,6 +310,12 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.storage.remove(transition_id)
     }
 
+    /// Stores the given `(serial number, tag)` pairs of spent records into storage, without their transitions.
+    /// Note: This is only intended for the records spent in the blocks pruned below a ledger checkpoint.
+    pub fn insert_spent(&self, spent: &[(Field<N>, Field<N>)]) -> Result<()> {
+        self.inputs.insert_spent(spent)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -321,8 +327,8 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
     }
 
     /// Checkpoints the atomic batch.
-    pub fn atomic_checkpoint(&self) {
-        self.storage.atomic_checkpoint();
+    pub fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        self.storage.atomic_checkpoint(label);
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -422,7 +428,7 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
 impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
     /// Returns `true` if the given transition ID exists.
     pub fn contains_transition_id(&self, transition_id: &N::TransitionID) -> Result<bool> {
-        self.locator.contains_key_confirmed(transition_id)
+        Ok(self.locator.contains_key_confirmed(transition_id)?)
     }
 
     /* Input */
@@ -468,12 +474,12 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
 
     /// Returns `true` if the given transition public key exists.
     pub fn contains_tpk(&self, tpk: &Group<N>) -> Result<bool> {
-        self.reverse_tpk.contains_key_confirmed(tpk)
+        Ok(self.reverse_tpk.contains_key_confirmed(tpk)?)
     }
 
     /// Returns `true` if the given transition commitment exists.
     pub fn contains_tcm(&self, tcm: &Field<N>) -> Result<bool> {
-        self.reverse_tcm.contains_key_confirmed(tcm)
+        Ok(self.reverse_tcm.contains_key_confirmed(tcm)?)
     }
 }
 
@@ -99,16 +99,16 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
     }
 
     /// Checkpoints the atomic batch.
-    fn atomic_checkpoint(&self) {
-        self.id_map().atomic_checkpoint();
-        self.reverse_id_map().atomic_checkpoint();
-        self.constant_map().atomic_checkpoint();
-        self.public_map().atomic_checkpoint();
-        self.private_map().atomic_checkpoint();
-        self.record_map().atomic_checkpoint();
-        self.record_nonce_map().atomic_checkpoint();
-        self.external_record_map().atomic_checkpoint();
-        self.future_map().atomic_checkpoint();
+    fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        self.id_map().atomic_checkpoint(label);
+        self.reverse_id_map().atomic_checkpoint(label);
+        self.constant_map().atomic_checkpoint(label);
+        self.public_map().atomic_checkpoint(label);
+        self.private_map().atomic_checkpoint(label);
+        self.record_map().atomic_checkpoint(label);
+        self.record_nonce_map().atomic_checkpoint(label);
+        self.external_record_map().atomic_checkpoint(label);
+        self.future_map().atomic_checkpoint(label);
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -160,7 +160,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.record_map().finish_atomic()?;
         self.record_nonce_map().finish_atomic()?;
         self.external_record_map().finish_atomic()?;
-        self.future_map().finish_atomic()
+        Ok(self.future_map().finish_atomic()?)
     }
 
     /// Stores the given `(transition ID, output)` pair into storage.
@@ -377,8 +377,8 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
     }
 
     /// Checkpoints the atomic batch.
-    pub fn atomic_checkpoint(&self) {
-        self.storage.atomic_checkpoint();
+    pub fn atomic_checkpoint(&self, label: Option<&'static str>) {
+        self.storage.atomic_checkpoint(label);
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -430,7 +430,7 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
             Ok(Some(Cow::Borrowed((_, None)))) => Ok(None),
             Ok(Some(Cow::Owned((_, None)))) => Ok(None),
             Ok(None) => bail!("Record '{commitment}' not found"),
-            Err(e) => Err(e),
+            Err(e) => Err(e.into()),
         }
     }
 }
@@ -445,12 +445,12 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
 impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
     /// Returns `true` if the given output ID exists.
     pub fn contains_output_id(&self, output_id: &Field<N>) -> Result<bool> {
-        self.storage.reverse_id_map().contains_key_confirmed(output_id)
+        Ok(self.storage.reverse_id_map().contains_key_confirmed(output_id)?)
     }
 
     /// Returns `true` if the given commitment exists.
     pub fn contains_commitment(&self, commitment: &Field<N>) -> Result<bool> {
-        self.record.contains_key_confirmed(commitment)
+        Ok(self.record.contains_key_confirmed(commitment)?)
     }
 
     /// Returns `true` if the given checksum exists.
@@ -460,7 +460,7 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
 
     /// Returns `true` if the given nonce exists.
     pub fn contains_nonce(&self, nonce: &Group<N>) -> Result<bool> {
-        self.record_nonce.contains_key_confirmed(nonce)
+        Ok(self.record_nonce.contains_key_confirmed(nonce)?)
     }
 }
 
@@ -68,9 +68,17 @@ macro_rules! impl_store_and_remote_fetch {
         }
 
         #[cfg(not(feature = "wasm"))]
-        fn remote_fetch(buffer: &mut Vec<u8>, url: &str) -> Result<(), $crate::errors::ParameterError> {
+        fn remote_fetch(
+            buffer: &mut Vec<u8>,
+            url: &str,
+            timeout: std::time::Duration,
+            progress: &dyn Fn(u64, u64),
+        ) -> Result<(), $crate::errors::ParameterError> {
             let mut easy = curl::easy::Easy::new();
             easy.follow_location(true)?;
+            easy.fail_on_error(true)?;
+            easy.connect_timeout(timeout)?;
+            easy.progress(true)?;
             easy.url(url)?;
 
             #[cfg(not(feature = "no_std_out"))]
@@ -79,9 +87,14 @@ macro_rules! impl_store_and_remote_fetch {
 
                 let output = format!("{:>15} - Downloading \"{}\"", "Installation", url);
                 println!("{}", output.dimmed());
+            }
+
+            let mut transfer = easy.transfer();
+            transfer.progress_function(|total_download, current_download, _, _| {
+                #[cfg(not(feature = "no_std_out"))]
+                {
+                    use colored::*;
 
-                easy.progress(true)?;
-                easy.progress_function(|total_download, current_download, _, _| {
                     let percent = (current_download / total_download) * 100.0;
                     let size_in_megabytes = total_download as u64 / 1_048_576;
                     let output = format!(
@@ -89,11 +102,12 @@ macro_rules! impl_store_and_remote_fetch {
                         "Installation", percent, size_in_megabytes
                     );
                     print!("{}", output.dimmed());
-                    true
-                })?;
-            }
+                }
 
-            let mut transfer = easy.transfer();
+                // Report the progress of the download.
+                progress(current_download as u64, total_download as u64);
+                true
+            })?;
             transfer.write_function(|data| {
                 buffer.extend_from_slice(data);
                 Ok(data.len())
@@ -167,7 +181,7 @@ macro_rules! impl_load_bytes_logic_local {
 }
 
 macro_rules! impl_load_bytes_logic_remote {
-    ($remote_url: expr, $local_dir: expr, $filename: expr, $metadata: expr, $expected_checksum: expr, $expected_size: expr) => {
+    ($config: expr, $progress: expr, $local_dir: expr, $filename: expr, $metadata: expr, $expected_checksum: expr, $expected_size: expr) => {
         // Compose the correct file path for the parameter file.
         let mut file_path = aleo_std::aleo_dir();
         file_path.push($local_dir);
@@ -188,20 +202,11 @@ macro_rules! impl_load_bytes_logic_remote {
                 );
             }
 
-            // Construct the URL.
-            let url = format!("{}/{}", $remote_url, $filename);
-
             // Load remote file
             cfg_if::cfg_if! {
                 if #[cfg(not(feature = "wasm"))] {
-                    let mut buffer = vec![];
-                    Self::remote_fetch(&mut buffer, &url)?;
-
-                    // Ensure the checksum matches.
-                    let candidate_checksum = checksum!(&buffer);
-                    if $expected_checksum != candidate_checksum {
-                        return checksum_error!($expected_checksum, candidate_checksum)
-                    }
+                    // Download the file, retrying as configured, and ensure the checksum matches.
+                    let buffer = $config.fetch($filename, &$expected_checksum, $progress, Self::remote_fetch)?;
 
                     match Self::store_bytes(&buffer, &file_path) {
                         Ok(()) => buffer,
@@ -214,7 +219,9 @@ macro_rules! impl_load_bytes_logic_remote {
                         }
                     }
                 } else if #[cfg(feature = "wasm")] {
-                    let buffer = Self::remote_fetch(&url)?;
+                    // Note: The progress of a download is not reported in WASM.
+                    let _ = $progress;
+                    let buffer = Self::remote_fetch(&$config.url_for($filename))?;
 
                     // Ensure the checksum matches.
                     let candidate_checksum = checksum!(&buffer);
@@ -317,7 +324,17 @@ macro_rules! impl_remote {
         impl $name {
             impl_store_and_remote_fetch!();
 
+            /// Loads the bytes, downloading them with the global `PowersConfig` if they are not stored locally.
             pub fn load_bytes() -> Result<Vec<u8>, $crate::errors::ParameterError> {
+                Self::load_bytes_with_config($crate::testnet3::PowersConfig::global(), &$crate::testnet3::NoProgress)
+            }
+
+            /// Loads the bytes, downloading them with the given `PowersConfig` if they are not stored locally,
+            /// and reporting the progress of the download to `progress`.
+            pub fn load_bytes_with_config(
+                config: &$crate::testnet3::PowersConfig,
+                progress: &dyn $crate::testnet3::ProgressReporter,
+            ) -> Result<Vec<u8>, $crate::errors::ParameterError> {
                 const METADATA: &'static str = include_str!(concat!($local_dir, $fname, ".metadata"));
 
                 let metadata: serde_json::Value =
@@ -334,7 +351,8 @@ macro_rules! impl_remote {
                 };
 
                 impl_load_bytes_logic_remote!(
-                    $remote_url,
+                    config,
+                    progress,
                     $local_dir,
                     &filename,
                     metadata,
@@ -373,8 +391,11 @@ macro_rules! impl_remote {
                     _ => format!("{}.{}", $fname, $ftype),
                 };
 
+                // The circuit keys are always downloaded from `$remote_url`, without retries.
+                let config = $crate::testnet3::PowersConfig { base_url: $remote_url.to_string(), ..Default::default() };
                 impl_load_bytes_logic_remote!(
-                    $remote_url,
+                    config,
+                    &$crate::testnet3::NoProgress,
                     $local_dir,
                     &filename,
                     metadata,
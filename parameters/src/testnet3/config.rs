@@ -0,0 +1,256 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::REMOTE_URL;
+#[cfg(not(feature = "wasm"))]
+use super::ProgressReporter;
+#[cfg(not(feature = "wasm"))]
+use crate::errors::ParameterError;
+
+use once_cell::sync::OnceCell;
+use std::time::Duration;
+
+/// The global configuration used to download the powers, if one was set.
+static GLOBAL_POWERS_CONFIG: OnceCell<PowersConfig> = OnceCell::new();
+
+/// The configuration used to download the powers of beta that are not bundled with the library.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PowersConfig {
+    /// The base URL that the power files are downloaded from.
+    pub base_url: String,
+    /// The number of times a failed download is retried.
+    pub max_retries: usize,
+    /// The timeout for establishing a connection to the base URL.
+    pub timeout: Duration,
+    /// The delay before the first retry, which doubles on every subsequent retry.
+    pub retry_backoff: Duration,
+}
+
+impl Default for PowersConfig {
+    /// Returns the default configuration, which downloads from the Aleo parameters bucket without retries.
+    fn default() -> Self {
+        Self {
+            base_url: REMOTE_URL.to_string(),
+            max_retries: 0,
+            timeout: Duration::from_secs(300),
+            retry_backoff: Duration::from_secs(1),
+        }
+    }
+}
+
+impl PowersConfig {
+    /// Sets the global configuration used to download the powers.
+    /// Returns the given configuration as an error if the global configuration was already set.
+    pub fn set_global(config: PowersConfig) -> Result<(), PowersConfig> {
+        GLOBAL_POWERS_CONFIG.set(config)
+    }
+
+    /// Returns the global configuration used to download the powers.
+    /// If no global configuration was set, the default configuration is used from now on.
+    pub fn global() -> &'static PowersConfig {
+        GLOBAL_POWERS_CONFIG.get_or_init(PowersConfig::default)
+    }
+
+    /// Returns the URL of the given file.
+    pub fn url_for(&self, filename: &str) -> String {
+        format!("{}/{}", self.base_url.trim_end_matches('/'), filename)
+    }
+
+    /// Downloads the given file with `remote_fetch`, and ensures its checksum matches the expected checksum.
+    /// A failed download, or a download with a mismatched checksum, is retried up to `max_retries` times.
+    /// The progress of each download is reported to `progress`, labeled with the filename.
+    #[cfg(not(feature = "wasm"))]
+    pub(crate) fn fetch(
+        &self,
+        filename: &str,
+        expected_checksum: &str,
+        progress: &dyn ProgressReporter,
+        remote_fetch: impl Fn(&mut Vec<u8>, &str, Duration, &dyn Fn(u64, u64)) -> Result<(), ParameterError>,
+    ) -> Result<Vec<u8>, ParameterError> {
+        let url = self.url_for(filename);
+        let report = |downloaded_bytes, total_bytes| progress.on_progress(downloaded_bytes, total_bytes, filename);
+
+        let mut backoff = self.retry_backoff;
+        let mut attempt = 0;
+        loop {
+            let mut buffer = vec![];
+            let result = remote_fetch(&mut buffer, &url, self.timeout, &report).and_then(|()| {
+                // Ensure the checksum matches.
+                let candidate_checksum = checksum!(&buffer);
+                match expected_checksum == candidate_checksum {
+                    true => Ok(buffer),
+                    false => checksum_error!(expected_checksum.to_string(), candidate_checksum),
+                }
+            });
+
+            match result {
+                Err(error) if attempt < self.max_retries => {
+                    attempt += 1;
+                    #[cfg(not(feature = "no_std_out"))]
+                    eprintln!(
+                        "\n⚠️  Failed to download \"{filename}\" ({error}). Retrying in {backoff:?} ({attempt}/{}).\n",
+                        self.max_retries
+                    );
+                    #[cfg(feature = "no_std_out")]
+                    let _ = error;
+                    std::thread::sleep(backoff);
+                    backoff = backoff.saturating_mul(2);
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+#[cfg(all(test, not(feature = "wasm")))]
+mod tests {
+    use super::*;
+    use crate::testnet3::{Degree17, NoProgress, ProgressReporter};
+
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        thread,
+        time::Instant,
+    };
+
+    /// Starts a local HTTP server that answers the `i`-th request with `responses[i]`,
+    /// and returns its base URL along with the paths of the requests it received.
+    fn serve(responses: Vec<(u16, &'static [u8])>) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let paths = Arc::new(Mutex::new(Vec::new()));
+
+        let received = paths.clone();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                // Read the request line and headers.
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                received.lock().unwrap().push(request_line.split_whitespace().nth(1).unwrap().to_string());
+                let mut header = String::new();
+                while reader.read_line(&mut header).unwrap() > 2 {
+                    header.clear();
+                }
+                // Write the response.
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    body.len()
+                )
+                .unwrap();
+                stream.write_all(body).unwrap();
+            }
+        });
+
+        (base_url, paths)
+    }
+
+    fn sample_config(base_url: String, max_retries: usize) -> PowersConfig {
+        PowersConfig {
+            base_url,
+            max_retries,
+            timeout: Duration::from_secs(5),
+            retry_backoff: Duration::from_millis(50),
+        }
+    }
+
+    #[test]
+    fn test_fetch_honors_base_url() {
+        let body: &'static [u8] = b"powers";
+        let (base_url, paths) = serve(vec![(200, body)]);
+        let config = sample_config(format!("{base_url}/custom/"), 0);
+
+        let bytes =
+            config.fetch("powers-of-beta-17.usrs", &checksum!(body), &NoProgress, Degree17::remote_fetch).unwrap();
+        assert_eq!(bytes, body);
+        assert_eq!(*paths.lock().unwrap(), vec!["/custom/powers-of-beta-17.usrs".to_string()]);
+    }
+
+    #[test]
+    fn test_fetch_retries_with_backoff() {
+        let body: &'static [u8] = b"powers";
+        let (base_url, paths) = serve(vec![(500, b""), (503, b""), (200, body)]);
+        let config = sample_config(base_url, 2);
+
+        let timer = Instant::now();
+        let bytes =
+            config.fetch("powers-of-beta-17.usrs", &checksum!(body), &NoProgress, Degree17::remote_fetch).unwrap();
+        assert_eq!(bytes, body);
+        // The retries wait for 50ms, and then for 100ms.
+        assert!(timer.elapsed() >= Duration::from_millis(150));
+        assert_eq!(paths.lock().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_fetch_fails_after_max_retries() {
+        let (base_url, paths) = serve(vec![(500, b""), (500, b""), (500, b"")]);
+        let config = sample_config(base_url, 1);
+
+        assert!(config
+            .fetch("powers-of-beta-17.usrs", &checksum!(b"powers"), &NoProgress, Degree17::remote_fetch)
+            .is_err());
+        assert_eq!(paths.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_fetch_reports_progress() {
+        /// A progress reporter that records every call.
+        #[derive(Default)]
+        struct CountingReporter(Mutex<Vec<(u64, u64, String)>>);
+
+        impl ProgressReporter for CountingReporter {
+            fn on_progress(&self, downloaded_bytes: u64, total_bytes: u64, label: &str) {
+                self.0.lock().unwrap().push((downloaded_bytes, total_bytes, label.to_string()));
+            }
+        }
+
+        let body: &'static [u8] = Box::leak(vec![7u8; 1 << 20].into_boxed_slice());
+        let (base_url, _) = serve(vec![(200, body)]);
+        let config = sample_config(base_url, 0);
+
+        let reporter = CountingReporter::default();
+        config.fetch("powers-of-beta-17.usrs", &checksum!(body), &reporter, Degree17::remote_fetch).unwrap();
+
+        // Ensure the reporter was called with monotonically increasing byte counts, up to the full download.
+        let calls = reporter.0.into_inner().unwrap();
+        assert!(!calls.is_empty());
+        assert!(calls.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+        assert_eq!(calls.last().unwrap().0, body.len() as u64);
+        assert!(calls.iter().all(|(downloaded, total, label)| {
+            label == "powers-of-beta-17.usrs" && (*total == 0 || downloaded <= total)
+        }));
+    }
+
+    #[test]
+    fn test_fetch_verifies_checksum() {
+        let (base_url, paths) = serve(vec![(200, b"corrupted"), (200, b"powers")]);
+        let config = sample_config(base_url, 1);
+
+        // A download with a mismatched checksum is retried.
+        let bytes =
+            config.fetch("powers-of-beta-17.usrs", &checksum!(b"powers"), &NoProgress, Degree17::remote_fetch).unwrap();
+        assert_eq!(bytes, b"powers");
+        assert_eq!(paths.lock().unwrap().len(), 2);
+
+        // Once the retries are exhausted, the mismatch is returned.
+        let (base_url, _) = serve(vec![(200, b"corrupted")]);
+        let config = sample_config(base_url, 0);
+        let result = config.fetch("powers-of-beta-17.usrs", &checksum!(b"powers"), &NoProgress, Degree17::remote_fetch);
+        assert!(matches!(result, Err(ParameterError::ChecksumMismatch(..))));
+    }
+}
@@ -12,12 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod config;
+pub use config::*;
+
 pub mod genesis;
 pub use genesis::*;
 
 pub mod powers;
 pub use powers::*;
 
+pub mod progress;
+pub use progress::*;
+
 const REMOTE_URL: &str = "https://s3-us-west-1.amazonaws.com/testnet3.parameters";
 
 // Degrees
@@ -73,13 +73,60 @@ pub struct PowersOfG<E: PairingEngine> {
     prepared_negative_powers_of_beta_h: Arc<BTreeMap<usize, <E::G2Affine as PairingCurve>::Prepared>>,
     /// beta * h
     beta_h: E::G2Affine,
+    /// If `true`, missing powers are never downloaded, and requesting them returns an error instead.
+    offline: bool,
+    /// The configuration used to download missing powers.
+    config: PowersConfig,
 }
 
 impl<E: PairingEngine> PowersOfG<E> {
-    /// Initializes the hard-coded instance of the powers.
+    /// Initializes the hard-coded instance of the powers, downloading missing powers with the global `PowersConfig`.
     pub fn load() -> Result<Self> {
+        Self::load_with_config(PowersConfig::global().clone())
+    }
+
+    /// Initializes the hard-coded instance of the powers, downloading missing powers with the given `config`.
+    pub fn load_with_config(config: PowersConfig) -> Result<Self> {
         let powers_of_beta_g = PowersOfBetaG::load()?;
+        let beta_h = E::G2Affine::deserialize_uncompressed_unchecked(&**BETA_H)?;
+        Self::from_parts(powers_of_beta_g, beta_h, config)
+    }
 
+    /// Initializes the hard-coded instance of the powers, extended with the given bytes of the
+    /// `powers-of-beta-16` and `shifted-powers-of-beta-16` power files, and with the given bytes of `beta-h`.
+    /// This does not download any powers, which allows a host that cannot block on a download
+    /// (e.g. a browser) to fetch the power files itself.
+    ///
+    /// The group elements in the given bytes are checked to be valid.
+    pub fn from_bytes(powers_16: &[u8], shifted_powers_16: &[u8], beta_h: &[u8]) -> Result<Self> {
+        Self::from_bytes_with_mode(powers_16, shifted_powers_16, beta_h, Validate::Yes)
+    }
+
+    /// Initializes the hard-coded instance of the powers, extended with the given bytes of the
+    /// `powers-of-beta-16` and `shifted-powers-of-beta-16` power files, and with the given bytes of `beta-h`.
+    ///
+    /// The group elements in the given bytes are **not** checked to be valid,
+    /// so the bytes must come from a trusted source.
+    pub fn from_bytes_unchecked(powers_16: &[u8], shifted_powers_16: &[u8], beta_h: &[u8]) -> Result<Self> {
+        Self::from_bytes_with_mode(powers_16, shifted_powers_16, beta_h, Validate::No)
+    }
+
+    /// Initializes the powers from the given bytes, validating the group elements as specified by `validate`.
+    fn from_bytes_with_mode(
+        powers_16: &[u8],
+        shifted_powers_16: &[u8],
+        beta_h: &[u8],
+        validate: Validate,
+    ) -> Result<Self> {
+        let mut powers_of_beta_g = PowersOfBetaG::load()?;
+        powers_of_beta_g.extend_powers_from_bytes(NUM_POWERS_16, powers_16, validate)?;
+        powers_of_beta_g.extend_shifted_powers_from_bytes(NUM_POWERS_16, shifted_powers_16, validate)?;
+        let beta_h = E::G2Affine::deserialize_with_mode(beta_h, Compress::No, validate)?;
+        Self::from_parts(powers_of_beta_g, beta_h, PowersConfig::global().clone())
+    }
+
+    /// Initializes the powers from the given powers of beta G and beta H, and the hard-coded remaining powers.
+    fn from_parts(powers_of_beta_g: PowersOfBetaG<E>, beta_h: E::G2Affine, config: PowersConfig) -> Result<Self> {
         // Reconstruct powers of beta_times_gamma_g.
         let powers_of_beta_times_gamma_g =
             Arc::new(BTreeMap::deserialize_uncompressed_unchecked(&**POWERS_OF_BETA_GAMMA_G)?);
@@ -92,8 +139,6 @@ impl<E: PairingEngine> PowersOfG<E> {
         let prepared_negative_powers_of_beta_h: Arc<BTreeMap<usize, <E::G2Affine as PairingCurve>::Prepared>> =
             Arc::new(negative_powers_of_beta_h.iter().map(|(d, affine)| (*d, affine.prepare())).collect());
 
-        let beta_h = E::G2Affine::deserialize_uncompressed_unchecked(&**BETA_H)?;
-
         // Return the powers.
         Ok(Self {
             powers_of_beta_g,
@@ -101,12 +146,64 @@ impl<E: PairingEngine> PowersOfG<E> {
             negative_powers_of_beta_h,
             prepared_negative_powers_of_beta_h,
             beta_h,
+            offline: false,
+            config,
         })
     }
 
     /// Download the powers of beta G specified by `range`.
     pub fn download_powers_for(&mut self, range: Range<usize>) -> Result<()> {
-        self.powers_of_beta_g.download_powers_for(&range)
+        self.download_powers_for_with_progress(range, &NoProgress)
+    }
+
+    /// Download the powers of beta G specified by `range`, reporting the progress to `progress`.
+    pub fn download_powers_for_with_progress(
+        &mut self,
+        range: Range<usize>,
+        progress: &dyn ProgressReporter,
+    ) -> Result<()> {
+        self.ensure_available(&range)?;
+        self.powers_of_beta_g.download_powers_for(&range, &self.config, progress)
+    }
+
+    /// Returns `true` if the powers of beta G specified by `range` are available without a download.
+    pub fn contains_powers_for(&self, range: &Range<usize>) -> bool {
+        range.is_empty() || self.powers_of_beta_g.contains_powers(range)
+    }
+
+    /// Returns `true` if the powers are in offline mode.
+    pub const fn is_offline(&self) -> bool {
+        self.offline
+    }
+
+    /// Returns the configuration used to download missing powers.
+    pub const fn config(&self) -> &PowersConfig {
+        &self.config
+    }
+
+    /// Sets the configuration used to download missing powers.
+    pub fn set_config(&mut self, config: PowersConfig) {
+        self.config = config;
+    }
+
+    /// Sets the offline mode. In offline mode, any request for powers that are not
+    /// already available returns an error listing the missing power files, instead of downloading them.
+    pub fn set_offline(&mut self, offline: bool) {
+        self.offline = offline;
+    }
+
+    /// In offline mode, ensures the powers of beta G specified by `range` are available without a download.
+    fn ensure_available(&self, range: &Range<usize>) -> Result<()> {
+        if self.offline && !self.contains_powers_for(range) {
+            let missing_files = self.powers_of_beta_g.missing_files_for(range)?;
+            bail!(
+                "Powers {}..{} are not available in offline mode (missing power files: {})",
+                range.start,
+                range.end,
+                missing_files.join(", ")
+            );
+        }
+        Ok(())
     }
 
     /// Returns the number of contiguous powers of beta G starting from the 0-th power.
@@ -126,12 +223,14 @@ impl<E: PairingEngine> PowersOfG<E> {
 
     /// Returns the `index`-th power of beta * G.
     pub fn power_of_beta_g(&mut self, index: usize) -> Result<E::G1Affine> {
-        self.powers_of_beta_g.power(index)
+        self.ensure_available(&(index..index.saturating_add(1)))?;
+        self.powers_of_beta_g.power(index, &self.config)
     }
 
     /// Returns the powers of `beta * G` that lie within `range`.
     pub fn powers_of_beta_g(&mut self, range: Range<usize>) -> Result<&[E::G1Affine]> {
-        self.powers_of_beta_g.powers(range)
+        self.ensure_available(&range)?;
+        self.powers_of_beta_g.powers(range, &self.config)
     }
 
     pub fn negative_powers_of_beta_h(&self) -> Arc<BTreeMap<usize, E::G2Affine>> {
@@ -192,6 +291,8 @@ impl<E: PairingEngine> CanonicalDeserialize for PowersOfG<E> {
             negative_powers_of_beta_h,
             prepared_negative_powers_of_beta_h,
             beta_h,
+            offline: false,
+            config: PowersConfig::global().clone(),
         };
         if let Validate::Yes = validate {
             powers.check()?;
@@ -252,6 +353,39 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
         Ok(PowersOfBetaG { powers_of_beta_g, shifted_powers_of_beta_g })
     }
 
+    /// Extends the powers of beta G with the bytes of the `powers-of-beta-{log2(num_powers)}` power file,
+    /// which contains the powers from `num_powers / 2` up to `num_powers`.
+    fn extend_powers_from_bytes(&mut self, num_powers: usize, bytes: &[u8], validate: Validate) -> Result<()> {
+        ensure!(
+            self.powers_of_beta_g.len() * 2 == num_powers,
+            "Cannot extend {} powers with the powers up to {num_powers}",
+            self.powers_of_beta_g.len()
+        );
+        // Deserialize the group elements.
+        let additional_powers: Vec<E::G1Affine> = Vec::deserialize_with_mode(bytes, Compress::No, validate)?;
+        ensure!(additional_powers.len() == num_powers / 2, "Loaded an incorrect number of powers");
+        // Extend the powers.
+        self.powers_of_beta_g.extend(additional_powers);
+        Ok(())
+    }
+
+    /// Extends the shifted powers of beta G with the bytes of the `shifted-powers-of-beta-{log2(num_powers)}`
+    /// power file, which contains the `num_powers / 2` shifted powers preceding the existing shifted powers.
+    fn extend_shifted_powers_from_bytes(&mut self, num_powers: usize, bytes: &[u8], validate: Validate) -> Result<()> {
+        ensure!(
+            self.shifted_powers_of_beta_g.len() * 2 == num_powers,
+            "Cannot extend {} shifted powers with the shifted powers up to {num_powers}",
+            self.shifted_powers_of_beta_g.len()
+        );
+        // Deserialize the group elements.
+        let mut additional_powers: Vec<E::G1Affine> = Vec::deserialize_with_mode(bytes, Compress::No, validate)?;
+        ensure!(additional_powers.len() == num_powers / 2, "Loaded an incorrect number of shifted powers");
+        // Prepend the shifted powers, as they precede the existing shifted powers.
+        additional_powers.extend(self.shifted_powers_of_beta_g.iter());
+        self.shifted_powers_of_beta_g = additional_powers;
+        Ok(())
+    }
+
     /// Returns the range of powers of beta G.
     /// In detail, it returns the range of the available "normal" powers of beta G, i.e. the
     /// contiguous range of powers of beta G starting from G, and, the range of shifted_powers.
@@ -320,12 +454,12 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
     }
 
     /// Returns the power of beta times G specified by `target`.
-    fn power(&mut self, target: usize) -> Result<E::G1Affine> {
-        self.powers(target..(target + 1)).map(|s| s[0])
+    fn power(&mut self, target: usize, config: &PowersConfig) -> Result<E::G1Affine> {
+        self.powers(target..(target + 1), config).map(|s| s[0])
     }
 
     /// Slices the underlying file to return a vector of affine elements between `lower` and `upper`.
-    fn powers(&mut self, range: Range<usize>) -> Result<&[E::G1Affine]> {
+    fn powers(&mut self, range: Range<usize>, config: &PowersConfig) -> Result<&[E::G1Affine]> {
         if range.is_empty() {
             return Ok(&self.powers_of_beta_g[0..0]);
         }
@@ -333,7 +467,7 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
         ensure!(range.end <= MAX_NUM_POWERS, "Upper bound must be less than the maximum number of powers");
         if !self.contains_powers(&range) {
             // We must download the powers.
-            self.download_powers_for(&range)?;
+            self.download_powers_for(&range, config, &NoProgress)?;
         }
         match self.contains_in_normal_powers(&range) {
             true => self.normal_powers(range),
@@ -341,7 +475,12 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
         }
     }
 
-    pub fn download_powers_for(&mut self, range: &Range<usize>) -> Result<()> {
+    pub fn download_powers_for(
+        &mut self,
+        range: &Range<usize>,
+        config: &PowersConfig,
+        progress: &dyn ProgressReporter,
+    ) -> Result<()> {
         if self.contains_in_normal_powers(range) || self.contains_in_shifted_powers(range) {
             return Ok(());
         }
@@ -349,21 +488,37 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
         if (range.start <= half_max) && (range.end > half_max) {
             // If the range contains the midpoint, then we must download all the powers.
             // (because we round up to the next power of two).
-            self.download_powers_up_to(range.end)?;
+            self.download_powers_up_to(range.end, config, progress)?;
             self.shifted_powers_of_beta_g = Vec::new();
         } else if self.distance_from_shifted_of(range) < self.distance_from_normal_of(range) {
             // If the range is closer to the shifted powers, then we download the shifted powers.
-            self.download_shifted_powers_from(range.start)?;
+            self.download_shifted_powers_from(range.start, config, progress)?;
         } else {
             // Otherwise, we download the normal powers.
-            self.download_powers_up_to(range.end)?;
+            self.download_powers_up_to(range.end, config, progress)?;
         }
         Ok(())
     }
 
-    /// This method downloads the universal SRS powers up to the `next_power_of_two(target_degree)`,
-    /// and updates `Self` in place with the new powers.
-    fn download_powers_up_to(&mut self, end: usize) -> Result<()> {
+    /// Returns the names of the power files that must be downloaded for `self` to contain `range`.
+    /// This mirrors the choice of powers made by `download_powers_for`.
+    pub fn missing_files_for(&self, range: &Range<usize>) -> Result<Vec<String>> {
+        if self.contains_in_normal_powers(range) || self.contains_in_shifted_powers(range) {
+            return Ok(Vec::new());
+        }
+        let half_max = MAX_NUM_POWERS / 2;
+        let (prefix, download_queue) = if (range.start <= half_max) && (range.end > half_max) {
+            ("powers-of-beta", self.download_queue_up_to(range.end)?)
+        } else if self.distance_from_shifted_of(range) < self.distance_from_normal_of(range) {
+            ("shifted-powers-of-beta", self.shifted_download_queue_from(range.start)?.1)
+        } else {
+            ("powers-of-beta", self.download_queue_up_to(range.end)?)
+        };
+        Ok(download_queue.iter().map(|num_powers| format!("{prefix}-{}", num_powers.trailing_zeros())).collect())
+    }
+
+    /// Returns the numbers of powers to download, for the normal powers to contain the powers up to `end`.
+    fn download_queue_up_to(&self, end: usize) -> Result<Vec<usize>> {
         // Determine the new power of two.
         let final_power_of_two =
             end.checked_next_power_of_two().ok_or_else(|| anyhow!("Requesting too many powers"))?;
@@ -390,6 +545,23 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
         }
         ensure!(final_power_of_two * 2 == accumulator, "Ensure the loop terminates at the right power of two");
 
+        Ok(download_queue)
+    }
+
+    /// This method downloads the universal SRS powers up to the `next_power_of_two(target_degree)`,
+    /// and updates `Self` in place with the new powers.
+    fn download_powers_up_to(
+        &mut self,
+        end: usize,
+        config: &PowersConfig,
+        progress: &dyn ProgressReporter,
+    ) -> Result<()> {
+        // Determine the powers of two to download.
+        let download_queue = self.download_queue_up_to(end)?;
+        // Determine the new power of two.
+        let final_power_of_two =
+            end.checked_next_power_of_two().ok_or_else(|| anyhow!("Requesting too many powers"))?;
+
         // Reserve capacity for the new powers of two.
         let additional_size = final_power_of_two
             .checked_sub(self.powers_of_beta_g.len())
@@ -404,34 +576,35 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
             // Download the universal SRS powers if they're not already on disk.
             let additional_bytes = match *num_powers {
                 NUM_POWERS_16 => Degree16::load_bytes()?,
-                NUM_POWERS_17 => Degree17::load_bytes()?,
-                NUM_POWERS_18 => Degree18::load_bytes()?,
-                NUM_POWERS_19 => Degree19::load_bytes()?,
-                NUM_POWERS_20 => Degree20::load_bytes()?,
-                NUM_POWERS_21 => Degree21::load_bytes()?,
-                NUM_POWERS_22 => Degree22::load_bytes()?,
-                NUM_POWERS_23 => Degree23::load_bytes()?,
-                NUM_POWERS_24 => Degree24::load_bytes()?,
-                NUM_POWERS_25 => Degree25::load_bytes()?,
-                NUM_POWERS_26 => Degree26::load_bytes()?,
-                NUM_POWERS_27 => Degree27::load_bytes()?,
-                NUM_POWERS_28 => Degree28::load_bytes()?,
+                NUM_POWERS_17 => Degree17::load_bytes_with_config(config, progress)?,
+                NUM_POWERS_18 => Degree18::load_bytes_with_config(config, progress)?,
+                NUM_POWERS_19 => Degree19::load_bytes_with_config(config, progress)?,
+                NUM_POWERS_20 => Degree20::load_bytes_with_config(config, progress)?,
+                NUM_POWERS_21 => Degree21::load_bytes_with_config(config, progress)?,
+                NUM_POWERS_22 => Degree22::load_bytes_with_config(config, progress)?,
+                NUM_POWERS_23 => Degree23::load_bytes_with_config(config, progress)?,
+                NUM_POWERS_24 => Degree24::load_bytes_with_config(config, progress)?,
+                NUM_POWERS_25 => Degree25::load_bytes_with_config(config, progress)?,
+                NUM_POWERS_26 => Degree26::load_bytes_with_config(config, progress)?,
+                NUM_POWERS_27 => Degree27::load_bytes_with_config(config, progress)?,
+                NUM_POWERS_28 => Degree28::load_bytes_with_config(config, progress)?,
                 _ => bail!("Cannot download an invalid degree of '{num_powers}'"),
             };
 
-            // Deserialize the group elements.
-            let additional_powers = Vec::deserialize_uncompressed_unchecked(&*additional_bytes)?;
+            // Report the power file as loaded.
+            let num_bytes = additional_bytes.len() as u64;
+            progress.on_progress(num_bytes, num_bytes, &format!("powers-of-beta-{}", num_powers.trailing_zeros()));
+
             // Extend the powers.
-            self.powers_of_beta_g.extend(&additional_powers);
+            self.extend_powers_from_bytes(*num_powers, &additional_bytes, Validate::No)?;
         }
         ensure!(self.powers_of_beta_g.len() == final_power_of_two, "Loaded an incorrect number of powers");
         Ok(())
     }
 
-    /// This method downloads the universal SRS powers from
-    /// `start` up to `MAXIMUM_NUM_POWERS - self.shifted_powers_of_beta_g.len()`,
-    /// and updates `Self` in place with the new powers.
-    fn download_shifted_powers_from(&mut self, start: usize) -> Result<()> {
+    /// Returns the final number of shifted powers, and the numbers of shifted powers to download,
+    /// for the shifted powers to contain the powers from `start`.
+    fn shifted_download_queue_from(&self, start: usize) -> Result<(usize, Vec<usize>)> {
         // Ensure the total number of powers is less than the maximum number of powers.
         ensure!(start <= MAX_NUM_POWERS, "Requesting more powers than exist in the SRS");
 
@@ -473,40 +646,56 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
         }
         download_queue.reverse(); // We want to download starting from the smallest power.
 
-        let mut final_powers = Vec::with_capacity(final_num_powers);
+        Ok((final_num_powers, download_queue))
+    }
+
+    /// This method downloads the universal SRS powers from
+    /// `start` up to `MAXIMUM_NUM_POWERS - self.shifted_powers_of_beta_g.len()`,
+    /// and updates `Self` in place with the new powers.
+    fn download_shifted_powers_from(
+        &mut self,
+        start: usize,
+        config: &PowersConfig,
+        progress: &dyn ProgressReporter,
+    ) -> Result<()> {
+        // Determine the numbers of shifted powers to download.
+        let (final_num_powers, download_queue) = self.shifted_download_queue_from(start)?;
+
         // If the `target_degree` exceeds the current `degree`, proceed to download the new powers.
-        for num_powers in &download_queue {
+        // Note: Each file of shifted powers precedes the existing shifted powers,
+        // so the files are prepended starting from the one with the fewest powers.
+        for num_powers in download_queue.iter().rev() {
             #[cfg(debug_assertions)]
             println!("Loading {num_powers} shifted powers");
 
             // Download the universal SRS powers if they're not already on disk.
             let additional_bytes = match *num_powers {
-                NUM_POWERS_16 => ShiftedDegree16::load_bytes()?,
-                NUM_POWERS_17 => ShiftedDegree17::load_bytes()?,
-                NUM_POWERS_18 => ShiftedDegree18::load_bytes()?,
-                NUM_POWERS_19 => ShiftedDegree19::load_bytes()?,
-                NUM_POWERS_20 => ShiftedDegree20::load_bytes()?,
-                NUM_POWERS_21 => ShiftedDegree21::load_bytes()?,
-                NUM_POWERS_22 => ShiftedDegree22::load_bytes()?,
-                NUM_POWERS_23 => ShiftedDegree23::load_bytes()?,
-                NUM_POWERS_24 => ShiftedDegree24::load_bytes()?,
-                NUM_POWERS_25 => ShiftedDegree25::load_bytes()?,
-                NUM_POWERS_26 => ShiftedDegree26::load_bytes()?,
-                NUM_POWERS_27 => ShiftedDegree27::load_bytes()?,
+                NUM_POWERS_16 => ShiftedDegree16::load_bytes_with_config(config, progress)?,
+                NUM_POWERS_17 => ShiftedDegree17::load_bytes_with_config(config, progress)?,
+                NUM_POWERS_18 => ShiftedDegree18::load_bytes_with_config(config, progress)?,
+                NUM_POWERS_19 => ShiftedDegree19::load_bytes_with_config(config, progress)?,
+                NUM_POWERS_20 => ShiftedDegree20::load_bytes_with_config(config, progress)?,
+                NUM_POWERS_21 => ShiftedDegree21::load_bytes_with_config(config, progress)?,
+                NUM_POWERS_22 => ShiftedDegree22::load_bytes_with_config(config, progress)?,
+                NUM_POWERS_23 => ShiftedDegree23::load_bytes_with_config(config, progress)?,
+                NUM_POWERS_24 => ShiftedDegree24::load_bytes_with_config(config, progress)?,
+                NUM_POWERS_25 => ShiftedDegree25::load_bytes_with_config(config, progress)?,
+                NUM_POWERS_26 => ShiftedDegree26::load_bytes_with_config(config, progress)?,
+                NUM_POWERS_27 => ShiftedDegree27::load_bytes_with_config(config, progress)?,
                 _ => bail!("Cannot download an invalid degree of '{num_powers}'"),
             };
 
-            // Deserialize the group elements.
-            let additional_powers = Vec::deserialize_uncompressed_unchecked(&*additional_bytes)?;
+            // Report the power file as loaded.
+            let num_bytes = additional_bytes.len() as u64;
+            progress.on_progress(
+                num_bytes,
+                num_bytes,
+                &format!("shifted-powers-of-beta-{}", num_powers.trailing_zeros()),
+            );
 
-            if final_powers.is_empty() {
-                final_powers = additional_powers;
-            } else {
-                final_powers.extend(additional_powers);
-            }
+            // Extend the shifted powers.
+            self.extend_shifted_powers_from_bytes(*num_powers, &additional_bytes, Validate::No)?;
         }
-        final_powers.extend(self.shifted_powers_of_beta_g.iter());
-        self.shifted_powers_of_beta_g = final_powers;
 
         ensure!(
             self.shifted_powers_of_beta_g.len() == final_num_powers,
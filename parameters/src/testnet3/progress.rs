@@ -0,0 +1,29 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// A reporter for the progress of the power downloads, e.g. to render a progress bar.
+pub trait ProgressReporter: Send + Sync {
+    /// Reports that `downloaded_bytes` out of `total_bytes` of the file `label` are available.
+    /// This is invoked for each chunk of a download, and once for each power file that is loaded.
+    /// Note: `total_bytes` may be `0` before the size of a download is known.
+    fn on_progress(&self, downloaded_bytes: u64, total_bytes: u64, label: &str) {
+        let _ = (downloaded_bytes, total_bytes, label);
+    }
+}
+
+/// A progress reporter that ignores all progress.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct NoProgress;
+
+impl ProgressReporter for NoProgress {}
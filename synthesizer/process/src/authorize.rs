@@ -26,7 +26,38 @@ impl<N: Network> Process<N> {
         rng: &mut R,
     ) -> Result<Authorization<N>> {
         // Authorize the call.
-        self.get_stack(program_id)?.authorize::<A, R>(private_key, function_name, inputs, rng)
+        let authorization = self.get_stack(program_id)?.authorize::<A, R>(private_key, function_name, inputs, rng)?;
+        // Ensure the signed requests are valid.
+        self.ensure_signed_requests_are_valid(&Vec::from(authorization.to_vec_deque()))?;
+        // Return the authorization.
+        Ok(authorization)
+    }
+
+    /// Constructs an authorization from the given signed requests, where the first request is the
+    /// top-level call, and the remaining requests are its nested calls, in the order they are invoked.
+    ///
+    /// This allows the requests to be signed out-of-band, where the signer derives the nonce of each request
+    /// (see `Request::commit_signer`), and signs the message of its unsigned request (see `UnsignedRequest`).
+    /// Each request signature is verified, and the requests are checked against the call chain
+    /// of the top-level function, before the authorization is constructed.
+    ///
+    /// Note: The transitions of the authorization are only produced once it is executed.
+    #[inline]
+    pub fn authorization_from_signed_requests(&self, requests: Vec<Request<N>>) -> Result<Authorization<N>> {
+        let timer = timer!("Process::authorization_from_signed_requests");
+
+        // Ensure the signed requests are valid.
+        self.ensure_signed_requests_are_valid(&requests)?;
+        lap!(timer, "Verify the requests");
+
+        // Construct the authorization.
+        let mut requests = requests.into_iter();
+        let authorization =
+            Authorization::new(requests.next().ok_or_else(|| anyhow!("Missing the top-level request"))?);
+        requests.for_each(|request| authorization.push(request));
+        finish!(timer, "Construct the authorization");
+
+        Ok(authorization)
     }
 
     /// Authorizes the fee given the credits record, the fee amount (in microcredits),
@@ -107,6 +138,83 @@ impl<N: Network> Process<N> {
     }
 }
 
+impl<N: Network> Process<N> {
+    /// Ensures the given signed requests are valid, where the first request is the top-level call,
+    /// and the remaining requests are its nested calls, in the order they are invoked.
+    fn ensure_signed_requests_are_valid(&self, requests: &[Request<N>]) -> Result<()> {
+        // Retrieve the top-level request.
+        let Some(top_level_request) = requests.first() else {
+            bail!("Cannot construct an authorization without any requests")
+        };
+        // Retrieve the signer of the top-level request.
+        let signer = *top_level_request.signer();
+
+        // Ensure the requests match the call chain of the top-level function.
+        let call_chain = self.get_call_chain(top_level_request.program_id(), top_level_request.function_name())?;
+        ensure!(
+            call_chain.len() == requests.len(),
+            "'{}/{}' expects {} requests, but {} were provided",
+            top_level_request.program_id(),
+            top_level_request.function_name(),
+            call_chain.len(),
+            requests.len()
+        );
+
+        for (index, (request, (program_id, function_name))) in requests.iter().zip_eq(&call_chain).enumerate() {
+            // Ensure the network ID matches.
+            ensure!(
+                **request.network_id() == N::ID,
+                "Network ID mismatch in request {index}. Expected {}, but found {}",
+                N::ID,
+                request.network_id()
+            );
+            // Ensure the request is for the expected function.
+            ensure!(
+                request.program_id() == program_id && request.function_name() == function_name,
+                "Expected request {index} to call '{program_id}/{function_name}', found '{}/{}'",
+                request.program_id(),
+                request.function_name()
+            );
+            // Ensure the request is signed by the top-level signer.
+            ensure!(*request.signer() == signer, "Request {index} is not signed by the top-level signer '{signer}'");
+            // Retrieve the input types.
+            let input_types = self.get_stack(program_id)?.get_function(function_name)?.input_types();
+            // Ensure the request is well-formed.
+            ensure!(request.verify(&input_types), "Request {index} ('{program_id}/{function_name}') is invalid");
+        }
+        Ok(())
+    }
+
+    /// Returns the program ID and function name of each request in the call chain of the given function,
+    /// in the order they are invoked (i.e. the given function, followed by its nested calls, depth-first).
+    pub(crate) fn get_call_chain(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+    ) -> Result<Vec<(ProgramID<N>, Identifier<N>)>> {
+        // Retrieve the function.
+        let function = self.get_stack(program_id)?.program().get_function_ref(function_name)?;
+
+        // Initialize the call chain with the given function.
+        let mut call_chain = vec![(*program_id, *function_name)];
+        for instruction in function.instructions() {
+            if let Instruction::Call(call) = instruction {
+                // Retrieve the program ID and resource of the call.
+                let (call_program_id, resource) = match call.operator() {
+                    CallOperator::Locator(locator) => (locator.program_id(), locator.resource()),
+                    CallOperator::Resource(resource) => (program_id, resource),
+                };
+                // If the call is to a function, append its call chain.
+                // Note: Closures do not produce a request, and are skipped.
+                if self.get_stack(call_program_id)?.program().contains_function(resource) {
+                    call_chain.extend(self.get_call_chain(call_program_id, resource)?);
+                }
+            }
+        }
+        Ok(call_chain)
+    }
+}
+
 /// Ensures the record contains a sufficient balance to pay the fee.
 fn ensure_record_microcredits_is_sufficient<N: Network>(
     record: &Record<N, Plaintext<N>>,
@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+};
+
+/// An error returned when a call exceeds the maximum call depth (`N::MAX_CALL_DEPTH`),
+/// either when a program is added to the process, or during evaluation or execution.
+///
+/// A caller may downcast the error returned by `Process::add_program`, `Process::evaluate`,
+/// or `Process::authorize` to a `CallDepthExceeded`, to distinguish it from other failures.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallDepthExceeded<N: Network> {
+    /// The program ID of the callee.
+    program_id: ProgramID<N>,
+    /// The name of the callee closure or function.
+    name: Identifier<N>,
+    /// The call depth reached by the callee.
+    depth: usize,
+}
+
+impl<N: Network> CallDepthExceeded<N> {
+    /// Initializes a new call depth error.
+    pub const fn new(program_id: ProgramID<N>, name: Identifier<N>, depth: usize) -> Self {
+        Self { program_id, name, depth }
+    }
+
+    /// Returns the program ID of the callee.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the name of the callee closure or function.
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the call depth reached by the callee.
+    pub const fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Ensures the given call depth of the callee does not exceed `N::MAX_CALL_DEPTH`.
+    ///
+    /// Note: This is a prover-side limit, and **not** a consensus rule. It is checked when a call is evaluated,
+    /// authorized, or executed, but not when a deployment is checked, and the verifier does not check the call depth
+    /// of an execution. As such, a deployed program that exceeds the limit still verifies and loads, but its calls
+    /// past the limit cannot be proven with this prover.
+    pub(crate) fn ensure(program_id: &ProgramID<N>, name: &Identifier<N>, depth: usize) -> Result<()> {
+        match depth <= N::MAX_CALL_DEPTH {
+            true => Ok(()),
+            false => Err(Self::new(*program_id, *name, depth).into()),
+        }
+    }
+}
+
+impl<N: Network> Display for CallDepthExceeded<N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "'{}/{}' reaches call depth {}, which exceeds the maximum call depth of {}",
+            self.program_id,
+            self.name,
+            self.depth,
+            N::MAX_CALL_DEPTH
+        )
+    }
+}
+
+impl<N: Network> std::error::Error for CallDepthExceeded<N> {}
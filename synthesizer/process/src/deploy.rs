@@ -37,6 +37,59 @@ impl<N: Network> Process<N> {
         deployment
     }
 
+    /// Returns the size and cost of deploying the given program, broken down by function.
+    ///
+    /// If the program already exists in the process, its cached verifying keys are reused, so that no synthesis is needed.
+    /// The certificates are not computed, as they do not depend on the program.
+    #[inline]
+    pub fn deployment_report<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program: &Program<N>,
+        rng: &mut R,
+    ) -> Result<DeploymentReport<N>> {
+        let timer = timer!("Process::deployment_report");
+
+        // Retrieve the program ID.
+        let program_id = program.id();
+        // Ensure the program contains functions.
+        ensure!(!program.functions().is_empty(), "Program '{program_id}' has no functions");
+
+        // Retrieve the existing stack, or compute a new one.
+        let new_stack;
+        let stack = match self.contains_program(program_id) {
+            true => {
+                let stack = self.get_stack(program_id)?;
+                ensure!(stack.program() == program, "Program '{program_id}' differs from the one in the process");
+                stack
+            }
+            false => {
+                new_stack = Stack::new(self, program)?;
+                &new_stack
+            }
+        };
+        lap!(timer, "Retrieve the stack");
+
+        // Retrieve the verifying key of each function, synthesizing it only if it is not cached.
+        let verifying_keys = program
+            .functions()
+            .keys()
+            .map(|function_name| {
+                stack.synthesize_key::<A, R>(function_name, rng)?;
+                Ok((*function_name, stack.get_verifying_key(function_name)?))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        lap!(timer, "Retrieve the verifying keys");
+
+        // Compute the report.
+        let report = DeploymentReport::new(
+            N::EDITION,
+            program,
+            verifying_keys.iter().map(|(function_name, verifying_key)| (function_name, verifying_key)),
+        );
+        finish!(timer);
+        report
+    }
+
     /// Adds the newly-deployed program.
     /// This method assumes the given deployment **is valid**.
     #[inline]
@@ -61,3 +114,62 @@ impl<N: Network> Process<N> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_deployment_report() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program with two functions.
+        let program = Program::from_str(
+            r"
+program testing.aleo;
+
+function add_one:
+    input r0 as u32.private;
+    add r0 1u32 into r1;
+    output r1 as u32.private;
+
+function twice:
+    input r0 as u64.public;
+    add r0 r0 into r1;
+    output r1 as u64.public;",
+        )
+        .unwrap();
+
+        // Compute the report for a program that is not in the process.
+        let process = Process::load().unwrap();
+        let report = process.deployment_report::<CurrentAleo, _>(&program, rng).unwrap();
+        assert_eq!(report, process.deploy::<CurrentAleo, _>(&program, rng).unwrap().report().unwrap());
+        assert_eq!(report.functions().len(), program.functions().len());
+        assert!(!process.contains_program(program.id()));
+
+        // Add the program, and ensure the report synthesizes and caches its keys.
+        let mut process = process;
+        process.add_program(&program).unwrap();
+        assert_eq!(report, process.deployment_report::<CurrentAleo, _>(&program, rng).unwrap());
+        for function_name in program.functions().keys() {
+            assert!(process.get_stack(program.id()).unwrap().contains_verifying_key(function_name));
+        }
+        // Ensure the report is unchanged when the keys are cached.
+        assert_eq!(report, process.deployment_report::<CurrentAleo, _>(&program, rng).unwrap());
+
+        // Ensure a different program with the same ID is rejected.
+        let other = Program::from_str(
+            r"
+program testing.aleo;
+
+function add_one:
+    input r0 as u32.private;
+    add r0 2u32 into r1;
+    output r1 as u32.private;",
+        )
+        .unwrap();
+        assert!(process.deployment_report::<CurrentAleo, _>(&other, rng).is_err());
+    }
+}
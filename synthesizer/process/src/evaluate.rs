@@ -13,9 +13,12 @@
 // limitations under the License.
 
 use super::*;
+use console::types::Address;
 
 impl<N: Network> Process<N> {
     /// Evaluates a program function on the given request.
+    ///
+    /// If the function halts, the returned error can be downcast to an `ExecutionHalt`.
     #[inline]
     pub fn evaluate<A: circuit::Aleo<Network = N>>(&self, authorization: Authorization<N>) -> Result<Response<N>> {
         let timer = timer!("Process::evaluate");
@@ -36,4 +39,29 @@ impl<N: Network> Process<N> {
 
         response
     }
+
+    /// Evaluates a program function on the given inputs, without an authorization or a proof.
+    /// This is intended to preview the outputs of a function, e.g. before authorizing it.
+    ///
+    /// The inputs are checked against the input types of the function, and record inputs are given as plaintext records.
+    /// As there is no signer, `self.signer` (and `self.caller` in the top-level function) evaluates to the zero address,
+    /// and any output records have placeholder nonces.
+    #[inline]
+    pub fn evaluate_function<A: circuit::Aleo<Network = N>>(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: &[Value<N>],
+    ) -> Result<Vec<Value<N>>> {
+        // Prepare the function name.
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+        // Retrieve the stack.
+        let stack = self.get_stack(program_id)?;
+
+        #[cfg(feature = "aleo-cli")]
+        println!("{}", format!(" • Evaluating '{}/{function_name}'...", stack.program_id()).dimmed());
+
+        // Evaluate the function.
+        stack.evaluate_function_without_request::<A>(&function_name, inputs, Address::zero(), None)
+    }
 }
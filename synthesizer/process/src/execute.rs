@@ -13,9 +13,12 @@
 // limitations under the License.
 
 use super::*;
+use ledger_query::QueryTrait;
 
 impl<N: Network> Process<N> {
     /// Executes the given authorization.
+    ///
+    /// If the function halts, the returned error can be downcast to an `ExecutionHalt`.
     #[inline]
     pub fn execute<A: circuit::Aleo<Network = N>>(
         &self,
@@ -32,7 +35,19 @@ impl<N: Network> Process<N> {
         println!("{}", format!(" • Executing '{locator}'...",).dimmed());
 
         // Initialize the trace.
-        let trace = Arc::new(RwLock::new(Trace::new()));
+        let mut trace = Trace::new();
+        // If the execution trace is being recorded, record the requests and the invoked programs.
+        if self.record_trace {
+            let mut programs = Vec::<Program<N>>::new();
+            for (program_id, _) in self.get_call_chain(request.program_id(), request.function_name())? {
+                let program = self.get_stack(program_id)?.program();
+                if !programs.contains(program) {
+                    programs.push(program.clone());
+                }
+            }
+            trace.record(authorization.to_vec_deque().into(), programs);
+        }
+        let trace = Arc::new(RwLock::new(trace));
         // Initialize the call stack.
         let call_stack = CallStack::execute(authorization, trace.clone())?;
         lap!(timer, "Initialize call stack");
@@ -51,6 +66,49 @@ impl<N: Network> Process<N> {
         finish!(timer);
         Ok((response, trace))
     }
+
+    /// Returns a public fee for the given fee amount (in microcredits) and the deployment or execution ID.
+    ///
+    /// Note: This method does not consult the public balance of the account; the sufficiency
+    /// of the balance is checked when the fee is finalized.
+    #[inline]
+    pub fn execute_fee_public<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        base_fee_in_microcredits: u64,
+        priority_fee_in_microcredits: u64,
+        deployment_or_execution_id: Field<N>,
+        query: impl QueryTrait<N>,
+        rng: &mut R,
+    ) -> Result<Fee<N>> {
+        let timer = timer!("Process::execute_fee_public");
+
+        // Authorize the fee.
+        let authorization = self.authorize_fee_public::<A, R>(
+            private_key,
+            base_fee_in_microcredits,
+            priority_fee_in_microcredits,
+            deployment_or_execution_id,
+            rng,
+        )?;
+        lap!(timer, "Authorize the fee");
+
+        // Execute the fee.
+        let (_, mut trace) = self.execute::<A>(authorization)?;
+        // Ensure the trace is for a public fee.
+        ensure!(trace.is_fee_public(), "The trace must be for a call to 'credits.aleo/fee_public'");
+        lap!(timer, "Execute the fee");
+
+        // Prepare the assignments.
+        trace.prepare(query)?;
+        lap!(timer, "Prepare the assignments");
+
+        // Compute the proof and construct the fee.
+        let fee = trace.prove_fee::<A, R>(rng)?;
+        finish!(timer, "Compute the proof");
+
+        Ok(fee)
+    }
 }
 
 #[cfg(test)]
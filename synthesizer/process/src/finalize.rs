@@ -16,6 +16,8 @@ use super::*;
 use console::program::{Future, Register};
 use synthesizer_program::{Await, FinalizeRegistersState, Operand};
 
+use std::time::Instant;
+
 impl<N: Network> Process<N> {
     /// Finalizes the deployment and fee.
     /// This method assumes the given deployment **is valid**.
@@ -217,10 +219,31 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
         mut recent_call_locator,
     }) = states.pop()
     {
+        // Retrieve the profiler, if one is set.
+        let profiler = stack.profiler();
+        // Records the command at the given index, if the profiler is set.
+        let profile = |index: usize, command: &Command<N>, start: Option<Instant>| {
+            if let (Some(profiler), Some(start)) = (&profiler, start) {
+                profiler.record(ProfileEvent {
+                    stage: ProfileStage::Finalize,
+                    program_id: *stack.program_id(),
+                    name: *finalize.name(),
+                    index,
+                    opcode: command.opcode(),
+                    duration: start.elapsed(),
+                    num_constraints: 0,
+                });
+            }
+        };
+
         // Evaluate the commands.
         while counter < finalize.commands().len() {
             // Retrieve the command.
             let command = &finalize.commands()[counter];
+            // Record the index of the command.
+            let index = counter;
+            // If the profiler is set, start the timer.
+            let start = profiler.as_ref().map(|_| Instant::now());
             // Finalize the command.
             match &command {
                 Command::BranchEq(branch_eq) => {
@@ -303,6 +326,8 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                     // Push the callee state onto the stack.
                     states.push(callee_state);
 
+                    // Record the command.
+                    profile(index, command, start);
                     break;
                 }
                 _ => {
@@ -322,6 +347,8 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                     counter += 1;
                 }
             };
+            // Record the command.
+            profile(index, command, start);
         }
     }
 
@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+};
+
+/// An error returned when a function halts deterministically during evaluation or execution,
+/// such as when an `assert.eq` fails or a division by zero occurs.
+///
+/// A caller may downcast the error returned by `Process::evaluate` or `Process::execute` to an
+/// `ExecutionHalt`, to distinguish a halting program from an internal failure of the process.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionHalt<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The index of the instruction that halted.
+    instruction_index: usize,
+    /// The halt message.
+    message: String,
+}
+
+impl<N: Network> ExecutionHalt<N> {
+    /// Initializes a new execution halt.
+    pub fn new(
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        instruction_index: usize,
+        message: String,
+    ) -> Self {
+        Self { program_id, function_name, instruction_index, message }
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the index of the instruction that halted.
+    pub const fn instruction_index(&self) -> usize {
+        self.instruction_index
+    }
+
+    /// Returns the halt message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Runs the given step of the instruction at `instruction_index`, and classifies its failure.
+    ///
+    /// A halt of the environment or a failed assertion is returned as an `ExecutionHalt`,
+    /// and an `ExecutionHalt` from a nested call is returned unchanged.
+    /// Any other failure, including a genuine panic, is returned as a regular error.
+    pub(crate) fn catch<T>(
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        instruction_index: usize,
+        step: impl FnOnce() -> Result<T>,
+    ) -> Result<T> {
+        let halt = |message: &str| Self::new(*program_id, *function_name, instruction_index, message.to_string());
+        match Halt::catch(step) {
+            Ok(Ok(output)) => Ok(output),
+            // If a nested call halted, propagate its halt unchanged.
+            Ok(Err(error)) if error.is::<Self>() => Err(error),
+            // If an assertion failed, the program halted.
+            Ok(Err(error)) => match error.downcast_ref::<Halt>() {
+                Some(payload) => Err(halt(payload.message()).into()),
+                None => Err(error),
+            },
+            Err(payload) => match Halt::from_payload(payload.as_ref()) {
+                // If the environment halted, the program halted.
+                Some(payload) => Err(halt(payload.message()).into()),
+                // Otherwise, resume the genuine panic.
+                None => std::panic::resume_unwind(payload),
+            },
+        }
+    }
+}
+
+impl<N: Network> Display for ExecutionHalt<N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "'{}/{}' halted at instruction {}: {}",
+            self.program_id, self.function_name, self.instruction_index, self.message
+        )
+    }
+}
+
+impl<N: Network> std::error::Error for ExecutionHalt<N> {}
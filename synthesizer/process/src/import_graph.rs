@@ -0,0 +1,309 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use indexmap::IndexSet;
+
+/// The import graph of a set of programs, with an edge from each program to each of its imports.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ImportGraph<N: Network> {
+    /// The mapping of program IDs to their edition.
+    nodes: IndexMap<ProgramID<N>, u16>,
+    /// The set of imports, as `(program ID, imported program ID)`.
+    edges: IndexSet<(ProgramID<N>, ProgramID<N>)>,
+}
+
+impl<N: Network> Default for ImportGraph<N> {
+    /// Initializes an empty import graph.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> ImportGraph<N> {
+    /// Initializes an empty import graph.
+    pub fn new() -> Self {
+        Self { nodes: IndexMap::new(), edges: IndexSet::new() }
+    }
+
+    /// Inserts the given program ID and its edition into the graph.
+    pub fn add_node(&mut self, program_id: ProgramID<N>, edition: u16) {
+        self.nodes.insert(program_id, edition);
+    }
+
+    /// Inserts an edge from the given program ID to the given imported program ID.
+    pub fn add_edge(&mut self, program_id: ProgramID<N>, import: ProgramID<N>) {
+        self.edges.insert((program_id, import));
+    }
+
+    /// Returns `true` if the graph contains the given program ID.
+    pub fn contains(&self, program_id: &ProgramID<N>) -> bool {
+        self.nodes.contains_key(program_id)
+    }
+
+    /// Returns the program IDs in the graph, with their edition.
+    pub const fn nodes(&self) -> &IndexMap<ProgramID<N>, u16> {
+        &self.nodes
+    }
+
+    /// Returns the imports in the graph, as `(program ID, imported program ID)`.
+    pub const fn edges(&self) -> &IndexSet<(ProgramID<N>, ProgramID<N>)> {
+        &self.edges
+    }
+
+    /// Returns the imports of the given program ID.
+    pub fn imports<'a>(&'a self, program_id: &'a ProgramID<N>) -> impl 'a + Iterator<Item = &'a ProgramID<N>> {
+        self.edges.iter().filter(move |(from, _)| from == program_id).map(|(_, to)| to)
+    }
+
+    /// Returns the program IDs in topological order, where every program appears after all of its imports.
+    /// If the graph contains an import cycle, returns an error with the full cycle path.
+    pub fn topological_order(&self) -> Result<Vec<ProgramID<N>>> {
+        // Ensure every import is a program in the graph.
+        for (program_id, import) in &self.edges {
+            ensure!(self.contains(program_id), "Program '{program_id}' does not exist in the import graph");
+            ensure!(self.contains(import), "Program '{program_id}' imports '{import}', which does not exist");
+        }
+
+        // Initialize the topological order.
+        let mut order = Vec::with_capacity(self.nodes.len());
+        // Initialize the set of visited program IDs.
+        let mut visited = IndexSet::with_capacity(self.nodes.len());
+        // Initialize the path of program IDs that are currently being visited.
+        let mut path = IndexSet::new();
+
+        // Visit each program ID, in insertion order, so that the order is deterministic.
+        for program_id in self.nodes.keys() {
+            self.visit(program_id, &mut visited, &mut path, &mut order)?;
+        }
+        Ok(order)
+    }
+
+    /// Visits the given program ID in depth-first order, appending it to `order` after all of its imports.
+    fn visit(
+        &self,
+        program_id: &ProgramID<N>,
+        visited: &mut IndexSet<ProgramID<N>>,
+        path: &mut IndexSet<ProgramID<N>>,
+        order: &mut Vec<ProgramID<N>>,
+    ) -> Result<()> {
+        // If the program ID is on the current path, then the imports contain a cycle.
+        if let Some(start) = path.get_index_of(program_id) {
+            let cycle = path.iter().skip(start).chain([program_id]).map(|id| id.to_string()).join(" -> ");
+            bail!("Found an import cycle: {cycle}")
+        }
+        // If the program ID was already visited, then it is already in the order.
+        if visited.contains(program_id) {
+            return Ok(());
+        }
+
+        // Visit each import of the program ID.
+        path.insert(*program_id);
+        for import in self.imports(program_id) {
+            self.visit(import, visited, path, order)?;
+        }
+        path.pop();
+
+        // Append the program ID, after all of its imports.
+        visited.insert(*program_id);
+        order.push(*program_id);
+        Ok(())
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns the import graph of the given program ID, with all of its direct and indirect imports.
+    /// Note: The graph is computed from the stacks in the process.
+    #[inline]
+    pub fn import_graph(&self, program_id: &ProgramID<N>) -> Result<ImportGraph<N>> {
+        // Initialize the import graph.
+        let mut graph = ImportGraph::new();
+        // Initialize a queue of program IDs to visit.
+        let mut queue = vec![*program_id];
+
+        while let Some(program_id) = queue.pop() {
+            // Skip the program ID, if it was already visited.
+            if graph.contains(&program_id) {
+                continue;
+            }
+            // Retrieve the stack.
+            let stack = self.get_stack(program_id)?;
+            // Add the program ID to the graph.
+            // Note: Every program in the process is currently of the network edition.
+            graph.add_node(program_id, N::EDITION);
+            // Add the imports of the program to the graph, and visit them.
+            for import in stack.program().imports().keys() {
+                graph.add_edge(program_id, *import);
+                queue.push(*import);
+            }
+        }
+
+        // Ensure the import graph is acyclic.
+        graph.topological_order()?;
+        // Return the import graph.
+        Ok(graph)
+    }
+
+    /// Adds the given programs to the process, in the topological order of their imports.
+    /// An import must be one of the given programs, or already exist in the process.
+    ///
+    /// Note: If a program fails to be added, the programs that precede it in the order remain in the process.
+    #[inline]
+    pub fn add_programs(&mut self, programs: &[Program<N>]) -> Result<()> {
+        // Construct the import graph of the given programs.
+        let mut graph = ImportGraph::new();
+        for program in programs {
+            // Ensure the program is not given twice.
+            ensure!(!graph.contains(program.id()), "Program '{}' is given more than once", program.id());
+            graph.add_node(*program.id(), N::EDITION);
+        }
+        for program in programs {
+            for import in program.imports().keys() {
+                // Skip the import, if it already exists in the process.
+                if !graph.contains(import) && self.contains_program(import) {
+                    continue;
+                }
+                graph.add_edge(*program.id(), *import);
+            }
+        }
+
+        // Compute the order in which to add the programs.
+        let order = graph.topological_order()?;
+        // Add the programs, in order.
+        for program_id in order {
+            match programs.iter().find(|program| program.id() == &program_id) {
+                Some(program) => self.add_program(program)?,
+                None => bail!("Program '{program_id}' is missing from the given programs"),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns a program with the given name and imports.
+    fn sample_program(name: &str, imports: &[&str]) -> Program<CurrentNetwork> {
+        let imports = imports.iter().map(|import| format!("import {import}.aleo;\n")).collect::<String>();
+        Program::from_str(&format!(
+            r"{imports}
+program {name}.aleo;
+
+function compute:
+    input r0 as u8.private;
+    output r0 as u8.private;"
+        ))
+        .unwrap()
+    }
+
+    /// Returns the program ID for the given name.
+    fn program_id(name: &str) -> ProgramID<CurrentNetwork> {
+        ProgramID::from_str(&format!("{name}.aleo")).unwrap()
+    }
+
+    #[test]
+    fn test_import_graph_diamond() {
+        // Initialize a diamond of imports, where 'top' imports 'left' and 'right', which both import 'bottom'.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        process.add_program(&sample_program("bottom", &[])).unwrap();
+        process.add_program(&sample_program("left", &["bottom"])).unwrap();
+        process.add_program(&sample_program("right", &["bottom"])).unwrap();
+        process.add_program(&sample_program("top", &["left", "right"])).unwrap();
+
+        // Compute the import graph.
+        let graph = process.import_graph(&program_id("top")).unwrap();
+
+        // Ensure the nodes and edges are correct.
+        assert_eq!(graph.nodes().len(), 4);
+        assert!(graph.nodes().values().all(|edition| *edition == CurrentNetwork::EDITION));
+        let mut edges = graph.edges().iter().map(|(from, to)| (from.to_string(), to.to_string())).collect::<Vec<_>>();
+        edges.sort();
+        assert_eq!(edges, vec![
+            ("left.aleo".to_string(), "bottom.aleo".to_string()),
+            ("right.aleo".to_string(), "bottom.aleo".to_string()),
+            ("top.aleo".to_string(), "left.aleo".to_string()),
+            ("top.aleo".to_string(), "right.aleo".to_string()),
+        ]);
+
+        // Ensure every program appears after its imports, and 'bottom' appears once.
+        let order = graph.topological_order().unwrap();
+        assert_eq!(order.len(), 4);
+        let position = |name: &str| order.iter().position(|id| id == &program_id(name)).unwrap();
+        assert!(position("bottom") < position("left"));
+        assert!(position("bottom") < position("right"));
+        assert!(position("left") < position("top"));
+        assert!(position("right") < position("top"));
+
+        // Ensure the import graph of a leaf only contains the leaf.
+        let graph = process.import_graph(&program_id("bottom")).unwrap();
+        assert_eq!(graph.nodes().keys().collect::<Vec<_>>(), vec![&program_id("bottom")]);
+        assert!(graph.edges().is_empty());
+    }
+
+    #[test]
+    fn test_add_programs_diamond() {
+        // Add a diamond of imports, given in reverse order.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        let programs = [
+            sample_program("top", &["left", "right"]),
+            sample_program("right", &["bottom"]),
+            sample_program("left", &["bottom"]),
+            sample_program("bottom", &[]),
+        ];
+        process.add_programs(&programs).unwrap();
+
+        // Ensure all of the programs were added.
+        for name in ["top", "right", "left", "bottom"] {
+            assert!(process.contains_program(&program_id(name)));
+        }
+    }
+
+    #[test]
+    fn test_import_cycle() {
+        // Initialize a cycle of three programs.
+        let programs = [sample_program("a", &["b"]), sample_program("b", &["c"]), sample_program("c", &["a"])];
+
+        // Ensure the cycle is reported with its full path.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        let error = process.add_programs(&programs).unwrap_err();
+        assert_eq!(error.to_string(), "Found an import cycle: a.aleo -> b.aleo -> c.aleo -> a.aleo");
+        // Ensure none of the programs were added.
+        assert!(programs.iter().all(|program| !process.contains_program(program.id())));
+
+        // Ensure the cycle is reported by the import graph directly.
+        let mut graph = ImportGraph::<CurrentNetwork>::new();
+        for name in ["a", "b", "c"] {
+            graph.add_node(program_id(name), CurrentNetwork::EDITION);
+        }
+        graph.add_edge(program_id("a"), program_id("b"));
+        graph.add_edge(program_id("b"), program_id("c"));
+        graph.add_edge(program_id("c"), program_id("a"));
+        let error = graph.topological_order().unwrap_err();
+        assert_eq!(error.to_string(), "Found an import cycle: a.aleo -> b.aleo -> c.aleo -> a.aleo");
+    }
+
+    #[test]
+    fn test_missing_import() {
+        // Ensure a missing import is reported.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        let error = process.add_programs(&[sample_program("a", &["missing"])]).unwrap_err();
+        assert_eq!(error.to_string(), "Program 'a.aleo' imports 'missing.aleo', which does not exist");
+    }
+}
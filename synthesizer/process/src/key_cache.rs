@@ -0,0 +1,319 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+};
+use synthesizer_snark::ProvingKey;
+
+use indexmap::IndexMap;
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+/// A handle to a proving key cache.
+///
+/// The cache of a process is shared with each stack registered through `Process::add_stack`,
+/// while any other stack writes its proving keys to a private cache.
+pub(crate) type KeyCacheHandle<N> = Arc<Mutex<KeyCache<N>>>;
+
+/// The metrics of the proving key cache.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeyCacheMetrics {
+    /// The number of proving keys in the cache.
+    pub size: usize,
+    /// The total size of the proving keys in the cache, in bytes.
+    /// Note: The proving keys are only measured while a capacity in bytes is set.
+    pub size_in_bytes: usize,
+    /// The number of lookups that found the proving key in the cache.
+    pub hits: u64,
+    /// The number of lookups that did not find the proving key in the cache.
+    pub misses: u64,
+    /// The number of proving keys evicted from the cache.
+    pub evictions: u64,
+}
+
+/// A least-recently-used cache of proving keys, keyed by `(program ID, function name)`.
+///
+/// The proving keys for 'credits.aleo' are pinned, and do not count towards either capacity.
+/// An evicted proving key is re-synthesized the next time its function is executed.
+pub(crate) struct KeyCache<N: Network> {
+    /// The maximum number of (unpinned) proving keys, or `None` if the number is unbounded.
+    capacity: Option<usize>,
+    /// The maximum total size of the (unpinned) proving keys in bytes, or `None` if the size is unbounded.
+    capacity_in_bytes: Option<usize>,
+    /// The program ID of 'credits.aleo', whose proving keys are pinned.
+    credits_program_id: ProgramID<N>,
+    /// The proving keys and their sizes in bytes (if measured), in order from least-recently to most-recently used.
+    proving_keys: IndexMap<(ProgramID<N>, Identifier<N>), (ProvingKey<N>, Option<usize>)>,
+    /// The metrics of the cache.
+    metrics: KeyCacheMetrics,
+}
+
+impl<N: Network> KeyCache<N> {
+    /// Initializes a new, unbounded cache.
+    pub(crate) fn new() -> Result<Self> {
+        Ok(Self {
+            capacity: None,
+            capacity_in_bytes: None,
+            credits_program_id: ProgramID::from_str("credits.aleo")?,
+            proving_keys: Default::default(),
+            metrics: Default::default(),
+        })
+    }
+
+    /// Initializes a new, unbounded cache, and returns a handle to it.
+    pub(crate) fn new_handle() -> Result<KeyCacheHandle<N>> {
+        Ok(Arc::new(Mutex::new(Self::new()?)))
+    }
+
+    /// Returns `true` if the proving key for the given function is cached.
+    pub(crate) fn contains(&self, program_id: &ProgramID<N>, function_name: &Identifier<N>) -> bool {
+        self.proving_keys.contains_key(&(*program_id, *function_name))
+    }
+
+    /// Returns the proving key for the given function, if it is cached, and marks it as most-recently used.
+    pub(crate) fn get(&mut self, program_id: &ProgramID<N>, function_name: &Identifier<N>) -> Option<ProvingKey<N>> {
+        match self.proving_keys.get_index_of(&(*program_id, *function_name)) {
+            Some(index) => {
+                self.metrics.hits += 1;
+                self.proving_keys.move_index(index, self.proving_keys.len() - 1);
+                self.proving_keys.last().map(|(_, (proving_key, _))| proving_key.clone())
+            }
+            None => {
+                self.metrics.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Inserts the proving key for the given function as most-recently used, evicting as needed,
+    /// and returns the proving key, even if it is evicted right away.
+    pub(crate) fn insert(
+        &mut self,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        proving_key: ProvingKey<N>,
+    ) -> ProvingKey<N> {
+        // Compute the size of the proving key, if the cache is bounded in bytes.
+        let num_bytes = self.capacity_in_bytes.map(|_| Self::size_in_bytes(&proving_key));
+        self.proving_keys.shift_remove(&(program_id, function_name));
+        self.proving_keys.insert((program_id, function_name), (proving_key.clone(), num_bytes));
+        self.evict();
+        proving_key
+    }
+
+    /// Removes and returns all of the proving keys, in order from least-recently to most-recently used.
+    pub(crate) fn drain(&mut self) -> Vec<(ProgramID<N>, Identifier<N>, ProvingKey<N>)> {
+        self.proving_keys
+            .drain(..)
+            .map(|((program_id, function_name), (proving_key, _))| (program_id, function_name, proving_key))
+            .collect()
+    }
+
+    /// Removes the proving key for the given function.
+    pub(crate) fn remove(&mut self, program_id: &ProgramID<N>, function_name: &Identifier<N>) {
+        self.proving_keys.shift_remove(&(*program_id, *function_name));
+    }
+
+    /// Removes the proving keys for the given program.
+    pub(crate) fn remove_program(&mut self, program_id: &ProgramID<N>) {
+        self.proving_keys.retain(|(candidate, _), _| candidate != program_id);
+    }
+
+    /// Sets the maximum number of (unpinned) proving keys, evicting as needed.
+    pub(crate) fn set_capacity(&mut self, capacity: Option<usize>) -> Result<()> {
+        ensure!(capacity != Some(0), "The proving key cache capacity must be at least 1");
+        self.capacity = capacity;
+        self.evict();
+        Ok(())
+    }
+
+    /// Sets the maximum total size of the (unpinned) proving keys in bytes, evicting as needed.
+    pub(crate) fn set_capacity_in_bytes(&mut self, capacity_in_bytes: Option<usize>) -> Result<()> {
+        ensure!(capacity_in_bytes != Some(0), "The proving key cache capacity in bytes must be at least 1");
+        self.capacity_in_bytes = capacity_in_bytes;
+        // Measure the proving keys that were inserted while the cache was not bounded in bytes.
+        if self.capacity_in_bytes.is_some() {
+            for (proving_key, num_bytes) in self.proving_keys.values_mut() {
+                num_bytes.get_or_insert_with(|| Self::size_in_bytes(proving_key));
+            }
+        }
+        self.evict();
+        Ok(())
+    }
+
+    /// Returns the metrics of the cache.
+    pub(crate) fn metrics(&self) -> KeyCacheMetrics {
+        let size_in_bytes = self.proving_keys.values().filter_map(|(_, num_bytes)| *num_bytes).sum();
+        KeyCacheMetrics { size: self.proving_keys.len(), size_in_bytes, ..self.metrics }
+    }
+
+    /// Evicts the least-recently used (unpinned) proving keys, until the cache is within both of its capacities.
+    fn evict(&mut self) {
+        if self.capacity.is_none() && self.capacity_in_bytes.is_none() {
+            return;
+        }
+        // Determine the number and total size of the unpinned proving keys.
+        let (mut num_unpinned, mut num_unpinned_bytes) = self
+            .proving_keys
+            .iter()
+            .filter(|((program_id, _), _)| !self.is_pinned(program_id))
+            .fold((0, 0), |(count, total), (_, (_, num_bytes))| (count + 1, total + num_bytes.unwrap_or(0)));
+        // Evict the least-recently used unpinned proving keys, until both capacities are met.
+        let (capacity, capacity_in_bytes) = (self.capacity, self.capacity_in_bytes);
+        let is_over_capacity = |num_keys: usize, num_bytes: usize| {
+            capacity.map_or(false, |capacity| num_keys > capacity)
+                || capacity_in_bytes.map_or(false, |capacity_in_bytes| num_bytes > capacity_in_bytes)
+        };
+        let credits_program_id = self.credits_program_id;
+        let mut num_evictions = 0;
+        self.proving_keys.retain(|(program_id, _), (_, num_bytes)| {
+            if *program_id == credits_program_id || !is_over_capacity(num_unpinned, num_unpinned_bytes) {
+                return true;
+            }
+            num_unpinned -= 1;
+            num_unpinned_bytes -= num_bytes.unwrap_or(0);
+            num_evictions += 1;
+            false
+        });
+        self.metrics.evictions += num_evictions;
+    }
+
+    /// Returns `true` if the proving keys for the given program ID are pinned.
+    fn is_pinned(&self, program_id: &ProgramID<N>) -> bool {
+        *program_id == self.credits_program_id
+    }
+
+    /// Returns the size of the given proving key in bytes.
+    fn size_in_bytes(proving_key: &ProvingKey<N>) -> usize {
+        /// A writer that counts the number of bytes written to it.
+        struct ByteCounter(usize);
+
+        impl std::io::Write for ByteCounter {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0 += buf.len();
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut counter = ByteCounter(0);
+        // Writing to the counter is infallible, so the count is only partial if serialization itself fails.
+        let _ = proving_key.write_le(&mut counter);
+        counter.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_key_cache_eviction() {
+        let program_a = ProgramID::<CurrentNetwork>::from_str("a.aleo").unwrap();
+        let program_b = ProgramID::<CurrentNetwork>::from_str("b.aleo").unwrap();
+        let credits = ProgramID::<CurrentNetwork>::from_str("credits.aleo").unwrap();
+        let function = Identifier::from_str("main").unwrap();
+
+        // Sample a proving key.
+        let (_, proving_key, _) = crate::test_helpers::sample_key();
+
+        // Initialize a cache with a capacity of 1.
+        let mut cache = KeyCache::<CurrentNetwork>::new().unwrap();
+        cache.set_capacity(Some(1)).unwrap();
+        assert!(cache.set_capacity(Some(0)).is_err());
+
+        // Insert a pinned proving key, and two unpinned proving keys.
+        cache.insert(credits, function, proving_key.clone());
+        cache.insert(program_a, function, proving_key.clone());
+        cache.insert(program_b, function, proving_key.clone());
+
+        // Ensure the least-recently used unpinned proving key was evicted.
+        assert!(cache.contains(&credits, &function));
+        assert!(!cache.contains(&program_a, &function));
+        assert!(cache.contains(&program_b, &function));
+        assert_eq!(cache.metrics().size, 2);
+        assert_eq!(cache.metrics().evictions, 1);
+
+        // Ensure the lookups are recorded.
+        assert!(cache.get(&program_a, &function).is_none());
+        assert!(cache.get(&program_b, &function).is_some());
+        assert!(cache.get(&credits, &function).is_some());
+        assert_eq!((cache.metrics().hits, cache.metrics().misses), (2, 1));
+
+        // Ensure an unbounded cache does not evict.
+        cache.set_capacity(None).unwrap();
+        cache.insert(program_a, function, proving_key);
+        assert!(cache.contains(&program_a, &function) && cache.contains(&program_b, &function));
+        assert_eq!(cache.metrics().evictions, 1);
+
+        // Ensure a lookup marks the proving key as most-recently used.
+        assert!(cache.get(&program_b, &function).is_some());
+        cache.set_capacity(Some(1)).unwrap();
+        assert!(!cache.contains(&program_a, &function) && cache.contains(&program_b, &function));
+        assert_eq!(cache.metrics().evictions, 2);
+    }
+
+    #[test]
+    fn test_key_cache_eviction_by_size() {
+        let program_a = ProgramID::<CurrentNetwork>::from_str("a.aleo").unwrap();
+        let program_b = ProgramID::<CurrentNetwork>::from_str("b.aleo").unwrap();
+        let credits = ProgramID::<CurrentNetwork>::from_str("credits.aleo").unwrap();
+        let function = Identifier::from_str("main").unwrap();
+
+        // Sample a proving key, and compute its size.
+        let (_, proving_key, _) = crate::test_helpers::sample_key();
+        let num_bytes = proving_key.to_bytes_le().unwrap().len();
+
+        // Insert a pinned proving key, which is not measured while the cache is not bounded in bytes.
+        let mut cache = KeyCache::<CurrentNetwork>::new().unwrap();
+        cache.insert(credits, function, proving_key.clone());
+        assert_eq!(cache.metrics().size_in_bytes, 0);
+
+        // Set a capacity of one and a half proving keys, and ensure the pinned proving key is measured.
+        cache.set_capacity_in_bytes(Some(num_bytes + num_bytes / 2)).unwrap();
+        assert!(cache.set_capacity_in_bytes(Some(0)).is_err());
+        assert_eq!(cache.metrics().size_in_bytes, num_bytes);
+
+        // Insert two unpinned proving keys.
+        cache.insert(program_a, function, proving_key.clone());
+        assert_eq!(cache.metrics().size_in_bytes, 2 * num_bytes);
+        cache.insert(program_b, function, proving_key.clone());
+
+        // Ensure the least-recently used unpinned proving key was evicted, and the pinned proving key was not counted.
+        assert!(cache.contains(&credits, &function));
+        assert!(!cache.contains(&program_a, &function));
+        assert!(cache.contains(&program_b, &function));
+        assert_eq!(cache.metrics().size_in_bytes, 2 * num_bytes);
+        assert_eq!(cache.metrics().evictions, 1);
+
+        // Ensure lowering the capacity below a single proving key evicts all unpinned proving keys.
+        cache.set_capacity_in_bytes(Some(num_bytes - 1)).unwrap();
+        assert!(cache.contains(&credits, &function) && !cache.contains(&program_b, &function));
+        assert_eq!(cache.metrics().evictions, 2);
+
+        // Ensure an inserted proving key is returned, even though it is evicted right away.
+        let inserted = cache.insert(program_a, function, proving_key.clone());
+        assert_eq!(inserted.to_bytes_le().unwrap(), proving_key.to_bytes_le().unwrap());
+        assert!(!cache.contains(&program_a, &function));
+        assert_eq!(cache.metrics().evictions, 3);
+    }
+}
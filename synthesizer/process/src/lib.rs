@@ -24,14 +24,30 @@ pub use stack::*;
 mod trace;
 pub use trace::*;
 
+mod call_depth;
+pub use call_depth::*;
+
+mod halt;
+pub use halt::*;
+
 mod traits;
 pub use traits::*;
 
+mod import_graph;
+pub use import_graph::*;
+
+mod key_cache;
+pub use key_cache::*;
+
+mod profiler;
+pub use profiler::*;
+
 mod authorize;
 mod deploy;
 mod evaluate;
 mod execute;
 mod finalize;
+mod replay;
 mod verify_deployment;
 mod verify_execution;
 mod verify_fee;
@@ -42,13 +58,14 @@ mod tests;
 use console::{
     account::PrivateKey,
     network::prelude::*,
-    program::{Identifier, Literal, Locator, Plaintext, ProgramID, Record, Response, Value},
+    program::{Identifier, Literal, Locator, Plaintext, ProgramID, Record, Request, Response, Value},
     types::{Field, U16, U64},
 };
-use ledger_block::{Deployment, Execution, Fee, Input, Transition};
+use ledger_block::{Deployment, DeploymentReport, Execution, Fee, Input, Transition};
 use ledger_store::{atomic_batch_scope, FinalizeStorage, FinalizeStore};
 use synthesizer_program::{
     Branch,
+    CallOperator,
     Closure,
     Command,
     Finalize,
@@ -76,6 +93,12 @@ pub struct Process<N: Network> {
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of program IDs to stacks.
     stacks: IndexMap<ProgramID<N>, Stack<N>>,
+    /// A flag indicating whether executions record an execution trace for replay.
+    record_trace: bool,
+    /// The instruction profiler, which is shared with each stack.
+    profiler: ProfilerHandle<N>,
+    /// The proving key cache, which is shared with each stack.
+    key_cache: KeyCacheHandle<N>,
 }
 
 impl<N: Network> Process<N> {
@@ -85,7 +108,13 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process:setup");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            record_trace: false,
+            profiler: Default::default(),
+            key_cache: KeyCache::new_handle()?,
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -115,18 +144,62 @@ impl<N: Network> Process<N> {
     /// If you intend to `execute` the program, use `deploy` and `finalize_deployment` instead.
     #[inline]
     pub fn add_program(&mut self, program: &Program<N>) -> Result<()> {
-        // Compute the program stack, and add it to the process.
-        self.add_stack(Stack::new(self, program)?);
+        // Compute the program stack.
+        let stack = Stack::new(self, program)?;
+        // Ensure no function in the program exceeds the maximum call depth.
+        stack.check_call_depths()?;
+        // Add the stack to the process.
+        self.add_stack(stack);
         Ok(())
     }
 
     /// Adds a new stack to the process.
     /// If you intend to `execute` the program, use `deploy` and `finalize_deployment` instead.
     #[inline]
-    pub fn add_stack(&mut self, stack: Stack<N>) {
+    pub fn add_stack(&mut self, mut stack: Stack<N>) {
+        // Share the proving key cache of the process with the stack.
+        stack.bind_key_cache(&self.key_cache);
         // Add the stack to the process.
         self.stacks.insert(*stack.program_id(), stack);
     }
+
+    /// Removes the program with the given program ID from the process.
+    /// Note: This is used to revert a deployment, and fails if the program is imported by another program.
+    #[inline]
+    pub fn remove_program(&mut self, program_id: &ProgramID<N>) -> Result<()> {
+        // Ensure the program can be removed.
+        self.check_remove_programs(&[program_id])?;
+        // Remove the stack from the process.
+        self.stacks.shift_remove(program_id);
+        // Remove the proving keys for the program.
+        self.key_cache.lock().remove_program(program_id);
+        Ok(())
+    }
+
+    /// Ensures the given programs can be removed from the process, in the given order, without removing them.
+    #[inline]
+    pub fn check_remove_programs(&self, program_ids: &[&ProgramID<N>]) -> Result<()> {
+        for (index, program_id) in program_ids.iter().enumerate() {
+            // Retrieve the programs that are removed before this program.
+            let removed = &program_ids[..index];
+            // Ensure the program is not 'credits.aleo'.
+            ensure!(*program_id != &ProgramID::from_str("credits.aleo")?, "Cannot remove 'credits.aleo' from the process");
+            // Ensure the program exists in the process, and is not already removed.
+            if !self.contains_program(program_id) || removed.contains(program_id) {
+                bail!("Program '{program_id}' does not exist in the process")
+            }
+            // Ensure the program is not imported by another remaining program.
+            if let Some(stack) = self
+                .stacks
+                .values()
+                .filter(|stack| !removed.contains(&stack.program_id()))
+                .find(|stack| stack.program().imports().contains_key(*program_id))
+            {
+                bail!("Cannot remove '{program_id}' from the process, as it is imported by '{}'", stack.program_id())
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<N: Network> Process<N> {
@@ -136,7 +209,13 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            record_trace: false,
+            profiler: Default::default(),
+            key_cache: KeyCache::new_handle()?,
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -164,12 +243,48 @@ impl<N: Network> Process<N> {
         Ok(process)
     }
 
+    /// Initializes a new process, synthesizing the 'credits.aleo' circuit keys if they are not bundled for the network.
+    ///
+    /// The 'credits.aleo' circuits commit to the network ID, so a network that does not bundle its own keys
+    /// (such as `DevnetV0`) synthesizes them for its network ID, and registers them for subsequent loads.
+    #[inline]
+    pub fn load_or_setup<A: circuit::Aleo<Network = N>>() -> Result<Self> {
+        // If the 'credits.aleo' keys are available for the network, load them.
+        if N::get_credits_verifying_key("transfer_public".to_string()).is_ok() {
+            return Self::load();
+        }
+
+        // Synthesize the 'credits.aleo' circuit keys for the network ID.
+        let process = Self::setup::<A, _>(&mut rand::thread_rng())?;
+        // Register the keys with the network, so they are loaded by the stores and subsequent processes.
+        let stack = process.get_stack("credits.aleo")?;
+        let keys = stack
+            .program()
+            .functions()
+            .keys()
+            .map(|function_name| {
+                let proving_key = stack.get_proving_key(function_name)?;
+                let verifying_key = stack.get_verifying_key(function_name)?;
+                Ok((function_name.to_string(), Arc::new((*proving_key).clone()), Arc::new((*verifying_key).clone())))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        N::set_credits_keys(keys)?;
+        // Return the process.
+        Ok(process)
+    }
+
     /// Initializes a new process without downloading the 'credits.aleo' circuit keys (for web contexts).
     #[inline]
     #[cfg(feature = "wasm")]
     pub fn load_web() -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            record_trace: false,
+            profiler: Default::default(),
+            key_cache: KeyCache::new_handle()?,
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -190,6 +305,53 @@ impl<N: Network> Process<N> {
         &self.universal_srs
     }
 
+    /// Returns `true` if executions record an execution trace for replay.
+    #[inline]
+    pub const fn record_trace(&self) -> bool {
+        self.record_trace
+    }
+
+    /// Sets whether executions record an execution trace for replay (see `Trace::to_execution_trace`).
+    #[inline]
+    pub fn set_record_trace(&mut self, record_trace: bool) {
+        self.record_trace = record_trace;
+    }
+
+    /// Returns the instruction profiler, if one is set.
+    #[inline]
+    pub fn profiler(&self) -> Option<Arc<dyn Profiler<N>>> {
+        self.profiler.read().clone()
+    }
+
+    /// Sets the instruction profiler for every stack in the process (and its clones), or unsets it if `None`.
+    /// When no profiler is set, instructions are not timed.
+    #[inline]
+    pub fn set_profiler(&self, profiler: Option<Arc<dyn Profiler<N>>>) {
+        *self.profiler.write() = profiler;
+    }
+
+    /// Sets the maximum number of proving keys kept in memory, or `None` for no limit (the default).
+    /// The least-recently used proving keys are evicted first, and are re-synthesized when their function
+    /// is next executed. The proving keys for 'credits.aleo' are never evicted, and do not count towards the capacity.
+    #[inline]
+    pub fn set_key_cache_capacity(&self, capacity: Option<usize>) -> Result<()> {
+        self.key_cache.lock().set_capacity(capacity)
+    }
+
+    /// Sets the maximum total size of the proving keys kept in memory in bytes, or `None` for no limit (the default).
+    /// The least-recently used proving keys are evicted first, as with `set_key_cache_capacity`, and
+    /// the proving keys for 'credits.aleo' do not count towards the budget.
+    #[inline]
+    pub fn set_key_cache_capacity_in_bytes(&self, capacity_in_bytes: Option<usize>) -> Result<()> {
+        self.key_cache.lock().set_capacity_in_bytes(capacity_in_bytes)
+    }
+
+    /// Returns the metrics of the proving key cache.
+    #[inline]
+    pub fn key_cache_metrics(&self) -> KeyCacheMetrics {
+        self.key_cache.lock().metrics()
+    }
+
     /// Returns `true` if the process contains the program with the given ID.
     #[inline]
     pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
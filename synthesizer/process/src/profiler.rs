@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+};
+use synthesizer_program::Opcode;
+
+use indexmap::IndexMap;
+use parking_lot::{Mutex, RwLock};
+use std::{sync::Arc, time::Duration};
+
+/// A handle to the profiler of a process, which is shared with each of its stacks.
+pub(crate) type ProfilerHandle<N> = Arc<RwLock<Option<Arc<dyn Profiler<N>>>>>;
+
+/// The stage in which an instruction or command is profiled.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProfileStage {
+    /// The console evaluation of an instruction.
+    Evaluate,
+    /// The circuit synthesis of an instruction.
+    Execute,
+    /// The finalize execution of a command.
+    Finalize,
+}
+
+/// A profile of a single instruction or command.
+#[derive(Clone, Debug)]
+pub struct ProfileEvent<N: Network> {
+    /// The stage in which the instruction was profiled.
+    pub stage: ProfileStage,
+    /// The program ID.
+    pub program_id: ProgramID<N>,
+    /// The closure, function, or finalize name.
+    pub name: Identifier<N>,
+    /// The index of the instruction in the closure, function, or finalize block.
+    pub index: usize,
+    /// The opcode of the instruction.
+    pub opcode: Opcode,
+    /// The time spent on the instruction.
+    pub duration: Duration,
+    /// The number of constraints introduced by the instruction.
+    pub num_constraints: u64,
+}
+
+/// A profiler receives a callback for each instruction evaluated, synthesized, or finalized by a process.
+pub trait Profiler<N: Network>: Send + Sync {
+    /// Records the profile of a single instruction or command.
+    fn record(&self, event: ProfileEvent<N>);
+}
+
+/// The aggregated profile of an opcode.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct OpcodeSummary {
+    /// The number of times the opcode was profiled.
+    pub count: u64,
+    /// The total time spent on the opcode.
+    pub duration: Duration,
+    /// The total number of constraints introduced by the opcode.
+    pub num_constraints: u64,
+}
+
+/// A profiler that aggregates the profiles for each opcode, by stage.
+#[derive(Default)]
+pub struct AggregatingProfiler {
+    /// The mapping of `(stage, opcode)` to the aggregated profile.
+    summary: Mutex<IndexMap<(ProfileStage, Opcode), OpcodeSummary>>,
+}
+
+impl AggregatingProfiler {
+    /// Initializes a new aggregating profiler.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the aggregated profile for each `(stage, opcode)`, in the order they were first profiled.
+    pub fn summary(&self) -> IndexMap<(ProfileStage, Opcode), OpcodeSummary> {
+        self.summary.lock().clone()
+    }
+
+    /// Returns the aggregated profile for each opcode in the given stage.
+    pub fn summary_for(&self, stage: ProfileStage) -> IndexMap<Opcode, OpcodeSummary> {
+        self.summary
+            .lock()
+            .iter()
+            .filter(|((candidate, _), _)| *candidate == stage)
+            .map(|((_, opcode), summary)| (*opcode, *summary))
+            .collect()
+    }
+
+    /// Clears the aggregated profiles.
+    pub fn reset(&self) {
+        self.summary.lock().clear()
+    }
+}
+
+impl<N: Network> Profiler<N> for AggregatingProfiler {
+    /// Adds the profile to the summary of its opcode.
+    fn record(&self, event: ProfileEvent<N>) {
+        let mut summary = self.summary.lock();
+        let entry = summary.entry((event.stage, event.opcode)).or_default();
+        entry.count += 1;
+        entry.duration += event.duration;
+        entry.num_constraints += event.num_constraints;
+    }
+}
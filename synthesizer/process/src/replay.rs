@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Process<N> {
+    /// Replays the given execution trace, and returns the response and execution.
+    ///
+    /// The proof may differ from the recorded execution, however the transition IDs,
+    /// output IDs, and global state root are ensured to match the recorded execution.
+    #[inline]
+    pub fn replay<A: circuit::Aleo<Network = N>>(
+        &self,
+        execution_trace: &ExecutionTrace<N>,
+    ) -> Result<(Response<N>, Execution<N>)> {
+        let timer = timer!("Process::replay");
+
+        // Ensure the recorded programs match the programs in the process.
+        for program in execution_trace.programs() {
+            ensure!(
+                self.get_stack(program.id())?.program() == program,
+                "Program '{}' in the process does not match the recorded program",
+                program.id()
+            );
+        }
+        lap!(timer, "Check the programs");
+
+        // Construct the authorization from the recorded requests.
+        let authorization = self.authorization_from_signed_requests(execution_trace.requests().to_vec())?;
+        // Retrieve the top-level request.
+        let request = authorization.peek_next()?;
+        // Construct the locator.
+        let locator = Locator::new(*request.program_id(), *request.function_name());
+        lap!(timer, "Construct the authorization");
+
+        // Execute the authorization.
+        let (response, mut trace) = self.execute::<A>(authorization)?;
+        lap!(timer, "Execute the authorization");
+
+        // Ensure the transition IDs match.
+        let transition_ids = trace.transitions().iter().map(|transition| *transition.id()).collect::<Vec<_>>();
+        ensure!(
+            transition_ids == execution_trace.transition_ids(),
+            "Replay of '{locator}' produced different transition IDs than the recorded execution"
+        );
+        // Ensure the output IDs match.
+        let output_ids =
+            trace.transitions().iter().flat_map(|transition| transition.output_ids().copied()).collect::<Vec<_>>();
+        ensure!(
+            output_ids == execution_trace.output_ids(),
+            "Replay of '{locator}' produced different output IDs than the recorded execution"
+        );
+
+        // Prepare the trace with the recorded global state root and state paths.
+        trace.prepare_from_execution_trace(execution_trace)?;
+        // Prove the execution with the recorded seed.
+        let execution = trace.prove_execution_with_seed::<A>(&locator.to_string(), execution_trace.seed())?;
+        lap!(timer, "Prove the execution");
+
+        // Ensure the global state root matches.
+        ensure!(
+            execution.global_state_root() == execution_trace.global_state_root(),
+            "Replay of '{locator}' produced a different global state root than the recorded execution"
+        );
+
+        finish!(timer);
+        Ok((response, execution))
+    }
+}
@@ -84,13 +84,14 @@ impl<N: Network> CallTrait<N> for Call<N> {
                 bail!("Expected {} inputs, found {}", closure.inputs().len(), inputs.len())
             }
             // Evaluate the closure, and load the outputs.
-            substack.evaluate_closure::<A>(
+            substack.evaluate_closure_at_depth::<A>(
                 &closure,
                 &inputs,
                 registers.call_stack(),
                 registers.signer()?,
                 registers.caller()?,
                 registers.tvk()?,
+                registers.call_depth() + 1,
             )?
         }
         // If the operator is a function, retrieve the function and compute the output.
@@ -102,7 +103,11 @@ impl<N: Network> CallTrait<N> for Call<N> {
             // Set the (console) caller.
             let console_caller = Some(*stack.program_id());
             // Evaluate the function.
-            let response = substack.evaluate_function::<A>(registers.call_stack(), console_caller)?;
+            let response = substack.evaluate_function_at_depth::<A>(
+                registers.call_stack(),
+                console_caller,
+                registers.call_depth() + 1,
+            )?;
             // Load the outputs.
             response.outputs().to_vec()
         }
@@ -168,13 +173,14 @@ impl<N: Network> CallTrait<N> for Call<N> {
         // If the operator is a closure, retrieve the closure and compute the output.
         let outputs = if let Ok(closure) = substack.program().get_closure(resource) {
             // Execute the closure, and load the outputs.
-            substack.execute_closure(
+            substack.execute_closure_at_depth(
                 &closure,
                 &inputs,
                 registers.call_stack(),
                 registers.signer_circuit()?,
                 registers.caller_circuit()?,
                 registers.tvk_circuit()?,
+                registers.call_depth() + 1,
             )?
         }
         // If the operator is a function, retrieve the function and compute the output.
@@ -201,6 +207,8 @@ impl<N: Network> CallTrait<N> for Call<N> {
 
                 // Set the (console) caller.
                 let console_caller = Some(*stack.program_id());
+                // Set the call depth of the callee.
+                let call_depth = registers.call_depth() + 1;
 
                 match registers.call_stack() {
                     // If the circuit is in authorize or synthesize mode, then add any external calls to the stack.
@@ -225,7 +233,8 @@ impl<N: Network> CallTrait<N> for Call<N> {
                         authorization.push(request.clone());
 
                         // Execute the request.
-                        let response = substack.execute_function::<A>(call_stack, console_caller)?;
+                        let response =
+                            substack.execute_function_at_depth::<A>(call_stack, console_caller, call_depth)?;
 
                         // Return the request and response.
                         (request, response)
@@ -247,7 +256,8 @@ impl<N: Network> CallTrait<N> for Call<N> {
                         call_stack.push(request.clone())?;
 
                         // Execute the request.
-                        let response = substack.execute_function::<A>(call_stack, console_caller)?;
+                        let response =
+                            substack.execute_function_at_depth::<A>(call_stack, console_caller, call_depth)?;
                         // Return the request and response.
                         (request, response)
                     }
@@ -266,10 +276,17 @@ impl<N: Network> CallTrait<N> for Call<N> {
                         })?;
 
                         // Evaluate the function, and load the outputs.
-                        let console_response =
-                            substack.evaluate_function::<A>(registers.call_stack().replicate(), console_caller)?;
+                        let console_response = substack.evaluate_function_at_depth::<A>(
+                            registers.call_stack().replicate(),
+                            console_caller,
+                            call_depth,
+                        )?;
                         // Execute the request.
-                        let response = substack.execute_function::<A>(registers.call_stack(), console_caller)?;
+                        let response = substack.execute_function_at_depth::<A>(
+                            registers.call_stack(),
+                            console_caller,
+                            call_depth,
+                        )?;
                         // Ensure the values are equal.
                         if console_response.outputs() != response.outputs() {
                             #[cfg(debug_assertions)]
@@ -14,6 +14,8 @@
 
 use super::*;
 
+use std::time::{Duration, Instant};
+
 impl<N: Network> Stack<N> {
     /// Deploys the given program ID, if it does not exist.
     #[inline]
@@ -22,6 +24,8 @@ impl<N: Network> Stack<N> {
 
         // Ensure the program contains functions.
         ensure!(!self.program.functions().is_empty(), "Program '{}' has no functions", self.program.id());
+        // Ensure the program passes the checks for new deployments.
+        self.check_new_deployment()?;
 
         // Initialize a vector for the verifying keys and certificates.
         let mut verifying_keys = Vec::with_capacity(self.program.functions().len());
@@ -52,12 +56,29 @@ impl<N: Network> Stack<N> {
     }
 
     /// Checks each function in the program on the given verifying key and certificate.
+    /// This is the strict check, which synthesizes the circuit of each function.
     #[inline]
     pub fn verify_deployment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
         deployment: &Deployment<N>,
         rng: &mut R,
     ) -> Result<()> {
+        self.verify_deployment_with_report::<A, R>(deployment, true, rng).map(|_| ())
+    }
+
+    /// Checks each function in the program on the given verifying key and certificate,
+    /// and returns the time spent synthesizing and verifying each function.
+    ///
+    /// If `strict` is `true`, the circuit of each function is synthesized, and the certificate of each
+    /// function is verified against its circuit assignment and included verifying key.
+    /// Otherwise, only the structure of the deployment is checked, and each function reports a zero duration.
+    #[inline]
+    pub fn verify_deployment_with_report<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        deployment: &Deployment<N>,
+        strict: bool,
+        rng: &mut R,
+    ) -> Result<IndexMap<Identifier<N>, Duration>> {
         let timer = timer!("Stack::verify_deployment");
 
         // Sanity Checks //
@@ -67,6 +88,16 @@ impl<N: Network> Stack<N> {
         // Ensure the program in the stack and deployment matches.
         ensure!(&self.program == deployment.program(), "The stack program does not match the deployment program");
 
+        // If the check is not strict, skip the synthesis of the circuits.
+        if !strict {
+            finish!(timer);
+            return Ok(deployment
+                .verifying_keys()
+                .iter()
+                .map(|(function_name, _)| (*function_name, Duration::ZERO))
+                .collect());
+        }
+
         // Check Verifying Keys //
 
         let program_id = self.program.id();
@@ -116,8 +147,11 @@ impl<N: Network> Stack<N> {
         }
 
         // Verify the certificates.
-        cfg_iter!(call_stacks).zip_eq(deployment.verifying_keys()).try_for_each(
-            |((function_name, call_stack, assignments), (_, (verifying_key, certificate)))| {
+        let report = cfg_iter!(call_stacks)
+            .zip_eq(deployment.verifying_keys())
+            .map(|((function_name, call_stack, assignments), (_, (verifying_key, certificate)))| {
+                // Start the timer for the function.
+                let start = Instant::now();
                 // Synthesize the circuit.
                 if let Err(err) = self.execute_function::<A>(call_stack.clone(), None) {
                     bail!("Failed to synthesize the circuit for '{function_name}': {err}")
@@ -132,12 +166,24 @@ impl<N: Network> Stack<N> {
                         }
                     }
                 };
-                Ok(())
-            },
-        )?;
+                Ok((**function_name, start.elapsed()))
+            })
+            .collect::<Result<IndexMap<_, _>>>()?;
 
         finish!(timer);
 
-        Ok(())
+        Ok(report)
+    }
+
+    /// Checks the program against the rules that apply only to new deployments.
+    ///
+    /// Note: These rules are not checked when a deployment is verified or loaded, as deployments in past blocks
+    /// may not satisfy them, and a node that syncs those blocks must still accept them. Instead, these rules are
+    /// checked when a program is deployed, and when a new deployment is received (see `Process::check_new_deployment`).
+    pub(crate) fn check_new_deployment(&self) -> Result<()> {
+        // Ensure no name in the program shadows an imported program or a closure.
+        self.program.check_shadowed_names()?;
+        // Ensure no function in the program exceeds the maximum call depth.
+        self.check_call_depths()
     }
 }
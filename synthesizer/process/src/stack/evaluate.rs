@@ -13,14 +13,16 @@
 // limitations under the License.
 
 use super::*;
+use synthesizer_program::Call;
 
 impl<N: Network> StackEvaluate<N> for Stack<N> {
-    /// Evaluates a program closure on the given inputs.
+    /// Evaluates a program closure on the given inputs, at the given call depth.
     ///
     /// # Errors
     /// This method will halt if the given inputs are not the same length as the input statements.
+    /// This method will halt if the call depth exceeds `N::MAX_CALL_DEPTH`.
     #[inline]
-    fn evaluate_closure<A: circuit::Aleo<Network = N>>(
+    fn evaluate_closure_at_depth<A: circuit::Aleo<Network = N>>(
         &self,
         closure: &Closure<N>,
         inputs: &[Value<N>],
@@ -28,16 +30,21 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         signer: Address<N>,
         caller: Address<N>,
         tvk: Field<N>,
+        call_depth: usize,
     ) -> Result<Vec<Value<N>>> {
         let timer = timer!("Stack::evaluate_closure");
 
+        // Ensure the call depth does not exceed the maximum.
+        CallDepthExceeded::ensure(self.program_id(), closure.name(), call_depth)?;
+
         // Ensure the number of inputs matches the number of input statements.
         if closure.inputs().len() != inputs.len() {
             bail!("Expected {} inputs, found {}", closure.inputs().len(), inputs.len())
         }
 
         // Initialize the registers.
-        let mut registers = Registers::<N, A>::new(call_stack, self.get_register_types(closure.name())?.clone());
+        let mut registers =
+            Registers::<N, A>::new_at_depth(call_stack, self.get_register_types(closure.name())?.clone(), call_depth);
         // Set the transition signer.
         registers.set_signer(signer);
         // Set the transition caller.
@@ -53,12 +60,29 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         })?;
         lap!(timer, "Store the inputs");
 
+        // Retrieve the profiler, if one is set.
+        let profiler = self.profiler();
+
         // Evaluate the instructions.
-        for instruction in closure.instructions() {
+        for (index, instruction) in closure.instructions().iter().enumerate() {
+            // If the profiler is set, start the timer.
+            let start = profiler.as_ref().map(|_| Instant::now());
             // If the evaluation fails, bail and return the error.
             if let Err(error) = instruction.evaluate(self, &mut registers) {
                 bail!("Failed to evaluate instruction ({instruction}): {error}");
             }
+            // If the profiler is set, record the instruction.
+            if let (Some(profiler), Some(start)) = (&profiler, start) {
+                profiler.record(ProfileEvent {
+                    stage: ProfileStage::Evaluate,
+                    program_id: *self.program_id(),
+                    name: *closure.name(),
+                    index,
+                    opcode: instruction.opcode(),
+                    duration: start.elapsed(),
+                    num_constraints: 0,
+                });
+            }
         }
         lap!(timer, "Evaluate the instructions");
 
@@ -91,15 +115,17 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         outputs
     }
 
-    /// Evaluates a program function on the given inputs.
+    /// Evaluates a program function on the given inputs, at the given call depth.
     ///
     /// # Errors
     /// This method will halt if the given inputs are not the same length as the input statements.
+    /// This method will halt if the call depth exceeds `N::MAX_CALL_DEPTH`.
     #[inline]
-    fn evaluate_function<A: circuit::Aleo<Network = N>>(
+    fn evaluate_function_at_depth<A: circuit::Aleo<Network = N>>(
         &self,
         call_stack: CallStack<N>,
         caller: Option<ProgramID<N>>,
+        call_depth: usize,
     ) -> Result<Response<N>> {
         let timer = timer!("Stack::evaluate_function");
 
@@ -130,6 +156,8 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
 
         // Retrieve the function, inputs, and transition view key.
         let function = self.get_function(request.function_name())?;
+        // Ensure the call depth does not exceed the maximum.
+        CallDepthExceeded::ensure(self.program_id(), function.name(), call_depth)?;
         let inputs = request.inputs();
         let signer = *request.signer();
         let caller = match caller {
@@ -153,7 +181,8 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         lap!(timer, "Perform input checks");
 
         // Initialize the registers.
-        let mut registers = Registers::<N, A>::new(call_stack, self.get_register_types(function.name())?.clone());
+        let mut registers =
+            Registers::<N, A>::new_at_depth(call_stack, self.get_register_types(function.name())?.clone(), call_depth);
         // Set the transition signer.
         registers.set_signer(signer);
         // Set the transition caller.
@@ -173,19 +202,43 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         })?;
         lap!(timer, "Store the inputs");
 
+        // Retrieve the profiler, if one is set.
+        let profiler = self.profiler();
+
         // Evaluate the instructions.
         // Note: We handle the `call` instruction separately, as it requires special handling.
-        for instruction in function.instructions() {
+        for (index, instruction) in function.instructions().iter().enumerate() {
+            // If the profiler is set, start the timer.
+            let start = profiler.as_ref().map(|_| Instant::now());
             // Evaluate the instruction.
-            let result = match instruction {
-                // If the instruction is a `call` instruction, we need to handle it separately.
-                Instruction::Call(call) => CallTrait::evaluate(call, self, &mut registers),
-                // Otherwise, evaluate the instruction normally.
-                _ => instruction.evaluate(self, &mut registers),
-            };
-            // If the evaluation fails, bail and return the error.
-            if let Err(error) = result {
-                bail!("Failed to evaluate instruction ({instruction}): {error}");
+            let result = ExecutionHalt::catch(self.program_id(), function.name(), index, || {
+                match instruction {
+                    // If the instruction is a `call` instruction, we need to handle it separately.
+                    Instruction::Call(call) => CallTrait::evaluate(call, self, &mut registers),
+                    // Otherwise, evaluate the instruction normally.
+                    _ => instruction.evaluate(self, &mut registers),
+                }
+            });
+            match result {
+                // If the function halted, return the halt.
+                Err(error) if error.is::<ExecutionHalt<N>>() => return Err(error),
+                // If a call exceeded the maximum call depth, return the error.
+                Err(error) if error.is::<CallDepthExceeded<N>>() => return Err(error),
+                // If the evaluation fails, bail and return the error.
+                Err(error) => bail!("Failed to evaluate instruction ({instruction}): {error}"),
+                Ok(()) => (),
+            }
+            // If the profiler is set, record the instruction.
+            if let (Some(profiler), Some(start)) = (&profiler, start) {
+                profiler.record(ProfileEvent {
+                    stage: ProfileStage::Evaluate,
+                    program_id: *self.program_id(),
+                    name: *function.name(),
+                    index,
+                    opcode: instruction.opcode(),
+                    duration: start.elapsed(),
+                    num_constraints: 0,
+                });
             }
         }
         lap!(timer, "Evaluate the instructions");
@@ -243,3 +296,136 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         )
     }
 }
+
+impl<N: Network> Stack<N> {
+    /// Evaluates a program function on the given inputs, without a request.
+    ///
+    /// As there is no request, the signer (and the top-level caller) is the zero address,
+    /// and the transition view key is zero, so any output records have placeholder nonces.
+    pub(crate) fn evaluate_function_without_request<A: circuit::Aleo<Network = N>>(
+        &self,
+        function_name: &Identifier<N>,
+        inputs: &[Value<N>],
+        signer: Address<N>,
+        caller: Option<ProgramID<N>>,
+    ) -> Result<Vec<Value<N>>> {
+        let timer = timer!("Stack::evaluate_function_without_request");
+
+        // Retrieve the function.
+        let function = self.get_function_ref(function_name)?;
+        // Ensure the number of inputs matches.
+        if function.inputs().len() != inputs.len() {
+            bail!(
+                "Function '{function_name}' in the program '{}' expects {} inputs, but {} were provided.",
+                self.program.id(),
+                function.inputs().len(),
+                inputs.len()
+            )
+        }
+        // Ensure the inputs match their declared types.
+        for (input, input_type) in inputs.iter().zip_eq(&function.input_types()) {
+            self.matches_value_type(input, input_type)?;
+        }
+        lap!(timer, "Verify the inputs");
+
+        // Initialize the registers.
+        let call_stack = CallStack::evaluate(Authorization::try_from((vec![], vec![]))?)?;
+        let mut registers = Registers::<N, A>::new(call_stack, self.get_register_types(function.name())?.clone());
+        // Set the transition signer.
+        registers.set_signer(signer);
+        // Set the transition caller.
+        registers.set_caller(match caller {
+            // If a caller is provided, then this is an evaluation of a child function.
+            Some(caller) => caller.to_address()?,
+            // If no caller is provided, then this is an evaluation of a top-level function.
+            None => signer,
+        });
+        // Set the transition view key.
+        registers.set_tvk(Field::zero());
+        lap!(timer, "Initialize the registers");
+
+        // Store the inputs.
+        function.inputs().iter().map(|i| i.register()).zip_eq(inputs).try_for_each(|(register, input)| {
+            // Assign the input value to the register.
+            registers.store(self, register, input.clone())
+        })?;
+        lap!(timer, "Store the inputs");
+
+        // Evaluate the instructions.
+        // Note: Function calls are evaluated directly, as there are no requests to consume.
+        for instruction in function.instructions() {
+            // Evaluate the instruction.
+            let result = match instruction {
+                Instruction::Call(call) if call.is_function_call(self)? => {
+                    self.evaluate_function_call_without_request::<A>(call, &mut registers, signer)
+                }
+                Instruction::Call(call) => CallTrait::evaluate(call, self, &mut registers),
+                _ => instruction.evaluate(self, &mut registers),
+            };
+            // If the evaluation fails, bail and return the error.
+            if let Err(error) = result {
+                bail!("Failed to evaluate instruction ({instruction}): {error}");
+            }
+        }
+        lap!(timer, "Evaluate the instructions");
+
+        // Load the outputs.
+        let outputs = function
+            .outputs()
+            .iter()
+            .map(|output| {
+                match output.operand() {
+                    // If the operand is a literal, use the literal directly.
+                    Operand::Literal(literal) => Ok(Value::Plaintext(Plaintext::from(literal))),
+                    // If the operand is a register, retrieve the stack value from the register.
+                    Operand::Register(register) => registers.load(self, &Operand::Register(register.clone())),
+                    // If the operand is the program ID, convert the program ID into an address.
+                    Operand::ProgramID(program_id) => {
+                        Ok(Value::Plaintext(Plaintext::from(Literal::Address(program_id.to_address()?))))
+                    }
+                    // If the operand is the signer, retrieve the signer from the registers.
+                    Operand::Signer => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.signer()?)))),
+                    // If the operand is the caller, retrieve the caller from the registers.
+                    Operand::Caller => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.caller()?)))),
+                    // If the operand is the block height, throw an error.
+                    Operand::BlockHeight => bail!("Cannot retrieve the block height from a function scope."),
+                }
+            })
+            .collect();
+        lap!(timer, "Load the outputs");
+
+        finish!(timer);
+        outputs
+    }
+
+    /// Evaluates the given call to an external function, without a request.
+    fn evaluate_function_call_without_request<A: circuit::Aleo<Network = N>>(
+        &self,
+        call: &Call<N>,
+        registers: &mut Registers<N, A>,
+        signer: Address<N>,
+    ) -> Result<()> {
+        // Retrieve the substack and function name.
+        let (substack, function_name) = match call.operator() {
+            CallOperator::Locator(locator) => (self.get_external_stack(locator.program_id())?, locator.resource()),
+            CallOperator::Resource(resource) => {
+                bail!("Cannot call '{resource}'. Use a closure ('closure {resource}:') instead.")
+            }
+        };
+        // Load the operands values.
+        let inputs: Vec<_> = call.operands().iter().map(|operand| registers.load(self, operand)).try_collect()?;
+        // Evaluate the function.
+        let outputs = substack.evaluate_function_without_request::<A>(
+            function_name,
+            &inputs,
+            signer,
+            Some(*self.program_id()),
+        )?;
+        // Assign the outputs to the destination registers.
+        for (output, register) in outputs.into_iter().zip_eq(&call.destinations()) {
+            // Assign the output to the register.
+            registers.store(self, register, output)?;
+        }
+        Ok(())
+    }
+}
@@ -15,12 +15,13 @@
 use super::*;
 
 impl<N: Network> StackExecute<N> for Stack<N> {
-    /// Executes a program closure on the given inputs.
+    /// Executes a program closure on the given inputs, at the given call depth.
     ///
     /// # Errors
     /// This method will halt if the given inputs are not the same length as the input statements.
+    /// This method will halt if the call depth exceeds `N::MAX_CALL_DEPTH`.
     #[inline]
-    fn execute_closure<A: circuit::Aleo<Network = N>>(
+    fn execute_closure_at_depth<A: circuit::Aleo<Network = N>>(
         &self,
         closure: &Closure<N>,
         inputs: &[circuit::Value<A>],
@@ -28,9 +29,15 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         signer: circuit::Address<A>,
         caller: circuit::Address<A>,
         tvk: circuit::Field<A>,
+        call_depth: usize,
     ) -> Result<Vec<circuit::Value<A>>> {
         let timer = timer!("Stack::execute_closure");
 
+        // Ensure the call depth does not exceed the maximum, unless a deployment is being checked.
+        if !matches!(call_stack, CallStack::CheckDeployment(..)) {
+            CallDepthExceeded::ensure(self.program_id(), closure.name(), call_depth)?;
+        }
+
         // Ensure the call stack is not `Evaluate`.
         ensure!(!matches!(call_stack, CallStack::Evaluate(..)), "Illegal operation: cannot evaluate in execute mode");
 
@@ -44,7 +51,8 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         let num_public = A::num_public();
 
         // Initialize the registers.
-        let mut registers = Registers::new(call_stack, self.get_register_types(closure.name())?.clone());
+        let mut registers =
+            Registers::new_at_depth(call_stack, self.get_register_types(closure.name())?.clone(), call_depth);
         // Set the transition signer, as a circuit.
         registers.set_signer_circuit(signer);
         // Set the transition caller, as a circuit.
@@ -66,8 +74,13 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         })?;
         lap!(timer, "Store the inputs");
 
+        // Retrieve the profiler, if one is set.
+        let profiler = self.profiler();
+
         // Execute the instructions.
-        for instruction in closure.instructions() {
+        for (index, instruction) in closure.instructions().iter().enumerate() {
+            // If the profiler is set, start the timer and record the number of constraints.
+            let start = profiler.as_ref().map(|_| (Instant::now(), A::num_constraints()));
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
                 // If the evaluation fails, bail and return the error.
@@ -77,6 +90,18 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             }
             // Execute the instruction.
             instruction.execute(self, &mut registers)?;
+            // If the profiler is set, record the instruction.
+            if let (Some(profiler), Some((start, num_constraints))) = (&profiler, start) {
+                profiler.record(ProfileEvent {
+                    stage: ProfileStage::Execute,
+                    program_id: *self.program_id(),
+                    name: *closure.name(),
+                    index,
+                    opcode: instruction.opcode(),
+                    duration: start.elapsed(),
+                    num_constraints: A::num_constraints().saturating_sub(num_constraints),
+                });
+            }
         }
         lap!(timer, "Execute the instructions");
 
@@ -124,17 +149,19 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         outputs
     }
 
-    /// Executes a program function on the given inputs.
+    /// Executes a program function on the given inputs, at the given call depth.
     ///
     /// Note: To execute a transition, do **not** call this method. Instead, call `Process::execute`.
     ///
     /// # Errors
     /// This method will halt if the given inputs are not the same length as the input statements.
+    /// This method will halt if the call depth exceeds `N::MAX_CALL_DEPTH`.
     #[inline]
-    fn execute_function<A: circuit::Aleo<Network = N>>(
+    fn execute_function_at_depth<A: circuit::Aleo<Network = N>>(
         &self,
         mut call_stack: CallStack<N>,
         console_caller: Option<ProgramID<N>>,
+        call_depth: usize,
     ) -> Result<Response<N>> {
         let timer = timer!("Stack::execute_function");
 
@@ -167,6 +194,10 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 
         // Retrieve the function from the program.
         let function = self.get_function(console_request.function_name())?;
+        // Ensure the call depth does not exceed the maximum, unless a deployment is being checked.
+        if !matches!(call_stack, CallStack::CheckDeployment(..)) {
+            CallDepthExceeded::ensure(self.program_id(), function.name(), call_depth)?;
+        }
         // Retrieve the number of inputs.
         let num_inputs = function.inputs().len();
         // Ensure the number of inputs matches the number of input statements.
@@ -191,7 +222,8 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         lap!(timer, "Verify the console request");
 
         // Initialize the registers.
-        let mut registers = Registers::new(call_stack, self.get_register_types(function.name())?.clone());
+        let mut registers =
+            Registers::new_at_depth(call_stack, self.get_register_types(function.name())?.clone(), call_depth);
 
         use circuit::{Eject, Inject};
 
@@ -252,33 +284,65 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         // Initialize a tracker to determine if there are any function calls.
         let mut contains_function_call = false;
 
+        // Retrieve the profiler, if one is set.
+        let profiler = self.profiler();
+
         // Execute the instructions.
-        for instruction in function.instructions() {
+        for (index, instruction) in function.instructions().iter().enumerate() {
+            // If the profiler is set, start the timer and record the number of constraints.
+            let start = profiler.as_ref().map(|_| (Instant::now(), A::num_constraints()));
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
                 // Evaluate the instruction.
-                let result = match instruction {
-                    // If the instruction is a `call` instruction, we need to handle it separately.
-                    Instruction::Call(call) => CallTrait::evaluate(call, self, &mut registers),
-                    // Otherwise, evaluate the instruction normally.
-                    _ => instruction.evaluate(self, &mut registers),
-                };
-                // If the evaluation fails, bail and return the error.
-                if let Err(error) = result {
-                    bail!("Failed to evaluate instruction ({instruction}): {error}");
+                let result = ExecutionHalt::catch(self.program_id(), function.name(), index, || {
+                    match instruction {
+                        // If the instruction is a `call` instruction, we need to handle it separately.
+                        Instruction::Call(call) => CallTrait::evaluate(call, self, &mut registers),
+                        // Otherwise, evaluate the instruction normally.
+                        _ => instruction.evaluate(self, &mut registers),
+                    }
+                });
+                match result {
+                    // If the function halted, return the halt.
+                    Err(error) if error.is::<ExecutionHalt<N>>() => return Err(error),
+                    // If a call exceeded the maximum call depth, return the error.
+                    Err(error) if error.is::<CallDepthExceeded<N>>() => return Err(error),
+                    // If the evaluation fails, bail and return the error.
+                    Err(error) => bail!("Failed to evaluate instruction ({instruction}): {error}"),
+                    Ok(()) => (),
                 }
             }
 
             // Execute the instruction.
-            let result = match instruction {
-                // If the instruction is a `call` instruction, we need to handle it separately.
-                Instruction::Call(call) => CallTrait::execute(call, self, &mut registers),
-                // Otherwise, execute the instruction normally.
-                _ => instruction.execute(self, &mut registers),
-            };
-            // If the execution fails, bail and return the error.
-            if let Err(error) = result {
-                bail!("Failed to execute instruction ({instruction}): {error}");
+            let result = ExecutionHalt::catch(self.program_id(), function.name(), index, || {
+                match instruction {
+                    // If the instruction is a `call` instruction, we need to handle it separately.
+                    Instruction::Call(call) => CallTrait::execute(call, self, &mut registers),
+                    // Otherwise, execute the instruction normally.
+                    _ => instruction.execute(self, &mut registers),
+                }
+            });
+            match result {
+                // If the function halted, return the halt.
+                Err(error) if error.is::<ExecutionHalt<N>>() => return Err(error),
+                // If a call exceeded the maximum call depth, return the error.
+                Err(error) if error.is::<CallDepthExceeded<N>>() => return Err(error),
+                // If the execution fails, bail and return the error.
+                Err(error) => bail!("Failed to execute instruction ({instruction}): {error}"),
+                Ok(()) => (),
+            }
+
+            // If the profiler is set, record the instruction.
+            if let (Some(profiler), Some((start, num_constraints))) = (&profiler, start) {
+                profiler.record(ProfileEvent {
+                    stage: ProfileStage::Execute,
+                    program_id: *self.program_id(),
+                    name: *function.name(),
+                    index,
+                    opcode: instruction.opcode(),
+                    duration: start.elapsed(),
+                    num_constraints: A::num_constraints().saturating_sub(num_constraints),
+                });
             }
 
             // If the instruction was a function call, then set the tracker to `true`.
@@ -395,17 +459,15 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         // Eject the circuit assignment and reset the circuit.
         let assignment = A::eject_assignment_and_reset();
 
-        // If the circuit is in `Synthesize` or `Execute` mode, synthesize the circuit key, if it does not exist.
-        if matches!(registers.call_stack(), CallStack::Synthesize(..))
-            || matches!(registers.call_stack(), CallStack::Execute(..))
-        {
-            // If the proving key does not exist, then synthesize it.
-            if !self.contains_proving_key(function.name()) {
-                // Add the circuit key to the mapping.
-                self.synthesize_from_assignment(function.name(), &assignment)?;
-                lap!(timer, "Synthesize the {} circuit key", function.name());
+        // If the circuit is in `Synthesize` or `Execute` mode, retrieve the proving key, synthesizing it if it does not exist.
+        let proving_key = match registers.call_stack() {
+            CallStack::Synthesize(..) | CallStack::Execute(..) => {
+                let proving_key = self.get_or_synthesize_proving_key(function.name(), &assignment)?;
+                lap!(timer, "Retrieve the {} circuit key", function.name());
+                Some(proving_key)
             }
-        }
+            _ => None,
+        };
         // If the circuit is in `Authorize` mode, then save the transition.
         if let CallStack::Authorize(_, _, authorization) = registers.call_stack() {
             // Construct the transition.
@@ -437,7 +499,9 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             let transition = Transition::from(&console_request, &response, &output_types, &output_registers)?;
 
             // Retrieve the proving key.
-            let proving_key = self.get_proving_key(function.name())?;
+            let Some(proving_key) = proving_key else {
+                bail!("Proving key not found for: {}/{}", self.program_id(), function.name())
+            };
             // Construct the call metrics.
             let metrics = CallMetrics {
                 program_id: *self.program_id(),
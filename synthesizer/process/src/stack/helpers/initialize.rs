@@ -24,9 +24,12 @@ impl<N: Network> Stack<N> {
             external_stacks: Default::default(),
             register_types: Default::default(),
             finalize_types: Default::default(),
+            call_depths: Default::default(),
             universal_srs: process.universal_srs().clone(),
-            proving_keys: Default::default(),
+            // The stack writes its proving keys to a private cache, until it is added to the process.
+            proving_keys: KeyCache::new_handle()?,
             verifying_keys: Default::default(),
+            profiler: process.profiler.clone(),
         };
 
         // Add all of the imports into the stack.
@@ -102,6 +105,24 @@ impl<N: Network> Stack<N> {
         // Add the function name and register types to the stack.
         self.register_types.insert(*name, register_types);
 
+        // Compute the static call depth of the function, from the stored call depths of its callees.
+        let mut call_depth = 0;
+        for instruction in function.instructions() {
+            if let Instruction::Call(call) = instruction {
+                // Note: Closures do not contain calls, and a local call can only invoke a closure.
+                let callee_depth = match call.operator() {
+                    CallOperator::Locator(locator) if call.is_function_call(self)? => {
+                        self.get_external_stack(locator.program_id())?.get_call_depth(locator.resource())?
+                    }
+                    _ => 0,
+                };
+                // Update the call depth, counting the call itself.
+                call_depth = call_depth.max(callee_depth + 1);
+            }
+        }
+        // Add the function name and call depth to the stack.
+        self.call_depths.insert(*name, call_depth);
+
         // If the function contains a finalize, insert it.
         if let Some(finalize) = function.finalize_logic() {
             // Compute the finalize types.
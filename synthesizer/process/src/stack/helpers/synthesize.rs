@@ -86,4 +86,34 @@ impl<N: Network> Stack<N> {
         // Insert the verifying key.
         self.insert_verifying_key(function_name, verifying_key)
     }
+
+    /// Returns the proving key for the given function name, and if it is not cached,
+    /// synthesizes and stores the `(proving_key, verifying_key)` from the given assignment.
+    ///
+    /// Note: The proving key is returned from the same cache lookup (or insertion) that finds it,
+    /// so it can not be evicted by a concurrent insertion in between.
+    #[inline]
+    pub(crate) fn get_or_synthesize_proving_key(
+        &self,
+        function_name: &Identifier<N>,
+        assignment: &circuit::Assignment<N::Field>,
+    ) -> Result<ProvingKey<N>> {
+        // Ensure the function name exists in the program.
+        ensure!(
+            self.program.contains_function(function_name),
+            "Function '{function_name}' does not exist in program '{}'.",
+            self.program.id()
+        );
+        // Return the proving key, if it is cached.
+        if let Some(proving_key) = self.proving_keys.lock().get(self.program.id(), function_name) {
+            return Ok(proving_key);
+        }
+
+        // Synthesize the proving and verifying key.
+        let (proving_key, verifying_key) = self.universal_srs.to_circuit_key(&function_name.to_string(), assignment)?;
+        // Insert the verifying key.
+        self.insert_verifying_key(function_name, verifying_key)?;
+        // Insert the proving key, and return it.
+        Ok(self.proving_keys.lock().insert(*self.program.id(), *function_name, proving_key))
+    }
 }
@@ -36,7 +36,20 @@ mod evaluate;
 mod execute;
 mod helpers;
 
-use crate::{traits::*, CallMetrics, Process, Trace};
+use crate::{
+    traits::*,
+    CallDepthExceeded,
+    CallMetrics,
+    ExecutionHalt,
+    KeyCache,
+    KeyCacheHandle,
+    Process,
+    ProfileEvent,
+    ProfileStage,
+    Profiler,
+    ProfilerHandle,
+    Trace,
+};
 use console::{
     account::{Address, PrivateKey},
     network::prelude::*,
@@ -68,7 +81,7 @@ use synthesizer_snark::{Certificate, ProvingKey, UniversalSRS, VerifyingKey};
 use aleo_std::prelude::{finish, lap, timer};
 use indexmap::IndexMap;
 use parking_lot::RwLock;
-use std::sync::Arc;
+use std::{sync::Arc, time::Instant};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -174,12 +187,16 @@ pub struct Stack<N: Network> {
     register_types: IndexMap<Identifier<N>, RegisterTypes<N>>,
     /// The mapping of finalize names to their register types.
     finalize_types: IndexMap<Identifier<N>, FinalizeTypes<N>>,
+    /// The mapping of function names to their static call depths.
+    call_depths: IndexMap<Identifier<N>, usize>,
     /// The universal SRS.
     universal_srs: Arc<UniversalSRS<N>>,
-    /// The mapping of function name to proving key.
-    proving_keys: Arc<RwLock<IndexMap<Identifier<N>, ProvingKey<N>>>>,
+    /// The cache of proving keys, which is shared with the process once the stack is added to it.
+    proving_keys: KeyCacheHandle<N>,
     /// The mapping of function name to verifying key.
     verifying_keys: Arc<RwLock<IndexMap<Identifier<N>, VerifyingKey<N>>>>,
+    /// The instruction profiler, which is shared with the process.
+    profiler: ProfilerHandle<N>,
 }
 
 impl<N: Network> Stack<N> {
@@ -203,7 +220,7 @@ impl<N: Network> Stack<N> {
         // Ensure the program deserializes from a string correctly.
         ensure!(program == &Program::from_str(&program_string)?, "Program string serialization failed");
 
-        // Return the stack.
+        // Construct the stack for the program.
         Stack::initialize(process, program)
     }
 }
@@ -311,10 +328,38 @@ impl<N: Network> StackProgramTypes<N> for Stack<N> {
 }
 
 impl<N: Network> Stack<N> {
+    /// Returns the instruction profiler, if one is set.
+    #[inline]
+    pub(crate) fn profiler(&self) -> Option<Arc<dyn Profiler<N>>> {
+        self.profiler.read().clone()
+    }
+
     /// Returns `true` if the proving key for the given function name exists.
     #[inline]
     pub fn contains_proving_key(&self, function_name: &Identifier<N>) -> bool {
-        self.proving_keys.read().contains_key(function_name)
+        self.proving_keys.lock().contains(self.program.id(), function_name)
+    }
+
+    /// Returns the maximum call depth reached by the given function, where the function itself is at depth 0.
+    #[inline]
+    pub fn get_call_depth(&self, function_name: &Identifier<N>) -> Result<usize> {
+        // Retrieve the call depth.
+        self.call_depths
+            .get(function_name)
+            .copied()
+            .ok_or_else(|| anyhow!("Call depth for '{function_name}' does not exist"))
+    }
+
+    /// Ensures the call depth of every function in the program does not exceed `N::MAX_CALL_DEPTH`.
+    ///
+    /// Note: This is checked for new programs only, so that deployed programs can still be loaded.
+    /// The call depth is also limited when proving, see `CallDepthExceeded::ensure`.
+    #[inline]
+    pub(crate) fn check_call_depths(&self) -> Result<()> {
+        for (function_name, call_depth) in &self.call_depths {
+            CallDepthExceeded::ensure(self.program_id(), function_name, *call_depth)?;
+        }
+        Ok(())
     }
 
     /// Returns `true` if the verifying key for the given function name exists.
@@ -329,8 +374,8 @@ impl<N: Network> Stack<N> {
         // If the program is 'credits.aleo', try to load the proving key, if it does not exist.
         self.try_insert_credits_function_proving_key(function_name)?;
         // Return the proving key, if it exists.
-        match self.proving_keys.read().get(function_name) {
-            Some(proving_key) => Ok(proving_key.clone()),
+        match self.proving_keys.lock().get(self.program.id(), function_name) {
+            Some(proving_key) => Ok(proving_key),
             None => bail!("Proving key not found for: {}/{function_name}", self.program.id()),
         }
     }
@@ -355,7 +400,7 @@ impl<N: Network> Stack<N> {
             self.program.id()
         );
         // Insert the proving key.
-        self.proving_keys.write().insert(*function_name, proving_key);
+        let _ = self.proving_keys.lock().insert(*self.program.id(), *function_name, proving_key);
         Ok(())
     }
 
@@ -373,10 +418,29 @@ impl<N: Network> Stack<N> {
         Ok(())
     }
 
+    /// Moves the proving keys of the stack into the given cache, and writes all future proving keys to it.
+    /// Any stale proving keys for the program in the given cache are removed.
+    pub(crate) fn bind_key_cache(&mut self, key_cache: &KeyCacheHandle<N>) {
+        // If the stack already uses the given cache, there is nothing to move.
+        if Arc::ptr_eq(&self.proving_keys, key_cache) {
+            return;
+        }
+        {
+            let mut key_cache = key_cache.lock();
+            // Remove the stale proving keys for the program.
+            key_cache.remove_program(self.program.id());
+            // Move the proving keys of the stack into the cache.
+            for (program_id, function_name, proving_key) in self.proving_keys.lock().drain() {
+                let _ = key_cache.insert(program_id, function_name, proving_key);
+            }
+        }
+        self.proving_keys = key_cache.clone();
+    }
+
     /// Removes the proving key for the given function name.
     #[inline]
     pub fn remove_proving_key(&self, function_name: &Identifier<N>) {
-        self.proving_keys.write().remove(function_name);
+        self.proving_keys.lock().remove(self.program.id(), function_name);
     }
 
     /// Removes the verifying key for the given function name.
@@ -390,9 +454,7 @@ impl<N: Network> Stack<N> {
     /// Inserts the proving key if the program ID is 'credits.aleo'.
     fn try_insert_credits_function_proving_key(&self, function_name: &Identifier<N>) -> Result<()> {
         // If the program is 'credits.aleo' and it does not exist yet, load the proving key directly.
-        if self.program_id() == &ProgramID::from_str("credits.aleo")?
-            && !self.proving_keys.read().contains_key(function_name)
-        {
+        if self.program_id() == &ProgramID::from_str("credits.aleo")? && !self.contains_proving_key(function_name) {
             // Load the 'credits.aleo' function proving key.
             let proving_key = N::get_credits_proving_key(function_name.to_string())?;
             // Insert the 'credits.aleo' function proving key.
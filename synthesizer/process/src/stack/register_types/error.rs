@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A type error found while checking an instruction of a closure or function.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TypeCheckError<N: Network> {
+    /// The index of the instruction in the closure or function.
+    pub index: usize,
+    /// The opcode of the instruction.
+    pub opcode: Opcode,
+    /// The position of the operand at fault, if it is known.
+    pub operand: Option<usize>,
+    /// The expected type of the operand at fault, if it is known.
+    pub expected: Option<RegisterType<N>>,
+    /// The found type of the operand at fault, if it is known.
+    pub found: Option<RegisterType<N>>,
+    /// The reason for the error.
+    pub reason: String,
+}
+
+impl<N: Network> Debug for TypeCheckError<N> {
+    /// Prints the type error as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for TypeCheckError<N> {
+    /// Prints the type error as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Instruction {} ('{}')", self.index, self.opcode)?;
+        if let Some(operand) = self.operand {
+            write!(f, ", operand {operand}")?;
+        }
+        if let (Some(expected), Some(found)) = (&self.expected, &self.found) {
+            write!(f, ": expected '{expected}', found '{found}'")?;
+        }
+        write!(f, " - {}", self.reason)
+    }
+}
+
+/// The type errors found while checking the instructions of a closure or function.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TypeCheckErrors<N: Network> {
+    /// The name of the closure or function.
+    name: Identifier<N>,
+    /// The type errors, in the order of their instructions.
+    errors: Vec<TypeCheckError<N>>,
+}
+
+impl<N: Network> TypeCheckErrors<N> {
+    /// Initializes a new instance of `TypeCheckErrors` for the given closure or function.
+    pub(super) const fn new(name: Identifier<N>, errors: Vec<TypeCheckError<N>>) -> Self {
+        Self { name, errors }
+    }
+
+    /// Returns the name of the closure or function.
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the type errors, in the order of their instructions.
+    pub fn errors(&self) -> &[TypeCheckError<N>] {
+        &self.errors
+    }
+}
+
+impl<N: Network> Debug for TypeCheckErrors<N> {
+    /// Prints the type errors as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for TypeCheckErrors<N> {
+    /// Prints the type errors as a string, with one line per error.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Found {} type error(s) in '{}':", self.errors.len(), self.name)?;
+        self.errors.iter().try_for_each(|error| write!(f, "\n  {error}"))
+    }
+}
+
+impl<N: Network> std::error::Error for TypeCheckErrors<N> {}
@@ -15,6 +15,8 @@
 use super::*;
 use synthesizer_program::CastType;
 
+use std::collections::HashSet;
+
 impl<N: Network> RegisterTypes<N> {
     /// Initializes a new instance of `RegisterTypes` for the given closure.
     /// Checks that the given closure is well-formed for the given stack.
@@ -38,9 +40,9 @@ impl<N: Network> RegisterTypes<N> {
             ensure!(instruction.opcode() != Opcode::Async, "An 'async' instruction is not allowed in closures");
             // Ensure the closure contains no call instructions.
             ensure!(instruction.opcode() != Opcode::Call, "A 'call' instruction is not allowed in closures");
-            // Check the instruction opcode, operands, and destinations.
-            register_types.check_instruction(stack, closure.name(), instruction)?;
         }
+        // Check the instruction opcodes, operands, and destinations.
+        register_types.check_instructions(stack, closure.name(), closure.instructions())?;
 
         // Step 3. Check the outputs are well-formed.
         for output in closure.outputs() {
@@ -85,10 +87,11 @@ impl<N: Network> RegisterTypes<N> {
         // - If the function has no finalize block, then it must **not** have `async` instructions.
         // - All `call` instructions must precede any `async` instruction.
 
+        // Check the instruction opcodes, operands, and destinations.
+        register_types.check_instructions(stack, function.name(), function.instructions())?;
+
         let mut async_ = None;
         for instruction in function.instructions() {
-            // Check the instruction opcode, operands, and destinations.
-            register_types.check_instruction(stack, function.name(), instruction)?;
             // Additional validation.
             match instruction.opcode() {
                 Opcode::Async => {
@@ -356,40 +359,142 @@ impl<N: Network> RegisterTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given instructions are well-formed, and reports the type errors of all instructions at once.
+    /// Note: An instruction that reads a destination of an ill-typed instruction is skipped,
+    /// as its type error would only be a consequence of the earlier type error.
+    #[inline]
+    fn check_instructions<'a>(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        closure_or_function_name: &Identifier<N>,
+        instructions: impl IntoIterator<Item = &'a Instruction<N>>,
+    ) -> Result<()>
+    where
+        N: 'a,
+    {
+        // Initialize a vector to store the type errors.
+        let mut errors = Vec::new();
+        // Initialize a set of the destination registers of the ill-typed instructions.
+        let mut skipped = HashSet::new();
+
+        for (index, instruction) in instructions.into_iter().enumerate() {
+            // Determine if the instruction reads a destination of an ill-typed instruction.
+            let is_skipped = instruction.operands().iter().any(|operand| match operand {
+                Operand::Register(register) => skipped.contains(&register.locator()),
+                _ => false,
+            });
+            // Check the instruction opcode, operands, and destinations, unless it is skipped.
+            let result = match is_skipped {
+                true => Ok(()),
+                false => self.check_instruction(stack, closure_or_function_name, index, instruction),
+            };
+
+            // If the instruction is skipped or ill-typed, reserve its remaining destination registers,
+            // so that the subsequent instructions are still checked in order.
+            if is_skipped || result.is_err() {
+                for destination in instruction.destinations() {
+                    if let Register::Locator(locator) = destination {
+                        // Note: The placeholder type is never read, as any instruction that reads
+                        // this register is skipped, and the type errors are returned below.
+                        let placeholder = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean));
+                        if !self.contains(&destination) && self.add_destination(destination, placeholder).is_ok() {
+                            skipped.insert(locator);
+                        }
+                    }
+                }
+            }
+            // Store the type error.
+            if let Err(error) = result {
+                errors.push(error);
+            }
+        }
+
+        // Ensure there are no type errors.
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(TypeCheckErrors::new(*closure_or_function_name, errors).into()),
+        }
+    }
+
     /// Ensures the given instruction is well-formed.
     #[inline]
     fn check_instruction(
         &mut self,
         stack: &(impl StackMatches<N> + StackProgram<N>),
         closure_or_function_name: &Identifier<N>,
+        index: usize,
         instruction: &Instruction<N>,
-    ) -> Result<()> {
+    ) -> Result<(), TypeCheckError<N>> {
+        // Initialize a helper to construct a type error for the instruction.
+        let type_error = |operand, expected, found, reason: Error| TypeCheckError {
+            index,
+            opcode: instruction.opcode(),
+            operand,
+            expected,
+            found,
+            reason: reason.to_string(),
+        };
+
         // Ensure the opcode is well-formed.
-        self.check_instruction_opcode(stack, closure_or_function_name, instruction)?;
+        self.check_instruction_opcode(stack, closure_or_function_name, instruction)
+            .map_err(|error| type_error(None, None, None, error))?;
 
         // Initialize a vector to store the register types of the operands.
         let mut operand_types = Vec::with_capacity(instruction.operands().len());
         // Iterate over the operands, and retrieve the register type of each operand.
-        for operand in instruction.operands() {
+        for (position, operand) in instruction.operands().iter().enumerate() {
             // Retrieve and append the register type.
-            operand_types.push(self.get_type_from_operand(stack, operand)?);
+            operand_types.push(
+                self.get_type_from_operand(stack, operand)
+                    .map_err(|error| type_error(Some(position), None, None, error))?,
+            );
         }
 
         // Compute the destination register types.
-        let destination_types = instruction.output_types(stack, &operand_types)?;
+        let destination_types = instruction.output_types(stack, &operand_types).map_err(|error| {
+            // Determine the operand at fault, if possible.
+            match Self::find_mismatched_operand(stack, instruction, &operand_types) {
+                Some((position, expected, found)) => type_error(Some(position), Some(expected), Some(found), error),
+                None => type_error(None, None, None, error),
+            }
+        })?;
 
         // Insert the destination register.
         for (destination, destination_type) in
             instruction.destinations().into_iter().zip_eq(destination_types.into_iter())
         {
             // Ensure the destination register is a locator (and does not reference an access).
-            ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
+            if !matches!(destination, Register::Locator(..)) {
+                return Err(type_error(None, None, None, anyhow!("Destination '{destination}' must be a locator.")));
+            }
             // Insert the destination register.
-            self.add_destination(destination, destination_type)?;
+            self.add_destination(destination, destination_type).map_err(|error| type_error(None, None, None, error))?;
         }
         Ok(())
     }
 
+    /// Returns the position, expected type, and found type of the operand at fault in an ill-typed instruction.
+    /// The operand at fault is the last operand that makes the instruction well-typed,
+    /// when its type is replaced by the type of another operand. If there is no such operand, returns `None`.
+    fn find_mismatched_operand(
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        instruction: &Instruction<N>,
+        operand_types: &[RegisterType<N>],
+    ) -> Option<(usize, RegisterType<N>, RegisterType<N>)> {
+        for (position, found) in operand_types.iter().enumerate().rev() {
+            for expected in operand_types.iter().filter(|candidate| *candidate != found) {
+                // Replace the type of the operand with the candidate type.
+                let mut candidate_types = operand_types.to_vec();
+                candidate_types[position] = expected.clone();
+                // If the instruction is well-typed with the candidate type, then this operand is at fault.
+                if instruction.output_types(stack, &candidate_types).is_ok() {
+                    return Some((position, expected.clone(), found.clone()));
+                }
+            }
+        }
+        None
+    }
+
     /// Ensures the opcode is a valid opcode and corresponds to the correct instruction.
     /// This method is called when adding a new closure or function to the program.
     #[inline]
@@ -766,6 +871,46 @@ impl<N: Network> RegisterTypes<N> {
                 matches!(instruction, Instruction::HashManyPSD8(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
             ),
+            "hash_canonical.bhp256" => ensure!(
+                matches!(instruction, Instruction::HashCanonicalBHP256(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "hash_canonical.bhp512" => ensure!(
+                matches!(instruction, Instruction::HashCanonicalBHP512(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "hash_canonical.bhp768" => ensure!(
+                matches!(instruction, Instruction::HashCanonicalBHP768(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "hash_canonical.bhp1024" => ensure!(
+                matches!(instruction, Instruction::HashCanonicalBHP1024(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "hash_canonical.keccak256" => ensure!(
+                matches!(instruction, Instruction::HashCanonicalKeccak256(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "hash_canonical.keccak384" => ensure!(
+                matches!(instruction, Instruction::HashCanonicalKeccak384(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "hash_canonical.keccak512" => ensure!(
+                matches!(instruction, Instruction::HashCanonicalKeccak512(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "hash_canonical.psd2" => ensure!(
+                matches!(instruction, Instruction::HashCanonicalPSD2(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "hash_canonical.psd4" => ensure!(
+                matches!(instruction, Instruction::HashCanonicalPSD4(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "hash_canonical.psd8" => ensure!(
+                matches!(instruction, Instruction::HashCanonicalPSD8(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
             _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
         }
         Ok(())
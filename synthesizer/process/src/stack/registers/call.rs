@@ -20,4 +20,10 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersCall<N> for Registers<N
     fn call_stack(&self) -> CallStack<N> {
         self.call_stack.clone()
     }
+
+    /// Returns the call depth of the closure or function of these registers.
+    #[inline]
+    fn call_depth(&self) -> usize {
+        self.call_depth
+    }
 }
@@ -41,6 +41,8 @@ use indexmap::IndexMap;
 pub struct Registers<N: Network, A: circuit::Aleo<Network = N>> {
     /// The current call stack.
     call_stack: CallStack<N>,
+    /// The call depth of the closure or function, where the top-level function is at depth 0.
+    call_depth: usize,
     /// The mapping of all registers to their defined types.
     register_types: RegisterTypes<N>,
     /// The mapping of assigned console registers to their values.
@@ -65,8 +67,15 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
     /// Initializes a new set of registers, given the call stack.
     #[inline]
     pub fn new(call_stack: CallStack<N>, register_types: RegisterTypes<N>) -> Self {
+        Self::new_at_depth(call_stack, register_types, 0)
+    }
+
+    /// Initializes a new set of registers, given the call stack and the call depth.
+    #[inline]
+    pub fn new_at_depth(call_stack: CallStack<N>, register_types: RegisterTypes<N>, call_depth: usize) -> Self {
         Self {
             call_stack,
+            call_depth,
             register_types,
             console_registers: IndexMap::new(),
             circuit_registers: IndexMap::new(),
@@ -12,5 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod test_call_depth;
 pub mod test_credits;
 pub mod test_execute;
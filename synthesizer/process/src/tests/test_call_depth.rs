@@ -0,0 +1,195 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{StackEvaluate, StackExecute},
+    Assignments,
+    CallDepthExceeded,
+    CallStack,
+    Process,
+    Stack,
+    Trace,
+};
+use circuit::network::AleoV0;
+use console::{
+    account::PrivateKey,
+    network::{prelude::*, Testnet3},
+    program::{Identifier, ProgramID, Value},
+};
+use synthesizer_program::Program;
+
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+type CurrentNetwork = Testnet3;
+type CurrentAleo = AleoV0;
+
+/// Returns a chain of `length` programs, in the order they must be added to a process.
+/// The function `chain_0.aleo/f` calls `chain_1.aleo/f`, and so on, until the last program calls the closure `inc`.
+/// As such, the function `chain_0.aleo/f` reaches a call depth of `length`.
+fn sample_chain(length: usize) -> Vec<Program<CurrentNetwork>> {
+    assert!(length > 0);
+    // Construct the last program, which calls a closure.
+    let last = Program::from_str(&format!(
+        r"
+program chain_{}.aleo;
+
+closure inc:
+    input r0 as u64;
+    add r0 1u64 into r1;
+    output r1 as u64;
+
+function f:
+    input r0 as u64.public;
+    call inc r0 into r1;
+    output r1 as u64.public;",
+        length - 1
+    ))
+    .unwrap();
+    let mut programs = vec![last];
+    // Construct the remaining programs, which each call the next program in the chain.
+    for index in (0..length - 1).rev() {
+        programs.push(
+            Program::from_str(&format!(
+                r"
+import chain_{next}.aleo;
+
+program chain_{index}.aleo;
+
+function f:
+    input r0 as u64.public;
+    call chain_{next}.aleo/f r0 into r1;
+    output r1 as u64.public;",
+                next = index + 1
+            ))
+            .unwrap(),
+        );
+    }
+    programs
+}
+
+#[test]
+fn test_call_depth_at_limit_is_accepted() {
+    // Initialize the process.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+    // Add a chain that reaches exactly the maximum call depth.
+    for program in sample_chain(CurrentNetwork::MAX_CALL_DEPTH) {
+        process.add_program(&program).unwrap();
+    }
+
+    // Ensure the static call depth of the first function is the maximum call depth.
+    let stack = process.get_stack(ProgramID::from_str("chain_0.aleo").unwrap()).unwrap();
+    let call_depth = stack.get_call_depth(&Identifier::from_str("f").unwrap()).unwrap();
+    assert_eq!(call_depth, CurrentNetwork::MAX_CALL_DEPTH);
+}
+
+#[test]
+fn test_call_depth_past_limit_is_rejected() {
+    // Initialize the process.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+    // Construct a chain that exceeds the maximum call depth by one.
+    let mut programs = sample_chain(CurrentNetwork::MAX_CALL_DEPTH + 1);
+    let first = programs.pop().unwrap();
+    // Ensure every program except the first is accepted.
+    for program in programs {
+        process.add_program(&program).unwrap();
+    }
+
+    // Ensure the first program is rejected, with a typed error.
+    let error = process.add_program(&first).unwrap_err();
+    let error = error.downcast_ref::<CallDepthExceeded<CurrentNetwork>>().unwrap();
+    assert_eq!(error.program_id(), first.id());
+    assert_eq!(error.name(), &Identifier::from_str("f").unwrap());
+    assert_eq!(error.depth(), CurrentNetwork::MAX_CALL_DEPTH + 1);
+    assert!(!process.contains_program(first.id()));
+}
+
+#[test]
+fn test_call_depth_past_limit_is_loaded() {
+    // Initialize the process.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+    // Construct a chain that exceeds the maximum call depth by one.
+    let mut programs = sample_chain(CurrentNetwork::MAX_CALL_DEPTH + 1);
+    let first = programs.pop().unwrap();
+    for program in programs {
+        process.add_program(&program).unwrap();
+    }
+
+    // Ensure the first program is rejected as a new deployment.
+    let error = process.deploy::<CurrentAleo, _>(&first, &mut TestRng::default()).unwrap_err();
+    assert!(error.is::<CallDepthExceeded<CurrentNetwork>>());
+
+    // Ensure the first program can still be loaded, as a program that was deployed prior to the limit.
+    process.add_stack(Stack::new(&process, &first).unwrap());
+    let stack = process.get_stack(first.id()).unwrap();
+    let call_depth = stack.get_call_depth(&Identifier::from_str("f").unwrap()).unwrap();
+    assert_eq!(call_depth, CurrentNetwork::MAX_CALL_DEPTH + 1);
+
+    // Ensure a call past the maximum call depth is still rejected by the prover.
+    let rng = &mut TestRng::default();
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let inputs = [Value::<CurrentNetwork>::from_str("1u64").unwrap()];
+    let error = process
+        .authorize::<CurrentAleo, _>(&private_key, first.id(), Identifier::from_str("f").unwrap(), inputs.iter(), rng)
+        .unwrap_err();
+    assert!(error.is::<CallDepthExceeded<CurrentNetwork>>());
+}
+
+#[test]
+fn test_call_depth_is_enforced_dynamically() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the process, with a chain that reaches a call depth of 2.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+    for program in sample_chain(2) {
+        process.add_program(&program).unwrap();
+    }
+    let program_id = ProgramID::<CurrentNetwork>::from_str("chain_0.aleo").unwrap();
+    let function_name = Identifier::<CurrentNetwork>::from_str("f").unwrap();
+    let stack = process.get_stack(program_id).unwrap();
+
+    // Authorize the function call.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let inputs = [Value::<CurrentNetwork>::from_str("1u64").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&private_key, program_id, function_name, inputs.iter(), rng).unwrap();
+    assert_eq!(authorization.len(), 2);
+
+    // Ensure the evaluation succeeds when the closure is at exactly the maximum call depth.
+    let depth = CurrentNetwork::MAX_CALL_DEPTH - 2;
+    let call_stack = CallStack::evaluate(authorization.replicate()).unwrap();
+    let response = stack.evaluate_function_at_depth::<CurrentAleo>(call_stack, None, depth).unwrap();
+    assert_eq!(response.outputs(), &[Value::from_str("2u64").unwrap()]);
+
+    // Ensure the evaluation fails when the closure is past the maximum call depth.
+    let depth = CurrentNetwork::MAX_CALL_DEPTH - 1;
+    let call_stack = CallStack::evaluate(authorization.replicate()).unwrap();
+    let error = stack.evaluate_function_at_depth::<CurrentAleo>(call_stack, None, depth).unwrap_err();
+    let error = error.downcast_ref::<CallDepthExceeded<CurrentNetwork>>().unwrap();
+    assert_eq!(error.program_id(), &ProgramID::from_str("chain_1.aleo").unwrap());
+    assert_eq!(error.name(), &Identifier::from_str("inc").unwrap());
+    assert_eq!(error.depth(), CurrentNetwork::MAX_CALL_DEPTH + 1);
+
+    // Ensure the execution fails when the closure is past the maximum call depth.
+    let call_stack = CallStack::execute(authorization.replicate(), Arc::new(RwLock::new(Trace::new()))).unwrap();
+    let error = stack.execute_function_at_depth::<CurrentAleo>(call_stack, None, depth).unwrap_err();
+    assert!(error.is::<CallDepthExceeded<CurrentNetwork>>());
+
+    // Ensure the deployment check succeeds when the closure is past the maximum call depth,
+    // so that a deployed program that exceeds the limit still verifies.
+    let request = authorization.peek_next().unwrap();
+    let call_stack = CallStack::CheckDeployment(vec![request], private_key, Assignments::<CurrentNetwork>::default());
+    let response = stack.execute_function_at_depth::<CurrentAleo>(call_stack, None, depth).unwrap();
+    assert_eq!(response.outputs(), &[Value::from_str("2u64").unwrap()]);
+}
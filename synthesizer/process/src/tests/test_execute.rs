@@ -14,19 +14,38 @@
 
 use crate::{
     traits::{StackEvaluate, StackExecute},
+    AggregatingProfiler,
+    Authorization,
     CallStack,
+    ExecutionHalt,
+    ExecutionTrace,
+    KeyCache,
     Process,
+    ProfileStage,
+    Stack,
     Trace,
 };
 use circuit::{network::AleoV0, Aleo};
 use console::{
     account::{Address, PrivateKey, ViewKey},
     network::{prelude::*, Testnet3},
-    program::{Identifier, Literal, Plaintext, ProgramID, Record, Value},
+    program::{
+        Identifier,
+        Literal,
+        Plaintext,
+        ProgramID,
+        Record,
+        RegisterType,
+        Request,
+        Response,
+        UnsignedRequest,
+        Value,
+        ValueType,
+    },
     types::{Field, U64},
 };
-use ledger_block::Fee;
-use ledger_query::Query;
+use ledger_block::{Execution, Fee};
+use ledger_query::{MemoryQuery, Query};
 use ledger_store::{
     helpers::memory::{BlockMemory, FinalizeMemory},
     BlockStorage,
@@ -34,7 +53,7 @@ use ledger_store::{
     FinalizeStorage,
     FinalizeStore,
 };
-use synthesizer_program::{FinalizeGlobalState, FinalizeStoreTrait, Program};
+use synthesizer_program::{FinalizeGlobalState, FinalizeStoreTrait, Opcode, Program};
 use synthesizer_snark::UniversalSRS;
 
 use indexmap::IndexMap;
@@ -281,6 +300,92 @@ output r1 as u64.private;",
     assert_eq!(expected, candidate[0]);
 }
 
+#[test]
+fn test_process_evaluate_function_matches_execute() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program preview.aleo;
+
+struct pair:
+    first as u64;
+    second as u64;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+closure add_pair:
+    input r0 as u64;
+    input r1 as u64;
+    add r0 r1 into r2;
+    output r2 as u64;
+
+function add_via_closure:
+    input r0 as u64.public;
+    input r1 as u64.private;
+    call add_pair r0 r1 into r2;
+    mul r2 r2 into r3;
+    output r3 as u64.private;
+
+function make_pair:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    cast r0 r1 into r2 as pair;
+    cast r2.second r2.first into r3 as pair;
+    output r2 as pair.private;
+    output r3 as pair.private;
+
+function peek:
+    input r0 as token.record;
+    add r0.amount 1u64 into r1;
+    output r1 as u64.private;
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Declare the function calls.
+    let record =
+        Record::from_str(&format!("{{ owner: {caller}.private, amount: 100u64.private, _nonce: 0group.public }}"))
+            .unwrap();
+    let calls = [
+        ("add_via_closure", vec![Value::from_str("3u64").unwrap(), Value::from_str("4u64").unwrap()]),
+        ("make_pair", vec![Value::from_str("5u64").unwrap(), Value::from_str("6u64").unwrap()]),
+        ("peek", vec![Value::<CurrentNetwork>::Record(record)]),
+    ];
+
+    for (function_name, inputs) in calls {
+        // Evaluate the function, without an authorization.
+        let candidate = process.evaluate_function::<CurrentAleo>(program.id(), function_name, &inputs).unwrap();
+
+        // Execute the function.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap();
+        let (response, _) = process.execute::<CurrentAleo>(authorization).unwrap();
+
+        // Ensure the outputs match.
+        assert_eq!(response.outputs(), candidate, "Mismatched outputs for '{function_name}'");
+    }
+
+    // Ensure the inputs are checked.
+    let inputs = [Value::from_str("3u64").unwrap(), Value::from_str("4u32").unwrap()];
+    assert!(process.evaluate_function::<CurrentAleo>(program.id(), "add_via_closure", &inputs).is_err());
+    assert!(process.evaluate_function::<CurrentAleo>(program.id(), "add_via_closure", &inputs[..1]).is_err());
+    assert!(process.evaluate_function::<CurrentAleo>(program.id(), "missing", &inputs).is_err());
+}
+
 #[test]
 fn test_program_evaluate_call() {
     // Initialize a new program.
@@ -546,6 +651,67 @@ fn test_process_execute_transfer_public() {
     // assert_eq!(79386, CurrentAleo::num_gates());
 }
 
+#[test]
+fn test_process_execute_transfer_private_with_memory_query() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::credits().unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the input record.
+    let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+        "{{ owner: {caller}.private, microcredits: 5_000_000_u64.private, _nonce: 0group.public }}"
+    ))
+    .unwrap();
+    // Compute the record commitment.
+    let commitment = record.to_commitment(program.id(), &Identifier::from_str("credits").unwrap()).unwrap();
+
+    // Seed the in-memory query with a state path for the record commitment.
+    let state_path =
+        console::program::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(Some(commitment), rng)
+            .unwrap();
+    let state_root = state_path.global_state_root();
+    let query = MemoryQuery::new(state_root).with_state_path(commitment, state_path).unwrap();
+
+    // Declare the input values.
+    let r0 = Value::<CurrentNetwork>::Record(record);
+    let r1 = Value::<CurrentNetwork>::from_str(&format!("{caller}")).unwrap();
+    let r2 = Value::<CurrentNetwork>::from_str("1_000_000_u64").unwrap();
+
+    // Construct the process.
+    let process = Process::load().unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            program.id(),
+            Identifier::from_str("transfer_private").unwrap(),
+            [r0, r1, r2].iter(),
+            rng,
+        )
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+    assert_eq!(2, response.outputs().len());
+
+    // Prepare the trace against the in-memory query.
+    trace.prepare(Query::<_, BlockMemory<_>>::from(query)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("credits.aleo/transfer_private", rng).unwrap();
+    // Ensure the execution is anchored to the seeded state root.
+    assert_eq!(execution.global_state_root(), state_root);
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+}
+
 #[test]
 fn test_process_circuit_key() {
     // Initialize a new program.
@@ -915,6 +1081,123 @@ fn test_process_output_operand() {
     );
 }
 
+#[test]
+fn test_process_key_cache_capacity() {
+    // Initialize two programs.
+    let program_a = Program::<CurrentNetwork>::from_str(
+        r"
+program cache_a.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+    )
+    .unwrap();
+    let program_b = Program::<CurrentNetwork>::from_str(
+        r"
+program cache_b.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    mul r0 r1 into r2;
+    output r2 as u32.public;",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process, with a proving key cache capacity of 1.
+    let mut process = crate::test_helpers::sample_process(&program_a);
+    process.add_program(&program_b).unwrap();
+    process.set_key_cache_capacity(Some(1)).unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Declare the inputs.
+    let r0 = Value::<CurrentNetwork>::from_str("3u32").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("5u32").unwrap();
+
+    // Execute the programs alternately.
+    for _ in 0..2 {
+        for (program, expected) in [(&program_a, "8u32"), (&program_b, "15u32")] {
+            // Execute the function.
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "compute", [&r0, &r1].into_iter(), rng)
+                .unwrap();
+            let (response, mut trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+            assert_eq!(response.outputs(), [Value::from_str(expected).unwrap()]);
+
+            // Prove and verify the execution.
+            trace.prepare(Query::from(&block_store)).unwrap();
+            let execution = trace.prove_execution::<CurrentAleo, _>(&program.id().to_string(), rng).unwrap();
+            process.verify_execution(&execution).unwrap();
+
+            // Ensure the cache stays within its capacity.
+            assert_eq!(process.key_cache_metrics().size, 1);
+        }
+    }
+
+    // Ensure a proving key was evicted each time the other program was executed.
+    assert_eq!(process.key_cache_metrics().evictions, 3);
+}
+
+#[test]
+fn test_process_key_cache_is_private_until_stack_is_added() {
+    // Initialize two programs.
+    let program_a = Program::<CurrentNetwork>::from_str(
+        r"
+program private_a.aleo;
+
+function compute:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.public;",
+    )
+    .unwrap();
+    let program_b = Program::<CurrentNetwork>::from_str(
+        r"
+program private_b.aleo;
+
+function compute:
+    input r0 as u32.private;
+    mul r0 r0 into r1;
+    output r1 as u32.public;",
+    )
+    .unwrap();
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process, and synthesize the proving key for the first program.
+    let mut process = crate::test_helpers::sample_process(&program_a);
+    process.synthesize_key::<CurrentAleo, _>(program_a.id(), &function_name, rng).unwrap();
+    let initial_size = process.key_cache_metrics().size;
+
+    // Ensure a temporary stack writes its proving keys to a private cache.
+    let stack = Stack::new(&process, &program_b).unwrap();
+    stack.synthesize_key::<CurrentAleo, _>(&function_name, rng).unwrap();
+    assert!(stack.contains_proving_key(&function_name));
+    assert_eq!(process.key_cache_metrics().size, initial_size);
+
+    // Ensure a temporary stack for an existing program does not remove its proving keys.
+    let _stack = Stack::initialize(&process, &program_a).unwrap();
+    assert!(process.get_stack(program_a.id()).unwrap().contains_proving_key(&function_name));
+
+    // Ensure adding the stack moves its proving keys into the shared cache.
+    process.add_stack(stack);
+    assert_eq!(process.key_cache_metrics().size, initial_size + 1);
+    assert!(process.get_stack(program_b.id()).unwrap().contains_proving_key(&function_name));
+}
+
 #[test]
 fn test_process_execute_call_closure() {
     // Initialize a new program.
@@ -1303,6 +1586,135 @@ finalize compute:
     assert_eq!(candidate, Value::from_str("8u64").unwrap());
 }
 
+#[test]
+fn test_process_profiler_matches_instruction_census() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program profiler.aleo;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+closure twice:
+    input r0 as u64;
+    add r0 r0 into r1;
+    output r1 as u64;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    add r1 r2 into r3;
+    mul r3 r2 into r4;
+    call twice r4 into r5;
+    async compute r0 r5 into r6;
+    output r6 as profiler.aleo/compute.future;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Count the instructions in the function and closure, and the commands in the finalize block.
+    // Note: Each closure is called exactly once by the function.
+    let mut instruction_census = IndexMap::<Opcode, u64>::new();
+    let function = program.get_function(&function_name).unwrap();
+    let closure = program.get_closure(&Identifier::from_str("twice").unwrap()).unwrap();
+    for instruction in function.instructions().iter().chain(closure.instructions()) {
+        *instruction_census.entry(instruction.opcode()).or_default() += 1;
+    }
+    let mut command_census = IndexMap::<Opcode, u64>::new();
+    for command in function.finalize_logic().unwrap().commands() {
+        *command_census.entry(command.opcode()).or_default() += 1;
+    }
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Set the profiler.
+    let profiler = Arc::new(AggregatingProfiler::new());
+    process.set_profiler(Some(profiler.clone()));
+
+    // Returns the number of profiled instructions for each opcode in the given stage.
+    let counts = |stage| {
+        profiler
+            .summary_for(stage)
+            .into_iter()
+            .map(|(opcode, summary)| (opcode, summary.count))
+            .collect::<IndexMap<_, _>>()
+    };
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the input value.
+    let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
+    let r2 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1, r2].iter(), rng)
+        .unwrap();
+
+    // Evaluate the function, and check the profile.
+    profiler.reset();
+    process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(counts(ProfileStage::Evaluate), instruction_census);
+    assert!(counts(ProfileStage::Execute).is_empty());
+
+    // Execute the function, and check the profile.
+    profiler.reset();
+    let (_, mut trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+    assert_eq!(counts(ProfileStage::Execute), instruction_census);
+    assert!(profiler.summary_for(ProfileStage::Execute).values().any(|summary| summary.num_constraints > 0));
+
+    // Prove the execution.
+    trace.prepare(Query::from(block_store)).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("profiler", rng).unwrap();
+
+    // Finalize the execution, and check the profile.
+    profiler.reset();
+    process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+    assert_eq!(counts(ProfileStage::Finalize), command_census);
+    assert!(counts(ProfileStage::Evaluate).is_empty());
+
+    // Unset the profiler, and ensure nothing is recorded.
+    process.set_profiler(None);
+    profiler.reset();
+    process.finalize_execution(sample_finalize_state(2), &finalize_store, &execution, None).unwrap();
+    assert!(profiler.summary().is_empty());
+}
+
 #[test]
 fn test_process_execute_and_finalize_increment_decrement_via_get_set() {
     // Initialize a new program.
@@ -2362,8 +2774,13 @@ fn test_process_deploy_credits_program() {
     let rng = &mut TestRng::default();
 
     // Initialize an empty process without the `credits` program.
-    let empty_process =
-        Process { universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()), stacks: IndexMap::new() };
+    let empty_process = Process {
+        universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()),
+        stacks: IndexMap::new(),
+        record_trace: false,
+        profiler: Default::default(),
+        key_cache: KeyCache::new_handle().unwrap(),
+    };
 
     // Construct the process.
     let process = Process::load().unwrap();
@@ -2483,3 +2900,570 @@ function {function_name}:
     assert_ne!(execution_1.peek().unwrap().id(), execution_2.peek().unwrap().id());
     assert_ne!(execution_1.to_execution_id().unwrap(), execution_2.to_execution_id().unwrap());
 }
+
+#[test]
+fn test_sum_rejects_non_numeric_arrays() {
+    // Construct the process.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+
+    // Initialize a program that sums an array of booleans.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program sum_boolean.aleo;
+
+function compute:
+    input r0 as [boolean; 4u32].private;
+    sum r0 into r1;
+    output r1 as boolean.private;",
+    )
+    .unwrap();
+    // Ensure the program is rejected by the type checker.
+    assert!(process.add_program(&program).is_err());
+
+    // Initialize a program that sums an array of integers.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program sum_u64.aleo;
+
+function compute:
+    input r0 as [u64; 4u32].private;
+    sum r0 into r1;
+    output r1 as u64.private;",
+    )
+    .unwrap();
+    // Ensure the program is accepted.
+    assert!(process.add_program(&program).is_ok());
+}
+
+#[test]
+fn test_process_execute_assert_with_message() {
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize a program with asserts that report an error message and an error code.
+    let program = Program::<CurrentNetwork>::from_str(
+        r#"
+program assert_message.aleo;
+
+function withdraw:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    assert.neq r0 0u64 7u16;
+    assert.eq r0 r1 "insufficient_balance";"#,
+    )
+    .unwrap();
+    let function_name = Identifier::from_str("withdraw").unwrap();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+    // Add the program to the process.
+    process.add_program(&program).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Executes the function on the given inputs, and returns the error.
+    let mut execute_error = |inputs: [Value<CurrentNetwork>; 2]| {
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap();
+        process.execute::<CurrentAleo>(authorization).err().unwrap().to_string()
+    };
+
+    // Ensure the error message is reported.
+    let error = execute_error([Value::from_str("1u64").unwrap(), Value::from_str("2u64").unwrap()]);
+    assert!(error.contains("with error \"insufficient_balance\""), "Unexpected error: {error}");
+    // Ensure the error code is reported.
+    let error = execute_error([Value::from_str("0u64").unwrap(), Value::from_str("0u64").unwrap()]);
+    assert!(error.contains("with error 7u16"), "Unexpected error: {error}");
+}
+
+#[test]
+fn test_process_verify_execution_concurrent() {
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+    )
+    .unwrap();
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+    // Add the program to the process.
+    process.add_program(&program).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+    // Create an execution of the function on the given inputs.
+    let mut execute = |inputs: [&str; 2]| {
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.into_iter(), rng)
+            .unwrap();
+        // Execute the request.
+        let (_, mut trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+        // Prepare the trace.
+        trace.prepare(Query::from(block_store.clone())).unwrap();
+        // Prove the execution.
+        trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap()
+    };
+    let execution_a = execute(["5u32", "10u32"]);
+    let execution_b = execute(["7u32", "11u32"]);
+
+    // Corrupt an execution by swapping in the proof of another execution.
+    let corrupted_a = Execution::from(
+        execution_a.transitions().cloned(),
+        execution_a.global_state_root(),
+        execution_b.proof().cloned(),
+    )
+    .unwrap();
+    // Corrupt an execution by removing its proof.
+    let corrupted_b =
+        Execution::from(execution_b.transitions().cloned(), execution_b.global_state_root(), None).unwrap();
+
+    // Verify a mix of valid and corrupted executions.
+    let executions = [&execution_a, &corrupted_a, &execution_b, &execution_a, &corrupted_b];
+    let errors = process.verify_execution_concurrent(&executions);
+
+    // Ensure exactly the corrupted executions are reported, in order.
+    assert_eq!(errors.iter().map(|(index, _)| *index).collect::<Vec<_>>(), vec![1, 4]);
+    // Ensure all of the valid executions are reported as valid.
+    assert!(process.verify_execution_concurrent(&[&execution_a, &execution_b]).is_empty());
+}
+
+#[test]
+fn test_process_authorization_from_signed_requests() {
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program offline_token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function split:
+    input r0 as token.record;
+    input r1 as u64.private;
+    sub r0.amount r1 into r2;
+    cast r0.owner r1 into r3 as token.record;
+    cast r0.owner r2 into r4 as token.record;
+    output r3 as token.record;
+    output r4 as token.record;
+
+function mint:
+    input r0 as u64.private;
+    cast self.caller r0 into r1 as token.record;
+    output r1 as token.record;",
+    )
+    .unwrap();
+    let function_name = Identifier::from_str("split").unwrap();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+    // Add the program to the process.
+    process.add_program(&program).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Prepare the inputs, with a record that belongs to the caller.
+    let inputs = vec![
+        Value::<CurrentNetwork>::from_str(&format!(
+            "{{ owner: {caller}.private, amount: 10u64.private, _nonce: 2293253577170800572742339369209137467208538700597121244293392265726446806023group.public }}"
+        ))
+        .unwrap(),
+        Value::from_str("3u64").unwrap(),
+    ];
+    let input_types = program.get_function(&function_name).unwrap().input_types();
+
+    // Phase 1: The signer derives the nonce, and commits to it (and to the record inputs).
+    let record_commitments = UnsignedRequest::record_commitments(program.id(), &inputs, &input_types).unwrap();
+    assert_eq!(record_commitments.len(), 1);
+    let (nonce, commitment) = Request::commit_signer(&caller_private_key, &record_commitments, rng).unwrap();
+
+    // Construct the unsigned request, without the private key.
+    let unsigned =
+        UnsignedRequest::new(&commitment, *program.id(), function_name, inputs.into_iter(), &input_types).unwrap();
+
+    // Phase 2: The signer signs the message of the unsigned request, with the same nonce.
+    let signature = Request::sign_with_nonce(&caller_private_key, nonce, unsigned.to_sign_message()).unwrap();
+    let request = unsigned.clone().into_request(signature).unwrap();
+    assert_eq!(&request.to_tpk(), commitment.tpk());
+
+    // Construct the authorization from the signed request.
+    let authorization = process.authorization_from_signed_requests(vec![request.clone()]).unwrap();
+    assert_eq!(authorization.len(), 1);
+    assert!(process.authorization_from_signed_requests(vec![]).is_err());
+
+    // Evaluate the request.
+    let response = process.evaluate::<CurrentAleo>(authorization).unwrap();
+    assert_eq!(response.outputs().len(), 2);
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Execute, prove, and verify the given authorization.
+    let prove_and_verify = |authorization: Authorization<CurrentNetwork>, rng: &mut TestRng| {
+        let (_, mut trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+        trace.prepare(Query::from(&block_store)).unwrap();
+        let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+        process.verify_execution(&execution).unwrap();
+    };
+
+    // Ensure an authorization from a request signed out-of-band is executed and verifies.
+    let mint_name = Identifier::from_str("mint").unwrap();
+    let mint_inputs = vec![Value::<CurrentNetwork>::from_str("5u64").unwrap()];
+    let mint_input_types = program.get_function(&mint_name).unwrap().input_types();
+    let (mint_nonce, mint_commitment) = Request::commit_signer(&caller_private_key, &[], rng).unwrap();
+    let unsigned_mint =
+        UnsignedRequest::new(&mint_commitment, *program.id(), mint_name, mint_inputs.into_iter(), &mint_input_types)
+            .unwrap();
+    let signature = Request::sign_with_nonce(&caller_private_key, mint_nonce, unsigned_mint.to_sign_message()).unwrap();
+    let mint_request = unsigned_mint.into_request(signature).unwrap();
+    prove_and_verify(process.authorization_from_signed_requests(vec![mint_request]).unwrap(), rng);
+
+    // Ensure an authorization from `Process::authorize` consists of valid signed requests, and verifies.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), mint_name, ["5u64"].into_iter(), rng)
+        .unwrap();
+    let requests = Vec::from(authorization.to_vec_deque());
+    assert!(process.authorization_from_signed_requests(requests).is_ok());
+    prove_and_verify(authorization, rng);
+
+    // Ensure a signature with a different nonce is rejected.
+    let (other_nonce, _) = Request::commit_signer(&caller_private_key, &record_commitments, rng).unwrap();
+    let signature = Request::sign_with_nonce(&caller_private_key, other_nonce, unsigned.to_sign_message()).unwrap();
+    let wrong_nonce = unsigned.into_request(signature).unwrap();
+    assert!(process.authorization_from_signed_requests(vec![wrong_nonce]).is_err());
+
+    // Tamper with the private input of the request.
+    let mut tampered_inputs = request.inputs().to_vec();
+    tampered_inputs[1] = Value::from_str("4u64").unwrap();
+    let tampered = Request::from((
+        *request.signer(),
+        *request.network_id(),
+        *request.program_id(),
+        *request.function_name(),
+        request.input_ids().to_vec(),
+        tampered_inputs,
+        *request.signature(),
+        *request.sk_tag(),
+        *request.tvk(),
+        *request.tcm(),
+    ));
+    // Ensure the tampered request is rejected.
+    let error = process.authorization_from_signed_requests(vec![tampered]).unwrap_err();
+    assert!(
+        error.to_string().contains("Request 0 ('offline_token.aleo/split') is invalid"),
+        "Unexpected error: {error}"
+    );
+}
+
+#[test]
+fn test_process_replay_execution_trace() {
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program replay.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as u64.private;
+    input r1 as u64.public;
+    add r0 r1 into r2;
+    cast self.caller r2 into r3 as token.record;
+    output r3 as token.record;
+    output r2 as u64.public;",
+    )
+    .unwrap();
+    let function_name = Identifier::from_str("mint").unwrap();
+
+    // Construct the process, which records the execution trace.
+    let mut process = Process::load().unwrap();
+    process.add_program(&program).unwrap();
+    process.set_record_trace(true);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            program.id(),
+            function_name,
+            ["5u64", "10u64"].into_iter(),
+            rng,
+        )
+        .unwrap();
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+    // Ensure the execution trace is not available before the execution is proven.
+    assert!(trace.to_execution_trace().is_err());
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("replay", rng).unwrap();
+
+    // Serialize the execution trace, and deserialize it elsewhere.
+    let execution_trace = trace.to_execution_trace().unwrap();
+    let candidate = ExecutionTrace::<CurrentNetwork>::from_str(&execution_trace.to_string()).unwrap();
+    assert_eq!(execution_trace, candidate);
+    let candidate: ExecutionTrace<CurrentNetwork> =
+        bincode::deserialize(&bincode::serialize(&execution_trace).unwrap()).unwrap();
+    assert_eq!(execution_trace, candidate);
+
+    // Construct another process, which does not record the execution trace.
+    let mut other_process = Process::load().unwrap();
+    other_process.add_program(&program).unwrap();
+
+    // Replay the execution.
+    let (replayed_response, replayed_execution) = other_process.replay::<CurrentAleo>(&candidate).unwrap();
+    // Ensure the replayed execution matches the original execution.
+    assert_eq!(response.outputs(), replayed_response.outputs());
+    assert_eq!(
+        execution.transitions().map(|transition| *transition.id()).collect::<Vec<_>>(),
+        replayed_execution.transitions().map(|transition| *transition.id()).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        execution.transitions().flat_map(|transition| transition.output_ids().copied()).collect::<Vec<_>>(),
+        replayed_execution.transitions().flat_map(|transition| transition.output_ids().copied()).collect::<Vec<_>>()
+    );
+    assert_eq!(execution.global_state_root(), replayed_execution.global_state_root());
+    // Ensure the replayed execution verifies.
+    other_process.verify_execution(&replayed_execution).unwrap();
+
+    // Ensure the replay fails if the program in the process differs from the recorded program.
+    let mut mismatched_process = Process::load().unwrap();
+    mismatched_process
+        .add_program(
+            &Program::<CurrentNetwork>::from_str(
+                r"
+program replay.aleo;
+
+function mint:
+    input r0 as u64.private;
+    input r1 as u64.public;
+    output r1 as u64.public;",
+            )
+            .unwrap(),
+        )
+        .unwrap();
+    assert!(mismatched_process.replay::<CurrentAleo>(&candidate).is_err());
+}
+
+#[test]
+fn test_process_add_program_reports_all_type_errors() {
+    // Construct the process.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+
+    // Initialize a program with three distinct type errors.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program type_errors.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u64.private;
+    input r2 as boolean.private;
+    add r0 r1 into r3;
+    mul r0 r0 into r4;
+    sub r3 r0 into r5;
+    assert.eq r4 r2;
+    add r4 r9 into r6;
+    output r4 as u32.private;",
+    )
+    .unwrap();
+
+    // Ensure the program is rejected.
+    let error = process.add_program(&program).unwrap_err();
+    let errors = error.downcast_ref::<crate::TypeCheckErrors<CurrentNetwork>>().unwrap();
+    assert_eq!(errors.name(), &Identifier::from_str("compute").unwrap());
+
+    // Ensure all three type errors are reported, with their locations.
+    // Note: The `sub` instruction is skipped, as it reads the destination of the ill-typed `add` instruction.
+    let locations = errors.errors().iter().map(|error| (error.index, error.operand)).collect::<Vec<_>>();
+    assert_eq!(locations, vec![(0, Some(1)), (3, Some(1)), (4, Some(1))]);
+
+    // Ensure the expected and found types are reported for the mismatched operands.
+    let type_of = |string: &str| Some(RegisterType::<CurrentNetwork>::from_str(string).unwrap());
+    assert_eq!(
+        (errors.errors()[0].expected.clone(), errors.errors()[0].found.clone()),
+        (type_of("u32"), type_of("u64"))
+    );
+    assert_eq!(
+        (errors.errors()[1].expected.clone(), errors.errors()[1].found.clone()),
+        (type_of("u32"), type_of("boolean"))
+    );
+    assert_eq!((errors.errors()[2].expected.clone(), errors.errors()[2].found.clone()), (None, None));
+
+    // Ensure the report prints one line per type error.
+    assert_eq!(error.to_string().lines().count(), 4);
+}
+
+#[test]
+fn test_process_execute_halt() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program halt.aleo;
+
+function check:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    add r0 r1 into r2;
+    assert.eq r2 10u64;
+    output r2 as u64.private;
+
+function divide:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    div r0 r1 into r2;
+    output r2 as u64.private;
+
+function to_group:
+    input r0 as field.private;
+    cast r0 into r1 as group;
+    output r1 as group.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+    // Initialize the caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Declare the inputs.
+    let inputs = [Value::<CurrentNetwork>::from_str("3u64").unwrap(), Value::from_str("4u64").unwrap()];
+
+    // Ensure a failing `assert.eq` is returned as a halt, when executing and when evaluating.
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "check", inputs.iter(), rng).unwrap();
+    let error = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap_err();
+    let halt = error.downcast_ref::<ExecutionHalt<CurrentNetwork>>().unwrap();
+    assert_eq!(halt.program_id(), program.id());
+    assert_eq!(halt.function_name(), &Identifier::from_str("check").unwrap());
+    assert_eq!(halt.instruction_index(), 1);
+    assert!(halt.message().contains("'assert.eq' failed"), "{}", halt.message());
+    let error = process.execute::<CurrentAleo>(authorization).unwrap_err();
+    assert_eq!(error.downcast_ref::<ExecutionHalt<CurrentNetwork>>(), Some(halt));
+
+    // Ensure a division by zero in the environment is returned as a halt.
+    let inputs = [Value::<CurrentNetwork>::from_str("3u64").unwrap(), Value::from_str("0u64").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "divide", inputs.iter(), rng).unwrap();
+    let error = process.execute::<CurrentAleo>(authorization).unwrap_err();
+    let halt = error.downcast_ref::<ExecutionHalt<CurrentNetwork>>().unwrap();
+    assert_eq!(halt.function_name(), &Identifier::from_str("divide").unwrap());
+    assert_eq!(halt.instruction_index(), 0);
+    assert!(halt.message().contains("Integer division failed"), "{}", halt.message());
+
+    // Ensure an error that does not halt the program, such as a failed cast, is not classified as a halt.
+    let inputs = [Value::<CurrentNetwork>::from_str("1field").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "to_group", inputs.iter(), rng).unwrap();
+    let error = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap_err();
+    assert!(!error.is::<ExecutionHalt<CurrentNetwork>>());
+    assert!(error.to_string().contains("Failed to evaluate instruction"), "{error}");
+    let error = process.execute::<CurrentAleo>(authorization).unwrap_err();
+    assert!(!error.is::<ExecutionHalt<CurrentNetwork>>());
+}
+
+#[test]
+fn test_process_response_typed_outputs() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program typed.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.public;
+    cast r0 r1 into r2 as token.record;
+    add r1 1u64 into r3;
+    async mint r1 into r4;
+    output r2 as token.record;
+    output r3 as u64.public;
+    output r1 as u64.private;
+    output r4 as typed.aleo/mint.future;
+
+finalize mint:
+    input r0 as u64.public;
+    assert.eq r0 r0;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+    // Initialize the caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Declare the inputs.
+    let inputs = [Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap(), Value::from_str("5u64").unwrap()];
+    // Authorize the function call.
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "mint", inputs.iter(), rng).unwrap();
+
+    // Ensure the typed outputs are classified correctly, when evaluating and when executing.
+    let evaluated = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    let (executed, _) = process.execute::<CurrentAleo>(authorization).unwrap();
+    for response in [evaluated, executed] {
+        let typed_outputs = response.typed_outputs().unwrap();
+        assert_eq!(typed_outputs.len(), 4);
+        assert!(matches!((&typed_outputs[0].0, &typed_outputs[0].1), (ValueType::Record(..), Value::Record(..))));
+        assert!(matches!((&typed_outputs[1].0, &typed_outputs[1].1), (ValueType::Public(..), Value::Plaintext(..))));
+        assert!(matches!((&typed_outputs[2].0, &typed_outputs[2].1), (ValueType::Private(..), Value::Plaintext(..))));
+        assert!(matches!((&typed_outputs[3].0, &typed_outputs[3].1), (ValueType::Future(..), Value::Future(..))));
+        assert_eq!(response.output_types().len(), response.output_ids().len());
+
+        // Ensure the extractors return each kind of output.
+        let records = response.records().collect::<Vec<_>>();
+        assert_eq!(records.len(), 1);
+        assert_eq!(**records[0].owner(), caller);
+        let plaintexts = response.plaintexts().map(|plaintext| plaintext.to_string()).collect::<Vec<_>>();
+        assert_eq!(plaintexts, ["6u64", "5u64"]);
+        let futures = response.futures().collect::<Vec<_>>();
+        assert_eq!(futures.len(), 1);
+        assert_eq!(futures[0].function_name(), &Identifier::from_str("mint").unwrap());
+    }
+
+    // Ensure a response with a mismatched number of outputs and output types is rejected, instead of panicking.
+    let response = Response::<CurrentNetwork>::from((vec![], vec![Value::from_str("1u64").unwrap()], vec![]));
+    assert!(response.typed_outputs().is_err());
+}
@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for ExecutionTrace<N> {
+    /// Reads the execution trace from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid execution trace version"));
+        }
+
+        // Read the requests.
+        let num_requests = u8::read_le(&mut reader)?;
+        let requests = (0..num_requests).map(|_| Request::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the programs.
+        let num_programs = u8::read_le(&mut reader)?;
+        let programs = (0..num_programs).map(|_| Program::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the transition IDs.
+        let num_transitions = u8::read_le(&mut reader)?;
+        let transition_ids =
+            (0..num_transitions).map(|_| N::TransitionID::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the output IDs.
+        let num_outputs = u16::read_le(&mut reader)?;
+        let output_ids = (0..num_outputs).map(|_| Field::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the global state root.
+        let global_state_root = N::StateRoot::read_le(&mut reader)?;
+        // Read the state paths.
+        let num_state_paths = u16::read_le(&mut reader)?;
+        let state_paths =
+            (0..num_state_paths).map(|_| StatePath::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the seed.
+        let seed = <[u8; 32]>::read_le(&mut reader)?;
+
+        // Return the execution trace.
+        Self::new(requests, programs, transition_ids, output_ids, global_state_root, state_paths, seed).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for ExecutionTrace<N> {
+    /// Writes the execution trace to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the requests.
+        u8::try_from(self.requests.len()).map_err(error)?.write_le(&mut writer)?;
+        self.requests.iter().try_for_each(|request| request.write_le(&mut writer))?;
+        // Write the programs.
+        u8::try_from(self.programs.len()).map_err(error)?.write_le(&mut writer)?;
+        self.programs.iter().try_for_each(|program| program.write_le(&mut writer))?;
+        // Write the transition IDs.
+        u8::try_from(self.transition_ids.len()).map_err(error)?.write_le(&mut writer)?;
+        self.transition_ids.iter().try_for_each(|transition_id| transition_id.write_le(&mut writer))?;
+        // Write the output IDs.
+        u16::try_from(self.output_ids.len()).map_err(error)?.write_le(&mut writer)?;
+        self.output_ids.iter().try_for_each(|output_id| output_id.write_le(&mut writer))?;
+        // Write the global state root.
+        self.global_state_root.write_le(&mut writer)?;
+        // Write the state paths.
+        u16::try_from(self.state_paths.len()).map_err(error)?.write_le(&mut writer)?;
+        self.state_paths.iter().try_for_each(|state_path| state_path.write_le(&mut writer))?;
+        // Write the seed.
+        self.seed.write_le(&mut writer)
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use console::{
+    network::prelude::*,
+    program::{Request, StatePath},
+    types::Field,
+};
+use synthesizer_program::Program;
+
+/// The recorded inputs of an execution, which allow it to be replayed deterministically (see `Process::replay`).
+///
+/// Note: An execution trace contains the private inputs of the execution and the seed of the proving RNG.
+/// It must never leave the prover.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ExecutionTrace<N: Network> {
+    /// The signed requests, in the order they are invoked.
+    requests: Vec<Request<N>>,
+    /// The programs invoked by the execution.
+    programs: Vec<Program<N>>,
+    /// The IDs of the transitions produced by the execution.
+    transition_ids: Vec<N::TransitionID>,
+    /// The IDs of the outputs produced by the execution.
+    output_ids: Vec<Field<N>>,
+    /// The global state root of the execution.
+    global_state_root: N::StateRoot,
+    /// The global state paths of the input records.
+    state_paths: Vec<StatePath<N>>,
+    /// The seed of the proving RNG.
+    seed: [u8; 32],
+}
+
+impl<N: Network> ExecutionTrace<N> {
+    /// Initializes a new execution trace.
+    pub fn new(
+        requests: Vec<Request<N>>,
+        programs: Vec<Program<N>>,
+        transition_ids: Vec<N::TransitionID>,
+        output_ids: Vec<Field<N>>,
+        global_state_root: N::StateRoot,
+        state_paths: Vec<StatePath<N>>,
+        seed: [u8; 32],
+    ) -> Result<Self> {
+        // Ensure the requests are not empty.
+        ensure!(!requests.is_empty(), "An execution trace must contain at least one request");
+        // Ensure the number of requests and transitions matches.
+        ensure!(
+            requests.len() == transition_ids.len(),
+            "The number of requests ({}) and transitions ({}) must match in the execution trace",
+            requests.len(),
+            transition_ids.len()
+        );
+        // Ensure the state paths are for the global state root.
+        ensure!(
+            state_paths.iter().all(|state_path| state_path.global_state_root() == global_state_root),
+            "The state paths in the execution trace must be for the global state root '{global_state_root}'"
+        );
+        Ok(Self { requests, programs, transition_ids, output_ids, global_state_root, state_paths, seed })
+    }
+
+    /// Returns the signed requests.
+    pub fn requests(&self) -> &[Request<N>] {
+        &self.requests
+    }
+
+    /// Returns the programs.
+    pub fn programs(&self) -> &[Program<N>] {
+        &self.programs
+    }
+
+    /// Returns the transition IDs.
+    pub fn transition_ids(&self) -> &[N::TransitionID] {
+        &self.transition_ids
+    }
+
+    /// Returns the output IDs.
+    pub fn output_ids(&self) -> &[Field<N>] {
+        &self.output_ids
+    }
+
+    /// Returns the global state root.
+    pub const fn global_state_root(&self) -> N::StateRoot {
+        self.global_state_root
+    }
+
+    /// Returns the global state paths of the input records.
+    pub fn state_paths(&self) -> &[StatePath<N>] {
+        &self.state_paths
+    }
+
+    /// Returns the seed of the proving RNG.
+    pub const fn seed(&self) -> [u8; 32] {
+        self.seed
+    }
+}
+
+impl<N: Network> ExecutionTrace<N> {
+    /// Returns the recorded global state root.
+    /// Note: This mirrors `QueryTrait::current_state_root`, so the trace can be used to prepare the inclusion.
+    pub(crate) fn current_state_root(&self) -> Result<N::StateRoot> {
+        Ok(self.global_state_root)
+    }
+
+    /// Returns the recorded state path for the given `commitment`.
+    /// Note: This mirrors `QueryTrait::get_state_path_for_commitment`, so the trace can be used to prepare the inclusion.
+    pub(crate) fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        self.state_paths
+            .iter()
+            .find(|state_path| state_path.transition_leaf().id() == *commitment)
+            .cloned()
+            .ok_or_else(|| anyhow!("Missing the recorded state path for commitment '{commitment}'"))
+    }
+}
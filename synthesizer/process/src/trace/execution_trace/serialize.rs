@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for ExecutionTrace<N> {
+    /// Serializes the execution trace into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut execution_trace = serializer.serialize_struct("ExecutionTrace", 7)?;
+                execution_trace.serialize_field("requests", &self.requests)?;
+                execution_trace.serialize_field("programs", &self.programs)?;
+                execution_trace.serialize_field("transition_ids", &self.transition_ids)?;
+                execution_trace.serialize_field("output_ids", &self.output_ids)?;
+                execution_trace.serialize_field("global_state_root", &self.global_state_root)?;
+                execution_trace.serialize_field("state_paths", &self.state_paths)?;
+                execution_trace.serialize_field("seed", &self.seed)?;
+                execution_trace.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for ExecutionTrace<N> {
+    /// Deserializes the execution trace from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the execution trace from a string into a value.
+                let mut execution_trace = serde_json::Value::deserialize(deserializer)?;
+                // Recover the execution trace.
+                Self::new(
+                    DeserializeExt::take_from_value::<D>(&mut execution_trace, "requests")?,
+                    DeserializeExt::take_from_value::<D>(&mut execution_trace, "programs")?,
+                    DeserializeExt::take_from_value::<D>(&mut execution_trace, "transition_ids")?,
+                    DeserializeExt::take_from_value::<D>(&mut execution_trace, "output_ids")?,
+                    DeserializeExt::take_from_value::<D>(&mut execution_trace, "global_state_root")?,
+                    DeserializeExt::take_from_value::<D>(&mut execution_trace, "state_paths")?,
+                    DeserializeExt::take_from_value::<D>(&mut execution_trace, "seed")?,
+                )
+                .map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "execution trace"),
+        }
+    }
+}
@@ -14,6 +14,7 @@
 
 mod prepare;
 
+use crate::ExecutionTrace;
 #[cfg(debug_assertions)]
 use crate::Stack;
 
@@ -168,7 +169,7 @@ pub struct InclusionAssignment<N: Network> {
     gamma: Group<N>,
     serial_number: Field<N>,
     local_state_root: N::TransactionID,
-    is_global: bool,
+    pub(crate) is_global: bool,
 }
 
 impl<N: Network> InclusionAssignment<N> {
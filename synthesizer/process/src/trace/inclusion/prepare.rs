@@ -110,6 +110,16 @@ impl<N: Network> Inclusion<N> {
         prepare_impl!(self, transitions, query, current_state_root, get_state_path_for_commitment)
     }
 
+    /// Returns the inclusion assignments for the given transitions,
+    /// using the recorded global state root and state paths of the given execution trace.
+    pub fn prepare_from_execution_trace(
+        &self,
+        transitions: &[Transition<N>],
+        execution_trace: &ExecutionTrace<N>,
+    ) -> Result<(Vec<InclusionAssignment<N>>, N::StateRoot)> {
+        prepare_impl!(self, transitions, execution_trace, current_state_root, get_state_path_for_commitment)
+    }
+
     /// Returns the inclusion assignments for the given transitions.
     #[cfg(feature = "async")]
     pub async fn prepare_async(
@@ -15,19 +15,25 @@
 mod call_metrics;
 pub use call_metrics::*;
 
+mod execution_trace;
+pub use execution_trace::*;
+
 mod inclusion;
 pub use inclusion::*;
 
 use circuit::Assignment;
 use console::{
     network::prelude::*,
-    program::{InputID, Locator},
+    program::{InputID, Locator, Request},
 };
 use ledger_block::{Execution, Fee, Transition};
 use ledger_query::QueryTrait;
+use synthesizer_program::Program;
 use synthesizer_snark::{Proof, ProvingKey, VerifyingKey};
 
 use once_cell::sync::OnceCell;
+use rand::SeedableRng;
+use rand_chacha::ChaChaRng;
 use std::collections::HashMap;
 
 #[derive(Clone, Debug, Default)]
@@ -45,6 +51,11 @@ pub struct Trace<N: Network> {
     inclusion_assignments: OnceCell<Vec<InclusionAssignment<N>>>,
     /// A tracker for the global state root.
     global_state_root: OnceCell<N::StateRoot>,
+
+    /// The recorded requests and programs, if the execution trace is being recorded.
+    recording: Option<(Vec<Request<N>>, Vec<Program<N>>)>,
+    /// A tracker for the seed of the proving RNG, if the execution trace is being recorded.
+    proving_seed: OnceCell<[u8; 32]>,
 }
 
 impl<N: Network> Trace<N> {
@@ -57,6 +68,8 @@ impl<N: Network> Trace<N> {
             inclusion_assignments: OnceCell::new(),
             global_state_root: OnceCell::new(),
             call_metrics: Vec::new(),
+            recording: None,
+            proving_seed: OnceCell::new(),
         }
     }
 
@@ -100,6 +113,52 @@ impl<N: Network> Trace<N> {
     }
 }
 
+impl<N: Network> Trace<N> {
+    /// Records the requests and programs of the execution, so that an execution trace can be produced.
+    pub(crate) fn record(&mut self, requests: Vec<Request<N>>, programs: Vec<Program<N>>) {
+        self.recording = Some((requests, programs));
+    }
+
+    /// Returns the execution trace, which allows the execution to be replayed with `Process::replay`.
+    ///
+    /// Note: This requires `Process::set_record_trace` to be enabled, and the execution to be proven.
+    /// The execution trace contains the private inputs and the proving seed of the execution,
+    /// so it must never leave the prover.
+    pub fn to_execution_trace(&self) -> Result<ExecutionTrace<N>> {
+        // Retrieve the recorded requests and programs.
+        let Some((requests, programs)) = &self.recording else { bail!("The execution trace was not recorded") };
+        // Retrieve the inclusion assignments.
+        let inclusion_assignments =
+            self.inclusion_assignments.get().ok_or_else(|| anyhow!("Inclusion assignments have not been set"))?;
+        // Retrieve the global state root.
+        let global_state_root =
+            self.global_state_root.get().ok_or_else(|| anyhow!("Global state root has not been set"))?;
+        // Retrieve the proving seed.
+        let seed = self.proving_seed.get().ok_or_else(|| anyhow!("The execution has not been proven"))?;
+
+        // Collect the transition IDs and output IDs.
+        let transition_ids = self.transitions.iter().map(|transition| *transition.id()).collect();
+        let output_ids = self.transitions.iter().flat_map(|transition| transition.output_ids().copied()).collect();
+        // Collect the global state paths.
+        let state_paths = inclusion_assignments
+            .iter()
+            .filter(|assignment| assignment.is_global)
+            .map(|assignment| assignment.state_path.clone())
+            .collect();
+
+        // Return the execution trace.
+        ExecutionTrace::new(
+            requests.clone(),
+            programs.clone(),
+            transition_ids,
+            output_ids,
+            *global_state_root,
+            state_paths,
+            *seed,
+        )
+    }
+}
+
 impl<N: Network> Trace<N> {
     /// Returns `true` if the trace is for a fee transition.
     pub fn is_fee(&self) -> bool {
@@ -132,6 +191,20 @@ impl<N: Network> Trace<N> {
         Ok(())
     }
 
+    /// Returns the inclusion assignments and global state root for the current transition(s),
+    /// using the recorded global state root and state paths of the given execution trace.
+    pub fn prepare_from_execution_trace(&mut self, execution_trace: &ExecutionTrace<N>) -> Result<()> {
+        // Compute the inclusion assignments.
+        let (inclusion_assignments, global_state_root) =
+            self.inclusion_tasks.prepare_from_execution_trace(&self.transitions, execution_trace)?;
+        // Store the inclusion assignments and global state root.
+        self.inclusion_assignments
+            .set(inclusion_assignments)
+            .map_err(|_| anyhow!("Failed to set inclusion assignments"))?;
+        self.global_state_root.set(global_state_root).map_err(|_| anyhow!("Failed to set global state root"))?;
+        Ok(())
+    }
+
     /// Returns the inclusion assignments and global state root for the current transition(s).
     #[cfg(feature = "async")]
     pub async fn prepare_async(&mut self, query: impl QueryTrait<N>) -> Result<()> {
@@ -151,6 +224,34 @@ impl<N: Network> Trace<N> {
         &self,
         locator: &str,
         rng: &mut R,
+    ) -> Result<Execution<N>> {
+        match self.recording.is_some() {
+            // If the execution trace is being recorded, prove with a (recorded) sampled seed.
+            true => self.prove_execution_with_seed::<A>(locator, rng.gen()),
+            false => self.prove_execution_with_rng::<A, R>(locator, rng),
+        }
+    }
+
+    /// Returns a new execution with a proof, using a proving RNG initialized from the given seed.
+    /// If the execution trace is being recorded, the seed is recorded.
+    pub fn prove_execution_with_seed<A: circuit::Aleo<Network = N>>(
+        &self,
+        locator: &str,
+        seed: [u8; 32],
+    ) -> Result<Execution<N>> {
+        // If the execution trace is being recorded, store the seed.
+        if self.recording.is_some() {
+            self.proving_seed.set(seed).map_err(|_| anyhow!("Failed to set the proving seed"))?;
+        }
+        // Prove the execution with the seeded RNG.
+        self.prove_execution_with_rng::<A, _>(locator, &mut ChaChaRng::from_seed(seed))
+    }
+
+    /// Returns a new execution with a proof, for the current inclusion assignments and global state root.
+    fn prove_execution_with_rng<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        locator: &str,
+        rng: &mut R,
     ) -> Result<Execution<N>> {
         // Ensure this is not a fee.
         ensure!(!self.is_fee(), "The trace cannot call 'prove_execution' for a fee type");
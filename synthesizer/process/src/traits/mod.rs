@@ -34,6 +34,25 @@ pub trait StackEvaluate<N: Network>: Clone {
         signer: Address<N>,
         caller: Address<N>,
         tvk: Field<N>,
+    ) -> Result<Vec<Value<N>>> {
+        self.evaluate_closure_at_depth::<A>(closure, inputs, call_stack, signer, caller, tvk, 0)
+    }
+
+    /// Evaluates a program closure on the given inputs, at the given call depth.
+    ///
+    /// # Errors
+    /// This method will halt if the given inputs are not the same length as the input statements.
+    /// This method will halt if the call depth exceeds `N::MAX_CALL_DEPTH`.
+    #[allow(clippy::too_many_arguments)]
+    fn evaluate_closure_at_depth<A: circuit::Aleo<Network = N>>(
+        &self,
+        closure: &Closure<N>,
+        inputs: &[Value<N>],
+        call_stack: CallStack<N>,
+        signer: Address<N>,
+        caller: Address<N>,
+        tvk: Field<N>,
+        call_depth: usize,
     ) -> Result<Vec<Value<N>>>;
 
     /// Evaluates a program function on the given inputs.
@@ -44,6 +63,20 @@ pub trait StackEvaluate<N: Network>: Clone {
         &self,
         call_stack: CallStack<N>,
         caller: Option<ProgramID<N>>,
+    ) -> Result<Response<N>> {
+        self.evaluate_function_at_depth::<A>(call_stack, caller, 0)
+    }
+
+    /// Evaluates a program function on the given inputs, at the given call depth.
+    ///
+    /// # Errors
+    /// This method will halt if the given inputs are not the same length as the input statements.
+    /// This method will halt if the call depth exceeds `N::MAX_CALL_DEPTH`.
+    fn evaluate_function_at_depth<A: circuit::Aleo<Network = N>>(
+        &self,
+        call_stack: CallStack<N>,
+        caller: Option<ProgramID<N>>,
+        call_depth: usize,
     ) -> Result<Response<N>>;
 }
 
@@ -60,6 +93,25 @@ pub trait StackExecute<N: Network> {
         signer: circuit::Address<A>,
         caller: circuit::Address<A>,
         tvk: circuit::Field<A>,
+    ) -> Result<Vec<circuit::Value<A>>> {
+        self.execute_closure_at_depth(closure, inputs, call_stack, signer, caller, tvk, 0)
+    }
+
+    /// Executes a program closure on the given inputs, at the given call depth.
+    ///
+    /// # Errors
+    /// This method will halt if the given inputs are not the same length as the input statements.
+    /// This method will halt if the call depth exceeds `N::MAX_CALL_DEPTH`.
+    #[allow(clippy::too_many_arguments)]
+    fn execute_closure_at_depth<A: circuit::Aleo<Network = N>>(
+        &self,
+        closure: &Closure<N>,
+        inputs: &[circuit::Value<A>],
+        call_stack: CallStack<N>,
+        signer: circuit::Address<A>,
+        caller: circuit::Address<A>,
+        tvk: circuit::Field<A>,
+        call_depth: usize,
     ) -> Result<Vec<circuit::Value<A>>>;
 
     /// Executes a program function on the given inputs.
@@ -72,6 +124,22 @@ pub trait StackExecute<N: Network> {
         &self,
         call_stack: CallStack<N>,
         console_caller: Option<ProgramID<N>>,
+    ) -> Result<Response<N>> {
+        self.execute_function_at_depth::<A>(call_stack, console_caller, 0)
+    }
+
+    /// Executes a program function on the given inputs, at the given call depth.
+    ///
+    /// Note: To execute a transition, do **not** call this method. Instead, call `Process::execute`.
+    ///
+    /// # Errors
+    /// This method will halt if the given inputs are not the same length as the input statements.
+    /// This method will halt if the call depth exceeds `N::MAX_CALL_DEPTH`.
+    fn execute_function_at_depth<A: circuit::Aleo<Network = N>>(
+        &self,
+        call_stack: CallStack<N>,
+        console_caller: Option<ProgramID<N>>,
+        call_depth: usize,
     ) -> Result<Response<N>>;
 }
 
@@ -86,4 +154,7 @@ pub trait StackProgramTypes<N: Network> {
 pub trait RegistersCall<N: Network> {
     /// Returns the current call stack.
     fn call_stack(&self) -> CallStack<N>;
+
+    /// Returns the call depth of the closure or function of these registers.
+    fn call_depth(&self) -> usize;
 }
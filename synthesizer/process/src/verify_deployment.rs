@@ -22,6 +22,23 @@ impl<N: Network> Process<N> {
         deployment: &Deployment<N>,
         rng: &mut R,
     ) -> Result<()> {
+        // Note: Consensus must use the strict check.
+        self.verify_deployment_with_report::<A, R>(deployment, true, rng).map(|_| ())
+    }
+
+    /// Verifies the given deployment is ordered, and returns the time spent verifying each function.
+    ///
+    /// If `strict` is `false`, the circuits are not synthesized, so the verifying keys and certificates
+    /// are *not* checked against the program. This fast path is only intended for deployments that were
+    /// already accepted, such as when syncing historical blocks; consensus must use the strict check,
+    /// as performed by `Process::verify_deployment`.
+    #[inline]
+    pub fn verify_deployment_with_report<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        deployment: &Deployment<N>,
+        strict: bool,
+        rng: &mut R,
+    ) -> Result<IndexMap<Identifier<N>, std::time::Duration>> {
         let timer = timer!("Process::verify_deployment");
         // Retrieve the program ID.
         let program_id = deployment.program().id();
@@ -33,11 +50,21 @@ impl<N: Network> Process<N> {
         lap!(timer, "Compute the stack");
 
         // Ensure the verifying keys are well-formed and the certificates are valid.
-        let verification = stack.verify_deployment::<A, R>(deployment, rng);
+        let report = stack.verify_deployment_with_report::<A, R>(deployment, strict, rng);
         lap!(timer, "Verify the deployment");
 
         finish!(timer);
-        verification
+        report
+    }
+
+    /// Checks the given program against the rules that apply only to new deployments.
+    ///
+    /// Note: This check is **not** part of `Process::verify_deployment`, as deployments in past blocks
+    /// may not satisfy these rules. It must only be used for new deployments, such as in the mempool.
+    #[inline]
+    pub fn check_new_deployment(&self, program: &Program<N>) -> Result<()> {
+        // Compute the stack, and check it against the rules for new deployments.
+        Stack::new(self, program)?.check_new_deployment()
     }
 }
 
@@ -45,6 +72,7 @@ impl<N: Network> Process<N> {
 mod tests {
     use super::*;
 
+    type CurrentNetwork = console::network::Testnet3;
     type CurrentAleo = circuit::network::AleoV0;
 
     /// Use `cargo test profiler --features timer` to run this test.
@@ -67,4 +95,116 @@ mod tests {
 
         bail!("\n\nRemember to #[ignore] this test!\n\n")
     }
+
+    #[test]
+    fn test_verify_deployment_with_report() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::load().unwrap();
+
+        // Initialize a program with two functions.
+        let program = Program::from_str(
+            r"
+program testing.aleo;
+
+function add_one:
+    input r0 as u32.private;
+    add r0 1u32 into r1;
+    output r1 as u32.private;
+
+function add_twice:
+    input r0 as u64.public;
+    add r0 r0 into r1;
+    output r1 as u64.public;",
+        )
+        .unwrap();
+
+        // Create a deployment for the program.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+
+        // Ensure the deployment is valid under both checks, and the report covers each function.
+        for strict in [true, false] {
+            let report = process.verify_deployment_with_report::<CurrentAleo, _>(&deployment, strict, rng).unwrap();
+            assert_eq!(report.keys().collect::<Vec<_>>(), program.functions().keys().collect::<Vec<_>>());
+        }
+
+        // Tamper with the deployment, by swapping the verifying keys of the functions.
+        let verifying_keys = deployment.verifying_keys();
+        let tampered_verifying_keys = vec![
+            (verifying_keys[0].0, (verifying_keys[1].1 .0.clone(), verifying_keys[0].1 .1.clone())),
+            (verifying_keys[1].0, (verifying_keys[0].1 .0.clone(), verifying_keys[1].1 .1.clone())),
+        ];
+        let tampered = Deployment::new(deployment.edition(), program.clone(), tampered_verifying_keys).unwrap();
+
+        // Ensure the tampered verifying keys are only caught by the strict check.
+        assert!(process.verify_deployment::<CurrentAleo, _>(&tampered, rng).is_err());
+        assert!(process.verify_deployment_with_report::<CurrentAleo, _>(&tampered, true, rng).is_err());
+        assert!(process.verify_deployment_with_report::<CurrentAleo, _>(&tampered, false, rng).is_ok());
+
+        // Tamper with the deployment, by swapping the certificates of the functions.
+        let tampered_certificates = vec![
+            (verifying_keys[0].0, (verifying_keys[0].1 .0.clone(), verifying_keys[1].1 .1.clone())),
+            (verifying_keys[1].0, (verifying_keys[1].1 .0.clone(), verifying_keys[0].1 .1.clone())),
+        ];
+        let tampered = Deployment::new(deployment.edition(), program.clone(), tampered_certificates).unwrap();
+
+        // Ensure the tampered certificates are caught by the strict check.
+        assert!(process.verify_deployment::<CurrentAleo, _>(&tampered, rng).is_err());
+        assert!(process.verify_deployment_with_report::<CurrentAleo, _>(&tampered, true, rng).is_err());
+    }
+
+    #[test]
+    fn test_verify_deployment_checks_shadowed_names() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a program in which a function input register shadows a closure name.
+        let program = Program::from_str(
+            r"
+program shadowed.aleo;
+
+closure r0:
+    input r0 as u32;
+    add r0 r0 into r1;
+    output r1 as u32;
+
+function compute:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.private;",
+        )
+        .unwrap();
+
+        // Ensure a new deployment of the program is rejected.
+        assert!(process.deploy::<CurrentAleo, _>(&program, rng).is_err());
+
+        // Construct a deployment of the program, as it would have been deployed prior to the check.
+        let stack = Stack::new(&process, &program).unwrap();
+        let mut verifying_keys = Vec::new();
+        for function_name in program.functions().keys() {
+            stack.synthesize_key::<CurrentAleo, _>(function_name, rng).unwrap();
+            let proving_key = stack.get_proving_key(function_name).unwrap();
+            let verifying_key = stack.get_verifying_key(function_name).unwrap();
+            let certificate =
+                synthesizer_snark::Certificate::certify(&function_name.to_string(), &proving_key, &verifying_key)
+                    .unwrap();
+            verifying_keys.push((*function_name, (verifying_key, certificate)));
+        }
+        let deployment = Deployment::new(CurrentNetwork::EDITION, program, verifying_keys).unwrap();
+
+        // Ensure the deployment is rejected as a new deployment.
+        let error = process.check_new_deployment(deployment.program()).unwrap_err().to_string();
+        assert!(error.contains("shadows closure 'r0'"), "{error}");
+
+        // Ensure the deployment still verifies, as it does when a node syncs the block that contains it.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+
+        // Ensure the deployed program is still read from storage and loaded into the process.
+        let deployment = Deployment::<CurrentNetwork>::from_bytes_le(&deployment.to_bytes_le().unwrap()).unwrap();
+        process.load_deployment(&deployment).unwrap();
+        assert!(process.contains_program(deployment.program_id()));
+    }
 }
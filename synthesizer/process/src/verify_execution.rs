@@ -14,7 +14,28 @@
 
 use super::*;
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 impl<N: Network> Process<N> {
+    /// Verifies the given executions concurrently, and returns the index and error of each invalid execution.
+    /// The errors are returned in ascending order of their index, regardless of the order of completion.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    #[inline]
+    pub fn verify_execution_concurrent(&self, executions: &[&Execution<N>]) -> Vec<(usize, Error)> {
+        let timer = timer!("Process::verify_execution_concurrent");
+
+        // Verify the executions, collecting the error of each invalid execution.
+        // Note: The parallel iterator preserves the order of the executions when collecting.
+        let errors = cfg_iter!(executions)
+            .enumerate()
+            .filter_map(|(index, execution)| self.verify_execution(execution).err().map(|error| (index, error)))
+            .collect();
+
+        finish!(timer);
+        errors
+    }
+
     /// Verifies the given execution is valid.
     /// Note: This does *not* check that the global state root exists in the ledger.
     #[inline]
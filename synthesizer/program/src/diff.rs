@@ -0,0 +1,284 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::{network::prelude::ToBits, types::Field};
+
+/// The changes to a set of program definitions, identified by name.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DefinitionDiff<N: Network> {
+    /// The names of the definitions that are only in the new program.
+    pub added: Vec<Identifier<N>>,
+    /// The names of the definitions that are only in the old program.
+    pub removed: Vec<Identifier<N>>,
+    /// The names of the definitions that are in both programs, but differ.
+    pub modified: Vec<Identifier<N>>,
+}
+
+impl<N: Network> DefinitionDiff<N> {
+    /// Returns the changes from the `old` definitions to the `new` definitions.
+    fn new<T: PartialEq>(old: &IndexMap<Identifier<N>, T>, new: &IndexMap<Identifier<N>, T>) -> Self {
+        Self {
+            added: new.keys().filter(|name| !old.contains_key(*name)).copied().collect(),
+            removed: old.keys().filter(|name| !new.contains_key(*name)).copied().collect(),
+            modified: old
+                .iter()
+                .filter(|(name, definition)| new.get(*name).map_or(false, |other| other != *definition))
+                .map(|(name, _)| *name)
+                .collect(),
+        }
+    }
+
+    /// Returns `true` if there are no changes.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+/// The structural changes between two programs.
+///
+/// Note: A function is considered modified if its inputs, instructions, outputs, or finalize block differ.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProgramDiff<N: Network> {
+    /// The changes to the mappings.
+    pub mappings: DefinitionDiff<N>,
+    /// The changes to the structs.
+    pub structs: DefinitionDiff<N>,
+    /// The changes to the records.
+    pub records: DefinitionDiff<N>,
+    /// The changes to the closures.
+    pub closures: DefinitionDiff<N>,
+    /// The changes to the functions.
+    pub functions: DefinitionDiff<N>,
+}
+
+impl<N: Network> ProgramDiff<N> {
+    /// Returns `true` if there are no changes.
+    pub fn is_empty(&self) -> bool {
+        self.mappings.is_empty()
+            && self.structs.is_empty()
+            && self.records.is_empty()
+            && self.closures.is_empty()
+            && self.functions.is_empty()
+    }
+}
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
+    /// Returns the checksum of the program, defined as the BHP hash of its canonical bytes.
+    pub fn checksum(&self) -> Result<Field<N>> {
+        N::hash_bhp1024(&self.to_bytes_le()?.to_bits_le())
+    }
+}
+
+impl<N: Network, Instruction: InstructionTrait<N> + PartialEq, Command: CommandTrait<N> + PartialEq>
+    ProgramCore<N, Instruction, Command>
+{
+    /// Returns the structural changes from this program to the given program.
+    pub fn diff(&self, other: &Self) -> ProgramDiff<N> {
+        ProgramDiff {
+            mappings: DefinitionDiff::new(&self.mappings, &other.mappings),
+            structs: DefinitionDiff::new(&self.structs, &other.structs),
+            records: DefinitionDiff::new(&self.records, &other.records),
+            closures: DefinitionDiff::new(&self.closures, &other.closures),
+            functions: DefinitionDiff::new(&self.functions, &other.functions),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Program;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the identifiers for the given names.
+    fn identifiers(names: &[&str]) -> Vec<Identifier<CurrentNetwork>> {
+        names.iter().map(|name| Identifier::from_str(name).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_checksum() {
+        let program = Program::<CurrentNetwork>::credits().unwrap();
+        // Ensure the checksum is deterministic.
+        assert_eq!(program.checksum().unwrap(), program.checksum().unwrap());
+        // Ensure the checksum is stable across serialization.
+        let candidate = Program::<CurrentNetwork>::from_str(&program.to_string()).unwrap();
+        assert_eq!(program.checksum().unwrap(), candidate.checksum().unwrap());
+        // Ensure the checksum differs for a different program.
+        let other = Program::<CurrentNetwork>::from_str(
+            r"
+program other.aleo;
+
+function foo:
+    input r0 as u8.private;
+    output r0 as u8.private;",
+        )
+        .unwrap();
+        assert_ne!(program.checksum().unwrap(), other.checksum().unwrap());
+    }
+
+    #[test]
+    fn test_diff() {
+        let old = Program::<CurrentNetwork>::from_str(
+            r"
+program sample.aleo;
+
+struct point:
+    x as u32;
+    y as u32;
+
+struct line:
+    start as point;
+    end as point;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+mapping balances:
+    key as address.public;
+    value as u64.public;
+
+mapping nonces:
+    key as address.public;
+    value as u64.public;
+
+closure twice:
+    input r0 as u32;
+    add r0 r0 into r1;
+    output r1 as u32;
+
+function transfer:
+    input r0 as address.public;
+    input r1 as u64.public;
+    async transfer r0 r1 into r2;
+    output r2 as sample.aleo/transfer.future;
+
+finalize transfer:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use balances[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into balances[r0];
+
+function compute:
+    input r0 as u32.private;
+    call twice r0 into r1;
+    output r1 as u32.private;
+
+function legacy:
+    input r0 as u32.private;
+    output r0 as u32.private;",
+        )
+        .unwrap();
+
+        let new = Program::<CurrentNetwork>::from_str(
+            r"
+program sample.aleo;
+
+struct point:
+    x as u64;
+    y as u64;
+
+struct line:
+    start as point;
+    end as point;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+record ticket:
+    owner as address.private;
+    id as field.private;
+
+mapping balances:
+    key as address.public;
+    value as u64.public;
+
+mapping supply:
+    key as boolean.public;
+    value as u64.public;
+
+closure twice:
+    input r0 as u32;
+    add r0 r0 into r1;
+    output r1 as u32;
+
+closure triple:
+    input r0 as u32;
+    mul r0 3u32 into r1;
+    output r1 as u32;
+
+function transfer:
+    input r0 as address.public;
+    input r1 as u64.public;
+    async transfer r0 r1 into r2;
+    output r2 as sample.aleo/transfer.future;
+
+finalize transfer:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use balances[r0] 0u64 into r2;
+    sub r2 r1 into r3;
+    set r3 into balances[r0];
+
+function compute:
+    input r0 as u32.private;
+    call twice r0 into r1;
+    output r1 as u32.private;
+
+function mint:
+    input r0 as u32.private;
+    call triple r0 into r1;
+    output r1 as u32.private;",
+        )
+        .unwrap();
+
+        // Ensure a program has no changes from itself.
+        assert!(old.diff(&old).is_empty());
+        assert!(new.diff(&new).is_empty());
+
+        // Ensure the changes are exact.
+        let diff = old.diff(&new);
+        assert!(!diff.is_empty());
+        assert_eq!(diff.mappings.added, identifiers(&["supply"]));
+        assert_eq!(diff.mappings.removed, identifiers(&["nonces"]));
+        assert!(diff.mappings.modified.is_empty());
+        assert!(diff.structs.added.is_empty());
+        assert!(diff.structs.removed.is_empty());
+        assert_eq!(diff.structs.modified, identifiers(&["point"]));
+        assert_eq!(diff.records.added, identifiers(&["ticket"]));
+        assert!(diff.records.removed.is_empty());
+        assert!(diff.records.modified.is_empty());
+        assert_eq!(diff.closures.added, identifiers(&["triple"]));
+        assert!(diff.closures.removed.is_empty());
+        assert!(diff.closures.modified.is_empty());
+        assert_eq!(diff.functions.added, identifiers(&["mint"]));
+        assert_eq!(diff.functions.removed, identifiers(&["legacy"]));
+        assert_eq!(diff.functions.modified, identifiers(&["transfer"]));
+
+        // Ensure the reverse changes are swapped.
+        let reverse = new.diff(&old);
+        assert_eq!(reverse.functions.added, diff.functions.removed);
+        assert_eq!(reverse.functions.removed, diff.functions.added);
+        assert_eq!(reverse.functions.modified, diff.functions.modified);
+
+        // Ensure the checksums differ.
+        assert_ne!(old.checksum().unwrap(), new.checksum().unwrap());
+    }
+}
@@ -17,6 +17,7 @@ use input::*;
 
 mod bytes;
 mod parse;
+mod serialize;
 
 use crate::traits::CommandTrait;
 use console::{
@@ -60,11 +60,15 @@ impl<N: Network, Command: CommandTrait<N>> FromStr for FinalizeCore<N, Command>
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string at {}. Found invalid character in: \"{remainder}\"",
+                    crate::parse::locate(string, remainder)
+                );
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => bail!("Failed to parse string{}. {error}", crate::parse::locate_error(string, &error)),
         }
     }
 }
@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network, Command: CommandTrait<N>> Serialize for FinalizeCore<N, Command> {
+    /// Serializes the finalize into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.collect_str(self),
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network, Command: CommandTrait<N>> Deserialize<'de> for FinalizeCore<N, Command> {
+    /// Deserializes the finalize from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => FromStr::from_str(&String::deserialize(deserializer)?).map_err(de::Error::custom),
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "finalize"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Finalize;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const FINALIZE: &str = r"
+finalize foo:
+    input r0 as field.public;
+    input r1 as field.public;
+    contains accounts[r0] into r2;
+    get.or_use accounts[r0] 0u64 into r3;
+    get accounts[r1] into r4;
+    rand.chacha r0 into r5 as field;
+    set r3 into accounts[r0];
+    remove accounts[r1];
+    add r0 r1 into r6;";
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        // Parse a new finalize.
+        let expected = Finalize::<CurrentNetwork>::from_str(FINALIZE)?;
+
+        // Serialize
+        let expected_string = &expected.to_string();
+        let candidate_string = serde_json::to_string(&expected)?;
+        assert_eq!(expected_string, serde_json::Value::from_str(&candidate_string)?.as_str().unwrap());
+
+        // Deserialize
+        assert_eq!(expected, Finalize::from_str(expected_string)?);
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        // Parse a new finalize.
+        let expected = Finalize::<CurrentNetwork>::from_str(FINALIZE)?;
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le()?;
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+        // Deserialize
+        assert_eq!(expected, Finalize::read_le(&expected_bytes[..])?);
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+
+        Ok(())
+    }
+}
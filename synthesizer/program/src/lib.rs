@@ -42,8 +42,12 @@ pub use mapping::*;
 pub mod traits;
 pub use traits::*;
 
+mod diff;
+pub use diff::*;
+
 mod bytes;
 mod parse;
+mod schema;
 mod serialize;
 
 use console::{
@@ -84,7 +88,7 @@ use console::{
         TypeName,
         Write,
     },
-    program::{Identifier, PlaintextType, ProgramID, RecordType, StructType},
+    program::{Identifier, PlaintextType, ProgramID, RecordType, Register, StructType},
 };
 
 use indexmap::IndexMap;
@@ -103,6 +107,19 @@ enum ProgramDefinition {
     Function,
 }
 
+impl Display for ProgramDefinition {
+    /// Prints the program definition as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Mapping => write!(f, "mapping"),
+            Self::Struct => write!(f, "struct"),
+            Self::Record => write!(f, "record"),
+            Self::Closure => write!(f, "closure"),
+            Self::Function => write!(f, "function"),
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Eq)]
 pub struct ProgramCore<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> {
     /// The ID of the program.
@@ -330,7 +347,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         ensure!(self.mappings.len() < N::MAX_MAPPINGS, "Program exceeds the maximum number of mappings");
 
         // Ensure the mapping name is new.
-        ensure!(self.is_unique_name(&mapping_name), "'{mapping_name}' is already in use.");
+        self.ensure_unique_name(&mapping_name, ProgramDefinition::Mapping)?;
         // Ensure the mapping name is not a reserved keyword.
         ensure!(!Self::is_reserved_keyword(&mapping_name), "'{mapping_name}' is a reserved keyword.");
         // Ensure the mapping name is not a reserved opcode.
@@ -360,7 +377,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         let struct_name = *struct_.name();
 
         // Ensure the struct name is new.
-        ensure!(self.is_unique_name(&struct_name), "'{struct_name}' is already in use.");
+        self.ensure_unique_name(&struct_name, ProgramDefinition::Struct)?;
         // Ensure the struct name is not a reserved opcode.
         ensure!(!Self::is_reserved_opcode(&struct_name.to_string()), "'{struct_name}' is a reserved opcode.");
         // Ensure the struct name is not a reserved keyword.
@@ -418,7 +435,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         let record_name = *record.name();
 
         // Ensure the record name is new.
-        ensure!(self.is_unique_name(&record_name), "'{record_name}' is already in use.");
+        self.ensure_unique_name(&record_name, ProgramDefinition::Record)?;
         // Ensure the record name is not a reserved opcode.
         ensure!(!Self::is_reserved_opcode(&record_name.to_string()), "'{record_name}' is a reserved opcode.");
         // Ensure the record name is not a reserved keyword.
@@ -478,7 +495,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         let closure_name = *closure.name();
 
         // Ensure the closure name is new.
-        ensure!(self.is_unique_name(&closure_name), "'{closure_name}' is already in use.");
+        self.ensure_unique_name(&closure_name, ProgramDefinition::Closure)?;
         // Ensure the closure name is not a reserved opcode.
         ensure!(!Self::is_reserved_opcode(&closure_name.to_string()), "'{closure_name}' is a reserved opcode.");
         // Ensure the closure name is not a reserved keyword.
@@ -526,7 +543,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         ensure!(self.functions.len() < N::MAX_FUNCTIONS, "Program exceeds the maximum number of functions");
 
         // Ensure the function name is new.
-        ensure!(self.is_unique_name(&function_name), "'{function_name}' is already in use.");
+        self.ensure_unique_name(&function_name, ProgramDefinition::Function)?;
         // Ensure the function name is not a reserved opcode.
         ensure!(!Self::is_reserved_opcode(&function_name.to_string()), "'{function_name}' is a reserved opcode.");
         // Ensure the function name is not a reserved keyword.
@@ -631,6 +648,58 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         !self.identifiers.contains_key(name)
     }
 
+    /// Ensures the given name for the given definition is not already in use by another definition in the program.
+    fn ensure_unique_name(&self, name: &Identifier<N>, definition: ProgramDefinition) -> Result<()> {
+        // Ensure the name is not in use by another definition.
+        if let Some(existing) = self.identifiers.get(name) {
+            bail!("'{name}' is already in use by {existing} '{name}', and cannot be reused by {definition} '{name}'.")
+        }
+        Ok(())
+    }
+
+    /// Ensures no definition in the program reuses the name of an imported program,
+    /// and no function input or output register shadows a closure name.
+    ///
+    /// Note: This is checked for new deployments only, and not when the program is constructed,
+    /// so that programs that were deployed prior to this check can still be loaded.
+    pub fn check_shadowed_names(&self) -> Result<()> {
+        // Ensure no definition reuses the name of an imported program.
+        for import in self.imports.values() {
+            if let Some(definition) = self.identifiers.get(import.name()) {
+                bail!(
+                    "'{name}' is already in use by import '{}', and cannot be reused by {definition} '{name}'.",
+                    import.program_id(),
+                    name = import.name()
+                )
+            }
+        }
+        // Ensure no function input or output register shadows a closure name.
+        for function in self.functions.values() {
+            for register in Self::function_registers(function) {
+                let register_name = Self::register_name(register)?;
+                if self.closures.contains_key(&register_name) {
+                    bail!("Register '{register}' in function '{}' shadows closure '{register_name}'.", function.name())
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the input and output registers of the given function.
+    fn function_registers(function: &FunctionCore<N, Instruction, Command>) -> impl '_ + Iterator<Item = &Register<N>> {
+        let inputs = function.inputs().iter().map(|input| input.register());
+        let outputs = function.outputs().iter().filter_map(|output| match output.operand() {
+            Operand::Register(register) => Some(register),
+            _ => None,
+        });
+        inputs.chain(outputs)
+    }
+
+    /// Returns the name of the given register (e.g. `r0`), as an identifier.
+    fn register_name(register: &Register<N>) -> Result<Identifier<N>> {
+        Identifier::from_str(&format!("r{}", register.locator()))
+    }
+
     /// Returns `true` if the given name is a reserved opcode.
     pub fn is_reserved_opcode(name: &str) -> bool {
         Instruction::is_reserved_opcode(name)
@@ -812,4 +881,151 @@ function swap:
 
         Ok(())
     }
+
+    #[test]
+    fn test_program_rejects_shadowed_identifiers() -> Result<()> {
+        // Each pair of definitions reuses the same identifier across namespaces.
+        let collisions = [
+            ("struct foo:\n    a as u8;", "mapping foo:\n    key as u8.public;\n    value as u8.public;"),
+            ("struct foo:\n    a as u8;", "record foo:\n    owner as address.private;"),
+            ("record foo:\n    owner as address.private;", "struct foo:\n    a as u8;"),
+            (
+                "mapping foo:\n    key as u8.public;\n    value as u8.public;",
+                "function foo:\n    input r0 as u8.public;",
+            ),
+            (
+                "closure foo:\n    input r0 as u8;\n    add r0 r0 into r1;\n    output r1 as u8;",
+                "function foo:\n    input r0 as u8.public;",
+            ),
+            (
+                "function foo:\n    input r0 as u8.public;",
+                "closure foo:\n    input r0 as u8;\n    add r0 r0 into r1;\n    output r1 as u8;",
+            ),
+        ];
+        for (first, second) in collisions {
+            // Ensure each definition is valid on its own.
+            Program::<CurrentNetwork>::from_str(&format!("program unknown.aleo;\n\n{first}"))?;
+            Program::<CurrentNetwork>::from_str(&format!("program unknown.aleo;\n\n{second}"))?;
+            // Ensure the program is rejected.
+            let program = format!("program unknown.aleo;\n\n{first}\n\n{second}");
+            assert!(Program::<CurrentNetwork>::from_str(&program).is_err(), "Expected an error for:\n{program}");
+        }
+
+        // Ensure the error names both definitions.
+        let mut program = Program::<CurrentNetwork>::new(ProgramID::from_str("unknown.aleo")?)?;
+        program.add_struct(StructType::from_str("struct foo:\n    a as u8;")?)?;
+        let error = program
+            .add_mapping(Mapping::from_str("mapping foo:\n    key as u8.public;\n    value as u8.public;")?)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("struct 'foo'") && error.contains("mapping 'foo'"), "{error}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_check_shadowed_imports() -> Result<()> {
+        // Each definition reuses the name of an imported program.
+        let definitions = [
+            "struct eth:\n    a as u8;",
+            "record eth:\n    owner as address.private;",
+            "mapping eth:\n    key as u8.public;\n    value as u8.public;",
+            "closure eth:\n    input r0 as u8;\n    add r0 r0 into r1;\n    output r1 as u8;",
+            "function eth:\n    input r0 as u8.public;",
+        ];
+        let name = Identifier::from_str("eth")?;
+        for definition in definitions {
+            // Ensure the definition passes the check without the import.
+            let standalone = Program::<CurrentNetwork>::from_str(&format!("program unknown.aleo;\n\n{definition}"))?;
+            standalone.check_shadowed_names()?;
+
+            // Add the definition after the import, as is done when a deployed program is read from bytes.
+            let mut program =
+                Program::<CurrentNetwork>::from_str("import eth.aleo;\n\nprogram unknown.aleo;\n\nstruct foo:\n    a as u8;")?;
+            let kind = definition.split_whitespace().next().unwrap();
+            match kind {
+                "struct" => program.add_struct(standalone.get_struct(&name)?.clone())?,
+                "record" => program.add_record(standalone.get_record(&name)?.clone())?,
+                "mapping" => program.add_mapping(standalone.get_mapping(&name)?)?,
+                "closure" => program.add_closure(standalone.get_closure(&name)?)?,
+                "function" => program.add_function(standalone.get_function(&name)?)?,
+                _ => unreachable!(),
+            }
+            // Ensure the program still deserializes, so that previously-deployed programs can be loaded.
+            assert_eq!(program, Program::from_bytes_le(&program.to_bytes_le()?)?);
+
+            // Ensure the program fails the check for new deployments, and the error names both definitions.
+            let error = program.check_shadowed_names().unwrap_err().to_string();
+            assert!(error.contains("import 'eth.aleo'") && error.contains(&format!("{kind} 'eth'")), "{error}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_check_registers_shadowing_closures() -> Result<()> {
+        let closure = "closure r0:\n    input r0 as u8;\n    add r0 r0 into r1;\n    output r1 as u8;";
+        let functions = [
+            // The input register shadows the closure.
+            "function foo:\n    input r0 as u8.public;",
+            // The output register shadows the closure.
+            "function foo:\n    input r1 as u8.public;\n    add r1 r1 into r0;\n    output r0 as u8.public;",
+        ];
+        for function in functions {
+            // Ensure the function passes the check on its own.
+            Program::<CurrentNetwork>::from_str(&format!("program unknown.aleo;\n\n{function}"))?
+                .check_shadowed_names()?;
+            // Ensure the program parses, but fails the check for new deployments,
+            // whether the closure is defined before or after the function.
+            for (first, second) in [(closure, function), (function, closure)] {
+                let program = Program::<CurrentNetwork>::from_str(&format!(
+                    "program unknown.aleo;\n\n{first}\n\n{second}"
+                ))?;
+                let error = program.check_shadowed_names().unwrap_err().to_string();
+                assert!(error.contains("function 'foo'") && error.contains("closure 'r0'"), "{error}");
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_accepts_distinct_identifiers() -> Result<()> {
+        // Ensure a program may share its own name with a definition, and a register may share a name
+        // with a closure, provided the register is not an input or output of a function.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+import eth.aleo;
+
+program token.aleo;
+
+struct pair:
+    first as u64;
+    second as u64;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+mapping balances:
+    key as address.public;
+    value as u64.public;
+
+closure r1:
+    input r0 as u64;
+    add r0 r0 into r1;
+    output r1 as u64;
+
+function mint:
+    input r0 as u64.private;
+    call r1 r0 into r1;
+    add r1 r0 into r2;
+    output r2 as u64.private;",
+        )?;
+        assert!(program.contains_import(&ProgramID::from_str("eth.aleo")?));
+        assert!(program.contains_closure(&Identifier::from_str("r1")?));
+        assert!(program.contains_function(&Identifier::from_str("mint")?));
+        program.check_shadowed_names()?;
+
+        // Ensure the program round-trips through bytes.
+        assert_eq!(program, Program::from_bytes_le(&program.to_bytes_le()?)?);
+        Ok(())
+    }
 }
@@ -222,6 +222,26 @@ impl<N: Network> ToBytes for GetOrUse<N> {
     }
 }
 
+impl<N: Network> Serialize for GetOrUse<N> {
+    /// Serializes the command into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.collect_str(self),
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for GetOrUse<N> {
+    /// Deserializes the command from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => FromStr::from_str(&String::deserialize(deserializer)?).map_err(de::Error::custom),
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "get.or_use"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -239,4 +259,20 @@ mod tests {
         assert_eq!(get_or_use.default, Operand::Register(Register::Locator(1)), "The second operand is incorrect");
         assert_eq!(get_or_use.destination, Register::Locator(2), "The second operand is incorrect");
     }
+
+    #[test]
+    fn test_serde() {
+        let expected = GetOrUse::<CurrentNetwork>::from_str("get.or_use account[r0] r1 into r2;").unwrap();
+
+        // Serialize to JSON.
+        let candidate_string = serde_json::to_string(&expected).unwrap();
+        assert_eq!(expected.to_string(), serde_json::Value::from_str(&candidate_string).unwrap().as_str().unwrap());
+        assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
+
+        // Serialize to bincode.
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        let candidate_bytes = bincode::serialize(&expected).unwrap();
+        assert_eq!(&expected_bytes[..], &candidate_bytes[8..]);
+        assert_eq!(expected, bincode::deserialize(&candidate_bytes[..]).unwrap());
+    }
 }
@@ -53,6 +53,7 @@ use crate::{
     FinalizeOperation,
     FinalizeRegistersState,
     Instruction,
+    Opcode,
 };
 use console::{
     network::prelude::*,
@@ -145,6 +146,24 @@ impl<N: Network> CommandTrait<N> for Command<N> {
 }
 
 impl<N: Network> Command<N> {
+    /// Returns the opcode of the command.
+    #[inline]
+    pub const fn opcode(&self) -> Opcode {
+        match self {
+            Command::Instruction(instruction) => instruction.opcode(),
+            Command::Await(_) => Await::<N>::opcode(),
+            Command::Contains(_) => Contains::<N>::opcode(),
+            Command::Get(_) => Get::<N>::opcode(),
+            Command::GetOrUse(_) => GetOrUse::<N>::opcode(),
+            Command::RandChaCha(_) => RandChaCha::<N>::opcode(),
+            Command::Remove(_) => Remove::<N>::opcode(),
+            Command::Set(_) => Set::<N>::opcode(),
+            Command::BranchEq(_) => BranchEq::<N>::opcode(),
+            Command::BranchNeq(_) => BranchNeq::<N>::opcode(),
+            Command::Position(_) => Position::<N>::opcode(),
+        }
+    }
+
     /// Finalizes the command.
     #[inline]
     pub fn finalize(
@@ -319,11 +338,15 @@ impl<N: Network> FromStr for Command<N> {
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string at {}. Found invalid character in: \"{remainder}\"",
+                    crate::parse::locate(string, remainder)
+                );
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => bail!("Failed to parse string{}. {error}", crate::parse::locate_error(string, &error)),
         }
     }
 }
@@ -354,6 +377,26 @@ impl<N: Network> Display for Command<N> {
     }
 }
 
+impl<N: Network> Serialize for Command<N> {
+    /// Serializes the command into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.collect_str(self),
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for Command<N> {
+    /// Deserializes the command from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => FromStr::from_str(&String::deserialize(deserializer)?).map_err(de::Error::custom),
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "command"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -520,4 +563,35 @@ mod tests {
         assert_eq!(Command::Position(Position::from_str(expected).unwrap()), command);
         assert_eq!(expected, command.to_string());
     }
+
+    #[test]
+    fn test_command_serde() {
+        for expected in [
+            "add r0 r1 into r2;",
+            "await r1;",
+            "contains object[r0] into r1;",
+            "get object[r0] into r1;",
+            "get.or_use object[r0] r1 into r2;",
+            "rand.chacha into r1 as field;",
+            "rand.chacha r0 r1 into r2 as group;",
+            "remove object[r0];",
+            "set r0 into object[r1];",
+            "branch.eq r0 r1 to exit;",
+            "branch.neq r2 r3 to start;",
+            "position exit;",
+        ] {
+            let command = Command::<CurrentNetwork>::from_str(expected).unwrap();
+
+            // Ensure the JSON form is the display string.
+            let candidate_string = serde_json::to_string(&command).unwrap();
+            assert_eq!(expected, serde_json::Value::from_str(&candidate_string).unwrap().as_str().unwrap());
+            assert_eq!(command, serde_json::from_str(&candidate_string).unwrap());
+
+            // Ensure the bincode form is the byte representation.
+            let expected_bytes = command.to_bytes_le().unwrap();
+            let candidate_bytes = bincode::serialize(&command).unwrap();
+            assert_eq!(&expected_bytes[..], &candidate_bytes[8..]);
+            assert_eq!(command, bincode::deserialize(&candidate_bytes[..]).unwrap());
+        }
+    }
 }
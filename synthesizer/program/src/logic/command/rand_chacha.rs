@@ -87,25 +87,15 @@ impl<N: Network> RandChaCha<N> {
         // Load the operands values.
         let seeds: Vec<_> = self.operands.iter().map(|operand| registers.load(stack, operand)).try_collect()?;
 
-        // Construct the random seed.
-        let preimage = to_bits_le![
-            registers.state().random_seed(),
+        // Construct the ChaChaRng seed.
+        let chacha_seed = registers.state().to_rand_seed::<N>(&(
             **registers.transition_id(),
-            stack.program_id(),
-            registers.function_name(),
+            *stack.program_id(),
+            *registers.function_name(),
             self.destination.locator(),
             self.destination_type.type_id(),
-            seeds
-        ];
-
-        // Hash the preimage.
-        let digest = N::hash_bhp1024(&preimage)?.to_bytes_le()?;
-        // Ensure the digest is 32-bytes.
-        ensure!(digest.len() == 32, "The digest for the ChaChaRng seed must be 32-bytes");
-
-        // Construct the ChaChaRng seed.
-        let mut chacha_seed = [0u8; 32];
-        chacha_seed.copy_from_slice(&digest[..32]);
+            seeds,
+        ))?;
 
         // Construct the ChaChaRng.
         let mut rng = rand_chacha::ChaCha20Rng::from_seed(chacha_seed);
@@ -285,6 +275,26 @@ impl<N: Network> ToBytes for RandChaCha<N> {
     }
 }
 
+impl<N: Network> Serialize for RandChaCha<N> {
+    /// Serializes the command into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.collect_str(self),
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for RandChaCha<N> {
+    /// Deserializes the command from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => FromStr::from_str(&String::deserialize(deserializer)?).map_err(de::Error::custom),
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "rand.chacha"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -340,4 +350,20 @@ mod tests {
             assert_eq!(rand.destination_type, *destination_type, "The destination type is incorrect");
         }
     }
+
+    #[test]
+    fn test_serde() {
+        let expected = RandChaCha::<CurrentNetwork>::from_str("rand.chacha r0 r1 into r2 as field;").unwrap();
+
+        // Serialize to JSON.
+        let candidate_string = serde_json::to_string(&expected).unwrap();
+        assert_eq!(expected.to_string(), serde_json::Value::from_str(&candidate_string).unwrap().as_str().unwrap());
+        assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
+
+        // Serialize to bincode.
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        let candidate_bytes = bincode::serialize(&expected).unwrap();
+        assert_eq!(&expected_bytes[..], &candidate_bytes[8..]);
+        assert_eq!(expected, bincode::deserialize(&candidate_bytes[..]).unwrap());
+    }
 }
@@ -164,6 +164,26 @@ impl<N: Network> ToBytes for Remove<N> {
     }
 }
 
+impl<N: Network> Serialize for Remove<N> {
+    /// Serializes the command into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.collect_str(self),
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for Remove<N> {
+    /// Deserializes the command from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => FromStr::from_str(&String::deserialize(deserializer)?).map_err(de::Error::custom),
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "remove"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -179,4 +199,20 @@ mod tests {
         assert_eq!(remove.operands().len(), 1, "The number of operands is incorrect");
         assert_eq!(remove.key, Operand::Register(Register::Locator(1)), "The first operand is incorrect");
     }
+
+    #[test]
+    fn test_serde() {
+        let expected = Remove::<CurrentNetwork>::from_str("remove account[r0];").unwrap();
+
+        // Serialize to JSON.
+        let candidate_string = serde_json::to_string(&expected).unwrap();
+        assert_eq!(expected.to_string(), serde_json::Value::from_str(&candidate_string).unwrap().as_str().unwrap());
+        assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
+
+        // Serialize to bincode.
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        let candidate_bytes = bincode::serialize(&expected).unwrap();
+        assert_eq!(&expected_bytes[..], &candidate_bytes[8..]);
+        assert_eq!(expected, bincode::deserialize(&candidate_bytes[..]).unwrap());
+    }
 }
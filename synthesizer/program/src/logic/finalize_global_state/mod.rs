@@ -97,4 +97,59 @@ impl FinalizeGlobalState {
     pub const fn random_seed(&self) -> &[u8; 32] {
         &self.random_seed
     }
+
+    /// Returns a 32-byte seed for a random-number generator, derived from the random seed and the given entropy.
+    ///
+    /// The seed is the first 32 bytes of `BHP1024(random_seed || additional_entropy)`, where each part is
+    /// encoded as little-endian bits. This is the seed derivation used by `rand.chacha`.
+    pub fn to_rand_seed<N: Network>(&self, additional_entropy: &impl ToBits) -> Result<[u8; 32]> {
+        // Construct the preimage.
+        let preimage = to_bits_le![self.random_seed, additional_entropy];
+        // Hash the preimage.
+        let digest = N::hash_bhp1024(&preimage)?.to_bytes_le()?;
+        // Ensure the digest is 32-bytes.
+        ensure!(digest.len() == 32, "The digest for the random seed must be 32-bytes");
+
+        // Convert the digest into a 32-byte array.
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(&digest[..32]);
+        Ok(seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::Testnet3, types::Field};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_rand_seed() {
+        let rng = &mut TestRng::default();
+
+        // Sample a global state and additional entropy.
+        let state = FinalizeGlobalState::from(rng.gen(), rng.gen(), rng.gen());
+        let entropy = [Field::<CurrentNetwork>::rand(rng), Field::rand(rng)];
+
+        // Ensure the seed is the truncated hash of the random seed, followed by the additional entropy.
+        let seed = state.to_rand_seed::<CurrentNetwork>(&entropy).unwrap();
+        let mut preimage = state.random_seed().to_bits_le();
+        preimage.extend(entropy[0].to_bits_le());
+        preimage.extend(entropy[1].to_bits_le());
+        let expected = CurrentNetwork::hash_bhp1024(&preimage).unwrap().to_bytes_le().unwrap();
+        assert_eq!(seed[..], expected[..32]);
+
+        // Ensure the seed is deterministic.
+        assert_eq!(seed, state.to_rand_seed::<CurrentNetwork>(&entropy).unwrap());
+        assert_eq!(
+            seed,
+            FinalizeGlobalState::from(0, 0, *state.random_seed()).to_rand_seed::<CurrentNetwork>(&entropy).unwrap()
+        );
+
+        // Ensure the seed depends on the random seed and the additional entropy.
+        let other_state = FinalizeGlobalState::from(state.block_round(), state.block_height(), rng.gen());
+        assert_ne!(seed, other_state.to_rand_seed::<CurrentNetwork>(&entropy).unwrap());
+        assert_ne!(seed, state.to_rand_seed::<CurrentNetwork>(&[entropy[1], entropy[0]]).unwrap());
+    }
 }
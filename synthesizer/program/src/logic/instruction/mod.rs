@@ -201,6 +201,34 @@ pub enum Instruction<N: Network> {
     Ternary(Ternary<N>),
     /// Performs a bitwise `xor` on `first` and `second`, storing the outcome in `destination`.
     Xor(Xor<N>),
+    /// Computes the sign of `first` as `-1`, `0`, or `1`, storing the outcome in `destination`.
+    Sign(Sign<N>),
+    /// Sums the elements of the array `first`, checking for overflow, and storing the outcome in `destination`.
+    Sum(Sum<N>),
+    /// Performs a BHP hash on the type ID and canonical field encoding of the input, in 256-bit chunks.
+    HashCanonicalBHP256(HashCanonicalBHP256<N>),
+    /// Performs a BHP hash on the type ID and canonical field encoding of the input, in 512-bit chunks.
+    HashCanonicalBHP512(HashCanonicalBHP512<N>),
+    /// Performs a BHP hash on the type ID and canonical field encoding of the input, in 768-bit chunks.
+    HashCanonicalBHP768(HashCanonicalBHP768<N>),
+    /// Performs a BHP hash on the type ID and canonical field encoding of the input, in 1024-bit chunks.
+    HashCanonicalBHP1024(HashCanonicalBHP1024<N>),
+    /// Performs a Keccak hash on the type ID and canonical field encoding of the input, outputting 256 bits.
+    HashCanonicalKeccak256(HashCanonicalKeccak256<N>),
+    /// Performs a Keccak hash on the type ID and canonical field encoding of the input, outputting 384 bits.
+    HashCanonicalKeccak384(HashCanonicalKeccak384<N>),
+    /// Performs a Keccak hash on the type ID and canonical field encoding of the input, outputting 512 bits.
+    HashCanonicalKeccak512(HashCanonicalKeccak512<N>),
+    /// Performs a Poseidon hash on the type ID and canonical field encoding of the input, with an input rate of 2.
+    HashCanonicalPSD2(HashCanonicalPSD2<N>),
+    /// Performs a Poseidon hash on the type ID and canonical field encoding of the input, with an input rate of 4.
+    HashCanonicalPSD4(HashCanonicalPSD4<N>),
+    /// Performs a Poseidon hash on the type ID and canonical field encoding of the input, with an input rate of 8.
+    HashCanonicalPSD8(HashCanonicalPSD8<N>),
+    /// Rotates `first` left by `second` bits, modulo the bit width of the type, storing the outcome in `destination`.
+    RotateLeft(RotateLeft<N>),
+    /// Rotates `first` right by `second` bits, modulo the bit width of the type, storing the outcome in `destination`.
+    RotateRight(RotateRight<N>),
 }
 
 /// Creates a match statement that applies the given operation for each instruction.
@@ -300,6 +328,21 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            // Note: New instructions are appended, as the position of each instruction determines its opcode index.
+            Sign,
+            Sum,
+            HashCanonicalBHP256,
+            HashCanonicalBHP512,
+            HashCanonicalBHP768,
+            HashCanonicalBHP1024,
+            HashCanonicalKeccak256,
+            HashCanonicalKeccak384,
+            HashCanonicalKeccak512,
+            HashCanonicalPSD2,
+            HashCanonicalPSD4,
+            HashCanonicalPSD8,
+            RotateLeft,
+            RotateRight,
         }}
     };
     // A variant **without** curly braces:
@@ -469,7 +512,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            68,
+            82,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
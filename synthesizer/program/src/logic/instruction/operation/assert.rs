@@ -32,11 +32,139 @@ enum Variant {
     AssertNeq,
 }
 
+/// The custom error code or message of an `assert` instruction, reported when the assertion fails.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub enum AssertMessage {
+    /// A numeric error code, e.g. `7u16`.
+    Code(u16),
+    /// An error message, e.g. `"insufficient_balance"`.
+    Message(String),
+}
+
+impl AssertMessage {
+    /// The maximum number of bytes in an error message.
+    pub const MAX_MESSAGE_BYTES: usize = 64;
+
+    /// Returns `true` if the given character may appear in an error message.
+    fn is_valid_char(c: char) -> bool {
+        c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | ':' | ' ')
+    }
+
+    /// Ensures the given error message is well-formed.
+    fn check_message(message: &str) -> Result<()> {
+        // Ensure the message is not empty.
+        ensure!(!message.is_empty(), "An assert message cannot be empty");
+        // Ensure the message does not exceed the maximum number of bytes.
+        ensure!(
+            message.len() <= Self::MAX_MESSAGE_BYTES,
+            "An assert message cannot exceed {} bytes, found {} bytes",
+            Self::MAX_MESSAGE_BYTES,
+            message.len()
+        );
+        // Ensure the message only contains valid characters.
+        ensure!(message.chars().all(Self::is_valid_char), "An assert message contains an invalid character");
+        Ok(())
+    }
+}
+
+impl Parser for AssertMessage {
+    /// Parses a string into an assert message.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse a numeric error code, e.g. `7u16`.
+        let parse_code = map_res(terminated(recognize(many1(one_of("0123456789"))), tag("u16")), |code: &str| {
+            code.parse::<u16>().map(Self::Code)
+        });
+        // Parse an error message, e.g. `"insufficient_balance"`.
+        let parse_message = map_res(
+            terminated(pair(char('"'), recognize(many1(alt((alphanumeric1, recognize(one_of("_-.: "))))))), char('"')),
+            |(_, message): (char, &str)| Self::check_message(message).map(|_| Self::Message(message.to_string())),
+        );
+        alt((parse_code, parse_message))(string)
+    }
+}
+
+impl FromStr for AssertMessage {
+    type Err = Error;
+
+    /// Parses a string into an assert message.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl Debug for AssertMessage {
+    /// Prints the assert message as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for AssertMessage {
+    /// Prints the assert message as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Code(code) => write!(f, "{code}u16"),
+            Self::Message(message) => write!(f, "\"{message}\""),
+        }
+    }
+}
+
+impl FromBytes for AssertMessage {
+    /// Reads the assert message from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        match u8::read_le(&mut reader)? {
+            0 => Ok(Self::Code(u16::read_le(&mut reader)?)),
+            1 => {
+                // Read the message.
+                let num_bytes = u8::read_le(&mut reader)?;
+                let mut bytes = vec![0u8; num_bytes as usize];
+                reader.read_exact(&mut bytes)?;
+                let message = String::from_utf8(bytes).map_err(|e| error(e.to_string()))?;
+                // Ensure the message is well-formed.
+                Self::check_message(&message).map_err(|e| error(e.to_string()))?;
+                Ok(Self::Message(message))
+            }
+            variant => Err(error(format!("Failed to deserialize assert message variant {variant}"))),
+        }
+    }
+}
+
+impl ToBytes for AssertMessage {
+    /// Writes the assert message to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::Code(code) => {
+                0u8.write_le(&mut writer)?;
+                code.write_le(&mut writer)
+            }
+            Self::Message(message) => {
+                // Ensure the message is well-formed.
+                Self::check_message(message).map_err(|e| error(e.to_string()))?;
+                1u8.write_le(&mut writer)?;
+                u8::try_from(message.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+                writer.write_all(message.as_bytes())
+            }
+        }
+    }
+}
+
 /// Asserts an operation on two operands.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct AssertInstruction<N: Network, const VARIANT: u8> {
     /// The operands.
     operands: Vec<Operand<N>>,
+    /// The optional error code or message, reported when the assertion fails.
+    message: Option<AssertMessage>,
 }
 
 impl<N: Network, const VARIANT: u8> AssertInstruction<N, VARIANT> {
@@ -46,7 +174,20 @@ impl<N: Network, const VARIANT: u8> AssertInstruction<N, VARIANT> {
         // Sanity check that the operands is exactly two inputs.
         ensure!(operands.len() == 2, "Assert instructions must have two operands");
         // Return the instruction.
-        Ok(Self { operands })
+        Ok(Self { operands, message: None })
+    }
+
+    /// Initializes a new `assert` instruction, with an error code or message reported on failure.
+    #[inline]
+    pub fn new_with_message(operands: Vec<Operand<N>>, message: AssertMessage) -> Result<Self> {
+        // Sanity check that the operands is exactly two inputs.
+        ensure!(operands.len() == 2, "Assert instructions must have two operands");
+        // Ensure the message is well-formed.
+        if let AssertMessage::Message(message) = &message {
+            AssertMessage::check_message(message)?;
+        }
+        // Return the instruction.
+        Ok(Self { operands, message: Some(message) })
     }
 
     /// Returns the opcode.
@@ -68,11 +209,25 @@ impl<N: Network, const VARIANT: u8> AssertInstruction<N, VARIANT> {
         &self.operands
     }
 
+    /// Returns the error code or message, if one is set.
+    #[inline]
+    pub const fn message(&self) -> Option<&AssertMessage> {
+        self.message.as_ref()
+    }
+
     /// Returns the destination register.
     #[inline]
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![]
     }
+
+    /// Returns the suffix of the halt error, which reports the error code or message, if one is set.
+    fn error_suffix(&self) -> String {
+        match &self.message {
+            Some(message) => format!(" with error {message}"),
+            None => String::new(),
+        }
+    }
 }
 
 impl<N: Network, const VARIANT: u8> AssertInstruction<N, VARIANT> {
@@ -96,12 +251,22 @@ impl<N: Network, const VARIANT: u8> AssertInstruction<N, VARIANT> {
         match VARIANT {
             0 => {
                 if input_a != input_b {
-                    bail!("'{}' failed: '{input_a}' is not equal to '{input_b}' (should be equal)", Self::opcode())
+                    return Err(Halt::new(format!(
+                        "'{}' failed{}: '{input_a}' is not equal to '{input_b}' (should be equal)",
+                        Self::opcode(),
+                        self.error_suffix()
+                    ))
+                    .into());
                 }
             }
             1 => {
                 if input_a == input_b {
-                    bail!("'{}' failed: '{input_a}' is equal to '{input_b}' (should not be equal)", Self::opcode())
+                    return Err(Halt::new(format!(
+                        "'{}' failed{}: '{input_a}' is equal to '{input_b}' (should not be equal)",
+                        Self::opcode(),
+                        self.error_suffix()
+                    ))
+                    .into());
                 }
             }
             _ => bail!("Invalid 'assert' variant: {VARIANT}"),
@@ -116,6 +281,8 @@ impl<N: Network, const VARIANT: u8> AssertInstruction<N, VARIANT> {
         stack: &(impl StackMatches<N> + StackProgram<N>),
         registers: &mut impl RegistersLoadCircuit<N, A>,
     ) -> Result<()> {
+        use circuit::Eject;
+
         // Ensure the number of operands is correct.
         if self.operands.len() != 2 {
             bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
@@ -125,12 +292,21 @@ impl<N: Network, const VARIANT: u8> AssertInstruction<N, VARIANT> {
         let input_a = registers.load_circuit(stack, &self.operands[0])?;
         let input_b = registers.load_circuit(stack, &self.operands[1])?;
 
-        // Assert the inputs.
-        match VARIANT {
-            0 => A::assert(input_a.is_equal(&input_b)),
-            1 => A::assert(input_a.is_not_equal(&input_b)),
+        // Compute the condition.
+        let condition = match VARIANT {
+            0 => input_a.is_equal(&input_b),
+            1 => input_a.is_not_equal(&input_b),
             _ => bail!("Invalid 'assert' variant: {VARIANT}"),
+        };
+        // If the condition is a constant `false`, halt with the error code or message, if one is set.
+        // Note: A non-constant condition is enforced below, and a failure is caught in `evaluate`.
+        if condition.is_constant() && !condition.eject_value() {
+            let message =
+                format!("'{}' failed{}: the inputs do not satisfy the assertion", Self::opcode(), self.error_suffix());
+            return Err(Halt::new(message).into());
         }
+        // Assert the inputs.
+        A::assert(condition);
         Ok(())
     }
 
@@ -190,8 +366,11 @@ impl<N: Network, const VARIANT: u8> Parser for AssertInstruction<N, VARIANT> {
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the second operand from the string.
         let (string, second) = Operand::parse(string)?;
+        // Parse the optional error code or message from the string.
+        let (string, message) =
+            opt(map(pair(Sanitizer::parse_whitespaces, AssertMessage::parse), |(_, message)| message))(string)?;
 
-        Ok((string, Self { operands: vec![first, second] }))
+        Ok((string, Self { operands: vec![first, second], message }))
     }
 }
 
@@ -229,22 +408,44 @@ impl<N: Network, const VARIANT: u8> Display for AssertInstruction<N, VARIANT> {
         }
         // Print the operation.
         write!(f, "{} ", Self::opcode())?;
-        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        // Print the error code or message, if one is set.
+        match &self.message {
+            Some(message) => write!(f, "{message}"),
+            None => Ok(()),
+        }
     }
 }
 
+/// The flag that prefixes the extended byte format, which carries an error code or message.
+/// Note: This flag is distinct from every operand variant, so the two-operand format remains valid.
+const EXTENDED_FORMAT_FLAG: u8 = u8::MAX;
+
 impl<N: Network, const VARIANT: u8> FromBytes for AssertInstruction<N, VARIANT> {
     /// Reads the operation from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the first byte, which is either the extended format flag, or the variant of the first operand.
+        let first_byte = u8::read_le(&mut reader)?;
+        let is_extended = first_byte == EXTENDED_FORMAT_FLAG;
+
         // Initialize the vector for the operands.
         let mut operands = Vec::with_capacity(2);
-        // Read the operands.
-        for _ in 0..2 {
-            operands.push(Operand::read_le(&mut reader)?);
+        // Read the first operand.
+        match is_extended {
+            true => operands.push(Operand::read_le(&mut reader)?),
+            false => operands.push(Operand::read_le([first_byte].as_slice().chain(&mut reader))?),
         }
+        // Read the second operand.
+        operands.push(Operand::read_le(&mut reader)?);
+
+        // Read the error code or message, if the format is extended.
+        let message = match is_extended {
+            true => Some(AssertMessage::read_le(&mut reader)?),
+            false => None,
+        };
 
         // Return the operation.
-        Ok(Self { operands })
+        Ok(Self { operands, message })
     }
 }
 
@@ -255,8 +456,17 @@ impl<N: Network, const VARIANT: u8> ToBytes for AssertInstruction<N, VARIANT> {
         if self.operands.len() != 2 {
             return Err(error(format!("The number of operands must be 2, found {}", self.operands.len())));
         }
+        // Write the extended format flag, if an error code or message is set.
+        if self.message.is_some() {
+            EXTENDED_FORMAT_FLAG.write_le(&mut writer)?;
+        }
         // Write the operands.
-        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the error code or message, if one is set.
+        match &self.message {
+            Some(message) => message.write_le(&mut writer),
+            None => Ok(()),
+        }
     }
 }
 
@@ -280,5 +490,59 @@ mod tests {
         assert_eq!(assert.operands.len(), 2, "The number of operands is incorrect");
         assert_eq!(assert.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
         assert_eq!(assert.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(assert.message, None, "The message is incorrect");
+    }
+
+    #[test]
+    fn test_parse_with_message() {
+        let (string, assert) = AssertEq::<CurrentNetwork>::parse("assert.eq r0 r1 7u16").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(assert.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(assert.message, Some(AssertMessage::Code(7)), "The message is incorrect");
+
+        let (string, assert) = AssertNeq::<CurrentNetwork>::parse("assert.neq r0 r1 \"insufficient_balance\"").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(assert.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(
+            assert.message,
+            Some(AssertMessage::Message("insufficient_balance".to_string())),
+            "The message is incorrect"
+        );
+
+        // Ensure invalid messages are rejected.
+        assert!(AssertEq::<CurrentNetwork>::from_str("assert.eq r0 r1 \"\"").is_err());
+        assert!(AssertEq::<CurrentNetwork>::from_str("assert.eq r0 r1 \"unterminated").is_err());
+        assert!(AssertEq::<CurrentNetwork>::from_str("assert.eq r0 r1 70000u16").is_err());
+        assert!(AssertEq::<CurrentNetwork>::from_str(&format!("assert.eq r0 r1 \"{}\"", "a".repeat(65))).is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        let assert = AssertEq::<CurrentNetwork>::from_str("assert.eq r0 r1").unwrap();
+        assert_eq!("assert.eq r0 r1 ", assert.to_string());
+
+        let assert = AssertEq::<CurrentNetwork>::from_str("assert.eq r0 r1 7u16").unwrap();
+        assert_eq!("assert.eq r0 r1 7u16", assert.to_string());
+
+        let assert = AssertNeq::<CurrentNetwork>::from_str("assert.neq r0 r1 \"insufficient_balance\"").unwrap();
+        assert_eq!("assert.neq r0 r1 \"insufficient_balance\"", assert.to_string());
+    }
+
+    #[test]
+    fn test_bytes() {
+        for string in ["assert.eq r0 r1", "assert.eq r0 1u8 7u16", "assert.eq r0.owner r1 \"insufficient_balance\""] {
+            let expected = AssertEq::<CurrentNetwork>::from_str(string).unwrap();
+            let candidate = AssertEq::<CurrentNetwork>::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
+            assert_eq!(expected, candidate);
+        }
+
+        // Ensure the two-operand format is unchanged.
+        let assert = AssertEq::<CurrentNetwork>::from_str("assert.eq r0 r1").unwrap();
+        let expected =
+            [Operand::<CurrentNetwork>::Register(Register::Locator(0)), Operand::Register(Register::Locator(1))]
+                .iter()
+                .flat_map(|operand| operand.to_bytes_le().unwrap())
+                .collect::<Vec<_>>();
+        assert_eq!(expected, assert.to_bytes_le().unwrap());
     }
 }
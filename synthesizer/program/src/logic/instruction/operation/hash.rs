@@ -20,6 +20,7 @@ use crate::{
 use console::{
     network::prelude::*,
     program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::Field,
 };
 
 /// BHP256 is a collision-resistant hash function that processes inputs in 256-bit chunks.
@@ -64,6 +65,32 @@ pub type HashManyPSD4<N> = HashInstruction<N, { Hasher::HashManyPSD4 as u8 }>;
 /// Poseidon8 is a cryptographic hash function that processes inputs in 8-field chunks.
 pub type HashManyPSD8<N> = HashInstruction<N, { Hasher::HashManyPSD8 as u8 }>;
 
+// The `hash_canonical.*` variants are separate opcodes, rather than a new input mode of the existing
+// `hash.*` opcodes, because the existing opcodes hash `to_bits_le` of the input, and any change to
+// their preimage would change the digests of deployed programs. The canonical variants hash the
+// preimage `[ TYPE_ID || FIELDS(INPUT) ]`, so that inputs of different types never share a preimage.
+
+/// BHP256 over the type ID and canonical field encoding of the input, outputting a field element.
+pub type HashCanonicalBHP256<N> = HashInstruction<N, { Hasher::HashCanonicalBHP256 as u8 }>;
+/// BHP512 over the type ID and canonical field encoding of the input, outputting a field element.
+pub type HashCanonicalBHP512<N> = HashInstruction<N, { Hasher::HashCanonicalBHP512 as u8 }>;
+/// BHP768 over the type ID and canonical field encoding of the input, outputting a field element.
+pub type HashCanonicalBHP768<N> = HashInstruction<N, { Hasher::HashCanonicalBHP768 as u8 }>;
+/// BHP1024 over the type ID and canonical field encoding of the input, outputting a field element.
+pub type HashCanonicalBHP1024<N> = HashInstruction<N, { Hasher::HashCanonicalBHP1024 as u8 }>;
+/// Keccak256 over the type ID and canonical field encoding of the input, outputting a field element.
+pub type HashCanonicalKeccak256<N> = HashInstruction<N, { Hasher::HashCanonicalKeccak256 as u8 }>;
+/// Keccak384 over the type ID and canonical field encoding of the input, outputting a field element.
+pub type HashCanonicalKeccak384<N> = HashInstruction<N, { Hasher::HashCanonicalKeccak384 as u8 }>;
+/// Keccak512 over the type ID and canonical field encoding of the input, outputting a field element.
+pub type HashCanonicalKeccak512<N> = HashInstruction<N, { Hasher::HashCanonicalKeccak512 as u8 }>;
+/// Poseidon2 over the type ID and canonical field encoding of the input, outputting a field element.
+pub type HashCanonicalPSD2<N> = HashInstruction<N, { Hasher::HashCanonicalPSD2 as u8 }>;
+/// Poseidon4 over the type ID and canonical field encoding of the input, outputting a field element.
+pub type HashCanonicalPSD4<N> = HashInstruction<N, { Hasher::HashCanonicalPSD4 as u8 }>;
+/// Poseidon8 over the type ID and canonical field encoding of the input, outputting a field element.
+pub type HashCanonicalPSD8<N> = HashInstruction<N, { Hasher::HashCanonicalPSD8 as u8 }>;
+
 enum Hasher {
     HashBHP256,
     HashBHP512,
@@ -83,6 +110,16 @@ enum Hasher {
     HashManyPSD2,
     HashManyPSD4,
     HashManyPSD8,
+    HashCanonicalBHP256,
+    HashCanonicalBHP512,
+    HashCanonicalBHP768,
+    HashCanonicalBHP1024,
+    HashCanonicalKeccak256,
+    HashCanonicalKeccak384,
+    HashCanonicalKeccak512,
+    HashCanonicalPSD2,
+    HashCanonicalPSD4,
+    HashCanonicalPSD8,
 }
 
 /// Returns the expected number of operands given the variant.
@@ -103,12 +140,79 @@ fn check_number_of_operands(variant: u8, opcode: Opcode, num_operands: usize) ->
     Ok(())
 }
 
-/// Returns 'true' if the destination type is valid.
-fn is_valid_destination_type<N: Network>(destination_type: &PlaintextType<N>) -> bool {
-    !matches!(
-        destination_type,
-        PlaintextType::Literal(LiteralType::Boolean) | PlaintextType::Literal(LiteralType::String)
-    )
+/// Returns 'true' if the destination type is valid for the given variant.
+fn is_valid_destination_type<N: Network>(variant: u8, destination_type: &PlaintextType<N>) -> bool {
+    match variant {
+        // The canonical variants always output a field element.
+        18..=27 => matches!(destination_type, PlaintextType::Literal(LiteralType::Field)),
+        _ => !matches!(
+            destination_type,
+            PlaintextType::Literal(LiteralType::Boolean) | PlaintextType::Literal(LiteralType::String)
+        ),
+    }
+}
+
+/// Returns the canonical type descriptor of the given plaintext, which is defined as:
+/// ```text
+/// descriptor := literal_type | "{" name ":" descriptor ("," name ":" descriptor)* "}" | "[" descriptor ";" length "]"
+/// ```
+/// where `literal_type` is the literal type name (e.g. `u8`), and `length` is the decimal array length.
+///
+/// The descriptor is derived from the value, because a console plaintext does not carry its struct name,
+/// and the operand register type is not available to `evaluate`, `execute`, and `finalize` alike.
+/// As such, struct members are described by name and in declaration order, so that two structs with
+/// the same member types in a different order (or under different names) are distinguished, while
+/// two structs with identical members (under different struct names) share a type ID.
+fn canonical_type_descriptor<N: Network>(plaintext: &Plaintext<N>) -> Result<String> {
+    match plaintext {
+        Plaintext::Literal(literal, _) => Ok(literal.to_type().to_string()),
+        Plaintext::Struct(members, _) => {
+            let members = members
+                .iter()
+                .map(|(name, member)| Ok(format!("{name}:{}", canonical_type_descriptor(member)?)))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(format!("{{{}}}", members.join(",")))
+        }
+        Plaintext::Array(elements, _) => match elements.first() {
+            Some(element) => Ok(format!("[{};{}]", canonical_type_descriptor(element)?, elements.len())),
+            None => bail!("Cannot canonically hash an empty array"),
+        },
+    }
+}
+
+/// Returns the type ID of the given plaintext, which domain-separates the canonical hash variants.
+fn canonical_type_id<N: Network>(plaintext: &Plaintext<N>) -> Result<Field<N>> {
+    N::hash_bhp1024(&canonical_type_descriptor(plaintext)?.as_bytes().to_bits_le())
+}
+
+/// Returns the canonical preimage of the given input, as `[ TYPE_ID || FIELDS(INPUT) ]`.
+fn canonical_preimage<N: Network>(input: &Value<N>) -> Result<Vec<Field<N>>> {
+    match input {
+        Value::Plaintext(plaintext) => {
+            let mut preimage = vec![canonical_type_id(plaintext)?];
+            preimage.extend(plaintext.to_fields()?);
+            Ok(preimage)
+        }
+        _ => bail!("Cannot canonically hash a non-plaintext value"),
+    }
+}
+
+/// Returns the canonical preimage of the given circuit input, as `[ TYPE_ID || FIELDS(INPUT) ]`.
+/// The type ID depends only on the shape of the input, and is therefore a constant in the circuit.
+fn canonical_preimage_circuit<N: Network, A: circuit::Aleo<Network = N>>(
+    input: &circuit::Value<A>,
+) -> Result<Vec<circuit::Field<A>>> {
+    use circuit::{traits::ToFields, Eject, Inject};
+
+    match input {
+        circuit::Value::Plaintext(plaintext) => {
+            let type_id = canonical_type_id(&plaintext.eject_value())?;
+            let mut preimage = vec![circuit::Field::new(circuit::Mode::Constant, type_id)];
+            preimage.extend(plaintext.to_fields());
+            Ok(preimage)
+        }
+        _ => bail!("Cannot canonically hash a non-plaintext value"),
+    }
 }
 
 /// Hashes the operand into the declared type.
@@ -133,7 +237,7 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Sanity check the number of operands.
         check_number_of_operands(VARIANT, Self::opcode(), operands.len())?;
         // Sanity check the destination type.
-        if !is_valid_destination_type(&destination_type) {
+        if !is_valid_destination_type(VARIANT, &destination_type) {
             bail!("Invalid destination type for 'hash' instruction")
         }
         // Return the instruction.
@@ -162,7 +266,17 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             15 => Opcode::Hash("hash_many.psd2"),
             16 => Opcode::Hash("hash_many.psd4"),
             17 => Opcode::Hash("hash_many.psd8"),
-            18.. => panic!("Invalid 'hash' instruction opcode"),
+            18 => Opcode::Hash("hash_canonical.bhp256"),
+            19 => Opcode::Hash("hash_canonical.bhp512"),
+            20 => Opcode::Hash("hash_canonical.bhp768"),
+            21 => Opcode::Hash("hash_canonical.bhp1024"),
+            22 => Opcode::Hash("hash_canonical.keccak256"),
+            23 => Opcode::Hash("hash_canonical.keccak384"),
+            24 => Opcode::Hash("hash_canonical.keccak512"),
+            25 => Opcode::Hash("hash_canonical.psd2"),
+            26 => Opcode::Hash("hash_canonical.psd4"),
+            27 => Opcode::Hash("hash_canonical.psd8"),
+            28.. => panic!("Invalid 'hash' instruction opcode"),
         }
     }
 
@@ -203,7 +317,10 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Ensure the number of operands is correct.
         check_number_of_operands(VARIANT, Self::opcode(), self.operands.len())?;
         // Ensure the destination type is valid.
-        ensure!(is_valid_destination_type(&self.destination_type), "Invalid destination type in 'hash' instruction");
+        ensure!(
+            is_valid_destination_type(VARIANT, &self.destination_type),
+            "Invalid destination type in 'hash' instruction"
+        );
 
         // Load the operand.
         let input = registers.load(stack, &self.operands[0])?;
@@ -248,7 +365,24 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             (15, _) => bail!("'hash_many.psd2' is not yet implemented"),
             (16, _) => bail!("'hash_many.psd4' is not yet implemented"),
             (17, _) => bail!("'hash_many.psd8' is not yet implemented"),
-            (18.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
+            (18..=27, PlaintextType::Literal(..)) => {
+                // Construct the canonical preimage.
+                let preimage = canonical_preimage(&input)?;
+                let bits = || preimage.iter().flat_map(|field| field.to_bits_le()).collect::<Vec<_>>();
+                match VARIANT {
+                    18 => Literal::Field(N::hash_bhp256(&bits())?),
+                    19 => Literal::Field(N::hash_bhp512(&bits())?),
+                    20 => Literal::Field(N::hash_bhp768(&bits())?),
+                    21 => Literal::Field(N::hash_bhp1024(&bits())?),
+                    22 => Literal::Field(N::hash_bhp256(&N::hash_keccak256(&bits())?)?),
+                    23 => Literal::Field(N::hash_bhp512(&N::hash_keccak384(&bits())?)?),
+                    24 => Literal::Field(N::hash_bhp512(&N::hash_keccak512(&bits())?)?),
+                    25 => Literal::Field(N::hash_psd2(&preimage)?),
+                    26 => Literal::Field(N::hash_psd4(&preimage)?),
+                    _ => Literal::Field(N::hash_psd8(&preimage)?),
+                }
+            }
+            (28.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
             (_, PlaintextType::Struct(..)) => bail!("Cannot hash into a struct"),
             (_, PlaintextType::Array(..)) => bail!("Cannot hash into an array (yet)"),
         };
@@ -274,7 +408,10 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Ensure the number of operands is correct.
         check_number_of_operands(VARIANT, Self::opcode(), self.operands.len())?;
         // Ensure the destination type is valid.
-        ensure!(is_valid_destination_type(&self.destination_type), "Invalid destination type in 'hash' instruction");
+        ensure!(
+            is_valid_destination_type(VARIANT, &self.destination_type),
+            "Invalid destination type in 'hash' instruction"
+        );
 
         // Load the operand.
         let input = registers.load_circuit(stack, &self.operands[0])?;
@@ -319,7 +456,24 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             (15, _) => bail!("'hash_many.psd2' is not yet implemented"),
             (16, _) => bail!("'hash_many.psd4' is not yet implemented"),
             (17, _) => bail!("'hash_many.psd8' is not yet implemented"),
-            (18.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
+            (18..=27, PlaintextType::Literal(..)) => {
+                // Construct the canonical preimage.
+                let preimage = canonical_preimage_circuit(&input)?;
+                let bits = || preimage.iter().flat_map(|field| field.to_bits_le()).collect::<Vec<_>>();
+                match VARIANT {
+                    18 => circuit::Literal::Field(A::hash_bhp256(&bits())),
+                    19 => circuit::Literal::Field(A::hash_bhp512(&bits())),
+                    20 => circuit::Literal::Field(A::hash_bhp768(&bits())),
+                    21 => circuit::Literal::Field(A::hash_bhp1024(&bits())),
+                    22 => circuit::Literal::Field(A::hash_bhp256(&A::hash_keccak256(&bits()))),
+                    23 => circuit::Literal::Field(A::hash_bhp512(&A::hash_keccak384(&bits()))),
+                    24 => circuit::Literal::Field(A::hash_bhp512(&A::hash_keccak512(&bits()))),
+                    25 => circuit::Literal::Field(A::hash_psd2(&preimage)),
+                    26 => circuit::Literal::Field(A::hash_psd4(&preimage)),
+                    _ => circuit::Literal::Field(A::hash_psd8(&preimage)),
+                }
+            }
+            (28.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
             (_, PlaintextType::Struct(..)) => bail!("Cannot hash into a struct"),
             (_, PlaintextType::Array(..)) => bail!("Cannot hash into an array (yet)"),
         };
@@ -357,14 +511,21 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Ensure the number of operands is correct.
         check_number_of_operands(VARIANT, Self::opcode(), self.operands.len())?;
         // Ensure the destination type is valid.
-        ensure!(is_valid_destination_type(&self.destination_type), "Invalid destination type in 'hash' instruction");
+        ensure!(
+            is_valid_destination_type(VARIANT, &self.destination_type),
+            "Invalid destination type in 'hash' instruction"
+        );
 
         // TODO (howardwu): If the operation is Pedersen, check that it is within the number of bits.
 
         match VARIANT {
             0..=14 => Ok(vec![RegisterType::Plaintext(self.destination_type.clone())]),
             15..=17 => bail!("'hash_many' is not yet implemented"),
-            18.. => bail!("Invalid 'hash' variant: {VARIANT}"),
+            18..=27 => match &input_types[0] {
+                RegisterType::Plaintext(..) => Ok(vec![RegisterType::Plaintext(self.destination_type.clone())]),
+                _ => bail!("Instruction '{}' expects a plaintext operand", Self::opcode()),
+            },
+            28.. => bail!("Invalid 'hash' variant: {VARIANT}"),
         }
     }
 }
@@ -413,13 +574,11 @@ impl<N: Network, const VARIANT: u8> Parser for HashInstruction<N, VARIANT> {
         // Parse the destination register type from the string.
         let (string, destination_type) = PlaintextType::parse(string)?;
         // Ensure the destination type is allowed.
-        match destination_type {
-            PlaintextType::Literal(LiteralType::Boolean) | PlaintextType::Literal(LiteralType::String) => {
-                map_res(fail, |_: ParserResult<Self>| {
-                    Err(error(format!("Failed to parse 'hash': '{destination_type}' is invalid")))
-                })(string)
-            }
-            _ => Ok((string, Self { operands, destination, destination_type })),
+        match is_valid_destination_type(VARIANT, &destination_type) {
+            false => map_res(fail, |_: ParserResult<Self>| {
+                Err(error(format!("Failed to parse 'hash': '{destination_type}' is invalid")))
+            })(string),
+            true => Ok((string, Self { operands, destination, destination_type })),
         }
     }
 }
@@ -530,4 +689,65 @@ mod tests {
             assert_eq!(&hash.destination_type, destination_type, "The destination type is incorrect");
         }
     }
+
+    #[test]
+    fn test_parse_canonical() {
+        let instruction = "hash_canonical.psd2 r0 into r1 as field";
+        let (string, hash) = HashCanonicalPSD2::<CurrentNetwork>::parse(instruction).unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(hash.operands.len(), 1, "The number of operands is incorrect");
+        assert_eq!(hash.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(hash.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(hash.destination_type, PlaintextType::Literal(LiteralType::Field), "The destination type is wrong");
+
+        // Ensure the canonical variants only output a field element.
+        for destination_type in valid_destination_types::<CurrentNetwork>() {
+            if destination_type != &PlaintextType::Literal(LiteralType::Field) {
+                let instruction = format!("hash_canonical.bhp256 r0 into r1 as {destination_type}");
+                assert!(HashCanonicalBHP256::<CurrentNetwork>::parse(&instruction).is_err());
+            }
+        }
+        // Ensure the existing variants do not parse the canonical opcode.
+        assert!(HashPSD2::<CurrentNetwork>::parse(instruction).is_err());
+    }
+
+    #[test]
+    fn test_canonical_type_descriptor() {
+        let check = |plaintext: &str, expected: &str| {
+            let plaintext = Plaintext::<CurrentNetwork>::from_str(plaintext).unwrap();
+            assert_eq!(canonical_type_descriptor(&plaintext).unwrap(), expected);
+        };
+        check("1u8", "u8");
+        check("{ a: 1u8, b: 2field }", "{a:u8,b:field}");
+        check("{ b: 2field, a: 1u8 }", "{b:field,a:u8}");
+        check("[1u8, 2u8, 3u8]", "[u8;3]");
+        check("{ a: [{ x: 0i64 }, { x: 1i64 }] }", "{a:[{x:i64};2]}");
+    }
+
+    #[test]
+    fn test_canonical_preimage() {
+        let struct_ = Value::<CurrentNetwork>::from_str("{ a: 1field, b: 1field }").unwrap();
+        let reordered = Value::<CurrentNetwork>::from_str("{ b: 1field, a: 1field }").unwrap();
+
+        // Check the preimage is the type ID followed by the field encoding of the input.
+        let preimage = canonical_preimage(&struct_).unwrap();
+        let type_id = CurrentNetwork::hash_bhp1024(&"{a:field,b:field}".as_bytes().to_bits_le()).unwrap();
+        assert_eq!(preimage[0], type_id);
+        assert_eq!(preimage[1..], struct_.to_fields().unwrap()[..]);
+
+        // Check the reordered struct is domain-separated from the original struct.
+        let reordered_preimage = canonical_preimage(&reordered).unwrap();
+        assert_ne!(preimage[0], reordered_preimage[0]);
+        assert_ne!(
+            CurrentNetwork::hash_psd2(&preimage).unwrap(),
+            CurrentNetwork::hash_psd2(&reordered_preimage).unwrap()
+        );
+
+        // Check records are rejected.
+        let record = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, _nonce: 0group.public }",
+        )
+        .unwrap();
+        assert!(canonical_preimage(&record).is_err());
+    }
 }
@@ -41,6 +41,9 @@ mod macros;
 mod sign_verify;
 pub use sign_verify::*;
 
+mod sum;
+pub use sum::Sum;
+
 use crate::Opcode;
 use console::network::prelude::*;
 
@@ -525,6 +528,52 @@ crate::operation!(
     }
 );
 
+/// Rotates `first` left by `second` bits, modulo the bit width of the type, storing the outcome in `destination`.
+pub type RotateLeft<N> = BinaryLiteral<N, RotateLeftOperation<N>>;
+
+crate::operation!(
+    pub struct RotateLeftOperation<console::prelude::RotateLeft, circuit::traits::RotateLeft, rotate_left, "rol"> {
+        (U8, U8) => U8,
+        (U8, U16) => U8,
+        (U8, U32) => U8,
+        (U16, U8) => U16,
+        (U16, U16) => U16,
+        (U16, U32) => U16,
+        (U32, U8) => U32,
+        (U32, U16) => U32,
+        (U32, U32) => U32,
+        (U64, U8) => U64,
+        (U64, U16) => U64,
+        (U64, U32) => U64,
+        (U128, U8) => U128,
+        (U128, U16) => U128,
+        (U128, U32) => U128,
+    }
+);
+
+/// Rotates `first` right by `second` bits, modulo the bit width of the type, storing the outcome in `destination`.
+pub type RotateRight<N> = BinaryLiteral<N, RotateRightOperation<N>>;
+
+crate::operation!(
+    pub struct RotateRightOperation<console::prelude::RotateRight, circuit::traits::RotateRight, rotate_right, "ror"> {
+        (U8, U8) => U8,
+        (U8, U16) => U8,
+        (U8, U32) => U8,
+        (U16, U8) => U16,
+        (U16, U16) => U16,
+        (U16, U32) => U16,
+        (U32, U8) => U32,
+        (U32, U16) => U32,
+        (U32, U32) => U32,
+        (U64, U8) => U64,
+        (U64, U16) => U64,
+        (U64, U32) => U64,
+        (U128, U8) => U128,
+        (U128, U16) => U128,
+        (U128, U32) => U128,
+    }
+);
+
 /// Shifts `first` left by `second` bits, storing the outcome in `destination`.
 pub type Shl<N> = BinaryLiteral<N, ShlOperation<N>>;
 
@@ -677,6 +726,19 @@ crate::operation!(
     }
 );
 
+/// Computes the sign of `first` as `-1`, `0`, or `1`, storing the outcome in `destination`.
+pub type Sign<N> = UnaryLiteral<N, SignOperation<N>>;
+
+crate::operation!(
+    pub struct SignOperation<console::prelude::Signum, circuit::traits::Signum, signum, "sign"> {
+        I8 => I8,
+        I16 => I16,
+        I32 => I32,
+        I64 => I64,
+        I128 => I128,
+    }
+);
+
 /// Squares `first`, storing the outcome in `destination`.
 pub type Square<N> = UnaryLiteral<N, SquareOperation<N>>;
 
@@ -0,0 +1,292 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    AddOperation,
+    Opcode,
+    Operand,
+    Operation,
+};
+use console::{
+    network::prelude::*,
+    program::{LiteralType, Plaintext, PlaintextType, Register, RegisterType},
+};
+
+/// Sums the elements of the array `first`, checking for overflow, and storing the outcome in `destination`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Sum<N: Network> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> Sum<N> {
+    /// Initializes a new `sum` instruction.
+    #[inline]
+    pub fn new(operand: Operand<N>, destination: Register<N>) -> Self {
+        Self { operands: vec![operand], destination }
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Literal("sum")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is exactly one input.
+        debug_assert!(self.operands.len() == 1, "Instruction '{}' must have one operand", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network> Sum<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Load the array elements.
+        let elements = match registers.load_plaintext(stack, &self.operands[0])? {
+            Plaintext::Array(elements, _) => elements,
+            plaintext => bail!("Instruction '{}' expects an array operand, found '{plaintext}'", Self::opcode()),
+        };
+        // Retrieve the literal of each element.
+        let mut literals = elements.into_iter().map(|element| match element {
+            Plaintext::Literal(literal, _) => Ok(literal),
+            element => bail!("Instruction '{}' expects an array of literals, found '{element}'", Self::opcode()),
+        });
+
+        // Sum the elements, starting from the first element.
+        // Note: The addition is checked, and halts on overflow.
+        let first =
+            literals.next().ok_or_else(|| anyhow!("Instruction '{}' expects a non-empty array", Self::opcode()))?;
+        let output = literals.try_fold(first?, |sum, element| AddOperation::<N>::evaluate(&[sum, element?]))?;
+
+        // Store the output.
+        registers.store_literal(stack, &self.destination, output)
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Load the array elements.
+        let elements = match registers.load_plaintext_circuit(stack, &self.operands[0])? {
+            circuit::Plaintext::Array(elements, _) => elements,
+            _ => bail!("Instruction '{}' expects an array operand", Self::opcode()),
+        };
+        // Retrieve the literal of each element.
+        let mut literals = elements.into_iter().map(|element| match element {
+            circuit::Plaintext::Literal(literal, _) => Ok(literal),
+            _ => bail!("Instruction '{}' expects an array of literals", Self::opcode()),
+        });
+
+        // Sum the elements by chaining the adders, starting from the first element.
+        let first =
+            literals.next().ok_or_else(|| anyhow!("Instruction '{}' expects a non-empty array", Self::opcode()))?;
+        let output = literals.try_fold(first?, |sum, element| AddOperation::<N>::execute::<A>(&[sum, element?]))?;
+
+        // Store the output.
+        registers.store_literal_circuit(stack, &self.destination, output)
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 1 {
+            bail!("Instruction '{}' expects 1 input, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Ensure the input is an array of field elements or integers.
+        match &input_types[0] {
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => match array_type.next_element_type() {
+                PlaintextType::Literal(
+                    literal_type @ (LiteralType::Field
+                    | LiteralType::I8
+                    | LiteralType::I16
+                    | LiteralType::I32
+                    | LiteralType::I64
+                    | LiteralType::I128
+                    | LiteralType::U8
+                    | LiteralType::U16
+                    | LiteralType::U32
+                    | LiteralType::U64
+                    | LiteralType::U128),
+                ) => Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(*literal_type))]),
+                element_type => bail!(
+                    "Instruction '{}' expects an array of field elements or integers, found an array of '{element_type}'",
+                    Self::opcode()
+                ),
+            },
+            input_type => bail!("Instruction '{}' expects an array, found '{input_type}'", Self::opcode()),
+        }
+    }
+}
+
+impl<N: Network> Parser for Sum<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the operand from the string.
+        let (string, operand) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![operand], destination }))
+    }
+}
+
+impl<N: Network> FromStr for Sum<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Sum<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Sum<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} {} into {}", Self::opcode(), self.operands[0], self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for Sum<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the operand.
+        let operand = Operand::read_le(&mut reader)?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Return the operation.
+        Ok(Self { operands: vec![operand], destination })
+    }
+}
+
+impl<N: Network> ToBytes for Sum<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(error(format!("The number of operands must be 1, found {}", self.operands.len())));
+        }
+        // Write the operand.
+        self.operands[0].write_le(&mut writer)?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Sum, *};
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, sum) = Sum::<CurrentNetwork>::parse("sum r0 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(sum.operands.len(), 1, "The number of operands is incorrect");
+        assert_eq!(sum.operands[0], Operand::Register(Register::Locator(0)), "The operand is incorrect");
+        assert_eq!(sum.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!("sum r0 into r1", sum.to_string());
+    }
+
+    #[test]
+    fn test_bytes() {
+        let expected = Sum::<CurrentNetwork>::from_str("sum r0.values into r1").unwrap();
+        let candidate = Sum::<CurrentNetwork>::read_le(&expected.to_bytes_le().unwrap()[..]).unwrap();
+        assert_eq!(expected, candidate);
+    }
+}
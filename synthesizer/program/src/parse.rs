@@ -14,6 +14,41 @@
 
 use super::*;
 
+use console::network::prelude::{error_remainder, ParserError, ParserPosition};
+
+/// The keywords that begin a program definition.
+const DEFINITION_KEYWORDS: &[&str] = &["program", "mapping", "struct", "record", "closure", "function", "finalize"];
+
+/// Returns the location at which parsing stopped in the input, as the line and column,
+/// followed by the nearest enclosing definition (e.g. `function foo`), if one exists.
+pub(crate) fn locate(input: &str, remainder: &str) -> String {
+    // Skip the leading whitespace, so that the location points to the invalid token.
+    let remainder = remainder.trim_start();
+    // Compute the position of the remainder.
+    let position = ParserPosition::locate(input, remainder);
+    // Retrieve the input up to the end of the line of the position.
+    let offset = input.len().saturating_sub(remainder.len());
+    let end = input.get(offset..).and_then(|rest| rest.find('\n')).map_or(input.len(), |index| offset + index);
+    // Find the nearest enclosing definition.
+    let enclosing = input.get(..end).unwrap_or(input).lines().rev().find_map(|line| {
+        let mut tokens = line.split_whitespace();
+        let keyword = tokens.next().filter(|keyword| DEFINITION_KEYWORDS.contains(keyword))?;
+        Some(format!("{keyword} {}", tokens.next()?.trim_end_matches([':', ';'])))
+    });
+    match enclosing {
+        Some(enclosing) => format!("{position} (in '{enclosing}')"),
+        None => position.to_string(),
+    }
+}
+
+/// Returns the location of the given parser error in the input, if it can be determined.
+pub(crate) fn locate_error(input: &str, error: &ParserError) -> String {
+    match error_remainder(error) {
+        Some(remainder) => format!(" at {}", locate(input, remainder)),
+        None => String::new(),
+    }
+}
+
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Parser
     for ProgramCore<N, Instruction, Command>
 {
@@ -109,11 +144,15 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
         match Self::parse(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Remaining invalid string is: \"{remainder}\"");
+                ensure!(
+                    remainder.is_empty(),
+                    "Failed to parse string at {}. Remaining invalid string is: \"{remainder}\"",
+                    locate(string, remainder)
+                );
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => bail!("Failed to parse string{}. {error}", locate_error(string, &error)),
         }
     }
 }
@@ -183,7 +222,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Dis
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Program;
+    use crate::{Command, Finalize, Function, Program};
     use console::network::Testnet3;
 
     type CurrentNetwork = Testnet3;
@@ -255,4 +294,44 @@ function compute:
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error_position() {
+        // Ensure a program error reports the line, column, and enclosing function.
+        let error = Program::<CurrentNetwork>::from_str(
+            "program located.aleo;
+
+function foo:
+    input r0 as u32.private;
+    bogus r0 into r1;
+    output r0 as u32.private;",
+        )
+        .unwrap_err();
+        assert!(error.to_string().starts_with("Failed to parse string at line 5, column 5 (in 'function foo')."));
+
+        // Ensure a function error reports the line, column, and enclosing function.
+        let error = Function::<CurrentNetwork>::from_str(
+            "function bar:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.private;
+      bogus;",
+        )
+        .unwrap_err();
+        assert!(error.to_string().starts_with("Failed to parse string at line 5, column 7 (in 'function bar')."));
+
+        // Ensure a finalize error reports the line, column, and enclosing finalize.
+        let error = Finalize::<CurrentNetwork>::from_str(
+            "finalize baz:
+    input r0 as u64.public;
+    add r0 r0 into r1;
+    bogus r1;",
+        )
+        .unwrap_err();
+        assert!(error.to_string().starts_with("Failed to parse string at line 4, column 5 (in 'finalize baz')."));
+
+        // Ensure a command error reports the line and column.
+        let error = Command::<CurrentNetwork>::from_str("position foo; bar").unwrap_err();
+        assert!(error.to_string().starts_with("Failed to parse string at line 1, column 15."));
+    }
 }
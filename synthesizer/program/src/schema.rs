@@ -0,0 +1,147 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::program::{EntryType, ValueType};
+use serde_json::{json, Map, Value};
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
+    /// Returns a JSON schema for the struct with the given name, including its nested structs and arrays.
+    pub fn struct_schema(&self, name: &Identifier<N>) -> Result<Value> {
+        // Ensure the struct exists.
+        ensure!(self.contains_struct(name), "Struct '{name}' is not defined in '{}'", self.id);
+        // Return the schema of the struct.
+        self.plaintext_schema(&PlaintextType::Struct(*name))
+    }
+
+    /// Returns a JSON schema for the inputs and outputs of the function with the given name.
+    pub fn function_io_schema(&self, name: &Identifier<N>) -> Result<Value> {
+        // Retrieve the function.
+        let function = self.get_function_ref(name)?;
+        // Construct the schema for each input.
+        let inputs = function
+            .inputs()
+            .iter()
+            .map(|input| {
+                let mut schema = Map::new();
+                schema.insert("register".to_string(), json!(input.register().to_string()));
+                schema.extend(self.value_schema(input.value_type())?);
+                Ok(Value::Object(schema))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Construct the schema for each output.
+        let outputs = function
+            .outputs()
+            .iter()
+            .map(|output| {
+                let mut schema = Map::new();
+                schema.insert("operand".to_string(), json!(output.operand().to_string()));
+                schema.extend(self.value_schema(output.value_type())?);
+                Ok(Value::Object(schema))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Return the schema of the function.
+        Ok(json!({
+            "program": self.id.to_string(),
+            "function": name.to_string(),
+            "inputs": inputs,
+            "outputs": outputs,
+        }))
+    }
+}
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
+    /// Returns a JSON schema for the given plaintext type.
+    fn plaintext_schema(&self, plaintext_type: &PlaintextType<N>) -> Result<Value> {
+        match plaintext_type {
+            PlaintextType::Literal(literal_type) => Ok(json!({ "type": literal_type.type_name() })),
+            PlaintextType::Struct(name) => {
+                // Retrieve the struct.
+                let struct_ = self.get_struct(name)?;
+                // Construct the schema for each member.
+                let members = struct_
+                    .members()
+                    .iter()
+                    .map(|(identifier, member_type)| Ok((identifier.to_string(), self.plaintext_schema(member_type)?)))
+                    .collect::<Result<Map<_, _>>>()?;
+                Ok(json!({ "type": "struct", "name": name.to_string(), "members": members }))
+            }
+            PlaintextType::Array(array_type) => Ok(json!({
+                "type": "array",
+                "length": **array_type.length(),
+                "element": self.plaintext_schema(array_type.next_element_type())?,
+            })),
+        }
+    }
+
+    /// Returns the JSON schema fields for the given value type.
+    fn value_schema(&self, value_type: &ValueType<N>) -> Result<Map<String, Value>> {
+        // Prepends the visibility to the schema of the given plaintext type.
+        let with_visibility = |visibility: &str, plaintext_type: &PlaintextType<N>| -> Result<Map<String, Value>> {
+            let mut schema = Map::new();
+            schema.insert("visibility".to_string(), json!(visibility));
+            if let Value::Object(fields) = self.plaintext_schema(plaintext_type)? {
+                schema.extend(fields);
+            }
+            Ok(schema)
+        };
+
+        match value_type {
+            ValueType::Constant(plaintext_type) => with_visibility("constant", plaintext_type),
+            ValueType::Public(plaintext_type) => with_visibility("public", plaintext_type),
+            ValueType::Private(plaintext_type) => with_visibility("private", plaintext_type),
+            ValueType::Record(name) => {
+                // Retrieve the record type.
+                let record_type = self.get_record(name)?;
+                // Construct the schema for each entry.
+                let entries = record_type
+                    .entries()
+                    .iter()
+                    .map(|(identifier, entry_type)| {
+                        let schema = match entry_type {
+                            EntryType::Constant(plaintext_type) => with_visibility("constant", plaintext_type)?,
+                            EntryType::Public(plaintext_type) => with_visibility("public", plaintext_type)?,
+                            EntryType::Private(plaintext_type) => with_visibility("private", plaintext_type)?,
+                        };
+                        Ok((identifier.to_string(), Value::Object(schema)))
+                    })
+                    .collect::<Result<Map<_, _>>>()?;
+                // Determine the visibility of the owner.
+                let owner = match record_type.owner().is_public() {
+                    true => "public",
+                    false => "private",
+                };
+                let mut schema = Map::new();
+                schema.insert("type".to_string(), json!("record"));
+                schema.insert("name".to_string(), json!(name.to_string()));
+                schema.insert("owner".to_string(), json!(owner));
+                schema.insert("entries".to_string(), Value::Object(entries));
+                Ok(schema)
+            }
+            ValueType::ExternalRecord(locator) => {
+                let mut schema = Map::new();
+                schema.insert("type".to_string(), json!("external_record"));
+                schema.insert("locator".to_string(), json!(locator.to_string()));
+                Ok(schema)
+            }
+            ValueType::Future(locator) => {
+                let mut schema = Map::new();
+                schema.insert("type".to_string(), json!("future"));
+                schema.insert("locator".to_string(), json!(locator.to_string()));
+                Ok(schema)
+            }
+        }
+    }
+}
@@ -32,6 +32,22 @@ pub fn sample_registers(
     stack: &Stack<CurrentNetwork>,
     function_name: &Identifier<CurrentNetwork>,
     values: &[(&Literal<CurrentNetwork>, Option<circuit::Mode>)],
+) -> Result<Registers<CurrentNetwork, CurrentAleo>> {
+    // Initialize the plaintexts.
+    let plaintexts = values.iter().map(|(literal, mode)| (Plaintext::from(*literal), *mode)).collect::<Vec<_>>();
+    // Sample the registers.
+    sample_plaintext_registers(
+        stack,
+        function_name,
+        &plaintexts.iter().map(|(plaintext, mode)| (plaintext, *mode)).collect::<Vec<_>>(),
+    )
+}
+
+/// Samples the registers with the given plaintexts. Note: Do not replicate this for real program use, it is insecure.
+pub fn sample_plaintext_registers(
+    stack: &Stack<CurrentNetwork>,
+    function_name: &Identifier<CurrentNetwork>,
+    values: &[(&Plaintext<CurrentNetwork>, Option<circuit::Mode>)],
 ) -> Result<Registers<CurrentNetwork, CurrentAleo>> {
     // Initialize the registers.
     let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
@@ -40,11 +56,11 @@ pub fn sample_registers(
     );
 
     // For each value, store the register and value.
-    for (index, (literal, mode)) in values.iter().enumerate() {
+    for (index, (plaintext, mode)) in values.iter().enumerate() {
         // Initialize the register.
         let register = Register::Locator(index as u64);
         // Initialize the console value.
-        let value = Value::Plaintext(Plaintext::from(*literal));
+        let value = Value::Plaintext((*plaintext).clone());
         // Store the value in the console registers.
         registers.store(stack, &register, value.clone())?;
         // If the mode is not `None`,
@@ -65,6 +81,18 @@ pub fn sample_finalize_registers(
     stack: &Stack<CurrentNetwork>,
     function_name: &Identifier<CurrentNetwork>,
     literals: &[&Literal<CurrentNetwork>],
+) -> Result<FinalizeRegisters<CurrentNetwork>> {
+    // Initialize the plaintexts.
+    let plaintexts = literals.iter().map(|literal| Plaintext::from(*literal)).collect::<Vec<_>>();
+    // Sample the finalize registers.
+    sample_plaintext_finalize_registers(stack, function_name, &plaintexts.iter().collect::<Vec<_>>())
+}
+
+/// Samples the finalize registers with the given plaintexts. Note: Do not replicate this for real program use, it is insecure.
+pub fn sample_plaintext_finalize_registers(
+    stack: &Stack<CurrentNetwork>,
+    function_name: &Identifier<CurrentNetwork>,
+    plaintexts: &[&Plaintext<CurrentNetwork>],
 ) -> Result<FinalizeRegisters<CurrentNetwork>> {
     // Initialize the registers.
     let mut finalize_registers = FinalizeRegisters::<CurrentNetwork>::new(
@@ -74,12 +102,12 @@ pub fn sample_finalize_registers(
         stack.get_finalize_types(function_name)?.clone(),
     );
 
-    // For each literal,
-    for (index, literal) in literals.iter().enumerate() {
+    // For each plaintext,
+    for (index, plaintext) in plaintexts.iter().enumerate() {
         // Initialize the register
         let register = Register::Locator(index as u64);
         // Initialize the console value.
-        let value = Value::Plaintext(Plaintext::from(*literal));
+        let value = Value::Plaintext((*plaintext).clone());
         // Store the value in the console registers.
         finalize_registers.store(stack, &register, value)?;
     }
@@ -14,7 +14,12 @@
 
 include!("../helpers/macros.rs");
 
-use crate::helpers::sample::{sample_finalize_registers, sample_registers};
+use crate::helpers::sample::{
+    sample_finalize_registers,
+    sample_plaintext_finalize_registers,
+    sample_plaintext_registers,
+    sample_registers,
+};
 
 use circuit::{AleoV0, Eject};
 use console::{
@@ -27,6 +32,16 @@ use snarkvm_synthesizer_program::{
     HashBHP256,
     HashBHP512,
     HashBHP768,
+    HashCanonicalBHP1024,
+    HashCanonicalBHP256,
+    HashCanonicalBHP512,
+    HashCanonicalBHP768,
+    HashCanonicalKeccak256,
+    HashCanonicalKeccak384,
+    HashCanonicalKeccak512,
+    HashCanonicalPSD2,
+    HashCanonicalPSD4,
+    HashCanonicalPSD8,
     HashInstruction,
     HashKeccak256,
     HashKeccak384,
@@ -191,6 +206,88 @@ fn check_hash<const VARIANT: u8>(
     <CurrentAleo as circuit::Environment>::reset();
 }
 
+/// Samples the stack for a canonical hash of the given input type, with the `point` and `reordered_point` structs.
+fn sample_canonical_stack(opcode: Opcode, type_: &str, mode: circuit::Mode) -> Result<Stack<CurrentNetwork>> {
+    // Initialize the program.
+    let program = Program::from_str(&format!(
+        "program testing.aleo;
+        struct point:
+            x as u64;
+            y as u64;
+        struct reordered_point:
+            y as u64;
+            x as u64;
+        function run:
+            input r0 as {type_}.{mode};
+            {opcode} r0 into r1 as field;
+            async run r0 into r2;
+            output r2 as testing.aleo/run.future;
+        finalize run:
+            input r0 as {type_}.public;
+            {opcode} r0 into r1 as field;
+        "
+    ))?;
+
+    // Initialize the stack.
+    Stack::new(&Process::load()?, &program)
+}
+
+/// Checks that evaluate, execute, and finalize agree on the canonical hash of the given plaintext, and returns the digest.
+fn check_hash_canonical<const VARIANT: u8>(
+    operation: impl FnOnce(
+        Vec<Operand<CurrentNetwork>>,
+        Register<CurrentNetwork>,
+        PlaintextType<CurrentNetwork>,
+    ) -> HashInstruction<CurrentNetwork, VARIANT>,
+    opcode: Opcode,
+    type_: &str,
+    plaintext: &Plaintext<CurrentNetwork>,
+    mode: circuit::Mode,
+) -> Value<CurrentNetwork> {
+    println!("Checking '{opcode}' for '{plaintext}' as '{type_}.{mode}'");
+
+    // Initialize the stack.
+    let stack = sample_canonical_stack(opcode, type_, mode).unwrap();
+
+    // Initialize the operation.
+    let destination = Register::Locator(1);
+    let operation = operation(
+        vec![Operand::Register(Register::Locator(0))],
+        destination.clone(),
+        PlaintextType::Literal(LiteralType::Field),
+    );
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run").unwrap();
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(destination);
+
+    // Evaluate the operation.
+    let mut evaluate_registers = sample_plaintext_registers(&stack, &function_name, &[(plaintext, None)]).unwrap();
+    operation.evaluate(&stack, &mut evaluate_registers).unwrap();
+
+    // Execute the operation.
+    let mut execute_registers = sample_plaintext_registers(&stack, &function_name, &[(plaintext, Some(mode))]).unwrap();
+    operation.execute::<CurrentAleo>(&stack, &mut execute_registers).unwrap();
+
+    // Finalize the operation.
+    let mut finalize_registers = sample_plaintext_finalize_registers(&stack, &function_name, &[plaintext]).unwrap();
+    operation.finalize(&stack, &mut finalize_registers).unwrap();
+
+    // Retrieve the outputs.
+    let output_a = evaluate_registers.load(&stack, &destination_operand).unwrap();
+    let output_b = execute_registers.load_circuit(&stack, &destination_operand).unwrap();
+    let output_c = finalize_registers.load(&stack, &destination_operand).unwrap();
+
+    // Check that the outputs are consistent.
+    assert_eq!(output_a, output_b.eject_value(), "The results of the evaluation and execution are inconsistent");
+    assert_eq!(output_a, output_c, "The results of the evaluation and finalization are inconsistent");
+
+    // Reset the circuit.
+    <CurrentAleo as circuit::Environment>::reset();
+
+    output_a
+}
+
 macro_rules! test_hash {
         ($name: tt, $hash:ident, $iterations:expr) => {
             paste::paste! {
@@ -245,6 +342,107 @@ test_hash!(hash_sha3_256, HashSha3_256, 5);
 test_hash!(hash_sha3_384, HashSha3_384, 5);
 test_hash!(hash_sha3_512, HashSha3_512, 5);
 
+macro_rules! test_hash_canonical {
+    ($name: tt, $hash:ident, $iterations:expr) => {
+        paste::paste! {
+            #[test]
+            fn [<test _ $name _ is _ consistent>]() {
+                // Initialize the operation.
+                let operation = |operands, destination, destination_type| $hash::<CurrentNetwork>::new(operands, destination, destination_type).unwrap();
+                // Initialize the opcode.
+                let opcode = $hash::<CurrentNetwork>::opcode();
+
+                // Prepare the rng.
+                let mut rng = TestRng::default();
+
+                // Prepare the test.
+                let modes = [circuit::Mode::Public, circuit::Mode::Private];
+
+                for _ in 0..$iterations {
+                    let literals = sample_literals!(CurrentNetwork, &mut rng);
+                    for literal in literals.iter() {
+                        for mode in modes.iter() {
+                            // Note: The canonical variants only output a field element.
+                            check_hash(
+                                operation,
+                                opcode,
+                                literal,
+                                mode,
+                                PlaintextType::Literal(LiteralType::Field),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+test_hash_canonical!(hash_canonical_bhp256, HashCanonicalBHP256, ITERATIONS);
+test_hash_canonical!(hash_canonical_bhp512, HashCanonicalBHP512, ITERATIONS);
+test_hash_canonical!(hash_canonical_bhp768, HashCanonicalBHP768, ITERATIONS);
+test_hash_canonical!(hash_canonical_bhp1024, HashCanonicalBHP1024, ITERATIONS);
+
+test_hash_canonical!(hash_canonical_keccak256, HashCanonicalKeccak256, 5);
+test_hash_canonical!(hash_canonical_keccak384, HashCanonicalKeccak384, 5);
+test_hash_canonical!(hash_canonical_keccak512, HashCanonicalKeccak512, 5);
+
+test_hash_canonical!(hash_canonical_psd2, HashCanonicalPSD2, ITERATIONS);
+test_hash_canonical!(hash_canonical_psd4, HashCanonicalPSD4, ITERATIONS);
+test_hash_canonical!(hash_canonical_psd8, HashCanonicalPSD8, ITERATIONS);
+
+macro_rules! test_hash_canonical_plaintexts {
+    ($name: tt, $hash:ident, $point:expr, $array:expr) => {
+        paste::paste! {
+            #[test]
+            fn [<test _ $name _ plaintexts _ are _ consistent>]() {
+                // Initialize the operation.
+                let operation = |operands, destination, destination_type| $hash::<CurrentNetwork>::new(operands, destination, destination_type).unwrap();
+                // Initialize the opcode.
+                let opcode = $hash::<CurrentNetwork>::opcode();
+
+                // Initialize the plaintexts.
+                let point = Plaintext::<CurrentNetwork>::from_str("{ x: 1u64, y: 2u64 }").unwrap();
+                let reordered_point = Plaintext::<CurrentNetwork>::from_str("{ y: 2u64, x: 1u64 }").unwrap();
+                let array = Plaintext::<CurrentNetwork>::from_str("[1u8, 2u8, 3u8]").unwrap();
+
+                for mode in [circuit::Mode::Public, circuit::Mode::Private] {
+                    // Check the struct digest.
+                    let digest = check_hash_canonical(operation, opcode, "point", &point, mode);
+                    assert_eq!(digest, Value::from_str($point).unwrap());
+
+                    // Check the array digest.
+                    let digest = check_hash_canonical(operation, opcode, "[u8; 3u32]", &array, mode);
+                    assert_eq!(digest, Value::from_str($array).unwrap());
+
+                    // Ensure a struct with the same members in a different order has a different digest.
+                    let digest = check_hash_canonical(operation, opcode, "reordered_point", &reordered_point, mode);
+                    assert_ne!(digest, Value::from_str($point).unwrap());
+                }
+            }
+        }
+    };
+}
+
+test_hash_canonical_plaintexts!(
+    hash_canonical_bhp256,
+    HashCanonicalBHP256,
+    "1868369425554802530064480025341575531706344145920020927850292462384072214939field",
+    "6902630172336227354325600941753277074124696665393339534087002638898287572285field"
+);
+test_hash_canonical_plaintexts!(
+    hash_canonical_keccak256,
+    HashCanonicalKeccak256,
+    "7392754207483326602442838943840821314376199334398590435767397177800850940335field",
+    "2894449088489147517146398297973987701362771289964151669970374371065852345496field"
+);
+test_hash_canonical_plaintexts!(
+    hash_canonical_psd2,
+    HashCanonicalPSD2,
+    "2350265257435251937334780899111377028939234228119590193847832683501992395249field",
+    "2626043179949959324282755706240702816108676058492500458618275640528311328455field"
+);
+
 // Note this test must be explicitly written, instead of using the macro, because HashPED64 fails on certain input types.
 #[test]
 fn test_hash_ped64_is_consistent() {
@@ -55,6 +55,7 @@ mod tests {
         // Check the byte representation.
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected, Certificate::read_le(&expected_bytes[..])?);
+        assert_eq!(expected_bytes.len(), Certificate::<CurrentNetwork>::SIZE_IN_BYTES);
         assert!(Certificate::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
 
         Ok(())
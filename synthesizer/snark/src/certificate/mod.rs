@@ -25,6 +25,10 @@ pub struct Certificate<N: Network> {
 }
 
 impl<N: Network> Certificate<N> {
+    /// The size in bytes of a certificate.
+    /// A certificate is a single opening proof without hiding, so its size does not depend on the circuit.
+    pub const SIZE_IN_BYTES: usize = 58;
+
     /// Initializes a new certificate.
     pub(super) const fn new(certificate: varuna::Certificate<N::PairingCurve>) -> Self {
         Self { certificate }
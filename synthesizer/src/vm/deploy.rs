@@ -65,6 +65,45 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Return the deploy transaction.
         Transaction::from_deployment(owner, deployment, fee)
     }
+
+    /// Returns a new deploy transaction, which reuses the deployment of the given deploy transaction with a new fee.
+    ///
+    /// The deployment (program, verifying keys, and certificates) is reused as-is, and is not re-synthesized.
+    /// The program owner is re-signed with the given private key, which must belong to the original owner,
+    /// and the new fee must be authorized for the deployment ID of the given deploy transaction.
+    pub fn redeploy_with_new_fee<R: Rng + CryptoRng>(
+        &self,
+        transaction: &Transaction<N>,
+        new_fee: Fee<N>,
+        private_key: &PrivateKey<N>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Retrieve the owner and deployment.
+        let Transaction::Deploy(id, owner, deployment, _) = transaction else {
+            bail!("Transaction '{}' is not a deploy transaction", transaction.id())
+        };
+        // Ensure the private key belongs to the original program owner.
+        ensure!(
+            owner.address() == Address::try_from(private_key)?,
+            "Attempted to re-sign deployment transaction '{id}' with a different program owner"
+        );
+        // Compute the deployment ID.
+        let deployment_id = deployment.to_deployment_id()?;
+        // Ensure the new fee is for the same deployment.
+        ensure!(
+            new_fee.deployment_or_execution_id()? == deployment_id,
+            "Attempted to redeploy transaction '{id}' with a fee for a different deployment"
+        );
+
+        // Construct the owner.
+        let owner = ProgramOwner::new(private_key, deployment_id, rng)?;
+        // Construct the deploy transaction.
+        let redeployment = Transaction::from_deployment(owner, deployment.as_ref().clone(), new_fee)?;
+        // Verify the new fee.
+        self.check_fee(&redeployment, None)?;
+        // Return the deploy transaction.
+        Ok(redeployment)
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -89,3 +128,87 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         result
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::test_helpers::{
+        sample_deployment_transaction,
+        sample_execution_transaction_with_public_fee,
+        sample_genesis_private_key,
+        sample_vm_with_genesis_block,
+    };
+
+    #[test]
+    fn test_deployment_report_matches_fee() {
+        let rng = &mut TestRng::default();
+
+        // Retrieve the deploy transaction.
+        let transaction = sample_deployment_transaction(rng);
+        let deployment = transaction.deployment().unwrap();
+
+        // Compute the report.
+        let report = deployment.report().unwrap();
+
+        // Ensure the report matches the minimum deployment cost.
+        let (total_cost, (storage_cost, namespace_cost)) = deployment_cost(deployment).unwrap();
+        assert_eq!(report.total_cost().unwrap(), total_cost);
+        assert_eq!(report.storage_cost(), storage_cost);
+        assert_eq!(report.namespace_cost(), namespace_cost);
+
+        // Ensure the report matches the base fee charged for the deployment.
+        assert_eq!(report.total_cost().unwrap(), *transaction.base_fee_amount().unwrap());
+    }
+
+    #[test]
+    fn test_redeploy_with_new_fee() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = sample_vm_with_genesis_block(rng);
+        // Retrieve the program owner's private key.
+        let private_key = sample_genesis_private_key(rng);
+        // Retrieve the deploy transaction.
+        let transaction = sample_deployment_transaction(rng);
+        let deployment = transaction.deployment().unwrap();
+        let deployment_id = deployment.to_deployment_id().unwrap();
+
+        // Compute a new (public) fee for the same deployment.
+        let (minimum_deployment_cost, _) = deployment_cost(deployment).unwrap();
+        let authorization =
+            vm.authorize_fee_public(&private_key, minimum_deployment_cost, 20, deployment_id, rng).unwrap();
+        let new_fee = vm.execute_fee_authorization(authorization, None, rng).unwrap();
+
+        // Redeploy with the new fee.
+        let redeployment = vm.redeploy_with_new_fee(&transaction, new_fee.clone(), &private_key, rng).unwrap();
+
+        // Ensure the redeployment verifies, with a new transaction ID.
+        assert_ne!(redeployment.id(), transaction.id());
+        assert_eq!(redeployment.fee_transition().unwrap(), new_fee);
+        vm.check_transaction(&redeployment, None).unwrap();
+
+        // Ensure the deployment is unchanged.
+        assert_eq!(redeployment.deployment().unwrap().to_bytes_le().unwrap(), deployment.to_bytes_le().unwrap());
+
+        // Ensure the new owner is signed for the deployment, and not for the transaction ID.
+        let old_owner = transaction.owner().unwrap();
+        let new_owner = redeployment.owner().unwrap();
+        assert_eq!(old_owner.address(), new_owner.address());
+        assert_ne!(old_owner.signature(), new_owner.signature());
+        assert!(new_owner.verify(deployment_id));
+        assert!(!old_owner.verify(*redeployment.id()));
+        assert!(!new_owner.verify(*redeployment.id()));
+
+        // Ensure a different program owner cannot re-sign the deployment.
+        let other_private_key = PrivateKey::new(rng).unwrap();
+        assert!(vm.redeploy_with_new_fee(&transaction, new_fee.clone(), &other_private_key, rng).is_err());
+
+        // Ensure a fee for a different transaction is rejected.
+        let execution = sample_execution_transaction_with_public_fee(rng);
+        let other_fee = execution.fee_transition().unwrap();
+        assert!(vm.redeploy_with_new_fee(&transaction, other_fee, &private_key, rng).is_err());
+
+        // Ensure a non-deploy transaction is rejected.
+        assert!(vm.redeploy_with_new_fee(&execution, new_fee, &private_key, rng).is_err());
+    }
+}
@@ -104,6 +104,30 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         debug_assert!(authorization.is_fee_private() || authorization.is_fee_public(), "Expected a fee authorization");
         self.execute_fee_authorization_raw(authorization, query, rng)
     }
+
+    /// Returns a new public fee for the given fee amount (in microcredits) and the deployment or execution ID.
+    ///
+    /// The fee is bound to the given deployment or execution ID. Note that this method does not consult
+    /// the public balance of the account, as the balance is checked when the fee is finalized.
+    pub fn execute_fee_public<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        base_fee_in_microcredits: u64,
+        priority_fee_in_microcredits: u64,
+        deployment_or_execution_id: Field<N>,
+        rng: &mut R,
+    ) -> Result<Fee<N>> {
+        // Authorize the fee.
+        let authorization = self.authorize_fee_public(
+            private_key,
+            base_fee_in_microcredits,
+            priority_fee_in_microcredits,
+            deployment_or_execution_id,
+            rng,
+        )?;
+        // Execute the fee.
+        self.execute_fee_authorization_raw(authorization, None, rng)
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -400,6 +424,45 @@ mod tests {
         assert_eq!(2011, fee_size_in_bytes, "Update me if serialization has changed");
     }
 
+    #[test]
+    fn test_execute_fee_public() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM.
+        let (vm, _) = prepare_vm(rng).unwrap();
+
+        // Prepare the inputs.
+        let inputs = [
+            Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ]
+        .into_iter();
+        // Authorize the execution.
+        let authorization = vm.authorize(&caller_private_key, "credits.aleo", "transfer_public", inputs, rng).unwrap();
+        // Compute the execution.
+        let execution = vm.execute_authorization(authorization, None, None, rng).unwrap().execution().unwrap().clone();
+        let execution_id = execution.to_execution_id().unwrap();
+
+        // Compute the public fee.
+        let fee = vm.execute_fee_public(&caller_private_key, 10_000_000, 100, execution_id, rng).unwrap();
+        assert!(fee.is_fee_public());
+        assert_eq!(fee.deployment_or_execution_id().unwrap(), execution_id);
+
+        // Construct the transaction, and ensure it is valid.
+        let transaction = Transaction::from_execution(execution.clone(), Some(fee)).unwrap();
+        assert!(matches!(transaction, Transaction::Execute(..)));
+        vm.check_transaction(&transaction, None).unwrap();
+
+        // Ensure a fee bound to a different execution ID is rejected.
+        let fee = vm.execute_fee_public(&caller_private_key, 10_000_000, 100, Field::rand(rng), rng).unwrap();
+        let transaction = Transaction::from_execution(execution, Some(fee)).unwrap();
+        assert!(vm.check_transaction(&transaction, None).is_err());
+    }
+
     #[test]
     fn test_fee_public_transition_size() {
         let rng = &mut TestRng::default();
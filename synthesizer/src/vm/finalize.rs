@@ -125,6 +125,54 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         finish!(timer, "Finished real-run of finalize");
         Ok(ratified_finalize_operations)
     }
+
+    /// Returns the value for the given `program ID`, `mapping name`, and `key`, as it would be
+    /// if the given candidate transactions were applied in the block after the latest block.
+    ///
+    /// Note: This method performs a **dry-run** of the candidate transactions, so no state is persisted,
+    /// and the confirmed value for the key is left untouched. As the dry-run uses the atomic batch of the store,
+    /// it holds the same lock as `VM::add_next_block`.
+    pub fn get_mapping_value_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        candidate_transactions: &[Transaction<N>],
+    ) -> Result<Option<Value<N>>> {
+        let timer = timer!("VM::get_mapping_value_speculative");
+
+        // Acquire the block lock, to ensure a block is not added concurrently.
+        let _block_lock = self.block_lock.lock();
+
+        // Retrieve the latest block header.
+        let Some(latest_height) = self.block_store().heights().max().map(|height| *height) else {
+            bail!("Cannot speculate on a mapping value - there are no blocks in storage")
+        };
+        let Some(latest_hash) = self.block_store().get_block_hash(latest_height)? else {
+            bail!("Cannot speculate on a mapping value - missing block hash for block {latest_height}")
+        };
+        let Some(latest_header) = self.block_store().get_block_header(&latest_hash)? else {
+            bail!("Cannot speculate on a mapping value - missing block header for block {latest_height}")
+        };
+
+        // Construct the finalize state for the next block.
+        let state = FinalizeGlobalState::new::<N>(
+            latest_header.round().saturating_add(1),
+            latest_height.saturating_add(1),
+            latest_header.cumulative_weight(),
+            latest_header.cumulative_proof_target(),
+            latest_hash,
+        )?;
+
+        // Performs a **dry-run** over the candidate transactions, and reads the value before the batch is aborted.
+        let (.., value) =
+            self.atomic_speculate_and_inspect(state, None, vec![], None, candidate_transactions.iter(), |store| {
+                store.get_value_speculative(program_id, mapping_name, key)
+            })?;
+
+        finish!(timer, "Finished dry-run of the transactions");
+        Ok(value)
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -150,6 +198,35 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         Vec<ConfirmedTransaction<N>>,
         Vec<(Transaction<N>, String)>,
         Vec<FinalizeOperation<N>>,
+    )> {
+        let (ratifications, confirmed, aborted, ratified_finalize_operations, ()) =
+            self.atomic_speculate_and_inspect(state, coinbase_reward, ratifications, solutions, transactions, |_| {
+                Ok(())
+            })?;
+        Ok((ratifications, confirmed, aborted, ratified_finalize_operations))
+    }
+
+    /// Performs atomic speculation over a list of transactions, and calls `inspect` on the
+    /// finalize store after the speculation, but before the atomic batch is aborted.
+    ///
+    /// Returns the ratifications, confirmed transactions, aborted transactions,
+    /// finalize operations from pre-ratify and post-ratify, and the output of `inspect`.
+    ///
+    /// Note: This method is used by `VM::atomic_speculate` and `VM::get_mapping_value_speculative`.
+    fn atomic_speculate_and_inspect<'a, T>(
+        &self,
+        state: FinalizeGlobalState,
+        coinbase_reward: Option<u64>,
+        ratifications: Vec<Ratify<N>>,
+        solutions: Option<&CoinbaseSolution<N>>,
+        transactions: impl ExactSizeIterator<Item = &'a Transaction<N>>,
+        inspect: impl FnOnce(&FinalizeStore<N, C::FinalizeStorage>) -> Result<T>,
+    ) -> Result<(
+        Ratifications<N>,
+        Vec<ConfirmedTransaction<N>>,
+        Vec<(Transaction<N>, String)>,
+        Vec<FinalizeOperation<N>>,
+        T,
     )> {
         let timer = timer!("VM::atomic_speculate");
 
@@ -169,12 +246,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
             // Initialize an iterator for ratifications before finalize.
             let pre_ratifications = ratifications.iter().filter(|r| match r {
-                Ratify::Genesis(_, _) => true,
+                Ratify::Genesis(..) => true,
                 Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => false,
             });
             // Initialize an iterator for ratifications after finalize.
             let post_ratifications = ratifications.iter().filter(|r| match r {
-                Ratify::Genesis(_, _) => false,
+                Ratify::Genesis(..) => false,
                 Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => true,
             });
 
@@ -365,11 +442,20 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 return Err("Failed to construct the ratifications after speculation".to_string());
             };
 
+            /* Inspect the finalize store after speculation. */
+
+            let inspected = match inspect(store) {
+                Ok(inspected) => inspected,
+                // Note: This will abort the entire atomic batch.
+                Err(e) => return Err(format!("Failed to inspect the speculation - {e}")),
+            };
+
             finish!(timer);
 
             // On return, 'atomic_finalize!' will abort the batch, and return the ratifications,
-            // confirmed & aborted transactions, and finalize operations from pre-ratify and post-ratify.
-            Ok((ratifications, confirmed, aborted, ratified_finalize_operations))
+            // confirmed & aborted transactions, finalize operations from pre-ratify and post-ratify,
+            // and the inspected output.
+            Ok((ratifications, confirmed, aborted, ratified_finalize_operations, inspected))
         })
     }
 
@@ -390,12 +476,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         atomic_finalize!(self.finalize_store(), FinalizeMode::RealRun, {
             // Initialize an iterator for ratifications before finalize.
             let pre_ratifications = ratifications.iter().filter(|r| match r {
-                Ratify::Genesis(_, _) => true,
+                Ratify::Genesis(..) => true,
                 Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => false,
             });
             // Initialize an iterator for ratifications after finalize.
             let post_ratifications = ratifications.iter().filter(|r| match r {
-                Ratify::Genesis(_, _) => false,
+                Ratify::Genesis(..) => false,
                 Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => true,
             });
 
@@ -595,6 +681,19 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 Err(e) => return Err(format!("Failed to post-ratify - {e}")),
             }
 
+            /* Record the history of the finalize store. */
+
+            // Note: The history is recorded in this atomic batch, so that it is committed together with the block.
+            if let Err(e) = store.insert_history(state.block_height()) {
+                // Note: This will abort the entire atomic batch.
+                return Err(format!("Failed to record the finalize history - {e}"));
+            }
+            // Note: The undo log is stored in this atomic batch, so that the block can be reverted after a restart.
+            if let Err(e) = store.insert_undo_log(state.block_height(), Self::MAX_REVERTIBLE_BLOCKS) {
+                // Note: This will abort the entire atomic batch.
+                return Err(format!("Failed to store the finalize undo log - {e}"));
+            }
+
             /* Start the commit process. */
 
             // Commit all of the stacks to the process.
@@ -633,16 +732,13 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Iterate over the ratifications.
         for ratify in pre_ratifications {
             match ratify {
-                Ratify::Genesis(committee, public_balances) => {
+                Ratify::Genesis(committee, public_balances, bonded_balances) => {
                     // Ensure this is the genesis block.
                     ensure!(state.block_height() == 0, "Ratify::Genesis(..) expected a genesis block");
-                    // Ensure the genesis committee round is 0.
-                    ensure!(
-                        committee.starting_round() == 0,
-                        "Ratify::Genesis(..) expected a genesis committee round of 0"
-                    );
                     // Ensure genesis has not been ratified yet.
                     ensure!(!is_genesis_ratified, "Ratify::Genesis(..) has already been ratified");
+                    // Ensure the genesis committee and bonded balances are valid.
+                    Ratify::check_bonded_balances(committee, bonded_balances)?;
 
                     // TODO (howardwu): Consider whether to initialize the mappings here.
                     //  Currently, this is breaking for test cases that use VM but do not insert the genesis block.
@@ -657,11 +753,26 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     // }
 
                     // Initialize the stakers.
-                    let mut stakers = IndexMap::with_capacity(committee.members().len());
+                    let mut stakers = IndexMap::with_capacity(committee.members().len() + bonded_balances.len());
                     // Iterate over the committee members.
                     for (validator, (microcredits, _)) in committee.members() {
+                        // Compute the stake delegated to the validator.
+                        let delegated = bonded_balances
+                            .values()
+                            .filter(|(bonded_validator, _)| bonded_validator == validator)
+                            .try_fold(0u64, |acc, (_, amount)| acc.checked_add(*amount))
+                            .ok_or_else(|| anyhow!("Ratify::Genesis(..) has an overflowing delegated stake"))?;
+                        // Compute the stake the validator bonds to itself.
+                        let self_bonded = microcredits.checked_sub(delegated).ok_or_else(|| {
+                            anyhow!("Ratify::Genesis(..) delegates more than the stake of validator '{validator}'")
+                        })?;
                         // Insert the validator into the stakers.
-                        stakers.insert(*validator, (*validator, *microcredits));
+                        stakers.insert(*validator, (*validator, self_bonded));
+                    }
+                    // Iterate over the bonded balances.
+                    for (staker, (validator, microcredits)) in bonded_balances {
+                        // Insert the staker into the stakers.
+                        stakers.insert(*staker, (*validator, *microcredits));
                     }
 
                     // Construct the next committee map and next bonded map.
@@ -787,8 +898,11 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         continue;
                     };
                     // Compute the proof targets, with the corresponding addresses.
-                    let proof_targets =
-                        solutions.values().map(|s| Ok((s.address(), s.to_target()?))).collect::<Result<Vec<_>>>()?;
+                    let proof_targets = solutions
+                        .solution_details()?
+                        .iter()
+                        .map(|detail| (detail.address(), detail.target()))
+                        .collect();
                     // Calculate the proving rewards.
                     let proving_rewards = proving_rewards(proof_targets, *puzzle_reward);
                     // Iterate over the proving rewards.
@@ -1542,4 +1656,104 @@ finalize compute:
         let expected = Value::<CurrentNetwork>::from_str("3u8").unwrap();
         assert_eq!(value, expected);
     }
+
+    #[test]
+    fn test_get_mapping_value_speculative() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key and address for the caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_address = Address::try_from(&caller_private_key).unwrap();
+
+        // Sample a private key and address for the recipient.
+        let recipient_private_key = PrivateKey::new(rng).unwrap();
+        let recipient_address = Address::try_from(&recipient_private_key).unwrap();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Deploy a new program.
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Construct the deployment block.
+        let (program_id, deployment_block) =
+            new_program_deployment(&vm, &caller_private_key, &genesis, &mut unspent_records, rng).unwrap();
+
+        // Add the deployment block to the VM.
+        vm.add_next_block(&deployment_block).unwrap();
+
+        // Generate more records to use for the next block.
+        let splits_block =
+            generate_splits(&vm, &caller_private_key, &deployment_block, &mut unspent_records, rng).unwrap();
+
+        // Add the splits block to the VM.
+        vm.add_next_block(&splits_block).unwrap();
+
+        // Construct the initial mint.
+        let initial_mint =
+            sample_mint_public(&vm, caller_private_key, &program_id, caller_address, 20, &mut unspent_records, rng);
+        let initial_mint_block =
+            sample_next_block(&vm, &caller_private_key, &[initial_mint], &splits_block, &mut unspent_records, rng)
+                .unwrap();
+
+        // Add the block to the vm.
+        vm.add_next_block(&initial_mint_block).unwrap();
+
+        // Construct a candidate transfer.
+        let transfer_10 = sample_transfer_public(
+            &vm,
+            caller_private_key,
+            &program_id,
+            recipient_address,
+            10,
+            &mut unspent_records,
+            rng,
+        );
+
+        // Prepare the mapping lookups.
+        let program_id = ProgramID::from_str(&program_id).unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let caller_key = Plaintext::from(Literal::Address(caller_address));
+        let recipient_key = Plaintext::from(Literal::Address(recipient_address));
+        let get_confirmed =
+            |key: &Plaintext<CurrentNetwork>| vm.finalize_store().get_value_confirmed(program_id, mapping_name, key);
+
+        // Speculate on the transfer, while concurrently reading the confirmed balance of the caller.
+        let (caller_value, recipient_value) = std::thread::scope(|scope| {
+            let reader = scope.spawn(|| {
+                for _ in 0..100 {
+                    assert_eq!(get_confirmed(&caller_key).unwrap(), Some(Value::from_str("20u64").unwrap()));
+                }
+            });
+            let transactions = [transfer_10.clone()];
+            let caller_value =
+                vm.get_mapping_value_speculative(program_id, mapping_name, &caller_key, &transactions).unwrap();
+            let recipient_value =
+                vm.get_mapping_value_speculative(program_id, mapping_name, &recipient_key, &transactions).unwrap();
+            reader.join().unwrap();
+            (caller_value, recipient_value)
+        });
+
+        // Ensure the speculative values reflect the transfer.
+        assert_eq!(caller_value, Some(Value::from_str("10u64").unwrap()));
+        assert_eq!(recipient_value, Some(Value::from_str("10u64").unwrap()));
+
+        // Ensure the confirmed values are untouched, and no state persisted.
+        assert_eq!(get_confirmed(&caller_key).unwrap(), Some(Value::from_str("20u64").unwrap()));
+        assert_eq!(get_confirmed(&recipient_key).unwrap(), None);
+        assert!(!vm.finalize_store().is_atomic_in_progress());
+
+        // Ensure that without candidate transactions, the speculative value matches the confirmed value.
+        let value = vm.get_mapping_value_speculative(program_id, mapping_name, &caller_key, &[]).unwrap();
+        assert_eq!(value, Some(Value::from_str("20u64").unwrap()));
+    }
 }
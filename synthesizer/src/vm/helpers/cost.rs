@@ -17,40 +17,13 @@ use console::{
     prelude::*,
     program::{LiteralType, PlaintextType},
 };
-use ledger_block::{Deployment, Execution};
+use ledger_block::Execution;
+pub use ledger_block::deployment_cost;
 use ledger_store::ConsensusStorage;
 use synthesizer_program::{Command, Finalize, Instruction};
 
 use std::collections::HashMap;
 
-/// Returns the *minimum* cost in microcredits to publish the given deployment (total cost, (storage cost, namespace cost)).
-pub fn deployment_cost<N: Network>(deployment: &Deployment<N>) -> Result<(u64, (u64, u64))> {
-    // Determine the number of bytes in the deployment.
-    let size_in_bytes = deployment.size_in_bytes()?;
-    // Retrieve the program ID.
-    let program_id = deployment.program_id();
-    // Determine the number of characters in the program ID.
-    let num_characters = u32::try_from(program_id.name().to_string().len())?;
-
-    // Compute the storage cost in microcredits.
-    let storage_cost = size_in_bytes
-        .checked_mul(N::DEPLOYMENT_FEE_MULTIPLIER)
-        .ok_or(anyhow!("The storage cost computation overflowed for a deployment"))?;
-
-    // Compute the namespace cost in credits: 10^(10 - num_characters).
-    let namespace_cost = 10u64
-        .checked_pow(10u32.saturating_sub(num_characters))
-        .ok_or(anyhow!("The namespace cost computation overflowed for a deployment"))?
-        .saturating_mul(1_000_000); // 1 microcredit = 1e-6 credits.
-
-    // Compute the total cost in microcredits.
-    let total_cost = storage_cost
-        .checked_add(namespace_cost)
-        .ok_or(anyhow!("The total cost computation overflowed for a deployment"))?;
-
-    Ok((total_cost, (storage_cost, namespace_cost)))
-}
-
 /// Returns the *minimum* cost in microcredits to publish the given execution (total cost, (storage cost, namespace cost)).
 pub fn execution_cost<N: Network, C: ConsensusStorage<N>>(
     vm: &VM<N, C>,
@@ -159,6 +132,16 @@ pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
         Command::Instruction(Instruction::HashManyPSD8(_)) => {
             bail!("`hash_many.psd8` is not supported in finalize.")
         }
+        Command::Instruction(Instruction::HashCanonicalBHP256(_)) => Ok(100_000),
+        Command::Instruction(Instruction::HashCanonicalBHP512(_)) => Ok(100_000),
+        Command::Instruction(Instruction::HashCanonicalBHP768(_)) => Ok(100_000),
+        Command::Instruction(Instruction::HashCanonicalBHP1024(_)) => Ok(100_000),
+        Command::Instruction(Instruction::HashCanonicalKeccak256(_)) => Ok(100_000),
+        Command::Instruction(Instruction::HashCanonicalKeccak384(_)) => Ok(100_000),
+        Command::Instruction(Instruction::HashCanonicalKeccak512(_)) => Ok(100_000),
+        Command::Instruction(Instruction::HashCanonicalPSD2(_)) => Ok(60_000),
+        Command::Instruction(Instruction::HashCanonicalPSD4(_)) => Ok(100_000),
+        Command::Instruction(Instruction::HashCanonicalPSD8(_)) => Ok(200_000),
         Command::Instruction(Instruction::Inv(_)) => Ok(10_000),
         Command::Instruction(Instruction::IsEq(_)) => Ok(2_000),
         Command::Instruction(Instruction::IsNeq(_)) => Ok(2_000),
@@ -176,6 +159,9 @@ pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
         Command::Instruction(Instruction::PowWrapped(_)) => Ok(2_000),
         Command::Instruction(Instruction::Rem(_)) => Ok(2_000),
         Command::Instruction(Instruction::RemWrapped(_)) => Ok(2_000),
+        Command::Instruction(Instruction::RotateLeft(_)) => Ok(2_000),
+        Command::Instruction(Instruction::RotateRight(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Sign(_)) => Ok(2_000),
         Command::Instruction(Instruction::SignVerify(_)) => Ok(250_000),
         Command::Instruction(Instruction::Shl(_)) => Ok(2_000),
         Command::Instruction(Instruction::ShlWrapped(_)) => Ok(2_000),
@@ -185,6 +171,7 @@ pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
         Command::Instruction(Instruction::SquareRoot(_)) => Ok(120_000),
         Command::Instruction(Instruction::Sub(_)) => Ok(10_000),
         Command::Instruction(Instruction::SubWrapped(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Sum(_)) => Ok(10_000),
         Command::Instruction(Instruction::Ternary(_)) => Ok(2_000),
         Command::Instruction(Instruction::Xor(_)) => Ok(2_000),
         // TODO: The following 'finalize' commands are currently priced higher than expected.
@@ -97,21 +97,15 @@ pub fn proving_rewards<N: Network>(
 
     // Calculate the rewards for the individual provers.
     for (address, proof_target) in proof_targets {
-        // Compute the numerator.
-        let numerator = (puzzle_reward as u128).saturating_mul(proof_target as u128);
-        // Compute the denominator.
-        // Note: We guarantee this denominator cannot be 0 (to prevent a div by 0).
-        let denominator = combined_proof_target.max(1);
-        // Compute the quotient.
-        let quotient = numerator.saturating_div(denominator);
-        // Ensure the proving reward is within a safe bound.
-        if quotient > MAX_COINBASE_REWARD as u128 {
-            error!("Prover reward ({quotient}) is too large - skipping solution from {address}");
-            continue;
-        }
-        // Cast the proving reward as a u64.
-        // Note: This '.expect' is guaranteed to be safe, as we ensure the quotient is within a safe bound.
-        let prover_reward = u64::try_from(quotient).expect("Prover reward is too large");
+        // Compute the proving reward.
+        // Note: A proving reward above the safety bound is skipped.
+        let prover_reward = match ledger_block::proving_reward(puzzle_reward, proof_target, combined_proof_target) {
+            Ok(prover_reward) => prover_reward,
+            Err(error) => {
+                error!("{error} - skipping solution from {address}");
+                continue;
+            }
+        };
         // If there is a proving reward, append it to the vector.
         if prover_reward > 0 {
             // Add the proving reward to the prover.
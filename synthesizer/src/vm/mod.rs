@@ -19,6 +19,7 @@ mod authorize;
 mod deploy;
 mod execute;
 mod finalize;
+mod revert;
 mod verify;
 
 use crate::{cast_mut_ref, cast_ref, process};
@@ -30,6 +31,7 @@ use console::{
 };
 use ledger_block::{
     Block,
+    BondedBalances,
     ConfirmedTransaction,
     Deployment,
     Execution,
@@ -46,6 +48,7 @@ use ledger_committee::Committee;
 use ledger_query::Query;
 use ledger_store::{
     atomic_finalize,
+    helpers::StorageHealth,
     BlockStore,
     ConsensusStorage,
     ConsensusStore,
@@ -60,7 +63,7 @@ use synthesizer_program::{FinalizeGlobalState, FinalizeOperation, FinalizeStoreT
 
 use aleo_std::prelude::{finish, lap, timer};
 use indexmap::IndexMap;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use std::sync::Arc;
 
 #[derive(Clone)]
@@ -69,6 +72,8 @@ pub struct VM<N: Network, C: ConsensusStorage<N>> {
     process: Arc<RwLock<Process<N>>>,
     /// The VM store.
     store: ConsensusStore<N, C>,
+    /// The lock for adding a block, which guards the atomic batch of the VM store.
+    block_lock: Arc<Mutex<()>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -76,6 +81,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     #[inline]
     pub fn from(store: ConsensusStore<N, C>) -> Result<Self> {
         // Initialize a new process.
+        // Note: A network that does not bundle its 'credits.aleo' keys (such as `DevnetV0`) must register them
+        // beforehand, with `Process::load_or_setup`, as the keys are loaded through `Network::get_credits_proving_key`.
         let mut process = Process::load()?;
 
         // Initialize the store for 'credits.aleo'.
@@ -142,7 +149,11 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         }
 
         // Return the new VM.
-        Ok(Self { process: Arc::new(RwLock::new(process)), store })
+        Ok(Self {
+            process: Arc::new(RwLock::new(process)),
+            store,
+            block_lock: Default::default(),
+        })
     }
 
     /// Returns `true` if a program with the given program ID exists.
@@ -182,6 +193,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     pub fn transition_store(&self) -> &TransitionStore<N, C::TransitionStorage> {
         self.store.transition_store()
     }
+
+    /// Returns the health of the storage write path.
+    #[inline]
+    pub fn storage_health(&self) -> StorageHealth {
+        self.store.storage_health()
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -212,6 +229,72 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         public_balances: IndexMap<Address<N>, u64>,
         rng: &mut R,
     ) -> Result<Block<N>> {
+        self.genesis_quorum_with_bonded_balances(private_key, committee, public_balances, BondedBalances::new(), rng)
+    }
+
+    /// Returns a new genesis block for a quorum chain,
+    /// where the given bonded balances delegate stake to the validators in the committee.
+    pub fn genesis_quorum_with_bonded_balances<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        committee: Committee<N>,
+        public_balances: IndexMap<Address<N>, u64>,
+        bonded_balances: BondedBalances<N>,
+        rng: &mut R,
+    ) -> Result<Block<N>> {
+        // Prepare the genesis ratifications, solutions, transactions, and ratified finalize operations.
+        let (ratifications, solutions, transactions, ratified_finalize_operations) =
+            self.prepare_genesis(private_key, committee, public_balances, bonded_balances, rng)?;
+
+        // Prepare the block header.
+        let header = Header::genesis(&ratifications, &transactions, ratified_finalize_operations)?;
+        // Prepare the previous block hash.
+        let previous_hash = N::BlockHash::default();
+
+        // Construct the block.
+        let block =
+            Block::new_beacon(private_key, previous_hash, header, ratifications, solutions, transactions, vec![], rng)?;
+        // Ensure the block is valid genesis block.
+        match block.is_genesis() {
+            true => Ok(block),
+            false => bail!("Failed to initialize a genesis block"),
+        }
+    }
+
+    /// Returns a new genesis block for a quorum chain, authorized by the given committee (instead of a signature),
+    /// where the given bonded balances delegate stake to the validators in the committee.
+    /// Note: Unlike `genesis_quorum`, the block has a genesis quorum authority, and therefore a different block hash.
+    pub fn genesis_quorum_from_committee<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        committee: Committee<N>,
+        public_balances: IndexMap<Address<N>, u64>,
+        bonded_balances: BondedBalances<N>,
+        rng: &mut R,
+    ) -> Result<Block<N>> {
+        // Prepare the genesis ratifications, solutions, transactions, and ratified finalize operations.
+        let (ratifications, solutions, transactions, ratified_finalize_operations) =
+            self.prepare_genesis(private_key, committee.clone(), public_balances, bonded_balances, rng)?;
+
+        // Construct the block.
+        let block =
+            Block::genesis_quorum(committee, ratifications, solutions, transactions, ratified_finalize_operations)?;
+        // Ensure the block is valid genesis block.
+        match block.is_genesis() {
+            true => Ok(block),
+            false => bail!("Failed to initialize a genesis block"),
+        }
+    }
+
+    /// Returns the ratifications, solutions, transactions, and ratified finalize operations for a genesis block.
+    fn prepare_genesis<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        committee: Committee<N>,
+        public_balances: IndexMap<Address<N>, u64>,
+        bonded_balances: BondedBalances<N>,
+        rng: &mut R,
+    ) -> Result<(Ratifications<N>, Option<CoinbaseSolution<N>>, Transactions<N>, Vec<FinalizeOperation<N>>)> {
         // Retrieve the total stake.
         let total_stake = committee.total_stake();
         // Compute the account supply.
@@ -231,7 +314,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let inputs = [caller.to_string(), format!("{amount}_u64")];
 
         // Prepare the ratifications.
-        let ratifications = vec![Ratify::Genesis(committee, public_balances)];
+        let ratifications = vec![Ratify::genesis(committee, public_balances, bonded_balances)?];
         // Prepare the solutions.
         let solutions = None; // The genesis block does not require solutions.
         // Prepare the transactions.
@@ -249,32 +332,15 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             "Failed to initialize a genesis block - found aborted transaction IDs"
         );
 
-        // Prepare the block header.
-        let header = Header::genesis(&ratifications, &transactions, ratified_finalize_operations)?;
-        // Prepare the previous block hash.
-        let previous_hash = N::BlockHash::default();
-
-        // Construct the block.
-        let block = Block::new_beacon(
-            private_key,
-            previous_hash,
-            header,
-            ratifications,
-            solutions,
-            transactions,
-            aborted_transaction_ids,
-            rng,
-        )?;
-        // Ensure the block is valid genesis block.
-        match block.is_genesis() {
-            true => Ok(block),
-            false => bail!("Failed to initialize a genesis block"),
-        }
+        Ok((ratifications, solutions, transactions, ratified_finalize_operations))
     }
 
     /// Adds the given block into the VM.
     #[inline]
     pub fn add_next_block(&self, block: &Block<N>) -> Result<()> {
+        // Acquire the block lock, to ensure no other operation uses the atomic batch of the store concurrently.
+        let _block_lock = self.block_lock.lock();
+
         // Construct the finalize state.
         let state = FinalizeGlobalState::new::<N>(
             block.round(),
@@ -289,8 +355,11 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         // First, insert the block.
         self.block_store().insert(block)?;
-        // Next, finalize the transactions.
-        match self.finalize(state, block.ratifications(), block.solutions(), block.transactions()) {
+        // Next, finalize the transactions, while recording the undo log of the finalize store.
+        self.finalize_store().start_undo_log();
+        let result = self.finalize(state, block.ratifications(), block.solutions(), block.transactions());
+        self.finalize_store().finish_undo_log();
+        match result {
             Ok(_ratified_finalize_operations) => Ok(()),
             Err(error) => {
                 // Rollback the block.
@@ -967,4 +1036,80 @@ function multitransfer:
             .unwrap();
         vm.add_next_block(&sample_next_block(&vm, &caller_private_key, &[execution], rng).unwrap()).unwrap();
     }
+
+    #[test]
+    fn test_finalize_history_through_add_next_block() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize a VM, with history mode enabled in the finalize store.
+        let store = ConsensusStore::open_with_history(None, ledger_store::FinalizeHistory::full()).unwrap();
+        let vm = VM::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::from(store).unwrap();
+        vm.add_next_block(&sample_genesis_block(rng)).unwrap();
+
+        // Retrieve the public balance of the caller after the genesis block.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from(Literal::Address(caller_address));
+        let genesis_balance = vm.finalize_store().get_value_confirmed(program_id, mapping_name, &key).unwrap();
+
+        // Add a block, in which the caller pays a public fee.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction_with_public_fee(rng);
+        vm.add_next_block(&sample_next_block(&vm, &caller_private_key, &[transaction], rng).unwrap()).unwrap();
+        let balance = vm.finalize_store().get_value_confirmed(program_id, mapping_name, &key).unwrap();
+        assert_ne!(balance, genesis_balance);
+
+        // Ensure the history is recorded by `add_next_block`.
+        let finalize_store = vm.finalize_store();
+        assert_eq!(finalize_store.history_range().unwrap(), Some(0..=1));
+        assert_eq!(finalize_store.get_value_at_height(program_id, mapping_name, &key, 0).unwrap(), genesis_balance);
+        assert_eq!(finalize_store.get_value_at_height(program_id, mapping_name, &key, 1).unwrap(), balance);
+
+        // Reopen the VM from its storage.
+        let vm = VM::from(vm.store.clone()).unwrap();
+        // Ensure the history survives the reopen.
+        let finalize_store = vm.finalize_store();
+        assert_eq!(finalize_store.history(), Some(ledger_store::FinalizeHistory::full()));
+        assert_eq!(finalize_store.history_range().unwrap(), Some(0..=1));
+        assert_eq!(finalize_store.get_value_at_height(program_id, mapping_name, &key, 0).unwrap(), genesis_balance);
+        assert_eq!(finalize_store.get_value_at_height(program_id, mapping_name, &key, 1).unwrap(), balance);
+    }
+
+    #[test]
+    fn test_revert_to_height_after_reopen() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        vm.add_next_block(&sample_genesis_block(rng)).unwrap();
+
+        // Retrieve the public balance of the caller after the genesis block.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from(Literal::Address(caller_address));
+        let genesis_balance = vm.finalize_store().get_value_confirmed(program_id, mapping_name, &key).unwrap();
+
+        // Add a block, in which the caller pays a public fee.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction_with_public_fee(rng);
+        let block = sample_next_block(&vm, &caller_private_key, &[transaction], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+        assert_ne!(vm.finalize_store().get_value_confirmed(program_id, mapping_name, &key).unwrap(), genesis_balance);
+        assert!(vm.finalize_store().get_undo_log(1).unwrap().is_some());
+
+        // Reopen the VM from its storage, and ensure the block can be reverted with the stored undo log.
+        let vm = VM::from(vm.store.clone()).unwrap();
+        assert_eq!(vm.revert_to_height(0).unwrap(), vec![block]);
+
+        // Ensure the block, its finalize writes, and its undo log are removed.
+        assert_eq!(vm.block_store().heights().max().map(|height| *height), Some(0));
+        assert_eq!(vm.finalize_store().get_value_confirmed(program_id, mapping_name, &key).unwrap(), genesis_balance);
+        assert!(vm.finalize_store().get_undo_log(1).unwrap().is_none());
+    }
 }
@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ledger_store::atomic_batch_scope;
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// The maximum number of recently added blocks that can be reverted.
+    pub const MAX_REVERTIBLE_BLOCKS: u32 = 100;
+
+    /// Reverts the VM to the given block height, and returns the removed blocks in ascending order of height.
+    /// The blocks are reverted in one atomic batch, by unapplying their finalize operations with the undo logs
+    /// that were stored when the blocks were added, removing their committees, undo logs, and deployed programs,
+    /// and removing the blocks from storage. If any block fails to revert, the VM remains at the latest block.
+    ///
+    /// Note: Only the most recent `MAX_REVERTIBLE_BLOCKS` blocks can be reverted.
+    pub fn revert_to_height(&self, height: u32) -> Result<Vec<Block<N>>> {
+        // Acquire the block lock, to ensure no other operation uses the atomic batch of the store concurrently.
+        let _block_lock = self.block_lock.lock();
+
+        // Retrieve the latest block height.
+        let Some(latest_height) = self.block_store().heights().max().map(|height| *height) else {
+            bail!("Cannot revert to block {height} - there are no blocks in storage")
+        };
+        // Ensure the given height is not above the latest height.
+        ensure!(height <= latest_height, "Cannot revert to block {height} - the latest block is {latest_height}");
+
+        // Ensure the undo log of each block to revert is available.
+        for block_height in (height + 1)..=latest_height {
+            ensure!(
+                self.finalize_store().get_undo_log(block_height)?.is_some(),
+                "Cannot revert to block {height} - block {block_height} is beyond the revertible boundary"
+            );
+        }
+
+        // Retrieve the blocks to revert and their undo logs, in descending order of height.
+        let mut reverts = Vec::with_capacity((latest_height - height) as usize);
+        for block_height in ((height + 1)..=latest_height).rev() {
+            // Retrieve the block.
+            let block = match self.block_store().get_block_hash(block_height)? {
+                Some(block_hash) => match self.block_store().get_block(&block_hash)? {
+                    Some(block) => block,
+                    None => bail!("Failed to revert block {block_height} - missing block {block_hash}"),
+                },
+                None => bail!("Failed to revert block {block_height} - missing block hash"),
+            };
+            // Retrieve the undo log.
+            let Some(undo_log) = self.finalize_store().get_undo_log(block_height)? else {
+                bail!("Failed to revert block {block_height} - missing undo log")
+            };
+            reverts.push((block_height, block, undo_log));
+        }
+
+        // If there are no blocks to revert, return early.
+        if reverts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Acquire the write lock on the process.
+        let mut process = self.process.write();
+
+        // Ensure the programs deployed in the blocks can be removed from the process, in reverse order.
+        // Note: This is checked without mutating the process, so that the removals below can not fail.
+        let program_ids: Vec<_> =
+            reverts.iter().flat_map(|(_, block, _)| deployed_program_ids(block).into_iter().rev()).collect();
+        process.check_remove_programs(&program_ids)?;
+
+        // Revert the blocks, in descending order of height.
+        atomic_batch_scope!(self.store, {
+            for (block_height, _, undo_log) in &reverts {
+                // Unapply the finalize operations of the block.
+                self.finalize_store().apply_undo_log(undo_log)?;
+                // Remove the undo log of the block.
+                self.finalize_store().remove_undo_log(*block_height)?;
+                // Remove the committee of the block, if one was stored.
+                let committee_store = self.finalize_store().committee_store();
+                if committee_store.get_committee(*block_height)?.is_some() {
+                    committee_store.remove(*block_height)?;
+                }
+            }
+            // Remove the history of the blocks, if history mode is enabled.
+            self.finalize_store().remove_history_above(height)?;
+            // Remove the blocks.
+            self.block_store().remove_last_n(latest_height - height)?;
+            Ok(())
+        })?;
+
+        // Remove the programs deployed in the blocks from the process, now that the blocks are removed from storage.
+        for program_id in program_ids {
+            process.remove_program(program_id)?;
+        }
+        drop(process);
+
+        // Collect the removed blocks.
+        let mut blocks: Vec<_> = reverts.into_iter().map(|(_, block, _)| block).collect();
+
+        // Return the removed blocks, in ascending order of height.
+        blocks.reverse();
+        Ok(blocks)
+    }
+}
+
+/// Returns the IDs of the programs deployed in the given block, in order of deployment.
+fn deployed_program_ids<N: Network>(block: &Block<N>) -> Vec<&ProgramID<N>> {
+    block
+        .transactions()
+        .iter()
+        .filter_map(|transaction| match transaction {
+            ConfirmedTransaction::AcceptedDeploy(_, Transaction::Deploy(_, _, deployment, _), _) => {
+                Some(deployment.program_id())
+            }
+            _ => None,
+        })
+        .collect()
+}
@@ -14,6 +14,9 @@
 
 use super::*;
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 /// Ensures the given iterator has no duplicate elements, and that the ledger
 /// does not already contain a given item.
 macro_rules! ensure_is_unique {
@@ -35,6 +38,78 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Verifies the transaction in the VM. On failure, returns an error.
     #[inline]
     pub fn check_transaction(&self, transaction: &Transaction<N>, rejected_id: Option<Field<N>>) -> Result<()> {
+        self.check_transaction_internal(transaction, rejected_id, false)
+    }
+
+    /// Verifies the given transactions in the VM, batching the verification of the executions across all transactions.
+    /// On failure, returns an error that reports every invalid transaction, in the order of the given transactions.
+    #[inline]
+    pub fn check_transactions(&self, transactions: &[(&Transaction<N>, Option<Field<N>>)]) -> Result<()> {
+        let timer = timer!("VM::check_transactions");
+
+        // Verify each transaction, deferring the verification of the executions.
+        let mut errors: Vec<(usize, Error)> = cfg_iter!(transactions)
+            .enumerate()
+            .filter_map(|(index, (transaction, rejected_id))| {
+                self.check_transaction_internal(transaction, *rejected_id, true).err().map(|error| (index, error))
+            })
+            .collect();
+        lap!(timer, "Verify the transactions");
+
+        // Retrieve the executions, along with the index of their transaction.
+        let (indices, executions): (Vec<_>, Vec<_>) = transactions
+            .iter()
+            .enumerate()
+            .filter_map(|(index, (transaction, _))| match transaction {
+                Transaction::Execute(_, execution, _) => Some((index, execution)),
+                _ => None,
+            })
+            .unzip();
+
+        // Verify the executions concurrently.
+        let execution_errors = self.process.read().verify_execution_concurrent(&executions);
+        lap!(timer, "Verify the executions");
+
+        // Ensure the global state root of each verified execution exists in the block store.
+        let mut execution_errors = execution_errors.into_iter().peekable();
+        for (position, (index, execution)) in indices.into_iter().zip_eq(executions).enumerate() {
+            match execution_errors.next_if(|(failed_position, _)| *failed_position == position) {
+                Some((_, error)) => errors.push((index, anyhow!("Execution verification failed: {error}"))),
+                None => {
+                    if let Err(error) = self.check_global_state_root(execution) {
+                        errors.push((index, error));
+                    }
+                }
+            }
+        }
+        finish!(timer, "Check the global state roots");
+
+        // Ensure all of the transactions are valid.
+        if !errors.is_empty() {
+            // Sort the errors by the index of their transaction, so that the report is deterministic.
+            // Note: The sort is stable, so errors for the same transaction retain the order in which they were found.
+            errors.sort_by_key(|(index, _)| *index);
+            bail!(
+                "Found {} invalid transaction(s):\n{}",
+                errors.len(),
+                errors
+                    .iter()
+                    .map(|(index, error)| format!("Transaction '{}' - {error}", transactions[*index].0.id()))
+                    .join("\n")
+            )
+        }
+        Ok(())
+    }
+
+    /// Verifies the transaction in the VM. On failure, returns an error.
+    /// If `defer_execution` is `true`, the execution is **not** verified, and must be verified by the caller.
+    #[inline]
+    fn check_transaction_internal(
+        &self,
+        transaction: &Transaction<N>,
+        rejected_id: Option<Field<N>>,
+        defer_execution: bool,
+    ) -> Result<()> {
         let timer = timer!("VM::check_transaction");
 
         /* Transaction */
@@ -42,6 +117,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Ensure the transaction ID is unique.
         if self.transaction_store().contains_transaction_id(&transaction.id())?
             || self.block_store().contains_rejected_or_aborted_transaction_id(&transaction.id())?
+            || self.block_store().contains_pruned_transaction_id(&transaction.id())?
         {
             bail!("Transaction '{}' already exists in the ledger", transaction.id())
         }
@@ -113,8 +189,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 self.check_deployment_internal(deployment)?;
             }
             Transaction::Execute(_, execution, _) => {
-                // Verify the execution.
-                self.check_execution_internal(execution)?;
+                // Verify the execution, unless it is deferred to the caller.
+                if !defer_execution {
+                    self.check_execution_internal(execution)?;
+                }
             }
             Transaction::Fee(..) => { /* no-op */ }
         }
@@ -219,17 +297,23 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Ensure the global state root exists in the block store.
         let result = match verification {
             // Ensure the global state root exists in the block store.
-            Ok(()) => match self.block_store().contains_state_root(&execution.global_state_root()) {
-                Ok(true) => Ok(()),
-                Ok(false) => bail!("Execution verification failed: global state root not found"),
-                Err(error) => bail!("Execution verification failed: {error}"),
-            },
+            Ok(()) => self.check_global_state_root(execution),
             Err(error) => bail!("Execution verification failed: {error}"),
         };
         finish!(timer, "Check the global state root");
         result
     }
 
+    /// Ensures the global state root of the given execution exists in the block store.
+    #[inline]
+    fn check_global_state_root(&self, execution: &Execution<N>) -> Result<()> {
+        match self.block_store().contains_state_root(&execution.global_state_root()) {
+            Ok(true) => Ok(()),
+            Ok(false) => bail!("Execution verification failed: global state root not found"),
+            Err(error) => bail!("Execution verification failed: {error}"),
+        }
+    }
+
     /// Verifies the given fee. On failure, returns an error.
     ///
     /// Note: This is an internal check only. To ensure all components of the fee are checked,
@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod utilities;
+use utilities::*;
+
+use console::network::prelude::*;
+use snarkvm_synthesizer::program::Program;
+
+use rayon::prelude::*;
+
+#[test]
+fn test_program_schema() {
+    // Load the tests.
+    let tests = load_tests::<_, FileParseTest>("./tests/schema", "./expectations/schema");
+    // Run each test and compare it against its corresponding expectation.
+    tests.par_iter().for_each(|test| {
+        // Parse the program.
+        let program = Program::<CurrentNetwork>::from_str(test.test_string()).unwrap();
+        // Construct the schemas for the structs and functions of the program.
+        let structs = program
+            .structs()
+            .keys()
+            .map(|name| Ok((name.to_string(), program.struct_schema(name)?)))
+            .collect::<Result<serde_json::Map<_, _>>>()
+            .unwrap();
+        let functions = program
+            .functions()
+            .keys()
+            .map(|name| Ok((name.to_string(), program.function_io_schema(name)?)))
+            .collect::<Result<serde_json::Map<_, _>>>()
+            .unwrap();
+        let output = serde_json::json!({ "structs": structs, "functions": functions });
+        let output = format!("{}\n", serde_json::to_string_pretty(&output).unwrap());
+        // Check against the expected output.
+        test.check(&output).unwrap();
+        // Save the output.
+        test.save(&output).unwrap();
+    });
+}
@@ -0,0 +1,57 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Returns `true` if the given limbs are equal.
+///
+/// The comparison does not exit early, so it runs in time that
+/// only depends on the lengths of the inputs, not on their contents.
+#[inline]
+pub fn ct_eq_limbs(a: &[u64], b: &[u64]) -> bool {
+    // The lengths are not secret, so they are compared directly.
+    if a.len() != b.len() {
+        return false;
+    }
+    // Accumulate the differences of all the limbs.
+    let difference = a.iter().zip(b).fold(0u64, |difference, (a, b)| difference | (a ^ b));
+    // Prevent the compiler from short-circuiting the accumulation.
+    core::hint::black_box(difference) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestRng;
+
+    use rand::Rng;
+
+    const ITERATIONS: usize = 1000;
+
+    #[test]
+    fn test_ct_eq_limbs() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let a: [u64; 4] = rng.gen();
+            let mut b = a;
+            assert!(ct_eq_limbs(&a, &b));
+
+            // Flip a random bit of a random limb.
+            b[rng.gen_range(0..4)] ^= 1 << rng.gen_range(0..64);
+            assert!(!ct_eq_limbs(&a, &b));
+
+            // Check that limbs of different lengths are not equal.
+            assert!(!ct_eq_limbs(&a, &a[..3]));
+        }
+    }
+}
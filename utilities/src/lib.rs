@@ -55,6 +55,9 @@ pub use bits::*;
 pub mod bytes;
 pub use bytes::*;
 
+pub mod constant_time;
+pub use constant_time::*;
+
 pub mod error;
 pub use error::*;
 
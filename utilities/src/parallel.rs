@@ -196,6 +196,20 @@ macro_rules! cfg_reduce_with {
     }};
 }
 
+/// Turns a map into an iterator over its keys.
+#[macro_export]
+macro_rules! cfg_keys {
+    ($e: expr) => {{
+        #[cfg(not(feature = "serial"))]
+        let result = $e.par_keys();
+
+        #[cfg(feature = "serial")]
+        let result = $e.keys();
+
+        result
+    }};
+}
+
 /// Turns a collection into an iterator.
 #[macro_export]
 macro_rules! cfg_values {
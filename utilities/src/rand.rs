@@ -36,26 +36,82 @@ where
     }
 }
 
+/// The environment variable that fixes the seed of every `TestRng` constructed from the environment.
+pub const TEST_SEED_ENV_VAR: &str = "SNARKVM_TEST_SEED";
+
+/// The prefix of every message logged by `TestRng`, so the seeds are easy to find in the test output.
+const TEST_RNG_LOG_PREFIX: &str = "[snarkvm-test-rng]";
+
 /// A fast RNG used **solely** for testing and benchmarking, **not** for any real world purposes.
-pub struct TestRng(XorShiftRng);
+pub struct TestRng {
+    /// The underlying Rng.
+    rng: XorShiftRng,
+    /// The seed of the underlying Rng.
+    seed: u64,
+}
 
 impl Default for TestRng {
+    /// Initializes a `TestRng` from the environment, see `TestRng::from_env`.
     fn default() -> Self {
-        // Obtain the initial seed using entropy provided by the OS.
-        let seed = StdRng::from_entropy().gen();
-
-        // Use it as the basis for the underlying Rng.
-        Self::fixed(seed)
+        Self::from_env()
     }
 }
 
 impl TestRng {
+    /// Initializes a `TestRng` with the seed in the `SNARKVM_TEST_SEED` environment variable, if it is set,
+    /// and otherwise with a seed sampled using entropy provided by the OS.
+    pub fn from_env() -> Self {
+        match Self::seed_from_env() {
+            Some(seed) => Self::fixed(seed),
+            None => Self::fixed(StdRng::from_entropy().gen()),
+        }
+    }
+
+    /// Initializes a `TestRng` with the seed in the `SNARKVM_TEST_SEED` environment variable, if it is set,
+    /// and otherwise with the given seed.
+    pub fn from_env_or(seed: u64) -> Self {
+        Self::from_seed_or(Self::seed_from_env(), seed)
+    }
+
+    /// Initializes a `TestRng` with the given seed, if it is set, and otherwise with the fallback seed.
+    pub fn from_seed_or(seed: Option<u64>, fallback: u64) -> Self {
+        Self::fixed(seed.unwrap_or(fallback))
+    }
+
+    /// Initializes a `TestRng` with the given seed.
     pub fn fixed(seed: u64) -> Self {
-        // Print the seed, so it's displayed if any of the tests using `test_rng` fails.
-        println!("\nInitializing 'TestRng' with seed '{seed}'\n");
+        // Log the seed, so it's displayed if any of the tests using the `TestRng` fails.
+        Self::log_seed(seed);
 
         // Use the seed to initialize a fast, non-cryptographic Rng.
-        Self(XorShiftRng::seed_from_u64(seed))
+        Self { rng: XorShiftRng::seed_from_u64(seed), seed }
+    }
+
+    /// Returns the seed of the `TestRng`.
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the seed in the `SNARKVM_TEST_SEED` environment variable, if it is set.
+    ///
+    /// Panics if the environment variable is set to an invalid seed, rather than silently ignoring it.
+    fn seed_from_env() -> Option<u64> {
+        std::env::var(TEST_SEED_ENV_VAR).ok().map(|seed| Self::parse_seed(&seed))
+    }
+
+    /// Returns the seed in the given value of the `SNARKVM_TEST_SEED` environment variable.
+    ///
+    /// Panics if the value is not a valid seed.
+    fn parse_seed(seed: &str) -> u64 {
+        match seed.trim().parse() {
+            Ok(seed) => seed,
+            Err(error) => panic!("{TEST_RNG_LOG_PREFIX} Invalid '{TEST_SEED_ENV_VAR}' of '{seed}': {error}"),
+        }
+    }
+
+    /// Logs the given seed, along with the instructions to reproduce it.
+    fn log_seed(seed: u64) {
+        eprintln!("\n{TEST_RNG_LOG_PREFIX} Initializing 'TestRng' with seed '{seed}'. Reproduce with {TEST_SEED_ENV_VAR}={seed}\n");
     }
 
     /// Returns a randomly-sampled `String`, given the maximum size in bytes and an RNG.
@@ -123,20 +179,46 @@ impl TestRng {
 
 impl rand::RngCore for TestRng {
     fn next_u32(&mut self) -> u32 {
-        self.0.next_u32()
+        self.rng.next_u32()
     }
 
     fn next_u64(&mut self) -> u64 {
-        self.0.next_u64()
+        self.rng.next_u64()
     }
 
     fn fill_bytes(&mut self, dest: &mut [u8]) {
-        self.0.fill_bytes(dest)
+        self.rng.fill_bytes(dest)
     }
 
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
-        self.0.try_fill_bytes(dest)
+        self.rng.try_fill_bytes(dest)
     }
 }
 
 impl rand::CryptoRng for TestRng {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng_from_seed_or() {
+        // Ensure the parsed seed is used over the fallback seed.
+        let mut first = TestRng::from_seed_or(Some(TestRng::parse_seed(" 1234567890\n")), 42);
+        let mut second = TestRng::fixed(1234567890);
+        assert_eq!(first.seed(), 1234567890);
+
+        // Ensure the sampled values are identical.
+        let values = (0..10).map(|_| first.gen::<u64>()).collect::<Vec<_>>();
+        assert_eq!(values, (0..10).map(|_| second.gen::<u64>()).collect::<Vec<_>>());
+
+        // Ensure the fallback seed is used when no seed is set.
+        assert_eq!(TestRng::from_seed_or(None, 42).seed(), 42);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_parse_seed_rejects_invalid_seed() {
+        TestRng::parse_seed("not a seed");
+    }
+}